@@ -0,0 +1,91 @@
+//! Heap allocations made by `LintContext::new`.
+//!
+//! Counts the allocations and bytes requested while building the context of
+//! documents dominated by headings, list items and blockquotes, whose per-line
+//! data refers to the source by byte spans instead of owning copies:
+//!
+//!   cargo bench --bench lint_context_allocations
+//!
+//! Inputs are generated deterministically, so counts are comparable between runs.
+
+use rumdl_lib::config::MarkdownFlavor;
+use rumdl_lib::lint_context::LintContext;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The system allocator, counting what it hands out
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: forwarded unchanged to the system allocator
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was allocated by `alloc` above with this layout
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        // SAFETY: forwarded unchanged to the system allocator
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Sections of ATX and Setext headings, bullet and ordered lists, and quotes
+fn gen_structured(sections: usize) -> String {
+    let mut s = String::with_capacity(sections * 300);
+    for i in 0..sections {
+        s.push_str(&format!("## Section {i} {{#section-{i}}}\n\n"));
+        s.push_str(&format!("Part {i}\n-------\n\n"));
+        for j in 0..4 {
+            s.push_str(&format!("- item {i}.{j} with some text\n"));
+        }
+        s.push('\n');
+        for j in 1..=3 {
+            s.push_str(&format!("{j}. step {i}.{j}\n"));
+        }
+        s.push('\n');
+        s.push_str(&format!("> A quote in section {i}\n> that spans two lines\n\n"));
+        s.push_str(&format!("### Details {i} ###\n\n"));
+    }
+    s
+}
+
+/// Allocations and bytes requested while building the context of `content`
+fn measure(content: &str) -> (usize, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    let measured = (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        BYTES.load(Ordering::Relaxed) - bytes,
+    );
+    drop(black_box(ctx));
+    measured
+}
+
+fn main() {
+    for sections in [100, 1_000, 5_000] {
+        let content = gen_structured(sections);
+        let lines = content.lines().count();
+        let (allocations, bytes) = measure(&content);
+        println!(
+            "LintContext::new, {lines:>6} lines: {allocations:>8} allocations ({:.2} per line), {:>6} KiB",
+            allocations as f64 / lines as f64,
+            bytes / 1024
+        );
+    }
+}
@@ -15,9 +15,9 @@ use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
 use rumdl_lib::rules::md013_line_length::md013_config::ReflowMode;
 use rumdl_lib::rules::{
-    AbsoluteLinksOption, MD011NoReversedLinks, MD013Config, MD013LineLength, MD018NoMissingSpaceAtx,
-    MD021NoMultipleSpaceClosedAtx, MD027MultipleSpacesBlockquote, MD032BlanksAroundLists, MD033NoInlineHtml,
-    MD052ReferenceLinkImages, MD057Config, MD057ExistingRelativeLinks,
+    AbsoluteLinksOption, MD009TrailingSpaces, MD010NoHardTabs, MD011NoReversedLinks, MD012NoMultipleBlanks,
+    MD013Config, MD013LineLength, MD018NoMissingSpaceAtx, MD021NoMultipleSpaceClosedAtx, MD027MultipleSpacesBlockquote,
+    MD032BlanksAroundLists, MD033NoInlineHtml, MD052ReferenceLinkImages, MD057Config, MD057ExistingRelativeLinks,
 };
 use rumdl_lib::workspace_index::{CrossFileLinkIndex, FileIndex, WorkspaceIndex};
 use std::hint::black_box;
@@ -220,6 +220,30 @@ fn bench_lint_overhead(c: &mut Criterion) {
     });
 }
 
+fn bench_whitespace_only(c: &mut Criterion) {
+    // `--enable MD009,MD010,MD012`: none of these rules reads links, images, or
+    // table blocks, so the lazily-built structures are never computed.
+    let mut content = gen_mixed(400);
+    content.push_str(&gen_prose_links(40, 12));
+    let rules: Vec<Box<dyn Rule>> = vec![
+        Box::new(MD009TrailingSpaces::default()),
+        Box::new(MD010NoHardTabs::default()),
+        Box::new(MD012NoMultipleBlanks::default()),
+    ];
+    c.bench_function("whitespace_only/md009_md010_md012_mixed", |b| {
+        b.iter(|| {
+            rumdl_lib::lint(
+                black_box(&content),
+                black_box(&rules),
+                false,
+                MarkdownFlavor::Standard,
+                None,
+                None,
+            )
+        });
+    });
+}
+
 fn bench_md013_reflow(c: &mut Criterion) {
     let content = gen_prose(60, 12);
     let cfg = MD013Config {
@@ -270,7 +294,7 @@ fn bench_atx_headings(c: &mut Criterion) {
     let content = gen_headings(800);
     let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
     let md018 = MD018NoMissingSpaceAtx::default();
-    let md021 = MD021NoMultipleSpaceClosedAtx::default();
+    let md021 = MD021NoMultipleSpaceClosedAtx;
     c.bench_function("atx_headings/md018_check", |b| b.iter(|| md018.check(black_box(&ctx))));
     c.bench_function("atx_headings/md021_check", |b| b.iter(|| md021.check(black_box(&ctx))));
 
@@ -333,6 +357,7 @@ criterion_group!(
     benches,
     bench_lint_context_new,
     bench_lint_overhead,
+    bench_whitespace_only,
     bench_md013_reflow,
     bench_block_scan,
     bench_atx_headings,
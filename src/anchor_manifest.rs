@@ -0,0 +1,185 @@
+//! Committed manifest of heading anchors.
+//!
+//! Backs `rumdl anchors` and MD101: the manifest records the anchor of every
+//! heading, per file, at the time it was written. Other sites, issue trackers
+//! and bookmarks deep-link to those anchors, so MD101 reports headings whose
+//! edits would make a recorded anchor disappear.
+//!
+//! The manifest is a JSON object mapping each file, relative to the project
+//! root and written with `/` separators, to its anchors in document order.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::lint_context::LintContext;
+use crate::utils::anchor_styles::AnchorStyle;
+use crate::utils::slug::Slugify;
+
+/// Default manifest location, relative to the project root
+pub const DEFAULT_MANIFEST: &str = ".rumdl-anchors.json";
+
+/// The anchor of one heading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadingAnchor {
+    /// 0-indexed line of the heading text
+    pub line_index: usize,
+    /// The anchor: the custom ID when the heading has one, otherwise the slug
+    pub anchor: String,
+    /// Whether the anchor comes from an explicit `{#custom-id}`
+    pub is_custom: bool,
+}
+
+/// Anchors of every heading in `ctx`, in document order.
+///
+/// Repeated slugs are numbered the way the platform does (`-1`, `-2`, or
+/// `_1`, `_2` for Python-Markdown); headings that slugify to nothing have no
+/// anchor, except under Python-Markdown.
+pub fn heading_anchors(ctx: &LintContext, slugify: &Slugify) -> Vec<HeadingAnchor> {
+    let underscore_dedup = slugify.is_style(&AnchorStyle::PythonMarkdown);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut anchors = Vec::new();
+
+    for valid in ctx.valid_headings() {
+        let line_index = valid.line_num - 1;
+        if let Some(id) = valid.heading.custom_id(ctx.content) {
+            anchors.push(HeadingAnchor {
+                line_index,
+                anchor: id.to_string(),
+                is_custom: true,
+            });
+            continue;
+        }
+
+        let slug = slugify.slugify(valid.heading.text(ctx.content));
+        if slug.is_empty() && !underscore_dedup {
+            continue;
+        }
+        let count = counts.entry(slug.clone()).or_insert(0);
+        let anchor = match (*count, slug.is_empty(), underscore_dedup) {
+            (_, true, _) => format!("_{}", *count + 1),
+            (0, false, _) => slug,
+            (n, false, true) => format!("{slug}_{n}"),
+            (n, false, false) => format!("{slug}-{n}"),
+        };
+        *count += 1;
+        anchors.push(HeadingAnchor {
+            line_index,
+            anchor,
+            is_custom: false,
+        });
+    }
+
+    anchors
+}
+
+/// Recorded heading anchors, keyed by project-relative file path
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnchorManifest {
+    files: BTreeMap<String, Vec<String>>,
+}
+
+impl AnchorManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a manifest, returning `None` when the file does not exist
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// The manifest as pretty-printed JSON, ending with a newline
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).unwrap_or_default();
+        json.push('\n');
+        json
+    }
+
+    /// Record the anchors of a file; files without anchors are left out
+    pub fn insert(&mut self, file: &Path, anchors: Vec<String>) {
+        if !anchors.is_empty() {
+            self.files.insert(manifest_key(file), anchors);
+        }
+    }
+
+    /// The anchors recorded for a project-relative file path
+    pub fn anchors(&self, file: &Path) -> Option<&[String]> {
+        self.files.get(&manifest_key(file)).map(Vec::as_slice)
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Manifest key of a project-relative path: `/`-separated on every platform
+fn manifest_key(file: &Path) -> String {
+    file.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn anchors(content: &str, slugify: &Slugify) -> Vec<String> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        heading_anchors(&ctx, slugify).into_iter().map(|a| a.anchor).collect()
+    }
+
+    #[test]
+    fn test_heading_anchors_number_repeats_and_keep_custom_ids() {
+        let content = "# Guide\n\n## Setup\n\n## Setup\n\n## Usage {#use}\n\n```\n# Not a heading\n```\n";
+        assert_eq!(
+            anchors(content, &Slugify::default()),
+            ["guide", "setup", "setup-1", "use"]
+        );
+        let python = Slugify::Style(AnchorStyle::PythonMarkdown);
+        assert_eq!(anchors("## Setup\n\n## Setup\n", &python), ["setup", "setup_1"]);
+    }
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_MANIFEST);
+        assert_eq!(AnchorManifest::load(&path).unwrap(), None);
+
+        let mut manifest = AnchorManifest::new();
+        manifest.insert(Path::new("docs/guide.md"), vec!["setup".to_string()]);
+        manifest.insert(Path::new("empty.md"), Vec::new());
+        manifest.save(&path).unwrap();
+
+        let loaded = AnchorManifest::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(
+            loaded.anchors(Path::new("docs/guide.md")),
+            Some(&["setup".to_string()][..])
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "{\n  \"docs/guide.md\": [\n    \"setup\"\n  ]\n}\n"
+        );
+    }
+}
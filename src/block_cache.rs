@@ -0,0 +1,234 @@
+//! Reusing rule results for unchanged blocks (`lint_with_block_cache`)
+//!
+//! Editors lint a document again after every keystroke, although most of it
+//! has not changed. A [`BlockCache`] splits the document into top-level blocks,
+//! the runs of lines between blank lines outside any container, and remembers
+//! what each [`Rule::is_block_local`] rule reported for each block. The next
+//! run checks only the blocks whose text changed and moves the remembered
+//! warnings to where their block now starts. Rules that need the whole document
+//! always run on it.
+
+use crate::config::Config;
+use crate::lint_context::{LineInfo, LintContext};
+use crate::rule::{LintResult, LintWarning, Rule};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// Warnings of block-local rules for the blocks of one document, as of its
+/// last lint
+#[derive(Debug, Default)]
+pub struct BlockCache {
+    /// Fingerprint of the config, flavor and path the entries were made with
+    settings: u64,
+    /// Incremented on every run; entries not used by the latest run are dropped
+    generation: u64,
+    /// Warnings relative to the start of the block, keyed by rule name and block hash
+    entries: HashMap<(&'static str, u64), Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    warnings: Vec<LintWarning>,
+    generation: u64,
+}
+
+/// A top-level block of a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Block {
+    /// 1-indexed line the block starts at
+    first_line: usize,
+    /// Bytes of the block, including the blank lines that follow it
+    range: Range<usize>,
+    hash: u64,
+}
+
+impl BlockCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of remembered block results
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing is remembered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Check the document of `ctx` with each of `rules`, which must be block
+    /// local, checking only the blocks that changed since the last call
+    pub(crate) fn check(
+        &mut self,
+        rules: &[&dyn Rule],
+        ctx: &LintContext,
+        config: Option<&Config>,
+    ) -> HashMap<&'static str, LintResult> {
+        let settings = settings_fingerprint(config, ctx);
+        if settings != self.settings {
+            self.entries.clear();
+            self.settings = settings;
+        }
+        self.generation += 1;
+
+        let blocks = blocks(ctx);
+        let mut results: HashMap<&'static str, LintResult> =
+            rules.iter().map(|rule| (rule.name(), Ok(Vec::new()))).collect();
+
+        for block in &blocks {
+            let mut block_ctx = None;
+            for rule in rules {
+                let key = (rule.name(), block.hash);
+                let entry = match self.entries.get_mut(&key) {
+                    Some(entry) => entry,
+                    None => {
+                        let block_ctx = block_ctx.get_or_insert_with(|| {
+                            LintContext::new(&ctx.content[block.range.clone()], ctx.flavor, ctx.source_file.clone())
+                        });
+                        match rule.check(block_ctx) {
+                            Ok(warnings) => self.entries.entry(key).or_insert(Entry {
+                                warnings,
+                                generation: self.generation,
+                            }),
+                            Err(e) => {
+                                results.insert(rule.name(), Err(e));
+                                continue;
+                            }
+                        }
+                    }
+                };
+                entry.generation = self.generation;
+                if let Some(Ok(warnings)) = results.get_mut(rule.name()) {
+                    warnings.extend(entry.warnings.iter().map(|warning| shift(warning, block)));
+                }
+            }
+        }
+
+        let generation = self.generation;
+        self.entries.retain(|_, entry| entry.generation == generation);
+        results
+    }
+}
+
+/// Move a warning from its block to the document
+fn shift(warning: &LintWarning, block: &Block) -> LintWarning {
+    let mut warning = warning.clone();
+    warning.line += block.first_line - 1;
+    if warning.end_line > 0 {
+        warning.end_line += block.first_line - 1;
+    }
+    if let Some(fix) = warning.fix.as_mut() {
+        fix.range = fix.range.start + block.range.start..fix.range.end + block.range.start;
+        for edit in &mut fix.additional_edits {
+            edit.range = edit.range.start + block.range.start..edit.range.end + block.range.start;
+        }
+    }
+    warning
+}
+
+fn settings_fingerprint(config: Option<&Config>, ctx: &LintContext) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    config
+        .map(|config| serde_json::to_string(config).unwrap_or_default())
+        .hash(&mut hasher);
+    std::mem::discriminant(&ctx.flavor).hash(&mut hasher);
+    ctx.source_file.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Whether a line is inside a construct that may span blank lines
+fn in_container(info: &LineInfo) -> bool {
+    info.in_code_block
+        || info.in_front_matter
+        || info.in_html_block
+        || info.in_html_comment
+        || info.in_math_block
+        || info.in_esm_block
+        || info.in_jsx_expression
+        || info.in_jsx_block
+        || info.in_mdx_comment
+        || info.in_mkdocstrings
+        || info.in_pandoc_div
+        || info.in_admonition
+        || info.in_content_tab
+        || info.in_mkdocs_html_markdown
+        || info.in_definition_list
+        || info.in_obsidian_comment
+        || info.in_pymdown_block
+        || info.in_kramdown_extension_block
+        || info.in_footnote_definition
+        || info.in_myst_directive
+}
+
+/// Split a document into top-level blocks: a block starts at an unindented
+/// line after a blank line, unless the blank line is inside a container
+fn blocks(ctx: &LintContext) -> Vec<Block> {
+    let lines = &ctx.lines;
+    let mut starts = vec![0];
+    for (i, pair) in lines.windows(2).enumerate() {
+        let (prev, line) = (&pair[0], &pair[1]);
+        if prev.is_blank && !line.is_blank && line.indent == 0 && !in_container(prev) {
+            starts.push(i + 1);
+        }
+    }
+
+    let mut blocks = Vec::with_capacity(starts.len());
+    for (n, &start) in starts.iter().enumerate() {
+        let byte_start = lines.get(start).map_or(0, |line| line.byte_offset);
+        let byte_end = starts
+            .get(n + 1)
+            .map_or(ctx.content.len(), |&next| lines[next].byte_offset);
+        let mut hasher = DefaultHasher::new();
+        // Front matter is only recognized at the start of a document
+        (byte_start == 0).hash(&mut hasher);
+        ctx.content[byte_start..byte_end].hash(&mut hasher);
+        blocks.push(Block {
+            first_line: start + 1,
+            range: byte_start..byte_end,
+            hash: hasher.finish(),
+        });
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    #[test]
+    fn blocks_split_at_blank_lines_outside_containers() {
+        let content = "# Title\n\nText\nmore\n\n```\na\n\nb\n```\n\n- item\n\n  continued\n\nEnd";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let starts: Vec<_> = blocks(&ctx).iter().map(|block| block.first_line).collect();
+        assert_eq!(starts, vec![1, 3, 6, 12, 16]);
+    }
+
+    #[test]
+    fn unchanged_blocks_reuse_results_at_their_new_lines() {
+        let rules = crate::rules::all_rules(&Config::default());
+        let md009: Vec<&dyn Rule> = rules
+            .iter()
+            .filter(|rule| rule.name() == "MD009")
+            .map(AsRef::as_ref)
+            .collect();
+        let mut cache = BlockCache::new();
+
+        let before = "# Title\n\nTrailing   \n";
+        let ctx = LintContext::new(before, MarkdownFlavor::Standard, None);
+        let first = cache.check(&md009, &ctx, None).remove("MD009").unwrap().unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].line, 3);
+
+        let after = "# Title\n\nNew paragraph\n\nTrailing   \n";
+        let ctx = LintContext::new(after, MarkdownFlavor::Standard, None);
+        let second = cache.check(&md009, &ctx, None).remove("MD009").unwrap().unwrap();
+        assert_eq!(second, md009[0].check(&ctx).unwrap());
+        assert_eq!(second[0].line, 5);
+        // Entries for the three blocks of the current document only
+        assert_eq!(cache.len(), 3);
+    }
+}
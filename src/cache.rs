@@ -3,9 +3,14 @@
 //! Inspired by Ruff's caching implementation, this module provides fast caching
 //! of lint results to avoid re-checking unchanged files.
 //!
-//! Cache key: (file_content_hash, config_hash, rumdl_version)
+//! Cache key: (file_content_hash, config_hash, rules_hash, rumdl_version)
 //! Cache value: `Vec<LintWarning>`
 //! Storage: .rumdl_cache/{version}/{hash}.json
+//!
+//! `config_hash` covers the effective config of the file: the config of its
+//! group (including `[overrides]` layers) combined with the settings resolved
+//! per path, so editing any of them invalidates the entry. Rule
+//! implementations are covered by the rumdl version.
 
 use rumdl_lib::rule::LintWarning;
 use serde::{Deserialize, Serialize};
@@ -49,6 +54,7 @@ pub enum CacheMissReason {
     ConfigChanged,
     RulesChanged,
     VersionChanged { cached: String, current: &'static str },
+    TargetChanged { path: PathBuf },
 }
 
 impl std::fmt::Display for CacheMissReason {
@@ -68,6 +74,7 @@ impl std::fmt::Display for CacheMissReason {
             Self::VersionChanged { cached, current } => {
                 write!(f, "rumdl version changed from {cached} to {current}")
             }
+            Self::TargetChanged { path } => write!(f, "link target {} was created or removed", path.display()),
         }
     }
 }
@@ -92,6 +99,13 @@ impl CacheStats {
     }
 }
 
+/// Whether a linked file existed when its linking file was cached
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TargetState {
+    path: PathBuf,
+    exists: bool,
+}
+
 /// A cache entry stored on disk
 #[derive(Debug, Serialize, Deserialize)]
 struct CacheEntry {
@@ -105,6 +119,9 @@ struct CacheEntry {
     version: String,
     /// Cached lint warnings
     warnings: Vec<LintWarning>,
+    /// Link targets the warnings depend on (see `MD057ExistingRelativeLinks::target_paths`)
+    #[serde(default)]
+    targets: Vec<TargetState>,
     /// Timestamp when cached (Unix timestamp)
     timestamp: i64,
 }
@@ -183,13 +200,35 @@ impl LintCache {
         hash
     }
 
+    /// Combine a group's config hash with the settings that config resolves
+    /// for a single file (its flavor and the rules `per-file-ignores` turns
+    /// off), so files with the same content but different effective settings
+    /// never share an entry.
+    pub fn hash_file_config(
+        config_hash: &str,
+        flavor: rumdl_lib::config::MarkdownFlavor,
+        ignored_rules: &std::collections::HashSet<String>,
+    ) -> String {
+        let mut ignored: Vec<&str> = ignored_rules.iter().map(String::as_str).collect();
+        ignored.sort_unstable();
+        let key = format!("{config_hash}|{flavor}|{}", ignored.join(","));
+        blake3::hash(key.as_bytes()).to_hex().to_string()
+    }
+
     /// Compute hash of enabled rules (Ruff-style)
     /// This ensures different rule configurations get different cache entries
     pub fn hash_rules(rules: &[Box<dyn rumdl_lib::rule::Rule>]) -> String {
         #[cfg(feature = "profiling")]
         let start = std::time::Instant::now();
         // Sort rule names for deterministic hashing
-        let mut rule_names: Vec<&str> = rules.iter().map(|r| r.name()).collect();
+        // Rules loaded at runtime also hash their definition
+        let mut rule_names: Vec<String> = rules
+            .iter()
+            .map(|r| match r.definition_fingerprint() {
+                Some(fingerprint) => format!("{}:{fingerprint}", r.name()),
+                None => r.name().to_string(),
+            })
+            .collect();
         rule_names.sort_unstable();
 
         // Hash the sorted rule names
@@ -306,6 +345,16 @@ impl LintCache {
                 current: VERSION,
             });
         }
+        if let Some(target) = entry
+            .targets
+            .iter()
+            .find(|target| target.path.exists() != target.exists)
+        {
+            self.record_miss();
+            return Err(CacheMissReason::TargetChanged {
+                path: target.path.clone(),
+            });
+        }
 
         // Cache hit!
         self.record_hit();
@@ -316,11 +365,21 @@ impl LintCache {
     #[cfg(test)]
     pub fn set(&self, content: &str, config_hash: &str, rules_hash: &str, warnings: Vec<LintWarning>) {
         let file_hash = Self::hash_content(content);
-        self.set_with_hash(&file_hash, config_hash, rules_hash, warnings);
+        self.set_with_hash(&file_hash, config_hash, rules_hash, warnings, &[]);
     }
 
     /// Store lint results in cache using a precomputed file hash.
-    pub fn set_with_hash(&self, file_hash: &str, config_hash: &str, rules_hash: &str, warnings: Vec<LintWarning>) {
+    ///
+    /// `targets` are the linked paths the warnings depend on; the entry is
+    /// discarded once any of them is created or removed.
+    pub fn set_with_hash(
+        &self,
+        file_hash: &str,
+        config_hash: &str,
+        rules_hash: &str,
+        warnings: Vec<LintWarning>,
+        targets: &[PathBuf],
+    ) {
         if !self.enabled {
             return;
         }
@@ -339,6 +398,13 @@ impl LintCache {
             rules_hash: rules_hash.to_string(),
             version: VERSION.to_string(),
             warnings,
+            targets: targets
+                .iter()
+                .map(|path| TargetState {
+                    path: path.clone(),
+                    exists: path.exists(),
+                })
+                .collect(),
             timestamp: chrono::Utc::now().timestamp(),
         };
 
@@ -580,6 +646,62 @@ mod tests {
         assert_eq!(reason.to_string(), "configuration hash changed");
     }
 
+    #[test]
+    fn test_cache_miss_reason_target_changed() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = LintCache::new(temp_dir.path().join("cache"), true);
+        cache.init().unwrap();
+
+        let file_hash = LintCache::hash_content("![x](a.png)");
+        let config_hash = "abc123";
+        let rules_hash = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let target = temp_dir.path().join("a.png");
+        fs::write(&target, b"png").unwrap();
+
+        cache.set_with_hash(
+            &file_hash,
+            config_hash,
+            rules_hash,
+            vec![],
+            std::slice::from_ref(&target),
+        );
+        assert!(
+            cache
+                .get_with_reason_for_hash(&file_hash, config_hash, rules_hash)
+                .is_ok()
+        );
+
+        fs::remove_file(&target).unwrap();
+        let reason = cache
+            .get_with_reason_for_hash(&file_hash, config_hash, rules_hash)
+            .expect_err("removed target should miss");
+        assert_eq!(reason, CacheMissReason::TargetChanged { path: target });
+    }
+
+    #[test]
+    fn test_hash_file_config_covers_per_file_settings() {
+        use rumdl_lib::config::MarkdownFlavor;
+        use std::collections::HashSet;
+
+        let none = HashSet::new();
+        let md009: HashSet<String> = ["MD009".to_string()].into_iter().collect();
+        let base = LintCache::hash_file_config("abc123", MarkdownFlavor::Standard, &none);
+
+        assert_eq!(
+            base,
+            LintCache::hash_file_config("abc123", MarkdownFlavor::Standard, &none)
+        );
+        assert_ne!(
+            base,
+            LintCache::hash_file_config("def456", MarkdownFlavor::Standard, &none)
+        );
+        assert_ne!(base, LintCache::hash_file_config("abc123", MarkdownFlavor::MDX, &none));
+        assert_ne!(
+            base,
+            LintCache::hash_file_config("abc123", MarkdownFlavor::Standard, &md009)
+        );
+    }
+
     #[test]
     fn test_hash_content() {
         let content1 = "# Test";
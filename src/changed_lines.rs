@@ -0,0 +1,173 @@
+//! Lines changed since a git revision, for `check --diff-base`
+
+use rumdl_lib::rule::LintWarning;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Added or modified lines of every file changed since a base revision.
+///
+/// Files are keyed by canonical path. A file that is not in the map has no
+/// changed lines; untracked files count as changed throughout.
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Option<Vec<RangeInclusive<usize>>>>,
+}
+
+impl ChangedLines {
+    /// Compare the working tree of the repository containing `dir` against the
+    /// merge base of `base` and `HEAD`, so commits that landed on `base` after
+    /// the branch was created are not counted as changes.
+    pub fn from_git(base: &str, dir: &Path) -> Result<Self, String> {
+        let root = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?.trim_end());
+        let base = git(dir, &["merge-base", base, "HEAD"])
+            .map(|commit| commit.trim().to_string())
+            .or_else(|_| git(dir, &["rev-parse", "--verify", base]).map(|commit| commit.trim().to_string()))?;
+        let diff = git(
+            &root,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-renames",
+                &base,
+                "--",
+            ],
+        )?;
+        let untracked = git(
+            &root,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "ls-files",
+                "--others",
+                "--exclude-standard",
+            ],
+        )?;
+
+        let mut changed = Self::parse_diff(&root, &diff);
+        for file in untracked.lines().filter(|line| !line.is_empty()) {
+            changed.files.insert(canonical(&root.join(file)), None);
+        }
+        Ok(changed)
+    }
+
+    /// Collect the added line ranges of a `git diff --unified=0` listing whose
+    /// paths are relative to `root`
+    fn parse_diff(root: &Path, diff: &str) -> Self {
+        let mut files: HashMap<PathBuf, Option<Vec<RangeInclusive<usize>>>> = HashMap::new();
+        let mut current: Option<PathBuf> = None;
+        // Added lines may start with `+++ ` too, so only file headers count
+        let mut in_header = false;
+        for line in diff.lines() {
+            if line.starts_with("diff ") {
+                in_header = true;
+            } else if in_header && let Some(path) = line.strip_prefix("+++ ") {
+                current = path.strip_prefix("b/").map(|path| canonical(&root.join(path)));
+                if let Some(path) = &current {
+                    files.entry(path.clone()).or_insert_with(|| Some(Vec::new()));
+                }
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                in_header = false;
+                if let Some(path) = &current
+                    && let Some(range) = added_lines(hunk)
+                    && let Some(Some(ranges)) = files.get_mut(path)
+                {
+                    ranges.push(range);
+                }
+            }
+        }
+        Self { files }
+    }
+
+    /// Whether `path` has any changed lines
+    pub fn contains_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&canonical(path))
+    }
+
+    /// Keep only the warnings of `path` that touch a changed line
+    pub fn retain(&self, path: &Path, warnings: &mut Vec<LintWarning>) {
+        match self.files.get(&canonical(path)) {
+            None => warnings.clear(),
+            Some(None) => {}
+            Some(Some(ranges)) => warnings.retain(|w| {
+                let end = w.end_line.max(w.line);
+                ranges
+                    .iter()
+                    .any(|range| w.line <= *range.end() && *range.start() <= end)
+            }),
+        }
+    }
+}
+
+/// New-side line range of a hunk header (`-a,b +c,d @@ ...`); `None` for a
+/// hunk that only deletes lines
+fn added_lines(hunk: &str) -> Option<RangeInclusive<usize>> {
+    let new_side = hunk.split_whitespace().find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match new_side.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_side.parse::<usize>().ok()?, 1),
+    };
+    (count > 0).then(|| start..=start + count - 1)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Run git in `dir` and return its stdout
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_added_lines_from_hunk_headers() {
+        assert_eq!(added_lines("-3,0 +4,2 @@ heading"), Some(4..=5));
+        assert_eq!(added_lines("-3 +3 @@"), Some(3..=3));
+        assert_eq!(added_lines("-3,2 +2,0 @@"), None);
+    }
+
+    #[test]
+    fn test_parse_diff_collects_ranges_per_file() {
+        let root = Path::new("/repo");
+        let diff = "diff --git a/a.md b/a.md\n--- a/a.md\n+++ b/a.md\n@@ -3,0 +4 @@\n+new\n@@ -5,0 +6 @@\n+++ b/other.md\n@@ -9,2 +10,0 @@\n-x\n-y\ndiff --git a/gone.md b/gone.md\n--- a/gone.md\n+++ /dev/null\n@@ -1 +0,0 @@\n-gone\n";
+        let changed = ChangedLines::parse_diff(root, diff);
+        assert!(changed.contains_file(&root.join("a.md")));
+        assert!(!changed.contains_file(&root.join("gone.md")));
+        assert!(!changed.contains_file(&root.join("other.md")));
+
+        let warning = |line| LintWarning {
+            rule_name: Some("MD009".to_string()),
+            message: String::new(),
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 1,
+            severity: rumdl_lib::rule::Severity::Warning,
+            fix: None,
+        };
+        let mut warnings = vec![warning(3), warning(4), warning(6), warning(10)];
+        changed.retain(&root.join("a.md"), &mut warnings);
+        assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![4, 6]);
+    }
+}
@@ -3,10 +3,12 @@
 //! This module contains the main check run logic shared by both
 //! the `check` command and watch mode.
 
+use crate::cli_types::ProfileFormat;
 use crate::formatter;
 use colored::*;
 use rayon::prelude::*;
 use rumdl_lib::config as rumdl_config;
+use rumdl_lib::exit_codes::IssueCounts;
 use rumdl_lib::rule::CrossFileScope;
 use rumdl_lib::workspace_index::WorkspaceIndex;
 use std::collections::HashMap;
@@ -36,12 +38,10 @@ pub struct CheckRunContext<'a> {
 }
 
 /// Perform a single check run.
-/// Returns (has_issues, has_warnings, has_errors, total_issues_fixed):
-///   - has_issues: any violations (info, warning, or error)
-///   - has_warnings: any Warning or Error severity violations
-///   - has_errors: any Error-severity violations
+/// Returns (counts, total_issues_fixed):
+///   - counts: the violations that count towards the exit policy
 ///   - total_issues_fixed: number of issues fixed (or would be fixed in diff mode)
-pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize) {
+pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (IssueCounts, usize) {
     let CheckRunContext {
         args,
         config,
@@ -55,7 +55,6 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         isolated,
     } = *ctx;
     use rumdl_lib::output::OutputWriter;
-    use rumdl_lib::rule::Severity;
 
     // Create output writer for linting results
     let output_writer = OutputWriter::new(args.stderr, args.silent);
@@ -64,15 +63,28 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         Ok(fmt) => fmt,
         Err(e) => {
             eprintln!("{}: {}", "Error".red().bold(), e);
-            return (true, true, true, 0);
+            return (IssueCounts::ABORTED, 0);
         }
     };
+    let rule_names: rumdl_lib::output::RuleNameDisplay = args.rule_names.into();
+    let group_by: rumdl_lib::output::GroupBy = args.group_by.into();
+    let docs_links = rumdl_lib::output::DocsLinks::from_config(config);
 
     // Handle stdin input - either explicit --stdin flag or "-" as file argument
     if args.stdin || (args.paths.len() == 1 && args.paths[0] == "-") {
-        let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, config);
-        crate::stdin_processor::process_stdin(&enabled_rules, args, config);
-        return (false, false, false, 0);
+        // With --stdin-filename the content is linted as that path: its
+        // `[overrides]` layers and `per-file-ignores` apply as they would on disk
+        let Some(stdin_path) = args.stdin_filename.as_deref().map(std::path::Path::new) else {
+            let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, config);
+            crate::stdin_processor::process_stdin(&enabled_rules, args, config);
+            return (IssueCounts::default(), 0);
+        };
+        let stdin_config = config.with_overrides(&config.matching_overrides(stdin_path));
+        let ignored_rules = stdin_config.get_ignored_rules_for_file(stdin_path);
+        let mut enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, &stdin_config);
+        enabled_rules.retain(|rule| !ignored_rules.contains(rule.name()));
+        crate::stdin_processor::process_stdin(&enabled_rules, args, &stdin_config);
+        return (IssueCounts::default(), 0);
     }
 
     // Find all markdown files to check
@@ -85,18 +97,18 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
             if !args.silent {
                 eprintln!("{}: Failed to find markdown files: {}", "Error".red().bold(), e);
             }
-            return (true, true, true, 0);
+            return (IssueCounts::ABORTED, 0);
         }
     };
     if file_paths.is_empty() {
         if !quiet {
             println!("No markdown files found to check.");
         }
-        return (false, false, false, 0);
+        return (IssueCounts::default(), 0);
     }
 
     // Resolve files into config groups (per-directory config discovery)
-    let config_groups = rumdl_lib::time_function!(
+    let mut config_groups = rumdl_lib::time_function!(
         "check: resolve config groups",
         crate::resolution::resolve_config_groups(
             &file_paths,
@@ -112,6 +124,46 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         )
     );
 
+    // With --diff-base, only warnings on lines changed since that revision are reported
+    let changed_lines = match args.diff_base.as_deref() {
+        Some(base) => match crate::changed_lines::ChangedLines::from_git(base, Path::new(".")) {
+            Ok(changed) => Some(changed),
+            Err(e) => {
+                eprintln!("{}: --diff-base: {}", "Error".red().bold(), e);
+                return (IssueCounts::ABORTED, 0);
+            }
+        },
+        None => None,
+    };
+    // With `fail-on-new` in [ci], every file is linted and every warning
+    // reported, but only warnings on changed lines count towards failing
+    let (changed_lines, new_lines) = if config.ci.fail_on_new {
+        (None, changed_lines)
+    } else {
+        (changed_lines, None)
+    };
+
+    // With --shard, lint only this shard's files, and with --diff-base only
+    // the changed ones. The other files stay known (with their group) so the
+    // workspace index can still cover them.
+    let mut other_shard_files: Vec<(usize, String)> = Vec::new();
+    if args.shard.is_some() || changed_lines.is_some() {
+        for (gi, group) in config_groups.iter_mut().enumerate() {
+            let (shard_files, others): (Vec<_>, Vec<_>) = std::mem::take(&mut group.files).into_iter().partition(|f| {
+                args.shard.is_none_or(|shard| shard.contains(f))
+                    && changed_lines
+                        .as_ref()
+                        .is_none_or(|changed| changed.contains_file(Path::new(f)))
+            });
+            group.files = shard_files;
+            other_shard_files.extend(others.into_iter().map(|f| (gi, f)));
+        }
+    }
+
+    if args.explain_skip {
+        print_skipped_rules(&config_groups, project_root);
+    }
+
     // Build file → group index mapping for cross-file analysis (Phase 2)
     let file_group_map: HashMap<PathBuf, usize> = rumdl_lib::time_function!(
         "check: build file group map",
@@ -145,8 +197,14 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         None
     };
 
+    // Text grouped by rule or severity can only be printed once every file is
+    // checked. Diffs are printed per file, so they keep the per-file layout.
+    let grouped_text = output_format == rumdl_lib::output::OutputFormat::Text
+        && group_by != rumdl_lib::output::GroupBy::File
+        && !args.diff;
+
     // Batch output formats need to collect all warnings before formatting
-    let needs_collection = output_format.is_batch();
+    let needs_collection = output_format.is_batch() || grouped_text;
 
     // Some batch formats report passing files too and need every checked
     // file's path, not just the ones with warnings.
@@ -164,8 +222,12 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
     let start_time = Instant::now();
 
-    // Enable parallel processing for both check and fix modes when there are multiple files
-    let use_parallel = file_paths.len() > 1;
+    // Enable parallel processing for both check and fix modes when there are
+    // multiple files, unless fixes are reviewed one file at a time
+    let use_parallel = file_paths.len() > 1 && !args.interactive;
+    let interactive = args
+        .interactive
+        .then(|| std::sync::Mutex::new(crate::interactive_fix::InteractiveSession::stdio()));
 
     // Collect all warnings for statistics if requested
     let mut all_warnings_for_stats = Vec::new();
@@ -190,11 +252,12 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
     let mut batch_file_warnings: Vec<(String, Vec<rumdl_lib::rule::LintWarning>)> = Vec::new();
     // For JUnit, the display paths of every checked file (clean and dirty).
     let mut batch_all_files: Vec<String> = Vec::new();
+    // For `--fix --dry-run --output-format json`, the planned fixes of every checked file
+    let mut fix_plans: Vec<(String, formatter::FixPlan)> = Vec::new();
 
     let (
         mut has_issues,
-        mut has_warnings,
-        mut has_errors,
+        mut counts,
         mut files_with_issues,
         files_fixed,
         mut total_issues,
@@ -219,6 +282,9 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                         quiet,
                         args.silent,
                         &output_format,
+                        rule_names,
+                        args.show_docs_links,
+                        args.show_source,
                         effective_output_writer,
                         &group.config,
                         cache.as_ref().map(Arc::clone),
@@ -226,6 +292,9 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                         project_root,
                         args.show_full_path,
                         group.cache_hashes.as_deref(),
+                        changed_lines.as_ref(),
+                        args.report_unused_disables,
+                        None,
                     );
                     (file_path.to_string(), result)
                 })
@@ -234,8 +303,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
         // Aggregate results and extract FileIndex for cross-file analysis
         let mut has_issues = false;
-        let mut has_warnings = false;
-        let mut has_errors = false;
+        let mut counts = IssueCounts::default();
         let mut files_with_issues = 0;
         let mut files_fixed = 0;
         let mut total_issues = 0;
@@ -255,6 +323,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     warnings,
                     file_index,
                     file_index_reused,
+                    fix_plan,
                 } = result;
 
                 summary_issues_fixed += file_summary_issues_fixed;
@@ -274,16 +343,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     files_already_with_issues.insert(canonical.clone());
                 }
 
-                if warnings
-                    .iter()
-                    .any(|w| matches!(w.severity, Severity::Warning | Severity::Error))
-                {
-                    has_warnings = true;
-                }
-
-                if warnings.iter().any(|w| w.severity == Severity::Error) {
-                    has_errors = true;
-                }
+                count_issues(&mut counts, new_lines.as_ref(), Path::new(&file_path), &warnings);
 
                 // Collect warnings for batch output formats; for JUnit also record every
                 // checked file so passing files appear in the report.
@@ -298,6 +358,12 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     }
                 }
 
+                if let Some(plan) = fix_plan {
+                    let display_path =
+                        crate::file_processor::resolve_display_path(&file_path, args.show_full_path, project_root);
+                    fix_plans.push((display_path, plan));
+                }
+
                 if args.statistics {
                     all_warnings_for_stats.extend(warnings);
                 }
@@ -310,8 +376,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
         (
             has_issues,
-            has_warnings,
-            has_errors,
+            counts,
             files_with_issues,
             files_fixed,
             total_issues,
@@ -323,8 +388,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
     } else {
         // Sequential processing for single files or when fixing
         let mut has_issues = false;
-        let mut has_warnings = false;
-        let mut has_errors = false;
+        let mut counts = IssueCounts::default();
         let mut files_with_issues = 0;
         let mut files_fixed = 0;
         let mut total_issues = 0;
@@ -345,6 +409,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     warnings,
                     file_index,
                     file_index_reused,
+                    fix_plan,
                 } = crate::file_processor::process_file_with_formatter(
                     file_path,
                     &group.rules,
@@ -354,6 +419,9 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     quiet,
                     args.silent,
                     &output_format,
+                    rule_names,
+                    args.show_docs_links,
+                    args.show_source,
                     effective_output_writer,
                     &group.config,
                     cache.as_ref().map(Arc::clone),
@@ -361,6 +429,9 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     project_root,
                     args.show_full_path,
                     group.cache_hashes.as_deref(),
+                    changed_lines.as_ref(),
+                    args.report_unused_disables,
+                    interactive.as_ref(),
                 );
 
                 if needs_cross_file {
@@ -385,16 +456,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     files_already_with_issues.insert(canonical);
                 }
 
-                if warnings
-                    .iter()
-                    .any(|w| matches!(w.severity, Severity::Warning | Severity::Error))
-                {
-                    has_warnings = true;
-                }
-
-                if warnings.iter().any(|w| w.severity == Severity::Error) {
-                    has_errors = true;
-                }
+                count_issues(&mut counts, new_lines.as_ref(), Path::new(&file_path), &warnings);
 
                 // Collect warnings for batch output formats; for JUnit also record every
                 // checked file so passing files appear in the report.
@@ -409,6 +471,12 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     }
                 }
 
+                if let Some(plan) = fix_plan {
+                    let display_path =
+                        crate::file_processor::resolve_display_path(file_path, args.show_full_path, project_root);
+                    fix_plans.push((display_path, plan));
+                }
+
                 if args.statistics {
                     all_warnings_for_stats.extend(warnings);
                 }
@@ -417,8 +485,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
         (
             has_issues,
-            has_warnings,
-            has_errors,
+            counts,
             files_with_issues,
             files_fixed,
             total_issues,
@@ -461,6 +528,37 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
             }
         });
 
+        // Files of other shards are not linted here, but links into them are
+        // checked here, so index them without running rules
+        if !other_shard_files.is_empty() {
+            let other_indices: Vec<_> = rumdl_lib::time_function!(
+                "workspace: index files of other shards",
+                other_shard_files
+                    .par_iter()
+                    .filter_map(|(gi, file)| {
+                        let content = crate::read_file_efficiently(Path::new(file)).ok()?;
+                        let canonical = std::fs::canonicalize(file).unwrap_or_else(|_| PathBuf::from(file));
+                        // Files unchanged since the cached index was saved keep their entry
+                        let content_hash = crate::cache::LintCache::hash_content(&content);
+                        if !workspace_index.is_file_stale(&canonical, &content_hash) {
+                            return None;
+                        }
+                        let group = &config_groups[*gi];
+                        let file_index = rumdl_lib::build_file_index_only(
+                            &content,
+                            &group.rules,
+                            group.config.get_flavor_for_file(Path::new(file)),
+                            Some(PathBuf::from(file)),
+                        );
+                        Some((canonical, file_index))
+                    })
+                    .collect()
+            );
+            for (path, file_index) in other_indices {
+                workspace_index.update_file(&path, file_index);
+            }
+        }
+
         // Prune deleted files from workspace index (use canonical paths for matching)
         let current_files: std::collections::HashSet<PathBuf> = rumdl_lib::time_function!(
             "workspace: canonicalize current files",
@@ -486,7 +584,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         }
 
         // Run cross-file checks using per-file config group rules
-        let formatter = output_format.create_formatter();
+        let mut late_reports: Vec<(PathBuf, Vec<rumdl_lib::rule::LintWarning>)> = Vec::new();
         rumdl_lib::time_section!("workspace: run cross-file checks", {
             // Iterate in path order so cross-file diagnostics are emitted in a
             // stable order across runs (the workspace index is a HashMap).
@@ -501,59 +599,96 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
                     rumdl_lib::run_cross_file_checks(file_path, file_index, cf_rules, &workspace_index, Some(cf_config))
                     && !cross_file_warnings.is_empty()
                 {
-                    has_issues = true;
-                    if !files_already_with_issues.contains(file_path) {
-                        files_with_issues += 1;
-                    }
-                    total_issues += cross_file_warnings.len();
+                    late_reports.push((file_path.to_path_buf(), cross_file_warnings));
+                }
+            }
+        });
 
-                    if cross_file_warnings
-                        .iter()
-                        .any(|w| matches!(w.severity, Severity::Warning | Severity::Error))
-                    {
-                        has_warnings = true;
+        // Workspace-level checks report on paths that need not be linted files
+        // (e.g. unreferenced images). Each rule runs once, with the settings of
+        // the first group that enables it.
+        rumdl_lib::time_section!("workspace: run workspace checks", {
+            let mut seen_workspace_rules = std::collections::HashSet::new();
+            // With --shard only the first shard runs them, so they report once
+            let run_workspace_checks = args.shard.is_none_or(|shard| shard.is_aggregate());
+            for group in config_groups.iter().filter(|_| run_workspace_checks) {
+                let workspace_rules: Vec<Box<dyn rumdl_lib::rule::Rule>> = group
+                    .rules
+                    .iter()
+                    .filter(|rule| {
+                        rule.cross_file_scope() == CrossFileScope::Workspace && seen_workspace_rules.insert(rule.name())
+                    })
+                    .cloned()
+                    .collect();
+                if workspace_rules.is_empty() {
+                    continue;
+                }
+                match rumdl_lib::run_workspace_checks(&workspace_rules, &workspace_index, Some(&group.config)) {
+                    Ok(reports) => late_reports.extend(reports.into_iter().filter(|(_, w)| !w.is_empty())),
+                    Err(e) => {
+                        if !args.silent {
+                            eprintln!("{}: Workspace check failed: {}", "Error".red().bold(), e);
+                        }
                     }
+                }
+            }
+        });
 
-                    if cross_file_warnings.iter().any(|w| w.severity == Severity::Error) {
-                        has_errors = true;
-                    }
+        if let Some(changed) = &changed_lines {
+            for (file_path, warnings) in &mut late_reports {
+                changed.retain(file_path, warnings);
+            }
+            late_reports.retain(|(_, warnings)| !warnings.is_empty());
+        }
 
-                    let display_path = crate::file_processor::resolve_display_path(
-                        &file_path.to_string_lossy(),
-                        args.show_full_path,
-                        project_root,
-                    );
+        let mut formatter = output_format.formatter_with_source(&docs_links, args.show_docs_links, args.show_source);
+        for (file_path, cross_file_warnings) in late_reports {
+            has_issues = true;
+            // Paths reported by workspace checks that were not linted (such as
+            // images) are not part of the checked-file count
+            if file_group_map.contains_key(&file_path) && !files_already_with_issues.contains(&file_path) {
+                files_with_issues += 1;
+            }
+            total_issues += cross_file_warnings.len();
 
-                    if needs_collection {
-                        // Collect cross-file warnings for batch output
-                        if let Some((_, warnings)) = batch_file_warnings.iter_mut().find(|(p, _)| p == &display_path) {
-                            warnings.extend(cross_file_warnings.clone());
-                        } else {
-                            batch_file_warnings.push((display_path, cross_file_warnings.clone()));
-                        }
-                    } else {
-                        // Stream cross-file warnings immediately
-                        if !args.silent {
-                            let file_content = std::fs::read_to_string(file_path).unwrap_or_default();
-                            let formatted = formatter.format_warnings_with_content(
-                                &cross_file_warnings,
-                                &display_path,
-                                &file_content,
-                            );
-                            if !formatted.is_empty() {
-                                output_writer.writeln(&formatted).unwrap_or_else(|e| {
-                                    eprintln!("Error writing output: {e}");
-                                });
-                            }
-                        }
-                    }
+            count_issues(&mut counts, new_lines.as_ref(), &file_path, &cross_file_warnings);
+
+            let display_path = crate::file_processor::resolve_display_path(
+                &file_path.to_string_lossy(),
+                args.show_full_path,
+                project_root,
+            );
 
-                    if args.statistics {
-                        all_warnings_for_stats.extend(cross_file_warnings);
+            if let Some((_, plan)) = fix_plans.iter_mut().find(|(p, _)| p == &display_path) {
+                plan.remaining
+                    .extend(rule_names.apply(&cross_file_warnings).into_owned());
+            }
+
+            if needs_collection {
+                // Collect cross-file warnings for batch output
+                if let Some((_, warnings)) = batch_file_warnings.iter_mut().find(|(p, _)| p == &display_path) {
+                    warnings.extend(cross_file_warnings.clone());
+                } else {
+                    batch_file_warnings.push((display_path, cross_file_warnings.clone()));
+                }
+            } else {
+                // Stream cross-file warnings immediately
+                if !args.silent {
+                    let file_content = std::fs::read_to_string(&file_path).unwrap_or_default();
+                    let formatted =
+                        formatter.file(&display_path, &rule_names.apply(&cross_file_warnings), &file_content);
+                    if !formatted.is_empty() {
+                        output_writer.writeln(&formatted).unwrap_or_else(|e| {
+                            eprintln!("Error writing output: {e}");
+                        });
                     }
                 }
             }
-        });
+
+            if args.statistics {
+                all_warnings_for_stats.extend(cross_file_warnings);
+            }
+        }
 
         // Save workspace index to cache
         if let Some(cache_dir) = workspace_cache_dir {
@@ -570,11 +705,35 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         }
     }
 
-    // Emit batch output for collection formats
-    if let Some(output) = output_format.format_batch(
-        &batch_file_warnings,
+    // Emit batch output for collection formats. With --statistics, JSON output
+    // is the per-rule summary instead of the individual warnings.
+    if args.statistics && output_format == rumdl_lib::output::OutputFormat::Json {
+        output_writer
+            .writeln(&formatter::format_statistics_json(&all_warnings_for_stats))
+            .unwrap_or_else(|e| {
+                eprintln!("Error writing output: {e}");
+            });
+    } else if args.fix && args.diff && output_format == rumdl_lib::output::OutputFormat::Json {
+        // Only files with planned edits or remaining warnings are listed
+        fix_plans.retain(|(_, plan)| !plan.edits.is_empty() || !plan.remaining.is_empty());
+        output_writer
+            .writeln(&formatter::format_fix_plan_json(&fix_plans))
+            .unwrap_or_else(|e| {
+                eprintln!("Error writing output: {e}");
+            });
+    } else if grouped_text {
+        if !batch_file_warnings.is_empty() {
+            let output = rumdl_lib::output::TextFormatter::new()
+                .format_report(&rule_names.apply_batch(&batch_file_warnings), group_by);
+            output_writer.writeln(&output).unwrap_or_else(|e| {
+                eprintln!("Error writing output: {e}");
+            });
+        }
+    } else if let Some(output) = output_format.format_batch(
+        &rule_names.apply_batch(&batch_file_warnings),
         &batch_all_files,
         start_time.elapsed().as_millis() as u64,
+        &docs_links,
     ) {
         output_writer.writeln(&output).unwrap_or_else(|e| {
             eprintln!("Error writing output: {e}");
@@ -586,7 +745,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
     // Print results summary if not in quiet or silent mode
     // Skip for batch formats to keep stdout as pure structured output
-    if !quiet && !args.silent && !needs_collection && !output_format.is_machine_readable() {
+    if !quiet && !args.silent && !output_format.is_batch() && !output_format.is_machine_readable() {
         formatter::print_results_from_checkargs(formatter::PrintResultsArgs {
             args,
             has_issues,
@@ -605,7 +764,7 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
     if args.statistics
         && !quiet
         && !args.silent
-        && !needs_collection
+        && !output_format.is_batch()
         && !output_format.is_machine_readable()
         && !all_warnings_for_stats.is_empty()
     {
@@ -614,13 +773,20 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
 
     // Print profiling information when explicitly requested. This intentionally
     // ignores --silent because --profile is itself an explicit output request.
-    if args.profile && !quiet {
-        match std::panic::catch_unwind(rumdl_lib::profiling::get_report) {
+    if let Some(profile_format) = args.profile
+        && !quiet
+    {
+        let get_report = || match profile_format {
+            ProfileFormat::Text => format!("\n{}", rumdl_lib::profiling::get_report()),
+            ProfileFormat::Json => rumdl_lib::profiling::get_json_report(),
+            ProfileFormat::Folded => rumdl_lib::profiling::get_folded_report(),
+        };
+        match std::panic::catch_unwind(get_report) {
             Ok(report) => {
                 if args.stderr {
-                    eprintln!("\n{report}");
+                    eprintln!("{report}");
                 } else {
-                    println!("\n{report}");
+                    println!("{report}");
                 }
             }
             Err(_) => {
@@ -633,5 +799,61 @@ pub fn perform_check_run(ctx: &CheckRunContext<'_>) -> (bool, bool, bool, usize)
         }
     }
 
-    (has_issues, has_warnings, has_errors, total_issues_fixed)
+    (counts, total_issues_fixed)
+}
+
+/// Count a file's warnings towards the exit policy; with `new_lines`, only
+/// those on changed lines count
+fn count_issues(
+    counts: &mut IssueCounts,
+    new_lines: Option<&crate::changed_lines::ChangedLines>,
+    path: &Path,
+    warnings: &[rumdl_lib::rule::LintWarning],
+) {
+    match new_lines {
+        Some(changed) => {
+            let mut new_warnings = warnings.to_vec();
+            changed.retain(path, &mut new_warnings);
+            counts.add(&new_warnings);
+        }
+        None => counts.add(warnings),
+    }
+}
+
+/// Print the rules each file skips and why (`--explain-skip`), on stderr so
+/// machine-readable output stays clean
+fn print_skipped_rules(config_groups: &[crate::resolution::ConfigGroup], project_root: Option<&Path>) {
+    let mut lines: Vec<String> = Vec::new();
+    for group in config_groups {
+        for file in &group.files {
+            let ignored = group.config.get_ignored_rules_for_file(Path::new(file));
+            let mut skipped: Vec<String> = group
+                .skipped_rules
+                .iter()
+                .map(|(rule, reason)| format!("{rule} ({reason})"))
+                .collect();
+            skipped.extend(
+                group
+                    .rules
+                    .iter()
+                    .map(|rule| rule.name())
+                    .filter(|name| ignored.contains(*name))
+                    .map(|name| format!("{name} (per-file-ignores)")),
+            );
+            if !skipped.is_empty() {
+                let display_path = crate::file_processor::to_display_path(file, project_root);
+                lines.push(format!("  {display_path}: {}", skipped.join(", ")));
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        eprintln!("No rules skipped");
+    } else {
+        lines.sort();
+        eprintln!("Skipped rules:");
+        for line in lines {
+            eprintln!("{line}");
+        }
+    }
 }
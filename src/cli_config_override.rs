@@ -218,6 +218,37 @@ pub fn apply_inline_overrides(sourced: &mut SourcedConfig, overrides: &[toml::Ta
     }
 }
 
+/// Resolve `--rule-profile NAME` against the config's `[profiles]` section.
+///
+/// The profile table is applied like an inline `--config` snippet, ahead of
+/// the user's own `--config` overrides so those still win. Returns the
+/// combined override list, or an error naming the available profiles.
+pub fn with_rule_profile(
+    sourced: &SourcedConfig,
+    profile: Option<&str>,
+    inline_overrides: &[toml::Table],
+) -> Result<Vec<toml::Table>, String> {
+    let Some(name) = profile else {
+        return Ok(inline_overrides.to_vec());
+    };
+    let profiles = &sourced.profiles.value;
+    let Some(table) = profiles.get(name) else {
+        let available = if profiles.is_empty() {
+            "no profiles are defined in [profiles]".to_string()
+        } else {
+            format!(
+                "available: {}",
+                profiles.keys().map(String::as_str).collect::<Vec<_>>().join(", ")
+            )
+        };
+        return Err(format!("Unknown rule profile '{name}' ({available})"));
+    };
+    let mut combined = Vec::with_capacity(inline_overrides.len() + 1);
+    combined.push(table.clone());
+    combined.extend_from_slice(inline_overrides);
+    Ok(combined)
+}
+
 fn apply_top_level_entry(
     sourced: &mut SourcedConfig,
     top_key: &str,
@@ -10,6 +10,69 @@ pub enum FixMode {
     Format,
 }
 
+/// One of `count` deterministic partitions of the discovered files (`--shard K/N`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    /// 1-based shard number
+    pub index: u64,
+    pub count: u64,
+}
+
+impl Shard {
+    /// Whether `path` belongs to this shard. The assignment depends only on the
+    /// path as discovered, so every CI job computes the same partition.
+    pub fn contains(&self, path: &str) -> bool {
+        let normalized = path.replace('\\', "/");
+        let normalized = normalized.strip_prefix("./").unwrap_or(&normalized);
+        let hash = blake3::hash(normalized.as_bytes());
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&hash.as_bytes()[..8]);
+        u64::from_le_bytes(bytes) % self.count == self.index - 1
+    }
+
+    /// The shard that runs workspace-level checks, which report once per run
+    pub fn is_aggregate(&self) -> bool {
+        self.index == 1
+    }
+}
+
+impl std::str::FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid shard '{s}': expected K/N with 1 <= K <= N, e.g. 2/4");
+        let (index, count) = s.split_once('/').ok_or_else(invalid)?;
+        let index: u64 = index.trim().parse().map_err(|_| invalid())?;
+        let count: u64 = count.trim().parse().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(invalid());
+        }
+        Ok(Self { index, count })
+    }
+}
+
+/// 1-indexed, inclusive range of lines to format (`--range START:END`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl std::str::FromStr for LineRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid range '{s}': expected START:END with 1 <= START <= END, e.g. 10:40");
+        let (start, end) = s.split_once(':').ok_or_else(invalid)?;
+        let start: usize = start.trim().parse().map_err(|_| invalid())?;
+        let end: usize = end.trim().parse().map_err(|_| invalid())?;
+        if start == 0 || start > end {
+            return Err(invalid());
+        }
+        Ok(Self { start, end })
+    }
+}
+
 /// Fail-on mode determines which severity triggers exit code 1
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
 pub enum FailOn {
@@ -24,6 +87,17 @@ pub enum FailOn {
     Never,
 }
 
+impl From<FailOn> for rumdl_lib::config::FailOnLevel {
+    fn from(fail_on: FailOn) -> Self {
+        match fail_on {
+            FailOn::Any => Self::Any,
+            FailOn::Warning => Self::Warning,
+            FailOn::Error => Self::Error,
+            FailOn::Never => Self::Never,
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct SharedCliArgs {
     /// Disable specific rules (comma-separated)
@@ -47,6 +121,14 @@ pub struct SharedCliArgs {
     #[arg(long, help = "Extend the list of disabled rules (additive with config)")]
     pub extend_disable: Option<String>,
 
+    /// Apply a named profile from the config's `[profiles]` section
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Apply a named rule profile from the config's [profiles] section"
+    )]
+    pub rule_profile: Option<String>,
+
     /// Only allow these rules to be fixed (comma-separated)
     #[arg(long, help = "Only allow these rules to be fixed (comma-separated)")]
     pub fixable: Option<String>,
@@ -55,6 +137,13 @@ pub struct SharedCliArgs {
     #[arg(long, help = "Prevent these rules from being fixed (comma-separated)")]
     pub unfixable: Option<String>,
 
+    /// Also apply fixes that may change what the document says
+    #[arg(
+        long,
+        help = "Also apply fixes that may change what the document says, such as heading capitalization or reflow"
+    )]
+    pub unsafe_fixes: bool,
+
     /// Exclude specific files or directories (comma-separated glob patterns)
     #[arg(long, help = "Exclude specific files or directories (comma-separated glob patterns)")]
     pub exclude: Option<String>,
@@ -88,10 +177,42 @@ pub struct SharedCliArgs {
     #[arg(long, help = "Show absolute file paths in output instead of relative paths")]
     pub show_full_path: bool,
 
-    /// Filename to use for stdin input (for context and error messages)
+    /// Filename to lint stdin as: drives config discovery, per-file settings, and error messages
     #[arg(long, help = "Filename to use when reading from stdin (e.g., README.md)")]
     pub stdin_filename: Option<String>,
 
+    /// Name rules in diagnostics by ID or by markdownlint alias
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "Name rules in diagnostics by ID (MD013) or markdownlint alias (line-length)"
+    )]
+    pub rule_names: RuleNames,
+
+    /// Follow each file's diagnostics with links to the rules' documentation
+    #[arg(
+        long,
+        help = "Show links to rule documentation after each file's diagnostics (honors docs-base-url and explain-url)"
+    )]
+    pub show_docs_links: bool,
+
+    /// Follow each text diagnostic with the offending source lines
+    #[arg(
+        long,
+        help = "Show the source line of each warning with the span underlined and surrounding context (text format)"
+    )]
+    pub show_source: bool,
+
+    /// Arrange text output by file, rule or severity
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "Arrange text output by file (default), by rule with counts and positions per file, or by severity"
+    )]
+    pub group_by: GroupBy,
+
     /// Output diagnostics to stderr instead of stdout
     #[arg(long, help = "Output diagnostics to stderr instead of stdout")]
     pub stderr: bool,
@@ -118,6 +239,15 @@ pub struct CheckArgs {
     #[arg(short, long, default_value = "false")]
     pub fix: bool,
 
+    /// Review each fix and apply only the accepted ones
+    #[arg(
+        long,
+        requires = "fix",
+        conflicts_with_all = ["diff", "watch", "stdin"],
+        help = "Review each fix as a diff and apply only the accepted ones (with --fix)"
+    )]
+    pub interactive: bool,
+
     /// Show diff of what would be fixed instead of fixing files
     #[arg(
         long,
@@ -146,9 +276,16 @@ pub struct CheckArgs {
     #[arg(short, long, help = "Show detailed output")]
     pub verbose: bool,
 
-    /// Show profiling information
-    #[arg(long, help = "Show profiling information")]
-    pub profile: bool,
+    /// Show profiling information: text (default), json, or folded
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        help = "Show profiling information: text (default), json, or folded stacks for flamegraph tools"
+    )]
+    pub profile: Option<ProfileFormat>,
 
     /// Show statistics summary of rule violations
     #[arg(long, help = "Show statistics summary of rule violations")]
@@ -195,16 +332,62 @@ pub struct CheckArgs {
     #[arg(
         long,
         value_enum,
-        default_value_t,
-        help = "Exit code behavior: 'any' (default) exits 1 on any violation, 'warning' on warning+error, 'error' only on errors, 'never' always exits 0"
+        help = "Exit code behavior: 'any' (default) exits 1 on any violation, 'warning' on warning+error, 'error' only on errors, 'never' always exits 0; overrides `fail-on` in [ci]"
     )]
-    pub fail_on: FailOn,
+    pub fail_on: Option<FailOn>,
+
+    /// Tolerate up to N violations of the --fail-on severity before exiting 1
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Exit 1 only when more than N violations of the --fail-on severity are found; overrides `max-warnings` in [ci]"
+    )]
+    pub max_warnings: Option<usize>,
+
+    /// Lint only shard K of N, for splitting a run across CI jobs
+    #[arg(
+        long,
+        value_name = "K/N",
+        help = "Lint only shard K of N (e.g. 2/4); files are assigned to shards by a hash of their path"
+    )]
+    pub shard: Option<Shard>,
+
+    /// Report only warnings on lines changed since this git revision
+    #[arg(
+        long,
+        value_name = "REF",
+        conflicts_with = "fix",
+        help = "Report only warnings on lines added or changed since REF (e.g. origin/main), using git"
+    )]
+    pub diff_base: Option<String>,
 
+    /// Report inline disable comments that suppress nothing
+    #[arg(
+        long,
+        help = "Report inline disable comments that suppress no warnings; with --fix, remove them"
+    )]
+    pub report_unused_disables: bool,
+
+    /// Lines to format from stdin; set from `fmt --range`
     #[arg(skip)]
-    pub fix_mode: FixMode,
+    pub range: Option<LineRange>,
+
+    /// List the rules each file skips and why
+    #[arg(
+        long,
+        help = "List the rules each file skips (per-file-ignores, [overrides], draft-disable) and why, on stderr"
+    )]
+    pub explain_skip: bool,
+
+    /// Run the external linters configured for code blocks
+    #[arg(
+        long,
+        help = "Pipe fenced code blocks to the linters configured under [code-linters] or [code-block-tools], as if `enabled = true` were set"
+    )]
+    pub lint_code_blocks: bool,
 
     #[arg(skip)]
-    pub fail_on_mode: FailOn,
+    pub fix_mode: FixMode,
 }
 
 #[derive(Args, Debug)]
@@ -238,8 +421,15 @@ pub struct FmtArgs {
     pub verbose: bool,
 
     /// Hidden compatibility flag from check
-    #[arg(long, hide = true)]
-    pub profile: bool,
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        hide = true
+    )]
+    pub profile: Option<ProfileFormat>,
 
     /// Hidden compatibility flag from check
     #[arg(long, hide = true)]
@@ -275,6 +465,14 @@ pub struct FmtArgs {
     )]
     pub silent: bool,
 
+    /// Format only this range of lines from stdin, for editors formatting a selection
+    #[arg(
+        long,
+        value_name = "START:END",
+        help = "Format only lines START to END (1-indexed, inclusive) of stdin; the rest is passed through unchanged"
+    )]
+    pub range: Option<LineRange>,
+
     /// Re-run formatting whenever files change
     #[arg(short, long, help = "Re-run formatting whenever files change")]
     pub watch: bool,
@@ -296,6 +494,7 @@ impl From<FmtArgs> for CheckArgs {
             // The flag is intentionally `false` so the check-dispatch path does not
             // independently enable `FixMode::CheckFix`.
             fix: false,
+            interactive: false,
             diff: args.diff,
             check: args.check,
             list_rules: args.list_rules,
@@ -310,9 +509,15 @@ impl From<FmtArgs> for CheckArgs {
             silent: args.silent,
             watch: args.watch,
             force_exclude: args.force_exclude,
-            fail_on: args.fail_on,
+            fail_on: Some(args.fail_on),
+            max_warnings: None,
+            shard: None,
+            diff_base: None,
+            report_unused_disables: false,
+            range: args.range,
+            explain_skip: false,
+            lint_code_blocks: false,
             fix_mode: FixMode::default(),
-            fail_on_mode: FailOn::default(),
         }
     }
 }
@@ -376,10 +581,15 @@ pub enum OutputFormat {
     Pylint,
     /// Azure Pipelines logging commands
     Azure,
+    /// TeamCity inspection service messages
+    #[value(name = "teamcity")]
+    TeamCity,
     /// SARIF 2.1.0 for static analysis tools
     Sarif,
     /// JUnit XML for CI test reporters
     Junit,
+    /// Standalone HTML report for CI artifacts
+    Html,
 }
 
 impl From<OutputFormat> for rumdl_lib::output::OutputFormat {
@@ -395,12 +605,64 @@ impl From<OutputFormat> for rumdl_lib::output::OutputFormat {
             OutputFormat::GitLab => Self::GitLab,
             OutputFormat::Pylint => Self::Pylint,
             OutputFormat::Azure => Self::Azure,
+            OutputFormat::TeamCity => Self::TeamCity,
             OutputFormat::Sarif => Self::Sarif,
             OutputFormat::Junit => Self::Junit,
+            OutputFormat::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum RuleNames {
+    /// Rule IDs such as MD013 (default)
+    #[default]
+    Id,
+    /// markdownlint aliases such as line-length
+    Alias,
+}
+
+impl From<RuleNames> for rumdl_lib::output::RuleNameDisplay {
+    fn from(names: RuleNames) -> Self {
+        match names {
+            RuleNames::Id => Self::Id,
+            RuleNames::Alias => Self::Alias,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum GroupBy {
+    /// Each file's warnings in turn (default)
+    #[default]
+    File,
+    /// One section per rule, most frequent first
+    Rule,
+    /// One section per severity, errors first
+    Severity,
+}
+
+impl From<GroupBy> for rumdl_lib::output::GroupBy {
+    fn from(group_by: GroupBy) -> Self {
+        match group_by {
+            GroupBy::File => Self::File,
+            GroupBy::Rule => Self::Rule,
+            GroupBy::Severity => Self::Severity,
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ProfileFormat {
+    /// Table of timed sections (default)
+    #[default]
+    Text,
+    /// Section, rule and file timings plus peak memory as JSON
+    Json,
+    /// Per-file rule timings as folded stacks for flamegraph tools
+    Folded,
+}
+
 #[derive(Clone, Copy, Debug, ValueEnum)]
 #[value(rename_all = "lower")]
 pub enum Flavor {
@@ -19,6 +19,12 @@ pub fn apply_cli_overrides(sourced: &mut rumdl_config::SourcedConfig, args: &Che
         sourced.global.flavor = rumdl_config::SourcedValue::new(flavor.into(), rumdl_config::ConfigSource::Cli);
     }
 
+    // Apply --lint-code-blocks: run the configured code block tools for this invocation
+    if args.lint_code_blocks {
+        sourced.code_block_tools.value.enabled = true;
+        sourced.code_block_tools.source = rumdl_config::ConfigSource::Cli;
+    }
+
     // Apply --respect-gitignore override if provided
     // This allows CLI to override config file setting
     if let Some(respect_gitignore) = args.respect_gitignore {
@@ -45,6 +51,11 @@ pub fn apply_cli_overrides(sourced: &mut rumdl_config::SourcedConfig, args: &Che
             .collect();
         sourced.global.unfixable = rumdl_config::SourcedValue::new(rules, rumdl_config::ConfigSource::Cli);
     }
+
+    // Apply --unsafe-fixes if provided
+    if args.unsafe_fixes {
+        sourced.global.unsafe_fixes = rumdl_config::SourcedValue::new(true, rumdl_config::ConfigSource::Cli);
+    }
 }
 
 /// Resolve the lint output format with the standard precedence:
@@ -79,6 +90,15 @@ pub fn resolve_output_format(
     rumdl_lib::output::OutputFormat::from_str(output_format_str).map_err(|e| e.to_string())
 }
 
+/// Resolve the exit policy: `--fail-on` and `--max-warnings` take precedence
+/// over the `[ci]` config section, each on its own.
+pub fn resolve_exit_policy(args: &CheckArgs, config: &rumdl_config::Config) -> rumdl_lib::exit_codes::ExitPolicy {
+    rumdl_lib::exit_codes::ExitPolicy {
+        fail_on: args.fail_on.map(Into::into).or(config.ci.fail_on).unwrap_or_default(),
+        max_warnings: args.max_warnings.or(config.ci.max_warnings),
+    }
+}
+
 /// Read file content as a UTF-8 string.
 pub fn read_file_efficiently(path: &Path) -> Result<String, Box<dyn Error>> {
     fs::read_to_string(path).map_err(|e| format!("Failed to read file {}: {}", path.display(), e).into())
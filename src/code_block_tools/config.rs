@@ -193,6 +193,66 @@ impl Default for ToolDefinition {
     }
 }
 
+/// A linter listed for a language under `[code-linters]`.
+///
+/// ```toml
+/// [code-linters]
+/// python = ["ruff:check"]
+/// shell = [["shellcheck", "--shell=bash", "-"]]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum CodeLinter {
+    /// ID of a built-in tool or one defined under `[code-block-tools.tools]`
+    Tool(String),
+    /// Command run with the block on stdin (first element is the binary)
+    Command(Vec<String>),
+}
+
+impl CodeBlockToolsConfig {
+    /// Add the `[code-linters]` entries as lint tools of their languages.
+    ///
+    /// Inline commands are registered as tools with the ID `<language>:<binary>`
+    /// (numbered when a language has several commands for one binary), which
+    /// is also the name their diagnostics are reported under.
+    pub fn add_code_linters(&mut self, linters: BTreeMap<String, Vec<CodeLinter>>) {
+        for (language, entries) in linters {
+            for entry in entries {
+                let tool_id = match entry {
+                    CodeLinter::Tool(id) => id,
+                    CodeLinter::Command(command) => self.register_inline_command(&language, command),
+                };
+                let lint = &mut self.languages.entry(language.clone()).or_default().lint;
+                if !lint.contains(&tool_id) {
+                    lint.push(tool_id);
+                }
+            }
+        }
+    }
+
+    fn register_inline_command(&mut self, language: &str, command: Vec<String>) -> String {
+        let binary = command.first().map_or("command", String::as_str);
+        let base = format!("{language}:{binary}");
+        let mut id = base.clone();
+        let mut n = 1;
+        while let Some(existing) = self.tools.get(&id) {
+            if existing.command == command {
+                return id;
+            }
+            n += 1;
+            id = format!("{base}:{n}");
+        }
+        self.tools.insert(
+            id.clone(),
+            ToolDefinition {
+                command,
+                ..ToolDefinition::default()
+            },
+        );
+        id
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,4 +472,37 @@ enabled = false
         let toml = toml::to_string_pretty(&config).expect("Failed to serialize");
         assert!(toml.contains("enabled = false"));
     }
+
+    #[test]
+    fn test_add_code_linters() {
+        let toml = r#"
+python = ["ruff:check"]
+shell = ["shellcheck", ["shellcheck", "--shell=bash", "-"], ["shellcheck", "--shell=sh", "-"]]
+"#;
+        let linters: BTreeMap<String, Vec<CodeLinter>> = toml::from_str(toml).expect("Failed to parse TOML");
+
+        let mut config = CodeBlockToolsConfig::default();
+        config.languages.insert(
+            "python".to_string(),
+            LanguageToolConfig {
+                lint: vec!["ruff:check".to_string()],
+                format: vec!["ruff:format".to_string()],
+                ..Default::default()
+            },
+        );
+        config.add_code_linters(linters);
+
+        let python = config.languages.get("python").expect("Missing python config");
+        assert_eq!(python.lint, vec!["ruff:check"]);
+        assert_eq!(python.format, vec!["ruff:format"]);
+
+        let shell = config.languages.get("shell").expect("Missing shell config");
+        assert_eq!(shell.lint, vec!["shellcheck", "shell:shellcheck", "shell:shellcheck:2"]);
+        let tool = config.tools.get("shell:shellcheck").expect("Missing inline tool");
+        assert_eq!(tool.command, vec!["shellcheck", "--shell=bash", "-"]);
+        let tool = config.tools.get("shell:shellcheck:2").expect("Missing inline tool");
+        assert_eq!(tool.command, vec!["shellcheck", "--shell=sh", "-"]);
+        assert!(tool.stdin);
+        assert!(!config.enabled);
+    }
 }
@@ -33,6 +33,16 @@
 //! bash = "shell"
 //! ```
 //!
+//! Lint tools can also be listed per language under `[code-linters]`, either
+//! as tool IDs or as inline commands; `rumdl check --lint-code-blocks` runs
+//! them without setting `enabled`:
+//!
+//! ```toml
+//! [code-linters]
+//! python = ["ruff:check"]
+//! shell = [["shellcheck", "--shell=bash", "-"]]
+//! ```
+//!
 //! # Built-in Tools
 //!
 //! Common tools are pre-configured:
@@ -69,7 +79,9 @@ pub mod linguist;
 pub mod processor;
 pub mod registry;
 
-pub use config::{CodeBlockToolsConfig, LanguageToolConfig, NormalizeLanguage, OnError, OnMissing, ToolDefinition};
+pub use config::{
+    CodeBlockToolsConfig, CodeLinter, LanguageToolConfig, NormalizeLanguage, OnError, OnMissing, ToolDefinition,
+};
 pub use executor::{ExecutorError, ToolExecutor, ToolOutput};
 pub use linguist::LinguistResolver;
 pub use processor::{
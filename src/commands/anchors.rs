@@ -0,0 +1,91 @@
+//! Handler for the `anchors` command.
+
+use clap::Parser;
+use colored::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rumdl_lib::anchor_manifest::AnchorManifest;
+use rumdl_lib::config as rumdl_config;
+use rumdl_lib::exit_codes::exit;
+use rumdl_lib::lint_context::LintContext;
+use rumdl_lib::rules::MD101StableAnchors;
+use rumdl_lib::utils::project_root::discover_project_root_from;
+
+use crate::CheckArgs;
+use crate::file_processor;
+
+/// `CheckArgs` with clap defaults, for reusing the `check` file discovery
+#[derive(Parser)]
+struct DefaultCheckArgs {
+    #[command(flatten)]
+    args: CheckArgs,
+}
+
+/// Handle the anchors command: print the anchor manifest, or write it for MD101.
+pub fn handle_anchors(write: bool, config_path: Option<&str>, no_config: bool, inline_overrides: &[toml::Table]) {
+    let mut sourced = match rumdl_config::SourcedConfig::load_with_discovery(config_path, None, no_config) {
+        Ok(s) => s,
+        Err(e) => fail(&format!("Config error: {e}")),
+    };
+    crate::cli_config_override::apply_inline_overrides(&mut sourced, inline_overrides);
+    let config: rumdl_config::Config = sourced.into_validated_unchecked().into();
+
+    let cwd = match std::env::current_dir() {
+        Ok(dir) => dir,
+        Err(e) => fail(&format!("Failed to read the current directory: {e}")),
+    };
+    let root = config
+        .project_root
+        .clone()
+        .unwrap_or_else(|| discover_project_root_from(&cwd));
+    let root = root.canonicalize().unwrap_or(root);
+
+    // The manifest covers the whole project, wherever the command runs from
+    if let Err(e) = std::env::set_current_dir(&root) {
+        fail(&format!("Failed to enter '{}': {e}", root.display()));
+    }
+    let args = DefaultCheckArgs::parse_from(["check", "--silent"]).args;
+    let files = match file_processor::find_markdown_files(&[], &args, &config, Some(&root)) {
+        Ok(files) => files,
+        Err(e) => fail(&format!("Failed to find Markdown files: {e}")),
+    };
+
+    let rule = MD101StableAnchors::for_config(&config);
+    let mut manifest = AnchorManifest::new();
+    let mut anchor_count = 0;
+    for file in &files {
+        let path = root.join(file);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => fail(&format!("Failed to read '{file}': {e}")),
+        };
+        let ctx = LintContext::new(&content, config.get_flavor_for_file(&path), Some(path.clone()));
+        let anchors: Vec<String> = rule.anchors(&ctx).into_iter().map(|heading| heading.anchor).collect();
+        anchor_count += anchors.len();
+        manifest.insert(&relative(&path, &root), anchors);
+    }
+
+    if !write {
+        print!("{}", manifest.to_json());
+        return;
+    }
+    let manifest_path = rule.manifest_path(&root);
+    if let Err(e) = manifest.save(&manifest_path) {
+        fail(&format!("Failed to write '{}': {e}", manifest_path.display()));
+    }
+    println!(
+        "Recorded {anchor_count} anchor(s) from {} file(s) in {}",
+        manifest.len(),
+        relative(&manifest_path, &root).display()
+    );
+}
+
+fn relative(path: &Path, root: &Path) -> PathBuf {
+    path.strip_prefix(root).unwrap_or(path).to_path_buf()
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}: {message}", "Error".red().bold());
+    exit::tool_error();
+}
@@ -6,7 +6,7 @@ use rumdl_lib::config as rumdl_config;
 use rumdl_lib::exit_codes::exit;
 
 use crate::cli_utils::{apply_cli_overrides, load_config_with_cli_error_handling_with_dir};
-use crate::{CheckArgs, FailOn, FixMode};
+use crate::{CheckArgs, FixMode};
 
 /// Run the check/lint/fmt command.
 pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool, inline_overrides: &[toml::Table]) {
@@ -26,10 +26,13 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
         exit::tool_error();
     }
 
-    // Validate mutually exclusive options
-    if args.diff && args.fix {
+    // Validate mutually exclusive options. With JSON output, `--fix --dry-run`
+    // reports the planned edits per file instead of applying them.
+    let plans_fixes = matches!(args.output_format, Some(crate::cli_types::OutputFormat::Json));
+    if args.diff && args.fix && !plans_fixes {
         eprintln!("{}: --diff and --fix cannot be used together", "Error".red().bold());
         eprintln!("Use --diff to preview changes, or --fix to apply them");
+        eprintln!("To get the planned fixes as JSON, add --output-format json");
         exit::tool_error();
     }
 
@@ -39,6 +42,15 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
         exit::tool_error();
     }
 
+    if args.interactive && args.paths.iter().any(|path| path == "-") {
+        eprintln!(
+            "{}: --interactive cannot be used with stdin input",
+            "Error".red().bold()
+        );
+        eprintln!("Answers to the prompts are read from stdin");
+        exit::tool_error();
+    }
+
     // Warn about deprecated --force-exclude flag
     if args.force_exclude {
         eprintln!(
@@ -74,13 +86,24 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
     // Per-file grouping still layers each file's own nearest config on top.
     //
     // Zero paths (lint the cwd recursively) keeps the cwd-based discovery.
+    //
+    // Stdin with `--stdin-filename` discovers config next to that file, as if
+    // it were on disk, so an editor piping a buffer gets the file's own config.
+    let stdin_filename = args
+        .stdin_filename
+        .as_deref()
+        .filter(|_| args.stdin || (args.paths.len() == 1 && args.paths[0] == "-"));
     let multi_path_root = if args.paths.len() > 1 {
         common_ancestor_dir(&args.paths)
     } else {
         None
     };
 
-    let discovery_dir = if args.paths.len() == 1 {
+    let discovery_dir = if let Some(stdin_filename) = stdin_filename {
+        std::path::Path::new(stdin_filename)
+            .parent()
+            .filter(|&parent| parent.is_dir())
+    } else if args.paths.len() == 1 {
         let first_path = std::path::Path::new(&args.paths[0]);
         if first_path.is_dir() {
             Some(first_path)
@@ -94,6 +117,10 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
     // 2. Load sourced config (for provenance and validation)
     let mut sourced = load_config_with_cli_error_handling_with_dir(global_config_path, isolated, discovery_dir);
 
+    // 2a. Resolve `--rule-profile` into an extra inline override. The combined
+    // list also reaches per-directory configs through `CheckRunContext`.
+    let inline_overrides = &resolve_rule_profile(&sourced, args, inline_overrides);
+
     // 2b. Apply inline `--config 'RULE.key=value'` overrides at CLI precedence
     // (highest), so they win over both file-loaded values and any later CLI
     // arg overrides that touch top-level globals.
@@ -160,6 +187,13 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
     // Validation warnings are already printed above, so we use into_validated_unchecked
     let config: rumdl_config::Config = sourced.into_validated_unchecked().into();
 
+    // 5b. In verbose mode, point out settings that conflict or have no effect
+    if args.verbose && !args.silent {
+        for warn in rumdl_config::config_conflicts(&config) {
+            eprintln!("\x1b[33m[config warning]\x1b[0m {}", warn.message);
+        }
+    }
+
     // 6. Initialize cache if enabled
     // CLI --no-cache flag takes precedence over config
     let cache_enabled = !args.no_cache && config.global.cache;
@@ -214,20 +248,15 @@ pub fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: b
         isolated,
     };
 
-    let (has_issues, has_warnings, has_errors, total_issues_fixed) = crate::check_runner::perform_check_run(&ctx);
+    let (counts, total_issues_fixed) = crate::check_runner::perform_check_run(&ctx);
 
     // In --check mode (for fmt), exit with code 1 if any formatting changes would be made
     if args.check && total_issues_fixed > 0 {
         exit::violations_found();
     }
 
-    // Determine if we should fail based on --fail-on setting
-    let should_fail = match args.fail_on_mode {
-        FailOn::Never => false,
-        FailOn::Error => has_errors,
-        FailOn::Warning => has_warnings,
-        FailOn::Any => has_issues,
-    };
+    // Determine if we should fail based on --fail-on/--max-warnings or [ci]
+    let should_fail = crate::cli_utils::resolve_exit_policy(args, &config).should_fail(&counts);
 
     if should_fail && args.fix_mode != FixMode::Format {
         exit::violations_found();
@@ -289,3 +318,19 @@ fn common_ancestor_dir(paths: &[String]) -> Option<std::path::PathBuf> {
     }
     Some(result)
 }
+
+/// Combine the `--rule-profile` table (if any) with the inline `--config`
+/// overrides, exiting with a tool error when the profile is not defined.
+pub(crate) fn resolve_rule_profile(
+    sourced: &rumdl_config::SourcedConfig,
+    args: &CheckArgs,
+    inline_overrides: &[toml::Table],
+) -> Vec<toml::Table> {
+    match crate::cli_config_override::with_rule_profile(sourced, args.rule_profile.as_deref(), inline_overrides) {
+        Ok(overrides) => overrides,
+        Err(message) => {
+            eprintln!("{}: {message}", "Error".red().bold());
+            exit::tool_error();
+        }
+    }
+}
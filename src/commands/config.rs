@@ -38,6 +38,8 @@ pub fn handle_config(
         handle_config_get(&key, config_path, no_config, inline_overrides);
     } else if let Some(ConfigSubcommand::File) = subcmd {
         handle_config_file(config_path, no_config, isolated);
+    } else if let Some(ConfigSubcommand::Lint) = subcmd {
+        handle_config_lint(config_path, no_config, isolated, inline_overrides);
     } else {
         // No subcommand: display full config
         handle_config_display(
@@ -115,6 +117,39 @@ fn handle_config_get(key: &str, config_path: Option<&str>, no_config: bool, inli
                     ),
                     sourced.global.include.source,
                 )),
+                "extensions" => Some((
+                    toml::Value::Array(
+                        final_config
+                            .global
+                            .extensions
+                            .iter()
+                            .map(|s| toml::Value::String(s.clone()))
+                            .collect(),
+                    ),
+                    sourced.global.extensions.source,
+                )),
+                "extract-from" | "extract_from" => Some((
+                    toml::Value::Array(
+                        final_config
+                            .global
+                            .extract_from
+                            .iter()
+                            .map(|s| toml::Value::String(s.clone()))
+                            .collect(),
+                    ),
+                    sourced.global.extract_from.source,
+                )),
+                "html-markdown-patterns" | "html_markdown_patterns" => Some((
+                    toml::Value::Array(
+                        final_config
+                            .global
+                            .html_markdown_patterns
+                            .iter()
+                            .map(|s| toml::Value::String(s.clone()))
+                            .collect(),
+                    ),
+                    sourced.global.html_markdown_patterns.source,
+                )),
                 "respect-gitignore" => Some((
                     toml::Value::Boolean(final_config.global.respect_gitignore),
                     sourced.global.respect_gitignore.source,
@@ -273,6 +308,58 @@ fn handle_config_file(config_path: Option<&str>, no_config: bool, isolated: bool
     }
 }
 
+/// Validate the loaded config. Unknown rules, unknown options, type
+/// mismatches and load failures are errors; deprecated settings and shadowed
+/// config files are warnings. Exits non-zero when there are errors.
+fn handle_config_lint(config_path: Option<&str>, no_config: bool, isolated: bool, inline_overrides: &[toml::Table]) {
+    let mut sourced = match rumdl_config::SourcedConfig::load_with_discovery(config_path, None, no_config || isolated) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}: {}", "error".red().bold(), e);
+            exit::violations_found();
+        }
+    };
+    crate::cli_config_override::apply_inline_overrides(&mut sourced, inline_overrides);
+
+    let registry = rumdl_config::default_registry();
+    let errors = rumdl_config::validate_config_sourced(&sourced, registry);
+    let mut warnings: Vec<String> = sourced.discovery_warnings.clone();
+    warnings.extend(
+        rumdl_config::deprecated_config_usages(&sourced, registry)
+            .into_iter()
+            .map(|warning| warning.message),
+    );
+    let config: rumdl_config::Config = sourced.clone().into_validated_unchecked().into();
+    warnings.extend(
+        rumdl_config::config_conflicts(&config)
+            .into_iter()
+            .map(|warning| warning.message),
+    );
+
+    for error in &errors {
+        println!("{}: {}", "error".red().bold(), error.message);
+    }
+    for warning in &warnings {
+        println!("{}: {}", "warning".yellow().bold(), warning);
+    }
+
+    let files = match sourced.loaded_files.len() {
+        0 => "No configuration file found".to_string(),
+        1 => "Checked 1 config file".to_string(),
+        n => format!("Checked {n} config files"),
+    };
+    let plural = |n: usize, word: &str| format!("{n} {word}{}", if n == 1 { "" } else { "s" });
+    println!(
+        "{files}: {}, {}",
+        plural(errors.len(), "error"),
+        plural(warnings.len(), "warning")
+    );
+
+    if !errors.is_empty() {
+        exit::violations_found();
+    }
+}
+
 fn handle_config_display(
     defaults: bool,
     no_defaults: bool,
@@ -444,6 +531,15 @@ fn filter_sourced_config_to_non_defaults(
     if sourced.global.include.source != rumdl_config::ConfigSource::Default {
         filtered.global.include = sourced.global.include.clone();
     }
+    if sourced.global.extensions.source != rumdl_config::ConfigSource::Default {
+        filtered.global.extensions = sourced.global.extensions.clone();
+    }
+    if sourced.global.extract_from.source != rumdl_config::ConfigSource::Default {
+        filtered.global.extract_from = sourced.global.extract_from.clone();
+    }
+    if sourced.global.html_markdown_patterns.source != rumdl_config::ConfigSource::Default {
+        filtered.global.html_markdown_patterns = sourced.global.html_markdown_patterns.clone();
+    }
     if sourced.global.respect_gitignore.source != rumdl_config::ConfigSource::Default {
         filtered.global.respect_gitignore = sourced.global.respect_gitignore.clone();
     }
@@ -459,6 +555,43 @@ fn filter_sourced_config_to_non_defaults(
     if sourced.global.cache.source != rumdl_config::ConfigSource::Default {
         filtered.global.cache = sourced.global.cache.clone();
     }
+    if sourced.global.nested_configs.source != rumdl_config::ConfigSource::Default {
+        filtered.global.nested_configs = sourced.global.nested_configs.clone();
+    }
+    if sourced.global.fix_max_iterations.source != rumdl_config::ConfigSource::Default {
+        filtered.global.fix_max_iterations = sourced.global.fix_max_iterations.clone();
+    }
+    if sourced.global.unsafe_fixes.source != rumdl_config::ConfigSource::Default {
+        filtered.global.unsafe_fixes = sourced.global.unsafe_fixes.clone();
+    }
+    if let Some(ref rule_timeout_ms) = sourced.global.rule_timeout_ms
+        && rule_timeout_ms.source != rumdl_config::ConfigSource::Default
+    {
+        filtered.global.rule_timeout_ms = Some(rule_timeout_ms.clone());
+    }
+    if let Some(ref max_file_size) = sourced.global.max_file_size
+        && max_file_size.source != rumdl_config::ConfigSource::Default
+    {
+        filtered.global.max_file_size = Some(max_file_size.clone());
+    }
+    if sourced.global.large_file_mode.source != rumdl_config::ConfigSource::Default {
+        filtered.global.large_file_mode = sourced.global.large_file_mode.clone();
+    }
+    if sourced.global.length_mode.source != rumdl_config::ConfigSource::Default {
+        filtered.global.length_mode = sourced.global.length_mode.clone();
+    }
+    if sourced.global.ambiguous_width.source != rumdl_config::ConfigSource::Default {
+        filtered.global.ambiguous_width = sourced.global.ambiguous_width.clone();
+    }
+    if sourced.global.draft_key.source != rumdl_config::ConfigSource::Default {
+        filtered.global.draft_key = sourced.global.draft_key.clone();
+    }
+    if sourced.global.draft_value.source != rumdl_config::ConfigSource::Default {
+        filtered.global.draft_value = sourced.global.draft_value.clone();
+    }
+    if sourced.global.draft_disable.source != rumdl_config::ConfigSource::Default {
+        filtered.global.draft_disable = sourced.global.draft_disable.clone();
+    }
     if sourced.global.fixable.source != rumdl_config::ConfigSource::Default {
         filtered.global.fixable = sourced.global.fixable.clone();
     }
@@ -475,11 +608,27 @@ fn filter_sourced_config_to_non_defaults(
     {
         filtered.global.cache_dir = Some(cache_dir.clone());
     }
+    if let Some(ref docs_base_url) = sourced.global.docs_base_url
+        && docs_base_url.source != rumdl_config::ConfigSource::Default
+    {
+        filtered.global.docs_base_url = Some(docs_base_url.clone());
+    }
+    if let Some(ref slugify) = sourced.global.slugify
+        && slugify.source != rumdl_config::ConfigSource::Default
+    {
+        filtered.global.slugify = Some(slugify.clone());
+    }
 
     // Filter per-file ignores
     if sourced.per_file_ignores.source != rumdl_config::ConfigSource::Default {
         filtered.per_file_ignores = sourced.per_file_ignores.clone();
     }
+    if sourced.overrides.source != rumdl_config::ConfigSource::Default {
+        filtered.overrides = sourced.overrides.clone();
+    }
+    if sourced.profiles.source != rumdl_config::ConfigSource::Default {
+        filtered.profiles = sourced.profiles.clone();
+    }
 
     // Filter rules - only include rules with at least one non-default value
     for (rule_name, rule_cfg) in &sourced.rules {
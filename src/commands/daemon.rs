@@ -0,0 +1,601 @@
+//! Handler for the `daemon` command.
+//!
+//! The daemon keeps one project's configuration, rules and workspace index
+//! loaded between runs and lints files on request over a unix socket, so
+//! pre-commit hooks and editor integrations that cannot speak LSP skip the
+//! startup work of a fresh `rumdl check`.
+
+use colored::*;
+
+use rumdl_lib::exit_codes::exit;
+
+use crate::DaemonAction;
+
+/// Handle the daemon subcommand (start, run, stop, status, or check).
+pub fn handle_daemon(
+    action: DaemonAction,
+    config_path: Option<&str>,
+    isolated: bool,
+    inline_overrides: &[toml::Table],
+) {
+    if !inline_overrides.is_empty() {
+        eprintln!(
+            "{}: inline --config overrides are not supported by the daemon; put them in a config file",
+            "Error".red().bold()
+        );
+        exit::tool_error();
+    }
+
+    #[cfg(unix)]
+    unix::handle(action, config_path, isolated);
+
+    #[cfg(not(unix))]
+    {
+        let _ = (action, config_path, isolated);
+        eprintln!("{}: rumdl daemon is only supported on Unix", "Error".red().bold());
+        exit::tool_error();
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::*;
+
+    use clap::Parser;
+    use rumdl_lib::config as rumdl_config;
+    use rumdl_lib::rule::{LintWarning, Rule};
+    use rumdl_lib::workspace_index::WorkspaceIndex;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{HashMap, HashSet};
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::os::unix::process::CommandExt;
+    use std::path::{Path, PathBuf};
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant, SystemTime};
+
+    use crate::CheckArgs;
+    use crate::file_processor;
+
+    /// How long `start` waits for a spawned daemon to accept connections
+    const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// How long the daemon waits on a client to send its request or take
+    /// the reply, so a stalled client cannot block everyone behind it
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// How long a client waits for the daemon's reply, which includes
+    /// indexing the project on the first request
+    const REPLY_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// A request sent to the daemon, one JSON object per line
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "request", rename_all = "kebab-case")]
+    enum Request {
+        Check { paths: Vec<PathBuf> },
+        Status,
+        Stop,
+    }
+
+    /// The daemon's reply to a [`Request`], one JSON object per line
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "response", rename_all = "kebab-case")]
+    enum Response {
+        Checked {
+            files: Vec<FileReport>,
+            files_processed: usize,
+        },
+        Status {
+            pid: u32,
+            root: PathBuf,
+            config_files: Vec<PathBuf>,
+            indexed_files: usize,
+            requests: u64,
+            uptime_secs: u64,
+        },
+        Stopped,
+        Error {
+            message: String,
+        },
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct FileReport {
+        path: PathBuf,
+        warnings: Vec<LintWarning>,
+    }
+
+    /// `CheckArgs` with clap defaults, for reusing the `check` file discovery
+    #[derive(Parser)]
+    struct DefaultCheckArgs {
+        #[command(flatten)]
+        args: CheckArgs,
+    }
+
+    fn check_args(paths: &[String]) -> CheckArgs {
+        let argv = ["check", "--silent", "--"].into_iter().map(String::from);
+        DefaultCheckArgs::parse_from(argv.chain(paths.iter().cloned())).args
+    }
+
+    pub(super) fn handle(action: DaemonAction, config_path: Option<&str>, isolated: bool) {
+        let root = project_root();
+        let socket = match socket_path(&root) {
+            Ok(socket) => socket,
+            Err(e) => {
+                eprintln!("{}: {}", "Error".red().bold(), e);
+                exit::tool_error();
+            }
+        };
+        // The daemon runs from the project root, so a relative config path
+        // must be resolved against the caller's directory first
+        let config_path = config_path.map(|path| canonical(Path::new(path)).to_string_lossy().into_owned());
+        let config_path = config_path.as_deref();
+
+        match action {
+            DaemonAction::Run => run(&root, &socket, config_path, isolated),
+            DaemonAction::Start => {
+                if is_running(&socket) {
+                    println!("rumdl daemon is already running for {}", root.display());
+                    return;
+                }
+                if let Err(e) = start(&socket, config_path, isolated) {
+                    eprintln!("{}: {}", "Error".red().bold(), e);
+                    exit::tool_error();
+                }
+                println!("Started rumdl daemon for {}", root.display());
+            }
+            DaemonAction::Stop => match send(&socket, &Request::Stop) {
+                Ok(_) => println!("Stopped rumdl daemon for {}", root.display()),
+                Err(_) => println!("No rumdl daemon is running for {}", root.display()),
+            },
+            DaemonAction::Status => match send(&socket, &Request::Status) {
+                Ok(Response::Status {
+                    pid,
+                    root,
+                    config_files,
+                    indexed_files,
+                    requests,
+                    uptime_secs,
+                }) => {
+                    println!("rumdl daemon is running for {}", root.display());
+                    println!("  pid:             {pid}");
+                    println!("  uptime:          {uptime_secs}s");
+                    println!("  requests served: {requests}");
+                    println!("  indexed files:   {indexed_files}");
+                    if config_files.is_empty() {
+                        println!("  config:          defaults");
+                    }
+                    for file in config_files {
+                        println!("  config:          {}", file.display());
+                    }
+                }
+                Ok(response) => unexpected(response),
+                Err(_) => {
+                    println!("No rumdl daemon is running for {}", root.display());
+                    std::process::exit(rumdl_lib::exit_codes::VIOLATIONS_FOUND);
+                }
+            },
+            DaemonAction::Check { paths } => check(&socket, &paths, config_path, isolated),
+        }
+    }
+
+    /// The project a daemon serves: the enclosing git work tree, or the
+    /// current directory outside of one
+    fn project_root() -> PathBuf {
+        let cwd = canonical(Path::new("."));
+        cwd.ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .map_or_else(|| cwd.clone(), Path::to_path_buf)
+    }
+
+    /// One socket per project root, so daemons of different projects coexist
+    fn socket_path(root: &Path) -> Result<PathBuf, String> {
+        let hash = blake3::hash(root.as_os_str().as_encoded_bytes()).to_hex();
+        Ok(socket_dir()?.join(format!("rumdl-{}.sock", &hash[..16])))
+    }
+
+    /// `$XDG_RUNTIME_DIR`, or else a directory in the temp directory that
+    /// only the current user can enter, so other users can neither connect
+    /// to a daemon nor plant a socket in its place
+    fn socket_dir() -> Result<PathBuf, String> {
+        if let Some(dir) = std::env::var_os("XDG_RUNTIME_DIR").filter(|dir| !dir.is_empty()) {
+            return Ok(PathBuf::from(dir));
+        }
+        // SAFETY: getuid has no preconditions and always succeeds
+        let uid = unsafe { libc::getuid() };
+        let dir = std::env::temp_dir().join(format!("rumdl-{uid}"));
+        match std::fs::DirBuilder::new().mode(0o700).create(&dir) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(format!("failed to create {}: {}", dir.display(), e)),
+        }
+        let metadata =
+            std::fs::symlink_metadata(&dir).map_err(|e| format!("failed to read {}: {}", dir.display(), e))?;
+        if !metadata.is_dir() || metadata.uid() != uid || metadata.mode() & 0o077 != 0 {
+            return Err(format!(
+                "{} must be a directory owned by the current user with mode 0700",
+                dir.display()
+            ));
+        }
+        Ok(dir)
+    }
+
+    fn canonical(path: &Path) -> PathBuf {
+        std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    fn is_running(socket: &Path) -> bool {
+        UnixStream::connect(socket).is_ok()
+    }
+
+    fn send(socket: &Path, request: &Request) -> std::io::Result<Response> {
+        let mut stream = UnixStream::connect(socket)?;
+        stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        stream.write_all(line.as_bytes())?;
+        let mut reply = String::new();
+        BufReader::new(stream).read_line(&mut reply)?;
+        Ok(serde_json::from_str(&reply)?)
+    }
+
+    fn unexpected(response: Response) -> ! {
+        let message = match response {
+            Response::Error { message } => message,
+            _ => "unexpected response from rumdl daemon".to_string(),
+        };
+        eprintln!("{}: {}", "Error".red().bold(), message);
+        exit::tool_error();
+    }
+
+    /// Spawn `rumdl daemon run` detached from the terminal and wait until it
+    /// accepts connections
+    fn start(socket: &Path, config_path: Option<&str>, isolated: bool) -> Result<(), String> {
+        let exe = std::env::current_exe().map_err(|e| format!("failed to locate rumdl executable: {e}"))?;
+        let mut command = Command::new(exe);
+        if let Some(path) = config_path {
+            command.args(["--config", path]);
+        }
+        if isolated {
+            command.arg("--no-config");
+        }
+        let mut child = command
+            .args(["daemon", "run"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            // A separate process group keeps Ctrl-C in the terminal from
+            // reaching the daemon
+            .process_group(0)
+            .spawn()
+            .map_err(|e| format!("failed to start rumdl daemon: {e}"))?;
+
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+        while Instant::now() < deadline {
+            if is_running(socket) {
+                return Ok(());
+            }
+            if let Ok(Some(status)) = child.try_wait() {
+                return Err(format!(
+                    "rumdl daemon exited during startup ({status}); run `rumdl daemon run` to see why"
+                ));
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        Err("timed out waiting for rumdl daemon to start".to_string())
+    }
+
+    /// Lint `paths` through the daemon, starting one first if none is running
+    fn check(socket: &Path, paths: &[String], config_path: Option<&str>, isolated: bool) {
+        let start_time = Instant::now();
+        if !is_running(socket)
+            && let Err(e) = start(socket, config_path, isolated)
+        {
+            eprintln!("{}: {}", "Error".red().bold(), e);
+            exit::tool_error();
+        }
+
+        let cwd = canonical(Path::new("."));
+        let paths = if paths.is_empty() {
+            vec![cwd]
+        } else {
+            paths.iter().map(|path| cwd.join(path)).collect()
+        };
+        let (files, files_processed) = match send(socket, &Request::Check { paths }) {
+            Ok(Response::Checked { files, files_processed }) => (files, files_processed),
+            Ok(response) => unexpected(response),
+            Err(e) => {
+                eprintln!("{}: failed to talk to rumdl daemon: {}", "Error".red().bold(), e);
+                exit::tool_error();
+            }
+        };
+
+        use rumdl_lib::output::OutputFormatter;
+        let formatter = rumdl_lib::output::formatters::TextFormatter::new();
+        for report in &files {
+            let display_path = file_processor::to_display_path(&report.path.to_string_lossy(), None);
+            println!("{}", formatter.format_warnings(&report.warnings, &display_path));
+        }
+
+        let total_issues = files.iter().map(|report| report.warnings.len()).sum();
+        let total_fixable_issues = files
+            .iter()
+            .flat_map(|report| &report.warnings)
+            .filter(|warning| warning.automatic_fix().is_some())
+            .count();
+        let args = check_args(&[]);
+        crate::formatter::print_results_from_checkargs(crate::formatter::PrintResultsArgs {
+            args: &args,
+            has_issues: total_issues > 0,
+            files_with_issues: files.len(),
+            files_fixed: 0,
+            total_issues,
+            summary_issues_fixed: 0,
+            total_issues_fixed: 0,
+            total_fixable_issues,
+            total_files_processed: files_processed,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        });
+
+        if total_issues > 0 {
+            exit::violations_found();
+        }
+    }
+
+    /// Serve requests in the foreground until a `stop` request arrives
+    fn run(root: &Path, socket: &Path, config_path: Option<&str>, isolated: bool) {
+        if is_running(socket) {
+            eprintln!(
+                "{}: rumdl daemon is already running for {}",
+                "Error".red().bold(),
+                root.display()
+            );
+            exit::tool_error();
+        }
+        if let Err(e) = std::env::set_current_dir(root) {
+            eprintln!("{}: failed to enter {}: {}", "Error".red().bold(), root.display(), e);
+            exit::tool_error();
+        }
+        let mut daemon = match Daemon::load(root, config_path, isolated) {
+            Ok(daemon) => daemon,
+            Err(e) => {
+                eprintln!("{}: {}", "Error".red().bold(), e);
+                exit::tool_error();
+            }
+        };
+
+        // A socket nobody listens on is left over from a daemon that was killed
+        let _ = std::fs::remove_file(socket);
+        let listener = match UnixListener::bind(socket) {
+            Ok(listener) => listener,
+            Err(e) => {
+                eprintln!(
+                    "{}: failed to listen on {}: {}",
+                    "Error".red().bold(),
+                    socket.display(),
+                    e
+                );
+                exit::tool_error();
+            }
+        };
+
+        // Clients that connect while the index is built wait in the backlog
+        daemon.refresh_index();
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            if stream.set_read_timeout(Some(REQUEST_TIMEOUT)).is_err()
+                || stream.set_write_timeout(Some(REQUEST_TIMEOUT)).is_err()
+            {
+                continue;
+            }
+            if !daemon.serve(&stream) {
+                break;
+            }
+        }
+        let _ = std::fs::remove_file(socket);
+    }
+
+    /// Everything the daemon keeps warm between requests
+    struct Daemon {
+        root: PathBuf,
+        config_path: Option<String>,
+        isolated: bool,
+        loaded: Loaded,
+        index: WorkspaceIndex,
+        /// Modification times of the files in `index`, to re-index only what changed
+        indexed: HashMap<PathBuf, SystemTime>,
+        requests: u64,
+        started: Instant,
+    }
+
+    /// Settings and rules built from the project config
+    struct Loaded {
+        config: rumdl_config::Config,
+        rules: Vec<Box<dyn Rule>>,
+        project_root: Option<PathBuf>,
+        /// Config files the settings came from, with their modification times
+        config_files: Vec<(PathBuf, Option<SystemTime>)>,
+    }
+
+    impl Loaded {
+        fn load(config_path: Option<&str>, isolated: bool) -> Result<Self, String> {
+            let sourced = rumdl_config::SourcedConfig::load_with_discovery(config_path, None, isolated)
+                .map_err(|e| format!("failed to load config: {e}"))?;
+            let config_files = sourced
+                .loaded_files
+                .iter()
+                .map(|file| {
+                    let path = canonical(Path::new(file));
+                    let modified = modified(&path);
+                    (path, modified)
+                })
+                .collect();
+            let project_root = sourced.project_root.clone();
+            let config: rumdl_config::Config = sourced.into_validated_unchecked().into();
+            let rules = file_processor::get_enabled_rules_from_checkargs(&check_args(&[]), &config);
+            Ok(Self {
+                config,
+                rules,
+                project_root,
+                config_files,
+            })
+        }
+
+        fn is_stale(&self) -> bool {
+            self.config_files.iter().any(|(path, time)| modified(path) != *time)
+        }
+    }
+
+    impl Daemon {
+        fn load(root: &Path, config_path: Option<&str>, isolated: bool) -> Result<Self, String> {
+            Ok(Self {
+                root: root.to_path_buf(),
+                config_path: config_path.map(String::from),
+                isolated,
+                loaded: Loaded::load(config_path, isolated)?,
+                index: WorkspaceIndex::new(),
+                indexed: HashMap::new(),
+                requests: 0,
+                started: Instant::now(),
+            })
+        }
+
+        /// Answer one connection; `false` once the daemon should shut down
+        fn serve(&mut self, stream: &UnixStream) -> bool {
+            let mut line = String::new();
+            // Probes from `start` connect without sending anything
+            if BufReader::new(stream).read_line(&mut line).is_err() || line.trim().is_empty() {
+                return true;
+            }
+            self.requests += 1;
+
+            let (response, keep_running) = match serde_json::from_str::<Request>(&line) {
+                Ok(Request::Check { paths }) => (self.check(&paths), true),
+                Ok(Request::Status) => (self.status(), true),
+                Ok(Request::Stop) => (Response::Stopped, false),
+                Err(e) => (
+                    Response::Error {
+                        message: format!("invalid request: {e}"),
+                    },
+                    true,
+                ),
+            };
+            if let Ok(mut reply) = serde_json::to_string(&response) {
+                reply.push('\n');
+                let mut stream = stream;
+                let _ = stream.write_all(reply.as_bytes());
+            }
+            keep_running
+        }
+
+        fn status(&self) -> Response {
+            Response::Status {
+                pid: std::process::id(),
+                root: self.root.clone(),
+                config_files: self.loaded.config_files.iter().map(|(path, _)| path.clone()).collect(),
+                indexed_files: self.index.file_count(),
+                requests: self.requests,
+                uptime_secs: self.started.elapsed().as_secs(),
+            }
+        }
+
+        fn check(&mut self, paths: &[PathBuf]) -> Response {
+            // Edited config files take effect on the next request; a config
+            // that no longer loads keeps the previous settings
+            if self.loaded.is_stale() {
+                match Loaded::load(self.config_path.as_deref(), self.isolated) {
+                    Ok(loaded) => {
+                        self.loaded = loaded;
+                        self.index = WorkspaceIndex::new();
+                        self.indexed.clear();
+                    }
+                    Err(message) => return Response::Error { message },
+                }
+            }
+            self.refresh_index();
+
+            let Loaded {
+                config,
+                rules,
+                project_root,
+                ..
+            } = &self.loaded;
+            let paths: Vec<String> = paths.iter().map(|path| path.to_string_lossy().into_owned()).collect();
+            let files =
+                match file_processor::find_markdown_files(&paths, &check_args(&paths), config, project_root.as_deref())
+                {
+                    Ok(files) => files,
+                    Err(e) => {
+                        return Response::Error {
+                            message: format!("failed to find markdown files: {e}"),
+                        };
+                    }
+                };
+
+            let mut reports = Vec::new();
+            for file in &files {
+                let result =
+                    file_processor::process_file_with_index(file, rules, false, true, true, config, None, None, None);
+                let path = canonical(Path::new(file));
+                let mut warnings = result.warnings;
+                if let Ok(cross_file_warnings) =
+                    rumdl_lib::run_cross_file_checks(&path, &result.file_index, rules, &self.index, Some(config))
+                {
+                    warnings.extend(cross_file_warnings);
+                }
+                warnings.sort_by_key(|w| (w.line, w.column));
+                if !warnings.is_empty() {
+                    reports.push(FileReport { path, warnings });
+                }
+            }
+            Response::Checked {
+                files: reports,
+                files_processed: files.len(),
+            }
+        }
+
+        /// Bring the workspace index up to date with the files on disk,
+        /// re-indexing only those modified since they were last indexed
+        fn refresh_index(&mut self) {
+            let Loaded {
+                config,
+                rules,
+                project_root,
+                ..
+            } = &self.loaded;
+            let files = file_processor::find_markdown_files(&[], &check_args(&[]), config, project_root.as_deref())
+                .unwrap_or_default();
+
+            let mut current = HashSet::new();
+            for file in files {
+                let path = canonical(Path::new(&file));
+                current.insert(path.clone());
+                let modified = modified(&path);
+                if modified.is_some() && self.indexed.get(&path) == modified.as_ref() {
+                    continue;
+                }
+                let Ok(content) = crate::read_file_efficiently(&path) else {
+                    continue;
+                };
+                let file_index = rumdl_lib::build_file_index_only(
+                    &content,
+                    rules,
+                    config.get_flavor_for_file(&path),
+                    Some(path.clone()),
+                );
+                self.index.update_file(&path, file_index);
+                if let Some(modified) = modified {
+                    self.indexed.insert(path, modified);
+                }
+            }
+            self.index.retain_only(&current);
+            self.indexed.retain(|path, _| current.contains(path));
+        }
+    }
+}
@@ -2,12 +2,180 @@
 
 use colored::*;
 use std::fs;
+use std::path::Path;
 
 use rumdl_lib::config as rumdl_config;
 use rumdl_lib::exit_codes::exit;
+use rumdl_lib::lint_context::LintContext;
+use rumdl_lib::rule::{LintWarning, Rule};
 
-/// Handle the explain command: show detailed rule documentation.
-pub fn handle_explain(rule_query: &str) {
+/// Handle the explain command: show detailed rule documentation, or, given a
+/// `FILE:LINE` location, why rules fire on that line.
+pub fn handle_explain(query: &str, config_path: Option<&str>, isolated: bool, inline_overrides: &[toml::Table]) {
+    match parse_location(query) {
+        Some((path, line)) => explain_location(path, line, config_path, isolated, inline_overrides),
+        None => explain_rule(query),
+    }
+}
+
+/// Split `FILE:LINE` or `FILE:LINE:COLUMN` into the file and line, if the file exists
+fn parse_location(query: &str) -> Option<(&Path, usize)> {
+    let (rest, last) = query.rsplit_once(':')?;
+    let last: usize = last.parse().ok()?;
+    // With a column, the line comes before it
+    let (path, line) = match rest.rsplit_once(':').map(|(path, line)| (path, line.parse::<usize>())) {
+        Some((path, Ok(line))) if Path::new(path).is_file() => (path, line),
+        _ => (rest, last),
+    };
+    let path = Path::new(path);
+    path.is_file().then_some((path, line))
+}
+
+/// Show the rules that fire on `line` of `path`, whether inline comments
+/// suppress them, their config and their fixes
+fn explain_location(
+    path: &Path,
+    line: usize,
+    config_path: Option<&str>,
+    isolated: bool,
+    inline_overrides: &[toml::Table],
+) {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{}: Failed to read {}: {e}", "Error".red().bold(), path.display());
+            exit::tool_error();
+        }
+    };
+    let line_count = content.lines().count();
+    if line == 0 || line > line_count {
+        eprintln!(
+            "{}: {} has {line_count} lines; line {line} does not exist.",
+            "Error".red().bold(),
+            path.display()
+        );
+        exit::tool_error();
+    }
+
+    let mut sourced = crate::load_config_with_cli_error_handling_with_dir(config_path, isolated, Some(path));
+    crate::apply_inline_overrides(&mut sourced, inline_overrides);
+    let config: rumdl_config::Config = sourced.into_validated_unchecked().into();
+    let flavor = config.get_flavor_for_file(path);
+    let ctx = LintContext::new(&content, flavor, Some(path.to_path_buf()));
+    let inline_config = ctx.inline_config();
+    // Config set with `<!-- rumdl-configure-file -->` applies to the whole file
+    let config = config.merge_with_inline_config(inline_config);
+
+    let all_rules = rumdl_lib::rules::all_rules(&config);
+    let mut rules = rumdl_lib::rules::filter_rules(&all_rules, &config.global);
+    let ignored = config.get_ignored_rules_for_file(path);
+    rules.retain(|rule| !ignored.contains(rule.name()));
+
+    println!("{}", format!("{}:{line}", path.display()).bold());
+    if let Some(text) = content.lines().nth(line - 1) {
+        println!("  {}", text.dimmed());
+    }
+    println!();
+
+    let mut disabled: Vec<_> = inline_config.get_disabled_rules(line).into_iter().collect();
+    disabled.sort();
+    print!("{} ", "Inline config at this line:".bold());
+    if disabled.is_empty() {
+        println!("no rules disabled");
+    } else {
+        println!("disabled {}", disabled.join(", "));
+    }
+
+    let mut found = false;
+    for rule in &rules {
+        let warnings = match rule.check(&ctx) {
+            Ok(warnings) => warnings,
+            Err(e) => {
+                eprintln!("{}: Rule {} failed: {e}", "Warning".yellow().bold(), rule.name());
+                continue;
+            }
+        };
+        let at_line: Vec<&LintWarning> = warnings
+            .iter()
+            .filter(|warning| warning.line <= line && line <= warning.end_line.max(warning.line))
+            .collect();
+        if at_line.is_empty() {
+            continue;
+        }
+        found = true;
+        println!();
+        println!("{}", format!("{} - {}", rule.name(), rule.description()).bold());
+        for warning in at_line {
+            explain_warning(warning, rule.as_ref(), &config, inline_config, &content);
+        }
+        print_rule_config(rule.as_ref(), &config);
+    }
+
+    if !found {
+        println!();
+        println!("No enabled rule reports line {line}.");
+    }
+}
+
+fn explain_warning(
+    warning: &LintWarning,
+    rule: &dyn Rule,
+    config: &rumdl_config::Config,
+    inline_config: &rumdl_lib::inline_config::InlineConfig,
+    content: &str,
+) {
+    let rule_name = warning.rule_name.as_deref().unwrap_or(rule.name());
+    let severity = config.get_rule_severity(rule_name).unwrap_or(warning.severity);
+    println!(
+        "  {}:{} [{severity:?}] {}",
+        warning.line, warning.column, warning.message
+    );
+
+    // Sub-rules like "MD029-style" are disabled by their base name
+    let base_name = rule_name.split('-').next().unwrap_or(rule_name);
+    let end = warning.end_line.max(warning.line);
+    if let Some(disabled_at) = (warning.line..=end).find(|&line| inline_config.is_rule_disabled(base_name, line)) {
+        println!(
+            "    {}",
+            format!("Suppressed: {base_name} is disabled by an inline comment at line {disabled_at}").yellow()
+        );
+    }
+
+    match &warning.fix {
+        Some(fix) => {
+            let current = content.get(fix.range.clone()).unwrap_or_default();
+            let kind = if fix.is_automatic() { "Fix" } else { "Suggested fix" };
+            println!("    {kind}: replace {current:?} with {:?}", fix.replacement);
+            if !fix.additional_edits.is_empty() {
+                println!("    (plus {} more edits)", fix.additional_edits.len());
+            }
+        }
+        None => println!("    No automatic fix"),
+    }
+}
+
+/// Print the rule's options with the value in effect and where it comes from
+fn print_rule_config(rule: &dyn Rule, config: &rumdl_config::Config) {
+    let Some((_, toml::Value::Table(defaults))) = rule.default_config_section() else {
+        return;
+    };
+    let configured = config.rules.get(rule.name()).map(|rule_config| &rule_config.values);
+    println!("  {}", "Config:".bold());
+    for (key, default) in &defaults {
+        let value = configured.and_then(|values| values.get(key).or_else(|| values.get(&key.replace('-', "_"))));
+        match value {
+            Some(value) => println!("    {key} = {value}"),
+            None if rumdl_lib::rule_config_serde::is_nullable_sentinel(default) => {
+                println!("    {key} {}", "(unset)".dimmed());
+            }
+            // The default of an option that takes several types is not known here
+            None if rumdl_lib::rule_config_serde::is_polymorphic_sentinel(default) => {}
+            None => println!("    {key} = {default} {}", "(default)".dimmed()),
+        }
+    }
+}
+
+fn explain_rule(rule_query: &str) {
     let default_config = rumdl_config::Config::default();
     let all_rules = rumdl_lib::rules::all_rules(&default_config);
 
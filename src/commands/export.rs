@@ -0,0 +1,79 @@
+//! Handler for the `export` command.
+
+use colored::*;
+use std::fs;
+use std::path::Path;
+
+use clap::ValueEnum;
+
+use rumdl_lib::config as rumdl_config;
+use rumdl_lib::exit_codes::exit;
+use rumdl_lib::markdownlint_config::export_to_markdownlint;
+
+#[derive(Clone, Default, ValueEnum)]
+pub enum ExportFormat {
+    /// `.markdownlint.json` / `.markdownlint.jsonc`
+    #[default]
+    MarkdownlintJson,
+    /// `.markdownlint.yaml`
+    MarkdownlintYaml,
+}
+
+/// Handle the export command: convert the effective rumdl config to markdownlint format.
+pub fn handle_export(
+    format: ExportFormat,
+    output: Option<String>,
+    config_path: Option<&str>,
+    no_config: bool,
+    inline_overrides: &[toml::Table],
+) {
+    let mut sourced = match rumdl_config::SourcedConfig::load_with_discovery(config_path, None, no_config) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}: {}", "Config error".red().bold(), e);
+            exit::tool_error();
+        }
+    };
+    crate::cli_config_override::apply_inline_overrides(&mut sourced, inline_overrides);
+    let config: rumdl_config::Config = sourced.into_validated_unchecked().into();
+
+    let export = export_to_markdownlint(&config);
+    if !export.skipped_rules.is_empty() {
+        eprintln!(
+            "{}: rules with no markdownlint equivalent were not exported: {}",
+            "Warning".yellow().bold(),
+            export.skipped_rules.join(", ")
+        );
+    }
+
+    let rendered = match format {
+        ExportFormat::MarkdownlintJson => serde_json::to_string_pretty(&export.config)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        ExportFormat::MarkdownlintYaml => serde_yaml::to_string(&export.config).map_err(|e| e.to_string()),
+    };
+    let rendered = match rendered {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!("{}: Failed to serialize config: {}", "Error".red().bold(), e);
+            exit::tool_error();
+        }
+    };
+
+    let Some(output_path) = output else {
+        print!("{rendered}");
+        return;
+    };
+
+    if Path::new(&output_path).exists() {
+        eprintln!("{}: Output file '{}' already exists", "Error".red().bold(), output_path);
+        exit::tool_error();
+    }
+    match fs::write(&output_path, rendered) {
+        Ok(()) => println!("Exported rumdl config to '{output_path}'"),
+        Err(e) => {
+            eprintln!("{}: Failed to write to '{}': {}", "Error".red().bold(), output_path, e);
+            exit::tool_error();
+        }
+    }
+}
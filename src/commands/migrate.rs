@@ -0,0 +1,73 @@
+//! Handler for the `migrate` command.
+
+use colored::*;
+use std::fs;
+use std::path::Path;
+
+use rumdl_lib::config as rumdl_config;
+use rumdl_lib::exit_codes::exit;
+
+/// Handle the migrate command: show the upgrades to old config files, or write them.
+pub fn handle_migrate(paths: Vec<String>, write: bool, config_path: Option<&str>) {
+    let files = if paths.is_empty() {
+        discovered_config_files(config_path)
+    } else {
+        paths
+    };
+    if files.is_empty() {
+        println!("No configuration file found");
+        return;
+    }
+
+    let mut changed = 0;
+    for file in &files {
+        let content = match fs::read_to_string(file) {
+            Ok(content) => content,
+            Err(e) => fail(&format!("Failed to read '{file}': {e}")),
+        };
+        let pyproject = Path::new(file).file_name().is_some_and(|name| name == "pyproject.toml");
+        let migration = match rumdl_config::migrate_config(&content, pyproject) {
+            Ok(migration) => migration,
+            Err(e) => fail(&format!("{file}: {e}")),
+        };
+        if !migration.is_changed() {
+            continue;
+        }
+        changed += 1;
+
+        print!(
+            "{}",
+            crate::formatter::generate_diff(&content, &migration.content, file)
+        );
+        for change in &migration.changes {
+            println!("  {} {change}", "-".cyan());
+        }
+        if write && let Err(e) = fs::write(file, &migration.content) {
+            fail(&format!("Failed to write '{file}': {e}"));
+        }
+    }
+
+    match (changed, write) {
+        (0, _) => println!("No config changes needed in {} file(s)", files.len()),
+        (n, true) => println!("\nUpdated {n} config file(s)"),
+        (n, false) => println!("\nWould update {n} config file(s); run with --write to apply"),
+    }
+}
+
+/// The TOML config files discovery would load, including `extends` targets
+fn discovered_config_files(config_path: Option<&str>) -> Vec<String> {
+    let sourced = match rumdl_config::SourcedConfig::load_with_discovery(config_path, None, false) {
+        Ok(sourced) => sourced,
+        Err(e) => fail(&format!("Config error: {e}")),
+    };
+    sourced
+        .loaded_files
+        .into_iter()
+        .filter(|file| Path::new(file).extension().is_some_and(|ext| ext == "toml"))
+        .collect()
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}: {message}", "Error".red().bold());
+    exit::tool_error();
+}
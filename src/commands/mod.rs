@@ -3,14 +3,19 @@
 //! Each subcommand has its own module with a public handler function
 //! that `main()` dispatches to.
 
+pub mod anchors;
 pub mod check;
 pub mod clean;
 pub mod code_block_tools_docs;
 pub mod completions;
 pub mod config;
+pub mod daemon;
 pub mod explain;
+pub mod export;
 pub mod import;
 pub mod init;
+pub mod migrate;
+pub mod mv;
 pub mod rule;
 pub mod schema;
 pub mod server;
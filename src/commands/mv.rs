@@ -0,0 +1,122 @@
+//! Handler for the `mv` command.
+
+use colored::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rumdl_lib::discovery::{MarkdownWalkOptions, has_markdown_extension, markdown_walk_builder};
+use rumdl_lib::exit_codes::exit;
+use rumdl_lib::link_rewrite::{FileEdit, MovePlan, plan_move};
+use rumdl_lib::utils::project_root::discover_project_root_from;
+
+/// Handle the mv command: move a Markdown file and rewrite links to it.
+pub fn handle_mv(from: String, to: String, dry_run: bool) {
+    let cwd = match std::env::current_dir().and_then(|dir| dir.canonicalize()) {
+        Ok(dir) => dir,
+        Err(e) => fail(&format!("Failed to read the current directory: {e}")),
+    };
+
+    let from_path = match Path::new(&from).canonicalize() {
+        Ok(path) if path.is_file() => path,
+        Ok(_) => fail(&format!("'{from}' is not a file")),
+        Err(e) => fail(&format!("Cannot move '{from}': {e}")),
+    };
+    if !has_markdown_extension(&from_path) {
+        fail(&format!("'{from}' is not a Markdown file"));
+    }
+
+    let mut to_path = normalize(&cwd.join(&to));
+    if to_path.is_dir() {
+        to_path.push(from_path.file_name().unwrap_or_default());
+    }
+    if to_path.exists() {
+        fail(&format!("'{}' already exists", display(&to_path, &cwd)));
+    }
+
+    let root = discover_project_root_from(&cwd);
+    let root = root.canonicalize().unwrap_or(root);
+    let files: Vec<PathBuf> = markdown_walk_builder(&root, &MarkdownWalkOptions::default())
+        .build()
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()) && has_markdown_extension(entry.path()))
+        .map(|entry| entry.into_path())
+        .collect();
+
+    let plan = match plan_move(&files, &from_path, &to_path) {
+        Ok(plan) => plan,
+        Err(e) => fail(&format!("Failed to read '{from}': {e}")),
+    };
+
+    if dry_run {
+        print_plan(&plan, &cwd);
+    } else if let Err(e) = apply_plan(&plan) {
+        fail(&e.to_string());
+    } else {
+        println!(
+            "Moved {} to {} and updated links in {} file(s)",
+            display(&plan.from, &cwd),
+            display(&plan.to, &cwd),
+            plan.dependents.len()
+        );
+    }
+}
+
+fn print_plan(plan: &MovePlan, cwd: &Path) {
+    let from = display(&plan.from, cwd);
+    let to = display(&plan.to, cwd);
+    println!("{} {from} -> {to}", "rename".cyan().bold());
+    if plan.moved.is_changed() {
+        print!("{}", diff(&plan.moved, &to));
+    }
+    for edit in &plan.dependents {
+        print!("{}", diff(edit, &display(&edit.path, cwd)));
+    }
+    println!(
+        "\nWould move {from} to {to} and update links in {} file(s)",
+        plan.dependents.len()
+    );
+}
+
+fn diff(edit: &FileEdit, path: &str) -> String {
+    crate::formatter::generate_diff(&edit.original, &edit.updated, path)
+}
+
+fn apply_plan(plan: &MovePlan) -> std::io::Result<()> {
+    for edit in &plan.dependents {
+        fs::write(&edit.path, &edit.updated)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("Failed to write '{}': {e}", edit.path.display())))?;
+    }
+    if let Some(parent) = plan.to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&plan.from, &plan.to)?;
+    if plan.moved.is_changed() {
+        fs::write(&plan.to, &plan.moved.updated)?;
+    }
+    Ok(())
+}
+
+/// Path relative to the working directory when it is inside it
+fn display(path: &Path, cwd: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+/// Resolve `.` and `..` without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                result.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}: {message}", "Error".red().bold());
+    exit::tool_error();
+}
@@ -4,7 +4,7 @@ use clap::ValueEnum;
 
 use rumdl_lib::config as rumdl_config;
 use rumdl_lib::exit_codes::exit;
-use rumdl_lib::rule::{FixCapability, Rule, RuleCategory};
+use rumdl_lib::rule::{FixCapability, FixSafety, Rule, RuleCategory};
 
 #[derive(Clone, Default, ValueEnum)]
 pub enum OutputFormat {
@@ -13,6 +13,9 @@ pub enum OutputFormat {
     Json,
     #[value(alias("jsonl"))]
     JsonLines,
+    /// Complete rule documentation as Markdown, one page per rule
+    #[value(alias("md"))]
+    Markdown,
 }
 
 /// Rule metadata for JSON export (matches Ruff's output format)
@@ -37,6 +40,9 @@ struct RuleInfo {
     /// Full explanation/documentation for the rule (from docs/*.md)
     #[serde(skip_serializing_if = "Option::is_none")]
     explanation: Option<String>,
+    /// Relevance of the rule to the `--search` query; higher is better
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<u32>,
 }
 
 /// Handle the rule command: show info about a rule or list all rules.
@@ -47,6 +53,7 @@ pub fn handle_rule(
     category: Option<String>,
     explain: bool,
     list_categories: bool,
+    search: Option<String>,
 ) {
     // Use the canonical all_rules function to avoid drift between CLI and library
     let default_config = rumdl_config::Config::default();
@@ -83,13 +90,13 @@ pub fn handle_rule(
         }
     }
 
-    let aliases_map = build_rule_aliases_map();
-
     // Helper to build RuleInfo from a rule
     let build_rule_info = |r: &dyn Rule, include_explanation: bool| -> RuleInfo {
         let code = r.name().to_string();
-        let all_aliases = aliases_map.get(&code).cloned().unwrap_or_default();
-        let (primary_name, remaining_aliases) = get_primary_and_remaining_aliases(&code, &all_aliases);
+        let (primary_name, remaining_aliases) = match rumdl_config::rule_aliases(&code) {
+            [] => (code.to_lowercase(), Vec::new()),
+            [primary, rest @ ..] => (primary.clone(), rest.to_vec()),
+        };
         let (fix_desc, fix_avail) = fix_capability_to_strings(r.fix_capability());
         let explanation = if include_explanation {
             read_rule_explanation(&code)
@@ -106,6 +113,7 @@ pub fn handle_rule(
             fix_availability: fix_avail.to_string(),
             url: format!("https://rumdl.dev/{}/", code.to_lowercase()),
             explanation,
+            score: None,
         }
     };
 
@@ -124,6 +132,18 @@ pub fn handle_rule(
             eprintln!("Rule '{rule_query}' not found.");
             exit::tool_error();
         }
+    } else if let Some(query) = &search {
+        // Ranked matches, best first
+        rumdl_lib::registry::search(query)
+            .into_iter()
+            .filter_map(|m| {
+                let r = all_rules.iter().find(|r| r.name() == m.rule.id)?;
+                Some(RuleInfo {
+                    score: Some(m.score),
+                    ..build_rule_info(r.as_ref(), explain)
+                })
+            })
+            .collect()
     } else {
         all_rules.iter().map(|r| build_rule_info(r.as_ref(), explain)).collect()
     };
@@ -140,6 +160,9 @@ pub fn handle_rule(
     // Check if no rules match filters
     if rule_infos.is_empty() && rule.is_none() {
         let mut filter_desc = Vec::new();
+        if let Some(ref query) = search {
+            filter_desc.push(format!("search={query:?}"));
+        }
         if fixable {
             filter_desc.push("fixable".to_string());
         }
@@ -180,6 +203,17 @@ pub fn handle_rule(
                 }
             }
         }
+        OutputFormat::Markdown => {
+            let opt_in = rumdl_lib::rules::opt_in_rules();
+            let pages: Vec<String> = rule_infos
+                .iter()
+                .filter_map(|info| {
+                    let r = all_rules.iter().find(|r| r.name() == info.code)?;
+                    Some(rule_markdown(info, r.as_ref(), opt_in.contains(r.name())))
+                })
+                .collect();
+            print!("{}", pages.join("\n"));
+        }
         OutputFormat::Text => {
             if rule.is_some() {
                 if let Some(info) = rule_infos.first() {
@@ -211,7 +245,10 @@ pub fn handle_rule(
                 } else {
                     String::new()
                 };
-                println!("Available rules{filter_info}:");
+                match &search {
+                    Some(query) => println!("Rules matching {query:?}{filter_info}:"),
+                    None => println!("Available rules{filter_info}:"),
+                }
                 for info in &rule_infos {
                     println!("  {} - {}", info.code, info.summary);
                 }
@@ -222,42 +259,140 @@ pub fn handle_rule(
     }
 }
 
-/// Read rule documentation from the docs directory
-fn read_rule_explanation(code: &str) -> Option<String> {
-    // Try to find the docs file in common locations
-    let code_lower = code.to_lowercase();
-    let possible_paths = [format!("docs/{code_lower}.md"), format!("../docs/{code_lower}.md")];
+/// Render the documentation page of a rule as Markdown.
+///
+/// Everything except the examples comes from the rule itself. The correct and
+/// incorrect examples are taken from the rule's docs page when it is available;
+/// the warnings and the fixed example are produced by running the rule on them.
+fn rule_markdown(info: &RuleInfo, rule: &dyn Rule, opt_in: bool) -> String {
+    let mut out = format!("# {} - {}\n\n", info.code, info.summary);
 
-    for path in &possible_paths {
-        if let Ok(content) = std::fs::read_to_string(path) {
-            return Some(content);
-        }
+    let mut names = vec![format!("`{}`", info.name)];
+    names.extend(info.aliases.iter().map(|alias| format!("`{alias}`")));
+    out.push_str(&format!("Aliases: {}\n\n", names.join(", ")));
+    out.push_str(&format!("- Category: {}\n", info.category));
+    out.push_str(&format!("- Fix: {}\n", info.fix));
+    if opt_in {
+        out.push_str(&format!(
+            "- Enabled by default: no (enable with `extend-enable = [\"{}\"]`)\n",
+            info.code
+        ));
+    } else {
+        out.push_str("- Enabled by default: yes\n");
     }
-    None
-}
 
-/// Build a map from canonical rule IDs to their aliases
-fn build_rule_aliases_map() -> std::collections::HashMap<String, Vec<String>> {
-    use rumdl_config::RULE_ALIAS_MAP;
+    out.push_str("\n## Configuration\n\n");
+    match rule.default_config_section() {
+        Some((section, toml::Value::Table(options))) if !options.is_empty() => {
+            out.push_str("| Option | Type | Default |\n| --- | --- | --- |\n");
+            for (key, value) in &options {
+                let default = crate::formatter::format_toml_value(value).replace('|', "\\|");
+                out.push_str(&format!("| `{key}` | {} | `{default}` |\n", value.type_str()));
+            }
+            let mut table = toml::Table::new();
+            table.insert(section, toml::Value::Table(options));
+            out.push_str(&format!(
+                "\n```toml\n{}```\n",
+                toml::to_string_pretty(&table).unwrap_or_default()
+            ));
+        }
+        _ => out.push_str("This rule has no configuration options.\n"),
+    }
 
-    let mut aliases_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    let explanation = info.explanation.clone().or_else(|| read_rule_explanation(&info.code));
+    let correct = explanation.as_deref().and_then(|doc| doc_example(doc, "Correct"));
+    let incorrect = explanation.as_deref().and_then(|doc| doc_example(doc, "Incorrect"));
+    if correct.is_some() || incorrect.is_some() {
+        out.push_str("\n## Examples\n");
+    }
+    if let Some(example) = correct {
+        out.push_str(&format!("\n### Correct\n\n{}", fenced("markdown", &example)));
+    }
+    if let Some(example) = incorrect {
+        out.push_str(&format!("\n### Incorrect\n\n{}", fenced("markdown", &example)));
+        let ctx =
+            rumdl_lib::lint_context::LintContext::new(&example, rumdl_lib::config::MarkdownFlavor::Standard, None);
+        let warnings = rule.check(&ctx).unwrap_or_default();
+        if !warnings.is_empty() {
+            let report: String = warnings
+                .iter()
+                .map(|w| format!("{}:{}: {}\n", w.line, w.column, w.message))
+                .collect();
+            out.push_str(&format!("\nWarnings:\n\n{}", fenced("text", &report)));
+        }
+        if rule.fix_capability() != FixCapability::Unfixable
+            && let Ok(fixed) = rule.fix(&ctx)
+            && fixed != example
+        {
+            out.push_str(&format!("\n### Fixed\n\n{}", fenced("markdown", &fixed)));
+        }
+    }
 
-    for (alias, canonical) in RULE_ALIAS_MAP.entries() {
-        // Skip identity mappings (where alias == canonical)
-        if *alias == *canonical {
-            continue;
+    out.push_str("\n## Fix behavior\n\n");
+    out.push_str(match rule.fix_capability() {
+        FixCapability::FullyFixable => "`rumdl check --fix` and `rumdl fmt` fix every violation of this rule.\n",
+        FixCapability::ConditionallyFixable => {
+            "`rumdl check --fix` and `rumdl fmt` fix violations when a safe fix exists;\nthe rest need manual changes.\n"
         }
-        // Convert alias to kebab-case lowercase
-        let alias_kebab = alias.to_lowercase();
-        aliases_map.entry(canonical.to_string()).or_default().push(alias_kebab);
+        FixCapability::Unfixable => "Violations of this rule must be fixed manually.\n",
+    });
+    if rule.fix_capability() != FixCapability::Unfixable && rule.fix_safety() == FixSafety::Unsafe {
+        out.push_str(
+            "Its fixes may change what the document says, so they are only applied\nwith `--unsafe-fixes` or `unsafe-fixes = true`.\n",
+        );
     }
 
-    // Sort aliases for consistent output
-    for aliases in aliases_map.values_mut() {
-        aliases.sort();
+    out.push_str(&format!("\nDocumentation: <{}>\n", info.url));
+    out
+}
+
+/// Wrap text in a code block whose fence is longer than any backtick fence
+/// inside it
+fn fenced(language: &str, text: &str) -> String {
+    let longest = text
+        .lines()
+        .map(|line| line.trim_start().chars().take_while(|&c| c == '`').count())
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    let newline = if text.ends_with('\n') { "" } else { "\n" };
+    format!("{fence}{language}\n{text}{newline}{fence}\n")
+}
+
+/// The first fenced code block under the `Correct` or `Incorrect` example
+/// heading of a rule's docs page
+fn doc_example(doc: &str, label: &str) -> Option<String> {
+    let mut lines = doc.lines();
+    lines.find(|line| {
+        line.starts_with("###")
+            && line
+                .trim_start_matches('#')
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .starts_with(label)
+    })?;
+
+    let opening = lines
+        .by_ref()
+        .take_while(|line| !line.starts_with("## "))
+        .find(|line| line.starts_with("```") || line.starts_with("~~~"))?;
+    let fence_char = opening.chars().next()?;
+    let fence_len = opening.chars().take_while(|&c| c == fence_char).count();
+
+    let mut example = String::new();
+    for line in lines {
+        let trimmed = line.trim_end();
+        if trimmed.len() >= fence_len && trimmed.chars().all(|c| c == fence_char) {
+            return Some(example);
+        }
+        example.push_str(line);
+        example.push('\n');
     }
+    None
+}
 
-    aliases_map
+/// Rule documentation, embedded in the library from the docs directory
+fn read_rule_explanation(code: &str) -> Option<String> {
+    rumdl_lib::registry::rule_docs(code).map(String::from)
 }
 
 /// Convert RuleCategory to a string for JSON output
@@ -286,14 +421,3 @@ fn fix_capability_to_strings(capability: FixCapability) -> (&'static str, &'stat
         FixCapability::Unfixable => ("Fix is not available.", "None"),
     }
 }
-
-/// Get the primary alias (kebab-case name) for a rule, and remaining aliases
-fn get_primary_and_remaining_aliases(code: &str, aliases: &[String]) -> (String, Vec<String>) {
-    if aliases.is_empty() {
-        (code.to_lowercase(), Vec::new())
-    } else {
-        let primary = aliases[0].clone();
-        let remaining: Vec<String> = aliases.iter().skip(1).cloned().collect();
-        (primary, remaining)
-    }
-}
@@ -0,0 +1,122 @@
+//! Analysis of settings that are valid on their own but contradict each other
+//! or have no effect.
+
+use super::source_tracking::ConfigValidationWarning;
+use super::types::{Config, get_rule_config_value};
+use crate::rule_config_serde::load_rule_config;
+use crate::rules::md013_line_length::md013_config::ReflowMode;
+use std::collections::HashSet;
+
+/// Below this line length, sentence-based reflow reports nearly every sentence
+const MIN_SENTENCE_LINE_LENGTH: usize = 40;
+
+/// Find settings that conflict with each other or are redundant.
+///
+/// Like deprecated settings these do not stop a run: `rumdl config lint`
+/// lists them, and `rumdl check --verbose` prints them at startup.
+pub fn config_conflicts(config: &Config) -> Vec<ConfigValidationWarning> {
+    let all_rules = crate::rules::all_rules(config);
+    let enabled: HashSet<&str> = crate::rules::filter_rules(&all_rules, &config.global)
+        .iter()
+        .map(|rule| rule.name())
+        .collect();
+    let mut warnings = Vec::new();
+    let mut warn = |rule: Option<&str>, key: Option<&str>, message: String| {
+        warnings.push(ConfigValidationWarning {
+            message,
+            rule: rule.map(str::to_string),
+            key: key.map(str::to_string),
+        });
+    };
+
+    // A rule listed on both sides ends up disabled, whatever the user meant
+    let global = &config.global;
+    let enable_lists = [("enable", &global.enable), ("extend-enable", &global.extend_enable)];
+    let disable_lists = [("disable", &global.disable), ("extend-disable", &global.extend_disable)];
+    for (enable_key, enable_list) in enable_lists {
+        for rule in enable_list.iter().filter(|rule| !rule.eq_ignore_ascii_case("all")) {
+            if let Some((disable_key, _)) = disable_lists.iter().find(|(_, list)| list.contains(rule)) {
+                warn(
+                    Some(rule),
+                    Some(enable_key),
+                    format!("{rule} is listed in both {enable_key} and {disable_key}; it stays disabled"),
+                );
+            }
+        }
+    }
+
+    // extend-enable only adds to the defaults when there is no enable list
+    if global.enable.is_empty() && !global.enable_is_explicit {
+        let opt_in = crate::rules::opt_in_rules();
+        for rule in &global.extend_enable {
+            if !opt_in.contains(rule.as_str()) && all_rules.iter().any(|r| r.name() == rule) {
+                warn(
+                    Some(rule),
+                    Some("extend-enable"),
+                    format!("{rule} in extend-enable has no effect: the rule is enabled by default"),
+                );
+            }
+        }
+    }
+
+    if enabled.contains("MD013") {
+        let md013: crate::rules::MD013Config = load_rule_config(config);
+        let line_length = if md013.line_length.get() == 80 {
+            global.line_length
+        } else {
+            md013.line_length
+        };
+        let mode = match md013.reflow_mode {
+            ReflowMode::SentencePerLine => Some("sentence-per-line"),
+            ReflowMode::SemanticLineBreaks => Some("semantic-line-breaks"),
+            _ => None,
+        };
+        if let Some(mode) = mode
+            && md013.reflow
+            && !line_length.is_unlimited()
+            && line_length.get() < MIN_SENTENCE_LINE_LENGTH
+        {
+            warn(
+                Some("MD013"),
+                Some("line-length"),
+                format!(
+                    "MD013 reflow-mode = \"{mode}\" with line-length = {} flags or splits nearly every \
+                     sentence; raise line-length or set it to 0 to allow sentences of any length",
+                    line_length.get()
+                ),
+            );
+        }
+    }
+
+    // The first heading MD041 asks for is the title MD025 counts
+    if enabled.contains("MD025") && enabled.contains("MD041") {
+        let md025_level = get_rule_config_value::<u8>(config, "MD025", "level").unwrap_or(1);
+        let md041_level = get_rule_config_value::<u8>(config, "MD041", "level").unwrap_or(1);
+        if md025_level != md041_level {
+            warn(
+                Some("MD041"),
+                Some("level"),
+                format!(
+                    "MD041 level = {md041_level} requires a level-{md041_level} first heading, but MD025 \
+                     level = {md025_level} treats level-{md025_level} headings as the document title"
+                ),
+            );
+        }
+    }
+
+    // Indented code blocks cannot carry a language
+    if enabled.contains("MD040") && enabled.contains("MD046") {
+        let style = get_rule_config_value::<String>(config, "MD046", "style").unwrap_or_default();
+        if style == "indented" {
+            warn(
+                Some("MD046"),
+                Some("style"),
+                "MD040 has no effect with MD046 style = \"indented\": indented code blocks cannot \
+                 declare a language"
+                    .to_string(),
+            );
+        }
+    }
+
+    warnings
+}
@@ -17,25 +17,43 @@ use std::str::FromStr;
 use super::flavor::{MarkdownFlavor, normalize_key};
 use super::registry::RuleRegistry;
 use super::source_tracking::{ConfigSource, SourcedGlobalConfig, SourcedValue};
-use crate::types::LineLength;
+use super::types::{LargeFileMode, NestedConfigs};
+use crate::types::{AmbiguousWidth, LengthMode, LineLength};
+use crate::utils::slug::Slugify;
 
 /// Global configuration keys that hold plain values (normalized kebab-case).
 pub const GLOBAL_VALUE_KEYS: &[&str] = &[
     "enable",
     "disable",
     "include",
+    "extensions",
     "exclude",
+    "extract-from",
+    "html-markdown-patterns",
     "extend-enable",
     "extend-disable",
     "respect-gitignore",
     "force-exclude",
     "line-length",
+    "length-mode",
+    "ambiguous-width",
     "output-format",
     "cache-dir",
+    "docs-base-url",
+    "slugify",
     "cache",
     "fixable",
     "unfixable",
     "flavor",
+    "nested-configs",
+    "fix-max-iterations",
+    "unsafe-fixes",
+    "rule-timeout-ms",
+    "max-file-size",
+    "large-file-mode",
+    "draft-key",
+    "draft-value",
+    "draft-disable",
 ];
 
 /// Whether a (normalized) key names a global value setting.
@@ -83,7 +101,7 @@ pub fn apply_global_key(
         |arr: &[toml::Value]| -> Vec<String> { arr.iter().filter_map(|v| v.as_str()).map(str::to_string).collect() };
 
     match norm_key {
-        "enable" | "disable" | "extend-enable" | "extend-disable" | "fixable" | "unfixable" => {
+        "enable" | "disable" | "extend-enable" | "extend-disable" | "fixable" | "unfixable" | "draft-disable" => {
             let toml::Value::Array(arr) = value else {
                 return ApplyOutcome::TypeMismatch { expected: "array" };
             };
@@ -95,23 +113,27 @@ pub fn apply_global_key(
                 "extend-disable" => global.extend_disable.push_override(values, source, origin),
                 "fixable" => global.fixable.push_override(values, source, origin),
                 "unfixable" => global.unfixable.push_override(values, source, origin),
+                "draft-disable" => global.draft_disable.push_override(values, source, origin),
                 _ => unreachable!("outer match limits the keys"),
             }
             ApplyOutcome::Applied
         }
-        "include" | "exclude" => {
+        "include" | "extensions" | "exclude" | "extract-from" | "html-markdown-patterns" => {
             let toml::Value::Array(arr) = value else {
                 return ApplyOutcome::TypeMismatch { expected: "array" };
             };
             let values = to_strings(arr);
             match norm_key {
                 "include" => global.include.push_override(values, source, origin),
+                "extensions" => global.extensions.push_override(values, source, origin),
                 "exclude" => global.exclude.push_override(values, source, origin),
+                "extract-from" => global.extract_from.push_override(values, source, origin),
+                "html-markdown-patterns" => global.html_markdown_patterns.push_override(values, source, origin),
                 _ => unreachable!("outer match limits the keys"),
             }
             ApplyOutcome::Applied
         }
-        "respect-gitignore" | "force-exclude" | "cache" => {
+        "respect-gitignore" | "force-exclude" | "cache" | "unsafe-fixes" => {
             let Some(b) = value.as_bool() else {
                 return ApplyOutcome::TypeMismatch { expected: "boolean" };
             };
@@ -119,6 +141,7 @@ pub fn apply_global_key(
                 "respect-gitignore" => global.respect_gitignore.push_override(b, source, origin),
                 "force-exclude" => global.force_exclude.push_override(b, source, origin),
                 "cache" => global.cache.push_override(b, source, origin),
+                "unsafe-fixes" => global.unsafe_fixes.push_override(b, source, origin),
                 _ => unreachable!("outer match limits the keys"),
             }
             ApplyOutcome::Applied
@@ -133,13 +156,38 @@ pub fn apply_global_key(
                 .push_override(LineLength::new(n.max(0) as usize), source, origin);
             ApplyOutcome::Applied
         }
-        "output-format" | "cache-dir" => {
+        "length-mode" => {
+            let Some(s) = value.as_str() else {
+                return ApplyOutcome::TypeMismatch { expected: "string" };
+            };
+            match LengthMode::from_str(s) {
+                Ok(mode) => {
+                    global.length_mode.push_override(mode, source, origin);
+                    ApplyOutcome::Applied
+                }
+                Err(message) => ApplyOutcome::InvalidValue { message },
+            }
+        }
+        "ambiguous-width" => {
+            let Some(s) = value.as_str() else {
+                return ApplyOutcome::TypeMismatch { expected: "string" };
+            };
+            match AmbiguousWidth::from_str(s) {
+                Ok(width) => {
+                    global.ambiguous_width.push_override(width, source, origin);
+                    ApplyOutcome::Applied
+                }
+                Err(message) => ApplyOutcome::InvalidValue { message },
+            }
+        }
+        "output-format" | "cache-dir" | "docs-base-url" => {
             let Some(s) = value.as_str() else {
                 return ApplyOutcome::TypeMismatch { expected: "string" };
             };
             let slot = match norm_key {
                 "output-format" => &mut global.output_format,
                 "cache-dir" => &mut global.cache_dir,
+                "docs-base-url" => &mut global.docs_base_url,
                 _ => unreachable!("outer match limits the keys"),
             };
             if let Some(sv) = slot.as_mut() {
@@ -151,6 +199,44 @@ pub fn apply_global_key(
             }
             ApplyOutcome::Applied
         }
+        "draft-key" | "draft-value" => {
+            let Some(s) = value.as_str() else {
+                return ApplyOutcome::TypeMismatch { expected: "string" };
+            };
+            let slot = match norm_key {
+                "draft-key" => &mut global.draft_key,
+                "draft-value" => &mut global.draft_value,
+                _ => unreachable!("outer match limits the keys"),
+            };
+            slot.push_override(s.to_string(), source, origin);
+            ApplyOutcome::Applied
+        }
+        "slugify" => {
+            if !matches!(value, toml::Value::String(_) | toml::Value::Array(_)) {
+                return ApplyOutcome::TypeMismatch {
+                    expected: "string or array",
+                };
+            }
+            let Ok(slugify) = value.clone().try_into::<Slugify>() else {
+                return ApplyOutcome::InvalidValue {
+                    message: format!(
+                        "expected a slug style (github, gitlab, kramdown-gfm, kramdown, python-markdown) \
+                         or a list of {{ pattern, replacement }} tables, got {value}"
+                    ),
+                };
+            };
+            if let Err(message) = slugify.validate() {
+                return ApplyOutcome::InvalidValue { message };
+            }
+            if let Some(sv) = global.slugify.as_mut() {
+                sv.push_override(slugify, source, origin);
+            } else {
+                let mut sv = SourcedValue::new(slugify, source);
+                sv.origin = origin;
+                global.slugify = Some(sv);
+            }
+            ApplyOutcome::Applied
+        }
         "flavor" => {
             let Some(s) = value.as_str() else {
                 return ApplyOutcome::TypeMismatch { expected: "string" };
@@ -165,6 +251,65 @@ pub fn apply_global_key(
                 },
             }
         }
+        "nested-configs" => {
+            let Some(s) = value.as_str() else {
+                return ApplyOutcome::TypeMismatch { expected: "string" };
+            };
+            match NestedConfigs::from_str(s) {
+                Ok(mode) => {
+                    global.nested_configs.push_override(mode, source, origin);
+                    ApplyOutcome::Applied
+                }
+                Err(message) => ApplyOutcome::InvalidValue { message },
+            }
+        }
+        "fix-max-iterations" => {
+            let Some(n) = value.as_integer() else {
+                return ApplyOutcome::TypeMismatch { expected: "integer" };
+            };
+            if n < 1 {
+                return ApplyOutcome::InvalidValue {
+                    message: format!("fix-max-iterations must be at least 1, got {n}"),
+                };
+            }
+            global.fix_max_iterations.push_override(n as usize, source, origin);
+            ApplyOutcome::Applied
+        }
+        "rule-timeout-ms" | "max-file-size" => {
+            let Some(n) = value.as_integer() else {
+                return ApplyOutcome::TypeMismatch { expected: "integer" };
+            };
+            if n < 1 {
+                return ApplyOutcome::InvalidValue {
+                    message: format!("{norm_key} must be at least 1, got {n}"),
+                };
+            }
+            let slot = match norm_key {
+                "rule-timeout-ms" => &mut global.rule_timeout_ms,
+                "max-file-size" => &mut global.max_file_size,
+                _ => unreachable!("outer match limits the keys"),
+            };
+            if let Some(sv) = slot.as_mut() {
+                sv.push_override(n as u64, source, origin);
+            } else {
+                let mut sv = SourcedValue::new(n as u64, source);
+                sv.origin = origin;
+                *slot = Some(sv);
+            }
+            ApplyOutcome::Applied
+        }
+        "large-file-mode" => {
+            let Some(s) = value.as_str() else {
+                return ApplyOutcome::TypeMismatch { expected: "string" };
+            };
+            match LargeFileMode::from_str(s) {
+                Ok(mode) => {
+                    global.large_file_mode.push_override(mode, source, origin);
+                    ApplyOutcome::Applied
+                }
+                Err(message) => ApplyOutcome::InvalidValue { message },
+            }
+        }
         _ => ApplyOutcome::Unrecognized,
     }
 }
@@ -257,4 +402,15 @@ mod tests {
         assert!(matches!(outcome, ApplyOutcome::InvalidValue { .. }));
         assert_eq!(global.flavor.source, ConfigSource::Default);
     }
+
+    #[test]
+    fn length_settings_parse_or_are_rejected() {
+        let (global, outcome) = apply("length-mode", &toml::Value::String("bytes".to_string()));
+        assert!(matches!(outcome, ApplyOutcome::Applied));
+        assert_eq!(global.length_mode.value, LengthMode::Bytes);
+
+        let (global, outcome) = apply("ambiguous-width", &toml::Value::String("double".to_string()));
+        assert!(matches!(outcome, ApplyOutcome::InvalidValue { .. }));
+        assert_eq!(global.ambiguous_width.source, ConfigSource::Default);
+    }
 }
@@ -1,5 +1,5 @@
 use indexmap::IndexSet;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
@@ -131,6 +131,19 @@ fn expand_env_vars(input: &str, lookup: impl Fn(&str) -> Option<String>) -> Resu
     Ok(out)
 }
 
+/// Whether an `extends` entry names a remote source such as `github:org/repo`
+/// or `https://...` rather than a local path. Single-letter schemes are
+/// Windows drive letters and count as local.
+fn is_remote_extends(extends_value: &str) -> bool {
+    extends_value.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
 /// Resolve an `extends` path relative to the config file that contains it.
 ///
 /// - `$VAR` / `${VAR}`: expanded from the environment first (see [`expand_env_vars`])
@@ -258,16 +271,30 @@ pub(crate) fn format_shadow_warning(shadow: &ShadowedConfigs) -> String {
     )
 }
 
+/// Config files seen while resolving one `extends` graph
+#[derive(Debug, Default)]
+struct ExtendsState {
+    /// Files currently being loaded, outermost first; meeting one again is a cycle
+    chain: IndexSet<PathBuf>,
+    /// Files already merged; a base shared by several configs is merged only once
+    merged: HashSet<PathBuf>,
+}
+
 /// Load a config file (and any base configs it extends) into a SourcedConfig.
 ///
-/// This function handles the recursive `extends` chain:
+/// This function handles the recursive `extends` graph:
 /// 1. Parse the config file into a fragment
-/// 2. If the fragment has `extends`, recursively load the base config first
-/// 3. Merge the base config, then merge this fragment on top
+/// 2. Recursively load each base config in `extends`, in list order, so later
+///    bases override earlier ones
+/// 3. Merge this fragment on top of the bases
+///
+/// The result is a depth-first, post-order merge. A base reached through more
+/// than one path is merged at its first occurrence only, so it cannot undo a
+/// config that was merged after it.
 fn load_config_with_extends(
     sourced_config: &mut SourcedConfig<ConfigLoaded>,
     config_file_path: &Path,
-    visited: &mut IndexSet<PathBuf>,
+    state: &mut ExtendsState,
     chain_source: ConfigSource,
 ) -> Result<(), ConfigError> {
     // Canonicalize the path for circular reference detection
@@ -276,25 +303,26 @@ fn load_config_with_extends(
         .unwrap_or_else(|_| config_file_path.to_path_buf());
 
     // Check for circular references
-    if visited.contains(&canonical) {
-        let chain: Vec<String> = visited.iter().map(|p| p.display().to_string()).collect();
+    if state.chain.contains(&canonical) {
+        let chain: Vec<String> = state.chain.iter().map(|p| p.display().to_string()).collect();
         return Err(ConfigError::CircularExtends {
             path: config_file_path.display().to_string(),
             chain,
         });
     }
 
+    if state.merged.contains(&canonical) {
+        return Ok(());
+    }
+
     // Check depth limit
-    if visited.len() >= MAX_EXTENDS_DEPTH {
+    if state.chain.len() >= MAX_EXTENDS_DEPTH {
         return Err(ConfigError::ExtendsDepthExceeded {
             path: config_file_path.display().to_string(),
             max_depth: MAX_EXTENDS_DEPTH,
         });
     }
 
-    // Mark as visited
-    visited.insert(canonical);
-
     let path_str = config_file_path.display().to_string();
     let filename = config_file_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
 
@@ -313,8 +341,16 @@ fn load_config_with_extends(
         parsers::parse_rumdl_toml(&content, &path_str, chain_source)?
     };
 
-    // If this fragment has `extends`, load the base config first
-    if let Some(ref extends_value) = fragment.extends {
+    state.chain.insert(canonical.clone());
+
+    // Load the base configs first, in list order
+    for extends_value in &fragment.extends {
+        if is_remote_extends(extends_value) {
+            return Err(ConfigError::ExtendsRemote {
+                spec: extends_value.clone(),
+                from: path_str.clone(),
+            });
+        }
         let base_path = resolve_extends_path(extends_value, config_file_path)?;
 
         if !base_path.exists() {
@@ -331,16 +367,19 @@ fn load_config_with_extends(
         );
 
         // Recursively load the base config
-        load_config_with_extends(sourced_config, &base_path, visited, chain_source)?;
+        load_config_with_extends(sourced_config, &base_path, state, chain_source)?;
     }
 
-    // Merge this fragment on top (base config was already merged if present)
+    // Merge this fragment on top (base configs were already merged if present)
     // Strip the `extends` field since it's been consumed
     let mut fragment_for_merge = fragment;
-    fragment_for_merge.extends = None;
+    fragment_for_merge.extends.clear();
     sourced_config.merge(fragment_for_merge);
     sourced_config.loaded_files.push(path_str);
 
+    state.chain.shift_remove(&canonical);
+    state.merged.insert(canonical);
+
     Ok(())
 }
 
@@ -369,15 +408,45 @@ impl SourcedConfig<ConfigLoaded> {
             .retain(|rule| !self.global.enable.value.contains(rule));
 
         self.global.include.merge_from(fragment.global.include);
+        self.global.extensions.merge_from(fragment.global.extensions);
         self.global.exclude.merge_from(fragment.global.exclude);
+        self.global.extract_from.merge_from(fragment.global.extract_from);
+        self.global
+            .html_markdown_patterns
+            .merge_from(fragment.global.html_markdown_patterns);
         self.global
             .respect_gitignore
             .merge_from(fragment.global.respect_gitignore);
         self.global.line_length.merge_from(fragment.global.line_length);
+        self.global.length_mode.merge_from(fragment.global.length_mode);
+        self.global.ambiguous_width.merge_from(fragment.global.ambiguous_width);
         self.global.fixable.merge_from(fragment.global.fixable);
         self.global.unfixable.merge_from(fragment.global.unfixable);
         self.global.flavor.merge_from(fragment.global.flavor);
         self.global.force_exclude.merge_from(fragment.global.force_exclude);
+        self.global.nested_configs.merge_from(fragment.global.nested_configs);
+        self.global
+            .fix_max_iterations
+            .merge_from(fragment.global.fix_max_iterations);
+        self.global.unsafe_fixes.merge_from(fragment.global.unsafe_fixes);
+        if let Some(rule_timeout_ms_fragment) = fragment.global.rule_timeout_ms {
+            if let Some(ref mut rule_timeout_ms) = self.global.rule_timeout_ms {
+                rule_timeout_ms.merge_from(rule_timeout_ms_fragment);
+            } else {
+                self.global.rule_timeout_ms = Some(rule_timeout_ms_fragment);
+            }
+        }
+        if let Some(max_file_size_fragment) = fragment.global.max_file_size {
+            if let Some(ref mut max_file_size) = self.global.max_file_size {
+                max_file_size.merge_from(max_file_size_fragment);
+            } else {
+                self.global.max_file_size = Some(max_file_size_fragment);
+            }
+        }
+        self.global.large_file_mode.merge_from(fragment.global.large_file_mode);
+        self.global.draft_key.merge_from(fragment.global.draft_key);
+        self.global.draft_value.merge_from(fragment.global.draft_value);
+        self.global.draft_disable.merge_from(fragment.global.draft_disable);
 
         // Merge output_format if present
         if let Some(output_format_fragment) = fragment.global.output_format {
@@ -397,6 +466,24 @@ impl SourcedConfig<ConfigLoaded> {
             }
         }
 
+        // Merge docs_base_url if present
+        if let Some(docs_base_url_fragment) = fragment.global.docs_base_url {
+            if let Some(ref mut docs_base_url) = self.global.docs_base_url {
+                docs_base_url.merge_from(docs_base_url_fragment);
+            } else {
+                self.global.docs_base_url = Some(docs_base_url_fragment);
+            }
+        }
+
+        // Merge slugify if present
+        if let Some(slugify_fragment) = fragment.global.slugify {
+            if let Some(ref mut slugify) = self.global.slugify {
+                slugify.merge_from(slugify_fragment);
+            } else {
+                self.global.slugify = Some(slugify_fragment);
+            }
+        }
+
         // Merge cache if not default (only override when explicitly set)
         if fragment.global.cache.source != ConfigSource::Default {
             self.global.cache.merge_from(fragment.global.cache);
@@ -404,7 +491,11 @@ impl SourcedConfig<ConfigLoaded> {
 
         self.per_file_ignores.merge_from(fragment.per_file_ignores);
         self.per_file_flavor.merge_from(fragment.per_file_flavor);
+        self.overrides.merge_from(fragment.overrides);
+        self.profiles.merge_from(fragment.profiles);
         self.code_block_tools.merge_from(fragment.code_block_tools);
+        self.ci.merge_from(fragment.ci);
+        self.prose_styles.merge_from(fragment.prose_styles);
 
         // Merge rule configs
         for (rule_name, rule_fragment) in fragment.rules {
@@ -706,9 +797,9 @@ impl SourcedConfig<ConfigLoaded> {
 
         if filename == "pyproject.toml" || filename == ".rumdl.toml" || filename == "rumdl.toml" {
             // Use extends-aware loading for rumdl TOML configs
-            let mut visited = IndexSet::new();
+            let mut state = ExtendsState::default();
             let chain_source = source_from_filename(filename);
-            load_config_with_extends(sourced_config, path_obj, &mut visited, chain_source)?;
+            load_config_with_extends(sourced_config, path_obj, &mut state, chain_source)?;
         } else if MARKDOWNLINT_FILENAMES.contains(&filename)
             || path_str.ends_with(".json")
             || path_str.ends_with(".jsonc")
@@ -721,9 +812,9 @@ impl SourcedConfig<ConfigLoaded> {
             sourced_config.loaded_files.push(path_str);
         } else {
             // Try TOML with extends support
-            let mut visited = IndexSet::new();
+            let mut state = ExtendsState::default();
             let chain_source = source_from_filename(filename);
-            load_config_with_extends(sourced_config, path_obj, &mut visited, chain_source)?;
+            load_config_with_extends(sourced_config, path_obj, &mut state, chain_source)?;
         }
 
         Ok(())
@@ -770,13 +861,8 @@ impl SourcedConfig<ConfigLoaded> {
 
             // User config fallback also supports extends chains.
             // Use a uniform source across the chain so child overrides are determined by chain order.
-            let mut visited = IndexSet::new();
-            load_config_with_extends(
-                sourced_config,
-                &user_config_path,
-                &mut visited,
-                ConfigSource::UserConfig,
-            )?;
+            let mut state = ExtendsState::default();
+            load_config_with_extends(sourced_config, &user_config_path, &mut state, ConfigSource::UserConfig)?;
         } else {
             log::debug!("[rumdl-config] No user configuration file found");
         }
@@ -838,10 +924,10 @@ impl SourcedConfig<ConfigLoaded> {
                 sourced_config.project_root = Some(project_root);
 
                 // Use extends-aware loading for discovered configs
-                let mut visited = IndexSet::new();
+                let mut state = ExtendsState::default();
                 let root_filename = config_file.file_name().and_then(|n| n.to_str()).unwrap_or("");
                 let chain_source = source_from_filename(root_filename);
-                load_config_with_extends(&mut sourced_config, &config_file, &mut visited, chain_source)?;
+                load_config_with_extends(&mut sourced_config, &config_file, &mut state, chain_source)?;
             } else {
                 // No rumdl project config - try markdownlint config
                 log::debug!("[rumdl-config] No rumdl config found, checking markdownlint config");
@@ -940,7 +1026,11 @@ impl SourcedConfig<ConfigLoaded> {
             global: self.global,
             per_file_ignores: self.per_file_ignores,
             per_file_flavor: self.per_file_flavor,
+            overrides: self.overrides,
+            profiles: self.profiles,
             code_block_tools: self.code_block_tools,
+            ci: self.ci,
+            prose_styles: self.prose_styles,
             rules: self.rules,
             loaded_files: self.loaded_files,
             unknown_keys: self.unknown_keys,
@@ -976,7 +1066,11 @@ impl SourcedConfig<ConfigLoaded> {
             global: self.global,
             per_file_ignores: self.per_file_ignores,
             per_file_flavor: self.per_file_flavor,
+            overrides: self.overrides,
+            profiles: self.profiles,
             code_block_tools: self.code_block_tools,
+            ci: self.ci,
+            prose_styles: self.prose_styles,
             rules: self.rules,
             loaded_files: self.loaded_files,
             unknown_keys: self.unknown_keys,
@@ -1044,33 +1138,45 @@ impl SourcedConfig<ConfigLoaded> {
     pub fn load_sourced_for_path(
         config_path: &Path,
         project_root: &Path,
+    ) -> Result<SourcedConfig<ConfigLoaded>, ConfigError> {
+        Self::load_sourced_chain(std::slice::from_ref(&config_path.to_path_buf()), project_root)
+    }
+
+    /// Load several config files into one sourced config, outermost first, so
+    /// each file overrides the ones before it. Used for `nested-configs = "merge"`,
+    /// where a file's config is every config from the project root down to it.
+    pub fn load_sourced_chain(
+        config_paths: &[PathBuf],
+        project_root: &Path,
     ) -> Result<SourcedConfig<ConfigLoaded>, ConfigError> {
         let mut sourced_config = SourcedConfig {
             project_root: Some(project_root.to_path_buf()),
             ..SourcedConfig::default()
         };
 
-        let filename = config_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-        let path_str = config_path.display().to_string();
-
-        // Determine if this is a markdownlint config or rumdl config
-        let is_markdownlint = MARKDOWNLINT_CONFIG_FILES.contains(&filename)
-            || (filename != "pyproject.toml"
-                && filename != ".rumdl.toml"
-                && filename != "rumdl.toml"
-                && (path_str.ends_with(".json")
-                    || path_str.ends_with(".jsonc")
-                    || path_str.ends_with(".yaml")
-                    || path_str.ends_with(".yml")));
-
-        if is_markdownlint {
-            let fragment = parsers::load_from_markdownlint(&path_str)?;
-            sourced_config.merge(fragment);
-            sourced_config.loaded_files.push(path_str);
-        } else {
-            let mut visited = IndexSet::new();
-            let chain_source = source_from_filename(filename);
-            load_config_with_extends(&mut sourced_config, config_path, &mut visited, chain_source)?;
+        for config_path in config_paths {
+            let filename = config_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let path_str = config_path.display().to_string();
+
+            // Determine if this is a markdownlint config or rumdl config
+            let is_markdownlint = MARKDOWNLINT_CONFIG_FILES.contains(&filename)
+                || (filename != "pyproject.toml"
+                    && filename != ".rumdl.toml"
+                    && filename != "rumdl.toml"
+                    && (path_str.ends_with(".json")
+                        || path_str.ends_with(".jsonc")
+                        || path_str.ends_with(".yaml")
+                        || path_str.ends_with(".yml")));
+
+            if is_markdownlint {
+                let fragment = parsers::load_from_markdownlint(&path_str)?;
+                sourced_config.merge(fragment);
+                sourced_config.loaded_files.push(path_str);
+            } else {
+                let mut state = ExtendsState::default();
+                let chain_source = source_from_filename(filename);
+                load_config_with_extends(&mut sourced_config, config_path, &mut state, chain_source)?;
+            }
         }
 
         Ok(sourced_config)
@@ -1112,15 +1218,31 @@ impl From<SourcedConfig<ConfigValidated>> for Config {
             disable: sourced.global.disable.value,
             exclude: sourced.global.exclude.value,
             include: sourced.global.include.value,
+            extensions: sourced.global.extensions.value,
+            extract_from: sourced.global.extract_from.value,
+            html_markdown_patterns: sourced.global.html_markdown_patterns.value,
             respect_gitignore: sourced.global.respect_gitignore.value,
             line_length: sourced.global.line_length.value,
+            length_mode: sourced.global.length_mode.value,
+            ambiguous_width: sourced.global.ambiguous_width.value,
             output_format: sourced.global.output_format.as_ref().map(|v| v.value.clone()),
             fixable: sourced.global.fixable.value,
             unfixable: sourced.global.unfixable.value,
             flavor: sourced.global.flavor.value,
             force_exclude: sourced.global.force_exclude.value,
             cache_dir: sourced.global.cache_dir.as_ref().map(|v| v.value.clone()),
+            docs_base_url: sourced.global.docs_base_url.as_ref().map(|v| v.value.clone()),
+            slugify: sourced.global.slugify.as_ref().map(|v| v.value.clone()),
             cache: sourced.global.cache.value,
+            nested_configs: sourced.global.nested_configs.value,
+            fix_max_iterations: sourced.global.fix_max_iterations.value,
+            unsafe_fixes: sourced.global.unsafe_fixes.value,
+            rule_timeout_ms: sourced.global.rule_timeout_ms.as_ref().map(|v| v.value),
+            max_file_size: sourced.global.max_file_size.as_ref().map(|v| v.value),
+            large_file_mode: sourced.global.large_file_mode.value,
+            draft_key: sourced.global.draft_key.value,
+            draft_value: sourced.global.draft_value.value,
+            draft_disable: sourced.global.draft_disable.value,
             extend_enable: sourced.global.extend_enable.value,
             extend_disable: sourced.global.extend_disable.value,
             enable_is_explicit,
@@ -1131,11 +1253,16 @@ impl From<SourcedConfig<ConfigValidated>> for Config {
             global,
             per_file_ignores: sourced.per_file_ignores.value,
             per_file_flavor: sourced.per_file_flavor.value,
+            overrides: sourced.overrides.value,
+            profiles: sourced.profiles.value,
             code_block_tools: sourced.code_block_tools.value,
+            ci: sourced.ci.value,
+            prose_styles: sourced.prose_styles.value,
             rules,
             project_root: sourced.project_root,
             per_file_ignores_cache: Arc::new(OnceLock::new()),
             per_file_flavor_cache: Arc::new(OnceLock::new()),
+            overrides_cache: Arc::new(OnceLock::new()),
             canonical_project_root_cache: Arc::new(OnceLock::new()),
         };
 
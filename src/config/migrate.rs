@@ -0,0 +1,487 @@
+//! Upgrades config files written for older rumdl versions.
+//!
+//! Backs `rumdl migrate`: rewrites deprecated option names, moves sections
+//! that were nested under `[global]` to the top level, and turns markdownlint
+//! leftovers (a `[rules]` wrapper, `MD013 = false` toggles, snake_case
+//! option names) into their rumdl form. Edits go through `toml_edit`, so
+//! comments and formatting elsewhere in the file are kept.
+
+use toml_edit::{Array, DocumentMut, Item, Key, Table, TableLike, Value};
+
+use super::flavor::normalize_key;
+use super::global_keys::is_global_value_key;
+use super::registry::{RuleRegistry, default_registry};
+use super::types::ConfigError;
+
+/// Sections that belong at the top level rather than inside `[global]`
+const TOP_LEVEL_SECTIONS: &[&str] = &[
+    "per-file-ignores",
+    "per-file-flavor",
+    "overrides",
+    "code-block-tools",
+    "code-linters",
+    "ci",
+    "prose-styles",
+];
+
+/// Result of migrating one config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigMigration {
+    /// The migrated file content
+    pub content: String,
+    /// One line per change, in the order they were made
+    pub changes: Vec<String>,
+}
+
+impl ConfigMigration {
+    pub fn is_changed(&self) -> bool {
+        !self.changes.is_empty()
+    }
+}
+
+/// Migrate the content of a `.rumdl.toml`-style file, or of the
+/// `[tool.rumdl]` table when `pyproject` is set.
+pub fn migrate_config(content: &str, pyproject: bool) -> Result<ConfigMigration, ConfigError> {
+    let mut doc = content
+        .parse::<DocumentMut>()
+        .map_err(|e| ConfigError::ParseError(format!("Failed to parse TOML: {e}")))?;
+
+    let root = if pyproject {
+        doc.get_mut("tool")
+            .and_then(Item::as_table_mut)
+            .and_then(|tool| tool.get_mut("rumdl"))
+            .and_then(Item::as_table_mut)
+    } else {
+        Some(doc.as_table_mut())
+    };
+    let Some(root) = root else {
+        return Ok(ConfigMigration {
+            content: content.to_string(),
+            changes: Vec::new(),
+        });
+    };
+
+    let mut migrator = Migrator {
+        registry: default_registry(),
+        prefix: if pyproject { "tool.rumdl." } else { "" },
+        pyproject,
+        changes: Vec::new(),
+    };
+    migrator.remove_force_exclude(root);
+    migrator.move_global_sections(root);
+    migrator.flatten_rules_wrapper(root);
+    migrator.convert_rule_toggles(root);
+    migrator.rename_rule_options(root);
+
+    let changes = migrator.changes;
+    let content = if changes.is_empty() {
+        content.to_string()
+    } else {
+        doc.to_string()
+    };
+    Ok(ConfigMigration { content, changes })
+}
+
+struct Migrator {
+    registry: &'static RuleRegistry,
+    /// Header prefix of the rumdl table, for change descriptions
+    prefix: &'static str,
+    /// In `[tool.rumdl]` the root table itself holds the global settings
+    pyproject: bool,
+    changes: Vec<String>,
+}
+
+impl Migrator {
+    fn section(&self, name: &str) -> String {
+        format!("[{}{name}]", self.prefix)
+    }
+
+    fn root_name(&self) -> String {
+        if self.pyproject {
+            "[tool.rumdl]".to_string()
+        } else {
+            "the top level".to_string()
+        }
+    }
+
+    /// `force-exclude` has no effect: exclude patterns are always respected
+    fn remove_force_exclude(&mut self, root: &mut Table) {
+        let mut removed = Vec::new();
+        if let Some(key) = find_key(root, "force-exclude") {
+            remove_keeping_comments(root, &key);
+            removed.push(self.root_name());
+        }
+        if let Some(global) = root.get_mut("global").and_then(Item::as_table_like_mut)
+            && let Some(key) = find_key_like(global, "force-exclude")
+        {
+            remove_keeping_comments(global, &key);
+            removed.push(self.section("global"));
+        }
+        for place in removed {
+            self.changes.push(format!(
+                "Removed force-exclude from {place}: exclude patterns are always respected"
+            ));
+        }
+    }
+
+    /// Tables written inside `[global]` are ignored there; move them to
+    /// their own top-level section, merging with one that already exists
+    fn move_global_sections(&mut self, root: &mut Table) {
+        let Some(global) = root.get_mut("global").and_then(Item::as_table_mut) else {
+            return;
+        };
+        let movable: Vec<(String, String)> = global
+            .iter()
+            .filter(|(_, item)| item.is_table_like())
+            .filter_map(|(key, _)| {
+                let norm = normalize_key(key);
+                if TOP_LEVEL_SECTIONS.contains(&norm.as_str()) {
+                    Some((key.to_string(), norm))
+                } else {
+                    self.registry
+                        .resolve_rule_name(key)
+                        .map(|_| (key.to_string(), key.to_string()))
+                }
+            })
+            .collect();
+
+        let mut moved = Vec::new();
+        for (key, target) in movable {
+            if let Some(item) = global.remove(&key) {
+                moved.push((key, target, item));
+            }
+        }
+        for (key, target, item) in moved {
+            self.changes.push(format!(
+                "Moved {} to {}",
+                self.section(&format!("global.{key}")),
+                self.section(&target)
+            ));
+            insert_or_merge(root, &target, item);
+        }
+    }
+
+    /// markdownlint ports group rules under `[rules]`; rumdl uses flat `[MDxxx]` sections
+    fn flatten_rules_wrapper(&mut self, root: &mut Table) {
+        let Some(rules) = root.get_mut("rules").and_then(Item::as_table_like_mut) else {
+            return;
+        };
+        let rule_keys: Vec<(String, String)> = rules
+            .iter()
+            .filter(|(_, item)| item.is_table_like())
+            .filter_map(|(key, _)| self.registry.resolve_rule_name(key).map(|rule| (key.to_string(), rule)))
+            .collect();
+
+        let mut moved = Vec::new();
+        for (key, rule) in rule_keys {
+            if let Some(item) = rules.remove(&key) {
+                moved.push((key, rule, item));
+            }
+        }
+        let now_empty = rules.is_empty();
+        if now_empty {
+            root.remove("rules");
+        }
+        for (key, rule, item) in moved {
+            self.changes.push(format!(
+                "Moved {} to {}",
+                self.section(&format!("rules.{key}")),
+                self.section(&rule)
+            ));
+            insert_or_merge(root, &rule, item);
+        }
+    }
+
+    /// `MD013 = false` / `no-inline-html = true` toggles are ignored by rumdl;
+    /// turn them into `disable` / `extend-enable` entries
+    fn convert_rule_toggles(&mut self, root: &mut Table) {
+        let mut toggles = Vec::new();
+        for in_global in [false, true] {
+            let table: Option<&mut dyn TableLike> = if in_global {
+                root.get_mut("global").and_then(Item::as_table_like_mut)
+            } else {
+                Some(root as &mut dyn TableLike)
+            };
+            let Some(table) = table else {
+                continue;
+            };
+            let keys: Vec<(String, String, bool)> = table
+                .iter()
+                .filter_map(|(key, item)| {
+                    let enabled = item.as_bool()?;
+                    if is_global_value_key(&normalize_key(key)) {
+                        return None;
+                    }
+                    let rule = self.registry.resolve_rule_name(key)?;
+                    Some((key.to_string(), rule, enabled))
+                })
+                .collect();
+            for (key, rule, enabled) in keys {
+                remove_keeping_comments(table, &key);
+                toggles.push((key, rule, enabled));
+            }
+        }
+
+        for (key, rule, enabled) in toggles {
+            let list = if enabled { "extend-enable" } else { "disable" };
+            let place = self.add_to_rule_list(root, list, &rule);
+            self.changes.push(format!(
+                "Replaced {key} = {enabled} with \"{rule}\" in {list} of {place}"
+            ));
+        }
+    }
+
+    /// Add `rule` to the `disable` or `extend-enable` list, preferring the
+    /// table that already has the list; returns that table's header
+    fn add_to_rule_list(&self, root: &mut Table, list: &str, rule: &str) -> String {
+        let global_has_list = root
+            .get("global")
+            .and_then(Item::as_table_like)
+            .is_some_and(|global| find_key_like(global, list).is_some());
+        let use_root = !global_has_list && (self.pyproject || find_key(root, list).is_some());
+
+        let (table, place): (&mut dyn TableLike, String) = if use_root {
+            (root, self.root_name())
+        } else {
+            let global = root.entry("global").or_insert_with(|| {
+                let mut table = Table::new();
+                // Place a new [global] before the rule sections
+                table.set_position(Some(0));
+                Item::Table(table)
+            });
+            let Some(global) = global.as_table_like_mut() else {
+                return self.section("global");
+            };
+            (global, self.section("global"))
+        };
+
+        let key = find_key_like(table, list).unwrap_or_else(|| list.to_string());
+        let item = table
+            .entry(&key)
+            .or_insert_with(|| Item::Value(Value::Array(Array::new())));
+        if let Some(array) = item.as_array_mut()
+            && !array.iter().any(|existing| existing.as_str() == Some(rule))
+        {
+            array.push(rule);
+        }
+        place
+    }
+
+    /// Rename deprecated and snake_case options in rule sections, including
+    /// those nested in `[overrides]` patterns
+    fn rename_rule_options(&mut self, root: &mut Table) {
+        let rule_keys: Vec<(String, String)> = root
+            .iter()
+            .filter(|(_, item)| item.is_table_like())
+            .filter_map(|(key, _)| self.registry.resolve_rule_name(key).map(|rule| (key.to_string(), rule)))
+            .collect();
+        for (key, rule) in rule_keys {
+            let section = self.section(&key);
+            if let Some(table) = root.get_mut(&key).and_then(Item::as_table_like_mut) {
+                self.rename_options(table, &rule, &section);
+            }
+        }
+
+        let Some(overrides) = root.get_mut("overrides").and_then(Item::as_table_like_mut) else {
+            return;
+        };
+        for (pattern, layer) in overrides.iter_mut() {
+            let Some(layer) = layer.as_table_like_mut() else {
+                continue;
+            };
+            for (key, rule_item) in layer.iter_mut() {
+                let Some(rule) = self.registry.resolve_rule_name(key.get()) else {
+                    continue;
+                };
+                if let Some(table) = rule_item.as_table_like_mut() {
+                    let section = format!("{} '{}' {}", self.section("overrides"), pattern.get(), key.get());
+                    self.rename_options(table, &rule, &section);
+                }
+            }
+        }
+    }
+
+    fn rename_options(&mut self, table: &mut dyn TableLike, rule: &str, section: &str) {
+        let Some(valid_keys) = self.registry.config_keys_for(rule) else {
+            return;
+        };
+        let keys: Vec<String> = table.iter().map(|(key, _)| key.to_string()).collect();
+        let mut renames = Vec::new();
+        for key in &keys {
+            if let Some(replacement) = self.registry.deprecated_key_replacement(rule, key) {
+                renames.push((key.clone(), replacement.to_string()));
+                continue;
+            }
+            let norm = normalize_key(key);
+            if norm != *key && valid_keys.contains(&norm) {
+                renames.push((key.clone(), norm));
+            }
+        }
+        if renames.is_empty() {
+            return;
+        }
+
+        // Re-insert every entry in its original order so renamed keys stay in place
+        let mut entries = Vec::new();
+        for key in &keys {
+            let decor = table.key(key).map(|k| k.leaf_decor().clone()).unwrap_or_default();
+            if let Some(item) = table.remove(key) {
+                entries.push((key.clone(), decor, item));
+            }
+        }
+        let existing: Vec<String> = keys.iter().map(|key| normalize_key(key)).collect();
+        for (key, decor, item) in entries {
+            let new_key = match renames.iter().find(|(old, _)| *old == key) {
+                Some((_, new)) if existing.contains(new) && normalize_key(&key) != *new => {
+                    self.changes
+                        .push(format!("Removed {key} from {section}: {new} is already set"));
+                    continue;
+                }
+                Some((_, new)) => {
+                    self.changes.push(format!("Renamed {key} to {new} in {section}"));
+                    new.clone()
+                }
+                None => key,
+            };
+            table
+                .entry_format(&Key::new(new_key).with_leaf_decor(decor))
+                .or_insert(item);
+        }
+    }
+}
+
+/// The key of `table` that normalizes to `name`, if any
+fn find_key(table: &Table, name: &str) -> Option<String> {
+    find_key_like(table, name)
+}
+
+fn find_key_like(table: &dyn TableLike, name: &str) -> Option<String> {
+    table
+        .iter()
+        .map(|(key, _)| key)
+        .find(|key| normalize_key(key) == name)
+        .map(ToString::to_string)
+}
+
+/// Remove a value, moving the comments above it to the entry that follows
+fn remove_keeping_comments(table: &mut dyn TableLike, key: &str) {
+    let keys: Vec<String> = table.iter().map(|(k, _)| k.to_string()).collect();
+    let comments = table
+        .key(key)
+        .and_then(|k| k.leaf_decor().prefix())
+        .and_then(|prefix| prefix.as_str())
+        .filter(|prefix| prefix.contains('#'))
+        .map(ToString::to_string);
+    table.remove(key);
+
+    let (Some(comments), Some(next)) = (comments, keys.iter().skip_while(|k| *k != key).nth(1)) else {
+        return;
+    };
+    if let Some(Item::Table(next_table)) = table.get_mut(next) {
+        let decor = next_table.decor_mut();
+        let existing = prefix_str(decor.prefix());
+        decor.set_prefix(format!("{comments}{existing}"));
+    } else if let Some(mut next_key) = table.key_mut(next) {
+        let existing = prefix_str(next_key.leaf_decor().prefix());
+        next_key.leaf_decor_mut().set_prefix(format!("{comments}{existing}"));
+    }
+}
+
+fn prefix_str(prefix: Option<&toml_edit::RawString>) -> String {
+    prefix.and_then(|raw| raw.as_str()).unwrap_or_default().to_string()
+}
+
+/// Insert a moved section, keeping the entries of an existing one
+fn insert_or_merge(root: &mut Table, key: &str, item: Item) {
+    let Some(existing) = root.get_mut(key).and_then(Item::as_table_like_mut) else {
+        root.insert(key, item);
+        return;
+    };
+    let Some(moved) = item.as_table_like() else {
+        return;
+    };
+    for (entry_key, entry) in moved.iter() {
+        if !existing.contains_key(entry_key) {
+            existing.insert(entry_key, entry.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(content: &str) -> ConfigMigration {
+        migrate_config(content, false).unwrap()
+    }
+
+    #[test]
+    fn test_up_to_date_config_is_unchanged() {
+        let content =
+            "# Project config\n[global]\ndisable = [\"MD033\"]\n\n[MD013]\nline-length = 100\nreflow = true\n";
+        let migration = migrate(content);
+        assert!(!migration.is_changed());
+        assert_eq!(migration.content, content);
+    }
+
+    #[test]
+    fn test_renames_deprecated_and_snake_case_options_in_place() {
+        let content = "[MD013]\nline_length = 100 # wide\nenable_reflow = true\ncode-blocks = false\n";
+        let migration = migrate(content);
+        assert_eq!(
+            migration.content,
+            "[MD013]\nline-length = 100 # wide\nreflow = true\ncode-blocks = false\n"
+        );
+        assert_eq!(
+            migration.changes,
+            [
+                "Renamed line_length to line-length in [MD013]",
+                "Renamed enable_reflow to reflow in [MD013]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drops_deprecated_option_when_replacement_is_set() {
+        let migration = migrate("[MD013]\nreflow = false\nenable-reflow = true\n");
+        assert_eq!(migration.content, "[MD013]\nreflow = false\n");
+        assert_eq!(
+            migration.changes,
+            ["Removed enable-reflow from [MD013]: reflow is already set"]
+        );
+    }
+
+    #[test]
+    fn test_moves_sections_out_of_global() {
+        let content = "[global]\nforce-exclude = true\nline-length = 100\n\n[global.per-file-ignores]\n\"README.md\" = [\"MD033\"]\n";
+        let migration = migrate(content);
+        assert_eq!(
+            migration.content,
+            "[global]\nline-length = 100\n\n[per-file-ignores]\n\"README.md\" = [\"MD033\"]\n"
+        );
+        assert_eq!(migration.changes.len(), 2);
+    }
+
+    #[test]
+    fn test_rewrites_markdownlint_leftovers() {
+        let content = "no-inline-html = false\nMD044 = true\n\n[rules.no-trailing-spaces]\nbr_spaces = 3\n";
+        let migration = migrate(content);
+        assert_eq!(
+            migration.content,
+            "[global]\ndisable = [\"MD033\"]\nextend-enable = [\"MD044\"]\n\n[MD009]\nbr-spaces = 3\n"
+        );
+        let parsed: toml::Value = toml::from_str(&migration.content).unwrap();
+        assert_eq!(parsed["MD009"]["br-spaces"].as_integer(), Some(3));
+    }
+
+    #[test]
+    fn test_pyproject_uses_tool_rumdl_table() {
+        let content = "[project]\nname = \"x\"\n\n[tool.rumdl]\ndisable = [\"MD041\"]\nMD013 = false\n\n[tool.rumdl.MD007]\nstart_indented = true\n";
+        let migration = migrate_config(content, true).unwrap();
+        assert_eq!(
+            migration.content,
+            "[project]\nname = \"x\"\n\n[tool.rumdl]\ndisable = [\"MD041\", \"MD013\"]\n\n[tool.rumdl.MD007]\nstart-indented = true\n"
+        );
+        assert!(!migrate_config("[project]\nname = \"x\"\n", true).unwrap().is_changed());
+    }
+}
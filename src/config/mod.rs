@@ -17,9 +17,15 @@ pub(crate) use loading::rumdl_configs_in_dir;
 pub mod registry;
 pub use registry::*;
 
+pub mod migrate;
+pub use migrate::{ConfigMigration, migrate_config};
+
 pub mod validation;
 pub use validation::*;
 
+pub mod analysis;
+pub use analysis::config_conflicts;
+
 pub mod global_keys;
 pub use global_keys::is_global_value_key;
 
@@ -5,7 +5,7 @@ use toml_edit::DocumentMut;
 
 use super::flavor::{MarkdownFlavor, normalize_key, warn_comma_without_brace_in_pattern};
 use super::source_tracking::{ConfigSource, SourcedConfigFragment, SourcedValue};
-use super::types::ConfigError;
+use super::types::{ConfigError, ConfigOverride, ExtendsValue};
 use super::validation::to_relative_display_path;
 
 /// Parses pyproject.toml content and extracts the [tool.rumdl] section if present.
@@ -27,9 +27,9 @@ pub(super) fn parse_pyproject_toml(
     if let Some(rumdl_config) = doc.get("tool").and_then(|t| t.get("rumdl"))
         && let Some(rumdl_table) = rumdl_config.as_table()
         && let Some(extends_val) = rumdl_table.get("extends")
-        && let Ok(extends_str) = String::deserialize(extends_val.clone())
     {
-        fragment.extends = Some(extends_str);
+        let extends = ExtendsValue::deserialize(extends_val.clone()).map_err(|_| invalid_extends(&display_path))?;
+        fragment.extends = extends.paths().to_vec();
     }
 
     // 1. Handle [tool.rumdl] and [tool.rumdl.global] sections
@@ -137,6 +137,65 @@ pub(super) fn parse_pyproject_toml(
                 .push_override(per_file_map, source, file.clone());
         }
 
+        // --- Extract [overrides] configurations ---
+        if let Some(overrides_table) = rumdl_table.get("overrides").and_then(|v| v.as_table()) {
+            fragment.overrides.push_override(
+                parse_overrides_table(overrides_table, registry, &display_path),
+                source,
+                file.clone(),
+            );
+        }
+
+        // --- Extract [tool.rumdl.profiles] ---
+        if let Some(profiles_table) = rumdl_table.get("profiles").and_then(|v| v.as_table()) {
+            fragment.profiles.push_override(
+                parse_profiles_table(profiles_table, &display_path),
+                source,
+                file.clone(),
+            );
+        }
+
+        // --- Extract [tool.rumdl.ci] exit-code policy ---
+        if let Some(ci_value) = rumdl_table.get("ci") {
+            match super::types::CiConfig::deserialize(ci_value.clone()) {
+                Ok(ci) => fragment.ci.push_override(ci, source, file.clone()),
+                // Reported by validation, since a policy silently ignored would let CI pass
+                Err(e) => fragment
+                    .unknown_keys
+                    .push(("[ci]".to_string(), e.to_string(), Some(path.to_string()))),
+            }
+        }
+
+        // --- Extract [tool.rumdl.prose-styles] ---
+        if let Some(prose_styles_value) = rumdl_table.get("prose-styles") {
+            match crate::prose_styles::ProseStylesConfig::deserialize(prose_styles_value.clone()) {
+                Ok(prose_styles) => fragment.prose_styles.push_override(prose_styles, source, file.clone()),
+                Err(e) => {
+                    fragment
+                        .unknown_keys
+                        .push(("[prose-styles]".to_string(), e.to_string(), Some(path.to_string())))
+                }
+            }
+        }
+
+        // --- Extract [tool.rumdl.code-linters] ---
+        if let Some(linters_value) = rumdl_table.get("code-linters") {
+            match BTreeMap::<String, Vec<crate::code_block_tools::CodeLinter>>::deserialize(linters_value.clone()) {
+                Ok(linters) => {
+                    let mut cbt_config = fragment.code_block_tools.value.clone();
+                    cbt_config.add_code_linters(linters);
+                    fragment
+                        .code_block_tools
+                        .push_override(cbt_config, source, file.clone());
+                }
+                Err(e) => {
+                    fragment
+                        .unknown_keys
+                        .push(("[code-linters]".to_string(), e.to_string(), Some(path.to_string())))
+                }
+            }
+        }
+
         // --- Extract rule-specific configurations ---
         for (key, value) in rumdl_table {
             let norm_rule_key = normalize_key(key);
@@ -147,11 +206,38 @@ pub(super) fn parse_pyproject_toml(
                 "enable",
                 "disable",
                 "include",
+                "extensions",
                 "exclude",
+                "extract_from",
+                "extract-from",
+                "html_markdown_patterns",
+                "html-markdown-patterns",
                 "respect_gitignore",
                 "respect-gitignore",
                 "force_exclude",
                 "force-exclude",
+                "nested_configs",
+                "nested-configs",
+                "fix_max_iterations",
+                "fix-max-iterations",
+                "unsafe_fixes",
+                "unsafe-fixes",
+                "rule_timeout_ms",
+                "rule-timeout-ms",
+                "max_file_size",
+                "max-file-size",
+                "large_file_mode",
+                "large-file-mode",
+                "length_mode",
+                "length-mode",
+                "ambiguous_width",
+                "ambiguous-width",
+                "draft_key",
+                "draft-key",
+                "draft_value",
+                "draft-value",
+                "draft_disable",
+                "draft-disable",
                 "output_format",
                 "output-format",
                 "fixable",
@@ -160,10 +246,20 @@ pub(super) fn parse_pyproject_toml(
                 "per_file_ignores",
                 "per-file-flavor",
                 "per_file_flavor",
+                "overrides",
+                "profiles",
+                "ci",
+                "prose-styles",
+                "prose_styles",
+                "code-linters",
+                "code_linters",
                 "global",
                 "flavor",
                 "cache_dir",
                 "cache-dir",
+                "docs_base_url",
+                "docs-base-url",
+                "slugify",
                 "cache",
                 "extend-enable",
                 "extend_enable",
@@ -294,27 +390,125 @@ pub(super) fn parse_pyproject_toml(
     }
 
     // Only return Some(fragment) if any config was found
-    let has_any = fragment.extends.is_some()
+    let has_any = !fragment.extends.is_empty()
         || !fragment.global.enable.value.is_empty()
         || !fragment.global.disable.value.is_empty()
         || !fragment.global.extend_enable.value.is_empty()
         || !fragment.global.extend_disable.value.is_empty()
         || !fragment.global.include.value.is_empty()
+        || fragment.global.extensions.source != ConfigSource::Default
         || !fragment.global.exclude.value.is_empty()
+        || !fragment.global.extract_from.value.is_empty()
+        || !fragment.global.html_markdown_patterns.value.is_empty()
         || !fragment.global.fixable.value.is_empty()
         || !fragment.global.unfixable.value.is_empty()
         || fragment.global.output_format.is_some()
         || fragment.global.cache_dir.is_some()
+        || fragment.global.docs_base_url.is_some()
+        || fragment.global.slugify.is_some()
         || fragment.global.cache.source != ConfigSource::Default
         || fragment.global.flavor.source != ConfigSource::Default
         || fragment.global.respect_gitignore.source != ConfigSource::Default
         || fragment.global.force_exclude.source != ConfigSource::Default
+        || fragment.global.nested_configs.source != ConfigSource::Default
+        || fragment.global.fix_max_iterations.source != ConfigSource::Default
+        || fragment.global.unsafe_fixes.source != ConfigSource::Default
+        || fragment.global.rule_timeout_ms.is_some()
+        || fragment.global.max_file_size.is_some()
+        || fragment.global.large_file_mode.source != ConfigSource::Default
+        || fragment.global.length_mode.source != ConfigSource::Default
+        || fragment.global.ambiguous_width.source != ConfigSource::Default
+        || fragment.global.draft_key.source != ConfigSource::Default
+        || fragment.global.draft_value.source != ConfigSource::Default
+        || !fragment.global.draft_disable.value.is_empty()
         || !fragment.per_file_ignores.value.is_empty()
         || !fragment.per_file_flavor.value.is_empty()
+        || !fragment.overrides.value.is_empty()
+        || !fragment.profiles.value.is_empty()
+        || fragment.code_block_tools.source != ConfigSource::Default
         || !fragment.rules.is_empty();
     if has_any { Ok(Some(fragment)) } else { Ok(None) }
 }
 
+/// Parses a `[profiles]` table: profile name → config-shaped table. Contents are
+/// validated when a profile is selected, so only the shape is checked here.
+fn parse_profiles_table(profiles_table: &toml::value::Table, display_path: &str) -> IndexMap<String, toml::Table> {
+    let mut profiles = IndexMap::new();
+    for (name, value) in profiles_table {
+        match value.as_table() {
+            Some(table) => {
+                profiles.insert(name.clone(), table.clone());
+            }
+            None => log::warn!("[WARN] Expected table for profile '{name}' in {display_path}, found {value:?}"),
+        }
+    }
+    profiles
+}
+
+/// Parses an `[overrides]` table: glob pattern → rule sections plus `disable`
+/// and `extend-enable` lists. Pattern order is preserved so later patterns
+/// win when several match a file.
+fn parse_overrides_table(
+    overrides_table: &toml::value::Table,
+    registry: &super::registry::RuleRegistry,
+    display_path: &str,
+) -> IndexMap<String, ConfigOverride> {
+    let resolve = |name: &str| registry.resolve_rule_name(name).unwrap_or_else(|| normalize_key(name));
+    let mut overrides = IndexMap::new();
+
+    for (pattern, layer_value) in overrides_table {
+        warn_comma_without_brace_in_pattern(pattern, display_path);
+        let Some(layer_table) = layer_value.as_table() else {
+            log::warn!(
+                "[WARN] Expected table for overrides pattern '{pattern}' in {display_path}, found {layer_value:?}"
+            );
+            continue;
+        };
+
+        let mut layer = ConfigOverride::default();
+        for (key, value) in layer_table {
+            match normalize_key(key).as_str() {
+                list_key @ ("disable" | "extend-enable") => match Vec::<String>::deserialize(value.clone()) {
+                    Ok(rules) => {
+                        let rules = rules.iter().map(|rule| resolve(rule));
+                        if list_key == "disable" {
+                            layer.disable.extend(rules);
+                        } else {
+                            layer.extend_enable.extend(rules);
+                        }
+                    }
+                    Err(_) => log::warn!(
+                        "[WARN] Expected array for '{list_key}' in overrides pattern '{pattern}' in {display_path}"
+                    ),
+                },
+                _ => {
+                    let (Some(rule_name), Some(rule_table)) = (registry.resolve_rule_name(key), value.as_table())
+                    else {
+                        log::warn!("[WARN] Unknown key '{key}' in overrides pattern '{pattern}' in {display_path}");
+                        continue;
+                    };
+                    let rule_config = layer.rules.entry(rule_name.clone()).or_default();
+                    for (rk, rv) in rule_table {
+                        let norm_rk = normalize_key(rk);
+                        if norm_rk != "severity" {
+                            rule_config.values.insert(norm_rk, rv.clone());
+                        } else if let Ok(severity) = crate::rule::Severity::deserialize(rv.clone()) {
+                            rule_config.severity = Some(severity);
+                        } else {
+                            log::warn!(
+                                "[WARN] Invalid severity {rv} for rule {rule_name} in overrides pattern '{pattern}' in {display_path}. Valid values: error, warning, info"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        overrides.insert(pattern.clone(), layer);
+    }
+
+    overrides
+}
+
 /// Applies a rule configuration table (in standard `toml` format) into the fragment.
 /// Used for rule sections parsed from pyproject.toml, including `[tool.rumdl.MDxxx]`
 /// and `[tool.rumdl.rules.MDxxx]` forms.
@@ -339,7 +533,7 @@ fn apply_rule_table_toml(
                 }
             } else if let Some(severity_str) = rv.as_str() {
                 log::warn!(
-                    "[WARN] Invalid severity '{severity_str}' for rule {norm_rule_name} in {display_path}. Valid values: error, warning"
+                    "[WARN] Invalid severity '{severity_str}' for rule {norm_rule_name} in {display_path}. Valid values: error, warning, info"
                 );
             }
             continue;
@@ -427,11 +621,16 @@ pub(super) fn parse_rumdl_toml(
     let file = Some(path.to_string());
 
     // Parse top-level `extends` key (not inside any section)
-    if let Some(extends_item) = doc.get("extends")
-        && let Some(extends_val) = extends_item.as_value()
-        && let Some(extends_str) = extends_val.as_str()
-    {
-        fragment.extends = Some(extends_str.to_string());
+    if let Some(extends_item) = doc.get("extends") {
+        let paths = match extends_item.as_value() {
+            Some(toml_edit::Value::String(path)) => Some(vec![path.value().clone()]),
+            Some(toml_edit::Value::Array(array)) => array
+                .iter()
+                .map(|item| item.as_str().map(ToString::to_string))
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        };
+        fragment.extends = paths.ok_or_else(|| invalid_extends(&display_path))?;
     }
 
     // Use the lazily-initialized default registry for alias resolution and schema validation
@@ -533,6 +732,48 @@ pub(super) fn parse_rumdl_toml(
             .push_override(per_file_map, source, file.clone());
     }
 
+    // Handle [overrides] section
+    if let Some(overrides_item) = doc.get("overrides")
+        && let Some(overrides_table) = overrides_item.as_table_like()
+    {
+        let mut overrides_doc = toml_edit::DocumentMut::new();
+        for (pattern, value) in overrides_table.iter() {
+            overrides_doc[pattern] = value.clone();
+        }
+        match toml::from_str::<toml::value::Table>(&overrides_doc.to_string()) {
+            Ok(table) => {
+                fragment.overrides.push_override(
+                    parse_overrides_table(&table, registry, &display_path),
+                    source,
+                    file.clone(),
+                );
+            }
+            Err(e) => {
+                log::warn!("[WARN] Failed to parse [overrides] section in {display_path}: {e}");
+            }
+        }
+    }
+
+    // Handle [profiles] section
+    if let Some(profiles_item) = doc.get("profiles")
+        && let Some(profiles_table) = profiles_item.as_table_like()
+    {
+        let mut profiles_doc = toml_edit::DocumentMut::new();
+        for (name, value) in profiles_table.iter() {
+            profiles_doc[name] = value.clone();
+        }
+        match toml::from_str::<toml::value::Table>(&profiles_doc.to_string()) {
+            Ok(table) => {
+                fragment
+                    .profiles
+                    .push_override(parse_profiles_table(&table, &display_path), source, file.clone());
+            }
+            Err(e) => {
+                log::warn!("[WARN] Failed to parse [profiles] section in {display_path}: {e}");
+            }
+        }
+    }
+
     // Handle [code-block-tools] section
     if let Some(cbt_item) = doc.get("code-block-tools")
         && let Some(cbt_table) = cbt_item.as_table()
@@ -556,13 +797,77 @@ pub(super) fn parse_rumdl_toml(
         }
     }
 
+    // Handle [code-linters] section: lint tools folded into this file's [code-block-tools]
+    if let Some(linters_item) = doc.get("code-linters")
+        && let Some(linters_table) = linters_item.as_table()
+    {
+        let mut linters_doc = toml_edit::DocumentMut::new();
+        for (key, value) in linters_table {
+            linters_doc[key] = value.clone();
+        }
+        match toml::from_str::<BTreeMap<String, Vec<crate::code_block_tools::CodeLinter>>>(&linters_doc.to_string()) {
+            Ok(linters) => {
+                let mut cbt_config = fragment.code_block_tools.value.clone();
+                cbt_config.add_code_linters(linters);
+                fragment
+                    .code_block_tools
+                    .push_override(cbt_config, source, file.clone());
+            }
+            Err(e) => {
+                log::warn!("[WARN] Failed to parse [code-linters] section in {display_path}: {e}");
+            }
+        }
+    }
+
+    // Handle [ci] section
+    if let Some(ci_item) = doc.get("ci")
+        && let Some(ci_table) = ci_item.as_table()
+    {
+        let mut ci_doc = toml_edit::DocumentMut::new();
+        for (key, value) in ci_table {
+            ci_doc[key] = value.clone();
+        }
+        match toml::from_str::<super::types::CiConfig>(&ci_doc.to_string()) {
+            Ok(ci) => fragment.ci.push_override(ci, source, file.clone()),
+            // Reported by validation, since a policy silently ignored would let CI pass
+            Err(e) => fragment.unknown_keys.push((
+                "[ci]".to_string(),
+                e.message().trim().to_string(),
+                Some(path.to_string()),
+            )),
+        }
+    }
+
+    // Handle [prose-styles] section
+    if let Some(prose_styles_item) = doc.get("prose-styles")
+        && let Some(prose_styles_table) = prose_styles_item.as_table()
+    {
+        let mut prose_styles_doc = toml_edit::DocumentMut::new();
+        for (key, value) in prose_styles_table {
+            prose_styles_doc[key] = value.clone();
+        }
+        match toml::from_str::<crate::prose_styles::ProseStylesConfig>(&prose_styles_doc.to_string()) {
+            Ok(prose_styles) => fragment.prose_styles.push_override(prose_styles, source, file.clone()),
+            Err(e) => fragment.unknown_keys.push((
+                "[prose-styles]".to_string(),
+                e.message().trim().to_string(),
+                Some(path.to_string()),
+            )),
+        }
+    }
+
     // Rule-specific: all other top-level tables
     for (key, item) in doc.iter() {
         // Skip known special sections and top-level value keys (already handled above)
         if key == "global"
             || key == "per-file-ignores"
             || key == "per-file-flavor"
+            || key == "overrides"
+            || key == "profiles"
             || key == "code-block-tools"
+            || key == "code-linters"
+            || key == "ci"
+            || key == "prose-styles"
             || key == "extends"
         {
             continue;
@@ -630,6 +935,15 @@ pub(super) fn parse_rumdl_toml(
     Ok(fragment)
 }
 
+/// Converts a `toml_edit` table item into a `toml::Value`, keeping its keys as written
+fn toml_edit_table_value(item: &toml_edit::Item) -> Option<toml::Value> {
+    let mut doc = toml_edit::DocumentMut::new();
+    doc["value"] = item.clone();
+    toml::from_str::<toml::value::Table>(&doc.to_string())
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+}
+
 /// Applies a rule configuration table (in toml_edit format) into the fragment.
 /// Used for both `[MDxxx]` and `[rules.MDxxx]` top-level table forms in rumdl.toml.
 fn apply_rule_table_toml_edit(
@@ -658,7 +972,7 @@ fn apply_rule_table_toml_edit(
                     }
                     Err(_) => {
                         log::warn!(
-                            "[WARN] Invalid severity '{severity_str}' for rule {norm_rule_name} in {display_path}. Valid values: error, warning"
+                            "[WARN] Invalid severity '{severity_str}' for rule {norm_rule_name} in {display_path}. Valid values: error, warning, info"
                         );
                     }
                 }
@@ -693,6 +1007,17 @@ fn apply_rule_table_toml_edit(
                 }
                 Some(toml::Value::Array(values))
             }
+            // Only options whose schema is a table (such as MD104 `templates`) take
+            // table values; their keys are data, not option names
+            Some(toml_edit::Value::InlineTable(_)) | None
+                if (rv_item.is_inline_table() || rv_item.is_table())
+                    && matches!(
+                        super::registry::default_registry().expected_value_for(norm_rule_name, &norm_rk),
+                        Some(toml::Value::Table(_))
+                    ) =>
+            {
+                toml_edit_table_value(rv_item)
+            }
             Some(toml_edit::Value::InlineTable(_)) => {
                 log::warn!(
                     "[WARN] Skipping inline table value for key '{norm_rule_name}.{norm_rk}' in {display_path}. Table conversion not yet fully implemented in parser."
@@ -716,6 +1041,11 @@ fn apply_rule_table_toml_edit(
     }
 }
 
+/// Error for an `extends` value that is neither a string nor a list of strings
+fn invalid_extends(display_path: &str) -> ConfigError {
+    ConfigError::ParseError(format!("{display_path}: `extends` must be a path or a list of paths"))
+}
+
 /// Loads and converts a markdownlint config file (.json or .yaml) into a SourcedConfigFragment.
 pub(super) fn load_from_markdownlint(path: &str) -> Result<SourcedConfigFragment, ConfigError> {
     let display_path = to_relative_display_path(path);
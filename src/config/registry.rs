@@ -30,6 +30,8 @@ pub struct RuleRegistry {
     pub rule_schemas: std::collections::BTreeMap<String, toml::map::Map<String, toml::Value>>,
     /// Map of rule name to config key aliases
     pub rule_aliases: std::collections::BTreeMap<String, std::collections::HashMap<String, String>>,
+    /// Map of rule name to its deprecated config keys and their replacements
+    pub deprecated_keys: std::collections::BTreeMap<String, &'static [(&'static str, &'static str)]>,
 }
 
 impl RuleRegistry {
@@ -37,6 +39,7 @@ impl RuleRegistry {
     pub fn from_rules(rules: &[Box<dyn Rule>]) -> Self {
         let mut rule_schemas = std::collections::BTreeMap::new();
         let mut rule_aliases = std::collections::BTreeMap::new();
+        let mut deprecated_keys = std::collections::BTreeMap::new();
 
         for rule in rules {
             let norm_name = if let Some((name, toml::Value::Table(mut table))) = rule.default_config_section() {
@@ -61,13 +64,19 @@ impl RuleRegistry {
 
             // Store aliases if the rule provides them
             if let Some(aliases) = rule.config_aliases() {
-                rule_aliases.insert(norm_name, aliases);
+                rule_aliases.insert(norm_name.clone(), aliases);
+            }
+
+            let deprecated = rule.deprecated_config_keys();
+            if !deprecated.is_empty() {
+                deprecated_keys.insert(norm_name, deprecated);
             }
         }
 
         RuleRegistry {
             rule_schemas,
             rule_aliases,
+            deprecated_keys,
         }
     }
 
@@ -81,9 +90,10 @@ impl RuleRegistry {
         self.rule_schemas.get(rule).map(|schema| {
             let mut all_keys = std::collections::BTreeSet::new();
 
-            // Always allow 'severity' and 'enabled' for any rule
+            // Always allow 'severity', 'enabled' and 'explain-url' for any rule
             all_keys.insert("severity".to_string());
             all_keys.insert("enabled".to_string());
+            all_keys.insert("explain-url".to_string());
 
             // Add original keys from schema
             for key in schema.keys() {
@@ -151,6 +161,16 @@ impl RuleRegistry {
         None
     }
 
+    /// The key that replaces `key` if it is a deprecated option of `rule`
+    pub fn deprecated_key_replacement(&self, rule: &str, key: &str) -> Option<&'static str> {
+        let key = normalize_key(key);
+        self.deprecated_keys
+            .get(rule)?
+            .iter()
+            .find(|(deprecated, _)| normalize_key(deprecated) == key)
+            .map(|(_, replacement)| *replacement)
+    }
+
     /// Resolve any rule name (canonical or alias) to its canonical form
     /// Returns None if the rule name is not recognized
     ///
@@ -261,6 +281,31 @@ pub static RULE_ALIAS_MAP: phf::Map<&'static str, &'static str> = phf::phf_map!
     "MD080" => "MD080",
     "MD081" => "MD081",
     "MD082" => "MD082",
+    "MD083" => "MD083",
+    "MD084" => "MD084",
+    "MD085" => "MD085",
+    "MD086" => "MD086",
+    "MD087" => "MD087",
+    "MD088" => "MD088",
+    "MD089" => "MD089",
+    "MD090" => "MD090",
+    "MD091" => "MD091",
+    "MD092" => "MD092",
+    "MD093" => "MD093",
+    "MD094" => "MD094",
+    "MD095" => "MD095",
+    "MD096" => "MD096",
+    "MD097" => "MD097",
+    "MD098" => "MD098",
+    "MD099" => "MD099",
+    "MD100" => "MD100",
+    "MD101" => "MD101",
+    "MD102" => "MD102",
+    "MD103" => "MD103",
+    "MD104" => "MD104",
+    "MD105" => "MD105",
+    "MD106" => "MD106",
+    "MD107" => "MD107",
 
     // Aliases (hyphen format)
     "HEADING-INCREMENT" => "MD001",
@@ -342,8 +387,70 @@ pub static RULE_ALIAS_MAP: phf::Map<&'static str, &'static str> = phf::phf_map!
     "HEADING-ANCHOR-COLLISION" => "MD080",
     "NO-EXCESSIVE-EMPHASIS" => "MD081",
     "NO-EMPTY-SECTIONS" => "MD082",
+    "UNDEFINED-JSX-COMPONENTS" => "MD083",
+    "SHELL-PROMPT-STYLE" => "MD084",
+    "CALLOUT-TITLE-CAPITALIZATION" => "MD085",
+    "UNUSED-IMAGES" => "MD086",
+    "HEADING-EMOJI" => "MD087",
+    "SORTED-LISTS" => "MD088",
+    "DEPRECATED-ANCHORS" => "MD089",
+    "CODE-SPAN-ASCII" => "MD090",
+    "CONFLICTING-REFERENCE-DEFINITIONS" => "MD091",
+    "FRONT-MATTER-TITLE-MATCH" => "MD092",
+    "ALT-TEXT-QUALITY" => "MD093",
+    "CALLOUT-STYLE" => "MD094",
+    "SORTED-REFERENCE-DEFINITIONS" => "MD095",
+    "DUPLICATE-PAGE-TITLES" => "MD096",
+    "DIAGRAM-SYNTAX" => "MD097",
+    "TYPOGRAPHY" => "MD098",
+    "LIST-ITEM-PUNCTUATION" => "MD099",
+    "TASK-LIST-STYLE" => "MD100",
+    "STABLE-ANCHORS" => "MD101",
+    "DANGEROUS-LINKS" => "MD102",
+    "INTERNAL-URLS" => "MD103",
+    "REQUIRED-SECTIONS" => "MD104",
+    "CHANGELOG-FORMAT" => "MD105",
+    "README-CONVENTIONS" => "MD106",
+    "LINE-ENDINGS" => "MD107",
 };
 
+/// Aliases that markdownlint lists after another name for the same rule.
+///
+/// Rules with a single alias need no entry here; for the few with several,
+/// these are skipped when picking the primary alias.
+const SECONDARY_RULE_ALIASES: &[&str] = &["SINGLE-H1", "FIRST-LINE-H1", "TABLE-CELL-ALIGNMENT"];
+
+/// Canonical rule ID -> lowercase aliases, primary alias first, derived from
+/// `RULE_ALIAS_MAP` so aliases are only ever spelled out once.
+static RULE_ALIASES_BY_ID: LazyLock<std::collections::HashMap<&'static str, Vec<String>>> = LazyLock::new(|| {
+    let mut by_id: std::collections::HashMap<&'static str, Vec<&'static str>> = std::collections::HashMap::new();
+    for (alias, canonical) in RULE_ALIAS_MAP.entries() {
+        if alias != canonical {
+            by_id.entry(canonical).or_default().push(alias);
+        }
+    }
+    by_id
+        .into_iter()
+        .map(|(id, mut aliases)| {
+            aliases.sort_by_key(|alias| (SECONDARY_RULE_ALIASES.contains(alias), *alias));
+            (id, aliases.iter().map(|alias| alias.to_ascii_lowercase()).collect())
+        })
+        .collect()
+});
+
+/// All aliases of a rule (e.g. `["single-title", "single-h1"]` for MD025), primary first.
+/// Accepts a canonical ID or any alias; returns an empty slice for unknown rules.
+pub fn rule_aliases(name: &str) -> &'static [String] {
+    resolve_rule_name_alias(name)
+        .and_then(|id| RULE_ALIASES_BY_ID.get(id))
+        .map_or(&[], Vec::as_slice)
+}
+
+/// The primary markdownlint alias of a rule (e.g. "line-length" for MD013).
+pub fn rule_primary_alias(name: &str) -> Option<&'static str> {
+    rule_aliases(name).first().map(String::as_str)
+}
+
 /// Resolve a rule name alias to its canonical form with O(1) perfect hash lookup
 /// Converts rule aliases (like "ul-style", "line-length") to canonical IDs (like "MD004", "MD013")
 /// Returns None if the rule name is not recognized
@@ -361,8 +468,13 @@ pub fn resolve_rule_name_alias(key: &str) -> Option<&'static str> {
 /// - "heading-increment" → "MD001" (alias)
 /// - "HEADING_INCREMENT" → "MD001" (case-insensitive, underscore variant)
 ///
+/// Prose style rule IDs (`Style.Rule`) are returned unchanged.
+///
 /// For unknown names, falls back to normalization (uppercase for MDxxx pattern, otherwise kebab-case).
 pub fn resolve_rule_name(name: &str) -> String {
+    if crate::prose_styles::is_style_rule_id(name) {
+        return name.to_string();
+    }
     resolve_rule_name_alias(name).map_or_else(|| normalize_key(name), std::string::ToString::to_string)
 }
 
@@ -380,10 +492,10 @@ pub fn resolve_rule_names(input: &str) -> std::collections::HashSet<String> {
 
 /// Checks if a rule name (or alias) is valid.
 /// Returns true if the name resolves to a known rule.
-/// Handles the special "all" value and all aliases.
+/// Handles the special "all" value, all aliases and prose style rule IDs.
 pub fn is_valid_rule_name(name: &str) -> bool {
     // Check for special "all" value (case-insensitive)
-    if name.eq_ignore_ascii_case("all") {
+    if name.eq_ignore_ascii_case("all") || crate::prose_styles::is_style_rule_id(name) {
         return true;
     }
     resolve_rule_name_alias(name).is_some()
@@ -419,6 +531,31 @@ pub fn canonicalize_rule_list_in_place(list: &mut Vec<String>) {
     *list = out;
 }
 
+#[cfg(test)]
+mod alias_tests {
+    use super::*;
+
+    #[test]
+    fn every_rule_has_a_primary_alias_that_resolves_back() {
+        for (name, canonical) in RULE_ALIAS_MAP.entries() {
+            if name != canonical {
+                continue;
+            }
+            let alias = rule_primary_alias(canonical).unwrap_or_else(|| panic!("{canonical} has no alias"));
+            assert_eq!(resolve_rule_name_alias(alias), Some(*canonical));
+        }
+    }
+
+    #[test]
+    fn primary_alias_follows_markdownlint_order() {
+        assert_eq!(rule_aliases("MD025"), ["single-title", "single-h1"]);
+        assert_eq!(rule_aliases("first_line_h1"), ["first-line-heading", "first-line-h1"]);
+        assert_eq!(rule_primary_alias("MD060"), Some("table-format"));
+        assert_eq!(rule_primary_alias("md013"), Some("line-length"));
+        assert!(rule_aliases("MD999").is_empty());
+    }
+}
+
 #[cfg(test)]
 mod canonicalize_tests {
     use super::canonicalize_rule_list_in_place;
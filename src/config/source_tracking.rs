@@ -113,15 +113,31 @@ pub struct SourcedGlobalConfig {
     pub disable: SourcedValue<Vec<String>>,
     pub exclude: SourcedValue<Vec<String>>,
     pub include: SourcedValue<Vec<String>>,
+    pub extensions: SourcedValue<Vec<String>>,
+    pub extract_from: SourcedValue<Vec<String>>,
+    pub html_markdown_patterns: SourcedValue<Vec<String>>,
     pub respect_gitignore: SourcedValue<bool>,
     pub line_length: SourcedValue<LineLength>,
+    pub length_mode: SourcedValue<crate::types::LengthMode>,
+    pub ambiguous_width: SourcedValue<crate::types::AmbiguousWidth>,
     pub output_format: Option<SourcedValue<String>>,
     pub fixable: SourcedValue<Vec<String>>,
     pub unfixable: SourcedValue<Vec<String>>,
     pub flavor: SourcedValue<MarkdownFlavor>,
     pub force_exclude: SourcedValue<bool>,
     pub cache_dir: Option<SourcedValue<String>>,
+    pub docs_base_url: Option<SourcedValue<String>>,
+    pub slugify: Option<SourcedValue<crate::utils::slug::Slugify>>,
     pub cache: SourcedValue<bool>,
+    pub nested_configs: SourcedValue<super::types::NestedConfigs>,
+    pub fix_max_iterations: SourcedValue<usize>,
+    pub unsafe_fixes: SourcedValue<bool>,
+    pub rule_timeout_ms: Option<SourcedValue<u64>>,
+    pub max_file_size: Option<SourcedValue<u64>>,
+    pub large_file_mode: SourcedValue<super::types::LargeFileMode>,
+    pub draft_key: SourcedValue<String>,
+    pub draft_value: SourcedValue<String>,
+    pub draft_disable: SourcedValue<Vec<String>>,
     pub extend_enable: SourcedValue<Vec<String>>,
     pub extend_disable: SourcedValue<Vec<String>>,
 }
@@ -133,15 +149,34 @@ impl Default for SourcedGlobalConfig {
             disable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             exclude: SourcedValue::new(Vec::new(), ConfigSource::Default),
             include: SourcedValue::new(Vec::new(), ConfigSource::Default),
+            extensions: SourcedValue::new(super::types::default_extensions(), ConfigSource::Default),
+            extract_from: SourcedValue::new(Vec::new(), ConfigSource::Default),
+            html_markdown_patterns: SourcedValue::new(Vec::new(), ConfigSource::Default),
             respect_gitignore: SourcedValue::new(true, ConfigSource::Default),
             line_length: SourcedValue::new(LineLength::default(), ConfigSource::Default),
+            length_mode: SourcedValue::new(crate::types::LengthMode::default(), ConfigSource::Default),
+            ambiguous_width: SourcedValue::new(crate::types::AmbiguousWidth::default(), ConfigSource::Default),
             output_format: None,
             fixable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             unfixable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             flavor: SourcedValue::new(MarkdownFlavor::default(), ConfigSource::Default),
             force_exclude: SourcedValue::new(false, ConfigSource::Default),
             cache_dir: None,
+            docs_base_url: None,
+            slugify: None,
             cache: SourcedValue::new(true, ConfigSource::Default),
+            nested_configs: SourcedValue::new(super::types::NestedConfigs::default(), ConfigSource::Default),
+            fix_max_iterations: SourcedValue::new(
+                crate::fix_coordinator::DEFAULT_MAX_ITERATIONS,
+                ConfigSource::Default,
+            ),
+            unsafe_fixes: SourcedValue::new(false, ConfigSource::Default),
+            rule_timeout_ms: None,
+            max_file_size: None,
+            large_file_mode: SourcedValue::new(super::types::LargeFileMode::default(), ConfigSource::Default),
+            draft_key: SourcedValue::new(super::types::default_draft_key(), ConfigSource::Default),
+            draft_value: SourcedValue::new(super::types::default_draft_value(), ConfigSource::Default),
+            draft_disable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             extend_enable: SourcedValue::new(Vec::new(), ConfigSource::Default),
             extend_disable: SourcedValue::new(Vec::new(), ConfigSource::Default),
         }
@@ -158,12 +193,17 @@ pub struct SourcedRuleConfig {
 /// Used as an intermediate step before merging into the final SourcedConfig.
 #[derive(Debug, Clone)]
 pub struct SourcedConfigFragment {
-    /// Path to a base config file to inherit from (consumed during loading, not a config setting)
-    pub extends: Option<String>,
+    /// Paths of base config files to inherit from, in merge order (consumed during
+    /// loading, not a config setting)
+    pub extends: Vec<String>,
     pub global: SourcedGlobalConfig,
     pub per_file_ignores: SourcedValue<BTreeMap<String, Vec<String>>>,
     pub per_file_flavor: SourcedValue<IndexMap<String, MarkdownFlavor>>,
+    pub overrides: SourcedValue<IndexMap<String, super::types::ConfigOverride>>,
+    pub profiles: SourcedValue<IndexMap<String, toml::Table>>,
     pub code_block_tools: SourcedValue<crate::code_block_tools::CodeBlockToolsConfig>,
+    pub ci: SourcedValue<super::types::CiConfig>,
+    pub prose_styles: SourcedValue<crate::prose_styles::ProseStylesConfig>,
     pub rules: BTreeMap<String, SourcedRuleConfig>,
     /// Maps canonical rule IDs to their preferred display names (used by import).
     /// When importing from markdownlint configs, this preserves the user's original
@@ -176,14 +216,18 @@ pub struct SourcedConfigFragment {
 impl Default for SourcedConfigFragment {
     fn default() -> Self {
         Self {
-            extends: None,
+            extends: Vec::new(),
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(BTreeMap::new(), ConfigSource::Default),
             per_file_flavor: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
+            overrides: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
+            profiles: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
             code_block_tools: SourcedValue::new(
                 crate::code_block_tools::CodeBlockToolsConfig::default(),
                 ConfigSource::Default,
             ),
+            ci: SourcedValue::new(super::types::CiConfig::default(), ConfigSource::Default),
+            prose_styles: SourcedValue::new(crate::prose_styles::ProseStylesConfig::default(), ConfigSource::Default),
             rules: BTreeMap::new(),
             rule_display_names: HashMap::new(),
             unknown_keys: Vec::new(),
@@ -221,7 +265,11 @@ pub struct SourcedConfig<State = ConfigLoaded> {
     pub global: SourcedGlobalConfig,
     pub per_file_ignores: SourcedValue<BTreeMap<String, Vec<String>>>,
     pub per_file_flavor: SourcedValue<IndexMap<String, MarkdownFlavor>>,
+    pub overrides: SourcedValue<IndexMap<String, super::types::ConfigOverride>>,
+    pub profiles: SourcedValue<IndexMap<String, toml::Table>>,
     pub code_block_tools: SourcedValue<crate::code_block_tools::CodeBlockToolsConfig>,
+    pub ci: SourcedValue<super::types::CiConfig>,
+    pub prose_styles: SourcedValue<crate::prose_styles::ProseStylesConfig>,
     pub rules: BTreeMap<String, SourcedRuleConfig>,
     pub loaded_files: Vec<String>,
     pub unknown_keys: Vec<(String, String, Option<String>)>, // (section, key, file_path)
@@ -243,10 +291,14 @@ impl Default for SourcedConfig<ConfigLoaded> {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(BTreeMap::new(), ConfigSource::Default),
             per_file_flavor: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
+            overrides: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
+            profiles: SourcedValue::new(IndexMap::new(), ConfigSource::Default),
             code_block_tools: SourcedValue::new(
                 crate::code_block_tools::CodeBlockToolsConfig::default(),
                 ConfigSource::Default,
             ),
+            ci: SourcedValue::new(super::types::CiConfig::default(), ConfigSource::Default),
+            prose_styles: SourcedValue::new(crate::prose_styles::ProseStylesConfig::default(), ConfigSource::Default),
             rules: BTreeMap::new(),
             loaded_files: Vec::new(),
             unknown_keys: Vec::new(),
@@ -681,6 +681,221 @@ fn test_md054_preferred_style_accepts_list_form() {
     );
 }
 
+#[test]
+fn test_docs_links_settings_pass_validation() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        "[global]\ndocs-base-url = \"https://docs.example.com/\"\n\n[MD013]\nexplain-url = \"https://wiki.example.com/#md013\"\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(
+        config.global.docs_base_url.as_deref(),
+        Some("https://docs.example.com/")
+    );
+    let docs = crate::output::DocsLinks::from_config(&config);
+    assert_eq!(
+        docs.url("line-length").as_deref(),
+        Some("https://wiki.example.com/#md013")
+    );
+    assert_eq!(docs.url("MD001").as_deref(), Some("https://docs.example.com/md001/"));
+}
+
+#[test]
+fn test_rule_timeout_ms_loads_and_passes_validation() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(&config_path, "[global]\nrule-timeout-ms = 250\n").unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(config.global.rule_timeout_ms, Some(250));
+    assert_eq!(Config::default().global.rule_timeout_ms, None);
+}
+
+#[test]
+fn test_large_file_settings_load() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        "[global]\nmax-file-size = 1000000\nlarge-file-mode = \"skip\"\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(config.global.max_file_size, Some(1_000_000));
+    assert_eq!(config.global.large_file_mode, LargeFileMode::Skip);
+    assert_eq!(GlobalConfig::default().large_file_mode, LargeFileMode::Stream);
+    assert!("truncate".parse::<LargeFileMode>().is_err());
+}
+
+#[test]
+fn test_global_length_settings_reach_md013_unless_overridden() {
+    use crate::rule::Rule;
+    use crate::rules::md013_line_length::MD013LineLength;
+    use crate::types::{AmbiguousWidth, LengthMode};
+
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        "[global]\nline-length = 10\nlength-mode = \"chars\"\nambiguous-width = \"wide\"\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(config.global.length_mode, LengthMode::Chars);
+    assert_eq!(config.global.ambiguous_width, AmbiguousWidth::Wide);
+
+    // 8 characters long but 14 columns wide
+    let content = "漢字 漢字 漢字\n";
+    let ctx = crate::lint_context::LintContext::new(content, MarkdownFlavor::Standard, None);
+    let rule = MD013LineLength::from_config(&config);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+
+    // The rule's own setting wins over the global one
+    let mut config = config;
+    config.rules.insert(
+        "MD013".to_string(),
+        RuleConfig {
+            severity: None,
+            values: [("length-mode".to_string(), toml::Value::String("visual".to_string()))].into(),
+        },
+    );
+    let rule = MD013LineLength::from_config(&config);
+    assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+}
+
+#[test]
+fn test_global_slugify_loads_style_and_custom_substitutions() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(&config_path, "[global]\nslugify = \"gitlab\"\n").unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(
+        config.global.slugify,
+        Some(crate::utils::slug::Slugify::Style(
+            crate::utils::anchor_styles::AnchorStyle::GitLab
+        ))
+    );
+
+    fs::write(
+        &config_path,
+        "[global]\nslugify = [{ pattern = \"[^a-z0-9 ]\", replacement = \"\" }, { pattern = \" \", replacement = \"_\" }]\n",
+    )
+    .unwrap();
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    let config: Config = sourced.into_validated_unchecked().into();
+    let slugify = config.global.slugify.expect("custom slugify should be set");
+    assert_eq!(slugify.slugify("Hello, World"), "hello_world");
+}
+
+#[test]
+fn test_global_slugify_ignores_invalid_pattern() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(&config_path, "[global]\nslugify = [{ pattern = \"(\" }]\n").unwrap();
+
+    // Like other invalid global values, the key is reported and ignored
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(config.global.slugify, None);
+}
+
+#[test]
+fn test_deprecated_config_usages_are_not_validation_warnings() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        "[global]\nforce_exclude = true\n\n[MD013]\nenable_reflow = true\nstrict-sentences = true\nreflow = true\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    let registry = default_registry();
+    assert!(validate_config_sourced(&sourced, registry).is_empty());
+
+    let deprecated = deprecated_config_usages(&sourced, registry);
+    let keys: Vec<_> = deprecated.iter().map(|w| w.key.as_deref().unwrap()).collect();
+    assert_eq!(keys, ["force-exclude", "enable-reflow", "strict-sentences"]);
+    assert_eq!(
+        registry.deprecated_key_replacement("MD013", "enable_reflow"),
+        Some("reflow")
+    );
+    assert_eq!(registry.deprecated_key_replacement("MD013", "reflow"), None);
+}
+
+#[test]
+fn test_config_conflicts() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        r#"[global]
+extend-enable = ["MD013", "MD060"]
+disable = ["MD060"]
+
+[MD013]
+line-length = 20
+reflow = true
+reflow-mode = "sentence-per-line"
+
+[MD041]
+level = 2
+
+[MD046]
+style = "indented"
+"#,
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    let conflicts = config_conflicts(&config);
+    let found: Vec<_> = conflicts
+        .iter()
+        .map(|w| (w.rule.as_deref().unwrap(), w.key.as_deref().unwrap()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("MD060", "extend-enable"),
+            ("MD013", "extend-enable"),
+            ("MD013", "line-length"),
+            ("MD041", "level"),
+            ("MD046", "style"),
+        ]
+    );
+
+    // The defaults are free of conflicts, and disabled rules cannot conflict
+    assert!(config_conflicts(&Config::default()).is_empty());
+    fs::write(
+        &config_path,
+        "[global]\ndisable = [\"MD013\", \"MD040\"]\n\n[MD013]\nline-length = 20\nreflow = true\nreflow-mode = \"semantic-line-breaks\"\n\n[MD046]\nstyle = \"indented\"\n",
+    )
+    .unwrap();
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert!(config_conflicts(&config).is_empty());
+}
+
 #[test]
 fn test_md054_preferred_style_unknown_key_still_warns() {
     use crate::rules;
@@ -1526,6 +1741,198 @@ fn test_per_file_flavor_character_class() {
     assert_eq!(flavor, MarkdownFlavor::Standard);
 }
 
+// ==========================================
+// Overrides Tests
+// ==========================================
+
+#[test]
+fn test_overrides_config_parsing_and_layering() {
+    use std::path::PathBuf;
+
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    let config_content = r#"
+[MD013]
+line_length = 80
+code_blocks = false
+
+[overrides]
+"docs/api/**" = { MD013 = { line_length = 120 }, disable = ["no-inline-html"] }
+
+[overrides."docs/api/v2/**"]
+extend-enable = ["MD060"]
+MD013 = { line-length = 100, severity = "info" }
+"#;
+    fs::write(&config_path, config_content).unwrap();
+
+    let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    let config: Config = sourced.into_validated_unchecked().into();
+
+    assert_eq!(config.overrides.len(), 2);
+    let first = &config.overrides["docs/api/**"];
+    assert_eq!(first.disable, vec!["MD033".to_string()]);
+    assert_eq!(
+        first.rules["MD013"].values.get("line-length"),
+        Some(&toml::Value::Integer(120))
+    );
+
+    assert!(config.matching_overrides(&PathBuf::from("README.md")).is_empty());
+    assert_eq!(
+        config.matching_overrides(&PathBuf::from("docs/api/v2/x.md")),
+        vec![0, 1]
+    );
+
+    // Later layers win key by key; untouched keys keep the top-level value
+    let layered = config.with_overrides(&[0, 1]);
+    let md013 = &layered.rules["MD013"];
+    assert_eq!(md013.values.get("line-length"), Some(&toml::Value::Integer(100)));
+    assert_eq!(md013.values.get("code-blocks"), Some(&toml::Value::Boolean(false)));
+    assert_eq!(md013.severity, Some(crate::rule::Severity::Info));
+    assert!(layered.global.disable.contains(&"MD033".to_string()));
+    assert!(layered.global.extend_enable.contains(&"MD060".to_string()));
+
+    // The base config is left untouched
+    assert!(config.global.disable.is_empty());
+}
+
+#[test]
+fn test_overrides_pyproject_toml() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join("pyproject.toml");
+    let config_content = r#"
+[tool.rumdl.overrides."CHANGELOG.md"]
+disable = ["MD024"]
+MD013 = { enabled = false }
+"#;
+    fs::write(&config_path, config_content).unwrap();
+
+    let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    let config: Config = sourced.into_validated_unchecked().into();
+
+    assert_eq!(config.overrides["CHANGELOG.md"].disable, vec!["MD024".to_string()]);
+    let layered = config.with_overrides(&[0]);
+    assert!(layered.global.disable.contains(&"MD024".to_string()));
+    assert!(layered.global.disable.contains(&"MD013".to_string()));
+}
+
+#[test]
+fn test_profiles_parsing_and_validation() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    let config_content = r#"
+[MD013]
+line-length = 80
+
+[profiles.strict]
+enable = ["ALL"]
+MD013 = { line-length = 60 }
+
+[profiles.ci]
+disable = ["MD013"]
+"#;
+    fs::write(&config_path, config_content).unwrap();
+
+    let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    // `[profiles]` is a known section, not a misspelled rule name
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+
+    assert_eq!(config.profiles.keys().collect::<Vec<_>>(), vec!["ci", "strict"]);
+    assert_eq!(
+        config.profiles["strict"]["MD013"]["line-length"],
+        toml::Value::Integer(60)
+    );
+    // Profiles only apply when selected
+    assert_eq!(
+        config.rules["MD013"].values.get("line-length"),
+        Some(&toml::Value::Integer(80))
+    );
+    assert!(config.global.disable.is_empty());
+}
+
+#[test]
+fn test_table_valued_rule_options() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    let config_content = r#"
+[MD040]
+preferred-aliases = { Shell = "sh" }
+
+[MD104.templates]
+"docs/adr/**" = ["Status", "Context"]
+"rfcs/*.md" = ["Summary"]
+"#;
+    fs::write(&config_path, config_content).unwrap();
+
+    let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+
+    // Table keys are data and keep their spelling
+    let templates = get_rule_config_value::<toml::Table>(&config, "MD104", "templates").unwrap();
+    assert_eq!(templates.keys().collect::<Vec<_>>(), vec!["docs/adr/**", "rfcs/*.md"]);
+    let aliases = get_rule_config_value::<toml::Table>(&config, "MD040", "preferred-aliases").unwrap();
+    assert_eq!(aliases["Shell"], toml::Value::String("sh".to_string()));
+}
+
+#[test]
+fn test_draft_settings_detect_drafts_and_disable_rules() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(
+        &config_path,
+        "[global]\ndraft-key = \"status\"\ndraft-value = \"wip\"\ndraft-disable = [\"line-length\", \"MD043\"]\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load(Some(config_path.to_str().unwrap()), None).expect("Config should load");
+    assert!(validate_config_sourced(&sourced, default_registry()).is_empty());
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(
+        config.global.draft_disable,
+        vec!["MD013".to_string(), "MD043".to_string()]
+    );
+
+    assert!(config.is_draft("---\nstatus: wip\n---\n\n# Title\n"));
+    assert!(config.is_draft("+++\nstatus = \"wip\"\n+++\n\n# Title\n"));
+    assert!(config.is_draft("{\n\"status\": \"wip\",\n\"title\": \"x\"\n}\n\n# Title\n"));
+    assert!(!config.is_draft("---\nstatus: done\n---\n\n# Title\n"));
+    assert!(!config.is_draft("# Title\n\nstatus: wip\n"));
+
+    let relaxed = config.with_draft_relaxations();
+    assert_eq!(relaxed.global.disable, vec!["MD013".to_string(), "MD043".to_string()]);
+    assert!(config.global.disable.is_empty());
+
+    // Without draft-disable, drafts are not detected at all
+    assert!(!Config::default().is_draft("---\ndraft: true\n---\n"));
+}
+
+#[test]
+fn test_nested_configs_mode_and_chain_loading() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let root_config = root.join(".rumdl.toml");
+    fs::write(
+        &root_config,
+        "[global]\nnested-configs = \"merge\"\ndisable = [\"MD033\"]\n\n[MD013]\nline-length = 40\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    let docs_config = root.join("docs/.rumdl.toml");
+    fs::write(&docs_config, "[MD013]\nline-length = 120\n").unwrap();
+
+    let config: Config = SourcedConfig::load_sourced_chain(&[root_config, docs_config], root)
+        .unwrap()
+        .into_validated_unchecked()
+        .into();
+
+    assert_eq!(config.global.nested_configs, NestedConfigs::Merge);
+    assert_eq!(config.global.disable, vec!["MD033".to_string()]);
+    assert_eq!(get_rule_config_value::<u64>(&config, "MD013", "line-length"), Some(120));
+    assert!("inherit".parse::<NestedConfigs>().is_err());
+    assert_eq!(GlobalConfig::default().nested_configs, NestedConfigs::Standalone);
+}
+
 // ==========================================
 // Path normalization robustness tests
 // (regression: per-file-flavor / per-file-ignores must work even when
@@ -2727,7 +3134,7 @@ fn test_is_valid_rule_name_invalid() {
     assert!(!is_valid_rule_name("MD002")); // gap in numbering
     assert!(!is_valid_rule_name("MD006")); // gap in numbering
     assert!(!is_valid_rule_name("MD999"));
-    assert!(!is_valid_rule_name("MD100"));
+    assert!(!is_valid_rule_name("MD108"));
 
     // Invalid formats
     assert!(!is_valid_rule_name(""));
@@ -3869,6 +4276,75 @@ fn test_extends_depth_limit_returns_error() {
     );
 }
 
+#[test]
+fn test_extends_list_merges_in_order_and_shared_base_once() {
+    // .rumdl.toml extends [style, team]; both extend common.toml. common is
+    // merged once, before style, so it cannot undo style's line-length.
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("common.toml"),
+        "[global]\nline-length = 80\nflavor = \"mkdocs\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("style.toml"),
+        "extends = \"common.toml\"\n[global]\nline-length = 100\ndisable = [\"MD013\"]\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("team.toml"),
+        "extends = \"common.toml\"\n[global]\ndisable = [\"MD033\"]\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join(".rumdl.toml"),
+        "extends = [\"style.toml\", \"./team.toml\"]\n[MD007]\nindent = 4\n",
+    )
+    .unwrap();
+
+    let sourced = SourcedConfig::load_with_discovery_impl(
+        Some(dir.path().join(".rumdl.toml").to_str().unwrap()),
+        None,
+        true,
+        None,
+        None,
+    )
+    .unwrap();
+    let loaded: Vec<&str> = sourced
+        .loaded_files
+        .iter()
+        .map(|f| std::path::Path::new(f).file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(loaded, ["common.toml", "style.toml", "team.toml", ".rumdl.toml"]);
+    let origin = sourced.global.line_length.origin.clone().unwrap();
+    assert!(origin.ends_with("style.toml"), "line-length origin: {origin}");
+
+    let config: Config = sourced.into_validated_unchecked().into();
+    assert_eq!(config.global.line_length.get(), 100);
+    assert_eq!(config.global.disable, vec!["MD033".to_string()]);
+    assert_eq!(config.global.flavor, MarkdownFlavor::MkDocs);
+}
+
+#[test]
+fn test_extends_rejects_remote_and_malformed_values() {
+    let dir = tempdir().unwrap();
+    let config_path = dir.path().join(".rumdl.toml");
+
+    fs::write(&config_path, "extends = [\"github:org/rumdl-config\"]\n").unwrap();
+    let result = SourcedConfig::load_with_discovery_impl(Some(config_path.to_str().unwrap()), None, true, None, None);
+    assert!(
+        matches!(&result, Err(ConfigError::ExtendsRemote { spec, .. }) if spec == "github:org/rumdl-config"),
+        "Expected ExtendsRemote error, got: {result:?}"
+    );
+
+    fs::write(&config_path, "extends = 42\n").unwrap();
+    let result = SourcedConfig::load_with_discovery_impl(Some(config_path.to_str().unwrap()), None, true, None, None);
+    assert!(
+        matches!(&result, Err(ConfigError::ParseError(msg)) if msg.contains("`extends` must be a path")),
+        "Expected ParseError, got: {result:?}"
+    );
+}
+
 #[serial_test::serial]
 #[test]
 fn test_user_config_loaded_alongside_markdownlint_config() {
@@ -4232,3 +4708,28 @@ allowed-elements = ["div", "img"]
         panic!("expected array for allowed-elements, got {val:?}");
     }
 }
+
+#[test]
+fn test_ci_section_parses_exit_policy() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().expect("Failed to create temporary directory");
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    std::fs::write(
+        &config_path,
+        "[ci]\nfail-on = \"warning\"\nmax_warnings = 50\nfail-on-new = true\n",
+    )
+    .expect("Failed to write config");
+
+    let loaded = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+        .expect("Should load config");
+    let config: Config = loaded.into_validated_unchecked().into();
+    assert_eq!(
+        config.ci,
+        CiConfig {
+            fail_on: Some(FailOnLevel::Warning),
+            max_warnings: Some(50),
+            fail_on_new: true,
+        }
+    );
+}
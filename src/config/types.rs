@@ -1,4 +1,4 @@
-use crate::types::LineLength;
+use crate::types::{AmbiguousWidth, LengthMode, LineLength};
 use globset::{Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,57 @@ pub struct RuleConfig {
     pub values: BTreeMap<String, toml::Value>,
 }
 
+/// Settings applied to files matching an `[overrides]` glob pattern
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
+pub struct ConfigOverride {
+    /// Rules to disable for matching files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disable: Vec<String>,
+
+    /// Rules to enable for matching files, in addition to the global selection
+    #[serde(default, rename = "extend-enable", skip_serializing_if = "Vec::is_empty")]
+    pub extend_enable: Vec<String>,
+
+    /// Rule settings layered over the top-level rule sections
+    #[serde(flatten)]
+    pub rules: BTreeMap<String, RuleConfig>,
+}
+
+/// Severity that makes a run fail, for `fail-on` in `[ci]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOnLevel {
+    /// Fail on any violation
+    #[default]
+    Any,
+    /// Fail on warning or error severity violations
+    Warning,
+    /// Fail only on error-severity violations
+    Error,
+    /// Never fail because of violations
+    Never,
+}
+
+/// Exit-code policy from the `[ci]` section, so every run of a project fails
+/// the same way. Command-line flags take precedence.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CiConfig {
+    /// Severity that fails the run (default: any)
+    #[serde(alias = "fail_on", skip_serializing_if = "Option::is_none")]
+    pub fail_on: Option<FailOnLevel>,
+
+    /// Fail only when more than this many violations of the `fail-on`
+    /// severity are reported
+    #[serde(alias = "max_warnings", skip_serializing_if = "Option::is_none")]
+    pub max_warnings: Option<usize>,
+
+    /// With `--diff-base`, report every warning but fail only on those on
+    /// changed lines
+    #[serde(alias = "fail_on_new", skip_serializing_if = "std::ops::Not::not")]
+    pub fail_on_new: bool,
+}
+
 /// Generate a JSON schema for arbitrary configuration values
 fn arbitrary_value_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
     schemars::json_schema!({
@@ -31,18 +82,38 @@ fn arbitrary_value_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Sch
     })
 }
 
+/// Generate a JSON schema for an optional `u64` setting without the `uint64` format
+fn optional_positive_integer_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": ["integer", "null"],
+        "minimum": 1
+    })
+}
+
+/// Generate a JSON schema for `[profiles]`: profile name → config-shaped table
+fn profiles_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "object",
+        "additionalProperties": {
+            "type": "object",
+            "additionalProperties": true
+        }
+    })
+}
+
 /// Represents the complete configuration loaded from rumdl.toml
 #[derive(Debug, Clone, Serialize, Deserialize, Default, schemars::JsonSchema)]
 #[schemars(
     description = "rumdl configuration for linting Markdown files. Rules can be configured individually using [MD###] sections with rule-specific options."
 )]
 pub struct Config {
-    /// Path to a base config file to inherit settings from.
+    /// Base config file(s) to inherit settings from, merged in list order.
     /// Supports relative paths, absolute paths, `~/` for the home directory, and
     /// `$VAR` / `${VAR}` environment-variable expansion (a literal `$` is written `$$`).
-    /// Example: `extends = "../base.rumdl.toml"` or `extends = "$GEM_PATH/base.rumdl.toml"`
+    /// Example: `extends = "../base.rumdl.toml"` or
+    /// `extends = ["$GEM_PATH/base.rumdl.toml", "./team.rumdl.toml"]`
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub extends: Option<String>,
+    pub extends: Option<ExtendsValue>,
 
     /// Global configuration options
     #[serde(default)]
@@ -60,11 +131,33 @@ pub struct Config {
     #[schemars(with = "BTreeMap<String, MarkdownFlavor>")]
     pub per_file_flavor: IndexMap<String, MarkdownFlavor>,
 
+    /// Glob-scoped rule settings, layered over the rule sections in config order
+    /// Example: { "docs/api/**" = { MD013 = { line-length = 120 }, disable = ["MD033"] } }
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    #[schemars(with = "BTreeMap<String, ConfigOverride>")]
+    pub overrides: IndexMap<String, ConfigOverride>,
+
+    /// Named rule profiles selected with `--rule-profile NAME`. Each profile is
+    /// a table of the same shape as the config file (global keys such as
+    /// `disable` or `extend-enable`, plus rule sections) layered over it.
+    /// Example: { docs = { extend-disable = ["MD013"], MD033 = { allowed-elements = ["br"] } } }
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    #[schemars(schema_with = "profiles_schema")]
+    pub profiles: IndexMap<String, toml::Table>,
+
     /// Code block tools configuration for per-language linting and formatting
     /// using external tools like ruff, prettier, shellcheck, etc.
     #[serde(default, rename = "code-block-tools")]
     pub code_block_tools: crate::code_block_tools::CodeBlockToolsConfig,
 
+    /// Exit-code policy for CI runs
+    #[serde(default, skip_serializing_if = "is_default_ci")]
+    pub ci: CiConfig,
+
+    /// Prose style rules loaded from Vale-compatible YAML files
+    #[serde(default, rename = "prose-styles", skip_serializing_if = "is_default_prose_styles")]
+    pub prose_styles: crate::prose_styles::ProseStylesConfig,
+
     /// Rule-specific configurations (e.g., MD013, MD007, MD044)
     /// Each rule section can contain options specific to that rule.
     ///
@@ -90,6 +183,10 @@ pub struct Config {
     #[schemars(skip)]
     pub(super) per_file_flavor_cache: Arc<OnceLock<PerFileFlavorCache>>,
 
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub(super) overrides_cache: Arc<OnceLock<OverridesCache>>,
+
     /// Lazily-computed canonical form of `project_root`.
     ///
     /// `normalize_match_path` needs the canonical project root to strip
@@ -125,12 +222,24 @@ impl PartialEq for Config {
         self.global == other.global
             && self.per_file_ignores == other.per_file_ignores
             && self.per_file_flavor == other.per_file_flavor
+            && self.overrides == other.overrides
+            && self.profiles == other.profiles
             && self.code_block_tools == other.code_block_tools
+            && self.ci == other.ci
+            && self.prose_styles == other.prose_styles
             && self.rules == other.rules
             && self.project_root == other.project_root
     }
 }
 
+fn is_default_ci(ci: &CiConfig) -> bool {
+    *ci == CiConfig::default()
+}
+
+fn is_default_prose_styles(prose_styles: &crate::prose_styles::ProseStylesConfig) -> bool {
+    *prose_styles == crate::prose_styles::ProseStylesConfig::default()
+}
+
 #[derive(Debug)]
 pub(super) struct PerFileIgnoreCache {
     globset: GlobSet,
@@ -142,6 +251,12 @@ pub(super) struct PerFileFlavorCache {
     matchers: Vec<(GlobMatcher, MarkdownFlavor)>,
 }
 
+/// One matcher per `[overrides]` entry, aligned with `Config::overrides`
+#[derive(Debug)]
+pub(super) struct OverridesCache {
+    matchers: Vec<Option<GlobMatcher>>,
+}
+
 impl Config {
     /// Check if the Markdown flavor is set to MkDocs
     pub fn is_mkdocs_flavor(&self) -> bool {
@@ -215,7 +330,7 @@ impl Config {
     /// otherwise cannot be canonicalized. Subsequent calls reuse the cached
     /// value, eliminating the per-file `canonicalize()` syscall that
     /// `normalize_match_path` would otherwise perform.
-    pub(super) fn canonical_project_root(&self) -> Option<&Path> {
+    pub(crate) fn canonical_project_root(&self) -> Option<&Path> {
         self.canonical_project_root_cache
             .get_or_init(|| self.project_root.as_deref().and_then(|p| p.canonicalize().ok()))
             .as_deref()
@@ -250,6 +365,23 @@ impl Config {
         ignored_rules
     }
 
+    /// Check whether a file matches the `extract-from` patterns, i.e. whether
+    /// markdown should be extracted from its doc comments and docstrings.
+    pub fn is_extract_from_path(&self, file_path: &Path) -> bool {
+        if self.global.extract_from.is_empty() {
+            return false;
+        }
+
+        let cwd = std::env::current_dir().ok();
+        let path_for_matching = normalize_match_path(file_path, self.canonical_project_root(), cwd.as_deref());
+
+        self.global
+            .extract_from
+            .iter()
+            .filter_map(|pattern| Glob::new(pattern).ok())
+            .any(|glob| glob.compile_matcher().is_match(path_for_matching.as_ref()))
+    }
+
     /// Get the MarkdownFlavor for a specific file based on per-file-flavor configuration.
     /// Returns the first matching pattern's flavor, or falls back to global flavor,
     /// or auto-detects from extension, or defaults to Standard.
@@ -287,6 +419,104 @@ impl Config {
         MarkdownFlavor::from_path(file_path)
     }
 
+    /// Indices into `overrides` of the patterns matching a file, in config order.
+    /// Files with the same indices share an effective config.
+    pub fn matching_overrides(&self, file_path: &Path) -> Vec<usize> {
+        if self.overrides.is_empty() {
+            return Vec::new();
+        }
+
+        let cwd = std::env::current_dir().ok();
+        let path_for_matching = normalize_match_path(file_path, self.canonical_project_root(), cwd.as_deref());
+
+        let cache = self
+            .overrides_cache
+            .get_or_init(|| OverridesCache::new(&self.overrides));
+
+        cache
+            .matchers
+            .iter()
+            .enumerate()
+            .filter(|(_, matcher)| matcher.as_ref().is_some_and(|m| m.is_match(path_for_matching.as_ref())))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// A copy of this config with the given `overrides` entries merged on top,
+    /// later entries winning. Rule settings replace individual keys of the rule
+    /// section; `disable` and `extend-enable` extend the global lists.
+    pub fn with_overrides(&self, indices: &[usize]) -> Config {
+        let mut merged = self.clone();
+        for layer in indices
+            .iter()
+            .filter_map(|&idx| self.overrides.get_index(idx))
+            .map(|(_, o)| o)
+        {
+            // Per-rule `enabled` inside a layer acts like the layer's own lists
+            let enabled = |want: bool| {
+                layer
+                    .rules
+                    .iter()
+                    .filter(move |(_, cfg)| cfg.values.get("enabled") == Some(&toml::Value::Boolean(want)))
+                    .map(|(name, _)| name)
+            };
+
+            for rule in layer.extend_enable.iter().chain(enabled(true)) {
+                merged.global.disable.retain(|name| name != rule);
+                merged.global.extend_disable.retain(|name| name != rule);
+                if !merged.global.extend_enable.contains(rule) {
+                    merged.global.extend_enable.push(rule.clone());
+                }
+            }
+            for rule in layer.disable.iter().chain(enabled(false)) {
+                merged.global.extend_enable.retain(|name| name != rule);
+                if !merged.global.disable.contains(rule) {
+                    merged.global.disable.push(rule.clone());
+                }
+            }
+            for (rule_name, rule_config) in &layer.rules {
+                let entry = merged.rules.entry(rule_name.clone()).or_default();
+                if rule_config.severity.is_some() {
+                    entry.severity = rule_config.severity;
+                }
+                entry
+                    .values
+                    .extend(rule_config.values.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+        merged
+    }
+
+    /// Whether `content` is a draft: its front matter sets `draft-key` to
+    /// `draft-value`. Always false while `draft-disable` is empty.
+    pub fn is_draft(&self, content: &str) -> bool {
+        if self.global.draft_disable.is_empty() {
+            return false;
+        }
+        crate::rules::front_matter_utils::FrontMatterUtils::get_front_matter_field_value(
+            content,
+            &self.global.draft_key,
+        )
+        .is_some_and(|value| {
+            // JSON front matter keeps the separator and quotes
+            let value = value.trim().trim_end_matches(',').trim();
+            value.trim_matches(|c| c == '"' || c == '\'') == self.global.draft_value
+        })
+    }
+
+    /// A copy of this config with the `draft-disable` rules disabled, the way
+    /// an `[overrides]` entry's `disable` list would.
+    pub fn with_draft_relaxations(&self) -> Config {
+        let mut merged = self.clone();
+        for rule in &self.global.draft_disable {
+            merged.global.extend_enable.retain(|name| name != rule);
+            if !merged.global.disable.contains(rule) {
+                merged.global.disable.push(rule.clone());
+            }
+        }
+        merged
+    }
+
     /// Canonicalize every rule-name list inside this `Config`.
     ///
     /// This is the single enforcement point for the runtime invariant:
@@ -301,13 +531,17 @@ impl Config {
     /// the linting pipeline.
     ///
     /// Covers `global.{enable,disable,extend_enable,extend_disable,fixable,unfixable}`
-    /// and the values of `per_file_ignores`. Idempotent.
+    /// and the values of `per_file_ignores` and `overrides`. Idempotent.
     pub fn canonicalize_rule_lists(&mut self) {
         use super::registry::canonicalize_rule_list_in_place;
         self.global.canonicalize_rule_lists();
         for rules in self.per_file_ignores.values_mut() {
             canonicalize_rule_list_in_place(rules);
         }
+        for layer in self.overrides.values_mut() {
+            canonicalize_rule_list_in_place(&mut layer.disable);
+            canonicalize_rule_list_in_place(&mut layer.extend_enable);
+        }
     }
 
     /// Merge inline configuration overrides into a copy of this config
@@ -369,7 +603,7 @@ impl Config {
 /// `canonical_project_root` is expected to already be canonical (via
 /// `Config::canonical_project_root`). `cwd` is canonicalized internally on each
 /// call since it is read fresh from the environment per invocation.
-pub(super) fn normalize_match_path<'a>(
+pub(crate) fn normalize_match_path<'a>(
     file_path: &'a Path,
     canonical_project_root: Option<&Path>,
     cwd: Option<&Path>,
@@ -463,7 +697,7 @@ impl std::fmt::Display for DisplayPathOrUnset<'_> {
 }
 
 /// Convert a serde_json::Value to a toml::Value
-pub(super) fn json_to_toml(json: &serde_json::Value) -> Option<toml::Value> {
+pub(crate) fn json_to_toml(json: &serde_json::Value) -> Option<toml::Value> {
     match json {
         serde_json::Value::Null => None,
         serde_json::Value::Bool(b) => Some(toml::Value::Boolean(*b)),
@@ -521,6 +755,25 @@ impl PerFileIgnoreCache {
     }
 }
 
+impl OverridesCache {
+    fn new(overrides: &IndexMap<String, ConfigOverride>) -> Self {
+        let matchers = overrides
+            .keys()
+            .map(
+                |pattern| match GlobBuilder::new(pattern).literal_separator(true).build() {
+                    Ok(glob) => Some(glob.compile_matcher()),
+                    Err(_) => {
+                        log::warn!("Invalid glob pattern in overrides: {pattern}");
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        Self { matchers }
+    }
+}
+
 impl PerFileFlavorCache {
     fn new(per_file_flavor: &IndexMap<String, MarkdownFlavor>) -> Self {
         let mut matchers = Vec::new();
@@ -557,6 +810,22 @@ pub struct GlobalConfig {
     #[serde(default)]
     pub include: Vec<String>,
 
+    /// File extensions treated as markdown when discovering files
+    /// (without the leading dot, matched case-insensitively)
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+
+    /// Source files whose doc comments and docstrings are linted as markdown
+    /// (e.g. `["**/*.py"]`). Rust, Python, and JavaScript/TypeScript are supported.
+    #[serde(default, alias = "extract_from")]
+    pub extract_from: Vec<String>,
+
+    /// Regexes locating markdown regions in HTML files matched by `extract-from`.
+    /// Each pattern captures the markdown in a `markdown` (or first) group.
+    /// Built-in patterns are used when empty.
+    #[serde(default, alias = "html_markdown_patterns")]
+    pub html_markdown_patterns: Vec<String>,
+
     /// Respect .gitignore files when scanning directories
     #[serde(default = "default_respect_gitignore", alias = "respect_gitignore")]
     pub respect_gitignore: bool,
@@ -565,6 +834,18 @@ pub struct GlobalConfig {
     #[serde(default, alias = "line_length")]
     pub line_length: LineLength,
 
+    /// How length-sensitive rules (MD013, MD060 table formatting, reflow)
+    /// measure lines: "visual" (default), "chars" or "bytes". MD013's own
+    /// `length-mode` overrides it
+    #[serde(default, alias = "length_mode")]
+    pub length_mode: LengthMode,
+
+    /// Display width of East Asian ambiguous-width characters in visual
+    /// mode: "narrow" (default) or "wide". MD013's own `ambiguous-width`
+    /// overrides it
+    #[serde(default, alias = "ambiguous_width")]
+    pub ambiguous_width: AmbiguousWidth,
+
     /// Output format for linting results (e.g., "text", "json", "pylint", etc.)
     #[serde(skip_serializing_if = "Option::is_none", alias = "output_format")]
     pub output_format: Option<String>,
@@ -597,11 +878,67 @@ pub struct GlobalConfig {
     #[serde(default, alias = "cache_dir", skip_serializing_if = "Option::is_none")]
     pub cache_dir: Option<String>,
 
+    /// Base URL of the rule documentation linked from diagnostics
+    /// (default: https://rumdl.dev/). A rule's `explain-url` setting
+    /// replaces its link entirely.
+    #[serde(default, alias = "docs_base_url", skip_serializing_if = "Option::is_none")]
+    pub docs_base_url: Option<String>,
+
+    /// Slug algorithm for heading anchors, used by every anchor-aware rule
+    /// that does not set its own `anchor-style`. A built-in style name or a
+    /// list of regex substitutions. Defaults to the flavor's native style.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slugify: Option<crate::utils::slug::Slugify>,
+
     /// Whether caching is enabled (default: true)
     /// Can also be disabled via --no-cache CLI flag
     #[serde(default = "default_true")]
     pub cache: bool,
 
+    /// How subdirectory config files relate to the configs above them.
+    /// Only read from the project root config.
+    #[serde(default, alias = "nested_configs")]
+    pub nested_configs: NestedConfigs,
+
+    /// Maximum number of fix passes per file before giving up on reaching a
+    /// fixpoint (default: 100)
+    #[serde(default = "default_fix_max_iterations", alias = "fix_max_iterations")]
+    pub fix_max_iterations: usize,
+
+    /// Whether fixes that may change what the document says, such as heading
+    /// capitalization or paragraph reflow, are applied (default: false).
+    /// Can also be enabled via --unsafe-fixes CLI flag
+    #[serde(default, alias = "unsafe_fixes")]
+    pub unsafe_fixes: bool,
+
+    /// Time budget in milliseconds for one rule on one file. A rule that runs
+    /// longer is skipped for that file with a warning. Unset means no limit.
+    #[serde(default, alias = "rule_timeout_ms", skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "optional_positive_integer_schema")]
+    pub rule_timeout_ms: Option<u64>,
+
+    /// Size in bytes above which a file is not linted as a whole; see
+    /// `large-file-mode`. Unset means no limit.
+    #[serde(default, alias = "max_file_size", skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "optional_positive_integer_schema")]
+    pub max_file_size: Option<u64>,
+
+    /// What to do with files larger than `max-file-size` (default: stream)
+    #[serde(default, alias = "large_file_mode")]
+    pub large_file_mode: LargeFileMode,
+
+    /// Front matter key marking a file as a draft (default: "draft")
+    #[serde(default = "default_draft_key", alias = "draft_key")]
+    pub draft_key: String,
+
+    /// Value of `draft-key` marking a file as a draft (default: "true")
+    #[serde(default = "default_draft_value", alias = "draft_value")]
+    pub draft_value: String,
+
+    /// Rules skipped for draft files. Drafts are not detected while empty.
+    #[serde(default, alias = "draft_disable")]
+    pub draft_disable: Vec<String>,
+
     /// Additional rules to enable on top of the base set (additive)
     #[serde(default, alias = "extend_enable")]
     pub extend_enable: Vec<String>,
@@ -617,6 +954,81 @@ pub struct GlobalConfig {
     pub enable_is_explicit: bool,
 }
 
+/// How a subdirectory config file combines with the configs above it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NestedConfigs {
+    /// The nearest config applies on its own; use `extends` to inherit
+    #[default]
+    Standalone,
+    /// Configs from the project root down to the file's directory are merged,
+    /// nearer files winning, like `.editorconfig`
+    Merge,
+}
+
+impl std::fmt::Display for NestedConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            NestedConfigs::Standalone => "standalone",
+            NestedConfigs::Merge => "merge",
+        })
+    }
+}
+
+impl std::str::FromStr for NestedConfigs {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "standalone" => Ok(NestedConfigs::Standalone),
+            "merge" => Ok(NestedConfigs::Merge),
+            _ => Err(format!(
+                "unknown nested-configs mode '{s}' (expected standalone or merge)"
+            )),
+        }
+    }
+}
+
+/// How files larger than `max-file-size` are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LargeFileMode {
+    /// Read and check the file a chunk at a time, with only the rules that
+    /// look at one block at a time, and without fixes
+    #[default]
+    Stream,
+    /// Leave the file unchecked, with a warning
+    Skip,
+}
+
+impl std::fmt::Display for LargeFileMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LargeFileMode::Stream => "stream",
+            LargeFileMode::Skip => "skip",
+        })
+    }
+}
+
+impl std::str::FromStr for LargeFileMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "stream" => Ok(LargeFileMode::Stream),
+            "skip" => Ok(LargeFileMode::Skip),
+            _ => Err(format!("unknown large-file-mode '{s}' (expected stream or skip)")),
+        }
+    }
+}
+
+pub(crate) fn default_extensions() -> Vec<String> {
+    crate::discovery::MARKDOWN_EXTENSIONS
+        .iter()
+        .map(|ext| (*ext).to_string())
+        .collect()
+}
+
 fn default_respect_gitignore() -> bool {
     true
 }
@@ -625,6 +1037,18 @@ fn default_true() -> bool {
     true
 }
 
+fn default_fix_max_iterations() -> usize {
+    crate::fix_coordinator::DEFAULT_MAX_ITERATIONS
+}
+
+pub(super) fn default_draft_key() -> String {
+    "draft".to_string()
+}
+
+pub(super) fn default_draft_value() -> String {
+    "true".to_string()
+}
+
 // Add the Default impl
 impl Default for GlobalConfig {
     #[allow(deprecated)]
@@ -634,15 +1058,31 @@ impl Default for GlobalConfig {
             disable: Vec::new(),
             exclude: Vec::new(),
             include: Vec::new(),
+            extensions: default_extensions(),
+            extract_from: Vec::new(),
+            html_markdown_patterns: Vec::new(),
             respect_gitignore: true,
             line_length: LineLength::default(),
+            length_mode: LengthMode::default(),
+            ambiguous_width: AmbiguousWidth::default(),
             output_format: None,
             fixable: Vec::new(),
             unfixable: Vec::new(),
             flavor: MarkdownFlavor::default(),
             force_exclude: false,
             cache_dir: None,
+            docs_base_url: None,
+            slugify: None,
             cache: true,
+            nested_configs: NestedConfigs::default(),
+            fix_max_iterations: default_fix_max_iterations(),
+            unsafe_fixes: false,
+            rule_timeout_ms: None,
+            max_file_size: None,
+            large_file_mode: LargeFileMode::default(),
+            draft_key: default_draft_key(),
+            draft_value: default_draft_value(),
+            draft_disable: Vec::new(),
             extend_enable: Vec::new(),
             extend_disable: Vec::new(),
             enable_is_explicit: false,
@@ -654,7 +1094,7 @@ impl GlobalConfig {
     /// Canonicalize every rule-name list in this `GlobalConfig`.
     ///
     /// Rewrites `enable`, `disable`, `extend_enable`, `extend_disable`, `fixable`,
-    /// and `unfixable` so that all entries are canonical rule IDs (`"MD033"`)
+    /// `unfixable` and `draft_disable` so that all entries are canonical rule IDs (`"MD033"`)
     /// rather than aliases (`"no-inline-html"`). Duplicates are removed,
     /// preserving first-occurrence order; the special `"all"` keyword is
     /// preserved.
@@ -672,6 +1112,7 @@ impl GlobalConfig {
         canonicalize_rule_list_in_place(&mut self.extend_disable);
         canonicalize_rule_list_in_place(&mut self.fixable);
         canonicalize_rule_list_in_place(&mut self.unfixable);
+        canonicalize_rule_list_in_place(&mut self.draft_disable);
     }
 }
 
@@ -916,6 +1357,24 @@ style = "consistent"
     .to_string()
 }
 
+/// The value of `extends`: a single base config or a list merged in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum ExtendsValue {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ExtendsValue {
+    /// The base config paths, in merge order
+    pub fn paths(&self) -> &[String] {
+        match self {
+            ExtendsValue::One(path) => std::slice::from_ref(path),
+            ExtendsValue::Many(paths) => paths,
+        }
+    }
+}
+
 /// Errors that can occur when loading configuration
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -943,6 +1402,12 @@ pub enum ConfigError {
     #[error("extends target not found: {path} (referenced from {from})")]
     ExtendsNotFound { path: String, from: String },
 
+    /// An `extends` entry names a remote source, which is never fetched
+    #[error(
+        "extends target {spec} is not a local path (referenced from {from}); rumdl does not fetch remote configs, vendor the file locally and extend its path"
+    )]
+    ExtendsRemote { spec: String, from: String },
+
     /// An `extends` path referenced an environment variable that is not set
     #[error("extends path references undefined environment variable ${var} (referenced from {from})")]
     ExtendsUndefinedVar { var: String, from: String },
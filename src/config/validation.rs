@@ -1,6 +1,6 @@
 use super::flavor::{ConfigLoaded, ConfigValidated};
-use super::registry::{RULE_ALIAS_MAP, RuleRegistry, is_valid_rule_name, resolve_rule_name_alias};
-use super::source_tracking::{ConfigValidationWarning, SourcedConfig, SourcedRuleConfig};
+use super::registry::{RULE_ALIAS_MAP, RuleRegistry, is_valid_rule_name};
+use super::source_tracking::{ConfigSource, ConfigValidationWarning, SourcedConfig, SourcedRuleConfig};
 use std::collections::BTreeMap;
 use std::path::Path;
 
@@ -26,7 +26,7 @@ pub fn validate_cli_rule_names(
             if name.eq_ignore_ascii_case("all") {
                 continue;
             }
-            if resolve_rule_name_alias(name).is_none() {
+            if !is_valid_rule_name(name) {
                 let message = if let Some(suggestion) = suggest_similar_key(name, &all_rule_names) {
                     let formatted = if suggestion.starts_with("MD") {
                         suggestion
@@ -179,6 +179,26 @@ pub(super) fn validate_config_sourced_internal<S>(
         }
     }
 
+    for rule_name in &sourced.global.draft_disable.value {
+        if !is_valid_rule_name(rule_name) {
+            let message = if let Some(suggestion) = suggest_similar_key(rule_name, &all_rule_names) {
+                let formatted = if suggestion.starts_with("MD") {
+                    suggestion
+                } else {
+                    suggestion.to_lowercase()
+                };
+                format!("Unknown rule in global.draft-disable: {rule_name} (did you mean: {formatted}?)")
+            } else {
+                format!("Unknown rule in global.draft-disable: {rule_name}")
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: Some(rule_name.clone()),
+                key: None,
+            });
+        }
+    }
+
     for rule_name in &sourced.global.unfixable.value {
         if !is_valid_rule_name(rule_name) {
             let message = if let Some(suggestion) = suggest_similar_key(rule_name, &all_rule_names) {
@@ -235,41 +255,8 @@ fn validate_config_sourced_impl(
     }
     // 2. Unknown options and type mismatches
     for (rule, rule_cfg) in rules {
-        if let Some(valid_keys) = registry.config_keys_for(rule) {
-            for key in rule_cfg.values.keys() {
-                if !valid_keys.contains(key) {
-                    let valid_keys_vec: Vec<String> = valid_keys.iter().cloned().collect();
-                    let message = if let Some(suggestion) = suggest_similar_key(key, &valid_keys_vec) {
-                        format!("Unknown option for rule {rule}: {key} (did you mean: {suggestion}?)")
-                    } else {
-                        format!("Unknown option for rule {rule}: {key}")
-                    };
-                    warnings.push(ConfigValidationWarning {
-                        message,
-                        rule: Some(rule.clone()),
-                        key: Some(key.clone()),
-                    });
-                } else {
-                    // Type check: compare type of value to type of default
-                    if let Some(expected) = registry.expected_value_for(rule, key) {
-                        let actual = &rule_cfg.values[key].value;
-                        if !toml_value_type_matches(expected, actual) {
-                            warnings.push(ConfigValidationWarning {
-                                message: format!(
-                                    "Type mismatch for {}.{}: expected {}, got {}",
-                                    rule,
-                                    key,
-                                    toml_type_name(expected),
-                                    toml_type_name(actual)
-                                ),
-                                rule: Some(rule.clone()),
-                                key: Some(key.clone()),
-                            });
-                        }
-                    }
-                }
-            }
-        }
+        let options = rule_cfg.values.iter().map(|(key, sv)| (key, &sv.value));
+        warnings.extend(validate_rule_options(rule, options, registry));
     }
     // 3. Unknown global options (from unknown_keys)
     let known_global_keys = vec![
@@ -278,23 +265,49 @@ fn validate_config_sourced_impl(
         "extend-enable".to_string(),
         "extend-disable".to_string(),
         "include".to_string(),
+        "extensions".to_string(),
         "exclude".to_string(),
+        "extract-from".to_string(),
+        "html-markdown-patterns".to_string(),
         "respect-gitignore".to_string(),
         "line-length".to_string(),
+        "length-mode".to_string(),
+        "ambiguous-width".to_string(),
         "fixable".to_string(),
         "unfixable".to_string(),
         "flavor".to_string(),
         "force-exclude".to_string(),
         "output-format".to_string(),
         "cache-dir".to_string(),
+        "docs-base-url".to_string(),
+        "slugify".to_string(),
         "cache".to_string(),
+        "fix-max-iterations".to_string(),
+        "unsafe-fixes".to_string(),
+        "rule-timeout-ms".to_string(),
+        "max-file-size".to_string(),
+        "large-file-mode".to_string(),
+        "draft-key".to_string(),
+        "draft-value".to_string(),
+        "draft-disable".to_string(),
     ];
 
     for (section, key, file_path) in unknown_keys {
         // Convert file path to relative for cleaner output
         let display_path = file_path.as_ref().map(|p| to_relative_display_path(p));
 
-        if section.contains("[global]") || section.contains("[tool.rumdl]") {
+        if section == "[ci]" || section == "[prose-styles]" {
+            let message = if let Some(ref path) = display_path {
+                format!("Invalid {section} section in {path}: {key}")
+            } else {
+                format!("Invalid {section} section: {key}")
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: None,
+                key: None,
+            });
+        } else if section.contains("[global]") || section.contains("[tool.rumdl]") {
             let message = if let Some(suggestion) = suggest_similar_key(key, &known_global_keys) {
                 if let Some(ref path) = display_path {
                     format!("Unknown global option in {path}: {key} (did you mean: {suggestion}?)")
@@ -345,6 +358,51 @@ fn validate_config_sourced_impl(
     warnings
 }
 
+/// Unknown options and type mismatches in the config section of one rule.
+///
+/// Rules without a config schema (unknown rules) produce no warnings.
+pub fn validate_rule_options<'a>(
+    rule: &str,
+    options: impl IntoIterator<Item = (&'a String, &'a toml::Value)>,
+    registry: &RuleRegistry,
+) -> Vec<ConfigValidationWarning> {
+    let mut warnings = Vec::new();
+    let Some(valid_keys) = registry.config_keys_for(rule) else {
+        return warnings;
+    };
+    for (key, value) in options {
+        if !valid_keys.contains(key) {
+            let valid_keys_vec: Vec<String> = valid_keys.iter().cloned().collect();
+            let message = if let Some(suggestion) = suggest_similar_key(key, &valid_keys_vec) {
+                format!("Unknown option for rule {rule}: {key} (did you mean: {suggestion}?)")
+            } else {
+                format!("Unknown option for rule {rule}: {key}")
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: Some(rule.to_string()),
+                key: Some(key.clone()),
+            });
+        } else if let Some(expected) = registry.expected_value_for(rule, key) {
+            // Type check: compare type of value to type of default
+            if !toml_value_type_matches(expected, value) {
+                warnings.push(ConfigValidationWarning {
+                    message: format!(
+                        "Type mismatch for {}.{}: expected {}, got {}",
+                        rule,
+                        key,
+                        toml_type_name(expected),
+                        toml_type_name(value)
+                    ),
+                    rule: Some(rule.to_string()),
+                    key: Some(key.clone()),
+                });
+            }
+        }
+    }
+    warnings
+}
+
 /// Convert a file path to a display-friendly relative path.
 ///
 /// Tries to make the path relative to the current working directory.
@@ -393,6 +451,50 @@ pub fn validate_config_sourced(
     validate_config_sourced_internal(sourced, registry)
 }
 
+/// Find deprecated settings in a loaded config.
+///
+/// Deprecated settings still take effect, so these are reported separately from
+/// the validation warnings: `rumdl config lint` shows them without failing.
+pub fn deprecated_config_usages<S>(
+    sourced: &SourcedConfig<S>,
+    registry: &RuleRegistry,
+) -> Vec<ConfigValidationWarning> {
+    let in_file = |origin: &Option<String>| {
+        origin
+            .as_deref()
+            .map(|path| format!(" in {}", to_relative_display_path(path)))
+            .unwrap_or_default()
+    };
+
+    let mut warnings = Vec::new();
+    if sourced.global.force_exclude.source != ConfigSource::Default {
+        warnings.push(ConfigValidationWarning {
+            message: format!(
+                "global.force-exclude{} is deprecated and has no effect; exclude patterns are always respected",
+                in_file(&sourced.global.force_exclude.origin)
+            ),
+            rule: None,
+            key: Some("force-exclude".to_string()),
+        });
+    }
+
+    for (rule, rule_cfg) in &sourced.rules {
+        for (key, value) in &rule_cfg.values {
+            if let Some(replacement) = registry.deprecated_key_replacement(rule, key) {
+                warnings.push(ConfigValidationWarning {
+                    message: format!(
+                        "{rule}.{key}{} is deprecated; use {replacement} instead",
+                        in_file(&value.origin)
+                    ),
+                    rule: Some(rule.clone()),
+                    key: Some(key.clone()),
+                });
+            }
+        }
+    }
+    warnings
+}
+
 /// Validate a config that has already been validated (no-op, returns stored warnings).
 ///
 /// This exists for API consistency - validated configs already have their warnings stored.
@@ -175,11 +175,15 @@ mod tests {
 
         // Create project config fragment
         let mut project_fragment = SourcedConfigFragment {
-            extends: None,
+            extends: Vec::new(),
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
             per_file_flavor: SourcedValue::new(Default::default(), ConfigSource::Default),
+            overrides: SourcedValue::new(Default::default(), ConfigSource::Default),
+            profiles: SourcedValue::new(Default::default(), ConfigSource::Default),
             code_block_tools: SourcedValue::new(Default::default(), ConfigSource::Default),
+            ci: SourcedValue::new(Default::default(), ConfigSource::Default),
+            prose_styles: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
             rule_display_names: Default::default(),
             unknown_keys: vec![],
@@ -209,11 +213,15 @@ mod tests {
 
         // Project config enables MD013 (conflict!)
         let mut project_fragment = SourcedConfigFragment {
-            extends: None,
+            extends: Vec::new(),
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
             per_file_flavor: SourcedValue::new(Default::default(), ConfigSource::Default),
+            overrides: SourcedValue::new(Default::default(), ConfigSource::Default),
+            profiles: SourcedValue::new(Default::default(), ConfigSource::Default),
             code_block_tools: SourcedValue::new(Default::default(), ConfigSource::Default),
+            ci: SourcedValue::new(Default::default(), ConfigSource::Default),
+            prose_styles: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
             rule_display_names: Default::default(),
             unknown_keys: vec![],
@@ -277,11 +285,15 @@ mod tests {
 
         // Project config has empty enable (no rules explicitly enabled)
         let project_fragment = SourcedConfigFragment {
-            extends: None,
+            extends: Vec::new(),
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
             per_file_flavor: SourcedValue::new(Default::default(), ConfigSource::Default),
+            overrides: SourcedValue::new(Default::default(), ConfigSource::Default),
+            profiles: SourcedValue::new(Default::default(), ConfigSource::Default),
             code_block_tools: SourcedValue::new(Default::default(), ConfigSource::Default),
+            ci: SourcedValue::new(Default::default(), ConfigSource::Default),
+            prose_styles: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
             rule_display_names: Default::default(),
             unknown_keys: vec![],
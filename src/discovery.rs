@@ -5,7 +5,9 @@
 //! rumdl process here? The pieces of that answer that must never diverge
 //! live in this module:
 //!
-//! - the markdown extension set and how it is matched,
+//! - the markdown extension set and how it is matched, including the
+//!   configured `extensions`,
+//! - how config `include` patterns select files,
 //! - how ignore-file handling (`.gitignore`, `.markdownlintignore`, hidden
 //!   entries) is configured on a walker,
 //! - how `exclude` patterns from config are expanded and matched.
@@ -144,7 +146,34 @@ pub fn has_markdown_extension(path: &Path) -> bool {
     path.extension().is_some_and(is_markdown_extension)
 }
 
-/// Ignore-handling options applied to a markdown discovery walk.
+/// Whether `ext` is one of `extensions` (config `extensions`). Matches
+/// case-insensitively and accepts entries written with a leading dot.
+pub fn is_extension_in(ext: &OsStr, extensions: &[String]) -> bool {
+    ext.to_str().is_some_and(|s| {
+        extensions
+            .iter()
+            .any(|known| s.eq_ignore_ascii_case(known.trim_start_matches('.')))
+    })
+}
+
+/// Config `include` patterns compiled against `root`, with the semantics the
+/// CLI walker gives them: gitignore-style globs, anchored to `root` when they
+/// contain a `/`. `None` when there are no patterns, i.e. everything is
+/// included. Invalid patterns are skipped.
+pub fn include_overrides(root: &Path, patterns: &[String]) -> Option<ignore::overrides::Override> {
+    if patterns.is_empty() {
+        return None;
+    }
+    let mut builder = ignore::overrides::OverrideBuilder::new(root);
+    for pattern in patterns {
+        if let Err(e) = builder.add(pattern) {
+            log::warn!("Invalid include pattern '{pattern}': {e}");
+        }
+    }
+    builder.build().ok()
+}
+
+/// Options applied to a markdown discovery walk.
 #[derive(Debug, Clone)]
 pub struct MarkdownWalkOptions {
     /// Honor `.gitignore`, `.ignore`, global gitignore, `.git/info/exclude`,
@@ -153,6 +182,13 @@ pub struct MarkdownWalkOptions {
     /// Skip `.git`, `node_modules`, and `target` directories outright, even
     /// when gitignore handling is disabled or would not cover them.
     pub skip_vendor_dirs: bool,
+    /// Extensions of the files that count as markdown. Driven by
+    /// `global.extensions`; callers filter walked files with
+    /// [`is_markdown_path`](Self::is_markdown_path).
+    pub extensions: Vec<String>,
+    /// Config `include` patterns; when non-empty, only matching files count.
+    /// Callers compile them per root with [`include_overrides`].
+    pub include: Vec<String>,
 }
 
 impl Default for MarkdownWalkOptions {
@@ -160,10 +196,20 @@ impl Default for MarkdownWalkOptions {
         Self {
             respect_gitignore: true,
             skip_vendor_dirs: false,
+            extensions: crate::config::types::default_extensions(),
+            include: Vec::new(),
         }
     }
 }
 
+impl MarkdownWalkOptions {
+    /// Whether `path` has one of the configured markdown extensions.
+    pub fn is_markdown_path(&self, path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| is_extension_in(ext, &self.extensions))
+    }
+}
+
 /// Apply the shared ignore-handling configuration to a walker.
 ///
 /// Hidden entries are always walked (a hidden `docs/.pages.md` lints the
@@ -299,6 +345,25 @@ mod tests {
         assert!(!has_markdown_extension(Path::new("lib.rs")));
     }
 
+    #[test]
+    fn configured_extensions_match_case_insensitively() {
+        let extensions = vec!["md".to_string(), ".txt".to_string()];
+        for ext in ["md", "MD", "txt", "Txt"] {
+            assert!(is_extension_in(OsStr::new(ext), &extensions), "{ext} should match");
+        }
+        for ext in ["markdown", "rs", ""] {
+            assert!(!is_extension_in(OsStr::new(ext), &extensions), "{ext} should not match");
+        }
+
+        let options = MarkdownWalkOptions {
+            extensions,
+            ..Default::default()
+        };
+        assert!(options.is_markdown_path(Path::new("docs/notes.txt")));
+        assert!(!options.is_markdown_path(Path::new("docs/guide.markdown")));
+        assert!(MarkdownWalkOptions::default().is_markdown_path(Path::new("docs/guide.markdown")));
+    }
+
     #[test]
     fn walk_includes_hidden_files() {
         let temp = tempdir().unwrap();
@@ -380,6 +445,7 @@ mod tests {
                     // temp directory's ancestry cannot mask the vendor-dir filtering
                     // this test exercises.
                     respect_gitignore: false,
+                    ..Default::default()
                 },
             )
             .build()
@@ -1,7 +1,9 @@
 //! Linting of markdown embedded in Rust doc comments (`///` and `//!`).
 //!
 //! This module provides extraction and check-only logic for line doc comments.
-//! It is used by both the CLI and LSP to lint Rust doc comments.
+//! It is used by both the CLI and LSP to lint Rust doc comments. Python
+//! docstrings and JSDoc blocks are extracted by the [`extraction`] submodule
+//! and checked through the same path (see [`check_source_doc_blocks`]).
 //!
 //! **Precondition:** Input content must be LF-normalized (no `\r\n`).
 //! The CLI path handles this via `normalize_line_ending`, but callers using
@@ -14,13 +16,24 @@ use crate::lint_context::LintContext;
 use crate::rule::{LintWarning, Rule};
 use crate::rules::md013_line_length::MD013LineLength;
 
+mod extraction;
+
+pub use extraction::{SourceLanguage, extract_jsdoc_blocks, extract_python_docstrings};
+
 /// The kind of doc comment: outer (`///`) or inner (`//!`).
+///
+/// Blocks extracted from other languages use [`DocCommentKind::Docstring`]
+/// and [`DocCommentKind::JsDoc`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DocCommentKind {
     /// Outer doc comment (`///`)
     Outer,
     /// Inner doc comment (`//!`)
     Inner,
+    /// Python docstring (`"""..."""`)
+    Docstring,
+    /// JSDoc block comment (`/** ... */`)
+    JsDoc,
 }
 
 /// Metadata for a single line in a doc comment block.
@@ -101,6 +114,7 @@ fn extract_markdown_from_line(trimmed: &str, kind: DocCommentKind) -> &str {
     let prefix = match kind {
         DocCommentKind::Outer => "///",
         DocCommentKind::Inner => "//!",
+        DocCommentKind::Docstring | DocCommentKind::JsDoc => "",
     };
 
     let after_prefix = &trimmed[prefix.len()..];
@@ -221,10 +235,32 @@ pub fn check_doc_comment_blocks(
     rules: &[Box<dyn Rule>],
     config: &rumdl_config::Config,
 ) -> Vec<LintWarning> {
-    let blocks = extract_doc_comment_blocks(content);
+    check_blocks(&extract_doc_comment_blocks(content), rules, config)
+}
+
+/// Check the documentation blocks of a source file in the given language.
+///
+/// Rust files use the same extraction as [`check_doc_comment_blocks`]; Python
+/// docstrings and JSDoc blocks are extracted by the [`extraction`] submodule.
+/// Warnings are remapped to the original file and carry no fixes.
+pub fn check_source_doc_blocks(
+    content: &str,
+    language: SourceLanguage,
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+) -> Vec<LintWarning> {
+    check_blocks(&language.extract_blocks(content), rules, config)
+}
+
+/// Lint extracted blocks and remap warning positions to the source file.
+fn check_blocks(
+    blocks: &[DocCommentBlock],
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+) -> Vec<LintWarning> {
     let mut all_warnings = Vec::new();
 
-    for block in &blocks {
+    for block in blocks {
         // Skip empty blocks to avoid spurious warnings
         if block.markdown.trim().is_empty() {
             continue;
@@ -0,0 +1,504 @@
+//! Extraction of markdown from documentation comments in non-Rust sources.
+//!
+//! Each extractor produces [`DocCommentBlock`]s carrying the same line and
+//! column mapping data as the Rust extractor, so warnings found in the
+//! extracted markdown are remapped to the original file by the shared
+//! check path in the parent module.
+//!
+//! Supported sources:
+//!
+//! - Python docstrings (module, class, and function docstrings delimited by
+//!   `"""` or `'''`, dedented the way `inspect.cleandoc` does)
+//! - JavaScript/TypeScript JSDoc blocks (`/** ... */`); the description is
+//!   extracted up to the first block tag (`@param`, `@returns`, ...)
+//!
+//! **Precondition:** Input content must be LF-normalized (no `\r\n`).
+
+use std::path::Path;
+
+use super::{DocCommentBlock, DocCommentKind, DocCommentLineInfo};
+
+/// Source languages whose documentation comments can be linted as markdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLanguage {
+    /// Rust line doc comments (`///`, `//!`)
+    Rust,
+    /// Python docstrings
+    Python,
+    /// JavaScript/TypeScript JSDoc blocks
+    JavaScript,
+}
+
+impl SourceLanguage {
+    /// Detect the source language from a file extension (case-insensitive).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "rs" => Some(Self::Rust),
+            "py" | "pyi" => Some(Self::Python),
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => Some(Self::JavaScript),
+            _ => None,
+        }
+    }
+
+    /// Extract all documentation blocks for this language.
+    pub fn extract_blocks(self, content: &str) -> Vec<DocCommentBlock> {
+        match self {
+            Self::Rust => super::extract_doc_comment_blocks(content),
+            Self::Python => extract_python_docstrings(content),
+            Self::JavaScript => extract_jsdoc_blocks(content),
+        }
+    }
+}
+
+/// One line of extracted markdown with its position in the source file.
+struct ExtractedLine<'a> {
+    /// 0-indexed line number in the source file
+    file_line: usize,
+    /// Leading whitespace stripped from the source line
+    leading_whitespace: &'a str,
+    /// Comment syntax stripped after the whitespace (delimiter, `*`, ...)
+    prefix: &'a str,
+    /// The markdown text
+    text: &'a str,
+}
+
+/// Byte offsets of the start of every line, plus a sentinel for the end.
+fn line_starts(content: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(content.match_indices('\n').map(|(i, _)| i + 1));
+    if !content.ends_with('\n') {
+        starts.push(content.len());
+    }
+    starts
+}
+
+/// Build a block from extracted lines, dropping leading and trailing blank lines.
+///
+/// Lines must be consecutive in the source file. Returns `None` when nothing
+/// but whitespace remains.
+fn build_block(kind: DocCommentKind, lines: &[ExtractedLine<'_>], starts: &[usize]) -> Option<DocCommentBlock> {
+    let first = lines.iter().position(|l| !l.text.trim().is_empty())?;
+    let last = lines.iter().rposition(|l| !l.text.trim().is_empty())?;
+    let kept = &lines[first..=last];
+
+    let start_line = kept[0].file_line;
+    let end_line = kept[kept.len() - 1].file_line;
+    let markdown = kept.iter().map(|l| l.text).collect::<Vec<_>>().join("\n");
+    let line_metadata = kept
+        .iter()
+        .map(|l| DocCommentLineInfo {
+            leading_whitespace: l.leading_whitespace.to_string(),
+            prefix: l.prefix.to_string(),
+        })
+        .collect();
+    let prefix_byte_lengths = kept
+        .iter()
+        .map(|l| l.leading_whitespace.len() + l.prefix.len())
+        .collect();
+
+    Some(DocCommentBlock {
+        kind,
+        start_line,
+        end_line,
+        byte_start: starts[start_line],
+        byte_end: starts.get(end_line + 1).copied().unwrap_or(starts[starts.len() - 1]),
+        markdown,
+        line_metadata,
+        prefix_byte_lengths,
+    })
+}
+
+/// Split a line into its leading whitespace and the rest.
+fn split_indent(line: &str) -> (&str, &str) {
+    let rest = line.trim_start_matches([' ', '\t']);
+    (&line[..line.len() - rest.len()], rest)
+}
+
+/// Return the opening docstring delimiter (including any string prefix such as
+/// `r` or `u`) and the quote style, if `code` starts a triple-quoted string.
+fn docstring_opening(code: &str) -> Option<(&str, &'static str)> {
+    let after_prefix = code.trim_start_matches(['r', 'R', 'u', 'U']);
+    let prefix_len = code.len() - after_prefix.len();
+    if prefix_len > 1 {
+        return None;
+    }
+    for quote in ["\"\"\"", "'''"] {
+        if after_prefix.starts_with(quote) {
+            return Some((&code[..prefix_len + quote.len()], quote));
+        }
+    }
+    None
+}
+
+/// Whether a (left-trimmed) Python line starts a `def` or `class` header.
+fn is_python_definition_start(code: &str) -> bool {
+    ["def ", "async def ", "class "].iter().any(|kw| code.starts_with(kw))
+}
+
+/// Strip a trailing `# comment` from a line of Python code (ignores `#` inside
+/// simple string literals) and trim the result.
+fn python_code_part(code: &str) -> &str {
+    let mut in_string: Option<char> = None;
+    for (i, c) in code.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_string = Some(c),
+            None if c == '#' => return code[..i].trim_end(),
+            None => {}
+        }
+    }
+    code.trim_end()
+}
+
+/// Net number of `(`, `[` and `{` a line of Python code leaves open (negative
+/// when it closes more than it opens), ignoring comments and brackets inside
+/// simple string literals.
+fn python_bracket_balance(code: &str) -> isize {
+    let mut in_string: Option<char> = None;
+    let mut balance = 0;
+    for c in python_code_part(code).chars() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_string = Some(c),
+            None if matches!(c, '(' | '[' | '{') => balance += 1,
+            None if matches!(c, ')' | ']' | '}') => balance -= 1,
+            None => {}
+        }
+    }
+    balance
+}
+
+/// Extract Python docstrings as markdown blocks.
+///
+/// Recognizes the module docstring (the first statement of the file) and the
+/// first statement after every `def`/`class` header, including headers that
+/// span several lines. Docstrings are dedented following PEP 257: the first
+/// line is left-trimmed, the remaining lines lose their common indentation,
+/// and leading/trailing blank lines are dropped.
+pub fn extract_python_docstrings(content: &str) -> Vec<DocCommentBlock> {
+    let starts = line_starts(content);
+    let lines: Vec<&str> = content.split('\n').collect();
+    let mut blocks = Vec::new();
+
+    // The module docstring may be the first statement of the file
+    let mut expect_docstring = true;
+    // Brackets still open in a `def`/`class` header that spans several lines
+    let mut open_brackets: usize = 0;
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let line = lines[idx];
+        let (indent, code) = split_indent(line);
+
+        if open_brackets > 0 {
+            open_brackets = open_brackets.saturating_add_signed(python_bracket_balance(code));
+            expect_docstring = open_brackets == 0 && python_code_part(code).ends_with(':');
+            idx += 1;
+            continue;
+        }
+
+        if code.is_empty() || code.starts_with('#') {
+            idx += 1;
+            continue;
+        }
+
+        if expect_docstring && let Some((opening, quote)) = docstring_opening(code) {
+            expect_docstring = false;
+            let after_open = &code[opening.len()..];
+
+            // Single-line docstring: `"""Summary."""`
+            if let Some(close) = after_open.find(quote) {
+                let text = &after_open[..close];
+                let trimmed = text.trim_start();
+                let prefix_len = indent.len() + opening.len() + (text.len() - trimmed.len());
+                let extracted = [ExtractedLine {
+                    file_line: idx,
+                    leading_whitespace: indent,
+                    prefix: &line[indent.len()..prefix_len],
+                    text: trimmed,
+                }];
+                blocks.extend(build_block(DocCommentKind::Docstring, &extracted, &starts));
+                idx += 1;
+                continue;
+            }
+
+            // Multi-line docstring: collect until the closing delimiter
+            let first_text = after_open.trim_start();
+            let first_prefix_len = indent.len() + opening.len() + (after_open.len() - first_text.len());
+            let mut raw: Vec<(usize, &str)> = Vec::new();
+            let mut end = idx + 1;
+            while end < lines.len() {
+                if let Some(close) = lines[end].find(quote) {
+                    raw.push((end, &lines[end][..close]));
+                    break;
+                }
+                raw.push((end, lines[end]));
+                end += 1;
+            }
+
+            // Common indentation of the non-blank continuation lines (cleandoc)
+            let common_indent = raw
+                .iter()
+                .filter(|(_, text)| !text.trim().is_empty())
+                .map(|(_, text)| split_indent(text).0.len())
+                .min()
+                .unwrap_or(0);
+
+            let mut extracted = vec![ExtractedLine {
+                file_line: idx,
+                leading_whitespace: indent,
+                prefix: &line[indent.len()..first_prefix_len],
+                text: first_text.trim_end(),
+            }];
+            for (file_line, text) in raw {
+                let ws_len = split_indent(text).0.len();
+                let strip = if text.trim().is_empty() {
+                    text.len()
+                } else {
+                    common_indent.min(ws_len)
+                };
+                extracted.push(ExtractedLine {
+                    file_line,
+                    leading_whitespace: &text[..strip],
+                    prefix: "",
+                    text: text[strip..].trim_end(),
+                });
+            }
+
+            blocks.extend(build_block(DocCommentKind::Docstring, &extracted, &starts));
+            idx = end + 1;
+            continue;
+        }
+
+        expect_docstring = false;
+        if is_python_definition_start(code) {
+            // A one-line `def f(x): return x` has no docstring and no header
+            // continuing on the next line
+            open_brackets = usize::try_from(python_bracket_balance(code)).unwrap_or(0);
+            expect_docstring = open_brackets == 0 && python_code_part(code).ends_with(':');
+        }
+        idx += 1;
+    }
+
+    blocks
+}
+
+/// Strip the conventional ` * ` continuation prefix from a JSDoc line, returning
+/// `(prefix, text)` where `prefix` is the stripped comment syntax.
+fn split_jsdoc_continuation(rest: &str) -> (&str, &str) {
+    let Some(after_star) = rest.strip_prefix('*') else {
+        return ("", rest);
+    };
+    let prefix_len = if after_star.starts_with(' ') || after_star.starts_with('\t') {
+        2
+    } else {
+        1
+    };
+    (&rest[..prefix_len], &rest[prefix_len..])
+}
+
+/// Extract JSDoc (`/** ... */`) blocks as markdown.
+///
+/// Only blocks whose `/**` opens the line are recognized; `/***` banners and
+/// the empty `/**/` comment are ignored. The description ends at the first
+/// line starting with a block tag (`@param`, `@returns`, ...), since tag
+/// sections are structured data rather than prose.
+pub fn extract_jsdoc_blocks(content: &str) -> Vec<DocCommentBlock> {
+    let starts = line_starts(content);
+    let lines: Vec<&str> = content.split('\n').collect();
+    let mut blocks = Vec::new();
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let line = lines[idx];
+        let (indent, code) = split_indent(line);
+
+        let is_opening = code.starts_with("/**") && !code.starts_with("/***") && !code.starts_with("/**/");
+        if !is_opening {
+            idx += 1;
+            continue;
+        }
+
+        let mut extracted: Vec<ExtractedLine<'_>> = Vec::new();
+        let mut in_tags = false;
+
+        // Opening line: `/** text` or `/** text */`
+        let after_open = &code[3..];
+        let (first_body, closed) = match after_open.find("*/") {
+            Some(close) => (&after_open[..close], true),
+            None => (after_open, false),
+        };
+        let first_text = first_body.trim_start();
+        let first_prefix_end = indent.len() + 3 + (first_body.len() - first_text.len());
+        if first_text.starts_with('@') {
+            in_tags = true;
+        } else {
+            extracted.push(ExtractedLine {
+                file_line: idx,
+                leading_whitespace: indent,
+                prefix: &line[indent.len()..first_prefix_end],
+                text: first_text.trim_end(),
+            });
+        }
+
+        let mut end = idx;
+        if !closed {
+            end += 1;
+            while end < lines.len() {
+                let current = lines[end];
+                let (ws, rest) = split_indent(current);
+                let (body, is_last) = match rest.find("*/") {
+                    Some(close) => (&rest[..close], true),
+                    None => (rest, false),
+                };
+                let (prefix, text) = split_jsdoc_continuation(body);
+                if text.trim_start().starts_with('@') {
+                    in_tags = true;
+                }
+                if !in_tags {
+                    extracted.push(ExtractedLine {
+                        file_line: end,
+                        leading_whitespace: ws,
+                        prefix,
+                        text: text.trim_end(),
+                    });
+                }
+                if is_last {
+                    break;
+                }
+                end += 1;
+            }
+        }
+
+        blocks.extend(build_block(DocCommentKind::JsDoc, &extracted, &starts));
+        idx = end + 1;
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_path() {
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("lib.rs")),
+            Some(SourceLanguage::Rust)
+        );
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("a/b.py")),
+            Some(SourceLanguage::Python)
+        );
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("x.PYI")),
+            Some(SourceLanguage::Python)
+        );
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("app.tsx")),
+            Some(SourceLanguage::JavaScript)
+        );
+        assert_eq!(SourceLanguage::from_path(Path::new("README.md")), None);
+        assert_eq!(SourceLanguage::from_path(Path::new("Makefile")), None);
+    }
+
+    #[test]
+    fn test_python_module_docstring() {
+        let content = "#!/usr/bin/env python\n\"\"\"Module summary.\n\nMore text.\n\"\"\"\nimport os\n";
+        let blocks = extract_python_docstrings(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start_line, 1);
+        assert_eq!(blocks[0].end_line, 3);
+        assert_eq!(blocks[0].markdown, "Module summary.\n\nMore text.");
+        assert_eq!(blocks[0].prefix_byte_lengths, vec![3, 0, 0]);
+    }
+
+    #[test]
+    fn test_python_function_docstring_is_dedented() {
+        let content =
+            "def f(x):\n    \"\"\"\n    Summary line.\n\n    - item\n      continued\n    \"\"\"\n    return x\n";
+        let blocks = extract_python_docstrings(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start_line, 2);
+        assert_eq!(blocks[0].markdown, "Summary line.\n\n- item\n  continued");
+        assert_eq!(blocks[0].prefix_byte_lengths, vec![4, 0, 4, 4]);
+        assert_eq!(blocks[0].kind, DocCommentKind::Docstring);
+    }
+
+    #[test]
+    fn test_python_single_line_and_raw_docstrings() {
+        let content = "class A:\n    r'''Raw *docstring*.'''\n\n    def m(self):\n        \"\"\"Method doc.\"\"\"\n";
+        let blocks = extract_python_docstrings(content);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].markdown, "Raw *docstring*.");
+        assert_eq!(blocks[0].prefix_byte_lengths, vec![8]);
+        assert_eq!(blocks[1].markdown, "Method doc.");
+        assert_eq!(blocks[1].start_line, 4);
+    }
+
+    #[test]
+    fn test_python_multiline_signature() {
+        let content = "def f(\n    a: int,  # the value\n) -> int:\n    \"\"\"Doc.\"\"\"\n";
+        let blocks = extract_python_docstrings(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start_line, 3);
+    }
+
+    #[test]
+    fn test_python_one_line_definition() {
+        let content =
+            "def f(x): return x\nif f(1):\n    \"\"\"Not a docstring.\"\"\"\n\ndef g():\n    \"\"\"Doc.\"\"\"\n";
+        let blocks = extract_python_docstrings(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].markdown, "Doc.");
+        assert_eq!(blocks[0].start_line, 5);
+    }
+
+    #[test]
+    fn test_python_ignores_non_docstring_strings() {
+        let content = "import os\nx = \"\"\"not a docstring\"\"\"\n\ndef f():\n    y = 1\n    \"\"\"Also not.\"\"\"\n";
+        assert!(extract_python_docstrings(content).is_empty());
+    }
+
+    #[test]
+    fn test_jsdoc_block() {
+        let content =
+            "/**\n * Adds numbers.\n *\n * Use `add(1, 2)`.\n * @param {number} a\n */\nfunction add(a, b) {}\n";
+        let blocks = extract_jsdoc_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].kind, DocCommentKind::JsDoc);
+        assert_eq!(blocks[0].start_line, 1);
+        assert_eq!(blocks[0].end_line, 3);
+        assert_eq!(blocks[0].markdown, "Adds numbers.\n\nUse `add(1, 2)`.");
+        assert_eq!(blocks[0].prefix_byte_lengths, vec![3, 2, 3]);
+    }
+
+    #[test]
+    fn test_jsdoc_single_line_and_indented() {
+        let content = "class A {\n  /** Single line. */\n  m() {}\n  /***** banner *****/\n  /**/\n}\n";
+        let blocks = extract_jsdoc_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].markdown, "Single line.");
+        assert_eq!(blocks[0].prefix_byte_lengths, vec![6]);
+    }
+
+    #[test]
+    fn test_jsdoc_text_on_opening_and_closing_lines() {
+        let content = "/** First\n * second */\n";
+        let blocks = extract_jsdoc_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].markdown, "First\nsecond");
+        assert_eq!(blocks[0].byte_start, 0);
+        assert_eq!(blocks[0].byte_end, content.len());
+    }
+
+    #[test]
+    fn test_jsdoc_tags_only_block_is_skipped() {
+        let content = "/**\n * @type {string}\n */\nconst x = '';\n";
+        assert!(extract_jsdoc_blocks(content).is_empty());
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides functions for checking markdown content that appears
 //! inside fenced code blocks with `markdown` or `md` language tags. These
 //! functions are used by both the CLI and LSP to lint embedded markdown.
+//!
+//! It also extracts markdown regions from HTML files (see
+//! [`check_html_markdown_blocks`]), using configurable extraction regexes.
 
 use crate::code_block_tools::{CodeBlockToolsConfig, RUMDL_BUILTIN_TOOL};
 use crate::config as rumdl_config;
@@ -10,6 +13,8 @@ use crate::inline_config::InlineConfig;
 use crate::lint_context::LintContext;
 use crate::rule::{LintWarning, Rule};
 use crate::utils::code_block_utils::CodeBlockUtils;
+use regex::Regex;
+use std::path::Path;
 
 /// Maximum recursion depth for linting nested markdown blocks.
 ///
@@ -144,6 +149,163 @@ fn check_embedded_markdown_blocks_recursive(
     all_warnings
 }
 
+/// Built-in extraction regexes for markdown regions in HTML files.
+///
+/// Used when `html-markdown-patterns` is not configured. Each pattern captures
+/// the markdown in its `markdown` group.
+pub const DEFAULT_HTML_MARKDOWN_PATTERNS: &[&str] = &[
+    // <script type="text/markdown">, as used by <zero-md> and similar components
+    r#"(?s)<script\b[^>]*\btype\s*=\s*["']text/markdown["'][^>]*>(?P<markdown>.*?)</script>"#,
+    // <!-- markdown --> ... <!-- /markdown --> markers
+    r"(?s)<!--\s*markdown\s*-->(?P<markdown>.*?)<!--\s*/markdown\s*-->",
+    // Jinja/Nunjucks filter blocks: {% filter markdown %} ... {% endfilter %}
+    r"(?s)\{%-?\s*filter\s+markdown\s*-?%\}(?P<markdown>.*?)\{%-?\s*endfilter\s*-?%\}",
+    // Template tags: {% markdown %} ... {% endmarkdown %}
+    r"(?s)\{%-?\s*markdown\s*-?%\}(?P<markdown>.*?)\{%-?\s*endmarkdown\s*-?%\}",
+];
+
+/// Check if a path is an HTML file (`.html` or `.htm`, case-insensitive).
+pub fn is_html_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"))
+}
+
+/// Compile HTML extraction patterns, falling back to
+/// [`DEFAULT_HTML_MARKDOWN_PATTERNS`] when none are configured.
+///
+/// Invalid patterns are logged and skipped.
+pub fn compile_html_markdown_patterns(patterns: &[String]) -> Vec<Regex> {
+    let compile = |pattern: &str| match Regex::new(pattern) {
+        Ok(regex) => Some(regex),
+        Err(e) => {
+            log::warn!("Invalid regex in html-markdown-patterns '{pattern}': {e}");
+            None
+        }
+    };
+    if patterns.is_empty() {
+        DEFAULT_HTML_MARKDOWN_PATTERNS
+            .iter()
+            .filter_map(|p| compile(p))
+            .collect()
+    } else {
+        patterns.iter().filter_map(|p| compile(p)).collect()
+    }
+}
+
+/// Find the byte ranges of markdown regions in HTML content.
+///
+/// Each regex contributes the span of its `markdown` capture group, or of its
+/// first capture group (the whole match if it has none). Overlapping regions
+/// keep the earliest match. A whitespace-only remainder of the opening line
+/// and a whitespace-only closing line are trimmed, so regions usually span
+/// whole lines.
+pub fn extract_html_markdown_regions(content: &str, patterns: &[Regex]) -> Vec<(usize, usize)> {
+    let mut regions: Vec<(usize, usize)> = Vec::new();
+    for regex in patterns {
+        for caps in regex.captures_iter(content) {
+            let Some(m) = caps.name("markdown").or_else(|| caps.get(1)).or_else(|| caps.get(0)) else {
+                continue;
+            };
+            let (mut start, mut end) = (m.start(), m.end());
+            let text = m.as_str();
+            if let Some(nl) = text.find('\n')
+                && text[..nl].trim().is_empty()
+            {
+                start += nl + 1;
+            }
+            if let Some(nl) = content[start..end].rfind('\n')
+                && content[start + nl + 1..end].trim().is_empty()
+            {
+                end = start + nl + 1;
+            }
+            if start < end {
+                regions.push((start, end));
+            }
+        }
+    }
+
+    regions.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(regions.len());
+    for region in regions {
+        if merged.last().is_none_or(|last| region.0 >= last.1) {
+            merged.push(region);
+        }
+    }
+    merged
+}
+
+/// Check markdown regions embedded in an HTML file.
+///
+/// Regions are located with the configured `html-markdown-patterns` (or the
+/// built-in defaults), dedented, and linted. Warnings are remapped to their
+/// line and column in the HTML file and carry no fixes. Inline config
+/// comments (`<!-- rumdl-disable ... -->`) in the HTML file are honored.
+pub fn check_html_markdown_blocks(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+) -> Vec<LintWarning> {
+    let patterns = compile_html_markdown_patterns(&config.global.html_markdown_patterns);
+    let regions = extract_html_markdown_regions(content, &patterns);
+    if regions.is_empty() {
+        return Vec::new();
+    }
+
+    let inline_config = InlineConfig::from_content(content);
+    let mut all_warnings = Vec::new();
+
+    for (start, end) in regions {
+        let region = &content[start..end];
+        if region.trim().is_empty() {
+            continue;
+        }
+
+        let line_offset = content[..start].matches('\n').count();
+        let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+        // Non-zero only when the region starts mid-line (e.g. `<!-- markdown --># Title`)
+        let first_line_col = start - line_start;
+
+        let (stripped_content, common_indent) = strip_common_indent(region);
+        let ctx = LintContext::new(&stripped_content, config.markdown_flavor(), None);
+
+        let col_offset = |line: usize| {
+            if line == 1 {
+                first_line_col + common_indent.len()
+            } else {
+                common_indent.len()
+            }
+        };
+
+        for rule in rules {
+            match rule.name() {
+                "MD041" => continue, // "First line in file should be heading" - not a file
+                "MD047" => continue, // "File should end with newline" - not a file
+                _ => {}
+            }
+
+            if let Ok(rule_warnings) = rule.check(&ctx) {
+                for warning in rule_warnings {
+                    let file_line = warning.line + line_offset;
+                    if inline_config.is_rule_disabled(rule.name(), file_line) {
+                        continue;
+                    }
+                    all_warnings.push(LintWarning {
+                        line: file_line,
+                        column: warning.column + col_offset(warning.line),
+                        end_line: warning.end_line + line_offset,
+                        end_column: warning.end_column + col_offset(warning.end_line),
+                        fix: None,
+                        ..warning
+                    });
+                }
+            }
+        }
+    }
+
+    all_warnings
+}
+
 /// Strip common leading indentation from all non-empty lines.
 /// Returns the stripped content and the common indent string.
 pub fn strip_common_indent(content: &str) -> (String, String) {
@@ -178,3 +340,58 @@ pub fn strip_common_indent(content: &str) -> (String, String) {
     let indent_str = " ".repeat(min_indent);
     (stripped, indent_str)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_patterns() -> Vec<Regex> {
+        compile_html_markdown_patterns(&[])
+    }
+
+    #[test]
+    fn test_is_html_path() {
+        assert!(is_html_path(Path::new("site/index.html")));
+        assert!(is_html_path(Path::new("PAGE.HTM")));
+        assert!(!is_html_path(Path::new("README.md")));
+        assert!(!is_html_path(Path::new("html")));
+    }
+
+    #[test]
+    fn test_extract_script_markdown_region() {
+        let content =
+            "<zero-md>\n  <script type=\"text/markdown\">\n    # Title\n\n    Text.\n  </script>\n</zero-md>\n";
+        let regions = extract_html_markdown_regions(content, &default_patterns());
+        assert_eq!(regions.len(), 1);
+        let (start, end) = regions[0];
+        assert_eq!(&content[start..end], "    # Title\n\n    Text.\n");
+    }
+
+    #[test]
+    fn test_extract_comment_markers_and_template_filters() {
+        let content = "<!-- markdown -->\n# A\n<!-- /markdown -->\n{% filter markdown %}\n# B\n{% endfilter %}\n";
+        let regions = extract_html_markdown_regions(content, &default_patterns());
+        let texts: Vec<&str> = regions.iter().map(|&(s, e)| &content[s..e]).collect();
+        assert_eq!(texts, vec!["# A\n", "# B\n"]);
+    }
+
+    #[test]
+    fn test_custom_pattern_uses_first_group_and_skips_invalid() {
+        let patterns =
+            compile_html_markdown_patterns(&[r"(?s)<div class=.md.>(.*?)</div>".to_string(), "(unclosed".to_string()]);
+        assert_eq!(patterns.len(), 1);
+        let content = "<div class=\"md\">*text*</div>";
+        let regions = extract_html_markdown_regions(content, &patterns);
+        assert_eq!(regions, vec![(16, 22)]);
+    }
+
+    #[test]
+    fn test_overlapping_regions_keep_earliest() {
+        let patterns =
+            compile_html_markdown_patterns(&[r"(?s)<a>(.*?)</a>".to_string(), r"(?s)<b>(.*?)</b>".to_string()]);
+        let content = "<a>x <b>y</b> z</a>";
+        let regions = extract_html_markdown_regions(content, &patterns);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(&content[regions[0].0..regions[0].1], "x <b>y</b> z");
+    }
+}
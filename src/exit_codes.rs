@@ -1,3 +1,6 @@
+use crate::config::FailOnLevel;
+use crate::rule::{LintWarning, Severity};
+
 /// Exit codes for rumdl, following Ruff's convention
 ///
 /// These exit codes allow users and CI/CD systems to distinguish between
@@ -11,6 +14,67 @@ pub const VIOLATIONS_FOUND: i32 = 1;
 /// Tool error - Configuration error, file access error, or internal error
 pub const TOOL_ERROR: i32 = 2;
 
+/// Violations of a run, counted at each severity threshold of [`ExitPolicy`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IssueCounts {
+    /// Violations of any severity
+    pub issues: usize,
+    /// Warning or Error severity violations
+    pub warnings: usize,
+    /// Error severity violations
+    pub errors: usize,
+}
+
+impl IssueCounts {
+    /// Counts of a run that could not complete, which fail under every
+    /// policy except `never`
+    pub const ABORTED: Self = Self {
+        issues: usize::MAX,
+        warnings: usize::MAX,
+        errors: usize::MAX,
+    };
+
+    /// Count `warnings` towards the run
+    pub fn add(&mut self, warnings: &[LintWarning]) {
+        self.issues += warnings.len();
+        self.warnings += warnings
+            .iter()
+            .filter(|w| matches!(w.severity, Severity::Warning | Severity::Error))
+            .count();
+        self.errors += warnings.iter().filter(|w| w.severity == Severity::Error).count();
+    }
+
+    /// Counts of `warnings` alone
+    pub fn of(warnings: &[LintWarning]) -> Self {
+        let mut counts = Self::default();
+        counts.add(warnings);
+        counts
+    }
+}
+
+/// When a run exits with [`VIOLATIONS_FOUND`], from `--fail-on` and
+/// `--max-warnings` or the `[ci]` config section
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExitPolicy {
+    /// Severity that counts towards failing
+    pub fail_on: FailOnLevel,
+    /// Violations of that severity tolerated before failing
+    pub max_warnings: Option<usize>,
+}
+
+impl ExitPolicy {
+    /// Whether a run with `counts` fails
+    pub fn should_fail(&self, counts: &IssueCounts) -> bool {
+        let counted = match self.fail_on {
+            FailOnLevel::Never => return false,
+            FailOnLevel::Any => counts.issues,
+            FailOnLevel::Warning => counts.warnings,
+            FailOnLevel::Error => counts.errors,
+        };
+        counted > self.max_warnings.unwrap_or(0)
+    }
+}
+
 /// Helper functions for consistent exit behavior
 pub mod exit {
     use super::{SUCCESS, TOOL_ERROR, VIOLATIONS_FOUND};
@@ -30,3 +94,48 @@ pub mod exit {
         std::process::exit(TOOL_ERROR);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(severity: Severity) -> LintWarning {
+        LintWarning {
+            rule_name: Some("MD001".to_string()),
+            message: String::new(),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 1,
+            severity,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_exit_policy_thresholds() {
+        let counts = IssueCounts::of(&[
+            warning(Severity::Info),
+            warning(Severity::Warning),
+            warning(Severity::Warning),
+        ]);
+        assert_eq!(
+            counts,
+            IssueCounts {
+                issues: 3,
+                warnings: 2,
+                errors: 0
+            }
+        );
+
+        let policy = |fail_on, max_warnings| ExitPolicy { fail_on, max_warnings };
+        assert!(policy(FailOnLevel::Any, None).should_fail(&counts));
+        assert!(policy(FailOnLevel::Warning, None).should_fail(&counts));
+        assert!(!policy(FailOnLevel::Error, None).should_fail(&counts));
+        assert!(!policy(FailOnLevel::Never, None).should_fail(&IssueCounts::ABORTED));
+        assert!(!policy(FailOnLevel::Any, Some(3)).should_fail(&counts));
+        assert!(policy(FailOnLevel::Any, Some(2)).should_fail(&counts));
+        assert!(!policy(FailOnLevel::Warning, Some(2)).should_fail(&counts));
+        assert!(!ExitPolicy::default().should_fail(&IssueCounts::default()));
+    }
+}
@@ -6,8 +6,8 @@ use ignore::overrides::OverrideBuilder;
 use rumdl_config::resolve_rule_names;
 use rumdl_lib::config as rumdl_config;
 use rumdl_lib::discovery::{
-    ExcludeMatchers, ExplicitIncludeMatchers, MARKDOWN_EXTENSIONS, MarkdownWalkOptions, apply_markdown_walk_options,
-    expand_directory_pattern, has_markdown_extension, path_relative_to,
+    ExcludeMatchers, ExplicitIncludeMatchers, MarkdownWalkOptions, apply_markdown_walk_options,
+    expand_directory_pattern, is_extension_in, path_relative_to,
 };
 use rumdl_lib::rule::Rule;
 use std::collections::HashSet;
@@ -290,20 +290,27 @@ pub fn find_markdown_files(
         ExplicitIncludeMatchers::new(&[])
     };
 
+    // Source files whose doc comments are linted as markdown (`extract-from`).
+    // Unlike config include, these apply whenever directories are walked.
+    let extract_from = ExplicitIncludeMatchers::new(&config.global.extract_from);
+
     // --- Add Lintable File Type Filter ---
     // CLI --include: no type filter (user controls which files to process)
     // Config include: expanded filter (markdown + rust + explicitly named
     // files, since the user spelled those out)
-    // Default: markdown-only filter
+    // Default: markdown-only filter (the configured `extensions`)
     if args.include.is_none() {
         let mut types_builder = ignore::types::TypesBuilder::new();
         types_builder.add_defaults();
-        for ext in MARKDOWN_EXTENSIONS {
+        for ext in &config.global.extensions {
+            let ext = ext.trim_start_matches('.');
             types_builder.add("markdown", &format!("*.{ext}"))?;
+            // Type globs match case-sensitively; cover the conventional
+            // capitalized R Markdown extension explicitly.
+            if ext.eq_ignore_ascii_case("rmd") {
+                types_builder.add("markdown", "*.Rmd")?;
+            }
         }
-        // Type globs match case-sensitively; cover the conventional
-        // capitalized R Markdown extension explicitly.
-        types_builder.add("markdown", "*.Rmd")?;
         types_builder.select("markdown");
         if has_config_include {
             // Config include is active: also allow Rust files for doc comment linting
@@ -317,6 +324,12 @@ pub fn find_markdown_files(
             }
             types_builder.select("configinclude");
         }
+        if !extract_from.is_empty() {
+            for glob in extract_from.file_name_globs() {
+                types_builder.add("extractfrom", glob)?;
+            }
+            types_builder.select("extractfrom");
+        }
         let types = types_builder.build()?;
         walk_builder.types(types);
     }
@@ -426,6 +439,7 @@ pub fn find_markdown_files(
         &MarkdownWalkOptions {
             respect_gitignore: config.global.respect_gitignore,
             skip_vendor_dirs: false,
+            ..Default::default()
         },
     );
 
@@ -579,7 +593,8 @@ pub fn find_markdown_files(
     // --- Final Lintable File Filter ---
     // CLI --include: no extension filter (user controls which files to process)
     // Config include: allow markdown + rust extensions + explicitly named files
-    // Default: markdown-only extensions
+    // extract-from: allow the named source files
+    // Default: the configured markdown extensions
     if args.include.is_none() {
         // Explicit include patterns are matched against the same base the
         // walker overrides use, so the full pattern path applies: a broad
@@ -589,21 +604,23 @@ pub fn find_markdown_files(
         file_paths.retain(|path_str| {
             let path = Path::new(path_str);
             let is_rust = has_config_include && path.extension().is_some_and(|ext| ext.to_str() == Some("rs"));
-            if has_markdown_extension(path) || is_rust {
+            if path
+                .extension()
+                .is_some_and(|ext| is_extension_in(ext, &config.global.extensions))
+                || is_rust
+            {
                 return true;
             }
-            if explicit_includes.is_empty() {
+            if explicit_includes.is_empty() && extract_from.is_empty() {
                 return false;
             }
-            match explicit_include_base
+            // Outside the pattern base only unanchored patterns can still
+            // apply; matching the full path covers those.
+            let relative = explicit_include_base
                 .as_deref()
                 .and_then(|base| path_relative_to(path, base))
-            {
-                Some(relative) => explicit_includes.matches_relative_path(&relative),
-                // Outside the pattern base only unanchored patterns can
-                // still apply; matching the full path covers those.
-                None => explicit_includes.matches_relative_path(path_str),
-            }
+                .unwrap_or_else(|| path_str.clone());
+            explicit_includes.matches_relative_path(&relative) || extract_from.matches_relative_path(&relative)
         });
     }
     // -------------------------------------
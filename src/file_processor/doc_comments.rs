@@ -119,6 +119,8 @@ fn restore_doc_comment_prefixes(markdown: &str, block: &DocCommentBlock, trailin
     let bare_prefix = match block.kind {
         DocCommentKind::Outer => "///",
         DocCommentKind::Inner => "//!",
+        // Docstring and JSDoc blocks are check-only and never reach fix mode
+        DocCommentKind::Docstring | DocCommentKind::JsDoc => "",
     };
 
     for (i, md_line) in md_lines.iter().enumerate() {
@@ -4,8 +4,12 @@ use crate::cache::LintCache;
 use crate::formatter;
 use colored::*;
 use rumdl_lib::config as rumdl_config;
+use rumdl_lib::doc_comment_lint::SourceLanguage;
+use rumdl_lib::embedded_lint::{check_html_markdown_blocks, is_html_path};
+use rumdl_lib::inline_config::UNUSED_DISABLE_RULE;
 use rumdl_lib::lint_context::LintContext;
-use rumdl_lib::rule::{FixCapability, LintWarning, Rule};
+use rumdl_lib::rule::{FixCapability, FixSafety, LintWarning, Rule};
+use rumdl_lib::rules::MD057ExistingRelativeLinks;
 use rumdl_lib::utils::code_block_utils::CodeBlockUtils;
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
@@ -31,6 +35,8 @@ pub struct FileProcessResult {
     pub warnings: Vec<rumdl_lib::rule::LintWarning>,
     pub file_index: rumdl_lib::workspace_index::FileIndex,
     pub file_index_reused: bool,
+    /// With `--fix --dry-run --output-format json`, the fixes that would be applied
+    pub fix_plan: Option<formatter::FixPlan>,
 }
 
 pub fn is_rule_actually_fixable(config: &rumdl_config::Config, rule_name: &str) -> bool {
@@ -58,6 +64,7 @@ pub fn is_rule_actually_fixable(config: &rumdl_config::Config, rule_name: &str)
 /// 1. It's not in the unfixable config list
 /// 2. It's in the fixable config list (if specified)
 /// 3. The rule itself doesn't declare FixCapability::Unfixable
+/// 4. Its fixes are safe, or unsafe fixes are enabled
 ///
 /// This replaces hardcoded rule name checks (e.g., `&& name != "MD033"`) with
 /// capability-based checks that are future-proof for any rule.
@@ -73,7 +80,10 @@ pub fn is_rule_cli_fixable(rules: &[Box<dyn Rule>], config: &rumdl_config::Confi
     rules
         .iter()
         .find(|r| r.name().eq_ignore_ascii_case(rule_name))
-        .is_none_or(|r| r.fix_capability() != FixCapability::Unfixable)
+        .is_none_or(|r| {
+            r.fix_capability() != FixCapability::Unfixable
+                && (r.fix_safety() == FixSafety::Safe || config.global.unsafe_fixes)
+        })
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -86,6 +96,9 @@ pub fn process_file_with_formatter(
     quiet: bool,
     silent: bool,
     output_format: &rumdl_lib::output::OutputFormat,
+    rule_names: rumdl_lib::output::RuleNameDisplay,
+    show_docs_links: bool,
+    show_source: bool,
     output_writer: &rumdl_lib::output::OutputWriter,
     config: &rumdl_config::Config,
     cache: Option<std::sync::Arc<LintCache>>,
@@ -93,8 +106,15 @@ pub fn process_file_with_formatter(
     project_root: Option<&Path>,
     show_full_path: bool,
     cache_hashes: Option<&CacheHashes>,
+    changed_lines: Option<&crate::changed_lines::ChangedLines>,
+    report_unused_disables: bool,
+    interactive: Option<&std::sync::Mutex<crate::interactive_fix::InteractiveSession>>,
 ) -> FileProcessResult {
-    let formatter = output_format.create_formatter();
+    let mut formatter = output_format.formatter_with_source(
+        &rumdl_lib::output::DocsLinks::from_config(config),
+        show_docs_links,
+        show_source,
+    );
 
     // Convert to display path (relative) unless --show-full-path is set
     let display_path = if show_full_path {
@@ -103,12 +123,28 @@ pub fn process_file_with_formatter(
         to_display_path(file_path, project_root)
     };
 
+    // Files over `max-file-size` are streamed or skipped instead of read whole
+    if let Some(size) = oversized_file(file_path, config) {
+        return process_large_file(
+            file_path,
+            &display_path,
+            size,
+            rules,
+            fix_mode != crate::FixMode::Check || diff,
+            silent,
+            &mut *formatter,
+            rule_names,
+            output_writer,
+            config,
+        );
+    }
+
     // Call the original process_file_inner to get warnings, original line ending, and FileIndex
     let (
-        all_warnings,
+        mut all_warnings,
         mut content,
-        total_warnings,
-        fixable_warnings,
+        mut total_warnings,
+        mut fixable_warnings,
         original_line_ending,
         file_index,
         file_index_reused,
@@ -124,6 +160,21 @@ pub fn process_file_with_formatter(
         cache_hashes,
     );
 
+    // With --diff-base, keep only the warnings on changed lines
+    if let Some(changed) = changed_lines {
+        changed.retain(Path::new(file_path), &mut all_warnings);
+        total_warnings = all_warnings.len();
+        fixable_warnings = all_warnings
+            .iter()
+            .filter(|w| {
+                w.automatic_fix().is_some()
+                    && w.rule_name
+                        .as_ref()
+                        .is_some_and(|name| is_rule_cli_fixable(rules, config, name))
+            })
+            .count();
+    }
+
     // Compute filtered rules based on per-file-ignores for embedded markdown formatting
     // This ensures embedded markdown formatting respects per-file-ignores just like linting does
     let ignored_rules_for_file = config.get_ignored_rules_for_file(Path::new(file_path));
@@ -137,6 +188,34 @@ pub fn process_file_with_formatter(
         rules.to_vec()
     };
 
+    let extraction = extraction_for_file(file_path, config);
+
+    // With --report-unused-disables, add warnings for disable comments that
+    // suppress nothing. Extracted markdown has no comments of its own to report.
+    if report_unused_disables && extraction.is_none() {
+        let flavor = config.get_flavor_for_file(Path::new(file_path));
+        match rumdl_lib::lint_unused_disables(&content, &filtered_rules, flavor, Some(PathBuf::from(file_path))) {
+            Ok(mut unused) => {
+                if let Some(changed) = changed_lines {
+                    changed.retain(Path::new(file_path), &mut unused);
+                }
+                if !unused.is_empty() {
+                    total_warnings += unused.len();
+                    if is_rule_cli_fixable(rules, config, UNUSED_DISABLE_RULE) {
+                        fixable_warnings += unused.len();
+                    }
+                    all_warnings.extend(unused);
+                    all_warnings.sort_by_key(|w| (w.line, w.column));
+                }
+            }
+            Err(e) => {
+                if !silent {
+                    eprintln!("Error checking unused disables in {display_path}: {e}");
+                }
+            }
+        }
+    }
+
     // In check mode with no warnings, return early
     if total_warnings == 0 && fix_mode == crate::FixMode::Check && !diff {
         return FileProcessResult {
@@ -148,6 +227,7 @@ pub fn process_file_with_formatter(
             warnings: Vec::new(),
             file_index,
             file_index_reused,
+            fix_plan: None,
         };
     }
 
@@ -173,6 +253,7 @@ pub fn process_file_with_formatter(
                 warnings: Vec::new(),
                 file_index,
                 file_index_reused,
+                fix_plan: None,
             };
         }
     }
@@ -181,10 +262,14 @@ pub fn process_file_with_formatter(
     if !silent && fix_mode == crate::FixMode::Check {
         if diff {
             // In diff mode, only show warnings for unfixable issues
-            let unfixable_warnings: Vec<_> = all_warnings.iter().filter(|w| w.fix.is_none()).cloned().collect();
+            let unfixable_warnings: Vec<_> = all_warnings
+                .iter()
+                .filter(|w| w.automatic_fix().is_none())
+                .cloned()
+                .collect();
 
             if !unfixable_warnings.is_empty() {
-                let formatted = formatter.format_warnings_with_content(&unfixable_warnings, &display_path, &content);
+                let formatted = formatter.file(&display_path, &rule_names.apply(&unfixable_warnings), &content);
                 if !formatted.is_empty() {
                     output_writer.writeln(&formatted).unwrap_or_else(|e| {
                         eprintln!("Error writing output: {e}");
@@ -205,7 +290,7 @@ pub fn process_file_with_formatter(
                     }
                 })
                 .collect();
-            let formatted = formatter.format_warnings_with_content(&display_warnings, &display_path, &content);
+            let formatted = formatter.file(&display_path, &rule_names.apply(&display_warnings), &content);
             if !formatted.is_empty() {
                 output_writer.writeln(&formatted).unwrap_or_else(|e| {
                     eprintln!("Error writing output: {e}");
@@ -219,28 +304,33 @@ pub fn process_file_with_formatter(
     if diff {
         // In diff mode, apply fixes to a copy and show diff
         let original_content = content.clone();
-        warnings_fixed = apply_fixes_coordinated(
-            rules,
-            &all_warnings,
-            &mut content,
-            true,
-            true,
-            config,
-            Some(Path::new(file_path)),
-        );
+        // Files linted through extracted markdown skip the whole-file
+        // coordinator, which would treat the surrounding code as markdown.
+        if extraction.is_none() {
+            warnings_fixed = remove_unused_disables(rules, config, &all_warnings, &mut content);
+            warnings_fixed += apply_fixes_coordinated(
+                rules,
+                &all_warnings,
+                &mut content,
+                true,
+                true,
+                config,
+                Some(Path::new(file_path)),
+            );
+        }
 
         // Format embedded markdown blocks (recursive formatting). This is opt-in
         // via code-block-tools (`[code-block-tools.languages.markdown] lint = ["rumdl"]`)
         // and gated identically to the check path, so `--fix` never rewrites the
         // contents of a markdown code block that `check` did not report on.
         // filtered_rules respects per-file-ignores for the embedded content.
-        if should_lint_embedded_markdown(&config.code_block_tools) {
+        if extraction.is_none() && should_lint_embedded_markdown(&config.code_block_tools) {
             let embedded_formatted = format_embedded_markdown_blocks(&mut content, &filtered_rules, config);
             warnings_fixed += embedded_formatted;
         }
 
-        // Format doc comments in Rust files
-        if Path::new(file_path).extension().is_some_and(|ext| ext == "rs") {
+        // Format doc comments in Rust files (docstrings and JSDoc are check-only)
+        if extraction == Some(Extraction::DocComments(SourceLanguage::Rust)) {
             let doc_formatted = super::doc_comments::format_doc_comment_blocks(&mut content, &filtered_rules, config);
             warnings_fixed += doc_formatted;
         }
@@ -272,20 +362,46 @@ pub fn process_file_with_formatter(
             }
         }
 
-        if warnings_fixed > 0 {
+        // `--fix --dry-run` with JSON output plans the fixes instead of showing a diff
+        let plan_fixes =
+            fix_mode == crate::FixMode::CheckFix && *output_format == rumdl_lib::output::OutputFormat::Json;
+        if warnings_fixed > 0 && !plan_fixes {
             let diff_output = formatter::generate_diff(&original_content, &content, &display_path);
             output_writer.writeln(&diff_output).unwrap_or_else(|e| {
                 eprintln!("Error writing diff output: {e}");
             });
         }
 
+        let remaining_warnings = if total_warnings > 0 {
+            relint_fixed_file_content(&content, file_path, rules, config)
+        } else {
+            Vec::new()
+        };
         let summary_issues_fixed = if total_warnings > 0 {
-            let remaining_warnings = relint_fixed_file_content(&content, file_path, rules, config);
             count_actually_fixed_warnings(rules, config, &all_warnings, &remaining_warnings)
         } else {
             warnings_fixed
         };
 
+        // Edits are planned against the bytes on disk and produce exactly what
+        // `--fix` would write
+        let fix_plan = plan_fixes.then(|| {
+            let on_disk = std::fs::read_to_string(file_path).unwrap_or_else(|_| {
+                rumdl_lib::utils::normalize_line_ending(&original_content, original_line_ending).into_owned()
+            });
+            let fixed = if warnings_fixed > 0 {
+                rumdl_lib::utils::normalize_line_ending(&content, original_line_ending).into_owned()
+            } else {
+                on_disk.clone()
+            };
+            formatter::FixPlan::new(
+                &on_disk,
+                &fixed,
+                summary_issues_fixed,
+                rule_names.apply(&remaining_warnings).into_owned(),
+            )
+        });
+
         // Don't actually write the file in diff mode, but report how many would be fixed
         return FileProcessResult {
             has_issues: total_warnings > 0 || warnings_fixed > 0,
@@ -296,31 +412,40 @@ pub fn process_file_with_formatter(
             warnings: all_warnings,
             file_index,
             file_index_reused,
+            fix_plan,
         };
     } else if fix_mode != crate::FixMode::Check {
+        // With --interactive, the fixes are reviewed against the original
+        let original_content = interactive.is_some().then(|| content.clone());
+
         // Apply fixes using Fix Coordinator
-        warnings_fixed = apply_fixes_coordinated(
-            rules,
-            &all_warnings,
-            &mut content,
-            quiet,
-            silent,
-            config,
-            Some(Path::new(file_path)),
-        );
+        // Files linted through extracted markdown skip the whole-file
+        // coordinator, which would treat the surrounding code as markdown.
+        if extraction.is_none() {
+            warnings_fixed = remove_unused_disables(rules, config, &all_warnings, &mut content);
+            warnings_fixed += apply_fixes_coordinated(
+                rules,
+                &all_warnings,
+                &mut content,
+                quiet,
+                silent,
+                config,
+                Some(Path::new(file_path)),
+            );
+        }
 
         // Format embedded markdown blocks (recursive formatting). This is opt-in
         // via code-block-tools (`[code-block-tools.languages.markdown] lint = ["rumdl"]`)
         // and gated identically to the check path, so `--fix` never rewrites the
         // contents of a markdown code block that `check` did not report on.
         // filtered_rules respects per-file-ignores for the embedded content.
-        if should_lint_embedded_markdown(&config.code_block_tools) {
+        if extraction.is_none() && should_lint_embedded_markdown(&config.code_block_tools) {
             let embedded_formatted = format_embedded_markdown_blocks(&mut content, &filtered_rules, config);
             warnings_fixed += embedded_formatted;
         }
 
-        // Format doc comments in Rust files
-        if Path::new(file_path).extension().is_some_and(|ext| ext == "rs") {
+        // Format doc comments in Rust files (docstrings and JSDoc are check-only)
+        if extraction == Some(Extraction::DocComments(SourceLanguage::Rust)) {
             let doc_formatted = super::doc_comments::format_doc_comment_blocks(&mut content, &filtered_rules, config);
             warnings_fixed += doc_formatted;
         }
@@ -352,6 +477,24 @@ pub fn process_file_with_formatter(
             }
         }
 
+        if let Some(session) = interactive
+            && let Some(original_content) = original_content
+            && warnings_fixed > 0
+        {
+            let mut session = session.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            let reviewed = session.review(&display_path, &original_content, &content, &all_warnings);
+            if reviewed == original_content {
+                warnings_fixed = 0;
+            } else if reviewed != content {
+                // Only some hunks were accepted: count what they fixed, but
+                // at least one so the reviewed content is still written
+                let remaining_warnings = relint_fixed_file_content(&reviewed, file_path, rules, config);
+                warnings_fixed =
+                    count_actually_fixed_warnings(rules, config, &all_warnings, &remaining_warnings).max(1);
+            }
+            content = reviewed;
+        }
+
         // Write fixed content back to file
         if warnings_fixed > 0 {
             // Denormalize back to original line ending before writing
@@ -382,6 +525,7 @@ pub fn process_file_with_formatter(
                 warnings: Vec::new(),
                 file_index,
                 file_index_reused,
+                fix_plan: None,
             };
         }
 
@@ -395,7 +539,7 @@ pub fn process_file_with_formatter(
             .map(|warning| {
                 let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
                 let is_fixable = is_rule_cli_fixable(rules, config, rule_name);
-                warning.fix.is_some()
+                warning.automatic_fix().is_some()
                     && is_fixable
                     && !remaining_warnings.iter().any(|w| {
                         w.line == warning.line
@@ -415,7 +559,7 @@ pub fn process_file_with_formatter(
                 OutputFormat::Text | OutputFormat::Full => {
                     let mut output = String::new();
                     for (warning, &was_fixed) in all_warnings.iter().zip(&fixed_status) {
-                        let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
+                        let rule_name = rule_names.name(warning.rule_name.as_deref().unwrap_or("unknown"));
 
                         let fix_indicator = if was_fixed {
                             " [fixed]".green().to_string()
@@ -445,12 +589,15 @@ pub fn process_file_with_formatter(
                     }
                 }
                 // Batch formats are handled by check_runner (silent=true suppresses this path)
-                OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit => {}
+                OutputFormat::Json
+                | OutputFormat::GitLab
+                | OutputFormat::Sarif
+                | OutputFormat::Junit
+                | OutputFormat::Html => {}
                 // Other streaming formats: use their formatter with remaining-only warnings
                 _ => {
                     if !remaining_warnings.is_empty() {
-                        let formatted =
-                            formatter.format_warnings_with_content(&remaining_warnings, &display_path, &content);
+                        let formatted = formatter.file(&display_path, &rule_names.apply(&remaining_warnings), &content);
                         if !formatted.is_empty() {
                             output_writer.writeln(&formatted).unwrap_or_else(|e| {
                                 eprintln!("Error writing output: {e}");
@@ -472,6 +619,7 @@ pub fn process_file_with_formatter(
             warnings: remaining_warnings,
             file_index,
             file_index_reused,
+            fix_plan: None,
         };
     }
 
@@ -484,6 +632,7 @@ pub fn process_file_with_formatter(
         warnings: all_warnings,
         file_index,
         file_index_reused,
+        fix_plan: None,
     }
 }
 
@@ -536,7 +685,7 @@ pub(crate) fn count_actually_fixed_warnings(
         .filter(|warning| {
             let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
             let is_fixable = is_rule_cli_fixable(rules, config, rule_name);
-            warning.fix.is_some()
+            warning.automatic_fix().is_some()
                 && is_fixable
                 && !remaining_warnings.iter().any(|w| {
                     w.line == warning.line
@@ -548,6 +697,34 @@ pub(crate) fn count_actually_fixed_warnings(
         .count()
 }
 
+/// Remove the disable comments reported by `--report-unused-disables`, before
+/// the rule fixes run so the reported offsets still hold
+fn remove_unused_disables(
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+    all_warnings: &[LintWarning],
+    content: &mut String,
+) -> usize {
+    if !is_rule_cli_fixable(rules, config, UNUSED_DISABLE_RULE) {
+        return 0;
+    }
+    let unused: Vec<LintWarning> = all_warnings
+        .iter()
+        .filter(|w| w.rule_name.as_deref() == Some(UNUSED_DISABLE_RULE))
+        .cloned()
+        .collect();
+    if unused.is_empty() {
+        return 0;
+    }
+    match rumdl_lib::utils::fix_utils::apply_warning_fixes(content, &unused) {
+        Ok(fixed) => {
+            *content = fixed;
+            unused.len()
+        }
+        Err(_) => 0,
+    }
+}
+
 /// Result type for file processing that includes index data for cross-file analysis
 pub struct ProcessFileResult {
     pub warnings: Vec<rumdl_lib::rule::LintWarning>,
@@ -663,21 +840,35 @@ pub fn process_file_with_index(
             }
         };
 
-    // Detect original line ending before any processing
-    let original_line_ending = rumdl_lib::time_function!(
-        "file: detect line endings",
-        rumdl_lib::utils::detect_line_ending_enum(&content)
-    );
+    // MD107 checks and fixes the file's own line endings, so with it enabled
+    // the content is linted as read and written back as the fixes leave it
+    // (`Mixed` leaves line endings unchanged on write)
+    let keep_line_endings = extraction_for_file(file_path, config).is_none()
+        && rules.iter().any(|rule| rule.name() == "MD107")
+        && !config
+            .get_ignored_rules_for_file(Path::new(file_path))
+            .contains("MD107");
+    let original_line_ending = if keep_line_endings {
+        rumdl_lib::utils::LineEnding::Mixed
+    } else {
+        // Detect original line ending before any processing
+        rumdl_lib::time_function!(
+            "file: detect line endings",
+            rumdl_lib::utils::detect_line_ending_enum(&content)
+        )
+    };
 
     // Normalize to LF for all internal processing
-    content = rumdl_lib::time_function!(
-        "file: normalize line endings",
-        rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf).into_owned()
-    );
+    if !keep_line_endings {
+        content = rumdl_lib::time_function!(
+            "file: normalize line endings",
+            rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf).into_owned()
+        );
+    }
 
-    // Route Rust files to doc comment linting instead of regular markdown linting
-    if Path::new(file_path).extension().is_some_and(|ext| ext == "rs") {
-        return process_rust_file_doc_comments(file_path, &content, rules, config, original_line_ending);
+    // Route source and HTML files to extracted markdown linting instead of regular markdown linting
+    if let Some(extraction) = extraction_for_file(file_path, config) {
+        return process_extracted_markdown(file_path, &content, extraction, rules, config, original_line_ending);
     }
 
     // Validate inline config comments and warn about unknown rules
@@ -698,8 +889,15 @@ pub fn process_file_with_index(
         };
     }
 
-    // Compute hashes for cache (Ruff-style: file content + config + enabled rules)
-    let (config_hash, rules_hash) = if let Some(hashes) = cache_hashes {
+    // Settings the config resolves per file: per-file-ignores and the flavor
+    // from per-file-flavor, global config, or file extension
+    let ignored_rules_for_file = config.get_ignored_rules_for_file(Path::new(file_path));
+    let flavor = config.get_flavor_for_file(Path::new(file_path));
+
+    // Compute hashes for cache (Ruff-style: file content + config + enabled rules).
+    // The config hash also covers the per-file settings, which differ between
+    // files of the same group.
+    let (group_config_hash, rules_hash) = if let Some(hashes) = cache_hashes {
         (Cow::Borrowed(&hashes.config_hash), Cow::Borrowed(&hashes.rules_hash))
     } else {
         (
@@ -707,6 +905,7 @@ pub fn process_file_with_index(
             Cow::Owned(LintCache::hash_rules(rules)),
         )
     };
+    let config_hash = LintCache::hash_file_config(&group_config_hash, flavor, &ignored_rules_for_file);
     let file_hash = LintCache::hash_content(&content);
 
     // Try to get from cache first (lock briefly for cache read)
@@ -726,7 +925,7 @@ pub fn process_file_with_index(
                     cached_warnings
                         .iter()
                         .filter(|w| {
-                            w.fix.is_some()
+                            w.automatic_fix().is_some()
                                 && w.rule_name
                                     .as_ref()
                                     .is_some_and(|name| is_rule_cli_fixable(rules, config, name))
@@ -735,7 +934,6 @@ pub fn process_file_with_index(
                 );
 
                 // Build FileIndex for cross-file analysis on cache hit (lightweight, no rule checking)
-                let flavor = config.get_flavor_for_file(Path::new(file_path));
                 let cached_file_index = workspace_index
                     .as_deref()
                     .and_then(|index| {
@@ -785,7 +983,6 @@ pub fn process_file_with_index(
     let lint_start = Instant::now();
 
     // Filter rules based on per-file-ignores configuration
-    let ignored_rules_for_file = config.get_ignored_rules_for_file(Path::new(file_path));
     let filtered_rules: Vec<_> = rumdl_lib::time_function!(
         "file: filter rules",
         if !ignored_rules_for_file.is_empty() {
@@ -799,9 +996,6 @@ pub fn process_file_with_index(
         }
     );
 
-    // Determine flavor based on per-file-flavor overrides, global config, or file extension
-    let flavor = config.get_flavor_for_file(Path::new(file_path));
-
     // Use lint_and_index for single-file linting + index contribution
     let source_file = Some(std::path::PathBuf::from(file_path));
     let (warnings_result, file_index) = rumdl_lib::time_function!(
@@ -825,10 +1019,7 @@ pub fn process_file_with_index(
     // Run code block tools linting if enabled
     if config.code_block_tools.enabled {
         rumdl_lib::time_section!("file: code block tools", {
-            let processor = rumdl_lib::code_block_tools::CodeBlockToolProcessor::new(
-                &config.code_block_tools,
-                config.get_flavor_for_file(Path::new(file_path)),
-            );
+            let processor = rumdl_lib::code_block_tools::CodeBlockToolProcessor::new(&config.code_block_tools, flavor);
             match processor.lint(&content) {
                 Ok(diagnostics) => {
                     let tool_warnings: Vec<_> = diagnostics.iter().map(|d| d.to_lint_warning()).collect();
@@ -868,7 +1059,7 @@ pub fn process_file_with_index(
     let fixable_warnings = all_warnings
         .iter()
         .filter(|w| {
-            w.fix.is_some()
+            w.automatic_fix().is_some()
                 && w.rule_name
                     .as_ref()
                     .is_some_and(|name| is_rule_cli_fixable(rules, config, name))
@@ -890,7 +1081,14 @@ pub fn process_file_with_index(
     // Store in cache before returning (ignore if mutex is poisoned)
     if let Some(ref cache_arc) = cache {
         rumdl_lib::time_section!("cache: store total", {
-            cache_arc.set_with_hash(&file_hash, &config_hash, &rules_hash, all_warnings.clone());
+            // MD057 results depend on which link targets exist, not only on
+            // this file's content
+            let targets = filtered_rules
+                .iter()
+                .find_map(|rule| rule.as_any().downcast_ref::<MD057ExistingRelativeLinks>())
+                .map(|md057| md057.target_paths(Path::new(file_path), &file_index, flavor))
+                .unwrap_or_default();
+            cache_arc.set_with_hash(&file_hash, &config_hash, &rules_hash, all_warnings.clone(), &targets);
         });
     }
 
@@ -920,9 +1118,43 @@ pub fn apply_fixes_coordinated(
     let start = Instant::now();
     let coordinator = FixCoordinator::new();
 
-    // Apply fixes iteratively (up to 100 iterations to ensure convergence, same as Ruff)
+    // Apply fixes iteratively until convergence, bounded by `fix-max-iterations`
     // Pass file_path to enable per-file flavor resolution
-    match coordinator.apply_fixes_iterative(rules, all_warnings, content, config, 100, file_path) {
+    let max_iterations = config.global.fix_max_iterations;
+    let result = coordinator.apply_fixes_iterative(rules, all_warnings, content, config, max_iterations, file_path);
+    report_fix_result(result, all_warnings, silent, file_path, start)
+}
+
+/// Like [`apply_fixes_coordinated`], but only applies fixes whose edits fall
+/// within `lines` (1-indexed, inclusive), leaving the rest of `content` as is.
+pub fn apply_fixes_in_lines(
+    rules: &[Box<dyn Rule>],
+    all_warnings: &[rumdl_lib::rule::LintWarning],
+    content: &mut String,
+    lines: std::ops::RangeInclusive<usize>,
+    silent: bool,
+    config: &rumdl_config::Config,
+    file_path: Option<&std::path::Path>,
+) -> usize {
+    use rumdl_lib::fix_coordinator::FixCoordinator;
+    use std::time::Instant;
+
+    let start = Instant::now();
+    let coordinator = FixCoordinator::new();
+    let max_iterations = config.global.fix_max_iterations;
+    let result = coordinator.apply_fixes_in_lines(rules, content, lines, config, max_iterations, file_path);
+    report_fix_result(result, all_warnings, silent, file_path, start)
+}
+
+/// Report a fix run and count the warnings of the rules that applied fixes
+fn report_fix_result(
+    result: Result<rumdl_lib::fix_coordinator::FixResult, String>,
+    all_warnings: &[rumdl_lib::rule::LintWarning],
+    silent: bool,
+    file_path: Option<&std::path::Path>,
+    start: std::time::Instant,
+) -> usize {
+    match result {
         Ok(result) => {
             let elapsed = start.elapsed();
 
@@ -1175,13 +1407,138 @@ fn build_non_convergence_warning_lines(
     lines
 }
 
-/// Process a Rust source file by linting markdown in doc comments.
+/// How a non-markdown file is linted through the markdown extracted from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Extraction {
+    /// Doc comments or docstrings of a source file
+    DocComments(SourceLanguage),
+    /// Markdown regions of an HTML file, located by `html-markdown-patterns`
+    Html,
+}
+
+/// Size of `file_path` when it is larger than `max-file-size`
+fn oversized_file(file_path: &str, config: &rumdl_config::Config) -> Option<u64> {
+    let max = config.global.max_file_size?;
+    let size = std::fs::metadata(file_path).ok()?.len();
+    (size > max).then_some(size)
+}
+
+/// Handle a file larger than `max-file-size` according to `large-file-mode`.
+///
+/// Streamed files are checked a chunk at a time by the block-local rules and
+/// never fixed. Source and HTML files are skipped in either mode, since their
+/// Markdown is extracted from the whole file.
+#[allow(clippy::too_many_arguments)]
+fn process_large_file(
+    file_path: &str,
+    display_path: &str,
+    size: u64,
+    rules: &[Box<dyn Rule>],
+    fixing: bool,
+    silent: bool,
+    formatter: &mut dyn rumdl_lib::output::Formatter,
+    rule_names: rumdl_lib::output::RuleNameDisplay,
+    output_writer: &rumdl_lib::output::OutputWriter,
+    config: &rumdl_config::Config,
+) -> FileProcessResult {
+    let mut result = FileProcessResult {
+        has_issues: false,
+        issues_found: 0,
+        issues_fixed: 0,
+        summary_issues_fixed: 0,
+        fixable_issues: 0,
+        warnings: Vec::new(),
+        file_index: rumdl_lib::workspace_index::FileIndex::new(),
+        file_index_reused: false,
+        fix_plan: None,
+    };
+    let max = config.global.max_file_size.unwrap_or_default();
+
+    if config.global.large_file_mode == rumdl_config::LargeFileMode::Skip
+        || extraction_for_file(file_path, config).is_some()
+    {
+        if !silent {
+            eprintln!(
+                "{} Skipping {display_path}: {size} bytes is over max-file-size ({max})",
+                "Warning:".yellow().bold()
+            );
+        }
+        return result;
+    }
+    if fixing && !silent {
+        eprintln!(
+            "{} Not fixing {display_path}: {size} bytes is over max-file-size ({max}); checked in chunks",
+            "Warning:".yellow().bold()
+        );
+    }
+
+    let ignored_rules_for_file = config.get_ignored_rules_for_file(Path::new(file_path));
+    let filtered_rules: Vec<Box<dyn Rule>> = rules
+        .iter()
+        .filter(|rule| !ignored_rules_for_file.contains(rule.name()))
+        .map(|r| dyn_clone::clone_box(&**r))
+        .collect();
+    let flavor = config.get_flavor_for_file(Path::new(file_path));
+    let warnings = std::fs::File::open(file_path)
+        .map_err(rumdl_lib::rule::LintError::from)
+        .and_then(|file| {
+            rumdl_lib::streaming::lint_chunked(
+                std::io::BufReader::new(file),
+                &filtered_rules,
+                flavor,
+                Some(Path::new(file_path)),
+                Some(config),
+            )
+        });
+    let mut warnings = match warnings {
+        Ok(warnings) => warnings,
+        Err(e) => {
+            if !silent {
+                eprintln!("Error reading file {file_path}: {e}");
+            }
+            return result;
+        }
+    };
+
+    warnings.sort_by_key(|w| (w.line, w.column));
+
+    if !silent && !warnings.is_empty() {
+        let formatted = formatter.file(display_path, &rule_names.apply(&warnings), "");
+        if !formatted.is_empty() {
+            output_writer.writeln(&formatted).unwrap_or_else(|e| {
+                eprintln!("Error writing output: {e}");
+            });
+        }
+    }
+    result.has_issues = !warnings.is_empty();
+    result.issues_found = warnings.len();
+    result.warnings = warnings;
+    result
+}
+
+/// Determine whether a file is linted through extracted markdown.
+///
+/// Rust files are always linted through their doc comments; Python,
+/// JavaScript/TypeScript, and HTML files only when they match `extract-from`.
+fn extraction_for_file(file_path: &str, config: &rumdl_config::Config) -> Option<Extraction> {
+    let path = Path::new(file_path);
+    match SourceLanguage::from_path(path) {
+        Some(SourceLanguage::Rust) => Some(Extraction::DocComments(SourceLanguage::Rust)),
+        Some(language) => config
+            .is_extract_from_path(path)
+            .then_some(Extraction::DocComments(language)),
+        None => (is_html_path(path) && config.is_extract_from_path(path)).then_some(Extraction::Html),
+    }
+}
+
+/// Process a source or HTML file by linting the markdown extracted from it.
 ///
 /// Returns a `ProcessFileResult` with warnings remapped to their original file
-/// positions. No cross-file analysis is performed for doc comments.
-fn process_rust_file_doc_comments(
+/// positions. No cross-file analysis is performed for extracted markdown.
+fn process_extracted_markdown(
     file_path: &str,
     content: &str,
+    extraction: Extraction,
     rules: &[Box<dyn Rule>],
     config: &rumdl_config::Config,
     original_line_ending: rumdl_lib::utils::LineEnding,
@@ -1198,19 +1555,29 @@ fn process_rust_file_doc_comments(
         rules.to_vec()
     };
 
-    let all_warnings = rumdl_lib::doc_comment_lint::check_doc_comment_blocks(content, &filtered_rules, config);
+    let all_warnings = match extraction {
+        Extraction::DocComments(language) => {
+            rumdl_lib::doc_comment_lint::check_source_doc_blocks(content, language, &filtered_rules, config)
+        }
+        Extraction::Html => check_html_markdown_blocks(content, &filtered_rules, config),
+    };
 
     let total_warnings = all_warnings.len();
     // Doc comment warnings have fix stripped (fix: None) in check mode, so
     // determine fixability by checking the rule's fix capability instead.
-    let fixable_warnings = all_warnings
-        .iter()
-        .filter(|w| {
-            w.rule_name
-                .as_ref()
-                .is_some_and(|name| is_rule_cli_fixable(rules, config, name))
-        })
-        .count();
+    // Only Rust doc comments are rewritten in fix mode.
+    let fixable_warnings = if extraction == Extraction::DocComments(SourceLanguage::Rust) {
+        all_warnings
+            .iter()
+            .filter(|w| {
+                w.rule_name
+                    .as_ref()
+                    .is_some_and(|name| is_rule_cli_fixable(rules, config, name))
+            })
+            .count()
+    } else {
+        0
+    };
 
     ProcessFileResult {
         warnings: all_warnings,
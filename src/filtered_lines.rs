@@ -343,16 +343,18 @@ impl LineFilterConfig {
 
 /// Iterator that yields filtered lines based on configuration
 pub struct FilteredLinesIter<'a> {
-    ctx: &'a LintContext<'a>,
+    lines: &'a [LineInfo],
+    raw_lines: &'a [&'a str],
     config: LineFilterConfig,
     current_index: usize,
 }
 
 impl<'a> FilteredLinesIter<'a> {
     /// Create a new filtered lines iterator
-    fn new(ctx: &'a LintContext<'a>, config: LineFilterConfig) -> Self {
+    fn new(ctx: &'a LintContext<'_>, config: LineFilterConfig) -> Self {
         Self {
-            ctx,
+            lines: &ctx.lines,
+            raw_lines: ctx.raw_lines(),
             config,
             current_index: 0,
         }
@@ -363,8 +365,8 @@ impl<'a> Iterator for FilteredLinesIter<'a> {
     type Item = FilteredLine<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let lines = &self.ctx.lines;
-        let raw_lines = self.ctx.raw_lines();
+        let lines = self.lines;
+        let raw_lines = self.raw_lines;
 
         while self.current_index < lines.len() {
             let idx = self.current_index;
@@ -442,14 +444,16 @@ pub trait FilteredLinesExt {
 
 /// Builder type that allows chaining filter configuration and converting to an iterator
 pub struct FilteredLinesBuilder<'a> {
-    ctx: &'a LintContext<'a>,
+    lines: &'a [LineInfo],
+    raw_lines: &'a [&'a str],
     config: LineFilterConfig,
 }
 
 impl<'a> FilteredLinesBuilder<'a> {
-    fn new(ctx: &'a LintContext<'a>) -> Self {
+    fn new(ctx: &'a LintContext<'_>) -> Self {
         Self {
-            ctx,
+            lines: &ctx.lines,
+            raw_lines: ctx.raw_lines(),
             config: LineFilterConfig::new(),
         }
     }
@@ -600,7 +604,12 @@ impl<'a> IntoIterator for FilteredLinesBuilder<'a> {
     type IntoIter = FilteredLinesIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        FilteredLinesIter::new(self.ctx, self.config)
+        FilteredLinesIter {
+            lines: self.lines,
+            raw_lines: self.raw_lines,
+            config: self.config,
+            current_index: 0,
+        }
     }
 }
 
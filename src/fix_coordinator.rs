@@ -1,12 +1,14 @@
 use crate::config::Config;
 use crate::lint_context::LintContext;
-use crate::rule::{FixCapability, LintWarning, Rule};
+use crate::rule::{FixCapability, FixPriority, FixSafety, LintWarning, Rule};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::ops::{Range, RangeInclusive};
 
-/// Maximum number of fix iterations before stopping (same as Ruff)
-const MAX_ITERATIONS: usize = 100;
+/// Default number of fix passes before giving up on a fixpoint (same as Ruff).
+/// Configurable with `fix-max-iterations`.
+pub const DEFAULT_MAX_ITERATIONS: usize = 100;
 
 /// Result of applying fixes iteratively
 ///
@@ -100,18 +102,31 @@ impl FixCoordinator {
         Self { dependencies }
     }
 
-    /// Get the optimal order for running rules based on dependencies
+    /// Get the canonical order for running rules.
+    ///
+    /// Rules are ordered by [`Rule::fix_priority`], then by name. The
+    /// dependencies above are hard constraints on top of that: a prerequisite
+    /// always runs before its dependents, even from a later priority. The
+    /// result does not depend on the order of `rules`.
     pub fn get_optimal_order<'a>(&self, rules: &'a [Box<dyn Rule>]) -> Vec<&'a dyn Rule> {
         // Build a map of rule names to rules for quick lookup
         let rule_map: HashMap<&str, &dyn Rule> = rules.iter().map(|r| (r.name(), r.as_ref())).collect();
+        let sort_key = |name: &str| (rule_map.get(name).map(|r| r.fix_priority()), name.to_string());
 
-        // Build reverse dependencies (rule -> rules it depends on)
-        let mut reverse_deps: HashMap<&str, HashSet<&str>> = HashMap::new();
+        // Build reverse dependencies (rule -> rules it depends on), each list
+        // in canonical order so prerequisites are visited deterministically
+        let mut reverse_deps: HashMap<&str, Vec<&str>> = HashMap::new();
         for (prereq, dependents) in &self.dependencies {
             for dependent in dependents {
-                reverse_deps.entry(dependent).or_default().insert(prereq);
+                reverse_deps.entry(dependent).or_default().push(prereq);
             }
         }
+        for prereqs in reverse_deps.values_mut() {
+            prereqs.sort_by_key(|name| sort_key(name));
+        }
+
+        let mut canonical: Vec<&dyn Rule> = rules.iter().map(AsRef::as_ref).collect();
+        canonical.sort_by_key(|rule| (rule.fix_priority(), rule.name()));
 
         // Perform topological sort
         let mut sorted = Vec::new();
@@ -121,7 +136,7 @@ impl FixCoordinator {
         fn visit<'a, 'b>(
             rule_name: &'b str,
             rule_map: &HashMap<&str, &'a dyn Rule>,
-            reverse_deps: &HashMap<&'b str, HashSet<&'b str>>,
+            reverse_deps: &HashMap<&'b str, Vec<&'b str>>,
             visited: &mut HashSet<&'b str>,
             visiting: &mut HashSet<&'b str>,
             sorted: &mut Vec<&'a dyn Rule>,
@@ -157,8 +172,8 @@ impl FixCoordinator {
             }
         }
 
-        // Visit all rules
-        for rule in rules {
+        // Visit all rules in canonical order
+        for rule in &canonical {
             visit(
                 rule.name(),
                 &rule_map,
@@ -170,19 +185,38 @@ impl FixCoordinator {
         }
 
         // Add any rules not in dependency graph
-        for rule in rules {
+        for rule in canonical {
             if !sorted.iter().any(|r| r.name() == rule.name()) {
-                sorted.push(rule.as_ref());
+                sorted.push(rule);
             }
         }
 
         sorted
     }
 
+    /// Whether a rule that must run before `rule_name` still has fixes pending
+    /// in the current pass
+    fn has_pending_prerequisite(&self, rule_name: &str, pending: &HashSet<&str>) -> bool {
+        self.dependencies
+            .iter()
+            .any(|(prereq, dependents)| dependents.contains(&rule_name) && pending.contains(prereq))
+    }
+
     /// Apply fixes iteratively until no more fixes are needed or max iterations reached.
     ///
-    /// This implements a Ruff-inspired fix loop that re-checks ALL rules after each fix
-    /// to detect cascading issues (e.g., MD046 creating code blocks that MD040 needs to fix).
+    /// Each pass checks every rule against the same content and collects the
+    /// edits attached to their warnings. Edits from different rules are
+    /// applied together in a single pass unless their byte ranges overlap; on
+    /// overlap the rule that comes first in [`Self::get_optimal_order`] wins
+    /// and the other is deferred to the next pass, where it is re-checked
+    /// against the updated content. A rule also waits while a rule it depends
+    /// on still has fixes pending, and rules with [`FixPriority::LineEndings`]
+    /// wait while any other rule does. Rules whose warnings do not all carry an
+    /// edit rewrite the whole document through [`Rule::fix`] and run alone.
+    ///
+    /// Passes repeat until the content stops changing (a fixpoint), a
+    /// previous state recurs (an oscillation between rules), or
+    /// `max_iterations` passes have run.
     ///
     /// The `file_path` parameter is used to determine per-file flavor overrides. If provided,
     /// the flavor for creating LintContext will be resolved using `config.get_flavor_for_file()`.
@@ -195,9 +229,52 @@ impl FixCoordinator {
         max_iterations: usize,
         file_path: Option<&std::path::Path>,
     ) -> Result<FixResult, String> {
-        // Use the minimum of max_iterations parameter and MAX_ITERATIONS constant
-        let max_iterations = max_iterations.min(MAX_ITERATIONS);
+        Ok(self.fix_loop(rules, content, config, max_iterations, file_path, None))
+    }
 
+    /// Apply fixes like [`Self::apply_fixes_iterative`], but only inside the
+    /// 1-indexed, inclusive line range `lines`.
+    ///
+    /// Only warnings whose edits all fall within those lines are applied, so
+    /// the rest of the document is left byte-for-byte unchanged. Rules that
+    /// can only rewrite the whole document through [`Rule::fix`] are skipped.
+    /// The range grows or shrinks with the edits made inside it, so later
+    /// passes see the same lines.
+    pub fn apply_fixes_in_lines(
+        &self,
+        rules: &[Box<dyn Rule>],
+        content: &mut String,
+        lines: RangeInclusive<usize>,
+        config: &Config,
+        max_iterations: usize,
+        file_path: Option<&std::path::Path>,
+    ) -> Result<FixResult, String> {
+        let mut start = content.len();
+        let mut end = content.len();
+        let mut offset = 0;
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            let line_number = index + 1;
+            if line_number == *lines.start() {
+                start = offset;
+            }
+            offset += line.len();
+            if line_number == *lines.end() {
+                end = offset;
+                break;
+            }
+        }
+        Ok(self.fix_loop(rules, content, config, max_iterations, file_path, Some(start..end)))
+    }
+
+    fn fix_loop(
+        &self,
+        rules: &[Box<dyn Rule>],
+        content: &mut String,
+        config: &Config,
+        max_iterations: usize,
+        file_path: Option<&std::path::Path>,
+        mut scope: Option<Range<usize>>,
+    ) -> FixResult {
         // Get optimal rule order based on dependencies
         let ordered_rules = self.get_optimal_order(rules);
 
@@ -205,9 +282,9 @@ impl FixCoordinator {
         let mut total_ctx_creations = 0;
         let mut iterations = 0;
 
-        // History tracks (content_hash, rule_that_produced_this_state).
-        // The initial entry has an empty rule name (no rule produced the initial content).
-        let mut history: Vec<(u64, &str)> = vec![(hash_content(content), "")];
+        // History tracks (content_hash, rules_that_produced_this_state).
+        // The initial entry has no rules (no rule produced the initial content).
+        let mut history: Vec<(u64, Vec<&str>)> = vec![(hash_content(content), Vec::new())];
 
         // Track which rules actually applied fixes
         let mut fixed_rule_names: HashSet<&str> = HashSet::new();
@@ -248,11 +325,17 @@ impl FixCoordinator {
                 }
             };
 
-            let mut any_fix_applied = false;
-            // The rule that applied a fix this iteration (used for cycle reporting).
-            let mut this_iter_rule: &str = "";
-
-            // Check and fix each rule in dependency order
+            // Rules with fixes to apply this pass, applied or deferred
+            let mut pending: HashSet<&str> = HashSet::new();
+            // Edits accepted for this pass: the rules, their warnings, and the
+            // byte ranges they claim
+            let mut batch_rules: Vec<&dyn Rule> = Vec::new();
+            let mut batch_warnings: Vec<LintWarning> = Vec::new();
+            let mut claimed: Vec<Range<usize>> = Vec::new();
+            // A whole-document rewrite, which runs instead of the batch
+            let mut document_fix: Option<(&str, String)> = None;
+
+            // Check each rule in dependency order
             for rule in &ordered_rules {
                 // Skip disabled rules
                 if unfixable_rules.contains(rule.name()) {
@@ -261,6 +344,9 @@ impl FixCoordinator {
                 if has_fixable_allowlist && !fixable_rules.contains(rule.name()) {
                     continue;
                 }
+                if rule.fix_safety() == FixSafety::Unsafe && !config.global.unsafe_fixes {
+                    continue;
+                }
 
                 // Use the inline-config-recreated instance when present so checks and
                 // fixes reflect inline `rumdl-configure-file` overrides; otherwise the
@@ -286,48 +372,134 @@ impl FixCoordinator {
                 let filtered_warnings =
                     crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, inline_config, rule.name());
 
+                // With a scope, only warnings whose edits all fall inside it count
+                let filtered_warnings = match &scope {
+                    Some(scope) => filtered_warnings
+                        .into_iter()
+                        .filter(|w| {
+                            w.automatic_fix().is_some_and(|fix| {
+                                std::iter::once(fix)
+                                    .chain(&fix.additional_edits)
+                                    .all(|edit| scope.start <= edit.range.start && edit.range.end <= scope.end)
+                            })
+                        })
+                        .collect(),
+                    None => filtered_warnings,
+                };
+
                 if filtered_warnings.is_empty() {
                     continue;
                 }
 
-                // Decide whether to dispatch to rule.fix(). Two paths qualify:
-                //   1. Any non-disabled warning carries an inline Fix (the
-                //      common case — most rules attach per-warning edits).
-                //   2. The rule advertises a fix capability via Rule::fix_capability().
-                //      This is for rules whose fix() rewrites at the document
-                //      level rather than producing per-warning edits (e.g.
-                //      MD046 fence-style normalization, MD076 list spacing).
-                // A rule is skipped only when it has no inline fix AND advertises
-                // no fix capability (Unfixable). Unfixable rules attach no inline
-                // fixes, so in practice they are never dispatched to fix().
-                let has_inline_fix = filtered_warnings.iter().any(|w| w.fix.is_some());
+                // A rule is fixable when any non-disabled warning carries an
+                // inline Fix (the common case) or when it advertises a fix
+                // capability via Rule::fix_capability() and rewrites at the
+                // document level in fix() (e.g. MD046 fence-style
+                // normalization, MD076 list spacing).
+                let has_inline_fix = filtered_warnings.iter().any(|w| w.automatic_fix().is_some());
                 let rule_advertises_fix = effective_rule.fix_capability() != FixCapability::Unfixable;
                 if !has_inline_fix && !rule_advertises_fix {
                     continue;
                 }
 
-                // Apply fix
-                match effective_rule.fix(&ctx) {
-                    Ok(fixed_content) => {
-                        if fixed_content != *content {
-                            *content = fixed_content;
-                            total_fixed += 1;
-                            any_fix_applied = true;
-                            this_iter_rule = rule.name();
-                            fixed_rule_names.insert(rule.name());
-
-                            // Break to re-check all rules with the new content
-                            // This is the key difference from the old approach:
-                            // we always restart from the beginning after a fix
+                // A rule waits while a rule that must run before it has work,
+                // so it sees that rule's result. Line-ending rules come last in
+                // the order and wait for every other rule.
+                let waits_for_all = effective_rule.fix_priority() == FixPriority::LineEndings && !pending.is_empty();
+                if waits_for_all || self.has_pending_prerequisite(rule.name(), &pending) {
+                    pending.insert(rule.name());
+                    continue;
+                }
+
+                let (edits, fixed_content) = if scope.is_some() {
+                    // Only the warnings' own edits are applied, so text outside
+                    // the scope is never touched by a whole-document rewrite
+                    (Some(edit_ranges(content, &filtered_warnings)), None)
+                } else {
+                    let Ok(fixed_content) = effective_rule.fix(&ctx) else {
+                        continue;
+                    };
+                    if fixed_content == *content {
+                        continue;
+                    }
+
+                    // The warnings' edits can join the batch only when they are the
+                    // whole fix: applied on their own they must give what fix() gives
+                    let edits = filtered_warnings
+                        .iter()
+                        .all(|w| w.automatic_fix().is_some())
+                        .then(|| {
+                            crate::utils::fix_utils::apply_warning_fixes(content, &filtered_warnings)
+                                .is_ok_and(|applied| applied == fixed_content)
+                        })
+                        .filter(|faithful| *faithful)
+                        .map(|_| edit_ranges(content, &filtered_warnings));
+                    (edits, Some(fixed_content))
+                };
+                if edits.as_ref().is_some_and(Vec::is_empty) {
+                    continue;
+                }
+                pending.insert(rule.name());
+
+                match edits {
+                    Some(edits) => {
+                        // Overlapping edits: the rule earlier in the order keeps
+                        // its edits; this one is re-checked on the next pass
+                        if edits
+                            .iter()
+                            .any(|range| claimed.iter().any(|other| ranges_conflict(range, other)))
+                        {
+                            log::debug!("Deferring {} fixes: edits overlap another rule's", rule.name());
+                            continue;
+                        }
+                        claimed.extend(edits);
+                        batch_rules.push(effective_rule);
+                        batch_warnings.extend(filtered_warnings);
+                    }
+                    None => {
+                        // Document-level fixes cannot be merged with other
+                        // edits, so they run alone in a pass of their own
+                        if batch_rules.is_empty()
+                            && let Some(fixed_content) = fixed_content
+                        {
+                            document_fix = Some((rule.name(), fixed_content));
                             break;
                         }
                     }
-                    Err(_) => {
-                        // Error applying fix, continue to next rule
-                        continue;
+                }
+            }
+
+            let mut pass_rules: Vec<&str> = Vec::new();
+            let len_before = content.len();
+            if let Some((rule_name, fixed_content)) = document_fix {
+                *content = fixed_content;
+                pass_rules.push(rule_name);
+            } else if !batch_rules.is_empty() {
+                match crate::utils::fix_utils::apply_warning_fixes(content, &batch_warnings) {
+                    Ok(fixed_content) => {
+                        *content = fixed_content;
+                        pass_rules.extend(batch_rules.iter().map(|rule| rule.name()));
+                    }
+                    Err(e) => {
+                        // A malformed edit; fall back to the first rule's own fix
+                        log::debug!("Failed to apply batched fixes: {e}");
+                        let rule = batch_rules[0];
+                        if scope.is_none()
+                            && let Ok(fixed_content) = rule.fix(&ctx)
+                            && fixed_content != *content
+                        {
+                            *content = fixed_content;
+                            pass_rules.push(rule.name());
+                        }
                     }
                 }
             }
+            if let Some(scope) = &mut scope {
+                // Every edit was inside the scope, so only its end moves
+                scope.end = (scope.end + content.len()).saturating_sub(len_before);
+            }
+            total_fixed += pass_rules.len();
+            fixed_rule_names.extend(pass_rules.iter().copied());
 
             let current_hash = hash_content(content);
 
@@ -335,7 +507,7 @@ impl FixCoordinator {
             if let Some(cycle_start) = history.iter().position(|(h, _)| *h == current_hash) {
                 if cycle_start == history.len() - 1 {
                     // Content matches the last recorded state: nothing changed this iteration.
-                    return Ok(FixResult {
+                    return FixResult {
                         rules_fixed: total_fixed,
                         iterations,
                         context_creations: total_ctx_creations,
@@ -343,26 +515,24 @@ impl FixCoordinator {
                         converged: true,
                         conflicting_rules: Vec::new(),
                         conflict_cycle: Vec::new(),
-                    });
+                    };
                 } else {
                     // Content matches an older state: oscillation cycle detected.
                     // Collect the rules that participate in the cycle.
-                    let conflict_cycle: Vec<String> = history[cycle_start + 1..]
+                    let cycle_rules: Vec<&str> = history[cycle_start + 1..]
                         .iter()
-                        .map(|(_, r)| r.to_string())
-                        .chain(std::iter::once(this_iter_rule.to_string()))
-                        .filter(|r| !r.is_empty())
+                        .flat_map(|(_, rules)| rules.iter().copied())
+                        .chain(pass_rules.iter().copied())
                         .collect();
-                    let conflicting_rules: Vec<String> = history[cycle_start + 1..]
-                        .iter()
-                        .map(|(_, r)| *r)
-                        .chain(std::iter::once(this_iter_rule))
-                        .filter(|r| !r.is_empty())
+                    let conflict_cycle: Vec<String> =
+                        cycle_rules.iter().map(std::string::ToString::to_string).collect();
+                    let conflicting_rules: Vec<String> = cycle_rules
+                        .into_iter()
                         .collect::<HashSet<&str>>()
                         .into_iter()
                         .map(std::string::ToString::to_string)
                         .collect();
-                    return Ok(FixResult {
+                    return FixResult {
                         rules_fixed: total_fixed,
                         iterations,
                         context_creations: total_ctx_creations,
@@ -370,16 +540,13 @@ impl FixCoordinator {
                         converged: false,
                         conflicting_rules,
                         conflict_cycle,
-                    });
+                    };
                 }
             }
 
-            // New state - record it.
-            history.push((current_hash, this_iter_rule));
-
             // If no fix was applied this iteration, content is stable.
-            if !any_fix_applied {
-                return Ok(FixResult {
+            if pass_rules.is_empty() {
+                return FixResult {
                     rules_fixed: total_fixed,
                     iterations,
                     context_creations: total_ctx_creations,
@@ -387,12 +554,15 @@ impl FixCoordinator {
                     converged: true,
                     conflicting_rules: Vec::new(),
                     conflict_cycle: Vec::new(),
-                });
+                };
             }
+
+            // New state - record it.
+            history.push((current_hash, pass_rules));
         }
 
         // Hit max iterations without detecting a cycle.
-        Ok(FixResult {
+        FixResult {
             rules_fixed: total_fixed,
             iterations,
             context_creations: total_ctx_creations,
@@ -400,10 +570,28 @@ impl FixCoordinator {
             converged: false,
             conflicting_rules: Vec::new(),
             conflict_cycle: Vec::new(),
-        })
+        }
     }
 }
 
+/// Byte ranges the warnings' edits change, skipping edits that would leave
+/// the text as it is
+fn edit_ranges(content: &str, warnings: &[LintWarning]) -> Vec<Range<usize>> {
+    warnings
+        .iter()
+        .filter_map(|w| w.automatic_fix())
+        .flat_map(|fix| std::iter::once(fix).chain(&fix.additional_edits))
+        .filter(|fix| content.get(fix.range.clone()) != Some(fix.replacement.as_str()))
+        .map(|fix| fix.range.clone())
+        .collect()
+}
+
+/// Whether two edits touch the same text. Edits starting at the same offset
+/// conflict even when one is an insertion, since their order would be ambiguous.
+fn ranges_conflict(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start == b.start || (a.start < b.end && b.start < a.end)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -493,6 +681,54 @@ mod tests {
         }
     }
 
+    // Mock rule that replaces every occurrence of a string, with one inline
+    // edit per occurrence, like most real rules
+    #[derive(Clone)]
+    struct ReplaceRule {
+        name: &'static str,
+        from: &'static str,
+        to: &'static str,
+    }
+
+    impl Rule for ReplaceRule {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn check(&self, ctx: &LintContext) -> LintResult {
+            Ok(ctx
+                .content
+                .match_indices(self.from)
+                .map(|(start, _)| LintWarning {
+                    line: 1,
+                    column: start + 1,
+                    end_line: 1,
+                    end_column: start + self.from.len() + 1,
+                    message: format!("Replace {}", self.from),
+                    rule_name: Some(self.name.to_string()),
+                    severity: Severity::Warning,
+                    fix: Some(Fix::new(start..start + self.from.len(), self.to.to_string())),
+                })
+                .collect())
+        }
+
+        fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+            Ok(ctx.content.replace(self.from, self.to))
+        }
+
+        fn description(&self) -> &'static str {
+            "Replace rule for testing"
+        }
+
+        fn category(&self) -> RuleCategory {
+            RuleCategory::Whitespace
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
     #[test]
     fn test_dependency_ordering() {
         let coordinator = FixCoordinator::new();
@@ -534,6 +770,57 @@ mod tests {
         assert!(md013_idx < md009_idx, "MD013 should come before MD009");
     }
 
+    #[test]
+    fn test_order_follows_fix_priority_and_ignores_registry_order() {
+        use crate::rule::FixPriority;
+
+        let coordinator = FixCoordinator::new();
+        let rules = crate::rules::all_rules(&Config::default());
+        let mut reversed = rules.clone();
+        reversed.reverse();
+
+        let names = |rules: &[Box<dyn Rule>]| -> Vec<&'static str> {
+            coordinator.get_optimal_order(rules).iter().map(|r| r.name()).collect()
+        };
+        let ordered = names(&rules);
+        assert_eq!(ordered, names(&reversed), "order must not depend on registration order");
+
+        let position = |name: &str| ordered.iter().position(|&n| n == name).unwrap();
+        // Structure, then content, then whitespace
+        assert!(position("MD001") < position("MD044"));
+        assert!(position("MD044") < position("MD022"));
+        // Dependencies override priority: MD013 reflow must still precede MD009
+        assert!(position("MD013") < position("MD009"));
+        // and MD010 (whitespace) must precede the list indentation rules
+        assert!(position("MD010") < position("MD005"));
+
+        let md013 = rules.iter().find(|r| r.name() == "MD013").unwrap();
+        assert_eq!(md013.fix_priority(), FixPriority::Reflow);
+    }
+
+    #[test]
+    fn test_line_endings_wait_for_other_fixes() {
+        use crate::rules::{LineEndingStyle, MD009TrailingSpaces, MD107LineEndings};
+
+        let coordinator = FixCoordinator::new();
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD107LineEndings::new(LineEndingStyle::Lf, false)),
+            Box::new(MD009TrailingSpaces::default()),
+        ];
+        let mut content = "\u{feff}Title   \r\nText\r\n".to_string();
+
+        let result = coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &Config::default(), 10, None)
+            .unwrap();
+
+        assert_eq!(content, "Title\nText\n");
+        assert!(result.converged);
+        // MD009 fixes the first pass alone, MD107 converts the second, and
+        // the third finds nothing left
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.fixed_rule_names.len(), 2);
+    }
+
     #[test]
     fn test_single_rule_fix() {
         let coordinator = FixCoordinator::new();
@@ -683,6 +970,26 @@ mod tests {
         assert_eq!(result.rules_fixed, 1);
     }
 
+    #[test]
+    fn test_unsafe_fixes_need_opt_in() {
+        let coordinator = FixCoordinator::new();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(crate::rules::MD063HeadingCapitalization::new())];
+        let mut config = Config::default();
+
+        let mut content = "# hello world\n".to_string();
+        let result = coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &config, 5, None)
+            .unwrap();
+        assert_eq!(content, "# hello world\n");
+        assert_eq!(result.rules_fixed, 0);
+
+        config.global.unsafe_fixes = true;
+        coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &config, 5, None)
+            .unwrap();
+        assert_eq!(content, "# Hello World\n");
+    }
+
     /// Aliases in `unfixable` (e.g. `"heading-increment"`) must reach
     /// `apply_fixes_iterative` already canonicalised — the runtime invariant
     /// enforced by `Config::canonicalize_rule_lists` at every mutation
@@ -995,4 +1302,118 @@ mod tests {
             "inline rumdl-configure-file override (allow-sentence-double-space) must prevent the MD064 fix"
         );
     }
+
+    #[test]
+    fn test_non_overlapping_fixes_apply_in_one_pass() {
+        let coordinator = FixCoordinator::new();
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(ReplaceRule {
+                name: "RuleA",
+                from: "aa",
+                to: "a",
+            }),
+            Box::new(ReplaceRule {
+                name: "RuleB",
+                from: "bb",
+                to: "b",
+            }),
+        ];
+
+        let mut content = "aa bb aa bb".to_string();
+        let result = coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &Config::default(), 10, None)
+            .unwrap();
+
+        assert_eq!(content, "a b a b");
+        assert!(result.converged);
+        assert_eq!(result.rules_fixed, 2);
+        // One pass applies both rules, a second confirms the fixpoint
+        assert_eq!(result.iterations, 2);
+        assert_eq!(result.context_creations, 2);
+    }
+
+    #[test]
+    fn test_overlapping_fixes_resolved_by_order() {
+        let coordinator = FixCoordinator::new();
+        // Both rules want to rewrite "xy"; RuleA comes first and wins, and RuleB
+        // is re-checked against RuleA's result in the next pass
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(ReplaceRule {
+                name: "RuleA",
+                from: "xy",
+                to: "z",
+            }),
+            Box::new(ReplaceRule {
+                name: "RuleB",
+                from: "y",
+                to: "w",
+            }),
+        ];
+
+        let mut content = "xy y".to_string();
+        let result = coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &Config::default(), 10, None)
+            .unwrap();
+
+        assert_eq!(content, "z w");
+        assert!(result.converged);
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.rules_fixed, 2);
+    }
+
+    #[test]
+    fn test_dependent_rule_waits_for_prerequisite() {
+        let mut coordinator = FixCoordinator::new();
+        coordinator.dependencies.insert("RuleA", vec!["RuleB"]);
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(ReplaceRule {
+                name: "RuleB",
+                from: "b",
+                to: "c",
+            }),
+            Box::new(ReplaceRule {
+                name: "RuleA",
+                from: "a",
+                to: "b",
+            }),
+        ];
+
+        // Applied together RuleB would fix the original "b" and then need a
+        // second pass for the "b" RuleA produces; waiting for RuleA lets it
+        // fix both in one pass
+        let mut content = "a b".to_string();
+        let result = coordinator
+            .apply_fixes_iterative(&rules, &[], &mut content, &Config::default(), 10, None)
+            .unwrap();
+
+        assert_eq!(content, "c c");
+        assert!(result.converged);
+        assert_eq!(result.rules_fixed, 2);
+    }
+
+    #[test]
+    fn test_apply_fixes_in_lines_leaves_other_lines_untouched() {
+        let coordinator = FixCoordinator::new();
+        let config = Config::default();
+        let rules: Vec<Box<dyn Rule>> = ["MD009", "MD010"]
+            .iter()
+            .map(|name| crate::rules::create_rule_by_name(name, &config).unwrap())
+            .collect();
+
+        // The tab expansion on line 3 moves the end of the range; line 4 is
+        // still fixed and line 5 is not
+        let mut content = "# T\n\na\tb   \nc   \nd   \n".to_string();
+        let result = coordinator
+            .apply_fixes_in_lines(&rules, &mut content, 3..=4, &config, 10, None)
+            .unwrap();
+        assert_eq!(content, "# T\n\na    b\nc\nd   \n");
+        assert!(result.converged);
+
+        // A range past the end of a document without a trailing newline
+        let mut content = "a   \nb   ".to_string();
+        coordinator
+            .apply_fixes_in_lines(&rules, &mut content, 2..=9, &config, 10, None)
+            .unwrap();
+        assert_eq!(content, "a   \nb");
+    }
 }
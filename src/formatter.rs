@@ -195,6 +195,13 @@ pub fn print_config_with_provenance_no_defaults(sourced: &rumdl_config::SourcedC
         ));
         has_global_section = true;
     }
+    if g.extensions.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("extensions = {:?}", g.extensions.value),
+            provenance_label(&g.extensions, root),
+        ));
+        has_global_section = true;
+    }
     if g.respect_gitignore.source != rumdl_config::ConfigSource::Default {
         global_lines.push((
             format!("respect_gitignore = {}", g.respect_gitignore.value),
@@ -216,6 +223,20 @@ pub fn print_config_with_provenance_no_defaults(sourced: &rumdl_config::SourcedC
         ));
         has_global_section = true;
     }
+    if g.length_mode.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("length_mode = \"{}\"", g.length_mode.value),
+            provenance_label(&g.length_mode, root),
+        ));
+        has_global_section = true;
+    }
+    if g.ambiguous_width.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("ambiguous_width = \"{}\"", g.ambiguous_width.value),
+            provenance_label(&g.ambiguous_width, root),
+        ));
+        has_global_section = true;
+    }
     if g.force_exclude.source != rumdl_config::ConfigSource::Default {
         global_lines.push((
             format!("force_exclude = {}", g.force_exclude.value),
@@ -227,6 +248,73 @@ pub fn print_config_with_provenance_no_defaults(sourced: &rumdl_config::SourcedC
         global_lines.push((format!("cache = {}", g.cache.value), provenance_label(&g.cache, root)));
         has_global_section = true;
     }
+    if g.nested_configs.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("nested_configs = \"{}\"", g.nested_configs.value),
+            provenance_label(&g.nested_configs, root),
+        ));
+        has_global_section = true;
+    }
+    if g.fix_max_iterations.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("fix_max_iterations = {}", g.fix_max_iterations.value),
+            provenance_label(&g.fix_max_iterations, root),
+        ));
+        has_global_section = true;
+    }
+    if g.unsafe_fixes.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("unsafe_fixes = {}", g.unsafe_fixes.value),
+            provenance_label(&g.unsafe_fixes, root),
+        ));
+        has_global_section = true;
+    }
+    if let Some(ref rule_timeout_ms) = g.rule_timeout_ms
+        && rule_timeout_ms.source != rumdl_config::ConfigSource::Default
+    {
+        global_lines.push((
+            format!("rule_timeout_ms = {}", rule_timeout_ms.value),
+            provenance_label(rule_timeout_ms, root),
+        ));
+        has_global_section = true;
+    }
+    if let Some(ref max_file_size) = g.max_file_size
+        && max_file_size.source != rumdl_config::ConfigSource::Default
+    {
+        global_lines.push((
+            format!("max_file_size = {}", max_file_size.value),
+            provenance_label(max_file_size, root),
+        ));
+        has_global_section = true;
+    }
+    if g.large_file_mode.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("large_file_mode = \"{}\"", g.large_file_mode.value),
+            provenance_label(&g.large_file_mode, root),
+        ));
+        has_global_section = true;
+    }
+    if g.draft_key.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("draft_key = {:?}", g.draft_key.value),
+            provenance_label(&g.draft_key, root),
+        ));
+        has_global_section = true;
+    }
+    if g.draft_value.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("draft_value = {:?}", g.draft_value.value),
+            provenance_label(&g.draft_value, root),
+        ));
+        has_global_section = true;
+    }
+    if g.draft_disable.source != rumdl_config::ConfigSource::Default {
+        global_lines.push((
+            format!("draft_disable = {:?}", g.draft_disable.value),
+            provenance_label(&g.draft_disable, root),
+        ));
+        has_global_section = true;
+    }
     if let Some(ref output_format) = g.output_format
         && output_format.source != rumdl_config::ConfigSource::Default
     {
@@ -245,6 +333,22 @@ pub fn print_config_with_provenance_no_defaults(sourced: &rumdl_config::SourcedC
         ));
         has_global_section = true;
     }
+    if let Some(ref docs_base_url) = g.docs_base_url
+        && docs_base_url.source != rumdl_config::ConfigSource::Default
+    {
+        global_lines.push((
+            format!("docs_base_url = {:?}", docs_base_url.value),
+            provenance_label(docs_base_url, root),
+        ));
+        has_global_section = true;
+    }
+    if let Some(ref slugify) = g.slugify
+        && slugify.source != rumdl_config::ConfigSource::Default
+    {
+        let value = toml::Value::try_from(&slugify.value).map_or_else(|_| "?".to_string(), |v| v.to_string());
+        global_lines.push((format!("slugify = {value}"), provenance_label(slugify, root)));
+        has_global_section = true;
+    }
     if g.fixable.source != rumdl_config::ConfigSource::Default {
         global_lines.push((
             format!("fixable = {:?}", g.fixable.value),
@@ -281,6 +385,31 @@ pub fn print_config_with_provenance_no_defaults(sourced: &rumdl_config::SourcedC
         all_lines.push((String::new(), String::new()));
     }
 
+    // Handle overrides if non-default
+    if sourced.overrides.source != rumdl_config::ConfigSource::Default && !sourced.overrides.value.is_empty() {
+        all_lines.push(("[overrides]".to_string(), String::new()));
+        for (pattern, layer) in &sourced.overrides.value {
+            let layer_str = toml::Value::try_from(layer).map(|v| v.to_string()).unwrap_or_default();
+            all_lines.push((
+                format!("{pattern:?} = {layer_str}"),
+                provenance_label(&sourced.overrides, root),
+            ));
+        }
+        all_lines.push((String::new(), String::new()));
+    }
+
+    // Handle profiles if non-default
+    if sourced.profiles.source != rumdl_config::ConfigSource::Default && !sourced.profiles.value.is_empty() {
+        all_lines.push(("[profiles]".to_string(), String::new()));
+        for (name, profile) in &sourced.profiles.value {
+            all_lines.push((
+                format!("{name} = {}", toml::Value::Table(profile.clone())),
+                provenance_label(&sourced.profiles, root),
+            ));
+        }
+        all_lines.push((String::new(), String::new()));
+    }
+
     // Handle rule configurations
     let mut rule_names: Vec<_> = sourced.rules.keys().cloned().collect();
     rule_names.sort();
@@ -357,6 +486,10 @@ pub fn print_config_with_provenance(sourced: &rumdl_config::SourcedConfig, all_r
             format!("include = {:?}", g.include.value),
             provenance_label(&g.include, root),
         ),
+        (
+            format!("extensions = {:?}", g.extensions.value),
+            provenance_label(&g.extensions, root),
+        ),
         (
             format!("respect_gitignore = {}", g.respect_gitignore.value),
             provenance_label(&g.respect_gitignore, root),
@@ -458,42 +591,54 @@ pub fn format_toml_value(val: &toml::Value) -> String {
     }
 }
 
-/// Print statistics about lint warnings by rule
-pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
-    use std::collections::HashMap;
+/// Warning counts for one rule, as reported by `--statistics`
+struct RuleStatistics<'a> {
+    rule: &'a str,
+    count: usize,
+    fixable: usize,
+}
 
-    // Group warnings by rule name
-    let mut rule_counts: HashMap<&str, usize> = HashMap::new();
-    let mut fixable_counts: HashMap<&str, usize> = HashMap::new();
+/// Per-rule warning counts, most frequent rule first and ties by rule name
+fn rule_statistics(warnings: &[rumdl_lib::rule::LintWarning]) -> Vec<RuleStatistics<'_>> {
+    use std::collections::HashMap;
 
+    let mut by_rule: HashMap<&str, RuleStatistics> = HashMap::new();
     for warning in warnings {
-        let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
-        *rule_counts.entry(rule_name).or_insert(0) += 1;
-
-        if warning.fix.is_some() {
-            *fixable_counts.entry(rule_name).or_insert(0) += 1;
+        let rule = warning.rule_name.as_deref().unwrap_or("unknown");
+        let stats = by_rule.entry(rule).or_insert(RuleStatistics {
+            rule,
+            count: 0,
+            fixable: 0,
+        });
+        stats.count += 1;
+        if warning.automatic_fix().is_some() {
+            stats.fixable += 1;
         }
     }
 
-    // Sort rules by count (descending)
-    let mut sorted_rules: Vec<_> = rule_counts.iter().collect();
-    sorted_rules.sort_by(|a, b| b.1.cmp(a.1));
+    let mut sorted: Vec<_> = by_rule.into_values().collect();
+    sorted.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.rule.cmp(b.rule)));
+    sorted
+}
+
+/// Print statistics about lint warnings by rule
+pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
+    let sorted_rules = rule_statistics(warnings);
 
     println!("\n{}", "Rule Violation Statistics:".bold().underline());
     println!("{:<8} {:<12} {:<8} Percentage", "Rule", "Violations", "Fixable");
     println!("{}", "-".repeat(50));
 
     let total_warnings = warnings.len();
-    for (rule, count) in sorted_rules {
-        let fixable = fixable_counts.get(rule).unwrap_or(&0);
-        let percentage = (*count as f64 / total_warnings as f64) * 100.0;
+    for stats in &sorted_rules {
+        let percentage = (stats.count as f64 / total_warnings as f64) * 100.0;
 
         println!(
             "{:<8} {:<12} {:<8} {:>6.1}%",
-            rule,
-            count,
-            if *fixable > 0 {
-                format!("{fixable}")
+            stats.rule,
+            stats.count,
+            if stats.fixable > 0 {
+                format!("{}", stats.fixable)
             } else {
                 "-".to_string()
             },
@@ -506,11 +651,39 @@ pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
         "{:<8} {:<12} {:<8} {:>6.1}%",
         "Total",
         total_warnings,
-        fixable_counts.values().sum::<usize>(),
+        sorted_rules.iter().map(|stats| stats.fixable).sum::<usize>(),
         100.0
     );
 }
 
+/// Statistics about lint warnings by rule as a JSON document, for
+/// `--statistics --output-format json`
+pub fn format_statistics_json(warnings: &[rumdl_lib::rule::LintWarning]) -> String {
+    let sorted_rules = rule_statistics(warnings);
+    let total_warnings = warnings.len();
+
+    let rules: Vec<serde_json::Value> = sorted_rules
+        .iter()
+        .map(|stats| {
+            // Rounded to one decimal like the text table
+            let percentage = (stats.count as f64 * 1000.0 / total_warnings as f64).round() / 10.0;
+            serde_json::json!({
+                "rule": stats.rule,
+                "count": stats.count,
+                "fixable": stats.fixable,
+                "percentage": percentage,
+            })
+        })
+        .collect();
+
+    let report = serde_json::json!({
+        "rules": rules,
+        "total": total_warnings,
+        "fixable": sorted_rules.iter().map(|stats| stats.fixable).sum::<usize>(),
+    });
+    serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+}
+
 /// Generate a unified diff between original and modified content
 pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String {
     let mut diff = String::new();
@@ -519,8 +692,14 @@ pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String
     diff.push_str(&format!("--- {file_path}\n"));
     diff.push_str(&format!("+++ {file_path} (fixed)\n"));
 
-    let original_lines: Vec<&str> = original.lines().collect();
-    let modified_lines: Vec<&str> = modified.lines().collect();
+    // Lines keep their `\r`, so a changed line ending shows up as a change
+    fn split_lines(text: &str) -> Vec<&str> {
+        text.split_inclusive('\n')
+            .map(|line| line.strip_suffix('\n').unwrap_or(line))
+            .collect()
+    }
+    let original_lines = split_lines(original);
+    let modified_lines = split_lines(modified);
 
     // Simple line-by-line diff (could be improved with a proper diff algorithm)
     let max_lines = original_lines.len().max(modified_lines.len());
@@ -550,6 +729,16 @@ pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String
         changes.push((diff_start, max_lines));
     }
 
+    // Changes closer together than the context merge into one block
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(changes.len());
+    for (start, end) in changes {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    let changes = merged;
+
     // Generate unified diff format for each change block
     if changes.is_empty() {
         diff.push_str("No changes\n");
@@ -588,6 +777,68 @@ pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String
     diff
 }
 
+/// Fixes planned for one file by `--fix --dry-run --output-format json`.
+///
+/// The plan comes from the same pipeline as `--fix`, but nothing is written:
+/// applying `edits` to the file on disk yields exactly the content `--fix`
+/// would write, whose hash is `fixed_hash`.
+pub struct FixPlan {
+    /// Blake3 hash of the file as read from disk
+    pub original_hash: String,
+    /// Blake3 hash of the content after all edits are applied
+    pub fixed_hash: String,
+    pub issues_fixed: usize,
+    /// Non-overlapping replacements, as byte ranges into the original, in order
+    pub edits: Vec<(std::ops::Range<usize>, String)>,
+    /// Warnings still reported once the fixes are applied
+    pub remaining: Vec<rumdl_lib::rule::LintWarning>,
+}
+
+impl FixPlan {
+    pub fn new(original: &str, fixed: &str, issues_fixed: usize, remaining: Vec<rumdl_lib::rule::LintWarning>) -> Self {
+        Self {
+            original_hash: crate::cache::LintCache::hash_content(original),
+            fixed_hash: crate::cache::LintCache::hash_content(fixed),
+            issues_fixed,
+            edits: rumdl_lib::utils::fix_utils::plan_edits(original, fixed),
+            remaining,
+        }
+    }
+}
+
+/// Format fix plans as a JSON array with one entry per file
+pub fn format_fix_plan_json(plans: &[(String, FixPlan)]) -> String {
+    use rumdl_lib::output::formatters::json::warning_to_json;
+
+    let files: Vec<serde_json::Value> = plans
+        .iter()
+        .map(|(file_path, plan)| {
+            serde_json::json!({
+                "file": file_path,
+                "original_hash": plan.original_hash,
+                "fixed_hash": plan.fixed_hash,
+                "fixed": plan.issues_fixed,
+                "edits": plan
+                    .edits
+                    .iter()
+                    .map(|(range, replacement)| {
+                        serde_json::json!({
+                            "range": { "start": range.start, "end": range.end },
+                            "replacement": replacement,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+                "remaining": plan
+                    .remaining
+                    .iter()
+                    .map(|warning| warning_to_json(file_path, warning))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&files).unwrap_or_else(|_| "[]".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -657,6 +908,16 @@ mod tests {
         assert!(result.contains("@@"), "Expected @@ hunk header in diff:\n{result}");
     }
 
+    #[test]
+    fn test_generate_diff_merges_nearby_changes_into_one_hunk() {
+        let original = "a\nb\nc\nd\ne\n";
+        let modified = "a\nB\nc\nD\ne\n";
+        let result = generate_diff(original, modified, "f.md");
+
+        assert_eq!(result.matches("@@ -").count(), 1, "Expected a single hunk:\n{result}");
+        assert_eq!(result.matches("-b\n").count(), 1);
+    }
+
     #[test]
     fn test_format_toml_value_string_is_quoted() {
         let val = toml::Value::String("hello world".to_string());
@@ -709,4 +970,18 @@ mod tests {
         ]);
         assert_eq!(format_toml_value(&val), "[1, 2, 3]");
     }
+
+    #[test]
+    fn test_format_fix_plan_json() {
+        let plan = FixPlan::new("a  \n", "a\n", 1, Vec::new());
+        let json: serde_json::Value =
+            serde_json::from_str(&format_fix_plan_json(&[("doc.md".to_string(), plan)])).unwrap();
+        assert_eq!(json[0]["file"], "doc.md");
+        assert_eq!(json[0]["fixed"], 1);
+        assert_eq!(json[0]["edits"][0]["range"]["start"], 1);
+        assert_eq!(json[0]["edits"][0]["range"]["end"], 3);
+        assert_eq!(json[0]["edits"][0]["replacement"], "");
+        assert_eq!(json[0]["fixed_hash"], crate::cache::LintCache::hash_content("a\n"));
+        assert_ne!(json[0]["original_hash"], json[0]["fixed_hash"]);
+    }
 }
@@ -0,0 +1,199 @@
+//! Per-document rule overrides declared in front matter
+//!
+//! Supports, in YAML, TOML or JSON front matter:
+//! - `rumdl: { disable: [MD025, line-length] }` - Disable rules for the entire file
+//! - `rumdl: { configure: { MD013: { line-length: 120 } } }` - Configure rules for the entire file
+//! - `draft: true` - Disable the length rules ([`DRAFT_DISABLED_RULES`]) on drafts,
+//!   as used by Hugo and Docusaurus
+//!
+//! `LintContext` folds these into its [`InlineConfig`](crate::inline_config::InlineConfig),
+//! so they behave like `disable-file` and `configure-file` comments for every rule.
+
+use crate::markdownlint_config::markdownlint_to_rumdl_rule_key;
+use crate::rules::front_matter_utils::{FrontMatterType, FrontMatterUtils};
+use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+
+/// Rules disabled in documents whose front matter sets `draft: true`
+pub const DRAFT_DISABLED_RULES: &[&str] = &["MD013"];
+
+/// Normalize a rule name to its canonical form (e.g., "line-length" -> "MD013").
+fn normalize_rule_name(rule: &str) -> String {
+    if crate::prose_styles::is_style_rule_id(rule) {
+        return rule.to_string();
+    }
+    markdownlint_to_rumdl_rule_key(rule).map_or_else(|| rule.to_uppercase(), std::string::ToString::to_string)
+}
+
+/// Rule overrides declared in a document's front matter
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrontMatterConfig {
+    /// Rules disabled via `rumdl.disable`, normalized to their canonical names
+    disabled_rules: HashSet<String>,
+    /// Configuration overrides from `rumdl.configure`, keyed by canonical rule name
+    rule_configs: HashMap<String, JsonValue>,
+    /// Whether the front matter sets `draft: true`
+    draft: bool,
+}
+
+impl FrontMatterConfig {
+    /// Read the overrides from the document's front matter
+    ///
+    /// Front matter that is absent, malformed or fails to parse yields no overrides.
+    pub fn from_content(content: &str) -> Self {
+        let front_matter_type = FrontMatterUtils::detect_front_matter_type(content);
+        if !matches!(
+            front_matter_type,
+            FrontMatterType::Yaml | FrontMatterType::Toml | FrontMatterType::Json
+        ) {
+            return Self::default();
+        }
+
+        let body = FrontMatterUtils::extract_front_matter(content).join("\n");
+        // Cheap pre-check: most front matter has neither key
+        if !body.contains("rumdl") && !body.contains("draft") {
+            return Self::default();
+        }
+
+        let value = match front_matter_type {
+            FrontMatterType::Yaml => serde_yaml::from_str::<JsonValue>(&body).ok(),
+            FrontMatterType::Toml => toml::from_str::<toml::Table>(&body)
+                .ok()
+                .and_then(|table| serde_json::to_value(table).ok()),
+            // The delimiter lines are the braces of the JSON object
+            _ => serde_json::from_str::<JsonValue>(&format!("{{{body}}}")).ok(),
+        };
+
+        value.map_or_else(Self::default, |value| Self::from_value(&value))
+    }
+
+    fn from_value(value: &JsonValue) -> Self {
+        let mut config = Self {
+            draft: value.get("draft").and_then(JsonValue::as_bool).unwrap_or(false),
+            ..Self::default()
+        };
+
+        let Some(rumdl) = value.get("rumdl") else {
+            return config;
+        };
+
+        match rumdl.get("disable") {
+            Some(JsonValue::String(rule)) => {
+                config.disabled_rules.insert(normalize_rule_name(rule));
+            }
+            Some(JsonValue::Array(rules)) => {
+                config
+                    .disabled_rules
+                    .extend(rules.iter().filter_map(JsonValue::as_str).map(normalize_rule_name));
+            }
+            _ => {}
+        }
+
+        if let Some(configure) = rumdl.get("configure").and_then(JsonValue::as_object) {
+            for (rule_name, rule_config) in configure {
+                if rule_config.is_object() {
+                    config
+                        .rule_configs
+                        .insert(normalize_rule_name(rule_name), rule_config.clone());
+                }
+            }
+        }
+
+        config
+    }
+
+    /// Whether the front matter declares no overrides
+    pub fn is_empty(&self) -> bool {
+        self.disabled_rules.is_empty() && self.rule_configs.is_empty() && !self.draft
+    }
+
+    /// Whether the document is a draft (`draft: true`)
+    pub fn is_draft(&self) -> bool {
+        self.draft
+    }
+
+    /// Rules disabled for the whole document, including those disabled on drafts
+    pub fn disabled_rules(&self) -> impl Iterator<Item = &str> {
+        let draft_rules = if self.draft { DRAFT_DISABLED_RULES } else { &[] };
+        self.disabled_rules
+            .iter()
+            .map(String::as_str)
+            .chain(draft_rules.iter().copied())
+    }
+
+    /// Check if the front matter disables a rule (by canonical name)
+    pub fn is_rule_disabled(&self, rule_name: &str) -> bool {
+        self.disabled_rules.contains(rule_name) || (self.draft && DRAFT_DISABLED_RULES.contains(&rule_name))
+    }
+
+    /// Get the configuration override for a rule (by canonical name)
+    pub fn get_rule_config(&self, rule_name: &str) -> Option<&JsonValue> {
+        self.rule_configs.get(rule_name)
+    }
+
+    /// Get all configuration overrides, keyed by canonical rule name
+    pub fn get_all_rule_configs(&self) -> &HashMap<String, JsonValue> {
+        &self.rule_configs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_yaml_disable_and_configure() {
+        let content = "---\ntitle: Post\nrumdl:\n  disable: [MD025, no-inline-html]\n  configure:\n    line-length:\n      line-length: 120\n---\n\n# Post\n";
+        let config = FrontMatterConfig::from_content(content);
+
+        assert!(config.is_rule_disabled("MD025"));
+        assert!(config.is_rule_disabled("MD033"));
+        assert!(!config.is_rule_disabled("MD013"));
+        assert_eq!(config.get_rule_config("MD013"), Some(&json!({ "line-length": 120 })));
+        assert!(!config.is_draft());
+    }
+
+    #[test]
+    fn test_yaml_flow_mapping_and_single_rule() {
+        let config = FrontMatterConfig::from_content("---\nrumdl: { disable: MD041 }\n---\n\nText\n");
+        assert!(config.is_rule_disabled("MD041"));
+        assert_eq!(config.disabled_rules().collect::<Vec<_>>(), vec!["MD041"]);
+    }
+
+    #[test]
+    fn test_draft_disables_length_rules() {
+        let config = FrontMatterConfig::from_content("---\ntitle: WIP\ndraft: true\n---\n\n# WIP\n");
+        assert!(config.is_draft());
+        assert!(config.is_rule_disabled("MD013"));
+        assert!(!config.is_rule_disabled("MD025"));
+
+        let published = FrontMatterConfig::from_content("---\ndraft: false\n---\n\n# Done\n");
+        assert!(published.is_empty());
+    }
+
+    #[test]
+    fn test_toml_front_matter() {
+        let content = "+++\ntitle = \"Post\"\ndraft = true\n\n[rumdl]\ndisable = [\"MD025\"]\n+++\n\n# Post\n";
+        let config = FrontMatterConfig::from_content(content);
+
+        assert!(config.is_draft());
+        assert!(config.is_rule_disabled("MD025"));
+        assert!(config.is_rule_disabled("MD013"));
+    }
+
+    #[test]
+    fn test_json_front_matter() {
+        let content = "{\n\"rumdl\": { \"configure\": { \"MD013\": { \"line_length\": 100 } } }\n}\n\n# Post\n";
+        let config = FrontMatterConfig::from_content(content);
+
+        assert_eq!(config.get_rule_config("MD013"), Some(&json!({ "line_length": 100 })));
+    }
+
+    #[test]
+    fn test_no_or_invalid_front_matter() {
+        assert!(FrontMatterConfig::from_content("# rumdl\n\ndraft: true\n").is_empty());
+        assert!(FrontMatterConfig::from_content("---\nrumdl: [unclosed\n---\n\n# Title\n").is_empty());
+        assert!(FrontMatterConfig::from_content("---\nrumdl: ignored\n---\n\n# Title\n").is_empty());
+    }
+}
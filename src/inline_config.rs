@@ -17,15 +17,26 @@
 //! - `<!-- prettier-ignore -->` - Disable all rules for next line (compatibility with prettier)
 //!
 //! Also supports rumdl-specific syntax with same semantics.
+//!
+//! Disable comments may explain themselves after a standalone `--`, as in
+//! `<!-- rumdl-disable-next-line MD013 -- generated table -->`; the reason is
+//! not read as a rule name.
 
+use crate::front_matter_config::FrontMatterConfig;
 use crate::markdownlint_config::markdownlint_to_rumdl_rule_key;
+use crate::rule::{Fix, LintWarning, Severity};
 use crate::utils::code_block_utils::CodeBlockUtils;
 use serde_json::Value as JsonValue;
 use std::collections::{HashMap, HashSet};
+use std::ops::{Range, RangeInclusive};
 
 /// Normalize a rule name to its canonical form (e.g., "line-length" -> "MD013").
-/// If the rule name is not recognized, returns it uppercase (for forward compatibility).
+/// Prose style rule IDs (`Style.Rule`) keep their case; other unrecognized
+/// names are uppercased (for forward compatibility).
 fn normalize_rule_name(rule: &str) -> String {
+    if crate::prose_styles::is_style_rule_id(rule) {
+        return rule.to_string();
+    }
     markdownlint_to_rumdl_rule_key(rule).map_or_else(|| rule.to_uppercase(), std::string::ToString::to_string)
 }
 
@@ -57,6 +68,37 @@ struct StateTransition {
     enabled: HashSet<String>,
 }
 
+/// A disable comment and the lines it covers, kept for reporting suppressions
+/// that no longer suppress anything
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisableComment {
+    /// The 1-indexed line of the comment
+    pub line: usize,
+    pub kind: DirectiveKind,
+    /// Rule names as written; empty disables every rule
+    pub rules: Vec<String>,
+    /// Explanation given after a standalone `--`
+    pub reason: Option<String>,
+    /// Byte range of the `<!-- ... -->` comment within its line
+    pub span: Range<usize>,
+    /// Lines whose warnings the comment suppresses (1-indexed)
+    pub scope: RangeInclusive<usize>,
+    /// Line and span of the enable comment ending a `disable` block, when it
+    /// re-enables exactly the rules this comment disabled
+    pub closed_by: Option<(usize, Range<usize>)>,
+}
+
+/// A configure-file comment, kept for validating its payload
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigureComment {
+    /// The 1-indexed line of the comment
+    pub line: usize,
+    /// Byte range of the `<!-- ... -->` comment within its line
+    pub span: Range<usize>,
+    /// The JSON payload, or `None` when it does not parse
+    pub payload: Option<JsonValue>,
+}
+
 #[derive(Debug, Clone)]
 pub struct InlineConfig {
     /// State transitions for persistent disable/enable directives, sorted by line number.
@@ -71,6 +113,10 @@ pub struct InlineConfig {
     /// Configuration overrides for specific rules from configure-file comments
     /// Maps rule name to configuration JSON value
     file_rule_config: HashMap<String, JsonValue>,
+    /// Disable comments in document order
+    disable_comments: Vec<DisableComment>,
+    /// Configure-file comments in document order
+    configure_comments: Vec<ConfigureComment>,
 }
 
 impl Default for InlineConfig {
@@ -87,6 +133,8 @@ impl InlineConfig {
             file_disabled_rules: HashSet::new(),
             file_enabled_rules: HashSet::new(),
             file_rule_config: HashMap::new(),
+            disable_comments: Vec::new(),
+            configure_comments: Vec::new(),
         }
     }
 
@@ -116,20 +164,38 @@ impl InlineConfig {
         }
 
         let code_blocks = CodeBlockUtils::detect_code_blocks(content);
-        Self::from_content_with_code_blocks_internal(content, &code_blocks)
+        Self::from_content_with_code_blocks_internal(content, &code_blocks, Self::new())
     }
 
     /// Process all inline comments in the content with precomputed code blocks.
     pub fn from_content_with_code_blocks(content: &str, code_blocks: &[(usize, usize)]) -> Self {
+        Self::from_content_with_front_matter(content, code_blocks, &FrontMatterConfig::default())
+    }
+
+    /// Process all inline comments on top of the overrides declared in front matter.
+    ///
+    /// Front matter disables and configures rules for the entire file, as if by
+    /// `disable-file` and `configure-file` comments on the first line, so comments
+    /// later in the document take precedence.
+    pub fn from_content_with_front_matter(
+        content: &str,
+        code_blocks: &[(usize, usize)],
+        front_matter: &FrontMatterConfig,
+    ) -> Self {
+        let mut config = Self::new();
+        config
+            .file_disabled_rules
+            .extend(front_matter.disabled_rules().map(str::to_string));
+        config.file_rule_config.clone_from(front_matter.get_all_rule_configs());
+
         if !has_inline_config_markers(content) {
-            return Self::new();
+            return config;
         }
 
-        Self::from_content_with_code_blocks_internal(content, code_blocks)
+        Self::from_content_with_code_blocks_internal(content, code_blocks, config)
     }
 
-    fn from_content_with_code_blocks_internal(content: &str, code_blocks: &[(usize, usize)]) -> Self {
-        let mut config = Self::new();
+    fn from_content_with_code_blocks_internal(content: &str, code_blocks: &[(usize, usize)], mut config: Self) -> Self {
         let lines: Vec<&str> = content.lines().collect();
 
         // Pre-compute line positions for checking if a line is in a code block
@@ -149,6 +215,9 @@ impl InlineConfig {
         let mut prev_disabled: HashSet<String> = HashSet::new();
         let mut prev_enabled: HashSet<String> = HashSet::new();
 
+        // Persistent disables whose scope is still open, as indices into disable_comments
+        let mut open_disables: Vec<usize> = Vec::new();
+
         // Record initial state (line 1: nothing disabled)
         config.transitions.push(StateTransition {
             line: 1,
@@ -193,6 +262,9 @@ impl InlineConfig {
             for directive in &directives {
                 match directive.kind {
                     DirectiveKind::DisableFile => {
+                        if directive.rules.is_empty() || !config.file_disabled_rules.contains("*") {
+                            config.record_disable(directive, line_num, 1..=lines.len());
+                        }
                         if directive.rules.is_empty() {
                             config.file_disabled_rules.clear();
                             config.file_disabled_rules.insert("*".to_string());
@@ -221,13 +293,17 @@ impl InlineConfig {
                         }
                     }
                     DirectiveKind::ConfigureFile => {
-                        if let Some(json_config) = parse_configure_file_comment(line)
-                            && let Some(obj) = json_config.as_object()
-                        {
+                        let payload = parse_configure_file_comment(line);
+                        if let Some(obj) = payload.as_ref().and_then(JsonValue::as_object) {
                             for (rule_name, rule_config) in obj {
                                 config.file_rule_config.insert(rule_name.clone(), rule_config.clone());
                             }
                         }
+                        config.configure_comments.push(ConfigureComment {
+                            line: line_num,
+                            span: directive.span.clone(),
+                            payload,
+                        });
                     }
                     _ => {}
                 }
@@ -238,6 +314,7 @@ impl InlineConfig {
                 match directive.kind {
                     DirectiveKind::DisableNextLine => {
                         let next_line = line_num + 1;
+                        config.record_disable(directive, line_num, next_line..=next_line);
                         let line_rules = config.line_disabled_rules.entry(next_line).or_default();
                         if directive.rules.is_empty() {
                             line_rules.insert("*".to_string());
@@ -248,6 +325,7 @@ impl InlineConfig {
                         }
                     }
                     DirectiveKind::DisableLine => {
+                        config.record_disable(directive, line_num, line_num..=line_num);
                         let line_rules = config.line_disabled_rules.entry(line_num).or_default();
                         if directive.rules.is_empty() {
                             line_rules.insert("*".to_string());
@@ -258,6 +336,10 @@ impl InlineConfig {
                         }
                     }
                     DirectiveKind::Disable => {
+                        if directive.rules.is_empty() || !currently_disabled.contains("*") {
+                            open_disables.push(config.disable_comments.len());
+                            config.record_disable(directive, line_num, line_num..=lines.len());
+                        }
                         if directive.rules.is_empty() {
                             currently_disabled.clear();
                             currently_disabled.insert("*".to_string());
@@ -298,6 +380,49 @@ impl InlineConfig {
                     // File-wide directives already handled in pass 1
                     DirectiveKind::DisableFile | DirectiveKind::EnableFile | DirectiveKind::ConfigureFile => {}
                 }
+
+                // An enable or restore ends the scope of the disables it lifts
+                if matches!(directive.kind, DirectiveKind::Enable | DirectiveKind::Restore) {
+                    let still_disabled = |rule: &str| {
+                        if currently_disabled.contains("*") {
+                            !currently_enabled.contains(rule)
+                        } else {
+                            currently_disabled.contains(rule)
+                        }
+                    };
+                    let mut closed = Vec::new();
+                    open_disables.retain(|&idx| {
+                        let comment = &mut config.disable_comments[idx];
+                        let open = if comment.rules.is_empty() {
+                            currently_disabled.contains("*")
+                        } else {
+                            comment
+                                .rules
+                                .iter()
+                                .any(|rule| still_disabled(&normalize_rule_name(rule)))
+                        };
+                        if !open {
+                            comment.scope = *comment.scope.start()..=line_num;
+                            closed.push(idx);
+                        }
+                        open
+                    });
+                    if let [idx] = closed[..]
+                        && directive.kind == DirectiveKind::Enable
+                    {
+                        let comment = &mut config.disable_comments[idx];
+                        let normalized = |rules: &[&str]| {
+                            rules
+                                .iter()
+                                .map(|rule| normalize_rule_name(rule))
+                                .collect::<HashSet<_>>()
+                        };
+                        let disabled: Vec<&str> = comment.rules.iter().map(String::as_str).collect();
+                        if normalized(&disabled) == normalized(&directive.rules) {
+                            comment.closed_by = Some((line_num, directive.span.clone()));
+                        }
+                    }
+                }
             }
 
             // prettier-ignore: disables all rules for next line
@@ -320,6 +445,23 @@ impl InlineConfig {
         config
     }
 
+    fn record_disable(&mut self, directive: &InlineDirective<'_>, line: usize, scope: RangeInclusive<usize>) {
+        self.disable_comments.push(DisableComment {
+            line,
+            kind: directive.kind,
+            rules: directive.rules.iter().map(|rule| (*rule).to_string()).collect(),
+            reason: directive.reason.map(str::to_string),
+            span: directive.span.clone(),
+            scope,
+            closed_by: None,
+        });
+    }
+
+    /// Disable comments in document order, with the lines each one covers
+    pub fn disable_comments(&self) -> &[DisableComment] {
+        &self.disable_comments
+    }
+
     /// Check if a rule is disabled at a specific line
     pub fn is_rule_disabled(&self, rule_name: &str, line_number: usize) -> bool {
         // Check file-wide disables first (highest priority)
@@ -374,6 +516,11 @@ impl InlineConfig {
         disabled
     }
 
+    /// Configure-file comments in document order
+    pub fn configure_comments(&self) -> &[ConfigureComment] {
+        &self.configure_comments
+    }
+
     /// Get configuration overrides for a specific rule from configure-file comments
     pub fn get_rule_config(&self, rule_name: &str) -> Option<&JsonValue> {
         self.file_rule_config.get(rule_name)
@@ -429,6 +576,10 @@ pub enum DirectiveKind {
 pub struct InlineDirective<'a> {
     pub kind: DirectiveKind,
     pub rules: Vec<&'a str>,
+    /// Explanation after a standalone `--` (e.g. `MD013 -- generated table`)
+    pub reason: Option<&'a str>,
+    /// Byte range of the whole `<!-- ... -->` comment within the line
+    pub span: Range<usize>,
 }
 
 /// Tool prefixes recognized in inline config comments.
@@ -449,10 +600,24 @@ const DIRECTIVE_KEYWORDS: &[(DirectiveKind, &str)] = &[
     (DirectiveKind::Restore, "restore"),
 ];
 
-/// Try to parse a single directive from text immediately after `<!-- `.
-/// Returns the directive and the number of bytes consumed (from `s` onward)
-/// so the caller can advance past `-->`.
-fn try_parse_directive(s: &str) -> Option<(InlineDirective<'_>, usize)> {
+/// Split rule names from the reason after a standalone `--`
+fn split_reason(s: &str) -> (&str, Option<&str>) {
+    for (idx, _) in s.match_indices("--") {
+        let standalone = s[..idx].chars().next_back().is_none_or(char::is_whitespace)
+            && s[idx + 2..].chars().next().is_none_or(char::is_whitespace);
+        if standalone {
+            let reason = s[idx + 2..].trim();
+            return (&s[..idx], (!reason.is_empty()).then_some(reason));
+        }
+    }
+    (s, None)
+}
+
+/// Try to parse a single directive from the comment opening at `comment_start`
+/// (the position of `<!-- `). The directive's span runs through the closing
+/// `-->` so the caller can advance past it.
+fn try_parse_directive(line: &str, comment_start: usize) -> Option<InlineDirective<'_>> {
+    let s = &line[comment_start + 5..]; // skip "<!-- "
     for tool in TOOL_PREFIXES {
         if !s.starts_with(tool) {
             continue;
@@ -474,15 +639,20 @@ fn try_parse_directive(s: &str) -> Option<(InlineDirective<'_>, usize)> {
             // Find closing -->
             let close_offset = after_kw.find("-->")?;
 
-            let rules_str = after_kw[..close_offset].trim();
-            let rules = if rules_str.is_empty() {
-                Vec::new()
+            let (rules_str, reason) = if kind == DirectiveKind::ConfigureFile {
+                (&after_kw[..close_offset], None)
             } else {
-                rules_str.split_whitespace().collect()
+                split_reason(&after_kw[..close_offset])
             };
+            let rules = rules_str.split_whitespace().collect();
 
             let consumed = tool.len() + keyword.len() + close_offset + 3; // 3 for "-->"
-            return Some((InlineDirective { kind, rules }, consumed));
+            return Some(InlineDirective {
+                kind,
+                rules,
+                reason,
+                span: comment_start..comment_start + 5 + consumed,
+            });
         }
 
         // Tool prefix matched but no keyword — not a directive we recognize.
@@ -506,11 +676,10 @@ pub fn parse_inline_directives(line: &str) -> Vec<InlineDirective<'_>> {
             break;
         };
         let comment_start = pos + open_offset;
-        let after_open = &line[comment_start + 5..]; // skip "<!-- "
 
-        if let Some((directive, consumed)) = try_parse_directive(after_open) {
+        if let Some(directive) = try_parse_directive(line, comment_start) {
+            pos = directive.span.end;
             results.push(directive);
-            pos = comment_start + 5 + consumed;
         } else {
             pos = comment_start + 5;
         }
@@ -718,6 +887,211 @@ pub fn validate_inline_config_rules(content: &str) -> Vec<InlineConfigWarning> {
     warnings
 }
 
+/// Rule name reported for disable comments that suppress nothing
+pub const UNUSED_DISABLE_RULE: &str = "unused-disable";
+
+/// Rule name reported for configure-file payloads that do not match the
+/// config of the rules they name
+pub const INLINE_CONFIG_RULE: &str = "inline-config";
+
+/// Report configure-file comments whose payload would be ignored.
+///
+/// Without these warnings an invalid payload is silently dropped: JSON that
+/// does not parse, a rule config that is not an object, an option the rule
+/// does not have, or a value of the wrong type. Each rule's options are
+/// checked against its config schema, as options in config files are.
+/// Unknown rule names are reported by [`validate_inline_config_rules`].
+pub fn configure_payload_warnings(content: &str, inline_config: &InlineConfig) -> Vec<LintWarning> {
+    use crate::config::{default_registry, json_to_toml, resolve_rule_name_alias, validate_rule_options};
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut warnings = Vec::new();
+    for comment in inline_config.configure_comments() {
+        let line = lines.get(comment.line - 1).copied().unwrap_or("");
+        let mut warn = |message: String| {
+            warnings.push(LintWarning {
+                message,
+                line: comment.line,
+                column: line[..comment.span.start].chars().count() + 1,
+                end_line: comment.line,
+                end_column: line[..comment.span.end].chars().count() + 1,
+                severity: Severity::Warning,
+                fix: None,
+                rule_name: Some(INLINE_CONFIG_RULE.to_string()),
+            });
+        };
+
+        let Some(payload) = &comment.payload else {
+            warn("Ignored configure-file comment: payload is not valid JSON".to_string());
+            continue;
+        };
+        let Some(rules) = payload.as_object() else {
+            warn("Ignored configure-file comment: payload must be a JSON object of rule configs".to_string());
+            continue;
+        };
+        for (rule_name, rule_config) in rules {
+            let Some(rule) = resolve_rule_name_alias(rule_name) else {
+                continue;
+            };
+            let Some(options) = rule_config.as_object() else {
+                warn(format!(
+                    "Ignored configure-file config for {rule}: expected a JSON object of options"
+                ));
+                continue;
+            };
+            // Options are merged in kebab-case, so validate them that way
+            let options: Vec<(String, toml::Value)> = options
+                .iter()
+                .filter_map(|(key, value)| Some((key.replace('_', "-"), json_to_toml(value)?)))
+                .collect();
+            for problem in validate_rule_options(rule, options.iter().map(|(k, v)| (k, v)), default_registry()) {
+                warn(format!("Invalid configure-file option: {}", problem.message));
+            }
+        }
+    }
+    warnings
+}
+
+/// Base rule of a warning, e.g. "MD029" for "MD029-style"
+fn warning_rule(warning: &LintWarning) -> &str {
+    let name = warning.rule_name.as_deref().unwrap_or("");
+    name.split_once('-')
+        .filter(|(prefix, _)| prefix.starts_with("MD"))
+        .map_or(name, |(prefix, _)| prefix)
+}
+
+/// Report disable comments that suppress nothing.
+///
+/// `warnings` are the file's warnings before inline suppression and
+/// `checked_rules` the canonical names of the rules that produced them. Rules
+/// outside `checked_rules` are never reported as unused, since nothing is
+/// known about what they would warn about. Each warning's fix removes the
+/// comment, or only the unused rule names when the comment's other rules
+/// still suppress something.
+pub fn unused_disable_warnings(
+    content: &str,
+    inline_config: &InlineConfig,
+    warnings: &[LintWarning],
+    checked_rules: &HashSet<String>,
+) -> Vec<LintWarning> {
+    let mut line_starts = Vec::new();
+    let mut pos = 0;
+    for line in content.split_inclusive('\n') {
+        line_starts.push(pos);
+        pos += line.len();
+    }
+
+    let mut unused_warnings = Vec::new();
+    for comment in inline_config.disable_comments() {
+        let in_scope = |warning: &&LintWarning| {
+            let end = warning.end_line.max(warning.line);
+            warning.line <= *comment.scope.end() && *comment.scope.start() <= end
+        };
+        let unused: Vec<String> = if comment.rules.is_empty() {
+            if warnings.iter().any(|w| in_scope(&w)) {
+                continue;
+            }
+            Vec::new()
+        } else {
+            let used: HashSet<&str> = warnings.iter().filter(in_scope).map(warning_rule).collect();
+            let unused: Vec<String> = comment
+                .rules
+                .iter()
+                .filter(|rule| {
+                    let name = normalize_rule_name(rule);
+                    checked_rules.contains(&name) && !used.contains(name.as_str())
+                })
+                .cloned()
+                .collect();
+            if unused.is_empty() {
+                continue;
+            }
+            unused
+        };
+
+        let Some(&line_start) = line_starts.get(comment.line - 1) else {
+            continue;
+        };
+        let line = content[line_start..].lines().next().unwrap_or("");
+        let text = &line[comment.span.clone()];
+        let removes_comment = unused.len() == comment.rules.len();
+        let (message, fix) = if removes_comment {
+            let message = if unused.is_empty() {
+                "Unused disable comment: no warnings to suppress".to_string()
+            } else {
+                let names: Vec<String> = unused.iter().map(|rule| normalize_rule_name(rule)).collect();
+                format!("Unused disable comment: no {} warnings to suppress", names.join(", "))
+            };
+            let mut fix = comment_removal(content, line_start, line, comment.span.clone());
+            // Drop the enable comment closing the block along with it
+            if let Some((enable_line, enable_span)) = &comment.closed_by
+                && let Some(&enable_start) = line_starts.get(enable_line - 1)
+            {
+                let enable_text = content[enable_start..].lines().next().unwrap_or("");
+                let enable_fix = comment_removal(content, enable_start, enable_text, enable_span.clone());
+                fix = Fix::with_additional_edits(fix.range, fix.replacement, vec![enable_fix]);
+            }
+            (message, fix)
+        } else {
+            let names: Vec<String> = unused.iter().map(|rule| normalize_rule_name(rule)).collect();
+            let head = text[5..].split_whitespace().next().unwrap_or("");
+            let kept: Vec<&str> = comment
+                .rules
+                .iter()
+                .filter(|rule| !unused.contains(rule))
+                .map(String::as_str)
+                .collect();
+            let reason = comment.reason.as_ref().map(|r| format!(" -- {r}")).unwrap_or_default();
+            let replacement = format!("<!-- {head} {}{reason} -->", kept.join(" "));
+            let range = line_start + comment.span.start..line_start + comment.span.end;
+            (
+                format!(
+                    "Unused rules in disable comment: no {} warnings to suppress",
+                    names.join(", ")
+                ),
+                Fix::new(range, replacement),
+            )
+        };
+
+        unused_warnings.push(LintWarning {
+            message,
+            line: comment.line,
+            column: line[..comment.span.start].chars().count() + 1,
+            end_line: comment.line,
+            end_column: line[..comment.span.end].chars().count() + 1,
+            severity: Severity::Warning,
+            fix: Some(fix),
+            rule_name: Some(UNUSED_DISABLE_RULE.to_string()),
+        });
+    }
+    unused_warnings
+}
+
+/// Fix removing the comment at `span` of `line`: the whole line when the
+/// comment stands alone, otherwise the comment and one side's whitespace
+fn comment_removal(content: &str, line_start: usize, line: &str, span: Range<usize>) -> Fix {
+    if line.trim() == &line[span.clone()] {
+        let line_end = line_start + line.len();
+        let range = if line_end < content.len() {
+            line_start..line_end + 1
+        } else {
+            // Last line without a newline: take the preceding one instead
+            line_start.saturating_sub(1)..line_end
+        };
+        return Fix::new(range, String::new());
+    }
+    let before = line[..span.start].trim_end_matches([' ', '\t']).len();
+    let (start, end) = if before < span.start {
+        (before, span.end)
+    } else {
+        (
+            span.start,
+            span.end + (line[span.end..].len() - line[span.end..].trim_start_matches([' ', '\t']).len()),
+        )
+    };
+    Fix::new(line_start + start..line_start + end, String::new())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1101,6 +1475,45 @@ Some content after restore
         assert_eq!(warnings[0].comment_type, "configure-file");
     }
 
+    #[test]
+    fn test_configure_payload_warnings() {
+        let content = r#"<!-- rumdl-configure-file { "MD013": { "line_lenght": 100, "code_blocks": "no", "tables": false } } -->
+<!-- markdownlint-configure-file { "MD013": { broken } -->
+<!-- rumdl-configure-file { "MD007": 4, "not-a-rule": { "x": 1 } } -->
+<!-- rumdl-configure-file { "line-length": { "line-length": 100 } } -->
+
+```markdown
+<!-- rumdl-configure-file { "MD013": { "nope": 1 } } -->
+```
+"#;
+        let inline_config = InlineConfig::from_content(content);
+        let warnings = configure_payload_warnings(content, &inline_config);
+        let report: Vec<(usize, &str)> = warnings.iter().map(|w| (w.line, w.message.as_str())).collect();
+        assert_eq!(
+            report,
+            vec![
+                (
+                    1,
+                    "Invalid configure-file option: Unknown option for rule MD013: line-lenght (did you mean: line-length?)"
+                ),
+                (
+                    1,
+                    "Invalid configure-file option: Type mismatch for MD013.code-blocks: expected boolean, got string"
+                ),
+                (2, "Ignored configure-file comment: payload is not valid JSON"),
+                (
+                    3,
+                    "Ignored configure-file config for MD007: expected a JSON object of options"
+                ),
+            ]
+        );
+        assert!(
+            warnings
+                .iter()
+                .all(|w| w.rule_name.as_deref() == Some(INLINE_CONFIG_RULE) && w.column == 1)
+        );
+    }
+
     #[test]
     fn test_get_rule_config_from_configure_file() {
         let content = r#"<!-- markdownlint-configure-file {"MD013": {"line_length": 50}} -->
@@ -1393,4 +1806,103 @@ Line 9\n";
         assert!(line_disabled[&2].contains("MD001"));
         assert!(!line_disabled.contains_key(&3), "Line 3 should not be affected");
     }
+
+    #[test]
+    fn test_parse_inline_directives_reason() {
+        let line = "Text <!-- rumdl-disable-next-line MD013 line-length -- generated table -->";
+        let directive = &parse_inline_directives(line)[0];
+        assert_eq!(directive.rules, vec!["MD013", "line-length"]);
+        assert_eq!(directive.reason, Some("generated table"));
+        assert_eq!(&line[directive.span.clone()], &line[5..]);
+
+        let directive = &parse_inline_directives("<!-- rumdl-disable -- legacy page -->")[0];
+        assert!(directive.rules.is_empty());
+        assert_eq!(directive.reason, Some("legacy page"));
+
+        // `--` only separates a reason when it stands alone
+        let directive = &parse_inline_directives("<!-- rumdl-disable MD001--x -->")[0];
+        assert_eq!(directive.rules, vec!["MD001--x"]);
+        assert_eq!(directive.reason, None);
+
+        let warnings = validate_inline_config_rules(
+            "<!-- rumdl-disable-line MD013 -- long URL -->
+",
+        );
+        assert!(warnings.is_empty(), "reason words are not rule names: {warnings:?}");
+    }
+
+    #[test]
+    fn test_disable_comment_scopes() {
+        let content = "<!-- rumdl-disable MD013 -->\nA\n<!-- rumdl-enable MD013 -->\n<!-- rumdl-disable-next-line -->\nB\nC <!-- rumdl-disable-line MD009 -->\n<!-- rumdl-disable MD033 -->\nD\n";
+        let config = InlineConfig::from_content(content);
+        let scopes: Vec<_> = config
+            .disable_comments()
+            .iter()
+            .map(|c| {
+                (
+                    c.line,
+                    c.kind,
+                    c.scope.clone(),
+                    c.closed_by.as_ref().map(|(line, _)| *line),
+                )
+            })
+            .collect();
+        assert_eq!(
+            scopes,
+            vec![
+                (1, DirectiveKind::Disable, 1..=3, Some(3)),
+                (4, DirectiveKind::DisableNextLine, 5..=5, None),
+                (6, DirectiveKind::DisableLine, 6..=6, None),
+                (7, DirectiveKind::Disable, 7..=8, None),
+            ]
+        );
+    }
+
+    fn warning(rule: &str, line: usize) -> LintWarning {
+        LintWarning {
+            message: String::new(),
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 1,
+            severity: Severity::Warning,
+            fix: None,
+            rule_name: Some(rule.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_unused_disable_warnings() {
+        let content = "<!-- rumdl-disable-next-line MD013 -->\nlong\n<!-- rumdl-disable-next-line MD009 MD013 -- why -->\nlong\nText <!-- rumdl-disable-line MD051 --> end\n<!-- rumdl-disable-next-line -->\nquiet\n";
+        let config = InlineConfig::from_content(content);
+        let checked: HashSet<String> = ["MD009", "MD013"].map(String::from).into();
+        let unused = unused_disable_warnings(content, &config, &[warning("MD013", 4)], &checked);
+
+        // MD051 did not run, so its comment is left alone
+        assert_eq!(unused.iter().map(|w| w.line).collect::<Vec<_>>(), vec![1, 3, 6]);
+        assert!(
+            unused
+                .iter()
+                .all(|w| w.rule_name.as_deref() == Some(UNUSED_DISABLE_RULE))
+        );
+
+        let fixed = crate::utils::fix_utils::apply_warning_fixes(content, &unused).unwrap();
+        assert_eq!(
+            fixed,
+            "long\n<!-- rumdl-disable-next-line MD013 -- why -->\nlong\nText <!-- rumdl-disable-line MD051 --> end\nquiet\n"
+        );
+    }
+
+    #[test]
+    fn test_unused_disable_removes_inline_comment_and_enable() {
+        let content = "Text <!-- rumdl-disable-line MD013 --> end\n<!-- rumdl-disable MD009 -->\nA\n<!-- rumdl-enable MD009 -->\nB\n";
+        let config = InlineConfig::from_content(content);
+        let checked: HashSet<String> = ["MD009", "MD013"].map(String::from).into();
+        let unused = unused_disable_warnings(content, &config, &[], &checked);
+        assert_eq!(unused.len(), 2);
+        assert_eq!((unused[0].column, unused[0].end_column), (6, 39));
+
+        let fixed = crate::utils::fix_utils::apply_warning_fixes(content, &unused).unwrap();
+        assert_eq!(fixed, "Text end\nA\nB\n");
+    }
 }
@@ -0,0 +1,289 @@
+//! Interactive review of fixes (`rumdl check --fix --interactive`).
+//!
+//! The fixes of a file are split into hunks, the separate places where the
+//! fixed file differs from the original. Each hunk is shown as a diff, labeled
+//! with the rules whose warnings it touches, and applied only if accepted.
+
+use colored::*;
+use rumdl_lib::rule::LintWarning;
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+use std::ops::Range;
+
+const HELP: &str = "\
+y - apply this fix
+n - skip this fix
+a - apply this fix and every later fix of the same rules
+d - skip this fix and every later fix of the same rules
+q - quit; skip this fix and every remaining one
+? - print help
+";
+
+/// Answers given so far, shared by every file of a run
+pub struct InteractiveSession {
+    input: Box<dyn BufRead + Send>,
+    output: Box<dyn Write + Send>,
+    /// Rules whose fixes are applied without asking
+    accepted_rules: BTreeSet<String>,
+    /// Rules whose fixes are skipped without asking
+    rejected_rules: BTreeSet<String>,
+    /// Whether the user quit; every remaining fix is skipped
+    quit: bool,
+}
+
+/// One place where the fixed file differs from the original
+struct Hunk {
+    /// Bytes of the original that are replaced
+    range: Range<usize>,
+    replacement: String,
+    /// Rules whose warnings lie on the lines of the hunk
+    rules: BTreeSet<String>,
+}
+
+impl InteractiveSession {
+    /// A session that prompts on stdout and reads answers from stdin
+    pub fn stdio() -> Self {
+        Self::new(
+            Box::new(std::io::BufReader::new(std::io::stdin())),
+            Box::new(std::io::stdout()),
+        )
+    }
+
+    pub fn new(input: Box<dyn BufRead + Send>, output: Box<dyn Write + Send>) -> Self {
+        Self {
+            input,
+            output,
+            accepted_rules: BTreeSet::new(),
+            rejected_rules: BTreeSet::new(),
+            quit: false,
+        }
+    }
+
+    /// Ask about each hunk between `original` and `fixed`, returning the
+    /// original with only the accepted hunks applied
+    pub fn review(&mut self, display_path: &str, original: &str, fixed: &str, warnings: &[LintWarning]) -> String {
+        let hunks = hunks(original, fixed, warnings);
+        let accepted: Vec<bool> = hunks
+            .iter()
+            .map(|hunk| self.decide(display_path, original, hunk))
+            .collect();
+
+        let mut result = String::with_capacity(fixed.len());
+        let mut last = 0;
+        for (hunk, _) in hunks.iter().zip(&accepted).filter(|(_, apply)| **apply) {
+            result.push_str(&original[last..hunk.range.start]);
+            result.push_str(&hunk.replacement);
+            last = hunk.range.end;
+        }
+        result.push_str(&original[last..]);
+        result
+    }
+
+    /// Whether to apply `hunk`, asking unless an earlier answer decides it
+    fn decide(&mut self, display_path: &str, original: &str, hunk: &Hunk) -> bool {
+        if self.quit {
+            return false;
+        }
+        if !hunk.rules.is_empty() {
+            if hunk.rules.is_subset(&self.accepted_rules) {
+                return true;
+            }
+            if hunk.rules.is_subset(&self.rejected_rules) {
+                return false;
+            }
+        }
+
+        // Failed writes are ignored; closed input ends the review like `q`
+        let _ = self.output.write_all(render(display_path, original, hunk).as_bytes());
+        loop {
+            let _ = write!(self.output, "{}", "Apply this fix [y,n,a,d,q,?]? ".bold());
+            let _ = self.output.flush();
+            let mut answer = String::new();
+            if self.input.read_line(&mut answer).unwrap_or(0) == 0 {
+                let _ = writeln!(self.output);
+                self.quit = true;
+                return false;
+            }
+            match answer.trim() {
+                "y" => return true,
+                "n" => return false,
+                "a" => {
+                    self.accepted_rules.extend(hunk.rules.iter().cloned());
+                    return true;
+                }
+                "d" => {
+                    self.rejected_rules.extend(hunk.rules.iter().cloned());
+                    return false;
+                }
+                "q" => {
+                    self.quit = true;
+                    return false;
+                }
+                _ => {
+                    let _ = write!(self.output, "{HELP}");
+                }
+            }
+        }
+    }
+}
+
+/// Split the changes from `original` to `fixed` into hunks and label each with
+/// the rules of the warnings on its lines
+fn hunks(original: &str, fixed: &str, warnings: &[LintWarning]) -> Vec<Hunk> {
+    rumdl_lib::utils::fix_utils::plan_edits(original, fixed)
+        .into_iter()
+        .map(|(range, replacement)| {
+            let lines = line_span(original, &range);
+            let rules = warnings
+                .iter()
+                .filter(|w| w.line <= *lines.end() && w.end_line.max(w.line) >= *lines.start())
+                .filter_map(|w| w.rule_name.clone())
+                .collect();
+            Hunk {
+                range,
+                replacement,
+                rules,
+            }
+        })
+        .collect()
+}
+
+/// 1-indexed lines of `text` that `range` touches
+fn line_span(text: &str, range: &Range<usize>) -> std::ops::RangeInclusive<usize> {
+    let line_of = |offset: usize| text[..offset].matches('\n').count() + 1;
+    let end = if range.end > range.start && text[..range.end].ends_with('\n') {
+        range.end - 1
+    } else {
+        range.end
+    };
+    line_of(range.start)..=line_of(end)
+}
+
+/// The lines a hunk touches, before and after, as a colored diff
+fn render(display_path: &str, original: &str, hunk: &Hunk) -> String {
+    let start = original[..hunk.range.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = if hunk.range.end > hunk.range.start && original[..hunk.range.end].ends_with('\n') {
+        hunk.range.end
+    } else {
+        original[hunk.range.end..]
+            .find('\n')
+            .map_or(original.len(), |i| hunk.range.end + i + 1)
+    };
+    let before = &original[start..end];
+    let after = format!(
+        "{}{}{}",
+        &original[start..hunk.range.start],
+        hunk.replacement,
+        &original[hunk.range.end..end]
+    );
+
+    let line = *line_span(original, &hunk.range).start();
+    let rules = if hunk.rules.is_empty() {
+        "formatting".to_string()
+    } else {
+        hunk.rules.iter().cloned().collect::<Vec<_>>().join(", ")
+    };
+    let mut out = format!(
+        "\n{} {}\n",
+        format!("{display_path}:{line}").blue().underline(),
+        format!("[{rules}]").yellow()
+    );
+    for line in before.lines() {
+        out.push_str(&format!("{}\n", format!("-{line}").red()));
+    }
+    for line in after.lines() {
+        out.push_str(&format!("{}\n", format!("+{line}").green()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::sync::{Arc, Mutex};
+
+    /// Output sink the test can read after the session is done with it
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn start(answers: &str) -> (InteractiveSession, Shared) {
+        let output = Shared::default();
+        let session = InteractiveSession::new(Box::new(Cursor::new(answers.to_string())), Box::new(output.clone()));
+        (session, output)
+    }
+
+    fn warning(rule: &str, line: usize) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 1,
+            message: String::new(),
+            rule_name: Some(rule.to_string()),
+            severity: rumdl_lib::rule::Severity::Warning,
+            fix: None,
+        }
+    }
+
+    const ORIGINAL: &str = "# Title\n\none  \ntwo  \n\nthree  \n";
+    const FIXED: &str = "# Title\n\none\ntwo\n\nthree\n";
+
+    #[test]
+    fn only_accepted_hunks_are_applied() {
+        let warnings = [warning("MD009", 3), warning("MD009", 4), warning("MD009", 6)];
+        let (mut session, output) = start("n\ny\n");
+        let reviewed = session.review("doc.md", ORIGINAL, FIXED, &warnings);
+        assert_eq!(reviewed, "# Title\n\none  \ntwo  \n\nthree\n");
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("doc.md:3 [MD009]\n-one  \n-two  \n+one\n+two\n"),
+            "got:\n{output}"
+        );
+        assert!(
+            output.contains("doc.md:6 [MD009]\n-three  \n+three\n"),
+            "got:\n{output}"
+        );
+    }
+
+    #[test]
+    fn rule_answers_apply_to_later_hunks_and_files() {
+        let warnings = [warning("MD009", 3), warning("MD009", 6)];
+        let (mut session, _) = start("a\n");
+        assert_eq!(session.review("a.md", ORIGINAL, FIXED, &warnings), FIXED);
+        assert_eq!(session.review("b.md", ORIGINAL, FIXED, &warnings), FIXED);
+
+        let (mut session, _) = start("d\n");
+        assert_eq!(session.review("a.md", ORIGINAL, FIXED, &warnings), ORIGINAL);
+    }
+
+    #[test]
+    fn quitting_or_running_out_of_input_skips_the_rest() {
+        let warnings = [warning("MD009", 3), warning("MD009", 6)];
+        let (mut session, _) = start("q\n");
+        assert_eq!(session.review("a.md", ORIGINAL, FIXED, &warnings), ORIGINAL);
+        assert_eq!(session.review("b.md", ORIGINAL, FIXED, &warnings), ORIGINAL);
+
+        let (mut session, output) = start("x\ny\n");
+        assert_eq!(
+            session.review("a.md", ORIGINAL, FIXED, &warnings),
+            "# Title\n\none\ntwo\n\nthree  \n"
+        );
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("a - apply this fix and every later fix"),
+            "got:\n{output}"
+        );
+    }
+}
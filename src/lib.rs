@@ -47,6 +47,8 @@
 // for symmetry with non-empty string literals.
 #![allow(clippy::manual_string_new)]
 
+pub mod anchor_manifest;
+pub mod block_cache;
 pub mod code_block_tools;
 pub mod config;
 pub mod discovery;
@@ -55,12 +57,19 @@ pub mod embedded_lint;
 pub mod exit_codes;
 pub mod filtered_lines;
 pub mod fix_coordinator;
+pub mod front_matter_config;
 pub mod inline_config;
 pub mod linguist_data;
+pub mod link_rewrite;
 pub mod lint_context;
 pub mod markdownlint_config;
 pub mod profiling;
+pub mod prose_styles;
+pub mod registry;
 pub mod rule;
+#[cfg(not(target_arch = "wasm32"))]
+mod rule_watchdog;
+pub mod streaming;
 #[cfg(feature = "native")]
 pub mod vscode;
 pub mod workspace_index;
@@ -90,14 +99,14 @@ pub use rules::heading_utils::HeadingStyle;
 pub use rules::*;
 
 pub use crate::lint_context::{LineInfo, LintContext, ListItemInfo};
-use crate::rule::{LintResult, Rule, RuleCategory};
+use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory};
 use crate::utils::calculate_indentation_width_default;
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
 
 /// Content characteristics for efficient rule filtering
-#[derive(Debug, Default)]
-struct ContentCharacteristics {
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ContentCharacteristics {
     has_headings: bool,    // # or setext headings
     has_lists: bool,       // *, -, +, 1. etc
     has_links: bool,       // [text](url) or [text][ref]
@@ -109,96 +118,174 @@ struct ContentCharacteristics {
     has_images: bool,      // ![alt](url)
 }
 
-/// Check if a line has enough leading whitespace to be an indented code block.
-/// Indented code blocks require 4+ columns of leading whitespace (with proper tab expansion).
-fn has_potential_indented_code_indent(line: &str) -> bool {
-    calculate_indentation_width_default(line) >= 4
+/// The bytes that can still set a flag of a [`ContentCharacteristics`]
+struct Needles {
+    bytes: Vec<u8>,
+    /// Membership of `bytes`, for when there are too many for memchr
+    table: [bool; 256],
+}
+
+impl Needles {
+    fn new(bytes: Vec<u8>) -> Self {
+        let mut table = [false; 256];
+        for &b in &bytes {
+            table[usize::from(b)] = true;
+        }
+        Self { bytes, table }
+    }
+
+    /// Offset of the first needle in `haystack`
+    fn find(&self, haystack: &[u8]) -> Option<usize> {
+        match self.bytes[..] {
+            [] => None,
+            [a] => memchr::memchr(a, haystack),
+            [a, b] => memchr::memchr2(a, b, haystack),
+            [a, b, c] => memchr::memchr3(a, b, c, haystack),
+            _ => haystack.iter().position(|&b| self.table[usize::from(b)]),
+        }
+    }
 }
 
 impl ContentCharacteristics {
-    fn analyze(content: &str) -> Self {
-        let mut chars = Self { ..Default::default() };
-
-        // Quick single-pass analysis
-        let mut has_atx_heading = false;
-        let mut has_setext_heading = false;
-
-        for line in content.lines() {
-            let trimmed = line.trim();
-
-            // Headings: ATX (#) or Setext (underlines). A blockquoted ATX
-            // heading (`> ## Title`) still emits a fragment anchor, so rules
-            // like MD051/MD080 must run for blockquote-only documents too.
-            // Stripping `>`/space/tab is a coarse, deliberately
-            // over-inclusive prefilter check (it must never skip a rule that
-            // has work; `parse_blockquote_prefix` also accepts a tab marker).
-            if !has_atx_heading
-                && (trimmed.starts_with('#') || trimmed.trim_start_matches(['>', ' ', '\t']).starts_with('#'))
-            {
-                has_atx_heading = true;
-            }
-            if !has_setext_heading && (trimmed.chars().all(|c| c == '=' || c == '-') && trimmed.len() > 1) {
-                has_setext_heading = true;
+    /// Scan `content` once, jumping from one byte that can still set a flag
+    /// to the next, and stop as soon as every flag is set
+    pub(crate) fn analyze(content: &str) -> Self {
+        let bytes = content.as_bytes();
+        let mut chars = Self::default();
+        chars.visit_line_start(bytes, 0);
+
+        let mut needles = chars.needles();
+        let mut pos = 0;
+        while let Some(offset) = needles.find(&bytes[pos..]) {
+            let i = pos + offset;
+            let before = chars;
+            chars.visit(bytes, i);
+            if chars != before {
+                needles = chars.needles();
             }
+            pos = i + 1;
+        }
+        chars
+    }
 
-            // Quick character-based detection (more efficient than regex)
-            // Include patterns without spaces to enable user-intention detection (MD030)
-            if !chars.has_lists
-                && (line.contains("* ")
-                    || line.contains("- ")
-                    || line.contains("+ ")
-                    || trimmed.starts_with("* ")
-                    || trimmed.starts_with("- ")
-                    || trimmed.starts_with("+ ")
-                    || trimmed.starts_with('*')
-                    || trimmed.starts_with('-')
-                    || trimmed.starts_with('+'))
-            {
-                chars.has_lists = true;
-            }
-            // Ordered lists: line starts with digit, or blockquote line contains digit followed by period
-            if !chars.has_lists
-                && ((line.chars().next().is_some_and(|c| c.is_ascii_digit())
-                    && (line.contains(". ") || line.contains('.')))
-                    || (trimmed.starts_with('>')
-                        && trimmed.chars().any(|c| c.is_ascii_digit())
-                        && (trimmed.contains(". ") || trimmed.contains('.'))))
-            {
-                chars.has_lists = true;
-            }
-            if !chars.has_links
-                && (line.contains('[')
-                    || line.contains("http://")
-                    || line.contains("https://")
-                    || line.contains("ftp://")
-                    || line.contains("www."))
-            {
-                chars.has_links = true;
-            }
-            if !chars.has_images && line.contains("![") {
-                chars.has_images = true;
-            }
-            if !chars.has_code
-                && (line.contains('`') || line.contains("~~~") || has_potential_indented_code_indent(line))
-            {
-                chars.has_code = true;
-            }
-            if !chars.has_emphasis && (line.contains('*') || line.contains('_')) {
-                chars.has_emphasis = true;
+    /// Bytes at which an unset flag may be found; empty once all are set
+    fn needles(&self) -> Needles {
+        let mut bytes = Vec::new();
+        if !self.has_headings || !self.has_lists || !self.has_code || !self.has_blockquotes {
+            bytes.push(b'\n');
+        }
+        if !self.has_links {
+            // `[`, and the `:` of `http://`, `https://`, `ftp://` and the `.` of `www.`
+            bytes.extend_from_slice(b"[:.");
+        }
+        if !self.has_images {
+            bytes.push(b'!');
+        }
+        if !self.has_code {
+            bytes.extend_from_slice(b"`~");
+        }
+        if !self.has_emphasis {
+            bytes.extend_from_slice(b"*_");
+        }
+        if !self.has_lists {
+            // Include markers without content to enable user-intention detection (MD030)
+            bytes.extend_from_slice(b"*-+");
+        }
+        if !self.has_html {
+            bytes.push(b'<');
+        }
+        if !self.has_tables {
+            bytes.push(b'|');
+        }
+        bytes.sort_unstable();
+        bytes.dedup();
+        Needles::new(bytes)
+    }
+
+    /// Set the flags the needle at `bytes[i]` shows
+    fn visit(&mut self, bytes: &[u8], i: usize) {
+        let next = bytes.get(i + 1).copied();
+        match bytes[i] {
+            b'\n' => self.visit_line_start(bytes, i + 1),
+            b'[' => self.has_links = true,
+            b'!' => self.has_images |= next == Some(b'['),
+            b':' => {
+                self.has_links |= bytes[i + 1..].starts_with(b"//")
+                    && [b"http".as_slice(), b"https", b"ftp"]
+                        .iter()
+                        .any(|scheme| bytes[..i].ends_with(scheme));
             }
-            if !chars.has_html && line.contains('<') {
-                chars.has_html = true;
+            b'.' => self.has_links |= bytes[..i].ends_with(b"www"),
+            b'`' => self.has_code = true,
+            b'~' => self.has_code |= bytes[i..].starts_with(b"~~~"),
+            b'*' => {
+                self.has_emphasis = true;
+                self.has_lists |= next == Some(b' ');
             }
-            if !chars.has_tables && line.contains('|') {
-                chars.has_tables = true;
+            b'_' => self.has_emphasis = true,
+            b'-' | b'+' => self.has_lists |= next == Some(b' '),
+            b'<' => self.has_html = true,
+            b'|' => self.has_tables = true,
+            _ => {}
+        }
+    }
+
+    /// Set the flags that depend on how the line at `start` begins
+    ///
+    /// The checks are coarse and deliberately over-inclusive: a prefilter
+    /// must never skip a rule that has work. The rest of the line is only
+    /// looked at when its first bytes can decide a flag that is still unset.
+    fn visit_line_start(&mut self, bytes: &[u8], start: usize) {
+        let rest = &bytes[start..];
+        if rest.first() == Some(&b'>') {
+            self.has_blockquotes = true;
+        }
+        if self.has_headings && self.has_lists && self.has_code {
+            return;
+        }
+
+        let indent = rest.iter().take_while(|&&b| b == b' ' || b == b'\t').count();
+        if !self.has_code && indent > 0 {
+            // Indented code blocks need 4+ columns, with tabs expanded
+            let prefix = std::str::from_utf8(&rest[..indent]).unwrap_or_default();
+            self.has_code = calculate_indentation_width_default(prefix) >= 4;
+        }
+
+        let lead = rest
+            .iter()
+            .take_while(|&&b| b != b'\n' && b.is_ascii_whitespace())
+            .count();
+        let Some(&first) = rest.get(lead) else {
+            return;
+        };
+        let line = || &rest[..memchr::memchr(b'\n', rest).unwrap_or(rest.len())];
+        match first {
+            b'#' => self.has_headings = true,
+            b'*' | b'+' => self.has_lists = true,
+            b'-' | b'=' => {
+                self.has_lists |= first == b'-';
+                // Setext underline
+                if !self.has_headings {
+                    let trimmed = line().trim_ascii();
+                    self.has_headings = trimmed.len() > 1 && trimmed.iter().all(|&b| b == b'=' || b == b'-');
+                }
             }
-            if !chars.has_blockquotes && line.starts_with('>') {
-                chars.has_blockquotes = true;
+            b'>' => {
+                let line = line();
+                // A blockquoted ATX heading (`> ## Title`) still emits a
+                // fragment anchor, so rules like MD051/MD080 must run for
+                // blockquote-only documents too
+                let quoted = line[lead..]
+                    .iter()
+                    .position(|b| !matches!(b, b'>' | b' ' | b'\t'))
+                    .map(|offset| line[lead + offset]);
+                self.has_headings |= quoted == Some(b'#');
+                // An ordered list in a blockquote
+                self.has_lists |= line.iter().any(u8::is_ascii_digit) && line.contains(&b'.');
             }
+            b'0'..=b'9' if lead == 0 => self.has_lists |= line().contains(&b'.'),
+            _ => {}
         }
-
-        chars.has_headings = has_atx_heading || has_setext_heading;
-        chars
     }
 
     /// Check if a rule should be skipped based on content characteristics
@@ -219,6 +306,30 @@ impl ContentCharacteristics {
     }
 }
 
+/// Files at least this large run their rule checks concurrently
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+const PARALLEL_RULES_MIN_SIZE: usize = 1 << 20;
+
+/// Check a file with each rule on its own thread, returning the results in rule
+/// order along with how long each check took
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn check_in_parallel(
+    rules: &[(&dyn Rule, &dyn Rule)],
+    ctx: &LintContext,
+) -> std::vec::IntoIter<(LintResult, std::time::Duration)> {
+    use rayon::prelude::*;
+
+    let results: Vec<_> = rules
+        .par_iter()
+        .map(|(_, effective_rule)| {
+            let start = Instant::now();
+            let result = effective_rule.check(ctx);
+            (result, start.elapsed())
+        })
+        .collect();
+    results.into_iter()
+}
+
 /// Compute content hash for incremental indexing change detection
 ///
 /// Uses blake3 for native builds (fast, cryptographic-strength hash)
@@ -257,6 +368,45 @@ pub fn lint(
     result
 }
 
+/// Report inline disable comments that suppress nothing
+///
+/// Runs `rules` over `content` with inline suppression turned off and matches
+/// the warnings against each disable comment; see
+/// [`inline_config::unused_disable_warnings`]. Cross-file rules only warn
+/// across the workspace, so comments naming them are never reported.
+pub fn lint_unused_disables(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    flavor: crate::config::MarkdownFlavor,
+    source_file: Option<std::path::PathBuf>,
+) -> LintResult {
+    if content.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut lint_ctx = crate::lint_context::LintContext::new(content, flavor, source_file);
+    let inline_config = lint_ctx.take_inline_config();
+    if inline_config.disable_comments().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut warnings = Vec::new();
+    let mut checked_rules = std::collections::HashSet::new();
+    for rule in rules {
+        if rule.cross_file_scope() != crate::rule::CrossFileScope::Workspace {
+            checked_rules.insert(rule.name().to_string());
+        }
+        if !rule.should_skip(&lint_ctx) {
+            warnings.extend(rule.check(&lint_ctx)?);
+        }
+    }
+    Ok(inline_config::unused_disable_warnings(
+        content,
+        &inline_config,
+        &warnings,
+        &checked_rules,
+    ))
+}
+
 /// Build FileIndex only (no linting) for cross-file analysis on cache hits
 ///
 /// This is a lightweight function that only builds the FileIndex without running
@@ -300,11 +450,92 @@ pub fn build_file_index_only(
                 rule.contribute_to_index(&lint_ctx, &mut file_index);
             }
         }
+        index_snippet_includes(&lint_ctx, rules, &mut file_index);
+        index_asset_references(&lint_ctx, rules, &mut file_index);
+        index_reference_definitions(&lint_ctx, rules, &mut file_index);
+        index_page_title(&lint_ctx, rules, &mut file_index);
     });
 
     file_index
 }
 
+/// Record the files a MkDocs page includes through snippet markers (`--8<--`)
+///
+/// Included content is part of the rendered page, so cross-file rules look
+/// through these includes. Only done when a cross-file rule is enabled, since
+/// resolving includes touches the filesystem.
+fn index_snippet_includes(
+    lint_ctx: &crate::lint_context::LintContext,
+    rules: &[Box<dyn Rule>],
+    file_index: &mut crate::workspace_index::FileIndex,
+) {
+    if lint_ctx.flavor != crate::config::MarkdownFlavor::MkDocs || !lint_ctx.content.contains("8<") {
+        return;
+    }
+    let Some(source_file) = lint_ctx.source_file.as_deref() else {
+        return;
+    };
+    if rules
+        .iter()
+        .any(|rule| rule.cross_file_scope() == crate::rule::CrossFileScope::Workspace)
+    {
+        file_index.snippet_includes =
+            crate::utils::mkdocs_snippets::resolve_snippet_includes(lint_ctx.content, source_file);
+    }
+}
+
+/// Record the local assets a file references
+///
+/// Extracted whenever any cross-file rule is enabled, not only when MD086 is,
+/// so a cached index reused by a later run with a different rule set still
+/// knows every reference and never reports a used asset as unreferenced.
+fn index_asset_references(
+    lint_ctx: &crate::lint_context::LintContext,
+    rules: &[Box<dyn Rule>],
+    file_index: &mut crate::workspace_index::FileIndex,
+) {
+    if rules
+        .iter()
+        .any(|rule| rule.cross_file_scope() == crate::rule::CrossFileScope::Workspace)
+    {
+        file_index.asset_references = crate::workspace_index::extract_asset_references(lint_ctx);
+    }
+}
+
+/// Record the link reference definitions a file declares
+///
+/// Like asset references, extracted whenever any cross-file rule is enabled
+/// so a cached index reused by a run that enables MD091 still has them.
+fn index_reference_definitions(
+    lint_ctx: &crate::lint_context::LintContext,
+    rules: &[Box<dyn Rule>],
+    file_index: &mut crate::workspace_index::FileIndex,
+) {
+    if rules
+        .iter()
+        .any(|rule| rule.cross_file_scope() == crate::rule::CrossFileScope::Workspace)
+    {
+        file_index.reference_definitions = crate::workspace_index::extract_reference_definitions(lint_ctx);
+    }
+}
+
+/// Record the page title (first H1) of a file
+///
+/// Like reference definitions, extracted whenever any cross-file rule is
+/// enabled so a cached index reused by a run that enables MD096 still has it.
+fn index_page_title(
+    lint_ctx: &crate::lint_context::LintContext,
+    rules: &[Box<dyn Rule>],
+    file_index: &mut crate::workspace_index::FileIndex,
+) {
+    if rules
+        .iter()
+        .any(|rule| rule.cross_file_scope() == crate::rule::CrossFileScope::Workspace)
+    {
+        file_index.page_title = crate::workspace_index::extract_page_title(lint_ctx);
+    }
+}
+
 /// Lint a file and contribute to workspace index for cross-file analysis
 ///
 /// This variant performs linting and optionally populates a `FileIndex` with data
@@ -312,7 +543,6 @@ pub fn build_file_index_only(
 /// avoiding duplicate parsing.
 ///
 /// Returns: (warnings, FileIndex) - the FileIndex contains headings/links for cross-file rules
-#[cfg_attr(test, allow(unused_variables))]
 pub fn lint_and_index(
     content: &str,
     rules: &[Box<dyn Rule>],
@@ -320,6 +550,37 @@ pub fn lint_and_index(
     flavor: crate::config::MarkdownFlavor,
     source_file: Option<std::path::PathBuf>,
     config: Option<&crate::config::Config>,
+) -> (LintResult, crate::workspace_index::FileIndex) {
+    lint_and_index_with_cache(content, rules, verbose, flavor, source_file, config, None)
+}
+
+/// Lint a file that was linted before with the same `cache`
+///
+/// Block-local rules only check the blocks that changed since then; see
+/// [`block_cache`]. Meant for editors, which lint after every change.
+pub fn lint_with_block_cache(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    flavor: crate::config::MarkdownFlavor,
+    source_file: Option<std::path::PathBuf>,
+    config: Option<&crate::config::Config>,
+    cache: &mut block_cache::BlockCache,
+) -> LintResult {
+    let (result, _file_index) =
+        lint_and_index_with_cache(content, rules, verbose, flavor, source_file, config, Some(cache));
+    result
+}
+
+#[cfg_attr(test, allow(unused_variables))]
+fn lint_and_index_with_cache(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    flavor: crate::config::MarkdownFlavor,
+    source_file: Option<std::path::PathBuf>,
+    config: Option<&crate::config::Config>,
+    block_cache: Option<&mut block_cache::BlockCache>,
 ) -> (LintResult, crate::workspace_index::FileIndex) {
     let mut warnings = Vec::new();
     // Compute content hash for change detection
@@ -331,6 +592,9 @@ pub fn lint_and_index(
         return (Ok(warnings), file_index);
     }
 
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    let file_timer = profiling::FileTimer::new(source_file.as_deref(), content.len());
+
     // Parse LintContext once (includes inline config parsing)
     let lint_ctx = time_function!(
         "lint: parse lint context",
@@ -388,24 +652,88 @@ pub fn lint_and_index(
         }
     }
 
-    {
-        let _timer = profiling::ScopedTimer::new("lint: run single-file rules");
-        for rule in &applicable_rules {
-            #[cfg(not(target_arch = "wasm32"))]
-            let rule_start = Instant::now();
-
-            // Skip rules that indicate they should be skipped (opt-in rules, content-based skipping)
-            if rule.should_skip(&lint_ctx) {
-                continue;
-            }
-
-            // Use recreated rule if inline config overrides exist for this rule
+    // Skip rules that indicate they should be skipped (opt-in rules, content-based skipping),
+    // and use the recreated rule if inline config overrides exist for a rule
+    let runnable_rules: Vec<(&dyn crate::rule::Rule, &dyn crate::rule::Rule)> = applicable_rules
+        .iter()
+        .filter(|rule| !rule.should_skip(&lint_ctx))
+        .map(|rule| {
             let effective_rule: &dyn crate::rule::Rule = recreated_rules
                 .get(rule.name())
                 .map_or(rule.as_ref(), std::convert::AsRef::as_ref);
+            (rule.as_ref(), effective_rule)
+        })
+        .collect();
+
+    // With a block cache, block-local rules only check the blocks that changed.
+    // Rules with inline config overrides are not cached.
+    let mut cached_results = block_cache.map_or_else(std::collections::HashMap::new, |cache| {
+        let block_local_rules: Vec<&dyn crate::rule::Rule> = runnable_rules
+            .iter()
+            .filter(|(rule, effective_rule)| {
+                effective_rule.is_block_local() && !recreated_rules.contains_key(rule.name())
+            })
+            .map(|&(_, effective_rule)| effective_rule)
+            .collect();
+        time_function!(
+            "lint: check changed blocks",
+            cache.check(&block_local_rules, &lint_ctx, config)
+        )
+    });
+    let checked_rules: Vec<_> = runnable_rules
+        .iter()
+        .filter(|(rule, _)| !cached_results.contains_key(rule.name()))
+        .copied()
+        .collect();
 
+    // With `rule-timeout-ms` set, checks run on a watchdog thread that gives up
+    // on any single rule exceeding the budget
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut watchdog = config.and_then(|c| c.global.rule_timeout_ms).map(|millis| {
+        rule_watchdog::RuleWatchdog::new(
+            checked_rules
+                .iter()
+                .map(|(_, effective_rule)| dyn_clone::clone_box(*effective_rule))
+                .collect(),
+            content,
+            flavor,
+            lint_ctx.source_file.clone(),
+            std::time::Duration::from_millis(millis),
+        )
+    });
+
+    // Checks of a very large file run concurrently; their results are still
+    // handled one rule at a time below, in rule order
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    let mut parallel_results = (watchdog.is_none() && content.len() >= PARALLEL_RULES_MIN_SIZE)
+        .then(|| check_in_parallel(&checked_rules, &lint_ctx));
+    #[cfg(all(not(feature = "parallel"), not(target_arch = "wasm32")))]
+    let mut parallel_results: Option<std::vec::IntoIter<(LintResult, std::time::Duration)>> = None;
+
+    {
+        let _timer = profiling::ScopedTimer::new("lint: run single-file rules");
+        for &(rule, effective_rule) in &runnable_rules {
             // Run single-file check with the effective rule (possibly with inline config applied)
-            let result = effective_rule.check(&lint_ctx);
+            #[cfg(not(target_arch = "wasm32"))]
+            let (result, rule_duration) = if let Some(result) = cached_results.remove(rule.name()) {
+                (result, std::time::Duration::ZERO)
+            } else if let Some(results) = parallel_results.as_mut() {
+                results.next().expect("one result per checked rule")
+            } else {
+                let rule_start = Instant::now();
+                let result = match watchdog.as_mut() {
+                    Some(watchdog) => match watchdog.next_result() {
+                        Some(result) => result,
+                        None => continue,
+                    },
+                    None => effective_rule.check(&lint_ctx),
+                };
+                (result, rule_start.elapsed())
+            };
+            #[cfg(target_arch = "wasm32")]
+            let result = cached_results
+                .remove(rule.name())
+                .unwrap_or_else(|| effective_rule.check(&lint_ctx));
 
             match result {
                 Ok(rule_warnings) => {
@@ -467,10 +795,12 @@ pub fn lint_and_index(
 
             #[cfg(not(target_arch = "wasm32"))]
             {
-                let rule_duration = rule_start.elapsed();
                 if profile_rules {
                     eprintln!("[RULE] {:6} {:?}", rule.name(), rule_duration);
                 }
+                if profiling::PROFILING_ENABLED {
+                    profiling::record_rule(file_timer.file(), rule.name(), rule_duration);
+                }
 
                 #[cfg(not(test))]
                 if verbose && rule_duration.as_millis() > 500 {
@@ -480,6 +810,11 @@ pub fn lint_and_index(
         }
     }
 
+    // Configure-file payloads that would otherwise be dropped without notice
+    if !inline_config.configure_comments().is_empty() {
+        warnings.extend(inline_config::configure_payload_warnings(content, inline_config));
+    }
+
     // Contribute to index for cross-file rules (done after all rules checked)
     // NOTE: We iterate over ALL rules (not just applicable_rules) because cross-file
     // rules need to extract data from every file in the workspace, regardless of whether
@@ -492,6 +827,10 @@ pub fn lint_and_index(
                 rule.contribute_to_index(&lint_ctx, &mut file_index);
             }
         }
+        index_snippet_includes(&lint_ctx, rules, &mut file_index);
+        index_asset_references(&lint_ctx, rules, &mut file_index);
+        index_reference_definitions(&lint_ctx, rules, &mut file_index);
+        index_page_title(&lint_ctx, rules, &mut file_index);
     });
 
     #[cfg(not(test))]
@@ -579,6 +918,100 @@ pub fn run_cross_file_checks(
     Ok(warnings)
 }
 
+/// Run cross-file checks for one document against the files it links to
+///
+/// For content that is linted on its own, such as stdin with `--stdin-filename`,
+/// where indexing the whole workspace would be wasteful. Only the Markdown
+/// files that `file_index` links to are read from disk (relative to
+/// `file_path`) and indexed, which is all that link-target rules such as MD051
+/// need.
+pub fn run_cross_file_checks_for_links(
+    file_path: &std::path::Path,
+    file_index: &crate::workspace_index::FileIndex,
+    rules: &[Box<dyn Rule>],
+    flavor: crate::config::MarkdownFlavor,
+    config: Option<&crate::config::Config>,
+) -> LintResult {
+    use crate::workspace_index::WorkspaceIndex;
+
+    // The document itself comes first, so a link to its own path sees this
+    // content rather than the copy on disk
+    let mut workspace_index = WorkspaceIndex::new();
+    workspace_index.insert_file(WorkspaceIndex::normalize_path(file_path), file_index.clone());
+    let source_dir = file_path.parent().unwrap_or(std::path::Path::new(""));
+    for link in &file_index.cross_file_links {
+        let target = WorkspaceIndex::normalize_path(&source_dir.join(&link.target_path));
+        // Extension-less links resolve like MD051 does: `page#section` -> `page.md`
+        let mut candidates = vec![target.clone()];
+        if target.extension().is_none() {
+            candidates.extend(
+                crate::discovery::MARKDOWN_EXTENSIONS
+                    .iter()
+                    .map(|ext| target.with_extension(ext)),
+            );
+        }
+        for candidate in candidates {
+            if workspace_index.contains_file(&candidate) {
+                break;
+            }
+            if let Ok(content) = std::fs::read_to_string(&candidate) {
+                let target_flavor = config.map_or(flavor, |cfg| cfg.get_flavor_for_file(&candidate));
+                let target_index = build_file_index_only(&content, rules, target_flavor, Some(candidate.clone()));
+                workspace_index.insert_file(candidate, target_index);
+                break;
+            }
+        }
+    }
+
+    run_cross_file_checks(file_path, file_index, rules, &workspace_index, config)
+}
+
+/// Run workspace-level checks once the whole workspace has been indexed
+///
+/// Unlike [`run_cross_file_checks`], the warnings are not tied to a Markdown
+/// file: each rule returns the paths it reports on (such as unreferenced
+/// image files) with their warnings. `per-file-ignores` and severity
+/// overrides from `config` are applied per reported path.
+///
+/// Returns the reported paths in sorted order.
+pub fn run_workspace_checks(
+    rules: &[Box<dyn Rule>],
+    workspace_index: &crate::workspace_index::WorkspaceIndex,
+    config: Option<&crate::config::Config>,
+) -> Result<Vec<(std::path::PathBuf, Vec<LintWarning>)>, LintError> {
+    use crate::rule::CrossFileScope;
+
+    let mut reports: std::collections::BTreeMap<std::path::PathBuf, Vec<LintWarning>> =
+        std::collections::BTreeMap::new();
+
+    for rule in rules {
+        if rule.cross_file_scope() != CrossFileScope::Workspace {
+            continue;
+        }
+
+        let rule_reports = time_function!("workspace: workspace rule check", rule.workspace_check(workspace_index))
+            .inspect_err(|e| log::error!("Error in workspace check for rule {}: {}", rule.name(), e))?;
+
+        for (path, warnings) in rule_reports {
+            if config.is_some_and(|cfg| cfg.get_ignored_rules_for_file(&path).contains(rule.name())) {
+                continue;
+            }
+            let severity = config.and_then(|cfg| cfg.get_rule_severity(rule.name()));
+            reports
+                .entry(path)
+                .or_default()
+                .extend(warnings.into_iter().map(|mut warning| {
+                    if let Some(severity) = severity {
+                        warning.severity = severity;
+                    }
+                    warning
+                }));
+        }
+    }
+
+    Ok(reports.into_iter().collect())
+}
+
 /// Get the profiling report
 pub fn get_profiling_report() -> String {
     profiling::get_report()
@@ -600,6 +1033,45 @@ mod tests {
     use crate::rule::Rule;
     use crate::rules::{MD001HeadingIncrement, MD009TrailingSpaces};
 
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_large_file_parallel_checks_keep_rule_order() {
+        let section = "## Release\n\n- Fixed   a bug   \n- See http://example.com\n\n";
+        let content = section.repeat(PARALLEL_RULES_MIN_SIZE / section.len() + 1);
+        let rules: Vec<Box<dyn Rule>> = crate::rules::all_rules(&crate::config::Config::default())
+            .into_iter()
+            .filter(|rule| matches!(rule.name(), "MD009" | "MD024" | "MD034"))
+            .collect();
+
+        let warnings = lint(
+            &content,
+            &rules,
+            false,
+            crate::config::MarkdownFlavor::Standard,
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Same warnings, in the same order, as running the rules one at a time
+        let expected: Vec<_> = rules
+            .iter()
+            .flat_map(|rule| {
+                lint(
+                    &content,
+                    std::slice::from_ref(rule),
+                    false,
+                    crate::config::MarkdownFlavor::Standard,
+                    None,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(warnings, expected);
+    }
+
     #[test]
     fn test_content_characteristics_analyze() {
         // Test empty content
@@ -697,6 +1169,10 @@ mod tests {
         // Test blockquotes
         let chars = ContentCharacteristics::analyze("> Quote");
         assert!(chars.has_blockquotes);
+        let chars = ContentCharacteristics::analyze("Text\r\n> Quote");
+        assert!(chars.has_blockquotes);
+        let chars = ContentCharacteristics::analyze("Text > not a quote");
+        assert!(!chars.has_blockquotes);
 
         // Test mixed content
         let content = "# Heading\n* List item\n[link](url)\n`code`\n*emphasis*\n<p>html</p>\n| table |\n> quote\n![image](img.png)";
@@ -0,0 +1,398 @@
+//! Link rewriting for file moves.
+//!
+//! Backs `rumdl mv`: when a Markdown file moves, every relative link that
+//! pointed at it has to follow, and the moved file's own relative links have
+//! to be re-expressed from its new directory. Files that link to the moved
+//! file are found through the [`WorkspaceIndex`] reverse dependencies.
+//!
+//! Only the destination is rewritten; link text, titles, fragments and query
+//! strings are left as written. Root-relative (`/docs/a.md`) and external
+//! links never depend on the file's location and are not touched.
+
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+
+use pulldown_cmark::LinkType;
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::workspace_index::{CrossFileLinkIndex, FileIndex, PROTOCOL_DOMAIN_REGEX, WorkspaceIndex, url_decode};
+
+/// A file whose content changes as part of a move
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEdit {
+    /// Path of the file before the move
+    pub path: PathBuf,
+    /// Content before the move
+    pub original: String,
+    /// Content after links have been rewritten
+    pub updated: String,
+}
+
+impl FileEdit {
+    /// Whether the rewrite changed anything
+    pub fn is_changed(&self) -> bool {
+        self.original != self.updated
+    }
+}
+
+/// Everything that has to change to move `from` to `to`
+#[derive(Debug, Clone)]
+pub struct MovePlan {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// The moved file, with its relative links re-expressed from `to`
+    pub moved: FileEdit,
+    /// Other files whose links to `from` now point at `to`, sorted by path
+    pub dependents: Vec<FileEdit>,
+}
+
+/// Plan moving `from` to `to` within a workspace of Markdown `files`.
+///
+/// All paths must be absolute and normalized. `from` is read even if it is
+/// not in `files`. Nothing is written; the caller applies the plan.
+pub fn plan_move(files: &[PathBuf], from: &Path, to: &Path) -> std::io::Result<MovePlan> {
+    let mut index = WorkspaceIndex::new();
+    let mut contents = Vec::with_capacity(files.len());
+    for file in files {
+        if file == from {
+            continue;
+        }
+        // Unreadable files (binary, permissions) cannot link anywhere
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        let ctx = LintContext::new(&content, MarkdownFlavor::from_path(file), Some(file.clone()));
+        let mut file_index = FileIndex::new();
+        for range in link_destinations(&ctx) {
+            let (path, _) = split_destination(&content[range]);
+            if is_rewritable(path) {
+                file_index.add_cross_file_link(CrossFileLinkIndex {
+                    target_path: url_decode(path),
+                    fragment: String::new(),
+                    line: 0,
+                    column: 0,
+                });
+            }
+        }
+        index.update_file(file, file_index);
+        contents.push((file, content));
+    }
+
+    let dependents = index.get_dependents(from);
+    let mut edits: Vec<FileEdit> = contents
+        .into_iter()
+        .filter(|(file, _)| dependents.contains(file))
+        .map(|(file, content)| {
+            let updated = rewrite_links(&content, MarkdownFlavor::from_path(file), file, file, from, to);
+            FileEdit {
+                path: file.clone(),
+                original: content,
+                updated,
+            }
+        })
+        .filter(FileEdit::is_changed)
+        .collect();
+    edits.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let original = std::fs::read_to_string(from)?;
+    let updated = rewrite_links(&original, MarkdownFlavor::from_path(from), from, to, from, to);
+    Ok(MovePlan {
+        from: from.to_path_buf(),
+        to: to.to_path_buf(),
+        moved: FileEdit {
+            path: from.to_path_buf(),
+            original,
+            updated,
+        },
+        dependents: edits,
+    })
+}
+
+/// Rewrite the relative links in `content` for a move of `from` to `to`.
+///
+/// `file` is where `content` lives now and `new_file` where it will live
+/// afterwards; they differ only for the moved file itself. Links that
+/// resolved to `from` are pointed at `to`, and when the containing directory
+/// changes every other relative link is re-expressed from the new directory.
+pub fn rewrite_links(
+    content: &str,
+    flavor: MarkdownFlavor,
+    file: &Path,
+    new_file: &Path,
+    from: &Path,
+    to: &Path,
+) -> String {
+    let ctx = LintContext::new(content, flavor, Some(file.to_path_buf()));
+    let dir = file.parent().unwrap_or(Path::new(""));
+    let new_dir = new_file.parent().unwrap_or(Path::new(""));
+
+    let mut replacements: Vec<(Range<usize>, String)> = link_destinations(&ctx)
+        .into_iter()
+        .filter_map(|range| {
+            let angle = range.start > 0 && content.as_bytes()[range.start - 1] == b'<';
+            retarget(&content[range.clone()], angle, dir, new_dir, from, to).map(|url| (range, url))
+        })
+        .collect();
+    replacements.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+    replacements.dedup_by_key(|(range, _)| range.start);
+
+    let mut result = content.to_string();
+    for (range, url) in replacements {
+        result.replace_range(range, &url);
+    }
+    result
+}
+
+/// The new destination for `raw`, or `None` if it stays as written
+fn retarget(raw: &str, angle: bool, dir: &Path, new_dir: &Path, from: &Path, to: &Path) -> Option<String> {
+    let (path, suffix) = split_destination(raw);
+    if !is_rewritable(path) {
+        return None;
+    }
+
+    let resolved = WorkspaceIndex::normalize_path(&dir.join(url_decode(path)));
+    let target = if resolved == from { to } else { resolved.as_path() };
+    if target == resolved && dir == new_dir {
+        return None;
+    }
+
+    let mut url = relative_path(new_dir, target)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    if url.is_empty() {
+        url.push('.');
+    }
+    if path.ends_with('/') {
+        url.push('/');
+    }
+    if path.starts_with("./") && !url.starts_with('.') {
+        url.insert_str(0, "./");
+    }
+    if !angle {
+        url = url.replace(' ', "%20");
+    }
+    url.push_str(suffix);
+
+    (url != raw).then_some(url)
+}
+
+/// Split a destination into its path and its `?query#fragment` suffix
+pub(crate) fn split_destination(raw: &str) -> (&str, &str) {
+    raw.split_at(raw.find(['?', '#']).unwrap_or(raw.len()))
+}
+
+/// Whether a destination path resolves against the containing directory
+pub(crate) fn is_rewritable(path: &str) -> bool {
+    !(path.is_empty()
+        || path.starts_with('/')
+        || path.starts_with("{{")
+        || path.starts_with("{%")
+        || path.starts_with('~')
+        || path.starts_with('@')
+        || PROTOCOL_DOMAIN_REGEX.is_match(path))
+}
+
+/// Relative path from directory `from_dir` to `to`; both absolute, or both
+/// relative to the same directory
+pub(crate) fn relative_path(from_dir: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from_dir.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut rel = PathBuf::new();
+    for _ in common..from.len() {
+        rel.push("..");
+    }
+    for component in &to[common..] {
+        rel.push(component);
+    }
+    rel
+}
+
+/// Byte ranges of the destinations of inline links, inline images and
+/// reference definitions, without enclosing angle brackets
+pub(crate) fn link_destinations(ctx: &LintContext) -> Vec<Range<usize>> {
+    let content = ctx.content;
+    let inline = ctx
+        .links()
+        .iter()
+        .filter(|link| matches!(link.link_type, LinkType::Inline))
+        .map(|link| (link.byte_offset, link.byte_end))
+        .chain(
+            ctx.images()
+                .iter()
+                .filter(|image| matches!(image.link_type, LinkType::Inline))
+                .map(|image| (image.byte_offset, image.byte_end)),
+        )
+        .filter_map(|(start, end)| {
+            let source = content.get(start..end)?;
+            let open = closing_bracket(source)? + 1;
+            source[open..]
+                .starts_with('(')
+                .then(|| destination_range(source, open + 1))
+                .flatten()
+                .map(|range| start + range.start..start + range.end)
+        });
+
+    let definitions = ctx.reference_defs.iter().filter_map(|def| {
+        let source = content.get(def.byte_offset..def.byte_end)?;
+        let colon = closing_bracket(source)? + 1;
+        source[colon..]
+            .starts_with(':')
+            .then(|| destination_range(source, colon + 1))
+            .flatten()
+            .map(|range| def.byte_offset + range.start..def.byte_offset + range.end)
+    });
+
+    inline.chain(definitions).collect()
+}
+
+/// Offset of the `]` closing the first bracketed label in `source`
+fn closing_bracket(source: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, c) in source.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' if depth == 1 => return Some(i),
+            ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Range of the destination starting at or after `start` (after leading
+/// whitespace), excluding angle brackets
+fn destination_range(source: &str, start: usize) -> Option<Range<usize>> {
+    let rest = &source[start..];
+    let start = start + (rest.len() - rest.trim_start().len());
+    let rest = &source[start..];
+
+    if let Some(inner) = rest.strip_prefix('<') {
+        let end = inner.find('>')?;
+        return Some(start + 1..start + 1 + end);
+    }
+
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut end = rest.len();
+    for (i, c) in rest.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                end = i;
+                break;
+            }
+            ')' => depth -= 1,
+            c if c.is_whitespace() => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    (end > 0).then_some(start..start + end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn rewrite(content: &str, file: &str, new_file: &str, from: &str, to: &str) -> String {
+        rewrite_links(
+            content,
+            MarkdownFlavor::Standard,
+            Path::new(file),
+            Path::new(new_file),
+            Path::new(from),
+            Path::new(to),
+        )
+    }
+
+    #[test]
+    fn test_inbound_links_follow_the_moved_file() {
+        let content = "\
+See [guide](guide.md#setup \"Guide\"), ![diagram](img/a.png) and <guide.md>.
+
+[ref]: ./guide.md?plain=1
+[other]: https://example.com/guide.md
+
+`[code](guide.md)`
+";
+        let updated = rewrite(
+            content,
+            "/w/docs/index.md",
+            "/w/docs/index.md",
+            "/w/docs/guide.md",
+            "/w/manual/start.md",
+        );
+        assert_eq!(
+            updated,
+            "\
+See [guide](../manual/start.md#setup \"Guide\"), ![diagram](img/a.png) and <guide.md>.
+
+[ref]: ../manual/start.md?plain=1
+[other]: https://example.com/guide.md
+
+`[code](guide.md)`
+"
+        );
+    }
+
+    #[test]
+    fn test_moved_file_links_are_reexpressed_from_new_directory() {
+        let content = "\
+[Sibling](other.md) [Up](../README.md) [Self](guide.md#top) [Anchor](#top)
+[![badge](img/b.svg)](<my notes.md>) [Spaced](my%20notes.md) [Root](/abs.md)
+";
+        let updated = rewrite(
+            content,
+            "/w/docs/guide.md",
+            "/w/docs/deep/start.md",
+            "/w/docs/guide.md",
+            "/w/docs/deep/start.md",
+        );
+        assert_eq!(
+            updated,
+            "\
+[Sibling](../other.md) [Up](../../README.md) [Self](start.md#top) [Anchor](#top)
+[![badge](../img/b.svg)](<../my notes.md>) [Spaced](../my%20notes.md) [Root](/abs.md)
+"
+        );
+    }
+
+    #[test]
+    fn test_rename_in_place_only_touches_self_links() {
+        let content = "[a](./other.md) [b](guide.md)\n";
+        let updated = rewrite(content, "/w/guide.md", "/w/intro.md", "/w/guide.md", "/w/intro.md");
+        assert_eq!(updated, "[a](./other.md) [b](intro.md)\n");
+    }
+
+    #[test]
+    fn test_plan_move_finds_dependents_through_index() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("README.md"), "[Guide](docs/guide.md)\n").unwrap();
+        fs::write(root.join("docs/guide.md"), "[Home](../README.md)\n").unwrap();
+        fs::write(root.join("docs/unrelated.md"), "[Home](../README.md)\n").unwrap();
+
+        let files = ["README.md", "docs/guide.md", "docs/unrelated.md"].map(|f| root.join(f));
+        let plan = plan_move(&files, &root.join("docs/guide.md"), &root.join("guide.md")).unwrap();
+
+        assert_eq!(plan.moved.updated, "[Home](README.md)\n");
+        assert_eq!(plan.dependents.len(), 1);
+        assert_eq!(plan.dependents[0].path, root.join("README.md"));
+        assert_eq!(plan.dependents[0].updated, "[Guide](guide.md)\n");
+    }
+}
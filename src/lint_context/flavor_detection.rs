@@ -150,6 +150,7 @@ pub(super) fn detect_jsx_blocks(content: &str, lines: &mut [LineInfo], flavor: M
     }
 
     let mut tag_stack: Vec<(String, usize)> = Vec::new();
+    let mut document_fence = FencedCodeTracker::new();
 
     for i in 0..lines.len() {
         if lines[i].in_front_matter || lines[i].in_html_comment {
@@ -158,9 +159,12 @@ pub(super) fn detect_jsx_blocks(content: &str, lines: &mut [LineInfo], flavor: M
 
         let line_content = lines[i].content(content);
         let trimmed = line_content.trim();
+        let in_fence = document_fence.process_line(trimmed);
 
-        // Skip lines in code blocks that don't contain '<' — they can't have JSX tags
-        if lines[i].in_code_block && !trimmed.contains('<') {
+        // Skip lines in code blocks that don't contain '<' — they can't have JSX
+        // tags — and fenced code altogether: a `<Component />` in a ```jsx
+        // example is sample code, not a component usage.
+        if lines[i].in_code_block && (in_fence || !trimmed.contains('<')) {
             continue;
         }
 
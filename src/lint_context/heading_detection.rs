@@ -1,9 +1,35 @@
 use crate::config::MarkdownFlavor;
 use crate::utils::table_utils::TableUtils;
+use std::borrow::Cow;
 use std::sync::LazyLock;
 
 use super::types::*;
 
+/// Whether heading detection needs pulldown-cmark link byte ranges.
+///
+/// They are only consulted for an ATX-looking line that could start inside a
+/// multi-line link. A link cannot span a blank line, so that requires a
+/// non-blank line right before it; documents without one can defer the link
+/// parse until a rule actually asks for links.
+pub(super) fn needs_link_byte_ranges(content_lines: &[&str], lines: &[LineInfo]) -> bool {
+    (1..lines.len().min(content_lines.len()))
+        .any(|i| !lines[i].in_code_block && !lines[i - 1].is_blank && content_lines[i].trim_start().starts_with('#'))
+}
+
+/// The text and custom ID of a heading whose raw text is `raw_text`, found
+/// in `line`, which starts at byte `line_offset` of the document
+fn heading_text(line: &str, line_offset: usize, raw_text: &str) -> (SourceText, Option<SourceText>) {
+    // Only attribute lists and HTML anchors change the text
+    if !raw_text.contains(['{', '<']) {
+        return (SourceText::find(line, line_offset, raw_text), None);
+    }
+    let (clean_text, custom_id) = crate::utils::header_id_utils::extract_header_id(raw_text);
+    (
+        SourceText::find(line, line_offset, &clean_text),
+        custom_id.map(|id| SourceText::find(line, line_offset, &id)),
+    )
+}
+
 /// Detect headings and blockquotes (called after HTML block detection)
 pub(super) fn detect_headings_and_blockquotes(
     content_lines: &[&str],
@@ -30,7 +56,7 @@ pub(super) fn detect_headings_and_blockquotes(
             let nesting_level = bq.nesting_level;
             let marker_column = bq.indent.len();
             let content_leading_ws_len = bq.content.len() - bq.content.trim_start_matches([' ', '\t']).len();
-            let full_prefix = format!("{}{}", bq.prefix, &bq.content[..content_leading_ws_len]);
+            let prefix_len = bq.prefix.len() + content_leading_ws_len;
             let normalized_content = &bq.content[content_leading_ws_len..];
 
             let has_multiple_spaces = bq.spaces_after_marker.chars().filter(|&c| c == ' ').count() > 1;
@@ -38,9 +64,10 @@ pub(super) fn detect_headings_and_blockquotes(
             lines[i].blockquote = Some(Box::new(BlockquoteInfo {
                 nesting_level,
                 marker_column,
-                prefix: full_prefix,
-                content: normalized_content.to_string(),
                 has_multiple_spaces_after_marker: has_multiple_spaces,
+                line_start: lines[i].byte_offset,
+                content_start: lines[i].byte_offset + prefix_len,
+                line_end: lines[i].byte_offset + line.len(),
             }));
 
             // Update is_horizontal_rule for blockquote content
@@ -88,13 +115,14 @@ pub(super) fn detect_headings_and_blockquotes(
             let leading_spaces = caps.get(1).map_or("", |m| m.as_str());
             let hashes = caps.get(2).map_or("", |m| m.as_str());
             let spaces_after = caps.get(3).map_or("", |m| m.as_str());
-            let rest = caps.get(4).map_or("", |m| m.as_str());
+            let rest_start = caps.get(4).map_or(line.len(), |m| m.start());
+            let rest = &line[rest_start..];
 
             let level = hashes.len() as u8;
             let marker_column = leading_spaces.len();
 
             // Check for closing sequence, but handle custom IDs that might come after
-            let (text, has_closing, closing_seq) = {
+            let (text, closing_span) = {
                 let (rest_without_id, custom_id_part) = if let Some(id_start) = rest.rfind(" {#") {
                     if rest[id_start..].trim_end().ends_with('}') {
                         (&rest[..id_start], &rest[id_start..])
@@ -126,28 +154,32 @@ pub(super) fn detect_headings_and_blockquotes(
                         let is_all_hashes = potential_closing.chars().all(|c| c == '#');
 
                         if is_all_hashes && has_space_before {
-                            let closing_hashes = potential_closing.to_string();
+                            let closing_start = line_offset + rest_start + start_of_hashes;
                             let text_part = if !custom_id_part.is_empty() {
-                                format!("{}{}", trimmed_rest[..start_of_hashes].trim_end(), custom_id_part)
+                                Cow::Owned(format!(
+                                    "{}{}",
+                                    trimmed_rest[..start_of_hashes].trim_end(),
+                                    custom_id_part
+                                ))
                             } else {
-                                trimmed_rest[..start_of_hashes].trim_end().to_string()
+                                Cow::Borrowed(trimmed_rest[..start_of_hashes].trim_end())
                             };
-                            (text_part, true, closing_hashes)
+                            (text_part, closing_start..closing_start + potential_closing.len())
                         } else {
-                            (rest.to_string(), false, String::new())
+                            (Cow::Borrowed(rest), 0..0)
                         }
                     } else {
-                        (rest.to_string(), false, String::new())
+                        (Cow::Borrowed(rest), 0..0)
                     }
                 } else {
-                    (rest.to_string(), false, String::new())
+                    (Cow::Borrowed(rest), 0..0)
                 }
             };
 
             let content_column = marker_column + hashes.len() + spaces_after.len();
 
-            let raw_text = text.trim().to_string();
-            let (clean_text, mut custom_id) = crate::utils::header_id_utils::extract_header_id(&raw_text);
+            let raw_text = text.trim();
+            let (clean_text, mut custom_id) = heading_text(line, line_offset, raw_text);
 
             if custom_id.is_none() && i + 1 < content_lines.len() && i + 1 < lines.len() {
                 let next_line = content_lines[i + 1];
@@ -156,7 +188,7 @@ pub(super) fn detect_headings_and_blockquotes(
                     && let Some(next_line_id) =
                         crate::utils::header_id_utils::extract_standalone_attr_list_id(next_line)
                 {
-                    custom_id = Some(next_line_id);
+                    custom_id = Some(SourceText::find(next_line, lines[i + 1].byte_offset, &next_line_id));
                 }
             }
 
@@ -165,18 +197,19 @@ pub(super) fn detect_headings_and_blockquotes(
                 || level > 1
                 || rest.trim().chars().next().is_some_and(char::is_uppercase);
 
+            let marker_start = line_offset + marker_column;
             lines[i].heading = Some(Box::new(HeadingInfo {
                 level,
                 style: HeadingStyle::ATX,
-                marker: hashes.to_string(),
                 marker_column,
                 content_column,
-                text: clean_text,
-                custom_id,
-                raw_text,
-                has_closing_sequence: has_closing,
-                closing_sequence: closing_seq,
+                has_closing_sequence: !closing_span.is_empty(),
                 is_valid,
+                marker_span: marker_start..marker_start + hashes.len(),
+                text_source: clean_text,
+                custom_id_source: custom_id,
+                raw_text_source: SourceText::find(line, line_offset, raw_text),
+                closing_sequence_span: closing_span,
             }));
         }
         // Check for Setext headings (need to look at next line)
@@ -283,8 +316,9 @@ pub(super) fn detect_headings_and_blockquotes(
                     HeadingStyle::Setext2
                 };
 
-                let raw_text = line.trim().to_string();
-                let (clean_text, mut custom_id) = crate::utils::header_id_utils::extract_header_id(&raw_text);
+                let line_offset = lines[i].byte_offset;
+                let raw_text = line.trim();
+                let (clean_text, mut custom_id) = heading_text(line, line_offset, raw_text);
 
                 if custom_id.is_none() && i + 2 < content_lines.len() && i + 2 < lines.len() {
                     let attr_line = content_lines[i + 2];
@@ -293,22 +327,24 @@ pub(super) fn detect_headings_and_blockquotes(
                         && let Some(attr_line_id) =
                             crate::utils::header_id_utils::extract_standalone_attr_list_id(attr_line)
                     {
-                        custom_id = Some(attr_line_id);
+                        custom_id = Some(SourceText::find(attr_line, lines[i + 2].byte_offset, &attr_line_id));
                     }
                 }
 
+                let marker_column = next_line.len() - next_line.trim_start().len();
+                let marker_start = lines[i + 1].byte_offset + marker_column;
                 lines[i].heading = Some(Box::new(HeadingInfo {
                     level,
                     style,
-                    marker: underline.to_string(),
-                    marker_column: next_line.len() - next_line.trim_start().len(),
+                    marker_column,
                     content_column: lines[i].indent,
-                    text: clean_text,
-                    custom_id,
-                    raw_text,
                     has_closing_sequence: false,
-                    closing_sequence: String::new(),
                     is_valid: true,
+                    marker_span: marker_start..marker_start + underline.len(),
+                    text_source: clean_text,
+                    custom_id_source: custom_id,
+                    raw_text_source: SourceText::find(line, line_offset, raw_text),
+                    closing_sequence_span: 0..0,
                 }));
             }
         }
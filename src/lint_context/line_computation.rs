@@ -61,7 +61,7 @@ pub(super) fn compute_basic_line_info(
     };
 
     // Use pulldown-cmark to detect list items AND emphasis spans in a single pass
-    let (list_item_map, emphasis_spans) = detect_list_items_and_emphasis_with_pulldown(
+    let (mut list_item_map, emphasis_spans) = detect_list_items_and_emphasis_with_pulldown(
         pulldown_input,
         line_offsets,
         flavor,
@@ -106,14 +106,15 @@ pub(super) fn compute_basic_line_info(
             None
         } else {
             list_item_map
-                .get(&byte_offset)
-                .map(|(is_ordered, marker, marker_column, content_column, number)| {
+                .remove(&byte_offset)
+                .map(|(is_ordered, marker_len, marker_column, content_column, number)| {
+                    let marker_start = byte_offset + marker_column;
                     Box::new(ListItemInfo {
-                        marker: marker.clone(),
-                        is_ordered: *is_ordered,
-                        number: *number,
-                        marker_column: *marker_column,
-                        content_column: *content_column,
+                        is_ordered,
+                        number,
+                        marker_column,
+                        content_column,
+                        marker_span: marker_start..marker_start + marker_len,
                     })
                 })
         };
@@ -140,7 +141,6 @@ pub(super) fn compute_basic_line_info(
             in_front_matter,
             in_html_block: false,
             in_list_block: false,
-            in_table_block: false,
             in_html_comment,
             list_item,
             heading: None,
@@ -541,32 +541,28 @@ pub(super) fn detect_list_items_and_emphasis_with_pulldown(
                         if let Some((leading_spaces, number_str, delimiter, spacing, _content)) =
                             parse_ordered_list(line_to_parse)
                         {
-                            let marker = format!("{number_str}{delimiter}");
+                            let marker_len = number_str.len() + delimiter.len_utf8();
                             let marker_column = blockquote_prefix_len + leading_spaces.len();
-                            let content_column = marker_column + marker.len() + spacing.len();
+                            let content_column = marker_column + marker_len + spacing.len();
                             let number = number_str.parse().ok();
 
                             list_items.entry(line_start_byte).or_insert((
                                 true,
-                                marker,
+                                marker_len,
                                 marker_column,
                                 content_column,
                                 number,
                             ));
                         }
-                    } else if let Some((leading_spaces, marker, spacing, _content)) =
+                    } else if let Some((leading_spaces, _marker, spacing, _content)) =
                         parse_unordered_list(line_to_parse)
                     {
                         let marker_column = blockquote_prefix_len + leading_spaces.len();
                         let content_column = marker_column + 1 + spacing.len();
 
-                        list_items.entry(line_start_byte).or_insert((
-                            false,
-                            marker.to_string(),
-                            marker_column,
-                            content_column,
-                            None,
-                        ));
+                        list_items
+                            .entry(line_start_byte)
+                            .or_insert((false, 1, marker_column, content_column, None));
                     }
                 }
             }
@@ -819,7 +819,7 @@ pub(super) fn parse_reference_defs(content: &str, lines: &[LineInfo]) -> Vec<Ref
         // blockquote (`> [id]: url`). Match the quoted content and offset the byte
         // positions past the blockquote prefix; plain lines are matched verbatim.
         let (line, base_offset) = match line_info.blockquote.as_deref() {
-            Some(bq) => (bq.content.as_str(), line_info.byte_offset + bq.prefix.len()),
+            Some(bq) => (bq.content(content), line_info.byte_offset + bq.prefix_len()),
             None => (line_info.content(content), line_info.byte_offset),
         };
 
@@ -869,8 +869,8 @@ pub(super) fn parse_reference_defs(content: &str, lines: &[LineInfo]) -> Vec<Ref
                 let cur_nesting = line_info.blockquote.as_deref().map_or(0, |bq| bq.nesting_level);
                 let (next_line, next_base, next_nesting) = match next.blockquote.as_deref() {
                     Some(bq) => (
-                        bq.content.as_str(),
-                        next.byte_offset + bq.prefix.len(),
+                        bq.content(content),
+                        next.byte_offset + bq.prefix_len(),
                         bq.nesting_level,
                     ),
                     None => (next.content(content), next.byte_offset, 0),
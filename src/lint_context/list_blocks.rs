@@ -60,9 +60,9 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
     ) {
         *has_list_breaking_content = false;
         let marker_width = if list_item.is_ordered {
-            list_item.marker.len() + 1 // Ordered markers need space after period/paren
+            list_item.marker_len() + 1 // Ordered markers need space after period/paren
         } else {
-            list_item.marker.len()
+            list_item.marker_len()
         };
         *min_continuation = if list_item.is_ordered {
             marker_width
@@ -276,7 +276,9 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
             if debug_list {
                 eprintln!(
                     "[DEBUG] Line {}: list item found, marker={:?}, indent={}",
-                    line_num, list_item.marker, item_indent
+                    line_num,
+                    list_item.marker(content),
+                    item_indent
                 );
             }
 
@@ -290,8 +292,9 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
                 let reasonable_distance = line_num <= last_list_item_line + 2 || line_num == block.end_line + 1;
 
                 // For unordered lists, also check marker consistency
-                let marker_compatible =
-                    block.is_ordered || block.marker.is_none() || block.marker.as_ref() == Some(&list_item.marker);
+                let marker_compatible = block.is_ordered
+                    || block.marker.is_none()
+                    || block.marker.as_deref() == Some(list_item.marker(content));
 
                 // O(1) check: Use the tracked variable instead of O(n) nested loop
                 let has_non_list_content = has_list_breaking_content_since_last_item;
@@ -340,13 +343,16 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
 
                     // Update max marker width
                     block.max_marker_width = block.max_marker_width.max(if list_item.is_ordered {
-                        list_item.marker.len() + 1
+                        list_item.marker_len() + 1
                     } else {
-                        list_item.marker.len()
+                        list_item.marker_len()
                     });
 
                     // Update marker consistency for unordered lists
-                    if !block.is_ordered && block.marker.is_some() && block.marker.as_ref() != Some(&list_item.marker) {
+                    if !block.is_ordered
+                        && block.marker.is_some()
+                        && block.marker.as_deref() != Some(list_item.marker(content))
+                    {
                         // Mixed markers, clear the marker field
                         block.marker = None;
                     }
@@ -375,15 +381,15 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
                         marker: if list_item.is_ordered {
                             None
                         } else {
-                            Some(list_item.marker.clone())
+                            Some(list_item.marker(content).to_string())
                         },
                         blockquote_prefix: blockquote_prefix.clone(),
                         item_lines: vec![line_num],
                         nesting_level: nesting,
                         max_marker_width: if list_item.is_ordered {
-                            list_item.marker.len() + 1
+                            list_item.marker_len() + 1
                         } else {
-                            list_item.marker.len()
+                            list_item.marker_len()
                         },
                     };
                     let old_block = std::mem::replace(block, new_block);
@@ -405,12 +411,12 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
                     marker: if list_item.is_ordered {
                         None
                     } else {
-                        Some(list_item.marker.clone())
+                        Some(list_item.marker(content).to_string())
                     },
                     blockquote_prefix,
                     item_lines: vec![line_num],
                     nesting_level: nesting,
-                    max_marker_width: list_item.marker.len(),
+                    max_marker_width: list_item.marker_len(),
                 });
 
                 // Initialize tracked state for new block
@@ -424,9 +430,9 @@ pub(super) fn parse_list_blocks(content: &str, lines: &[LineInfo]) -> Vec<ListBl
             last_list_item_line = line_num;
             current_indent_level = item_indent;
             last_marker_width = if list_item.is_ordered {
-                list_item.marker.len() + 1 // Add 1 for the space after ordered list markers
+                list_item.marker_len() + 1 // Add 1 for the space after ordered list markers
             } else {
-                list_item.marker.len()
+                list_item.marker_len()
             };
         } else if let Some(ref mut block) = current_block {
             // Not a list item - check if it continues the current block
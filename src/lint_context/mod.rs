@@ -11,6 +11,7 @@ mod list_blocks;
 mod tests;
 
 use crate::config::MarkdownFlavor;
+use crate::front_matter_config::FrontMatterConfig;
 use crate::inline_config::InlineConfig;
 use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::utils::code_block_utils::{CodeBlockDetail, CodeBlockUtils};
@@ -45,14 +46,23 @@ pub(super) struct SkipByteRanges<'a> {
     pub(super) pymdown_block_ranges: &'a [crate::utils::skip_context::ByteRange],
 }
 
-use std::sync::{Arc, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 
 /// Map from line byte offset to list item data: (is_ordered, marker, marker_column, content_column, number)
-pub(super) type ListItemMap = std::collections::HashMap<usize, (bool, String, usize, usize, Option<usize>)>;
+pub(super) type ListItemMap = std::collections::HashMap<usize, (bool, usize, usize, usize, Option<usize>)>;
 
 /// Type alias for byte ranges used in JSX expression and MDX comment detection
 pub(super) type ByteRanges = Vec<(usize, usize)>;
 
+/// Links, images, broken references, and footnote references, finalized
+/// together from the pulldown-cmark parse on first access.
+struct LinkCollections<'a> {
+    links: Vec<ParsedLink<'a>>,
+    images: Vec<ParsedImage<'a>>,
+    broken_links: Vec<BrokenLinkInfo>,
+    footnote_refs: Vec<FootnoteRef>,
+}
+
 pub struct LintContext<'a> {
     pub content: &'a str,
     content_lines: Vec<&'a str>, // Pre-split lines from content (avoids repeated allocations)
@@ -63,10 +73,8 @@ pub struct LintContext<'a> {
     pub line_to_list: crate::utils::code_block_utils::LineToListMap, // Ordered list membership by line
     pub list_start_values: crate::utils::code_block_utils::ListStartValues, // Start values per list ID
     pub lines: Vec<LineInfo>,             // Pre-computed line information
-    pub links: Vec<ParsedLink<'a>>,       // Pre-parsed links
-    pub images: Vec<ParsedImage<'a>>,     // Pre-parsed images
-    pub broken_links: Vec<BrokenLinkInfo>, // Broken/undefined references
-    pub footnote_refs: Vec<FootnoteRef>,  // Pre-parsed footnote references
+    pending_links: Mutex<Option<link_parser::PulldownParseResult<'a>>>, // Eager pulldown link parse awaiting finalize
+    link_collections_cache: OnceLock<LinkCollections<'a>>, // Lazy-finalized links, images, broken links, footnote refs
     pub reference_defs: Vec<ReferenceDef>, // Reference definitions
     reference_defs_map: HashMap<String, usize>, // O(1) lookup by lowercase ID -> index in reference_defs
     code_spans_cache: OnceLock<Arc<Vec<CodeSpan>>>, // Lazy-loaded inline code spans
@@ -81,7 +89,7 @@ pub struct LintContext<'a> {
     bare_urls_cache: OnceLock<Arc<Vec<BareUrl>>>,          // Lazy-loaded bare URLs
     has_mixed_list_nesting_cache: OnceLock<bool>, // Cached result for mixed ordered/unordered list nesting detection
     html_comment_ranges: Vec<crate::utils::skip_context::ByteRange>, // Pre-computed HTML comment ranges
-    pub table_blocks: Vec<crate::utils::table_utils::TableBlock>, // Pre-computed table blocks
+    table_blocks_cache: OnceLock<Vec<crate::utils::table_utils::TableBlock>>, // Lazy-loaded table blocks
     pub line_index: crate::utils::range_utils::LineIndex<'a>, // Pre-computed line index for byte position calculations
     jinja_ranges: Vec<(usize, usize)>,            // Pre-computed Jinja template ranges ({{ }}, {% %})
     pub flavor: MarkdownFlavor,                   // Markdown flavor being used
@@ -103,16 +111,17 @@ pub struct LintContext<'a> {
     pandoc_metadata_ranges: Vec<crate::utils::skip_context::ByteRange>, // Pre-computed Pandoc YAML metadata block ranges (--- ... --- or ...)
     grid_table_ranges: Vec<crate::utils::skip_context::ByteRange>, // Pre-computed Pandoc grid-table ranges (+---+---+)
     multi_line_table_ranges: Vec<crate::utils::skip_context::ByteRange>, // Pre-computed Pandoc multi-line table ranges
-    shortcode_ranges: Vec<(usize, usize)>, // Pre-computed Hugo/Quarto shortcode ranges ({{< ... >}} and {{% ... %}})
-    link_title_ranges: Vec<(usize, usize)>, // Pre-computed sorted link title byte ranges
-    code_span_byte_ranges: Vec<(usize, usize)>, // Pre-computed code span byte ranges from pulldown-cmark
-    inline_config: InlineConfig,           // Parsed inline configuration comments for rule disabling
+    shortcode_ranges_cache: OnceLock<Vec<(usize, usize)>>, // Lazy-loaded Hugo/Quarto shortcode ranges ({{< ... >}} and {{% ... %}})
+    link_title_ranges: Vec<(usize, usize)>,                // Pre-computed sorted link title byte ranges
+    code_span_byte_ranges: Vec<(usize, usize)>,            // Pre-computed code span byte ranges from pulldown-cmark
+    inline_config: InlineConfig,                           // Parsed inline configuration comments for rule disabling
+    front_matter_config: FrontMatterConfig, // Rule overrides declared in front matter (already folded into inline_config)
     obsidian_comment_ranges: Vec<(usize, usize)>, // Pre-computed Obsidian comment ranges (%%...%%)
     lazy_cont_lines_cache: OnceLock<Arc<Vec<LazyContLine>>>, // Lazy-loaded lazy continuation lines
     myst_directive_ranges: Vec<(usize, usize)>, // Pre-computed MyST colon directive byte ranges (:::{name} ... :::)
     myst_comment_ranges: Vec<(usize, usize)>, // Pre-computed MyST comment byte ranges (% comment)
-    myst_role_ranges: Vec<(usize, usize)>, // Pre-computed MyST role byte ranges ({role}`content`)
-    front_matter_end: usize,               // 1-indexed line where front matter ends, 0 if none
+    myst_role_ranges: Vec<(usize, usize)>,  // Pre-computed MyST role byte ranges ({role}`content`)
+    front_matter_end: usize,                // 1-indexed line where front matter ends, 0 if none
 }
 
 impl<'a> LintContext<'a> {
@@ -519,13 +528,16 @@ impl<'a> LintContext<'a> {
         );
 
         // Run pulldown-cmark parse for links, images, and link byte ranges in a single pass.
-        // Link byte ranges are needed for heading detection; links/images are finalized later
-        // after code_spans are available.
-        let pulldown_result = profile_section!(
-            "Links, images & link ranges",
-            profile,
-            link_parser::parse_links_images_pulldown(content, &lines, &code_blocks, flavor, &html_comment_ranges)
-        );
+        // Link byte ranges are needed for heading detection only when a heading-like line
+        // continues a paragraph; otherwise the parse is deferred to the first `links()` call.
+        // Links/images are finalized lazily as well, after code_spans are available.
+        let pulldown_result = heading_detection::needs_link_byte_ranges(&content_lines, &lines).then(|| {
+            profile_section!(
+                "Links, images & link ranges",
+                profile,
+                link_parser::parse_links_images_pulldown(content, &lines, &code_blocks, flavor, &html_comment_ranges)
+            )
+        });
 
         // Now detect headings and blockquotes
         profile_section!(
@@ -536,7 +548,9 @@ impl<'a> LintContext<'a> {
                 &mut lines,
                 flavor,
                 &html_comment_ranges,
-                &pulldown_result.link_byte_ranges,
+                pulldown_result
+                    .as_ref()
+                    .map_or(&[][..], |result| result.link_byte_ranges.as_slice()),
                 front_matter_end,
             )
         );
@@ -599,21 +613,6 @@ impl<'a> LintContext<'a> {
             }
         }
 
-        // Finalize links and images: filter by code_spans and run regex fallbacks
-        let (links, images, broken_links, footnote_refs) = profile_section!(
-            "Links & images finalize",
-            profile,
-            link_parser::finalize_links_and_images(
-                content,
-                &lines,
-                &code_blocks,
-                &code_spans,
-                flavor,
-                &html_comment_ranges,
-                pulldown_result
-            )
-        );
-
         let reference_defs = profile_section!(
             "Reference defs",
             profile,
@@ -629,42 +628,8 @@ impl<'a> LintContext<'a> {
             line_computation::compute_char_frequency(content)
         );
 
-        // Pre-compute table blocks for rules that need them (MD013, MD055, MD056, MD058, MD060)
-        let table_blocks = profile_section!(
-            "Table blocks",
-            profile,
-            crate::utils::table_utils::TableUtils::find_table_blocks_with_code_info(
-                content,
-                &code_blocks,
-                &code_spans,
-                &html_comment_ranges,
-            )
-        );
-
         // Layer 2: Filter pre-computed collections to exclude items inside kramdown extension blocks.
         // Rules that iterate these collections automatically skip kramdown content.
-        let links = links
-            .into_iter()
-            .filter(|link| !lines.get(link.line - 1).is_some_and(|l| l.in_kramdown_extension_block))
-            .collect::<Vec<_>>();
-        let images = images
-            .into_iter()
-            .filter(|img| !lines.get(img.line - 1).is_some_and(|l| l.in_kramdown_extension_block))
-            .collect::<Vec<_>>();
-        let broken_links = broken_links
-            .into_iter()
-            .filter(|bl| {
-                // BrokenLinkInfo has span but no line field; find line from byte offset
-                let line_idx = line_offsets
-                    .partition_point(|&offset| offset <= bl.span.start)
-                    .saturating_sub(1);
-                !lines.get(line_idx).is_some_and(|l| l.in_kramdown_extension_block)
-            })
-            .collect::<Vec<_>>();
-        let footnote_refs = footnote_refs
-            .into_iter()
-            .filter(|fr| !lines.get(fr.line - 1).is_some_and(|l| l.in_kramdown_extension_block))
-            .collect::<Vec<_>>();
         let reference_defs = reference_defs
             .into_iter()
             .filter(|def| !lines.get(def.line - 1).is_some_and(|l| l.in_kramdown_extension_block))
@@ -677,23 +642,14 @@ impl<'a> LintContext<'a> {
                     .is_some_and(|l| l.in_kramdown_extension_block)
             })
             .collect::<Vec<_>>();
-        let table_blocks = table_blocks
-            .into_iter()
-            .filter(|block| {
-                // TableBlock.start_line is 0-indexed
-                !lines
-                    .get(block.start_line)
-                    .is_some_and(|l| l.in_kramdown_extension_block)
-            })
-            .collect::<Vec<_>>();
         let emphasis_spans = emphasis_spans
             .into_iter()
             .filter(|span| !lines.get(span.line - 1).is_some_and(|l| l.in_kramdown_extension_block))
             .collect::<Vec<_>>();
 
-        // Mark lines covered by a list or table block so is_in_list_block /
-        // is_in_table_block are O(1) reads (mirrors in_html_block) instead of
-        // scanning the whole block vector on every call.
+        // Mark lines covered by a list block so is_in_list_block is an O(1)
+        // read (mirrors in_html_block) instead of scanning the whole block
+        // vector on every call.
         for block in &list_blocks {
             // ListBlock line numbers are 1-indexed.
             for line_num in block.start_line..=block.end_line {
@@ -702,14 +658,6 @@ impl<'a> LintContext<'a> {
                 }
             }
         }
-        for block in &table_blocks {
-            // TableBlock line numbers are 0-indexed.
-            for idx in block.start_line..=block.end_line {
-                if let Some(li) = lines.get_mut(idx) {
-                    li.in_table_block = true;
-                }
-            }
-        }
 
         // Rebuild reference_defs_map after filtering
         let reference_defs_map: HashMap<String, usize> = reference_defs
@@ -862,17 +810,12 @@ impl<'a> LintContext<'a> {
             }
         });
 
-        // Pre-compute Hugo/Quarto shortcode ranges ({{< ... >}} and {{% ... %}})
-        let shortcode_ranges = profile_section!("Shortcode ranges", profile, {
-            use crate::utils::regex_cache::HUGO_SHORTCODE_REGEX;
-            let mut ranges = Vec::new();
-            for mat in HUGO_SHORTCODE_REGEX.find_iter(content) {
-                ranges.push((mat.start(), mat.end()));
-            }
-            ranges
-        });
-
-        let inline_config = InlineConfig::from_content_with_code_blocks(content, &code_blocks);
+        let front_matter_config = if front_matter_end > 0 {
+            FrontMatterConfig::from_content(content)
+        } else {
+            FrontMatterConfig::default()
+        };
+        let inline_config = InlineConfig::from_content_with_front_matter(content, &code_blocks, &front_matter_config);
 
         Self {
             content,
@@ -884,10 +827,8 @@ impl<'a> LintContext<'a> {
             line_to_list,
             list_start_values,
             lines,
-            links,
-            images,
-            broken_links,
-            footnote_refs,
+            pending_links: Mutex::new(pulldown_result),
+            link_collections_cache: OnceLock::new(),
             reference_defs,
             reference_defs_map,
             code_spans_cache: OnceLock::from(Arc::new(code_spans)),
@@ -902,7 +843,7 @@ impl<'a> LintContext<'a> {
             bare_urls_cache: OnceLock::new(),
             has_mixed_list_nesting_cache: OnceLock::new(),
             html_comment_ranges,
-            table_blocks,
+            table_blocks_cache: OnceLock::new(),
             line_index,
             jinja_ranges,
             flavor,
@@ -924,10 +865,11 @@ impl<'a> LintContext<'a> {
             pandoc_metadata_ranges,
             grid_table_ranges,
             multi_line_table_ranges,
-            shortcode_ranges,
+            shortcode_ranges_cache: OnceLock::new(),
             link_title_ranges,
             code_span_byte_ranges: code_span_ranges,
             inline_config,
+            front_matter_config,
             obsidian_comment_ranges,
             lazy_cont_lines_cache: OnceLock::new(),
             myst_directive_ranges,
@@ -962,12 +904,14 @@ impl<'a> LintContext<'a> {
 
     /// Check if `pos` is inside any link byte range. O(log n).
     pub fn is_in_link(&self, pos: usize) -> bool {
-        let idx = self.links.partition_point(|link| link.byte_offset <= pos);
-        if idx > 0 && pos < self.links[idx - 1].byte_end {
+        let links = self.links();
+        let idx = links.partition_point(|link| link.byte_offset <= pos);
+        if idx > 0 && pos < links[idx - 1].byte_end {
             return true;
         }
-        let idx = self.images.partition_point(|img| img.byte_offset <= pos);
-        if idx > 0 && pos < self.images[idx - 1].byte_end {
+        let images = self.images();
+        let idx = images.partition_point(|img| img.byte_offset <= pos);
+        if idx > 0 && pos < images[idx - 1].byte_end {
             return true;
         }
         self.is_in_reference_def(pos)
@@ -978,6 +922,20 @@ impl<'a> LintContext<'a> {
         &self.inline_config
     }
 
+    /// Get the rule overrides declared in front matter (`rumdl:` and `draft:`).
+    ///
+    /// These are already applied through [`Self::inline_config`]; rules only need
+    /// this to tell front matter overrides apart from comments, e.g. for drafts.
+    pub fn front_matter_config(&self) -> &FrontMatterConfig {
+        &self.front_matter_config
+    }
+
+    /// Take the inline configuration out, leaving none, so rules report the
+    /// warnings inline comments would suppress
+    pub(crate) fn take_inline_config(&mut self) -> InlineConfig {
+        std::mem::take(&mut self.inline_config)
+    }
+
     /// Byte ranges of Azure DevOps colon code fences (`:::lang … :::`).
     /// Empty for all other flavors.
     pub fn colon_fence_ranges(&self) -> &[(usize, usize)] {
@@ -1149,6 +1107,102 @@ impl<'a> LintContext<'a> {
         )
     }
 
+    /// Finish link and image parsing on first access: run the pulldown-cmark
+    /// parse unless heading detection already did, filter by code spans, run the
+    /// regex fallbacks, and drop items inside kramdown extension blocks.
+    /// Rule sets that never look at links (e.g. whitespace-only runs) skip this.
+    fn link_collections(&self) -> &LinkCollections<'a> {
+        self.link_collections_cache.get_or_init(|| {
+            let pulldown_result = self
+                .pending_links
+                .lock()
+                .ok()
+                .and_then(|mut pending| pending.take())
+                .unwrap_or_else(|| {
+                    link_parser::parse_links_images_pulldown(
+                        self.content,
+                        &self.lines,
+                        &self.code_blocks,
+                        self.flavor,
+                        &self.html_comment_ranges,
+                    )
+                });
+            let (links, images, broken_links, footnote_refs) = link_parser::finalize_links_and_images(
+                self.content,
+                &self.lines,
+                &self.code_blocks,
+                &self.code_spans(),
+                self.flavor,
+                &self.html_comment_ranges,
+                pulldown_result,
+            );
+
+            // Layer 2: exclude items inside kramdown extension blocks
+            let in_kramdown = |line: usize| {
+                self.lines
+                    .get(line.wrapping_sub(1))
+                    .is_some_and(|l| l.in_kramdown_extension_block)
+            };
+            LinkCollections {
+                links: links.into_iter().filter(|link| !in_kramdown(link.line)).collect(),
+                images: images.into_iter().filter(|img| !in_kramdown(img.line)).collect(),
+                broken_links: broken_links
+                    .into_iter()
+                    .filter(|bl| {
+                        // BrokenLinkInfo has span but no line field; find line from byte offset
+                        let line_idx = self
+                            .line_offsets
+                            .partition_point(|&offset| offset <= bl.span.start)
+                            .saturating_sub(1);
+                        !in_kramdown(line_idx + 1)
+                    })
+                    .collect(),
+                footnote_refs: footnote_refs.into_iter().filter(|fr| !in_kramdown(fr.line)).collect(),
+            }
+        })
+    }
+
+    /// Get parsed links - finalized lazily on first access
+    pub fn links(&self) -> &[ParsedLink<'a>] {
+        &self.link_collections().links
+    }
+
+    /// Get parsed images - finalized lazily on first access
+    pub fn images(&self) -> &[ParsedImage<'a>] {
+        &self.link_collections().images
+    }
+
+    /// Get broken/undefined references - finalized lazily on first access
+    pub fn broken_links(&self) -> &[BrokenLinkInfo] {
+        &self.link_collections().broken_links
+    }
+
+    /// Get footnote references - finalized lazily on first access
+    pub fn footnote_refs(&self) -> &[FootnoteRef] {
+        &self.link_collections().footnote_refs
+    }
+
+    /// Get table blocks - computed lazily on first access (MD013, MD055, MD056, MD058, MD060, ...)
+    pub fn table_blocks(&self) -> &[crate::utils::table_utils::TableBlock] {
+        self.table_blocks_cache.get_or_init(|| {
+            crate::utils::table_utils::TableUtils::find_table_blocks_with_code_info(
+                self.content,
+                &self.code_blocks,
+                &self.code_spans(),
+                &self.html_comment_ranges,
+            )
+            .into_iter()
+            .filter(|block| {
+                // TableBlock.start_line is 0-indexed
+                !self
+                    .lines
+                    .get(block.start_line)
+                    .is_some_and(|l| l.in_kramdown_extension_block)
+            })
+            .collect()
+        })
+    }
+
     /// Get bare URLs - computed lazily on first access
     pub fn bare_urls(&self) -> Arc<Vec<BareUrl>> {
         Arc::clone(self.bare_urls_cache.get_or_init(|| {
@@ -1317,7 +1371,11 @@ impl<'a> LintContext<'a> {
         if line_num == 0 || line_num > self.lines.len() {
             return false;
         }
-        self.lines[line_num - 1].in_table_block
+        // Table blocks are sorted and disjoint; their line numbers are 0-indexed.
+        let idx = line_num - 1;
+        let blocks = self.table_blocks();
+        let pos = blocks.partition_point(|block| block.start_line <= idx);
+        pos > 0 && idx <= blocks[pos - 1].end_line
     }
 
     /// Check if a line and column is within a code span
@@ -1548,13 +1606,18 @@ impl<'a> LintContext<'a> {
     /// Check if a byte position is within a Hugo/Quarto shortcode ({{< ... >}} or {{% ... %}}). O(log n).
     #[inline]
     pub fn is_in_shortcode(&self, byte_pos: usize) -> bool {
-        Self::binary_search_ranges(&self.shortcode_ranges, byte_pos)
+        Self::binary_search_ranges(self.shortcode_ranges(), byte_pos)
     }
 
-    /// Pre-computed Hugo/Quarto shortcode ranges.
-    #[inline]
+    /// Hugo/Quarto shortcode ranges, computed lazily on first access.
     pub fn shortcode_ranges(&self) -> &[(usize, usize)] {
-        &self.shortcode_ranges
+        self.shortcode_ranges_cache.get_or_init(|| {
+            use crate::utils::regex_cache::HUGO_SHORTCODE_REGEX;
+            HUGO_SHORTCODE_REGEX
+                .find_iter(self.content)
+                .map(|mat| (mat.start(), mat.end()))
+                .collect()
+        })
     }
 
     /// Check if a byte position is within a link reference definition title. O(log n).
@@ -1650,7 +1713,7 @@ impl<'a> LintContext<'a> {
         if let Some(line_info) = self.lines.get(line_idx)
             && let Some(ref bq) = line_info.blockquote
         {
-            bq.prefix.trim_end().to_string()
+            bq.prefix(self.content).trim_end().to_string()
         } else {
             String::new()
         }
@@ -1724,7 +1787,7 @@ impl<'a> LintContext<'a> {
     /// let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
     ///
     /// for heading in ctx.valid_headings() {
-    ///     println!("Line {}: {} (level {})", heading.line_num, heading.heading.text, heading.heading.level);
+    ///     println!("Line {}: {} (level {})", heading.line_num, heading.heading.text(content), heading.heading.level);
     /// }
     /// // Only prints valid headings, skips `#NoSpace`
     /// ```
@@ -76,7 +76,7 @@ fn test_list_item_detection() {
     let line1 = &ctx.lines[0];
     assert!(line1.list_item.is_some());
     let list1 = line1.list_item.as_ref().unwrap();
-    assert_eq!(list1.marker, "-");
+    assert_eq!(list1.marker(ctx.content), "-");
     assert!(!list1.is_ordered);
     assert_eq!(list1.marker_column, 0);
     assert_eq!(list1.content_column, 2);
@@ -85,14 +85,14 @@ fn test_list_item_detection() {
     let line2 = &ctx.lines[1];
     assert!(line2.list_item.is_some());
     let list2 = line2.list_item.as_ref().unwrap();
-    assert_eq!(list2.marker, "*");
+    assert_eq!(list2.marker(ctx.content), "*");
     assert_eq!(list2.marker_column, 2);
 
     // Line 3: "1. Ordered item"
     let line3 = &ctx.lines[2];
     assert!(line3.list_item.is_some());
     let list3 = line3.list_item.as_ref().unwrap();
-    assert_eq!(list3.marker, "1.");
+    assert_eq!(list3.marker(ctx.content), "1.");
     assert!(list3.is_ordered);
     assert_eq!(list3.number, Some(1));
 
@@ -201,16 +201,43 @@ fn test_blockquote_with_indented_content() {
     // Verify blockquote content is correctly parsed
     // Note: spaces_after includes the spaces between `>` and content
     let bq3 = ctx.lines.get(2).unwrap().blockquote.as_ref().unwrap();
-    assert_eq!(bq3.content, "-S socket-path");
+    assert_eq!(bq3.content(content), "-S socket-path");
     assert_eq!(bq3.nesting_level, 1);
     // 6 spaces after the `>` marker
     assert!(bq3.has_multiple_spaces_after_marker);
 
     let bq4 = ctx.lines.get(3).unwrap().blockquote.as_ref().unwrap();
-    assert_eq!(bq4.content, "More text");
+    assert_eq!(bq4.content(content), "More text");
     assert_eq!(bq4.nesting_level, 1);
 }
 
+#[test]
+fn test_heading_text_accessors() {
+    let content = "## Title ## {#custom}\n\n## Old <a name=\"old\"></a>Name\n\nSetext {#id}\n---\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+
+    let atx = ctx.lines[0].heading.as_ref().unwrap();
+    assert_eq!(atx.marker(content), "##");
+    assert_eq!(atx.raw_text(content), "Title {#custom}");
+    assert_eq!(atx.text(content), "Title");
+    assert_eq!(atx.custom_id(content), Some("custom"));
+    assert_eq!(atx.closing_sequence(content), "##");
+
+    // Removing the anchor joins text from both sides of it, so the text is owned
+    let anchored = ctx.lines[2].heading.as_ref().unwrap();
+    assert_eq!(anchored.raw_text(content), "Old <a name=\"old\"></a>Name");
+    assert_eq!(anchored.text(content), "Old Name");
+    assert!(matches!(anchored.text_source, SourceText::Owned(_)));
+    assert!(matches!(atx.text_source, SourceText::Span(_)));
+    assert_eq!(anchored.custom_id(content), None);
+    assert_eq!(anchored.closing_sequence(content), "");
+
+    let setext = ctx.lines[4].heading.as_ref().unwrap();
+    assert_eq!(setext.marker(content), "---");
+    assert_eq!(setext.text(content), "Setext");
+    assert_eq!(setext.custom_id(content), Some("id"));
+}
+
 #[test]
 fn test_blockquote_spaced_nested_markers_are_detected() {
     let content = r#"> > Nested quote content
@@ -220,13 +247,13 @@ fn test_blockquote_spaced_nested_markers_are_detected() {
 
     let bq1 = ctx.lines.first().unwrap().blockquote.as_ref().unwrap();
     assert_eq!(bq1.nesting_level, 2);
-    assert_eq!(bq1.prefix, "> > ");
-    assert_eq!(bq1.content, "Nested quote content");
+    assert_eq!(bq1.prefix(content), "> > ");
+    assert_eq!(bq1.content(content), "Nested quote content");
 
     let bq2 = ctx.lines.get(1).unwrap().blockquote.as_ref().unwrap();
     assert_eq!(bq2.nesting_level, 2);
-    assert_eq!(bq2.prefix, "> > ");
-    assert_eq!(bq2.content, "Additional line");
+    assert_eq!(bq2.prefix(content), "> > ");
+    assert_eq!(bq2.content(content), "Additional line");
 }
 
 #[test]
@@ -391,7 +418,7 @@ fn test_mkdocs_admonition_link_with_paren_title() {
     let content = "!!! note\n    See [doc](https://example.com (paren title)) here.\n";
     let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
     let link = ctx
-        .links
+        .links()
         .iter()
         .find(|l| l.url == "https://example.com")
         .expect("MkDocs fallback must surface the link");
@@ -408,7 +435,7 @@ fn test_mkdocs_admonition_image_with_paren_title() {
     let content = "!!! note\n    See ![alt](https://example.com/x.png (paren title)) here.\n";
     let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
     let img = ctx
-        .images
+        .images()
         .iter()
         .find(|i| i.url == "https://example.com/x.png")
         .expect("MkDocs fallback must surface the image");
@@ -1469,16 +1496,16 @@ fn test_html_block_unclosed_pre_extends_to_eof() {
 #[test]
 fn test_link_no_title_yields_none() {
     let ctx = LintContext::new("[t](https://x.com)\n", MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
-    assert!(ctx.links[0].title.is_none(), "no title delimiter must be None");
+    assert_eq!(ctx.links().len(), 1);
+    assert!(ctx.links()[0].title.is_none(), "no title delimiter must be None");
 }
 
 #[test]
 fn test_link_explicit_empty_double_quote_title_yields_some_empty() {
     let ctx = LintContext::new(r#"[t](https://x.com "")"#, MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
+    assert_eq!(ctx.links().len(), 1);
     assert_eq!(
-        ctx.links[0].title.as_deref(),
+        ctx.links()[0].title.as_deref(),
         Some(""),
         "`\"\"` must be preserved as Some(\"\"), not collapsed to None"
     );
@@ -1487,29 +1514,29 @@ fn test_link_explicit_empty_double_quote_title_yields_some_empty() {
 #[test]
 fn test_link_explicit_empty_single_quote_title_yields_some_empty() {
     let ctx = LintContext::new("[t](https://x.com '')\n", MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
-    assert_eq!(ctx.links[0].title.as_deref(), Some(""));
+    assert_eq!(ctx.links().len(), 1);
+    assert_eq!(ctx.links()[0].title.as_deref(), Some(""));
 }
 
 #[test]
 fn test_link_explicit_empty_paren_title_yields_some_empty() {
     let ctx = LintContext::new("[t](https://x.com ())\n", MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
-    assert_eq!(ctx.links[0].title.as_deref(), Some(""));
+    assert_eq!(ctx.links().len(), 1);
+    assert_eq!(ctx.links()[0].title.as_deref(), Some(""));
 }
 
 #[test]
 fn test_image_explicit_empty_title_yields_some_empty() {
     let ctx = LintContext::new(r#"![alt](https://x.com/img.png "")"#, MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.images.len(), 1);
-    assert_eq!(ctx.images[0].title.as_deref(), Some(""));
+    assert_eq!(ctx.images().len(), 1);
+    assert_eq!(ctx.images()[0].title.as_deref(), Some(""));
 }
 
 #[test]
 fn test_link_non_empty_title_is_unaffected() {
     let ctx = LintContext::new(r#"[t](https://x.com "real")"#, MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
-    assert_eq!(ctx.links[0].title.as_deref(), Some("real"));
+    assert_eq!(ctx.links().len(), 1);
+    assert_eq!(ctx.links()[0].title.as_deref(), Some("real"));
 }
 
 #[test]
@@ -1518,8 +1545,8 @@ fn test_link_title_with_trailing_whitespace_inside_parens() {
     // the link's closing `)`. The detector must skip that whitespace so it
     // still recognizes the explicit-empty-title pair.
     let ctx = LintContext::new(r#"[t](https://x.com ""    )"#, MarkdownFlavor::Standard, None);
-    assert_eq!(ctx.links.len(), 1);
-    assert_eq!(ctx.links[0].title.as_deref(), Some(""));
+    assert_eq!(ctx.links().len(), 1);
+    assert_eq!(ctx.links()[0].title.as_deref(), Some(""));
 }
 
 #[test]
@@ -1934,3 +1961,36 @@ fn test_standard_flavor_skips_multi_line_table() {
     let pos = content.find("First").unwrap();
     assert!(!ctx.is_in_multi_line_table(pos));
 }
+
+#[test]
+fn test_links_and_tables_are_computed_lazily() {
+    let content =
+        "# Title\n\nSee [docs](https://example.com) and ![logo](logo.png).\n\n| a | b |\n| - | - |\n| 1 | 2 |\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+
+    // Nothing is parsed or finalized until a rule asks for it
+    assert!(ctx.pending_links.lock().unwrap().is_none());
+    assert!(ctx.link_collections_cache.get().is_none());
+    assert!(ctx.table_blocks_cache.get().is_none());
+
+    assert_eq!(ctx.links().len(), 1);
+    assert_eq!(ctx.images().len(), 1);
+    assert!(ctx.link_collections_cache.get().is_some());
+    assert!(ctx.table_blocks_cache.get().is_none());
+
+    assert!(!ctx.is_in_table_block(3));
+    assert!(ctx.is_in_table_block(5));
+    assert!(ctx.is_in_table_block(7));
+    assert_eq!(ctx.table_blocks().len(), 1);
+}
+
+#[test]
+fn test_link_parse_stays_eager_when_heading_may_be_inside_link() {
+    // `#tag` continues the link text, so heading detection needs the link
+    // byte ranges up front to avoid reporting it as a heading.
+    let content = "Intro [multi\n#tag inside](https://example.com) text\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    assert!(ctx.pending_links.lock().unwrap().is_some());
+    assert!(ctx.lines[1].heading.is_none());
+    assert_eq!(ctx.links().len(), 1);
+}
@@ -1,5 +1,6 @@
 use pulldown_cmark::LinkType;
 use std::borrow::Cow;
+use std::ops::Range;
 
 /// Pre-computed information about a line
 #[derive(Debug, Clone)]
@@ -24,8 +25,6 @@ pub struct LineInfo {
     pub in_html_block: bool,
     /// Whether this line is part of a list block (precomputed for O(1) lookup)
     pub in_list_block: bool,
-    /// Whether this line is part of a table block (precomputed for O(1) lookup)
-    pub in_table_block: bool,
     /// Whether this line is inside an HTML comment
     pub in_html_comment: bool,
     /// List item information if this line starts a list item
@@ -127,8 +126,6 @@ impl LineInfo {
 /// Information about a list item
 #[derive(Debug, Clone)]
 pub struct ListItemInfo {
-    /// The marker used (*, -, +, or number with . or ))
-    pub marker: String,
     /// Whether it's ordered (true) or unordered (false)
     pub is_ordered: bool,
     /// The number for ordered lists
@@ -137,6 +134,20 @@ pub struct ListItemInfo {
     pub marker_column: usize,
     /// Column where content after marker starts
     pub content_column: usize,
+    /// Byte offsets of the marker in the document
+    pub(crate) marker_span: Range<usize>,
+}
+
+impl ListItemInfo {
+    /// The marker used (*, -, +, or number with . or )) as a slice of the source document
+    pub fn marker<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.marker_span.clone()]
+    }
+
+    /// Length of the marker in bytes
+    pub fn marker_len(&self) -> usize {
+        self.marker_span.len()
+    }
 }
 
 /// Heading style type
@@ -249,6 +260,19 @@ pub struct ReferenceDef {
     pub title_byte_end: Option<usize>,
 }
 
+impl ReferenceDef {
+    /// Byte range of the URL in `content`, falling back to the start of the
+    /// definition when the URL was written with escapes or angle brackets
+    pub fn url_range(&self, content: &str) -> std::ops::Range<usize> {
+        let source = &content[self.byte_offset..self.byte_end];
+        let start = source
+            .find("]:")
+            .and_then(|colon| source[colon..].find(self.url.as_str()).map(|i| colon + i))
+            .map_or(self.byte_offset, |i| self.byte_offset + i);
+        start..(start + self.url.len()).min(self.byte_end)
+    }
+}
+
 /// Parsed code span information
 #[derive(Debug, Clone)]
 pub struct CodeSpan {
@@ -298,25 +322,80 @@ pub struct HeadingInfo {
     pub level: u8,
     /// Style of heading
     pub style: HeadingStyle,
-    /// The heading marker (# characters or underline)
-    pub marker: String,
     /// Column where the marker starts (0-based)
     pub marker_column: usize,
     /// Column where heading text starts
     pub content_column: usize,
-    /// The heading text (without markers and without custom ID syntax)
-    pub text: String,
-    /// Custom header ID if present (e.g., from {#custom-id} syntax)
-    pub custom_id: Option<String>,
-    /// Original heading text including custom ID syntax
-    pub raw_text: String,
     /// Whether it has a closing sequence (for ATX)
     pub has_closing_sequence: bool,
-    /// The closing sequence if present
-    pub closing_sequence: String,
     /// Whether this is a valid CommonMark heading (ATX headings require space after #)
     /// False for malformed headings like `#NoSpace` that MD018 should flag
     pub is_valid: bool,
+    /// Byte offsets of the marker (# characters or underline) in the document
+    pub(crate) marker_span: Range<usize>,
+    pub(crate) text_source: SourceText,
+    pub(crate) custom_id_source: Option<SourceText>,
+    pub(crate) raw_text_source: SourceText,
+    /// Byte offsets of the closing sequence, empty if there is none
+    pub(crate) closing_sequence_span: Range<usize>,
+}
+
+impl HeadingInfo {
+    /// The heading marker (# characters or underline) as a slice of the source document
+    pub fn marker<'a>(&'a self, source: &'a str) -> &'a str {
+        &source[self.marker_span.clone()]
+    }
+
+    /// Length of the marker in bytes
+    pub fn marker_len(&self) -> usize {
+        self.marker_span.len()
+    }
+
+    /// The heading text (without markers and without custom ID syntax)
+    pub fn text<'a>(&'a self, source: &'a str) -> &'a str {
+        self.text_source.get(source)
+    }
+
+    /// Custom header ID if present (e.g., from {#custom-id} syntax)
+    pub fn custom_id<'a>(&'a self, source: &'a str) -> Option<&'a str> {
+        self.custom_id_source.as_ref().map(|id| id.get(source))
+    }
+
+    /// Original heading text including custom ID syntax
+    pub fn raw_text<'a>(&'a self, source: &'a str) -> &'a str {
+        self.raw_text_source.get(source)
+    }
+
+    /// The closing sequence, empty if there is none
+    pub fn closing_sequence<'a>(&'a self, source: &'a str) -> &'a str {
+        &source[self.closing_sequence_span.clone()]
+    }
+}
+
+/// Text derived from the source document: a span of it, or an owned copy
+/// when markup inside the text (such as an HTML anchor) makes it non-contiguous
+#[derive(Debug, Clone)]
+pub(crate) enum SourceText {
+    Span(Range<usize>),
+    Owned(Box<str>),
+}
+
+impl SourceText {
+    /// `text` as a span of `haystack`, which starts at byte `offset` of the
+    /// document, or an owned copy when `haystack` does not contain it
+    pub(crate) fn find(haystack: &str, offset: usize, text: &str) -> Self {
+        match haystack.find(text) {
+            Some(start) => Self::Span(offset + start..offset + start + text.len()),
+            None => Self::Owned(text.into()),
+        }
+    }
+
+    pub(crate) fn get<'a>(&'a self, source: &'a str) -> &'a str {
+        match self {
+            Self::Span(span) => &source[span.clone()],
+            Self::Owned(text) => text,
+        }
+    }
 }
 
 /// A valid heading from a filtered iteration
@@ -381,12 +460,31 @@ pub struct BlockquoteInfo {
     pub nesting_level: usize,
     /// Column where the first > starts (0-based)
     pub marker_column: usize,
-    /// The blockquote prefix (e.g., "> ", ">> ", etc.)
-    pub prefix: String,
-    /// Content after the blockquote marker(s)
-    pub content: String,
     /// Whether the line has multiple spaces after the marker
     pub has_multiple_spaces_after_marker: bool,
+    /// Byte offset where the line starts in the document
+    pub(crate) line_start: usize,
+    /// Byte offset where the content after the prefix starts
+    pub(crate) content_start: usize,
+    /// Byte offset where the line ends (without newline)
+    pub(crate) line_end: usize,
+}
+
+impl BlockquoteInfo {
+    /// The blockquote prefix (e.g., "> ", ">> ", etc.) as a slice of the source document
+    pub fn prefix<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.line_start..self.content_start]
+    }
+
+    /// Content after the blockquote marker(s) as a slice of the source document
+    pub fn content<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.content_start..self.line_end]
+    }
+
+    /// Length of the prefix in bytes
+    pub fn prefix_len(&self) -> usize {
+        self.content_start - self.line_start
+    }
 }
 
 /// Information about a list block
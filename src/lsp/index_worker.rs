@@ -14,7 +14,7 @@ use tower_lsp::Client;
 use tower_lsp::lsp_types::*;
 
 use crate::config::{Config, MarkdownFlavor};
-use crate::discovery::{ExcludeMatchers, MarkdownWalkOptions, is_markdown_extension, path_relative_to};
+use crate::discovery::{ExcludeMatchers, MarkdownWalkOptions, include_overrides, path_relative_to};
 use crate::lint_context::LintContext;
 use crate::lsp::types::{IndexState, IndexUpdate};
 use crate::utils::anchor_styles::AnchorStyle;
@@ -24,13 +24,16 @@ use crate::workspace_index::{FileIndex, HeadingIndex, WorkspaceIndex, extract_cr
 ///
 /// Mirrors CLI discovery (gitignore handling driven by
 /// `global.respect_gitignore`, hidden files included, `.markdownlintignore`
-/// honored) with one deliberate divergence: `.git`/`node_modules`/`target`
+/// honored, `global.extensions` and `global.include` selecting the files)
+/// with one deliberate divergence: `.git`/`node_modules`/`target`
 /// are always skipped as an editor-performance safety net, even when not
 /// gitignored.
 pub(super) fn index_walk_options(config: &Config) -> MarkdownWalkOptions {
     MarkdownWalkOptions {
         respect_gitignore: config.global.respect_gitignore,
         skip_vendor_dirs: true,
+        extensions: config.global.extensions.clone(),
+        include: config.global.include.clone(),
     }
 }
 
@@ -184,7 +187,7 @@ impl IndexWorker {
         // Extract headings from the content
         for (line_num, line_info) in ctx.lines.iter().enumerate() {
             if let Some(heading) = &line_info.heading {
-                let auto_anchor = AnchorStyle::GitHub.generate_fragment(&heading.text);
+                let auto_anchor = AnchorStyle::GitHub.generate_fragment(heading.text(ctx.content));
                 let is_setext = matches!(
                     heading.style,
                     crate::lint_context::types::HeadingStyle::Setext1
@@ -192,9 +195,9 @@ impl IndexWorker {
                 );
 
                 file_index.add_heading(HeadingIndex {
-                    text: heading.text.clone(),
+                    text: heading.text(ctx.content).to_string(),
                     auto_anchor,
-                    custom_anchor: heading.custom_id.clone(),
+                    custom_anchor: heading.custom_id(ctx.content).map(str::to_string),
                     line: line_num + 1, // 1-indexed
                     is_setext,
                 });
@@ -397,8 +400,8 @@ async fn scan_markdown_files(
 }
 
 /// Collect markdown files from the given roots, respecting ignore files and
-/// config `exclude` patterns (matched relative to each root, like the CLI
-/// matches them relative to the project root).
+/// config `include`/`exclude` patterns (matched relative to each root, like
+/// the CLI matches them relative to the project root).
 fn collect_markdown_files(
     roots: &[PathBuf],
     options: &MarkdownWalkOptions,
@@ -407,13 +410,14 @@ fn collect_markdown_files(
     let mut files = Vec::new();
 
     for root in roots {
+        let include = include_overrides(root, &options.include);
         for result in crate::discovery::markdown_walk_builder(root, options).build() {
             match result {
                 Ok(entry) => {
                     let path = entry.path();
                     if entry.file_type().is_some_and(|t| t.is_file())
-                        && let Some(ext) = path.extension()
-                        && is_markdown_extension(ext)
+                        && options.is_markdown_path(path)
+                        && included(include.as_ref(), path)
                         && !excluded_relative_to_root(excludes, path, root)
                     {
                         files.push(path.to_path_buf());
@@ -427,6 +431,11 @@ fn collect_markdown_files(
     files
 }
 
+/// Whether `path` matches the config `include` patterns, if there are any.
+fn included(include: Option<&ignore::overrides::Override>, path: &Path) -> bool {
+    include.is_none_or(|overrides| overrides.matched(path, false).is_whitelist())
+}
+
 /// Whether `path` matches the config `exclude` patterns, matched against its
 /// root-relative form. Paths that cannot be relativized are not excluded.
 fn excluded_relative_to_root(excludes: &ExcludeMatchers, path: &Path, root: &Path) -> bool {
@@ -483,8 +492,10 @@ pub(super) fn path_is_ignored_for_index(
         return true;
     }
 
-    // Config exclude patterns, matched root-relative like the full scan.
-    if excluded_relative_to_root(excludes, path, root) {
+    // Config include/exclude patterns, matched root-relative like the full scan.
+    if !included(include_overrides(root, &options.include).as_ref(), path)
+        || excluded_relative_to_root(excludes, path, root)
+    {
         return true;
     }
 
@@ -722,6 +733,49 @@ More text with [link](./other.md#section).
         assert_eq!(names, vec!["guide.markdown".to_string(), "top.md".to_string()]);
     }
 
+    #[test]
+    fn test_collect_markdown_files_honors_extensions_and_include() {
+        use std::fs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        fs::write(root.join("top.md"), "# Top\n").unwrap();
+        fs::create_dir(root.join("docs")).unwrap();
+        fs::write(root.join("docs").join("guide.md"), "# Guide\n").unwrap();
+        fs::write(root.join("docs").join("notes.TXT"), "# Notes\n").unwrap();
+        fs::write(root.join("docs").join("old.markdown"), "# Old\n").unwrap();
+
+        let mut config = Config::default();
+        config.global.extensions = vec!["md".to_string(), ".txt".to_string()];
+        config.global.include = vec!["docs/**".to_string()];
+        let options = index_walk_options(&config);
+        let mut names: Vec<String> =
+            collect_markdown_files(&[root.to_path_buf()], &options, &ExcludeMatchers::new(&[]))
+                .iter()
+                .map(|p| p.file_name().unwrap().to_str().unwrap().to_string())
+                .collect();
+        names.sort();
+
+        // The index covers the files the CLI lints: configured extensions
+        // only, narrowed to the include patterns.
+        assert_eq!(names, vec!["guide.md".to_string(), "notes.TXT".to_string()]);
+        let roots = [root.to_path_buf()];
+        let no_excludes = ExcludeMatchers::new(&[]);
+        assert!(path_is_ignored_for_index(
+            &roots,
+            &root.join("top.md"),
+            &options,
+            &no_excludes
+        ));
+        assert!(!path_is_ignored_for_index(
+            &roots,
+            &root.join("docs").join("guide.md"),
+            &options,
+            &no_excludes
+        ));
+    }
+
     #[test]
     fn test_path_is_ignored_for_index() {
         use std::fs;
@@ -9,11 +9,11 @@ use tower_lsp::lsp_types::*;
 
 use crate::code_block_tools::CodeBlockToolProcessor;
 use crate::embedded_lint::{check_embedded_markdown_blocks, should_lint_embedded_markdown};
-use crate::rule::FixCapability;
+use crate::rule::{Applicability, FixCapability, FixSafety};
 use crate::rules;
 
 use super::server::RumdlLanguageServer;
-use super::types::{IndexState, warning_to_code_actions_with_md013_config, warning_to_diagnostic};
+use super::types::{IndexState, warning_to_code_actions_with_md013_config, warning_to_diagnostic_with_docs};
 use crate::rules::md013_line_length::MD013Config;
 
 impl RumdlLanguageServer {
@@ -117,15 +117,36 @@ impl RumdlLanguageServer {
             }
         }
 
-        // Run rumdl linting with the configured flavor
-        let mut all_warnings = match crate::lint(
-            text,
-            &filtered_rules,
-            false,
-            flavor,
-            file_path.clone(),
-            Some(&rumdl_config),
-        ) {
+        // Run rumdl linting with the configured flavor. Documents open in the
+        // editor recheck only the blocks that changed since their last lint.
+        let open_in_editor = self
+            .documents
+            .read()
+            .await
+            .get(uri)
+            .is_some_and(|entry| !entry.from_disk);
+        let result = if open_in_editor {
+            let mut block_caches = self.block_caches.write().await;
+            crate::lint_with_block_cache(
+                text,
+                &filtered_rules,
+                false,
+                flavor,
+                file_path.clone(),
+                Some(&rumdl_config),
+                block_caches.entry(uri.clone()).or_default(),
+            )
+        } else {
+            crate::lint(
+                text,
+                &filtered_rules,
+                false,
+                flavor,
+                file_path.clone(),
+                Some(&rumdl_config),
+            )
+        };
+        let mut all_warnings = match result {
             Ok(warnings) => warnings,
             Err(e) => {
                 log::error!("Failed to lint document {uri}: {e}");
@@ -190,7 +211,11 @@ impl RumdlLanguageServer {
             }
         }
 
-        let diagnostics = all_warnings.iter().map(warning_to_diagnostic).collect();
+        let docs_links = crate::output::DocsLinks::from_config(&rumdl_config);
+        let diagnostics = all_warnings
+            .iter()
+            .map(|warning| warning_to_diagnostic_with_docs(warning, &docs_links))
+            .collect();
         Ok(diagnostics)
     }
 
@@ -275,7 +300,7 @@ impl RumdlLanguageServer {
             &[],
             &mut fixed_text,
             &rumdl_config,
-            100,
+            rumdl_config.global.fix_max_iterations,
             file_path.as_deref(),
         ) {
             log::warn!("Failed to apply fixes: {e}");
@@ -407,7 +432,22 @@ impl RumdlLanguageServer {
             file_path.clone(),
             Some(&rumdl_config),
         ) {
-            Ok(warnings) => {
+            Ok(mut warnings) => {
+                // Without `unsafe-fixes`, unsafe fixes are only offered as
+                // suggested edits, so fix-all and preferred quick fixes skip them
+                if !rumdl_config.global.unsafe_fixes {
+                    for warning in &mut warnings {
+                        let is_unsafe = warning.rule_name.as_deref().is_some_and(|name| {
+                            filtered_rules
+                                .iter()
+                                .any(|r| r.name() == name && r.fix_safety() == FixSafety::Unsafe)
+                        });
+                        if is_unsafe && let Some(fix) = warning.fix.as_mut() {
+                            fix.applicability = Applicability::Suggested;
+                        }
+                    }
+                }
+
                 let mut actions = Vec::new();
 
                 for warning in &warnings {
@@ -428,7 +468,7 @@ impl RumdlLanguageServer {
 
                 // Count fixable warnings across the entire document for the fixAll gate.
                 // source.fixAll.rumdl applies to the whole file, not just the requested range.
-                let fixable_count = warnings.iter().filter(|w| w.fix.is_some()).count();
+                let fixable_count = warnings.iter().filter(|w| w.automatic_fix().is_some()).count();
 
                 if fixable_count > 0 {
                     // Only apply fixes from fixable rules during "Fix all"
@@ -436,6 +476,9 @@ impl RumdlLanguageServer {
                     let fixable_warnings: Vec<_> = warnings
                         .iter()
                         .filter(|w| {
+                            if w.automatic_fix().is_none() {
+                                return false;
+                            }
                             if let Some(rule_name) = &w.rule_name {
                                 filtered_rules
                                     .iter()
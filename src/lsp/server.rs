@@ -13,8 +13,9 @@ use tower_lsp::jsonrpc::Result as JsonRpcResult;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
+use crate::block_cache::BlockCache;
 use crate::config::{Config, is_valid_rule_name};
-use crate::discovery::{ExcludeMatchers, is_markdown_extension};
+use crate::discovery::ExcludeMatchers;
 use crate::lsp::index_worker::IndexWorker;
 use crate::lsp::types::{IndexState, IndexUpdate, LspRuleSettings, RumdlLspConfig};
 use crate::workspace_index::WorkspaceIndex;
@@ -65,6 +66,8 @@ pub struct RumdlLanguageServer {
     pub(crate) rumdl_config: Arc<RwLock<Config>>,
     /// Document store for open files and cached disk files
     pub(crate) documents: Arc<RwLock<HashMap<Url, DocumentEntry>>>,
+    /// Rule results for the unchanged blocks of open documents
+    pub(crate) block_caches: Arc<RwLock<HashMap<Url, BlockCache>>>,
     /// Workspace root folders from the client
     pub(crate) workspace_roots: Arc<RwLock<Vec<PathBuf>>>,
     /// Configuration cache: maps directory path to resolved config
@@ -125,6 +128,7 @@ impl RumdlLanguageServer {
             config: Arc::new(RwLock::new(initial_config)),
             rumdl_config,
             documents: Arc::new(RwLock::new(HashMap::new())),
+            block_caches: Arc::new(RwLock::new(HashMap::new())),
             workspace_roots,
             config_cache: Arc::new(RwLock::new(HashMap::new())),
             workspace_index,
@@ -365,18 +369,17 @@ impl LanguageServer for RumdlLanguageServer {
             log::info!("Triggered initial workspace indexing for cross-file analysis");
         }
 
-        // Register file watchers for markdown files and config files
-        let markdown_patterns = [
-            "**/*.md",
-            "**/*.markdown",
-            "**/*.mdx",
-            "**/*.mkd",
-            "**/*.mkdn",
-            "**/*.mdown",
-            "**/*.mdwn",
-            "**/*.qmd",
-            "**/*.rmd",
-        ];
+        // Register file watchers for markdown files (per `global.extensions`)
+        // and config files
+        let markdown_patterns: Vec<String> = self
+            .rumdl_config
+            .read()
+            .await
+            .global
+            .extensions
+            .iter()
+            .map(|ext| format!("**/*.{}", ext.trim_start_matches('.')))
+            .collect();
         let config_patterns = [
             "**/.rumdl.toml",
             "**/rumdl.toml",
@@ -387,9 +390,10 @@ impl LanguageServer for RumdlLanguageServer {
         ];
         let watchers: Vec<_> = markdown_patterns
             .iter()
-            .chain(config_patterns.iter())
+            .map(String::as_str)
+            .chain(config_patterns)
             .map(|pattern| FileSystemWatcher {
-                glob_pattern: GlobPattern::String((*pattern).to_string()),
+                glob_pattern: GlobPattern::String(pattern.to_string()),
                 kind: Some(WatchKind::all()),
             })
             .collect();
@@ -885,6 +889,7 @@ impl LanguageServer for RumdlLanguageServer {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         // Remove document from storage
         self.documents.write().await.remove(&params.text_document.uri);
+        self.block_caches.write().await.remove(&params.text_document.uri);
 
         // Always clear diagnostics on close to ensure cleanup
         // (Ruff does this unconditionally as a defensive measure)
@@ -931,9 +936,9 @@ impl LanguageServer for RumdlLanguageServer {
                 }
 
                 // Handle markdown file changes for workspace index
-                if let Some(ext) = path.extension()
-                    && is_markdown_extension(ext)
-                {
+                let is_markdown = crate::lsp::index_worker::index_walk_options(&*self.rumdl_config.read().await)
+                    .is_markdown_path(&path);
+                if is_markdown {
                     match change.typ {
                         FileChangeType::CREATED | FileChangeType::CHANGED => {
                             // Skip files the full scan would ignore (e.g. generated
@@ -74,7 +74,7 @@ pub(super) fn extract_heading_symbols(ctx: &LintContext) -> Vec<HeadingSymbol> {
         );
         headings.push(HeadingSymbol {
             level: heading.level,
-            name: heading.text.clone(),
+            name: heading.text(ctx.content).to_string(),
             line: i as u32,
             name_start,
             name_end,
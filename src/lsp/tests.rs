@@ -50,7 +50,7 @@ fn test_is_valid_rule_name() {
     // Invalid rule names - not in alias map
     assert!(!is_valid_rule_name("MD000")); // doesn't exist
     assert!(!is_valid_rule_name("MD999")); // doesn't exist
-    assert!(!is_valid_rule_name("MD100")); // doesn't exist
+    assert!(!is_valid_rule_name("MD108")); // doesn't exist
     assert!(!is_valid_rule_name("INVALID"));
     assert!(!is_valid_rule_name("not-a-rule"));
     assert!(!is_valid_rule_name(""));
@@ -82,6 +82,31 @@ async fn test_lint_document() {
     assert!(diagnostics.iter().any(|d| d.message.contains("trailing")));
 }
 
+#[tokio::test]
+async fn test_lint_document_reuses_unchanged_blocks() {
+    let server = create_test_server();
+    let uri = Url::parse("file:///open.md").unwrap();
+    let before = "# Test\n\nTrailing   \n";
+    let after = "# Test\n\nNew paragraph\n\nTrailing   \n";
+    server.documents.write().await.insert(
+        uri.clone(),
+        DocumentEntry {
+            content: before.to_string(),
+            version: Some(1),
+            from_disk: false,
+        },
+    );
+
+    server.lint_document(&uri, before, false).await.unwrap();
+    assert!(!server.block_caches.read().await[&uri].is_empty());
+
+    // The cached trailing-space warning moves with its block
+    let diagnostics = server.lint_document(&uri, after, false).await.unwrap();
+    let fresh = create_test_server().lint_document(&uri, after, false).await.unwrap();
+    assert_eq!(diagnostics, fresh);
+    assert!(diagnostics.iter().any(|d| d.range.start.line == 4));
+}
+
 #[tokio::test]
 async fn test_lint_document_disabled() {
     let server = create_test_server();
@@ -696,6 +721,47 @@ async fn test_unfixable_rules_excluded_from_formatting() {
     }
 }
 
+/// Unsafe fixes are offered as suggested edits and left out of Fix All
+/// unless `unsafe-fixes` is enabled
+#[tokio::test]
+async fn test_unsafe_fixes_excluded_from_fix_all() {
+    let server = create_test_server();
+    server.rumdl_config.write().await.global.extend_enable = vec!["MD063".to_string()];
+    let uri = Url::parse("file:///test.md").unwrap();
+    let text = "# hello world\n\nTrailing spaces  ";
+    let range = Range {
+        start: Position { line: 0, character: 0 },
+        end: Position { line: 2, character: 0 },
+    };
+    let fix_all_text = |actions: &[CodeAction]| {
+        actions
+            .iter()
+            .find(|action| action.title.contains("Fix all"))
+            .and_then(|action| action.edit.as_ref()?.changes.as_ref()?.get(&uri)?.first())
+            .map(|edit| edit.new_text.clone())
+            .unwrap()
+    };
+
+    let actions = server.get_code_actions(&uri, text, range).await.unwrap();
+    let md063 = actions
+        .iter()
+        .find(|action| action.title.contains("title case"))
+        .unwrap();
+    assert!(md063.title.starts_with("Suggested edit"), "got: {}", md063.title);
+    assert_eq!(md063.is_preferred, Some(false));
+    assert!(fix_all_text(&actions).starts_with("# hello world\n"));
+    assert!(
+        actions
+            .iter()
+            .any(|action| action.title == "Fix all rumdl issues (2 fixable)")
+    );
+
+    server.rumdl_config.write().await.global.unsafe_fixes = true;
+    server.config_cache.write().await.clear();
+    let actions = server.get_code_actions(&uri, text, range).await.unwrap();
+    assert!(fix_all_text(&actions).starts_with("# Hello World\n"));
+}
+
 /// Test that resolve_config_for_file() finds the correct config in multi-root workspace
 #[tokio::test]
 async fn test_resolve_config_for_file_multi_root() {
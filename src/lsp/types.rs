@@ -135,6 +135,15 @@ impl Default for RumdlLspConfig {
 
 /// Convert rumdl warnings to LSP diagnostics
 pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
+    warning_to_diagnostic_with_docs(warning, &crate::output::DocsLinks::default())
+}
+
+/// Convert a rumdl warning to an LSP diagnostic whose code description links
+/// to the documentation given by `docs`
+pub fn warning_to_diagnostic_with_docs(
+    warning: &crate::rule::LintWarning,
+    docs: &crate::output::DocsLinks,
+) -> Diagnostic {
     let start_position = Position {
         line: (warning.line.saturating_sub(1)) as u32,
         character: (warning.column.saturating_sub(1)) as u32,
@@ -152,20 +161,15 @@ pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
         crate::rule::Severity::Info => DiagnosticSeverity::INFORMATION,
     };
 
-    // Only generate documentation URLs for rumdl rule names (MD001, MD007, etc.),
-    // not for external tool names (jq, tombi, shellcheck, etc.)
-    let code_description = warning.rule_name.as_ref().and_then(|rule_name| {
-        let is_rumdl_rule = rule_name.len() > 2
-            && rule_name[..2].eq_ignore_ascii_case("MD")
-            && rule_name[2..].chars().all(|c| c.is_ascii_digit());
-        if is_rumdl_rule {
-            Url::parse(&format!("https://rumdl.dev/{}/", rule_name.to_lowercase()))
-                .ok()
-                .map(|href| CodeDescription { href })
-        } else {
-            None
-        }
-    });
+    // Only rumdl rule names (MD001, MD007, etc.) link to documentation by
+    // default; external tool names (jq, tombi, shellcheck, etc.) need an
+    // `explain-url` of their own
+    let code_description = warning
+        .rule_name
+        .as_ref()
+        .and_then(|rule_name| docs.url(rule_name))
+        .and_then(|url| Url::parse(&url).ok())
+        .map(|href| CodeDescription { href });
 
     Diagnostic {
         range: Range {
@@ -258,7 +262,7 @@ pub(crate) fn warning_to_code_actions_with_md013_config(
 ) -> Vec<CodeAction> {
     let mut actions = Vec::new();
 
-    // Add fix action if available (marked as preferred)
+    // Add fix action if available (marked as preferred unless only suggested)
     if let Some(fix_action) = create_fix_action(warning, uri, document_text) {
         actions.push(fix_action);
     }
@@ -266,7 +270,7 @@ pub(crate) fn warning_to_code_actions_with_md013_config(
     // Add manual reflow action for MD013 when no fix is available
     // This allows users to manually reflow paragraphs without enabling reflow globally
     if warning.rule_name.as_deref() == Some("MD013")
-        && warning.fix.is_none()
+        && warning.automatic_fix().is_none()
         && let Some(reflow_action) = create_reflow_action(warning, uri, document_text, md013_config)
     {
         actions.push(reflow_action);
@@ -318,13 +322,21 @@ fn create_fix_action(warning: &crate::rule::LintWarning, uri: &Url, document_tex
             change_annotations: None,
         };
 
+        // Suggested edits need review, so editors must not apply them as the
+        // preferred fix (e.g. on "auto fix" keybindings)
+        let (title, is_preferred) = if fix.is_automatic() {
+            (format!("Fix: {}", warning.message), true)
+        } else {
+            (format!("Suggested edit: {}", warning.message), false)
+        };
+
         Some(CodeAction {
-            title: format!("Fix: {}", warning.message),
+            title,
             kind: Some(CodeActionKind::QUICKFIX),
             diagnostics: Some(vec![warning_to_diagnostic(warning)]),
             edit: Some(workspace_edit),
             command: None,
-            is_preferred: Some(true),
+            is_preferred: Some(is_preferred),
             disabled: None,
             data: None,
         })
@@ -787,6 +799,33 @@ mod tests {
         assert_eq!(edits[0].new_text, "Fixed");
     }
 
+    #[test]
+    fn test_warning_to_code_action_with_suggestion() {
+        let warning = LintWarning {
+            line: 1,
+            column: 3,
+            end_line: 1,
+            end_column: 8,
+            rule_name: Some("MD024".to_string()),
+            message: "Duplicate heading: 'Usage'.".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix::suggestion(3..8, "Usage (2)".to_string())),
+        };
+
+        let uri = Url::parse("file:///test.md").unwrap();
+        let document_text = "## Usage";
+
+        let actions = warning_to_code_actions(&warning, &uri, document_text);
+        let action = &actions[0];
+
+        assert_eq!(action.title, "Suggested edit: Duplicate heading: 'Usage'.");
+        assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+        assert_eq!(action.is_preferred, Some(false));
+
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        assert_eq!(changes[&uri][0].new_text, "Usage (2)");
+    }
+
     #[test]
     fn test_warning_to_code_action_no_fix() {
         let warning = LintWarning {
@@ -949,6 +988,43 @@ mod tests {
         assert_eq!(url.as_str(), "https://rumdl.dev/md013/");
     }
 
+    #[test]
+    fn test_code_description_uses_configured_docs_links() {
+        let config: crate::config::Config = toml::from_str(
+            r#"
+[global]
+docs-base-url = "https://docs.example.com/"
+
+[MD013]
+explain-url = "https://wiki.example.com/style#line-length"
+"#,
+        )
+        .unwrap();
+        let docs = crate::output::DocsLinks::from_config(&config);
+        let warning = |rule: &str| LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: Some(rule.to_string()),
+            message: "Warning".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        };
+
+        let href = |rule: &str| {
+            warning_to_diagnostic_with_docs(&warning(rule), &docs)
+                .code_description
+                .map(|d| d.href.to_string())
+        };
+        assert_eq!(
+            href("MD013").as_deref(),
+            Some("https://wiki.example.com/style#line-length")
+        );
+        assert_eq!(href("MD001").as_deref(), Some("https://docs.example.com/md001/"));
+        assert_eq!(href("jq"), None);
+    }
+
     #[test]
     fn test_no_url_for_code_block_tool_warnings() {
         // Warnings from code-block-tools use the tool name (e.g., "jq") as rule_name.
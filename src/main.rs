@@ -26,9 +26,11 @@ use core::error::Error;
 use rumdl_lib::exit_codes::exit;
 
 mod cache;
+mod changed_lines;
 mod check_runner;
 mod file_processor;
 mod formatter;
+mod interactive_fix;
 mod resolution;
 mod stdin_processor;
 mod watch;
@@ -94,6 +96,23 @@ pub enum SchemaAction {
     Print,
 }
 
+#[derive(Subcommand)]
+pub enum DaemonAction {
+    /// Start the daemon in the background
+    Start,
+    /// Run the daemon in the foreground
+    Run,
+    /// Stop the daemon
+    Stop,
+    /// Show whether the daemon is running and what it has loaded
+    Status,
+    /// Lint files through the daemon, starting it if it is not running
+    Check {
+        /// Files or directories to lint (default: the current directory)
+        paths: Vec<String>,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum CodeBlockToolsDocsAction {
     /// Generate/update the built-in tools table in docs/code-block-tools.md
@@ -141,10 +160,14 @@ enum Commands {
         /// List available categories and exit
         #[arg(long)]
         list_categories: bool,
+        /// Search rule names, descriptions and docs, best matches first
+        #[arg(long, value_name = "QUERY", conflicts_with_all = ["rule", "list_categories"])]
+        search: Option<String>,
     },
-    /// Explain a rule with detailed information and examples
+    /// Explain a rule with detailed information and examples, or why rules
+    /// fire at a location
     Explain {
-        /// Rule name or ID to explain
+        /// Rule name or ID to explain, or a `FILE:LINE` location
         rule: String,
     },
     /// Show configuration or query a specific key
@@ -172,6 +195,11 @@ enum Commands {
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Keep a warm linter process for fast repeated runs (pre-commit hooks, editors)
+    Daemon {
+        #[command(subcommand)]
+        action: DaemonAction,
+    },
     /// Generate or check JSON schema for rumdl.toml
     Schema {
         #[command(subcommand)]
@@ -196,6 +224,39 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Export the effective configuration as a markdownlint config file
+    Export {
+        /// Output format
+        #[arg(long, default_value_t, value_enum)]
+        format: commands::export::ExportFormat,
+        /// Output file path (default: print to stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Move a Markdown file and rewrite every link to it
+    Mv {
+        /// File to move
+        from: String,
+        /// New path, or an existing directory to move the file into
+        to: String,
+        /// Print the rename and the link changes as a diff without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Print the anchor of every heading, or record them for MD101
+    Anchors {
+        /// Write the anchor manifest checked by MD101 instead of printing it
+        #[arg(long)]
+        write: bool,
+    },
+    /// Upgrade config files written for older rumdl versions or ported from markdownlint
+    Migrate {
+        /// Config files to migrate (default: the discovered config files)
+        paths: Vec<String>,
+        /// Write the changes instead of printing them as a diff
+        #[arg(long)]
+        write: bool,
+    },
     /// Install the rumdl VS Code extension
     Vscode {
         /// Force reinstall the current version even if already installed
@@ -228,6 +289,8 @@ pub enum ConfigSubcommand {
     Get { key: String },
     /// Show the absolute path of the configuration file that was loaded
     File,
+    /// Validate the configuration, exiting non-zero if it has errors
+    Lint,
 }
 
 #[derive(Clone, ValueEnum)]
@@ -329,7 +392,6 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
             Commands::Check(mut args) => {
                 args.fix_mode = if args.fix { FixMode::CheckFix } else { FixMode::Check };
-                args.fail_on_mode = args.fail_on;
 
                 let config_path = if cli.no_config || cli.isolated {
                     None
@@ -341,13 +403,17 @@ fn main() -> Result<(), Box<dyn Error>> {
             Commands::Fmt(args) => {
                 let mut args: CheckArgs = args.into();
                 args.fix_mode = FixMode::Format;
-                args.fail_on_mode = args.fail_on;
 
                 // --check mode enables diff (don't write files) and will exit 1 if changes needed
                 if args.check {
                     args.diff = true;
                 }
 
+                if args.range.is_some() && !(args.stdin || (args.paths.len() == 1 && args.paths[0] == "-")) {
+                    eprintln!("error: the argument '--range <START:END>' requires '--stdin'");
+                    exit::tool_error();
+                }
+
                 let config_path = if cli.no_config || cli.isolated {
                     None
                 } else {
@@ -362,11 +428,17 @@ fn main() -> Result<(), Box<dyn Error>> {
                 category,
                 explain,
                 list_categories,
+                search,
             } => {
-                commands::rule::handle_rule(rule, output_format, fixable, category, explain, list_categories);
+                commands::rule::handle_rule(rule, output_format, fixable, category, explain, list_categories, search);
             }
             Commands::Explain { rule } => {
-                commands::explain::handle_explain(&rule);
+                let config_path = if cli.no_config || cli.isolated {
+                    None
+                } else {
+                    config_path.as_deref()
+                };
+                commands::explain::handle_explain(&rule, config_path, cli.no_config || cli.isolated, &inline_overrides);
             }
             Commands::Config {
                 subcmd,
@@ -399,6 +471,14 @@ fn main() -> Result<(), Box<dyn Error>> {
                 };
                 commands::server::handle_server(port, stdio, verbose, config_path);
             }
+            Commands::Daemon { action } => {
+                let config_path = if cli.no_config || cli.isolated {
+                    None
+                } else {
+                    config_path.as_deref()
+                };
+                commands::daemon::handle_daemon(action, config_path, cli.no_config || cli.isolated, &inline_overrides);
+            }
             Commands::Import {
                 file,
                 output,
@@ -407,6 +487,29 @@ fn main() -> Result<(), Box<dyn Error>> {
             } => {
                 commands::import::handle_import(file, output, format, dry_run);
             }
+            Commands::Export { format, output } => {
+                commands::export::handle_export(
+                    format,
+                    output,
+                    config_path.as_deref(),
+                    cli.no_config || cli.isolated,
+                    &inline_overrides,
+                );
+            }
+            Commands::Mv { from, to, dry_run } => {
+                commands::mv::handle_mv(from, to, dry_run);
+            }
+            Commands::Migrate { paths, write } => {
+                commands::migrate::handle_migrate(paths, write, config_path.as_deref());
+            }
+            Commands::Anchors { write } => {
+                commands::anchors::handle_anchors(
+                    write,
+                    config_path.as_deref(),
+                    cli.no_config || cli.isolated,
+                    &inline_overrides,
+                );
+            }
             Commands::Vscode { force, update, status } => {
                 commands::vscode::handle_vscode(force, update, status);
             }
@@ -486,6 +486,130 @@ impl MarkdownlintConfig {
     }
 }
 
+/// Whether markdownlint has a rule with this ID.
+///
+/// rumdl keeps markdownlint's numbering for MD001-MD060; MD057 and
+/// everything after MD060 are rumdl-only.
+pub fn has_markdownlint_equivalent(rule: &str) -> bool {
+    rule.strip_prefix("MD")
+        .and_then(|n| n.parse::<u32>().ok())
+        .is_some_and(|n| (1..=60).contains(&n) && n != 57)
+}
+
+/// A rumdl configuration converted to markdownlint's format
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownlintExport {
+    /// The markdownlint config: `default` first, then rules by ID
+    pub config: serde_json::Map<String, serde_json::Value>,
+    /// Configured rumdl-only rules that have no markdownlint counterpart
+    pub skipped_rules: Vec<String>,
+}
+
+/// Convert an effective rumdl config to a markdownlint config.
+///
+/// This is the inverse of [`MarkdownlintConfig::map_to_sourced_rumdl_config_fragment`].
+/// When rumdl's `enable` list is set, the export uses `"default": false` and
+/// lists the enabled rules; otherwise it uses `"default": true` and lists the
+/// disabled ones, including rules that are opt-in in rumdl. Rule options are
+/// written with markdownlint's snake_case names.
+pub fn export_to_markdownlint(config: &crate::config::Config) -> MarkdownlintExport {
+    let enabled: std::collections::HashSet<&'static str> =
+        crate::rules::filter_rules(&crate::rules::all_rules(config), &config.global)
+            .iter()
+            .map(|rule| rule.name())
+            .collect();
+    let enable_list = !config.global.enable.is_empty() || config.global.enable_is_explicit;
+
+    let mut options: std::collections::BTreeMap<String, &crate::config::RuleConfig> = std::collections::BTreeMap::new();
+    let mut skipped_rules = Vec::new();
+    for (key, rule_config) in &config.rules {
+        let id = crate::config::resolve_rule_name_alias(key).map_or_else(|| key.to_ascii_uppercase(), str::to_string);
+        if has_markdownlint_equivalent(&id) {
+            options.insert(id, rule_config);
+        } else {
+            skipped_rules.push(id);
+        }
+    }
+    skipped_rules.sort();
+    skipped_rules.dedup();
+
+    let mut exported = serde_json::Map::new();
+    exported.insert("default".to_string(), serde_json::Value::Bool(!enable_list));
+
+    let mut ids: Vec<&str> = crate::rules::all_rules(config)
+        .iter()
+        .map(|rule| rule.name())
+        .filter(|name| has_markdownlint_equivalent(name))
+        .collect();
+    ids.sort_unstable();
+    for id in ids {
+        let is_enabled = enabled.contains(id);
+        let rule_options = if is_enabled {
+            markdownlint_rule_options(id, options.get(id).copied(), config)
+        } else {
+            None
+        };
+        let value = match rule_options {
+            Some(value) => value,
+            None if is_enabled == enable_list => serde_json::Value::Bool(is_enabled),
+            None => continue,
+        };
+        exported.insert(id.to_string(), value);
+    }
+
+    MarkdownlintExport {
+        config: exported,
+        skipped_rules,
+    }
+}
+
+/// markdownlint options for one rule, or `None` if nothing is configured
+fn markdownlint_rule_options(
+    rule: &str,
+    rule_config: Option<&crate::config::RuleConfig>,
+    config: &crate::config::Config,
+) -> Option<serde_json::Value> {
+    let mut values: toml::map::Map<String, toml::Value> = rule_config
+        .map(|rc| rc.values.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        .unwrap_or_default();
+
+    // A bare value (`MD013 = 100` in markdownlint) round-trips as itself
+    if values.len() == 1
+        && let Some(value) = values.remove("value")
+    {
+        return serde_json::to_value(value).ok();
+    }
+
+    match rule {
+        "MD007" => {
+            // Added on import; markdownlint always uses fixed indentation
+            values.remove("style");
+        }
+        "MD013" => {
+            if let Some(strict) = values.remove("strict") {
+                values.insert("stern".to_string(), strict);
+            }
+            let has_line_length = values.contains_key("line-length") || values.contains_key("line_length");
+            if !has_line_length && config.global.line_length != crate::config::GlobalConfig::default().line_length {
+                values.insert(
+                    "line-length".to_string(),
+                    toml::Value::Integer(config.global.line_length.get() as i64),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    if values.is_empty() {
+        return None;
+    }
+    let table: serde_json::Map<String, serde_json::Value> = values
+        .into_iter()
+        .filter_map(|(k, v)| Some((k.replace('-', "_"), serde_json::to_value(v).ok()?)))
+        .collect();
+    Some(serde_json::Value::Object(table))
+}
+
 // NOTE: 'code-block-style' (MD046) and 'code-fence-style' (MD048) are distinct and must not be merged. See markdownlint docs for details.
 
 #[cfg(test)]
@@ -1476,4 +1600,58 @@ MD013:
         // Display name is lowercased
         assert_eq!(fragment.rule_display_names.get("MD013").unwrap(), "line-length");
     }
+
+    #[test]
+    fn test_export_to_markdownlint_maps_options_and_disabled_rules() {
+        let config: crate::config::Config = toml::from_str(
+            r#"
+[global]
+disable = ["MD033"]
+
+[MD013]
+line-length = 100
+strict = true
+
+[MD007]
+indent = 4
+style = "fixed"
+
+[MD061]
+terms = ["TODO"]
+"#,
+        )
+        .unwrap();
+
+        let export = export_to_markdownlint(&config);
+        let json = serde_json::Value::Object(export.config);
+        assert_eq!(json["default"], serde_json::json!(true));
+        assert_eq!(json["MD013"], serde_json::json!({"line_length": 100, "stern": true}));
+        assert_eq!(json["MD007"], serde_json::json!({"indent": 4}));
+        assert_eq!(json["MD033"], serde_json::json!(false));
+        // Opt-in in rumdl, on by default in markdownlint
+        assert_eq!(json["MD060"], serde_json::json!(false));
+        assert!(json.get("MD001").is_none());
+        assert!(json.get("MD061").is_none());
+        assert_eq!(export.skipped_rules, vec!["MD061".to_string()]);
+    }
+
+    #[test]
+    fn test_export_to_markdownlint_enable_list_uses_default_false() {
+        let mut config: crate::config::Config =
+            toml::from_str("[global]\nenable = [\"MD001\", \"heading-style\"]\nline-length = 120\n").unwrap();
+        config.global.enable_is_explicit = true;
+
+        let export = export_to_markdownlint(&config);
+        let keys: Vec<&str> = export.config.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["default", "MD001", "MD003"]);
+        assert_eq!(export.config["default"], serde_json::json!(false));
+
+        // Round-trips through import
+        let yaml: HashMap<String, serde_yaml::Value> =
+            serde_yaml::from_str(&serde_yaml::to_string(&export.config).unwrap()).unwrap();
+        let fragment = MarkdownlintConfig(yaml).map_to_sourced_rumdl_config_fragment(None);
+        let mut enabled = fragment.global.enable.value;
+        enabled.sort();
+        assert_eq!(enabled, vec!["MD001".to_string(), "MD003".to_string()]);
+    }
 }
@@ -23,67 +23,6 @@ impl FullFormatter {
     pub fn without_colors() -> Self {
         Self { use_colors: false }
     }
-
-    /// Render the source context block: gutter, source line, and caret underline.
-    fn render_source_context(&self, output: &mut String, warning: &LintWarning, lines: &[&str]) {
-        let line_idx = warning.line.saturating_sub(1);
-        if line_idx >= lines.len() {
-            return;
-        }
-
-        let source_line = lines[line_idx];
-        let line_num = warning.line;
-        let gutter_width = line_num.to_string().len().max(2);
-        let empty_gutter = " ".repeat(gutter_width);
-
-        // Empty gutter line
-        if self.use_colors {
-            output.push_str(&format!("{empty_gutter} {}\n", "|".blue().bold()));
-        } else {
-            output.push_str(&format!("{empty_gutter} |\n"));
-        }
-
-        // Source line with line number
-        if self.use_colors {
-            output.push_str(&format!(
-                "{:>width$} {} {}\n",
-                line_num.to_string().blue().bold(),
-                "|".blue().bold(),
-                source_line,
-                width = gutter_width,
-            ));
-        } else {
-            output.push_str(&format!("{line_num:>gutter_width$} | {source_line}\n"));
-        }
-
-        // Caret underline
-        let col = warning.column.saturating_sub(1);
-        let end_col = if warning.end_column > warning.column {
-            warning.end_column.saturating_sub(1)
-        } else {
-            col + 1
-        };
-        let caret_len = end_col.saturating_sub(col).max(1);
-        let padding = " ".repeat(col);
-        let carets = "^".repeat(caret_len);
-
-        if self.use_colors {
-            output.push_str(&format!(
-                "{empty_gutter} {} {padding}{}\n",
-                "|".blue().bold(),
-                carets.yellow().bold(),
-            ));
-        } else {
-            output.push_str(&format!("{empty_gutter} | {padding}{carets}\n"));
-        }
-
-        // Closing empty gutter line
-        if self.use_colors {
-            output.push_str(&format!("{empty_gutter} {}\n", "|".blue().bold()));
-        } else {
-            output.push_str(&format!("{empty_gutter} |\n"));
-        }
-    }
 }
 
 impl OutputFormatter for FullFormatter {
@@ -93,7 +32,7 @@ impl OutputFormatter for FullFormatter {
 
         for warning in warnings {
             let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
-            let fix_indicator = if warning.fix.is_some() { " [*]" } else { "" };
+            let fix_indicator = if warning.automatic_fix().is_some() { " [*]" } else { "" };
 
             let line = format!(
                 "{}:{}:{}: [{}] {}{}",
@@ -125,7 +64,7 @@ impl OutputFormatter for FullFormatter {
             }
 
             let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
-            let fix_indicator = if warning.fix.is_some() { " [*]" } else { "" };
+            let fix_indicator = if warning.automatic_fix().is_some() { " [*]" } else { "" };
 
             // Header line: rule name and message
             if self.use_colors {
@@ -154,7 +93,7 @@ impl OutputFormatter for FullFormatter {
             }
 
             // Source context with gutter, source line, and carets
-            self.render_source_context(&mut output, warning, &lines);
+            render_source_context(&mut output, warning, &lines, 0, self.use_colors);
         }
 
         // Remove trailing newline
@@ -170,6 +109,81 @@ impl OutputFormatter for FullFormatter {
     }
 }
 
+/// Render the source context block: gutter, the warning's line with
+/// `context` lines on either side, and a caret underline of the warning's
+/// span. Shared by the `full` format and `text` with `--show-source`.
+pub(crate) fn render_source_context(
+    output: &mut String,
+    warning: &LintWarning,
+    lines: &[&str],
+    context: usize,
+    use_colors: bool,
+) {
+    let line_idx = warning.line.saturating_sub(1);
+    if line_idx >= lines.len() {
+        return;
+    }
+
+    let first_idx = line_idx.saturating_sub(context);
+    let last_idx = (line_idx + context).min(lines.len() - 1);
+    let gutter_width = (last_idx + 1).to_string().len().max(2);
+    let empty_gutter = " ".repeat(gutter_width);
+    let bar = if use_colors {
+        "|".blue().bold().to_string()
+    } else {
+        "|".to_string()
+    };
+
+    // Empty gutter line
+    output.push_str(&format!("{empty_gutter} {bar}\n"));
+
+    for (idx, source_line) in lines.iter().enumerate().take(last_idx + 1).skip(first_idx) {
+        // Source line with line number
+        let line_num = idx + 1;
+        if use_colors {
+            output.push_str(&format!(
+                "{:>width$} {bar} {source_line}\n",
+                line_num.to_string().blue().bold(),
+                width = gutter_width,
+            ));
+        } else {
+            output.push_str(&format!("{line_num:>gutter_width$} {bar} {source_line}\n"));
+        }
+
+        if idx != line_idx {
+            continue;
+        }
+
+        // Caret underline; spans continuing on later lines run to the end of this one
+        let col = warning.column.saturating_sub(1);
+        let end_col = if warning.end_line > warning.line {
+            source_line.chars().count().max(col + 1)
+        } else if warning.end_column > warning.column {
+            warning.end_column.saturating_sub(1)
+        } else {
+            col + 1
+        };
+        let caret_len = end_col.saturating_sub(col).max(1);
+        // Keep tabs in the padding so the carets line up with the source
+        let padding: String = source_line
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(col)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(caret_len);
+
+        if use_colors {
+            output.push_str(&format!("{empty_gutter} {bar} {padding}{}\n", carets.yellow().bold()));
+        } else {
+            output.push_str(&format!("{empty_gutter} {bar} {padding}{carets}\n"));
+        }
+    }
+
+    // Closing empty gutter line
+    output.push_str(&format!("{empty_gutter} {bar}\n"));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,6 +318,16 @@ mod tests {
         assert!(output.contains("    ^"));
     }
 
+    #[test]
+    fn test_full_formatter_multiline_span_underlines_to_line_end() {
+        let formatter = FullFormatter::without_colors();
+        let content = "<div>\ntext\n</div>\n";
+        let mut warning = make_warning(1, 1, 7, "MD033", "Inline HTML");
+        warning.end_line = 3;
+        let output = formatter.format_warnings_with_content(&[warning], "test.md", content);
+        assert!(output.contains(" 1 | <div>\n   | ^^^^^\n"));
+    }
+
     #[test]
     fn test_full_formatter_gutter_width_for_large_line_numbers() {
         let formatter = FullFormatter::without_colors();
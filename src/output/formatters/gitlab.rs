@@ -1,7 +1,7 @@
 //! GitLab Code Quality report format
 
 use crate::output::OutputFormatter;
-use crate::rule::LintWarning;
+use crate::rule::{LintWarning, Severity};
 use serde_json::json;
 
 /// GitLab Code Quality formatter
@@ -33,7 +33,7 @@ impl OutputFormatter for GitLabFormatter {
                     "description": warning.message,
                     "check_name": rule_name,
                     "fingerprint": fingerprint,
-                    "severity": "minor",
+                    "severity": gitlab_severity(warning.severity),
                     "location": {
                         "path": file_path,
                         "lines": {
@@ -48,6 +48,15 @@ impl OutputFormatter for GitLabFormatter {
     }
 }
 
+/// Map a warning severity to a GitLab Code Quality severity
+fn gitlab_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Info => "info",
+    }
+}
+
 /// Format all warnings as GitLab Code Quality report
 pub fn format_gitlab_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
     let mut issues = Vec::new();
@@ -63,7 +72,7 @@ pub fn format_gitlab_report(all_warnings: &[(String, Vec<LintWarning>)]) -> Stri
                 "description": warning.message,
                 "check_name": rule_name,
                 "fingerprint": fingerprint,
-                "severity": "minor",
+                "severity": gitlab_severity(warning.severity),
                 "location": {
                     "path": file_path,
                     "lines": {
@@ -82,7 +91,7 @@ pub fn format_gitlab_report(all_warnings: &[(String, Vec<LintWarning>)]) -> Stri
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rule::{Fix, Severity};
+    use crate::rule::Fix;
     use serde_json::Value;
 
     #[test]
@@ -333,10 +342,10 @@ mod tests {
     }
 
     #[test]
-    fn test_severity_always_minor() {
+    fn test_severity_mapping() {
         let formatter = GitLabFormatter::new();
 
-        // Test that all severities are output as "minor" in GitLab format
+        // Rule severities map onto GitLab's severity scale
         let warnings = vec![
             LintWarning {
                 line: 1,
@@ -363,9 +372,8 @@ mod tests {
         let output = formatter.format_warnings(&warnings, "test.md");
         let issues: Vec<Value> = serde_json::from_str(&output).unwrap();
 
-        // Both should use severity "minor" regardless of actual severity
         assert_eq!(issues[0]["severity"], "minor");
-        assert_eq!(issues[1]["severity"], "minor");
+        assert_eq!(issues[1]["severity"], "major");
     }
 
     #[test]
@@ -47,7 +47,7 @@ impl OutputFormatter for GroupedFormatter {
 
             for warning in rule_warnings {
                 output.push_str(&format!("    {}:{} {}", warning.line, warning.column, warning.message));
-                if warning.fix.is_some() {
+                if warning.automatic_fix().is_some() {
                     output.push_str(" (fixable)");
                 }
                 output.push('\n');
@@ -0,0 +1,475 @@
+//! Standalone HTML report format
+//!
+//! A single self-contained page (inline CSS and JS, no external assets) meant
+//! to be uploaded as a CI artifact: a file list for navigation, one section
+//! per file with collapsible warnings and highlighted source context, and
+//! controls to filter by rule.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::output::{DocsLinks, OutputFormatter};
+use crate::rule::{LintWarning, Severity};
+
+/// Lines of source shown before and after each warning
+const CONTEXT_LINES: usize = 2;
+
+/// HTML report formatter
+pub struct HtmlFormatter;
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl HtmlFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for HtmlFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        format_html_report(
+            &[(file_path.to_string(), warnings.to_vec())],
+            &[file_path.to_string()],
+            0,
+            &DocsLinks::default(),
+        )
+    }
+
+    fn format_warnings_with_content(&self, warnings: &[LintWarning], file_path: &str, content: &str) -> String {
+        let file = ReportFile {
+            path: file_path,
+            warnings,
+            source: Some(content.to_string()),
+        };
+        render_report(&[file], 0, &DocsLinks::default())
+    }
+}
+
+/// One checked file in the report
+struct ReportFile<'a> {
+    path: &'a str,
+    warnings: &'a [LintWarning],
+    /// File content for context lines; `None` when it could not be read
+    source: Option<String>,
+}
+
+/// Format an HTML report covering every checked file.
+///
+/// Files with warnings get a section each; `all_files` adds the clean ones to
+/// the file list. Context lines are read from each reported path, so they are
+/// left out for files that cannot be read from the working directory.
+pub fn format_html_report(
+    all_warnings: &[(String, Vec<LintWarning>)],
+    all_files: &[String],
+    duration_ms: u64,
+    docs: &DocsLinks,
+) -> String {
+    let mut files: Vec<ReportFile> = Vec::new();
+    let paths = all_files
+        .iter()
+        .map(String::as_str)
+        .chain(all_warnings.iter().map(|(path, _)| path.as_str()));
+    for path in paths {
+        if files.iter().any(|file| file.path == path) {
+            continue;
+        }
+        let warnings = all_warnings
+            .iter()
+            .find(|(warned, _)| warned == path)
+            .map_or(&[][..], |(_, warnings)| warnings.as_slice());
+        let source = if warnings.is_empty() {
+            None
+        } else {
+            std::fs::read_to_string(path).ok()
+        };
+        files.push(ReportFile { path, warnings, source });
+    }
+    render_report(&files, duration_ms, docs)
+}
+
+fn render_report(files: &[ReportFile], duration_ms: u64, docs: &DocsLinks) -> String {
+    let total_warnings: usize = files.iter().map(|file| file.warnings.len()).sum();
+    let files_with_issues = files.iter().filter(|file| !file.warnings.is_empty()).count();
+    let mut rule_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for warning in files.iter().flat_map(|file| file.warnings) {
+        *rule_counts.entry(rule_name(warning)).or_default() += 1;
+    }
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str("<title>rumdl report</title>\n<style>\n");
+    html.push_str(STYLE);
+    html.push_str("</style>\n</head>\n<body>\n<header>\n<h1>rumdl report</h1>\n");
+    let _ = writeln!(
+        html,
+        "<p class=\"summary\">{} in {} of {} checked in {:.3}s</p>",
+        plural(total_warnings, "issue"),
+        plural(files_with_issues, "file"),
+        files.len(),
+        duration_ms as f64 / 1000.0
+    );
+    html.push_str("</header>\n<div class=\"layout\">\n<nav>\n");
+
+    // Rule filters
+    if !rule_counts.is_empty() {
+        html.push_str("<h2>Rules</h2>\n<div class=\"controls\">\n");
+        html.push_str("<button type=\"button\" data-action=\"all-rules\">All</button>\n");
+        html.push_str("<button type=\"button\" data-action=\"no-rules\">None</button>\n");
+        html.push_str("<button type=\"button\" data-action=\"expand\">Expand</button>\n");
+        html.push_str("<button type=\"button\" data-action=\"collapse\">Collapse</button>\n");
+        html.push_str("</div>\n<ul class=\"rules\">\n");
+        for (rule, count) in &rule_counts {
+            let rule = escape(rule);
+            let _ = writeln!(
+                html,
+                "<li><label><input type=\"checkbox\" class=\"rule-filter\" value=\"{rule}\" checked> {rule} <span class=\"count\">{count}</span></label></li>"
+            );
+        }
+        html.push_str("</ul>\n");
+    }
+
+    // File list
+    html.push_str("<h2>Files</h2>\n<ul class=\"files\">\n");
+    for (index, file) in files.iter().enumerate() {
+        let path = escape(file.path);
+        if file.warnings.is_empty() {
+            let _ = writeln!(html, "<li class=\"clean\">{path} <span class=\"count\">0</span></li>");
+        } else {
+            let _ = writeln!(
+                html,
+                "<li data-file=\"file-{index}\"><a href=\"#file-{index}\">{path}</a> <span class=\"count\">{}</span></li>",
+                file.warnings.len()
+            );
+        }
+    }
+    html.push_str("</ul>\n</nav>\n<main>\n");
+
+    if total_warnings == 0 {
+        html.push_str("<p class=\"clean-run\">All checked files passed.</p>\n");
+    }
+    for (index, file) in files.iter().enumerate().filter(|(_, file)| !file.warnings.is_empty()) {
+        render_file(&mut html, index, file, docs);
+    }
+
+    html.push_str("</main>\n</div>\n<script>\n");
+    html.push_str(SCRIPT);
+    html.push_str("</script>\n</body>\n</html>\n");
+    html
+}
+
+fn render_file(html: &mut String, index: usize, file: &ReportFile, docs: &DocsLinks) {
+    let _ = writeln!(
+        html,
+        "<section class=\"file\" id=\"file-{index}\">\n<h2>{} <span class=\"count\">{}</span></h2>",
+        escape(file.path),
+        plural(file.warnings.len(), "issue")
+    );
+    let lines: Vec<&str> = file.source.as_deref().map(|s| s.lines().collect()).unwrap_or_default();
+
+    for warning in file.warnings {
+        let rule = escape(rule_name(warning));
+        let severity = severity_name(warning.severity);
+        let rule_label = match warning.rule_name.as_deref().and_then(|name| docs.url(name)) {
+            Some(url) => format!("<a class=\"rule\" href=\"{}\">{rule}</a>", escape(&url)),
+            None => format!("<span class=\"rule\">{rule}</span>"),
+        };
+        let fixable = if warning.automatic_fix().is_some() {
+            " <span class=\"fixable\">fixable</span>"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            html,
+            "<details class=\"warning sev-{severity}\" data-rule=\"{rule}\" open>\n<summary><span class=\"location\">{}:{}</span> <span class=\"severity\">{severity}</span> {rule_label} <span class=\"message\">{}</span>{fixable}</summary>",
+            warning.line,
+            warning.column,
+            escape(&warning.message)
+        );
+
+        if warning.line >= 1 && warning.line <= lines.len() {
+            let first = warning.line.saturating_sub(CONTEXT_LINES).max(1);
+            let last = (warning.line + CONTEXT_LINES).min(lines.len());
+            html.push_str("<pre class=\"context\"><code>");
+            for line_num in first..=last {
+                let class = if line_num == warning.line {
+                    "line current"
+                } else {
+                    "line"
+                };
+                let _ = writeln!(
+                    html,
+                    "<span class=\"{class}\"><span class=\"ln\">{line_num}</span>{}</span>",
+                    highlight_markdown(lines[line_num - 1])
+                );
+            }
+            html.push_str("</code></pre>\n");
+        }
+        html.push_str("</details>\n");
+    }
+    html.push_str("</section>\n");
+}
+
+/// Escape a Markdown source line and wrap its syntax in highlighting spans.
+///
+/// Works line by line, so constructs spanning lines (such as the inside of a
+/// fenced code block) are only partly recognized; this is for reading, not
+/// parsing.
+fn highlight_markdown(line: &str) -> String {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+
+    if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+        return format!("{indent}<span class=\"tok-fence\">{}</span>", escape(trimmed));
+    }
+    let heading_level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&heading_level) && trimmed[heading_level..].chars().next().is_none_or(char::is_whitespace) {
+        return format!("{indent}<span class=\"tok-heading\">{}</span>", escape(trimmed));
+    }
+
+    let mut out = String::from(indent);
+    let mut rest = trimmed;
+    if let Some(after) = rest.strip_prefix('>') {
+        out.push_str("<span class=\"tok-marker\">&gt;</span>");
+        rest = after;
+    }
+    let marker_len = list_marker_len(rest);
+    if marker_len > 0 {
+        let _ = write!(out, "<span class=\"tok-marker\">{}</span>", escape(&rest[..marker_len]));
+        rest = &rest[marker_len..];
+    }
+    highlight_inline(&mut out, rest);
+    out
+}
+
+/// Length of a `-`, `*`, `+` or `1.` list marker at the start of `text`
+fn list_marker_len(text: &str) -> usize {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    let marker = match text[digits..].chars().next() {
+        Some('-' | '*' | '+') if digits == 0 => 1,
+        Some('.' | ')') if digits > 0 => digits + 1,
+        _ => return 0,
+    };
+    if text[marker..].starts_with(' ') { marker } else { 0 }
+}
+
+/// Highlight code spans, link destinations and HTML tags
+fn highlight_inline(out: &mut String, text: &str) {
+    let mut rest = text;
+    while let Some(pos) = rest.find(['`', '<', ']']) {
+        out.push_str(&escape(&rest[..pos]));
+        let tail = &rest[pos..];
+        let (class, len) = match tail.as_bytes()[0] {
+            b'`' => {
+                let ticks = tail.bytes().take_while(|&b| b == b'`').count();
+                let closing = &tail[..ticks];
+                match tail[ticks..].find(closing) {
+                    Some(end) => ("tok-code", ticks + end + ticks),
+                    None => ("", ticks),
+                }
+            }
+            b'<' => match tail.find('>') {
+                Some(end) if tail[1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!') => {
+                    ("tok-html", end + 1)
+                }
+                _ => ("", 1),
+            },
+            _ => match tail[1..].strip_prefix('(').and_then(|dest| dest.find(')')) {
+                Some(end) => {
+                    out.push(']');
+                    rest = &tail[1..];
+                    let len = end + 2;
+                    let _ = write!(out, "<span class=\"tok-link\">{}</span>", escape(&rest[..len]));
+                    rest = &rest[len..];
+                    continue;
+                }
+                None => ("", 1),
+            },
+        };
+        if class.is_empty() {
+            out.push_str(&escape(&tail[..len]));
+        } else {
+            let _ = write!(out, "<span class=\"{class}\">{}</span>", escape(&tail[..len]));
+        }
+        rest = &tail[len..];
+    }
+    out.push_str(&escape(rest));
+}
+
+fn rule_name(warning: &LintWarning) -> &str {
+    warning.rule_name.as_deref().unwrap_or("unknown")
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+fn plural(count: usize, word: &str) -> String {
+    format!("{count} {word}{}", if count == 1 { "" } else { "s" })
+}
+
+/// Escape special HTML characters
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+const STYLE: &str = r#"body { margin: 0; font: 14px/1.5 system-ui, sans-serif; color: #1f2328; background: #fff; }
+header { padding: 12px 20px; border-bottom: 1px solid #d0d7de; }
+h1 { margin: 0; font-size: 20px; }
+h2 { font-size: 15px; margin: 16px 0 8px; }
+.summary { margin: 4px 0 0; color: #59636e; }
+.layout { display: flex; align-items: flex-start; }
+nav { position: sticky; top: 0; width: 280px; max-height: 100vh; overflow: auto; padding: 0 16px 16px; border-right: 1px solid #d0d7de; box-sizing: border-box; }
+nav ul { list-style: none; margin: 0; padding: 0; }
+nav li { padding: 2px 0; overflow-wrap: anywhere; }
+nav li.clean { color: #59636e; }
+nav li.hidden { display: none; }
+.controls { display: flex; flex-wrap: wrap; gap: 4px; margin-bottom: 8px; }
+main { flex: 1; min-width: 0; padding: 0 20px 20px; }
+section.file.hidden, details.hidden { display: none; }
+.count { color: #59636e; font-size: 12px; }
+details.warning { border: 1px solid #d0d7de; border-left-width: 4px; border-radius: 4px; margin: 6px 0; }
+details.sev-error { border-left-color: #cf222e; }
+details.sev-warning { border-left-color: #bf8700; }
+details.sev-info { border-left-color: #0969da; }
+summary { cursor: pointer; padding: 4px 8px; }
+.location, .severity { font-family: ui-monospace, monospace; color: #59636e; }
+.rule { font-family: ui-monospace, monospace; font-weight: 600; }
+.fixable { font-size: 12px; color: #1a7f37; border: 1px solid #1a7f37; border-radius: 8px; padding: 0 6px; }
+pre.context { margin: 0; padding: 6px 0; background: #f6f8fa; overflow-x: auto; font: 13px/1.45 ui-monospace, monospace; }
+.line { display: block; padding-right: 8px; white-space: pre; }
+.line.current { background: #fff8c5; }
+.ln { display: inline-block; width: 4em; padding-right: 12px; text-align: right; color: #8c959f; user-select: none; }
+.tok-heading { color: #0550ae; font-weight: 600; }
+.tok-marker, .tok-fence { color: #8250df; }
+.tok-code { color: #953800; }
+.tok-link { color: #116329; }
+.tok-html { color: #6e7781; }
+"#;
+
+const SCRIPT: &str = r#"(function () {
+  var filters = Array.prototype.slice.call(document.querySelectorAll(".rule-filter"));
+  function apply() {
+    var shown = {};
+    filters.forEach(function (f) { shown[f.value] = f.checked; });
+    document.querySelectorAll("section.file").forEach(function (section) {
+      var visible = 0;
+      section.querySelectorAll("details.warning").forEach(function (d) {
+        var on = shown[d.getAttribute("data-rule")] !== false;
+        d.classList.toggle("hidden", !on);
+        if (on) { visible++; }
+      });
+      section.classList.toggle("hidden", visible === 0);
+      var item = document.querySelector('nav li[data-file="' + section.id + '"]');
+      if (item) {
+        item.classList.toggle("hidden", visible === 0);
+        item.querySelector(".count").textContent = visible;
+      }
+    });
+  }
+  filters.forEach(function (f) { f.addEventListener("change", apply); });
+  document.querySelectorAll("button[data-action]").forEach(function (button) {
+    button.addEventListener("click", function () {
+      var action = button.getAttribute("data-action");
+      if (action === "all-rules" || action === "no-rules") {
+        filters.forEach(function (f) { f.checked = action === "all-rules"; });
+        apply();
+      } else {
+        document.querySelectorAll("details.warning").forEach(function (d) { d.open = action === "expand"; });
+      }
+    });
+  });
+})();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Fix;
+
+    fn warning(line: usize, column: usize, rule: &str, message: &str) -> LintWarning {
+        LintWarning {
+            line,
+            column,
+            end_line: line,
+            end_column: column,
+            rule_name: Some(rule.to_string()),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_report_lists_files_rules_and_warnings() {
+        let mut fixable = warning(3, 5, "MD009", "Trailing spaces");
+        fixable.fix = Some(Fix::new(0..1, String::new()));
+        let all_warnings = vec![(
+            "docs/a.md".to_string(),
+            vec![fixable, warning(1, 1, "MD041", "First line should be a heading")],
+        )];
+        let all_files = vec!["docs/a.md".to_string(), "clean.md".to_string()];
+        let html = format_html_report(&all_warnings, &all_files, 1500, &DocsLinks::default());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("2 issues in 1 file of 2 checked in 1.500s"));
+        assert!(html.contains("<a href=\"#file-0\">docs/a.md</a>"));
+        assert!(html.contains("<li class=\"clean\">clean.md"));
+        assert!(html.contains("class=\"rule-filter\" value=\"MD009\" checked> MD009 <span class=\"count\">1</span>"));
+        assert!(html.contains("<details class=\"warning sev-warning\" data-rule=\"MD041\" open>"));
+        assert!(html.contains("<a class=\"rule\" href=\"https://rumdl.dev/md009/\">MD009</a>"));
+        assert_eq!(html.matches("<span class=\"fixable\">").count(), 1);
+        assert!(!html.contains("<script src"), "the report must be self-contained");
+    }
+
+    #[test]
+    fn test_context_lines_are_escaped_and_highlighted() {
+        let content = "# Title\n\nSome <b>bold</b> & `code` [link](url.md)\n- item\n\n";
+        let warnings = vec![warning(3, 6, "MD033", "Inline HTML [Element: b]")];
+        let html = HtmlFormatter::new().format_warnings_with_content(&warnings, "a.md", content);
+
+        assert!(html.contains(
+            "<span class=\"line\"><span class=\"ln\">1</span><span class=\"tok-heading\"># Title</span></span>"
+        ));
+        assert!(html.contains(
+            "<span class=\"line current\"><span class=\"ln\">3</span>Some <span class=\"tok-html\">&lt;b&gt;</span>bold<span class=\"tok-html\">&lt;/b&gt;</span> &amp; <span class=\"tok-code\">`code`</span> [link]<span class=\"tok-link\">(url.md)</span></span>"
+        ));
+        assert!(html.contains("<span class=\"ln\">4</span><span class=\"tok-marker\">-</span> item</span>"));
+        assert!(html.contains("<span class=\"ln\">5</span></span>"));
+        assert!(!html.contains("<span class=\"ln\">6</span>"));
+    }
+
+    #[test]
+    fn test_clean_run_and_unreadable_source() {
+        let html = format_html_report(&[], &["a.md".to_string()], 0, &DocsLinks::default());
+        assert!(html.contains("All checked files passed."));
+        assert!(!html.contains("<section"));
+
+        let missing = vec![("missing/file.md".to_string(), vec![warning(1, 1, "MD001", "x")])];
+        let html = format_html_report(&missing, &[], 0, &DocsLinks::default());
+        assert!(html.contains("<section class=\"file\" id=\"file-0\">"));
+        assert!(!html.contains("<pre class=\"context\">"));
+    }
+
+    #[test]
+    fn test_special_characters_are_escaped() {
+        let warnings = vec![warning(1, 1, "MD001", "Bad <script>alert('x')</script>")];
+        let html = HtmlFormatter::new().format_warnings(&warnings, "a&b\".md");
+        assert!(html.contains("Bad &lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+        assert!(html.contains("a&amp;b&quot;.md"));
+        assert_eq!(html.matches("<script>").count(), 1);
+    }
+}
@@ -1,7 +1,7 @@
 //! JSON output formatter
 
 use crate::output::OutputFormatter;
-use crate::rule::LintWarning;
+use crate::rule::{FixSafety, LintWarning};
 use serde_json::{Value, json};
 
 /// JSON formatter for machine-readable output
@@ -31,24 +31,41 @@ impl OutputFormatter for JsonFormatter {
 
         let json_warnings: Vec<Value> = warnings
             .iter()
-            .map(|warning| {
-                json!({
-                    "file": file_path,
-                    "line": warning.line,
-                    "column": warning.column,
-                    "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
-                    "message": warning.message,
-                    "severity": warning.severity,
-                    "fixable": warning.fix.is_some(),
-                    "fix": warning.fix.as_ref().map(fix_to_json),
-                })
-            })
+            .map(|warning| warning_to_json(file_path, warning))
             .collect();
 
         serde_json::to_string_pretty(&json_warnings).unwrap_or_default()
     }
 }
 
+/// The JSON object for a single warning, as emitted by `--output-format json`
+pub fn warning_to_json(file_path: &str, warning: &LintWarning) -> Value {
+    json!({
+        "file": file_path,
+        "line": warning.line,
+        "column": warning.column,
+        "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
+        "message": warning.message,
+        "severity": warning.severity,
+        "fixable": warning.automatic_fix().is_some(),
+        "fix": warning.fix.as_ref().map(|fix| {
+            let mut obj = fix_to_json(fix);
+            obj["safety"] = json!(fix_safety(warning));
+            obj
+        }),
+    })
+}
+
+/// Whether the warning's rule marks its fixes unsafe. Warnings from outside
+/// rumdl's rules (such as code block linters) count as safe.
+fn fix_safety(warning: &LintWarning) -> FixSafety {
+    warning
+        .rule_name
+        .as_deref()
+        .and_then(crate::registry::fix_safety)
+        .unwrap_or_default()
+}
+
 fn fix_to_json(fix: &crate::rule::Fix) -> serde_json::Value {
     let mut obj = json!({
         "range": {
@@ -57,6 +74,9 @@ fn fix_to_json(fix: &crate::rule::Fix) -> serde_json::Value {
         },
         "replacement": fix.replacement,
     });
+    if !fix.is_automatic() {
+        obj["applicability"] = json!(fix.applicability);
+    }
     if !fix.additional_edits.is_empty() {
         obj["additional_edits"] = serde_json::Value::Array(fix.additional_edits.iter().map(fix_to_json).collect());
     }
@@ -72,16 +92,7 @@ pub fn format_all_warnings_as_json(all_warnings: &[(String, Vec<LintWarning>)])
 
     for (file_path, warnings) in all_warnings {
         for warning in warnings {
-            json_warnings.push(json!({
-                "file": file_path,
-                "line": warning.line,
-                "column": warning.column,
-                "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
-                "message": warning.message,
-                "severity": warning.severity,
-                "fixable": warning.fix.is_some(),
-                "fix": warning.fix.as_ref().map(fix_to_json),
-            }));
+            json_warnings.push(warning_to_json(file_path, warning));
         }
     }
 
@@ -169,6 +180,29 @@ mod tests {
         assert!(parsed[0]["fix"].is_null());
     }
 
+    #[test]
+    fn test_format_warning_with_suggestion() {
+        let formatter = JsonFormatter::new();
+        let warnings = vec![LintWarning {
+            line: 3,
+            column: 4,
+            end_line: 3,
+            end_column: 9,
+            rule_name: Some("MD024".to_string()),
+            message: "Duplicate heading: 'Usage'.".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix::suggestion(20..25, "Usage (2)".to_string())),
+        }];
+
+        let output = formatter.format_warnings(&warnings, "README.md");
+        let parsed: Vec<Value> = serde_json::from_str(&output).unwrap();
+
+        // Suggested edits are reported but not counted as fixable
+        assert_eq!(parsed[0]["fixable"], false);
+        assert_eq!(parsed[0]["fix"]["replacement"], "Usage (2)");
+        assert_eq!(parsed[0]["fix"]["applicability"], "suggested");
+    }
+
     #[test]
     fn test_format_warning_with_fix() {
         let formatter = JsonFormatter::new();
@@ -200,6 +234,29 @@ mod tests {
         assert_eq!(parsed[0]["fix"]["replacement"], "\n# Heading\n");
     }
 
+    #[test]
+    fn test_fix_safety_follows_rule() {
+        let warning = |rule: &str| LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 2,
+            rule_name: Some(rule.to_string()),
+            message: "msg".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix::new(0..1, String::new())),
+        };
+
+        assert_eq!(warning_to_json("a.md", &warning("MD009"))["fix"]["safety"], "safe");
+        assert_eq!(warning_to_json("a.md", &warning("MD013"))["fix"]["safety"], "unsafe");
+        assert_eq!(
+            warning_to_json("a.md", &warning("line-length"))["fix"]["safety"],
+            "unsafe"
+        );
+        // Warnings from outside rumdl's rules have nothing marking them unsafe
+        assert_eq!(warning_to_json("a.md", &warning("ruff:E501"))["fix"]["safety"], "safe");
+    }
+
     #[test]
     fn test_format_warning_with_additional_edits() {
         // Models MD054 ref-emit: a fix with one additional_edit. The JSON
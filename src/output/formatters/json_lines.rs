@@ -1,8 +1,8 @@
 //! JSON Lines output formatter (one JSON object per line)
 
 use crate::output::OutputFormatter;
+use crate::output::formatters::json::warning_to_json;
 use crate::rule::LintWarning;
-use serde_json::json;
 
 /// JSON Lines formatter - one JSON object per line
 pub struct JsonLinesFormatter;
@@ -24,15 +24,7 @@ impl OutputFormatter for JsonLinesFormatter {
         let mut output = String::new();
 
         for warning in warnings {
-            let json_obj = json!({
-                "file": file_path,
-                "line": warning.line,
-                "column": warning.column,
-                "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
-                "message": warning.message,
-                "severity": warning.severity,
-                "fixable": warning.fix.is_some()
-            });
+            let json_obj = warning_to_json(file_path, warning);
 
             // Compact JSON representation on a single line
             if let Ok(json_str) = serde_json::to_string(&json_obj) {
@@ -125,6 +117,10 @@ mod tests {
         // Parse the JSON to verify structure
         let json: Value = serde_json::from_str(&output).unwrap();
         assert_eq!(json["fixable"], true);
+        assert_eq!(json["fix"]["range"]["start"], 100);
+        assert_eq!(json["fix"]["range"]["end"], 110);
+        assert_eq!(json["fix"]["replacement"], "## Heading");
+        assert_eq!(json["fix"]["safety"], "safe");
     }
 
     #[test]
@@ -6,11 +6,13 @@ pub mod full;
 pub mod github;
 pub mod gitlab;
 pub mod grouped;
+pub mod html;
 pub mod json;
 pub mod json_lines;
 pub mod junit;
 pub mod pylint;
 pub mod sarif;
+pub mod teamcity;
 pub mod text;
 
 pub use azure::AzureFormatter;
@@ -19,9 +21,11 @@ pub use full::FullFormatter;
 pub use github::GitHubFormatter;
 pub use gitlab::GitLabFormatter;
 pub use grouped::GroupedFormatter;
+pub use html::HtmlFormatter;
 pub use json::JsonFormatter;
 pub use json_lines::JsonLinesFormatter;
 pub use junit::JunitFormatter;
 pub use pylint::PylintFormatter;
 pub use sarif::SarifFormatter;
+pub use teamcity::TeamCityFormatter;
 pub use text::TextFormatter;
@@ -1,6 +1,6 @@
 //! SARIF 2.1.0 output format
 
-use crate::output::OutputFormatter;
+use crate::output::{DocsLinks, OutputFormatter};
 use crate::rule::LintWarning;
 use serde_json::json;
 
@@ -76,6 +76,12 @@ impl OutputFormatter for SarifFormatter {
 /// In fix mode, only remaining (unfixed) warnings are passed in,
 /// matching ESLint/Ruff convention of reporting only what's left.
 pub fn format_sarif_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    format_sarif_report_with_docs(all_warnings, &DocsLinks::default())
+}
+
+/// Format a SARIF 2.1.0 report whose rule descriptors link to the
+/// documentation given by `docs` (`helpUri`)
+pub fn format_sarif_report_with_docs(all_warnings: &[(String, Vec<LintWarning>)], docs: &DocsLinks) -> String {
     let mut results = Vec::new();
     let mut rules = std::collections::HashMap::new();
 
@@ -84,7 +90,7 @@ pub fn format_sarif_report(all_warnings: &[(String, Vec<LintWarning>)]) -> Strin
             let rule_id = warning.rule_name.as_deref().unwrap_or("unknown");
 
             rules.entry(rule_id).or_insert_with(|| {
-                json!({
+                let mut descriptor = json!({
                     "id": rule_id,
                     "name": rule_id,
                     "shortDescription": {
@@ -93,7 +99,11 @@ pub fn format_sarif_report(all_warnings: &[(String, Vec<LintWarning>)]) -> Strin
                     "fullDescription": {
                         "text": format!("Markdown linting rule {}", rule_id)
                     }
-                })
+                });
+                if let Some(help_uri) = docs.url(rule_id) {
+                    descriptor["helpUri"] = json!(help_uri);
+                }
+                descriptor
             });
 
             let level = match warning.severity {
@@ -941,6 +951,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rule_help_uri_follows_docs_links() {
+        let warning = |rule: &str| LintWarning {
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 5,
+            rule_name: Some(rule.to_string()),
+            message: "Warning".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        };
+        let warnings = vec![(
+            "test.md".to_string(),
+            vec![warning("MD001"), warning("MD013"), warning("shellcheck")],
+        )];
+        let help_uri = |output: &str, rule: &str| {
+            let sarif: Value = serde_json::from_str(output).unwrap();
+            sarif["runs"][0]["tool"]["driver"]["rules"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|r| r["id"] == rule)
+                .unwrap()
+                .get("helpUri")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+
+        let output = format_sarif_report(&warnings);
+        assert_eq!(help_uri(&output, "MD001").as_deref(), Some("https://rumdl.dev/md001/"));
+        assert_eq!(help_uri(&output, "shellcheck"), None);
+
+        let config: crate::config::Config = toml::from_str(
+            r#"
+[global]
+docs-base-url = "https://docs.example.com/markdown"
+
+[MD013]
+explain-url = "https://wiki.example.com/style#line-length"
+"#,
+        )
+        .unwrap();
+        let output = format_sarif_report_with_docs(&warnings, &DocsLinks::from_config(&config));
+        assert_eq!(
+            help_uri(&output, "MD001").as_deref(),
+            Some("https://docs.example.com/markdown/md001/")
+        );
+        assert_eq!(
+            help_uri(&output, "MD013").as_deref(),
+            Some("https://wiki.example.com/style#line-length")
+        );
+    }
+
     #[test]
     fn test_unknown_rule_with_error_severity() {
         // Edge case: Unknown rule (None) with Error severity
@@ -0,0 +1,142 @@
+//! TeamCity service message format
+
+use crate::output::OutputFormatter;
+use crate::rule::{LintWarning, Severity};
+use std::collections::BTreeSet;
+
+/// TeamCity formatter
+/// Outputs inspection service messages, declaring each rule once per file before its first use:
+/// `##teamcity[inspectionType id='<rule>' name='<rule>' description='<rule>' category='rumdl']`
+/// `##teamcity[inspection typeId='<rule>' message='<message>' file='<file>' line='<line>' SEVERITY='<severity>']`
+pub struct TeamCityFormatter;
+
+impl Default for TeamCityFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl TeamCityFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Escape a value for a TeamCity service message attribute
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '|' => escaped.push_str("||"),
+            '\'' => escaped.push_str("|'"),
+            '\n' => escaped.push_str("|n"),
+            '\r' => escaped.push_str("|r"),
+            '[' => escaped.push_str("|["),
+            ']' => escaped.push_str("|]"),
+            // TeamCity takes four hex digits, so characters outside the BMP
+            // are written as their UTF-16 surrogate pair
+            c if !c.is_ascii() => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    escaped.push_str(&format!("|0x{unit:04x}"));
+                }
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl OutputFormatter for TeamCityFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        let mut output = String::new();
+        let mut declared = BTreeSet::new();
+        let file = escape(file_path);
+
+        for warning in warnings {
+            let rule_name = escape(warning.rule_name.as_deref().unwrap_or("unknown"));
+
+            // TeamCity requires an inspection type before inspections refer to it
+            if declared.insert(rule_name.clone()) {
+                output.push_str(&format!(
+                    "##teamcity[inspectionType id='{rule_name}' name='{rule_name}' description='{rule_name}' category='rumdl']\n"
+                ));
+            }
+
+            let severity = match warning.severity {
+                Severity::Error => "ERROR",
+                Severity::Warning => "WARNING",
+                Severity::Info => "INFO",
+            };
+
+            output.push_str(&format!(
+                "##teamcity[inspection typeId='{rule_name}' message='{}' file='{file}' line='{}' SEVERITY='{severity}']\n",
+                escape(&warning.message),
+                warning.line
+            ));
+        }
+
+        // Remove trailing newline
+        if output.ends_with('\n') {
+            output.pop();
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(line: usize, rule_name: &str, message: &str, severity: Severity) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 5,
+            rule_name: Some(rule_name.to_string()),
+            message: message.to_string(),
+            severity,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_format_warnings_empty() {
+        let formatter = TeamCityFormatter::new();
+        assert_eq!(formatter.format_warnings(&[], "test.md"), "");
+    }
+
+    #[test]
+    fn test_declares_each_rule_once() {
+        let formatter = TeamCityFormatter::new();
+        let warnings = vec![
+            warning(1, "MD013", "Line too long", Severity::Warning),
+            warning(4, "MD013", "Line too long", Severity::Warning),
+            warning(7, "MD025", "Multiple H1", Severity::Error),
+        ];
+
+        let output = formatter.format_warnings(&warnings, "docs/a.md");
+        assert_eq!(
+            output,
+            "##teamcity[inspectionType id='MD013' name='MD013' description='MD013' category='rumdl']\n\
+             ##teamcity[inspection typeId='MD013' message='Line too long' file='docs/a.md' line='1' SEVERITY='WARNING']\n\
+             ##teamcity[inspection typeId='MD013' message='Line too long' file='docs/a.md' line='4' SEVERITY='WARNING']\n\
+             ##teamcity[inspectionType id='MD025' name='MD025' description='MD025' category='rumdl']\n\
+             ##teamcity[inspection typeId='MD025' message='Multiple H1' file='docs/a.md' line='7' SEVERITY='ERROR']"
+        );
+    }
+
+    #[test]
+    fn test_escapes_special_characters() {
+        assert_eq!(escape("it's [a|b]\r\n"), "it|'s |[a||b|]|r|n");
+        assert_eq!(escape("café"), "caf|0x00e9");
+        assert_eq!(escape("ok 👍"), "ok |0xd83d|0xdc4d");
+
+        let formatter = TeamCityFormatter::new();
+        let output = formatter.format_warnings(&[warning(2, "MD044", "Use 'JavaScript'", Severity::Info)], "it's.md");
+        assert!(output.ends_with(
+            "##teamcity[inspection typeId='MD044' message='Use |'JavaScript|'' file='it|'s.md' line='2' SEVERITY='INFO']"
+        ));
+    }
+}
@@ -1,17 +1,25 @@
 //! Default text output formatter with colors and context
 
-use crate::output::OutputFormatter;
-use crate::rule::LintWarning;
+use super::full::render_source_context;
+use crate::output::{GroupBy, OutputFormatter};
+use crate::rule::{LintWarning, Severity};
 use colored::*;
 
+/// Lines of source shown before and after a warning with `--show-source`
+const SOURCE_CONTEXT_LINES: usize = 2;
+
 /// Default human-readable formatter with colors
 pub struct TextFormatter {
     use_colors: bool,
+    show_source: bool,
 }
 
 impl Default for TextFormatter {
     fn default() -> Self {
-        Self { use_colors: true }
+        Self {
+            use_colors: true,
+            show_source: false,
+        }
     }
 }
 
@@ -23,7 +31,168 @@ impl TextFormatter {
 
 impl TextFormatter {
     pub fn without_colors() -> Self {
-        Self { use_colors: false }
+        Self {
+            use_colors: false,
+            show_source: false,
+        }
+    }
+
+    /// Follow each warning with its source line, a caret underline of the
+    /// span and a few lines of context, like the `full` format
+    pub fn with_source(mut self, show_source: bool) -> Self {
+        self.show_source = show_source;
+        self
+    }
+
+    /// Format the warnings of a whole run, arranged by `group_by`. Grouping by
+    /// file gives the same lines as formatting each file in turn.
+    pub fn format_report(&self, file_warnings: &[(String, Vec<LintWarning>)], group_by: GroupBy) -> String {
+        match group_by {
+            GroupBy::File => file_warnings
+                .iter()
+                .map(|(path, warnings)| self.format_warnings(warnings, path))
+                .filter(|output| !output.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            GroupBy::Rule => self.format_by_rule(file_warnings),
+            GroupBy::Severity => self.format_by_severity(file_warnings),
+        }
+    }
+
+    /// One section per rule: a count header, then each file with the
+    /// positions of its warnings on one line
+    fn format_by_rule(&self, file_warnings: &[(String, Vec<LintWarning>)]) -> String {
+        let mut rules: Vec<(&str, Vec<(&str, &LintWarning)>)> = Vec::new();
+        for (path, warnings) in file_warnings {
+            for warning in warnings {
+                let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
+                match rules.iter_mut().find(|(name, _)| *name == rule_name) {
+                    Some((_, hits)) => hits.push((path, warning)),
+                    None => rules.push((rule_name, vec![(path, warning)])),
+                }
+            }
+        }
+        rules.sort_by(|(a_name, a_hits), (b_name, b_hits)| b_hits.len().cmp(&a_hits.len()).then(a_name.cmp(b_name)));
+
+        let sections: Vec<String> = rules
+            .iter()
+            .map(|(rule_name, hits)| {
+                let mut paths: Vec<&str> = Vec::new();
+                for (path, _) in hits {
+                    if !paths.contains(path) {
+                        paths.push(path);
+                    }
+                }
+                let fixable = hits.iter().filter(|(_, w)| w.automatic_fix().is_some()).count();
+                let mut lines = vec![self.header(&self.paint_rule(rule_name), hits.len(), paths.len(), fixable)];
+                for path in paths {
+                    let positions: Vec<String> = hits
+                        .iter()
+                        .filter(|(hit_path, _)| *hit_path == path)
+                        .map(|(_, w)| self.paint_position(&format!("{}:{}", w.line, w.column)))
+                        .collect();
+                    lines.push(format!("  {}: {}", self.paint_path(path), positions.join(", ")));
+                }
+                lines.join("\n")
+            })
+            .collect();
+        sections.join("\n\n")
+    }
+
+    /// One section per severity: a count header, then the usual warning lines
+    fn format_by_severity(&self, file_warnings: &[(String, Vec<LintWarning>)]) -> String {
+        let mut sections = Vec::new();
+        for severity in [Severity::Error, Severity::Warning, Severity::Info] {
+            let files: Vec<(&str, Vec<LintWarning>)> = file_warnings
+                .iter()
+                .map(|(path, warnings)| {
+                    let matching = warnings.iter().filter(|w| w.severity == severity).cloned().collect();
+                    (path.as_str(), matching)
+                })
+                .filter(|(_, warnings): &(&str, Vec<LintWarning>)| !warnings.is_empty())
+                .collect();
+            if files.is_empty() {
+                continue;
+            }
+
+            let total = files.iter().map(|(_, warnings)| warnings.len()).sum();
+            let fixable = files
+                .iter()
+                .flat_map(|(_, warnings)| warnings)
+                .filter(|w| w.automatic_fix().is_some())
+                .count();
+            let mut lines = vec![self.header(&self.paint_severity(severity), total, files.len(), fixable)];
+            for (path, warnings) in &files {
+                lines.extend(
+                    self.format_warnings(warnings, path)
+                        .lines()
+                        .map(|line| format!("  {line}")),
+                );
+            }
+            sections.push(lines.join("\n"));
+        }
+        sections.join("\n\n")
+    }
+
+    /// `<label>: 3 issues in 2 files (1 fixable)`
+    fn header(&self, label: &str, issues: usize, files: usize, fixable: usize) -> String {
+        let plural = |count: usize, noun: &str| {
+            if count == 1 {
+                format!("{count} {noun}")
+            } else {
+                format!("{count} {noun}s")
+            }
+        };
+        let mut header = format!("{label}: {} in {}", plural(issues, "issue"), plural(files, "file"));
+        if fixable > 0 {
+            let note = format!(" ({fixable} fixable)");
+            header.push_str(&if self.use_colors {
+                note.green().to_string()
+            } else {
+                note
+            });
+        }
+        header
+    }
+
+    fn paint_rule(&self, rule_name: &str) -> String {
+        if self.use_colors {
+            rule_name.yellow().bold().to_string()
+        } else {
+            rule_name.to_string()
+        }
+    }
+
+    fn paint_severity(&self, severity: Severity) -> String {
+        let label = match severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        if !self.use_colors {
+            return label.to_string();
+        }
+        match severity {
+            Severity::Error => label.red().bold().to_string(),
+            Severity::Warning => label.yellow().bold().to_string(),
+            Severity::Info => label.blue().bold().to_string(),
+        }
+    }
+
+    fn paint_path(&self, path: &str) -> String {
+        if self.use_colors {
+            path.blue().underline().to_string()
+        } else {
+            path.to_string()
+        }
+    }
+
+    fn paint_position(&self, position: &str) -> String {
+        if self.use_colors {
+            position.cyan().to_string()
+        } else {
+            position.to_string()
+        }
     }
 }
 
@@ -35,7 +204,7 @@ impl OutputFormatter for TextFormatter {
             let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
 
             // Add fix indicator if this warning has a fix
-            let fix_indicator = if warning.fix.is_some() { " [*]" } else { "" };
+            let fix_indicator = if warning.automatic_fix().is_some() { " [*]" } else { "" };
 
             // Format: file:line:column: [rule] message [*]
             let line = format!(
@@ -80,6 +249,29 @@ impl OutputFormatter for TextFormatter {
         output
     }
 
+    fn format_warnings_with_content(&self, warnings: &[LintWarning], file_path: &str, content: &str) -> String {
+        if !self.show_source || content.is_empty() {
+            return self.format_warnings(warnings, file_path);
+        }
+
+        let lines: Vec<&str> = content.lines().collect();
+        let mut output = String::new();
+        for (i, warning) in warnings.iter().enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            output.push_str(&self.format_warnings(std::slice::from_ref(warning), file_path));
+            output.push('\n');
+            render_source_context(&mut output, warning, &lines, SOURCE_CONTEXT_LINES, self.use_colors);
+        }
+
+        if output.ends_with('\n') {
+            output.pop();
+        }
+
+        output
+    }
+
     fn use_colors(&self) -> bool {
         self.use_colors
     }
@@ -315,6 +507,119 @@ mod tests {
         assert!(output.starts_with("path/with spaces/and-dashes.md:1:1:"));
     }
 
+    fn report_warning(line: usize, rule_name: &str, severity: Severity, fixable: bool) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 5,
+            rule_name: Some(rule_name.to_string()),
+            message: format!("{rule_name} warning"),
+            severity,
+            fix: fixable.then(|| Fix::new(0..1, String::new())),
+        }
+    }
+
+    fn report() -> Vec<(String, Vec<LintWarning>)> {
+        vec![
+            (
+                "a.md".to_string(),
+                vec![
+                    report_warning(1, "MD013", Severity::Warning, false),
+                    report_warning(3, "MD022", Severity::Error, true),
+                    report_warning(5, "MD013", Severity::Warning, false),
+                ],
+            ),
+            (
+                "b.md".to_string(),
+                vec![report_warning(2, "MD013", Severity::Warning, true)],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_format_report_by_file() {
+        let formatter = TextFormatter::without_colors();
+        let output = formatter.format_report(&report(), GroupBy::File);
+        assert_eq!(output.lines().count(), 4);
+        assert!(output.starts_with("a.md:1:1: [MD013] MD013 warning\n"));
+    }
+
+    #[test]
+    fn test_format_report_by_rule() {
+        let formatter = TextFormatter::without_colors();
+        assert_eq!(
+            formatter.format_report(&report(), GroupBy::Rule),
+            "MD013: 3 issues in 2 files (1 fixable)\n  a.md: 1:1, 5:1\n  b.md: 2:1\n\n\
+             MD022: 1 issue in 1 file (1 fixable)\n  a.md: 3:1"
+        );
+    }
+
+    #[test]
+    fn test_format_report_by_severity() {
+        let formatter = TextFormatter::without_colors();
+        assert_eq!(
+            formatter.format_report(&report(), GroupBy::Severity),
+            "error: 1 issue in 1 file (1 fixable)\n  a.md:3:1: [MD022] MD022 warning [*]\n\n\
+             warning: 3 issues in 2 files (1 fixable)\n  a.md:1:1: [MD013] MD013 warning\n  \
+             a.md:5:1: [MD013] MD013 warning\n  b.md:2:1: [MD013] MD013 warning [*]"
+        );
+    }
+
+    fn source_warning(line: usize, column: usize, end_column: usize, rule: &str, message: &str) -> LintWarning {
+        LintWarning {
+            line,
+            column,
+            end_line: line,
+            end_column,
+            rule_name: Some(rule.to_string()),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_show_source_renders_context_and_span() {
+        let content = "# Title\n\nSome text   \nMore\nEnd\nLast\n";
+        let warning = source_warning(3, 10, 13, "MD009", "Trailing spaces");
+        let formatter = TextFormatter::without_colors().with_source(true);
+        let output = formatter.format_warnings_with_content(&[warning], "test.md", content);
+
+        assert_eq!(
+            output,
+            "test.md:3:10: [MD009] Trailing spaces\n   |\n 1 | # Title\n 2 | \n 3 | Some text   \n   |          ^^^\n 4 | More\n 5 | End\n   |"
+        );
+    }
+
+    #[test]
+    fn test_show_source_off_or_without_content_keeps_plain_lines() {
+        let warnings = vec![source_warning(1, 1, 1, "MD041", "First line")];
+        let plain = TextFormatter::without_colors().format_warnings(&warnings, "test.md");
+
+        let off = TextFormatter::without_colors().format_warnings_with_content(&warnings, "test.md", "text\n");
+        assert_eq!(off, plain);
+        let no_content = TextFormatter::without_colors()
+            .with_source(true)
+            .format_warnings_with_content(&warnings, "test.md", "");
+        assert_eq!(no_content, plain);
+    }
+
+    #[test]
+    fn test_show_source_separates_warnings_and_aligns_tabs() {
+        let content = "\tindented\t\n";
+        let warnings = vec![
+            source_warning(1, 1, 2, "MD010", "Leading tab"),
+            source_warning(1, 10, 11, "MD010", "Trailing tab"),
+        ];
+        let output = TextFormatter::without_colors()
+            .with_source(true)
+            .format_warnings_with_content(&warnings, "test.md", content);
+
+        assert!(output.contains("   |\n\ntest.md:1:10:"));
+        assert!(output.contains("\n   | \t        ^\n"));
+    }
+
     #[test]
     fn test_use_colors_trait_method() {
         let formatter_with_colors = TextFormatter::new();
@@ -4,15 +4,23 @@
 //! similar to how Ruff handles multiple output formats.
 
 use crate::rule::LintWarning;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::io::{self, Write};
 use std::str::FromStr;
 
 pub mod formatters;
+pub mod registry;
 
 // Re-export formatters
 pub use formatters::*;
+pub use registry::{
+    BatchFormatter, DocsLinksFormatter, Formatter, FormatterRegistry, RunSummary, StreamingFormatter, format_run,
+    formatter_by_name, formatter_names, register_formatter,
+};
 
-/// Trait for output formatters
+/// Trait for output formatters that render one file's warnings at a time.
+/// Wrap one in a [`StreamingFormatter`] to drive it as a [`Formatter`].
 pub trait OutputFormatter {
     /// Format a collection of warnings for output
     fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String;
@@ -59,10 +67,14 @@ pub enum OutputFormat {
     Pylint,
     /// Azure Pipeline logging format
     Azure,
+    /// TeamCity inspection service messages
+    TeamCity,
     /// SARIF 2.1.0 format
     Sarif,
     /// JUnit XML format
     Junit,
+    /// Standalone HTML report
+    Html,
 }
 
 impl FromStr for OutputFormat {
@@ -80,37 +92,130 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(OutputFormat::GitLab),
             "pylint" => Ok(OutputFormat::Pylint),
             "azure" => Ok(OutputFormat::Azure),
+            "teamcity" => Ok(OutputFormat::TeamCity),
             "sarif" => Ok(OutputFormat::Sarif),
             "junit" => Ok(OutputFormat::Junit),
+            "html" => Ok(OutputFormat::Html),
             _ => Err(format!("Unknown output format: {s}")),
         }
     }
 }
 
 impl OutputFormat {
+    /// Every built-in format
+    pub const ALL: [OutputFormat; 14] = [
+        OutputFormat::Text,
+        OutputFormat::Full,
+        OutputFormat::Concise,
+        OutputFormat::Grouped,
+        OutputFormat::Json,
+        OutputFormat::JsonLines,
+        OutputFormat::GitHub,
+        OutputFormat::GitLab,
+        OutputFormat::Pylint,
+        OutputFormat::Azure,
+        OutputFormat::TeamCity,
+        OutputFormat::Sarif,
+        OutputFormat::Junit,
+        OutputFormat::Html,
+    ];
+
+    /// Canonical name, as accepted by `--output-format`
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Full => "full",
+            OutputFormat::Concise => "concise",
+            OutputFormat::Grouped => "grouped",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonLines => "json-lines",
+            OutputFormat::GitHub => "github",
+            OutputFormat::GitLab => "gitlab",
+            OutputFormat::Pylint => "pylint",
+            OutputFormat::Azure => "azure",
+            OutputFormat::TeamCity => "teamcity",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Html => "html",
+        }
+    }
+
+    /// Create a lifecycle [`Formatter`] for this format
+    pub fn formatter(&self) -> Box<dyn Formatter> {
+        self.formatter_with_docs(&DocsLinks::default(), false)
+    }
+
+    /// Create a lifecycle [`Formatter`] whose help links come from `docs`.
+    /// With `show_links`, human-readable formats follow each file's
+    /// diagnostics with the documentation links of the rules they name.
+    pub fn formatter_with_docs(&self, docs: &DocsLinks, show_links: bool) -> Box<dyn Formatter> {
+        self.formatter_with_source(docs, show_links, false)
+    }
+
+    /// Like [`OutputFormat::formatter_with_docs`]; with `show_source`, the
+    /// `text` format follows each warning with the source lines around it.
+    /// Other formats either always show source (`full`) or never do.
+    pub fn formatter_with_source(&self, docs: &DocsLinks, show_links: bool, show_source: bool) -> Box<dyn Formatter> {
+        let formatter = self.base_formatter(docs, show_source);
+        if show_links && !formatter.is_machine_readable() {
+            Box::new(DocsLinksFormatter::new(formatter, docs.clone()))
+        } else {
+            formatter
+        }
+    }
+
+    fn base_formatter(&self, docs: &DocsLinks, show_source: bool) -> Box<dyn Formatter> {
+        match self {
+            OutputFormat::Text => Box::new(StreamingFormatter::human(TextFormatter::new().with_source(show_source))),
+            OutputFormat::Full => Box::new(StreamingFormatter::human(FullFormatter::new())),
+            OutputFormat::Concise => Box::new(StreamingFormatter::human(ConciseFormatter::new())),
+            OutputFormat::Grouped => Box::new(StreamingFormatter::human(GroupedFormatter::new())),
+            OutputFormat::JsonLines => Box::new(StreamingFormatter::new(JsonLinesFormatter::new())),
+            OutputFormat::GitHub => Box::new(StreamingFormatter::new(GitHubFormatter::new())),
+            OutputFormat::Pylint => Box::new(StreamingFormatter::new(PylintFormatter::new())),
+            OutputFormat::Azure => Box::new(StreamingFormatter::new(AzureFormatter::new())),
+            OutputFormat::TeamCity => Box::new(StreamingFormatter::new(TeamCityFormatter::new())),
+            OutputFormat::Json => Box::new(BatchFormatter::new(|file_warnings, _, _| {
+                formatters::json::format_all_warnings_as_json(file_warnings)
+            })),
+            OutputFormat::GitLab => Box::new(BatchFormatter::new(|file_warnings, _, _| {
+                formatters::gitlab::format_gitlab_report(file_warnings)
+            })),
+            OutputFormat::Sarif => {
+                let docs = docs.clone();
+                Box::new(BatchFormatter::new(move |file_warnings, _, _| {
+                    formatters::sarif::format_sarif_report_with_docs(file_warnings, &docs)
+                }))
+            }
+            OutputFormat::Junit => Box::new(BatchFormatter::with_all_files(formatters::junit::format_junit_report)),
+            OutputFormat::Html => {
+                let docs = docs.clone();
+                Box::new(BatchFormatter::with_all_files(
+                    move |file_warnings, all_files, duration_ms| {
+                        formatters::html::format_html_report(file_warnings, all_files, duration_ms, &docs)
+                    },
+                ))
+            }
+        }
+    }
+
     /// Whether this format produces machine-readable output that should not
     /// be mixed with human-readable summary lines.
     pub fn is_machine_readable(&self) -> bool {
-        !matches!(
-            self,
-            OutputFormat::Text | OutputFormat::Full | OutputFormat::Concise | OutputFormat::Grouped
-        )
+        self.formatter().is_machine_readable()
     }
 
     /// Whether this format is a batch format: a single document spanning all
     /// results, which therefore needs every file's warnings collected before
     /// anything is emitted. Streaming formats emit per file as results arrive.
     pub fn is_batch(&self) -> bool {
-        matches!(
-            self,
-            OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit
-        )
+        self.formatter().is_batch()
     }
 
     /// Whether this batch format also reports passing files and therefore
     /// needs every checked file's path, not just the warning-bearing ones.
     pub fn needs_all_files(&self) -> bool {
-        matches!(self, OutputFormat::Junit)
+        self.formatter().needs_all_files()
     }
 
     /// Format the complete result set for a batch format. Returns `None` for
@@ -119,23 +224,18 @@ impl OutputFormat {
     ///
     /// `all_files` and `duration_ms` are consumed only by formats that report
     /// passing files and run time (JUnit); issue-list formats ignore them.
+    /// `docs` supplies the help links of formats that carry them (SARIF).
     pub fn format_batch(
         &self,
         file_warnings: &[(String, Vec<LintWarning>)],
         all_files: &[String],
         duration_ms: u64,
+        docs: &DocsLinks,
     ) -> Option<String> {
-        match self {
-            OutputFormat::Json => Some(formatters::json::format_all_warnings_as_json(file_warnings)),
-            OutputFormat::GitLab => Some(formatters::gitlab::format_gitlab_report(file_warnings)),
-            OutputFormat::Sarif => Some(formatters::sarif::format_sarif_report(file_warnings)),
-            OutputFormat::Junit => Some(formatters::junit::format_junit_report(
-                file_warnings,
-                all_files,
-                duration_ms,
-            )),
-            _ => None,
-        }
+        let mut formatter = self.formatter_with_docs(docs, false);
+        formatter
+            .is_batch()
+            .then(|| format_run(formatter.as_mut(), file_warnings, all_files, duration_ms))
     }
 
     /// Create a formatter instance for this format
@@ -151,10 +251,144 @@ impl OutputFormat {
             OutputFormat::GitLab => Box::new(GitLabFormatter::new()),
             OutputFormat::Pylint => Box::new(PylintFormatter::new()),
             OutputFormat::Azure => Box::new(AzureFormatter::new()),
+            OutputFormat::TeamCity => Box::new(TeamCityFormatter::new()),
             OutputFormat::Sarif => Box::new(SarifFormatter::new()),
             OutputFormat::Junit => Box::new(JunitFormatter::new()),
+            OutputFormat::Html => Box::new(HtmlFormatter::new()),
+        }
+    }
+}
+
+/// How rules are named in diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuleNameDisplay {
+    /// Rule IDs such as `MD013` (default)
+    #[default]
+    Id,
+    /// Primary markdownlint aliases such as `line-length`
+    Alias,
+}
+
+impl RuleNameDisplay {
+    /// Display form of a single rule name; names without an alias pass through
+    pub fn name<'a>(&self, rule_name: &'a str) -> &'a str {
+        match self {
+            RuleNameDisplay::Id => rule_name,
+            RuleNameDisplay::Alias => crate::config::rule_primary_alias(rule_name).unwrap_or(rule_name),
         }
     }
+
+    /// Rewrite the rule names of `warnings` for display. Warnings are borrowed
+    /// unchanged when showing IDs, which is how rules report themselves.
+    pub fn apply<'a>(&self, warnings: &'a [LintWarning]) -> Cow<'a, [LintWarning]> {
+        match self {
+            RuleNameDisplay::Id => Cow::Borrowed(warnings),
+            RuleNameDisplay::Alias => Cow::Owned(
+                warnings
+                    .iter()
+                    .map(|warning| LintWarning {
+                        rule_name: warning.rule_name.as_deref().map(|name| self.name(name).to_string()),
+                        ..warning.clone()
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Apply [`RuleNameDisplay::apply`] to every file of a batch result set
+    pub fn apply_batch<'a>(
+        &self,
+        file_warnings: &'a [(String, Vec<LintWarning>)],
+    ) -> Cow<'a, [(String, Vec<LintWarning>)]> {
+        match self {
+            RuleNameDisplay::Id => Cow::Borrowed(file_warnings),
+            RuleNameDisplay::Alias => Cow::Owned(
+                file_warnings
+                    .iter()
+                    .map(|(path, warnings)| (path.clone(), self.apply(warnings).into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// How the text format arranges the diagnostics of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// Each file's warnings in turn, as they are checked (default)
+    #[default]
+    File,
+    /// One section per rule, most frequent first, with the positions in each file
+    Rule,
+    /// One section per severity, errors first
+    Severity,
+}
+
+/// Where rule documentation lives unless `docs-base-url` says otherwise
+pub const DEFAULT_DOCS_BASE_URL: &str = "https://rumdl.dev/";
+
+/// Documentation links for rules, as shown by SARIF help URIs, LSP code
+/// descriptions and `--show-docs-links`.
+///
+/// A rule links to `<docs-base-url>/<id>/` unless its config section sets
+/// `explain-url`, which replaces the link entirely. Names of external tools
+/// (code-block-tools linters) have no link unless they set one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocsLinks {
+    base_url: String,
+    overrides: BTreeMap<String, String>,
+}
+
+impl Default for DocsLinks {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_DOCS_BASE_URL.to_string(),
+            overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl DocsLinks {
+    /// Read `docs-base-url` and every rule's `explain-url` from `config`
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        let overrides = config
+            .rules
+            .iter()
+            .filter_map(|(rule_name, rule_config)| {
+                let url = rule_config
+                    .values
+                    .get("explain-url")
+                    .or_else(|| rule_config.values.get("explain_url"))?
+                    .as_str()?;
+                Some((crate::config::resolve_rule_name(rule_name), url.to_string()))
+            })
+            .collect();
+        Self {
+            base_url: config
+                .global
+                .docs_base_url
+                .clone()
+                .unwrap_or_else(|| DEFAULT_DOCS_BASE_URL.to_string()),
+            overrides,
+        }
+    }
+
+    /// Documentation link for a rule, given by ID or alias
+    pub fn url(&self, rule_name: &str) -> Option<String> {
+        let rule_id = crate::config::resolve_rule_name(rule_name);
+        if let Some(url) = self.overrides.get(&rule_id) {
+            return Some(url.clone());
+        }
+        let is_rumdl_rule =
+            rule_id.len() > 2 && rule_id.starts_with("MD") && rule_id[2..].chars().all(|c| c.is_ascii_digit());
+        is_rumdl_rule.then(|| {
+            format!(
+                "{}/{}/",
+                self.base_url.trim_end_matches('/'),
+                rule_id.to_ascii_lowercase()
+            )
+        })
+    }
 }
 
 /// Output writer that handles stdout/stderr routing
@@ -227,6 +461,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rule_name_display() {
+        let mut unknown = create_test_warning(2, "Custom");
+        unknown.rule_name = Some("custom-rule".to_string());
+        let warnings = vec![create_test_warning(1, "Heading"), unknown];
+
+        assert!(matches!(RuleNameDisplay::Id.apply(&warnings), Cow::Borrowed(_)));
+        let shown = RuleNameDisplay::Alias.apply(&warnings);
+        assert_eq!(shown[0].rule_name.as_deref(), Some("heading-increment"));
+        assert_eq!(shown[1].rule_name.as_deref(), Some("custom-rule"));
+    }
+
     fn create_test_warning_with_fix(line: usize, message: &str, fix_text: &str) -> LintWarning {
         LintWarning {
             line,
@@ -254,8 +500,10 @@ mod tests {
         assert_eq!(OutputFormat::from_str("gitlab").unwrap(), OutputFormat::GitLab);
         assert_eq!(OutputFormat::from_str("pylint").unwrap(), OutputFormat::Pylint);
         assert_eq!(OutputFormat::from_str("azure").unwrap(), OutputFormat::Azure);
+        assert_eq!(OutputFormat::from_str("teamcity").unwrap(), OutputFormat::TeamCity);
         assert_eq!(OutputFormat::from_str("sarif").unwrap(), OutputFormat::Sarif);
         assert_eq!(OutputFormat::from_str("junit").unwrap(), OutputFormat::Junit);
+        assert_eq!(OutputFormat::from_str("html").unwrap(), OutputFormat::Html);
 
         // Case insensitive
         assert_eq!(OutputFormat::from_str("TEXT").unwrap(), OutputFormat::Text);
@@ -282,8 +530,10 @@ mod tests {
             OutputFormat::GitLab,
             OutputFormat::Pylint,
             OutputFormat::Azure,
+            OutputFormat::TeamCity,
             OutputFormat::Sarif,
             OutputFormat::Junit,
+            OutputFormat::Html,
         ];
 
         for format in &formats {
@@ -464,8 +714,10 @@ mod tests {
             OutputFormat::GitLab,
             OutputFormat::Pylint,
             OutputFormat::Azure,
+            OutputFormat::TeamCity,
             OutputFormat::Sarif,
             OutputFormat::Junit,
+            OutputFormat::Html,
         ];
 
         for format in &formats {
@@ -495,6 +747,7 @@ mod tests {
             OutputFormat::GitHub,
             OutputFormat::Pylint,
             OutputFormat::Azure,
+            OutputFormat::TeamCity,
         ];
 
         let file_warnings = vec![("dirty.md".to_string(), vec![create_test_warning(1, "w")])];
@@ -503,14 +756,16 @@ mod tests {
         for format in &batch {
             assert!(format.is_batch(), "{format:?} is a batch format");
             let output = format
-                .format_batch(&file_warnings, &all_files, 5)
+                .format_batch(&file_warnings, &all_files, 5, &DocsLinks::default())
                 .unwrap_or_else(|| panic!("{format:?} must format a batch"));
             assert!(!output.is_empty());
         }
         for format in &streaming {
             assert!(!format.is_batch(), "{format:?} is a streaming format");
             assert!(
-                format.format_batch(&file_warnings, &all_files, 5).is_none(),
+                format
+                    .format_batch(&file_warnings, &all_files, 5, &DocsLinks::default())
+                    .is_none(),
                 "{format:?} must not claim batch output"
             );
         }
@@ -519,7 +774,9 @@ mod tests {
         for format in batch.iter().chain(&streaming) {
             assert_eq!(format.needs_all_files(), *format == OutputFormat::Junit);
         }
-        let junit = OutputFormat::Junit.format_batch(&file_warnings, &all_files, 5).unwrap();
+        let junit = OutputFormat::Junit
+            .format_batch(&file_warnings, &all_files, 5, &DocsLinks::default())
+            .unwrap();
         assert!(junit.contains("clean.md"), "JUnit batch output reports passing files");
     }
 
@@ -538,6 +795,7 @@ mod tests {
         assert!(OutputFormat::GitLab.is_machine_readable());
         assert!(OutputFormat::Pylint.is_machine_readable());
         assert!(OutputFormat::Azure.is_machine_readable());
+        assert!(OutputFormat::TeamCity.is_machine_readable());
         assert!(OutputFormat::Sarif.is_machine_readable());
         assert!(OutputFormat::Junit.is_machine_readable());
     }
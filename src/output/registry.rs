@@ -0,0 +1,415 @@
+//! Formatter lifecycle and registry
+//!
+//! A [`Formatter`] sees a lint run as a sequence of events: [`Formatter::init`]
+//! once, [`Formatter::file`] for every checked file (which by default calls
+//! [`Formatter::warning`] per warning), and [`Formatter::finish`] once at the
+//! end. Streaming formats write as files arrive; batch formats collect in
+//! `file` and emit a single document from `finish`. Each hook returns the text
+//! to write, with an empty string meaning nothing to write.
+//!
+//! The built-in formats are listed in [`OutputFormat`]. Code embedding the
+//! library can add its own with [`register_formatter`] and look any format up
+//! by name with [`formatter_by_name`].
+
+use crate::output::{DocsLinks, OutputFormat, OutputFormatter};
+use crate::rule::LintWarning;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+
+/// Totals passed to [`Formatter::finish`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunSummary {
+    /// Number of files checked, including clean ones
+    pub files_processed: usize,
+    /// Wall-clock duration of the run
+    pub duration_ms: u64,
+}
+
+/// An output format driven through the lifecycle of a lint run
+pub trait Formatter: Send {
+    /// Called once before any file is reported
+    fn init(&mut self) -> String {
+        String::new()
+    }
+
+    /// Format a single warning. Only used by the default [`Formatter::file`].
+    fn warning(&mut self, _file_path: &str, _warning: &LintWarning) -> String {
+        String::new()
+    }
+
+    /// Report one checked file. Clean files are reported with no warnings;
+    /// `content` is the file's source, or empty when it is not available.
+    fn file(&mut self, file_path: &str, warnings: &[LintWarning], _content: &str) -> String {
+        warnings
+            .iter()
+            .map(|warning| self.warning(file_path, warning))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Called once after the last file
+    fn finish(&mut self, _summary: &RunSummary) -> String {
+        String::new()
+    }
+
+    /// Whether the output is meant for tools, so human-readable summary lines
+    /// must not be mixed into it
+    fn is_machine_readable(&self) -> bool {
+        true
+    }
+
+    /// Whether all output is produced by [`Formatter::finish`]
+    fn is_batch(&self) -> bool {
+        false
+    }
+
+    /// Whether clean files must be passed to [`Formatter::file`]
+    fn needs_all_files(&self) -> bool {
+        false
+    }
+}
+
+/// Streams each file through an [`OutputFormatter`] as it is reported
+pub struct StreamingFormatter<F> {
+    inner: F,
+    machine_readable: bool,
+}
+
+impl<F: OutputFormatter + Send> StreamingFormatter<F> {
+    /// Wrap a formatter whose output is consumed by tools
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner,
+            machine_readable: true,
+        }
+    }
+
+    /// Wrap a formatter whose output is read by people
+    pub fn human(inner: F) -> Self {
+        Self {
+            inner,
+            machine_readable: false,
+        }
+    }
+}
+
+impl<F: OutputFormatter + Send> Formatter for StreamingFormatter<F> {
+    fn file(&mut self, file_path: &str, warnings: &[LintWarning], content: &str) -> String {
+        if warnings.is_empty() {
+            return String::new();
+        }
+        self.inner.format_warnings_with_content(warnings, file_path, content)
+    }
+
+    fn is_machine_readable(&self) -> bool {
+        self.machine_readable
+    }
+}
+
+/// Renders a whole run: warning-bearing files, every checked file, duration
+pub type BatchReport = Box<dyn Fn(&[(String, Vec<LintWarning>)], &[String], u64) -> String + Send>;
+
+/// Collects every file and renders one document when the run finishes
+pub struct BatchFormatter {
+    report: BatchReport,
+    needs_all_files: bool,
+    file_warnings: Vec<(String, Vec<LintWarning>)>,
+    all_files: Vec<String>,
+}
+
+impl BatchFormatter {
+    /// A report covering only files with warnings
+    pub fn new(report: impl Fn(&[(String, Vec<LintWarning>)], &[String], u64) -> String + Send + 'static) -> Self {
+        Self {
+            report: Box::new(report),
+            needs_all_files: false,
+            file_warnings: Vec::new(),
+            all_files: Vec::new(),
+        }
+    }
+
+    /// A report that also lists clean files
+    pub fn with_all_files(
+        report: impl Fn(&[(String, Vec<LintWarning>)], &[String], u64) -> String + Send + 'static,
+    ) -> Self {
+        Self {
+            needs_all_files: true,
+            ..Self::new(report)
+        }
+    }
+}
+
+impl Formatter for BatchFormatter {
+    fn file(&mut self, file_path: &str, warnings: &[LintWarning], _content: &str) -> String {
+        if !warnings.is_empty() {
+            match self.file_warnings.iter_mut().find(|(path, _)| path == file_path) {
+                Some((_, existing)) => existing.extend_from_slice(warnings),
+                None => self.file_warnings.push((file_path.to_string(), warnings.to_vec())),
+            }
+        }
+        if self.needs_all_files && !self.all_files.iter().any(|path| path == file_path) {
+            self.all_files.push(file_path.to_string());
+        }
+        String::new()
+    }
+
+    fn finish(&mut self, summary: &RunSummary) -> String {
+        (self.report)(&self.file_warnings, &self.all_files, summary.duration_ms)
+    }
+
+    fn is_batch(&self) -> bool {
+        true
+    }
+
+    fn needs_all_files(&self) -> bool {
+        self.needs_all_files
+    }
+}
+
+/// Follows each file's diagnostics with links to the documentation of the
+/// rules they name, one line per rule. Meant for human-readable formats.
+pub struct DocsLinksFormatter {
+    inner: Box<dyn Formatter>,
+    docs: DocsLinks,
+}
+
+impl DocsLinksFormatter {
+    pub fn new(inner: Box<dyn Formatter>, docs: DocsLinks) -> Self {
+        Self { inner, docs }
+    }
+}
+
+impl Formatter for DocsLinksFormatter {
+    fn init(&mut self) -> String {
+        self.inner.init()
+    }
+
+    fn file(&mut self, file_path: &str, warnings: &[LintWarning], content: &str) -> String {
+        let output = self.inner.file(file_path, warnings, content);
+        if output.is_empty() {
+            return output;
+        }
+        let rule_names: BTreeSet<&str> = warnings.iter().filter_map(|w| w.rule_name.as_deref()).collect();
+        let links: Vec<String> = rule_names
+            .into_iter()
+            .filter_map(|rule_name| Some(format!("  {rule_name}: {}", self.docs.url(rule_name)?)))
+            .collect();
+        if links.is_empty() {
+            output
+        } else {
+            format!("{output}\n{}", links.join("\n"))
+        }
+    }
+
+    fn finish(&mut self, summary: &RunSummary) -> String {
+        self.inner.finish(summary)
+    }
+
+    fn is_machine_readable(&self) -> bool {
+        self.inner.is_machine_readable()
+    }
+
+    fn is_batch(&self) -> bool {
+        self.inner.is_batch()
+    }
+
+    fn needs_all_files(&self) -> bool {
+        self.inner.needs_all_files()
+    }
+}
+
+/// Drive `formatter` through a complete run and return everything it wrote.
+///
+/// Files are reported in `all_files` order, with files in `all_files` but not
+/// in `file_warnings` reported as clean; warning-bearing files missing from
+/// `all_files` follow. Source content is not available here, so formatters
+/// that show source lines fall back to their plain output.
+pub fn format_run(
+    formatter: &mut dyn Formatter,
+    file_warnings: &[(String, Vec<LintWarning>)],
+    all_files: &[String],
+    duration_ms: u64,
+) -> String {
+    let mut chunks = vec![formatter.init()];
+    for path in all_files {
+        let warnings: Vec<LintWarning> = file_warnings
+            .iter()
+            .filter(|(warned, _)| warned == path)
+            .flat_map(|(_, warnings)| warnings.iter().cloned())
+            .collect();
+        chunks.push(formatter.file(path, &warnings, ""));
+    }
+    let mut files_processed = all_files.len();
+    for (path, warnings) in file_warnings.iter().filter(|(path, _)| !all_files.contains(path)) {
+        chunks.push(formatter.file(path, warnings, ""));
+        files_processed += 1;
+    }
+    chunks.push(formatter.finish(&RunSummary {
+        files_processed,
+        duration_ms,
+    }));
+    chunks.retain(|chunk| !chunk.is_empty());
+    chunks.join("\n")
+}
+
+/// Creates a fresh formatter for each run
+pub type FormatterFactory = Arc<dyn Fn() -> Box<dyn Formatter> + Send + Sync>;
+
+/// Formats registered by name on top of the built-in [`OutputFormat`]s
+#[derive(Default, Clone)]
+pub struct FormatterRegistry {
+    custom: BTreeMap<String, FormatterFactory>,
+}
+
+impl FormatterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `factory` under `name` (case-insensitive). A registered name
+    /// takes precedence over a built-in format of the same name.
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn Formatter> + Send + Sync + 'static,
+    {
+        self.custom.insert(name.to_lowercase(), Arc::new(factory));
+    }
+
+    /// Create the formatter registered as `name`, falling back to built-ins
+    pub fn create(&self, name: &str) -> Option<Box<dyn Formatter>> {
+        match self.custom.get(&name.to_lowercase()) {
+            Some(factory) => Some(factory()),
+            None => OutputFormat::from_str(name).ok().map(|format| format.formatter()),
+        }
+    }
+
+    /// Canonical names of all available formats, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = OutputFormat::ALL
+            .iter()
+            .map(|format| format.name().to_string())
+            .collect();
+        names.extend(self.custom.keys().cloned());
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+static FORMATTERS: LazyLock<RwLock<FormatterRegistry>> = LazyLock::new(|| RwLock::new(FormatterRegistry::new()));
+
+/// Register a formatter with the process-wide registry
+pub fn register_formatter<F>(name: &str, factory: F)
+where
+    F: Fn() -> Box<dyn Formatter> + Send + Sync + 'static,
+{
+    FORMATTERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .register(name, factory);
+}
+
+/// Create a formatter from the process-wide registry by name
+pub fn formatter_by_name(name: &str) -> Option<Box<dyn Formatter>> {
+    FORMATTERS.read().unwrap_or_else(PoisonError::into_inner).create(name)
+}
+
+/// Names of all formats known to the process-wide registry
+pub fn formatter_names() -> Vec<String> {
+    FORMATTERS.read().unwrap_or_else(PoisonError::into_inner).names()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Severity;
+
+    fn warning(line: usize) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 2,
+            rule_name: Some("MD001".to_string()),
+            message: "Heading levels should only increment by one level at a time".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    /// Counts warnings per file and prints a total at the end
+    #[derive(Default)]
+    struct TallyFormatter {
+        total: usize,
+    }
+
+    impl Formatter for TallyFormatter {
+        fn init(&mut self) -> String {
+            "begin".to_string()
+        }
+
+        fn warning(&mut self, file_path: &str, warning: &LintWarning) -> String {
+            self.total += 1;
+            format!("{file_path}#{}", warning.line)
+        }
+
+        fn finish(&mut self, summary: &RunSummary) -> String {
+            format!("{} warnings in {} files", self.total, summary.files_processed)
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_hooks_run_in_order() {
+        let mut formatter = TallyFormatter::default();
+        let file_warnings = vec![("a.md".to_string(), vec![warning(1), warning(3)])];
+        let all_files = vec!["a.md".to_string(), "b.md".to_string()];
+        assert_eq!(
+            format_run(&mut formatter, &file_warnings, &all_files, 0),
+            "begin\na.md#1\na.md#3\n2 warnings in 2 files"
+        );
+    }
+
+    #[test]
+    fn test_registry_custom_format_and_builtin_fallback() {
+        let mut registry = FormatterRegistry::new();
+        registry.register("Tally", || Box::new(TallyFormatter::default()));
+
+        assert!(registry.create("tally").is_some());
+        assert!(registry.create("jsonlines").is_some());
+        assert!(registry.create("nope").is_none());
+        assert!(registry.names().contains(&"tally".to_string()));
+        assert!(registry.names().contains(&"json-lines".to_string()));
+
+        register_formatter("tally-global", || Box::new(TallyFormatter::default()));
+        assert!(formatter_by_name("tally-global").is_some());
+        assert!(formatter_names().contains(&"tally-global".to_string()));
+    }
+
+    #[test]
+    fn test_batch_formatter_merges_files() {
+        let mut formatter = BatchFormatter::with_all_files(|file_warnings, all_files, _| {
+            format!("{} dirty / {} checked", file_warnings.len(), all_files.len())
+        });
+        assert!(formatter.file("a.md", &[warning(1)], "").is_empty());
+        formatter.file("a.md", &[warning(2)], "");
+        formatter.file("b.md", &[], "");
+        assert_eq!(formatter.file_warnings[0].1.len(), 2);
+        assert_eq!(formatter.finish(&RunSummary::default()), "1 dirty / 2 checked");
+    }
+
+    #[test]
+    fn test_docs_links_formatter_lists_each_rule_once() {
+        let mut formatter = DocsLinksFormatter::new(Box::new(TallyFormatter::default()), DocsLinks::default());
+        let mut tool_warning = warning(5);
+        tool_warning.rule_name = Some("shellcheck".to_string());
+
+        assert_eq!(
+            formatter.file("a.md", &[warning(1), warning(3), tool_warning], ""),
+            "a.md#1\na.md#3\na.md#5\n  MD001: https://rumdl.dev/md001/"
+        );
+        // Clean files stay silent
+        assert!(formatter.file("b.md", &[], "").is_empty());
+    }
+}
@@ -38,6 +38,68 @@ pub struct AggregatePerformanceResult {
     pub rules_per_second: f64,
     pub lines_per_second: f64,
     pub bytes_per_second: f64,
+    /// Time to analyze the content for rule filtering
+    pub content_analysis: ContentAnalysisTiming,
+}
+
+/// Runs averaged by [`PerformanceBenchmark::benchmark_content_analysis`]
+pub const CONTENT_ANALYSIS_ITERATIONS: u32 = 100;
+
+/// Time of the content analysis that decides which rules can be skipped,
+/// next to a reference analysis that searches the document once per marker
+#[derive(Debug, Clone, Copy)]
+pub struct ContentAnalysisTiming {
+    /// The single-pass scanner, averaged over [`CONTENT_ANALYSIS_ITERATIONS`] runs
+    pub scan_time: Duration,
+    /// The reference analysis, averaged the same way
+    pub reference_time: Duration,
+}
+
+impl ContentAnalysisTiming {
+    /// How many times faster the scanner is than the reference
+    pub fn speedup(&self) -> f64 {
+        self.reference_time.as_secs_f64() / self.scan_time.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+/// Content analysis as a separate whole-document search per marker followed
+/// by a loop over the lines: the baseline the single-pass scanner is measured
+/// against, and must agree with
+fn reference_content_analysis(content: &str) -> crate::ContentCharacteristics {
+    let mut chars = crate::ContentCharacteristics {
+        has_lists: content.contains("* ") || content.contains("- ") || content.contains("+ "),
+        has_links: content.contains('[')
+            || content.contains("http://")
+            || content.contains("https://")
+            || content.contains("ftp://")
+            || content.contains("www."),
+        has_images: content.contains("!["),
+        has_code: content.contains('`') || content.contains("~~~"),
+        has_emphasis: content.contains('*') || content.contains('_'),
+        has_html: content.contains('<'),
+        has_tables: content.contains('|'),
+        has_blockquotes: content.starts_with('>') || content.contains("\n>"),
+        ..Default::default()
+    };
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#')
+            || trimmed.trim_start_matches(['>', ' ', '\t']).starts_with('#')
+            || (trimmed.len() > 1 && trimmed.bytes().all(|b| b == b'=' || b == b'-'))
+        {
+            chars.has_headings = true;
+        }
+        if trimmed.starts_with(['*', '-', '+'])
+            || (line.as_bytes().first().is_some_and(u8::is_ascii_digit) && line.contains('.'))
+            || (trimmed.starts_with('>') && trimmed.bytes().any(|b| b.is_ascii_digit()) && trimmed.contains('.'))
+        {
+            chars.has_lists = true;
+        }
+        if line.starts_with([' ', '\t']) && crate::utils::calculate_indentation_width_default(line) >= 4 {
+            chars.has_code = true;
+        }
+    }
+    chars
 }
 
 /// Test content generator for different file sizes
@@ -297,6 +359,26 @@ impl PerformanceBenchmark {
         }
     }
 
+    /// Benchmark the content analysis that decides which rules can be skipped
+    /// before linting against the reference analysis
+    pub fn benchmark_content_analysis(content: &str) -> ContentAnalysisTiming {
+        fn average(content: &str, analyze: fn(&str) -> crate::ContentCharacteristics) -> Duration {
+            // Warm up
+            std::hint::black_box(analyze(content));
+
+            let start = Instant::now();
+            for _ in 0..CONTENT_ANALYSIS_ITERATIONS {
+                std::hint::black_box(analyze(std::hint::black_box(content)));
+            }
+            start.elapsed() / CONTENT_ANALYSIS_ITERATIONS
+        }
+
+        ContentAnalysisTiming {
+            scan_time: average(content, crate::ContentCharacteristics::analyze),
+            reference_time: average(content, reference_content_analysis),
+        }
+    }
+
     /// Benchmark all rules with given content
     pub fn benchmark_all_rules(&self, content: &str) -> AggregatePerformanceResult {
         let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
@@ -336,6 +418,7 @@ impl PerformanceBenchmark {
             rules_per_second,
             lines_per_second,
             bytes_per_second,
+            content_analysis: Self::benchmark_content_analysis(content),
         }
     }
 
@@ -387,6 +470,14 @@ impl PerformanceBenchmark {
             println!("     - Rules per second: {:.1}", result.rules_per_second);
             println!("     - Lines per second: {:.0}", result.lines_per_second);
             println!("     - Bytes per second: {:.0}", result.bytes_per_second);
+            let analysis = &result.content_analysis;
+            println!(
+                "     - Content analysis: {:.3}ms ({:.0} MB/s), {:.1}x the per-marker reference ({:.3}ms)",
+                analysis.scan_time.as_secs_f64() * 1000.0,
+                result.content_size_bytes as f64 / analysis.scan_time.as_secs_f64() / 1_048_576.0,
+                analysis.speedup(),
+                analysis.reference_time.as_secs_f64() * 1000.0
+            );
             println!();
 
             // Show top 10 slowest rules
@@ -506,6 +597,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_content_analysis_matches_reference() {
+        let huge = ContentGenerator::huge_content();
+        let chars = crate::ContentCharacteristics::analyze(&huge);
+        assert!(chars.has_headings && chars.has_lists && chars.has_links && chars.has_code);
+        assert!(chars.has_emphasis && chars.has_tables);
+        // None of these occur, so the scan runs to the end of the document
+        assert!(!chars.has_html && !chars.has_blockquotes && !chars.has_images);
+
+        // Documents where a marker is missing, so the scan has to reach the end
+        let plain = "Plain prose without any markup. ".repeat(2000);
+        let late_table = format!("{}\n| a | b |\n", "Some text.\n".repeat(2000));
+        let corpora = [
+            ContentGenerator::small_content(),
+            ContentGenerator::medium_content(),
+            ContentGenerator::large_content(),
+            huge,
+            plain,
+            late_table,
+            "Heading\r\n---\r\n> 1. quoted\r\n\t  code\r\nsee www.example.com\r\n".to_string(),
+        ];
+        for content in &corpora {
+            assert_eq!(
+                crate::ContentCharacteristics::analyze(content),
+                reference_content_analysis(content),
+                "scanner and reference disagree on {} bytes starting {:?}",
+                content.len(),
+                &content[..content.len().min(40)]
+            );
+        }
+    }
+
     #[test]
     fn test_performance_benchmark_creation() {
         let rules: Vec<Box<dyn Rule>> = vec![];
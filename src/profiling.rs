@@ -20,6 +20,10 @@ pub(crate) const PROFILING_ENABLED: bool = false;
 pub struct Profiler {
     measurements: HashMap<String, (Duration, usize)>,
     active_timers: HashMap<String, Instant>,
+    /// Time spent in each rule's check, keyed by (file, rule)
+    rule_measurements: HashMap<(String, String), (Duration, usize)>,
+    /// Time spent linting each file, with the file's size in bytes
+    file_measurements: HashMap<String, (Duration, usize)>,
 }
 
 impl Default for Profiler {
@@ -34,6 +38,8 @@ impl Profiler {
         Profiler {
             measurements: HashMap::new(),
             active_timers: HashMap::new(),
+            rule_measurements: HashMap::new(),
+            file_measurements: HashMap::new(),
         }
     }
 
@@ -68,6 +74,30 @@ impl Profiler {
         }
     }
 
+    /// Record the time one rule's check took on one file
+    pub fn record_rule(&mut self, file: &str, rule: &str, duration: Duration) {
+        if PROFILING_ENABLED {
+            let entry = self
+                .rule_measurements
+                .entry((file.to_string(), rule.to_string()))
+                .or_insert((Duration::new(0, 0), 0));
+            entry.0 += duration;
+            entry.1 += 1;
+        }
+    }
+
+    /// Record the time linting a file of `bytes` bytes took
+    pub fn record_file(&mut self, file: &str, bytes: usize, duration: Duration) {
+        if PROFILING_ENABLED {
+            let entry = self
+                .file_measurements
+                .entry(file.to_string())
+                .or_insert((Duration::new(0, 0), bytes));
+            entry.0 += duration;
+            entry.1 = bytes;
+        }
+    }
+
     /// Get a report of all measurements
     pub fn get_report(&self) -> String {
         if !PROFILING_ENABLED || self.measurements.is_empty() {
@@ -108,13 +138,118 @@ impl Profiler {
         report
     }
 
+    /// Get all measurements as a JSON document, with per-section, per-rule and
+    /// per-file timings and the peak memory use of the process
+    pub fn get_json_report(&self) -> String {
+        let mut sections: Vec<_> = self.measurements.iter().collect();
+        sections.sort_by_key(|m| std::cmp::Reverse(m.1.0));
+        let sections: Vec<_> = sections
+            .into_iter()
+            .map(|(section, (duration, calls))| {
+                serde_json::json!({
+                    "name": section,
+                    "total_ms": duration_ms(*duration),
+                    "calls": calls,
+                    "avg_ms": duration_ms(*duration / *calls as u32),
+                })
+            })
+            .collect();
+
+        let mut by_rule: HashMap<&str, (Duration, usize)> = HashMap::new();
+        for ((_, rule), (duration, calls)) in &self.rule_measurements {
+            let entry = by_rule.entry(rule).or_default();
+            entry.0 += *duration;
+            entry.1 += calls;
+        }
+        let mut rules: Vec<_> = by_rule.into_iter().collect();
+        rules.sort_by(|a, b| b.1.0.cmp(&a.1.0).then_with(|| a.0.cmp(b.0)));
+        let rules: Vec<_> = rules
+            .into_iter()
+            .map(|(rule, (duration, calls))| {
+                serde_json::json!({
+                    "rule": rule,
+                    "total_ms": duration_ms(duration),
+                    "calls": calls,
+                    "avg_ms": duration_ms(duration / calls as u32),
+                })
+            })
+            .collect();
+
+        let mut files: Vec<_> = self.file_measurements.iter().collect();
+        files.sort_by(|a, b| b.1.0.cmp(&a.1.0).then_with(|| a.0.cmp(b.0)));
+        let files: Vec<_> = files
+            .into_iter()
+            .map(|(file, (duration, bytes))| {
+                serde_json::json!({
+                    "file": file,
+                    "total_ms": duration_ms(*duration),
+                    "bytes": bytes,
+                })
+            })
+            .collect();
+
+        let report = serde_json::json!({
+            "enabled": PROFILING_ENABLED,
+            "peak_memory_bytes": peak_memory_bytes(),
+            "sections": sections,
+            "rules": rules,
+            "files": files,
+        });
+        serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Get the per-file rule timings in the folded stack format read by
+    /// flamegraph tools: one `rumdl;<file>;<rule> <microseconds>` line each
+    pub fn get_folded_report(&self) -> String {
+        let mut stacks: Vec<_> = self.rule_measurements.iter().collect();
+        stacks.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut report = String::new();
+        for ((file, rule), (duration, _)) in stacks {
+            // Frames are separated by `;` and the count by a space
+            let file = file.replace([';', ' '], "_");
+            report.push_str(&format!("rumdl;{file};{rule} {}\n", duration.as_micros()));
+        }
+        report
+    }
+
     /// Reset all measurements
     pub fn reset(&mut self) {
         self.measurements.clear();
         self.active_timers.clear();
+        self.rule_measurements.clear();
+        self.file_measurements.clear();
+    }
+}
+
+/// Milliseconds rounded to microsecond precision
+fn duration_ms(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
+
+/// Peak resident memory of the process in bytes, where the platform reports it
+#[cfg(unix)]
+fn peak_memory_bytes() -> Option<u64> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes into the provided struct
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: getrusage returned success, so the struct is initialized
+    let max_rss = u64::try_from(unsafe { usage.assume_init() }.ru_maxrss).ok()?;
+    // macOS reports bytes, other Unix systems kilobytes
+    if cfg!(target_os = "macos") {
+        Some(max_rss)
+    } else {
+        Some(max_rss * 1024)
     }
 }
 
+#[cfg(not(unix))]
+fn peak_memory_bytes() -> Option<u64> {
+    None
+}
+
 /// Start a timer for a section
 ///
 /// If the mutex is poisoned, this is a no-op. Profiling failures should not crash the application.
@@ -156,6 +291,35 @@ pub fn get_report() -> String {
     }
 }
 
+/// Record the time one rule's check took on one file
+///
+/// If the mutex is poisoned, this is a no-op. Profiling failures should not crash the application.
+pub fn record_rule(file: &str, rule: &str, duration: Duration) {
+    if PROFILING_ENABLED && let Ok(mut profiler) = PROFILER.lock() {
+        profiler.record_rule(file, rule, duration);
+    }
+}
+
+/// Get all measurements as a JSON document
+///
+/// Reports `"enabled": false` and no measurements when profiling is compiled out.
+pub fn get_json_report() -> String {
+    match PROFILER.lock() {
+        Ok(profiler) => profiler.get_json_report(),
+        Err(_) => Profiler::new().get_json_report(),
+    }
+}
+
+/// Get the per-file rule timings in folded stack format
+///
+/// Empty when profiling is compiled out or the mutex is poisoned.
+pub fn get_folded_report() -> String {
+    match PROFILER.lock() {
+        Ok(profiler) => profiler.get_folded_report(),
+        Err(_) => String::new(),
+    }
+}
+
 /// Reset all measurements
 ///
 /// If the mutex is poisoned, this is a no-op. Profiling failures should not crash the application.
@@ -184,6 +348,46 @@ impl ScopedTimer {
     }
 }
 
+/// Times the linting of one file using RAII, like `ScopedTimer`
+pub struct FileTimer {
+    file: String,
+    bytes: usize,
+    start: Option<Instant>,
+}
+
+impl FileTimer {
+    /// Create a new file timer; `file` is only formatted when profiling is enabled
+    pub fn new(file: Option<&std::path::Path>, bytes: usize) -> Self {
+        if !PROFILING_ENABLED {
+            return FileTimer {
+                file: String::new(),
+                bytes,
+                start: None,
+            };
+        }
+        FileTimer {
+            file: file.map_or_else(|| "<stdin>".to_string(), |f| f.to_string_lossy().into_owned()),
+            bytes,
+            start: Some(Instant::now()),
+        }
+    }
+
+    /// The file label used for this file's measurements
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+}
+
+impl Drop for FileTimer {
+    fn drop(&mut self) {
+        if let Some(start) = self.start.take()
+            && let Ok(mut profiler) = PROFILER.lock()
+        {
+            profiler.record_file(&self.file, self.bytes, start.elapsed());
+        }
+    }
+}
+
 impl Drop for ScopedTimer {
     fn drop(&mut self) {
         if self.enabled
@@ -312,6 +516,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_profiler_json_report() {
+        let mut profiler = Profiler::new();
+        profiler.record_rule("a.md", "MD013", Duration::from_millis(4));
+        profiler.record_rule("b.md", "MD013", Duration::from_millis(2));
+        profiler.record_rule("a.md", "MD001", Duration::from_millis(1));
+        profiler.record_file("a.md", 120, Duration::from_millis(8));
+
+        let report: serde_json::Value = serde_json::from_str(&profiler.get_json_report()).unwrap();
+        assert_eq!(report["enabled"], PROFILING_ENABLED);
+        if PROFILING_ENABLED {
+            assert_eq!(report["rules"][0]["rule"], "MD013");
+            assert_eq!(report["rules"][0]["calls"], 2);
+            assert_eq!(report["rules"][0]["total_ms"], 6.0);
+            assert_eq!(report["rules"][0]["avg_ms"], 3.0);
+            assert_eq!(report["rules"][1]["rule"], "MD001");
+            assert_eq!(report["files"][0]["file"], "a.md");
+            assert_eq!(report["files"][0]["bytes"], 120);
+        } else {
+            assert_eq!(report["rules"], serde_json::json!([]));
+            assert_eq!(report["files"], serde_json::json!([]));
+        }
+    }
+
+    #[test]
+    fn test_profiler_folded_report() {
+        let mut profiler = Profiler::new();
+        profiler.record_rule("docs/my file.md", "MD013", Duration::from_micros(1500));
+        profiler.record_rule("a.md", "MD001", Duration::from_micros(20));
+
+        let report = profiler.get_folded_report();
+        if PROFILING_ENABLED {
+            assert_eq!(report, "rumdl;a.md;MD001 20\nrumdl;docs/my_file.md;MD013 1500\n");
+        } else {
+            assert!(report.is_empty());
+        }
+    }
+
     #[test]
     fn test_profiler_reset() {
         let mut profiler = Profiler::new();
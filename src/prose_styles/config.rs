@@ -0,0 +1,36 @@
+//! Configuration for prose style rules.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Directory searched for styles when `path` is not set
+pub const DEFAULT_STYLES_PATH: &str = "styles";
+
+/// Prose style rules loaded from Vale-compatible YAML files (`[prose-styles]`).
+///
+/// Nothing is loaded unless `styles` names at least one style.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProseStylesConfig {
+    /// Directory holding one folder of rule files per style, like Vale's
+    /// `StylesPath` (default: `styles`). Relative paths are resolved against
+    /// the project root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Styles to load, like Vale's `BasedOnStyles`. Each rule is reported as
+    /// `<Style>.<Rule>`, after its file name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub styles: Vec<String>,
+}
+
+impl ProseStylesConfig {
+    /// Directory holding the styles, resolved against `project_root`
+    pub fn styles_dir(&self, project_root: Option<&Path>) -> PathBuf {
+        let path = Path::new(self.path.as_deref().unwrap_or(DEFAULT_STYLES_PATH));
+        match project_root {
+            Some(root) if path.is_relative() => root.join(path),
+            _ => path.to_path_buf(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Prose style rules
+//!
+//! Loads rules written for [Vale](https://vale.sh) from YAML files and runs
+//! them next to the built-in rules. Each style is a folder of rule files under
+//! the `[prose-styles]` path, and each file becomes a rule named
+//! `<Style>.<Rule>` that works with inline disable comments, rule selection
+//! and `--fix` like any other rule.
+//!
+//! Only the `existence` and `substitution` checks are supported. Files using
+//! other checks, or scopes rumdl cannot map to Markdown structure, are skipped
+//! with a warning.
+
+mod config;
+mod rule;
+
+pub use config::{DEFAULT_STYLES_PATH, ProseStylesConfig};
+pub use rule::StyleRule;
+
+use crate::config::Config;
+use crate::rule::Rule;
+use std::path::PathBuf;
+
+/// Why a style rule file could not be loaded
+#[derive(Debug, thiserror::Error)]
+pub enum StyleRuleError {
+    #[error("cannot read rule file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid YAML: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("invalid pattern: {0}")]
+    Regex(#[from] fancy_regex::Error),
+    #[error("`extends: {0}` is not supported (only `existence` and `substitution` are)")]
+    UnsupportedCheck(String),
+    #[error("scope `{0}` is not supported")]
+    UnsupportedScope(String),
+    #[error("no tokens, raw patterns or swaps to match")]
+    NoPatterns,
+}
+
+/// Whether `name` has the `<Style>.<Rule>` form of a style rule ID
+pub fn is_style_rule_id(name: &str) -> bool {
+    let is_part =
+        |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    name.split_once('.')
+        .is_some_and(|(style, rule)| is_part(style) && is_part(rule))
+}
+
+/// Load the rules of every style named in `[prose-styles]`
+pub fn load_style_rules(config: &Config) -> Vec<Box<dyn Rule>> {
+    let settings = &config.prose_styles;
+    if settings.styles.is_empty() {
+        return Vec::new();
+    }
+
+    let styles_dir = settings.styles_dir(config.project_root.as_deref());
+    let mut rules: Vec<Box<dyn Rule>> = Vec::new();
+    for style in &settings.styles {
+        let style_dir = styles_dir.join(style);
+        let entries = match std::fs::read_dir(&style_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                log::warn!(
+                    "[WARN] Cannot read prose style '{style}' at {}: {e}",
+                    style_dir.display()
+                );
+                continue;
+            }
+        };
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| matches!(path.extension().and_then(|ext| ext.to_str()), Some("yml" | "yaml")))
+            .collect();
+        files.sort();
+
+        for file in files {
+            let Some(name) = file.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let loaded = std::fs::read_to_string(&file)
+                .map_err(StyleRuleError::from)
+                .and_then(|source| StyleRule::from_yaml(style, name, &source));
+            match loaded {
+                Ok(rule) => rules.push(Box::new(rule)),
+                Err(e) => log::warn!(
+                    "[WARN] Skipping prose style rule {style}.{name} ({}): {e}",
+                    file.display()
+                ),
+            }
+        }
+    }
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_style_rule_id() {
+        assert!(is_style_rule_id("Acme.Weasel"));
+        assert!(is_style_rule_id("write-good.So"));
+        assert!(!is_style_rule_id("MD013"));
+        assert!(!is_style_rule_id("Acme."));
+        assert!(!is_style_rule_id("Acme.Sub.Rule"));
+        assert!(!is_style_rule_id("a b.c"));
+    }
+
+    #[test]
+    fn test_load_style_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let acme = dir.path().join("styles/Acme");
+        std::fs::create_dir_all(&acme).unwrap();
+        std::fs::write(acme.join("Weasel.yml"), "extends: existence\ntokens: [very]\n").unwrap();
+        std::fs::write(acme.join("Spelling.yml"), "extends: spelling\n").unwrap();
+        std::fs::write(acme.join("notes.txt"), "not a rule").unwrap();
+
+        let mut config = Config::default();
+        config.project_root = Some(dir.path().to_path_buf());
+        assert!(load_style_rules(&config).is_empty());
+
+        config.prose_styles.styles = vec!["Acme".to_string(), "Missing".to_string()];
+        let names: Vec<&str> = load_style_rules(&config).iter().map(|rule| rule.name()).collect();
+        assert_eq!(names, vec!["Acme.Weasel"]);
+    }
+}
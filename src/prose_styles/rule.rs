@@ -0,0 +1,451 @@
+//! A prose style rule built from a Vale-compatible YAML definition.
+
+use super::StyleRuleError;
+use crate::lint_context::{LineInfo, LintContext};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, Severity};
+use crate::utils::prose::{NonProseSpans, is_non_prose_line};
+use crate::utils::string_interner::intern_static;
+use fancy_regex::Regex;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::hash::{Hash, Hasher};
+
+/// How serious a finding is, in Vale's terms
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    #[default]
+    Suggestion,
+    Warning,
+    Error,
+}
+
+impl From<Level> for Severity {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Suggestion => Severity::Info,
+            Level::Warning => Severity::Warning,
+            Level::Error => Severity::Error,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// The part of a Vale rule file that rumdl understands; other keys are ignored
+#[derive(Debug, Deserialize)]
+struct Definition {
+    extends: String,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    level: Level,
+    #[serde(default)]
+    ignorecase: bool,
+    #[serde(default)]
+    nonword: bool,
+    #[serde(default)]
+    tokens: Vec<String>,
+    #[serde(default)]
+    raw: Vec<String>,
+    #[serde(default)]
+    swap: IndexMap<String, String>,
+    #[serde(default)]
+    exceptions: Vec<String>,
+    #[serde(default)]
+    scope: Option<OneOrMany>,
+}
+
+/// The part of a document a rule applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Text,
+    Paragraph,
+    Heading(Option<u8>),
+    List,
+    Blockquote,
+    TableHeader,
+    TableCell,
+}
+
+impl Scope {
+    fn parse(scope: &str) -> Result<Self, StyleRuleError> {
+        Ok(match scope {
+            "text" => Scope::Text,
+            "paragraph" => Scope::Paragraph,
+            "heading" => Scope::Heading(None),
+            "list" => Scope::List,
+            "blockquote" => Scope::Blockquote,
+            "table.header" => Scope::TableHeader,
+            "table.cell" => Scope::TableCell,
+            _ => match scope
+                .strip_prefix("heading.h")
+                .and_then(|level| level.parse::<u8>().ok())
+            {
+                Some(level @ 1..=6) => Scope::Heading(Some(level)),
+                _ => return Err(StyleRuleError::UnsupportedScope(scope.to_string())),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Check {
+    /// Flag every match
+    Existence,
+    /// Flag each match of a swap key, suggesting its replacement
+    Substitution(Vec<(Regex, String)>),
+}
+
+/// A rule from a prose style, reported as `<Style>.<Rule>`
+#[derive(Debug, Clone)]
+pub struct StyleRule {
+    id: &'static str,
+    description: &'static str,
+    severity: Severity,
+    message: String,
+    scopes: Vec<Scope>,
+    pattern: Regex,
+    check: Check,
+    ignorecase: bool,
+    exceptions: Vec<String>,
+    fingerprint: String,
+}
+
+impl StyleRule {
+    /// Build the rule `<style>.<name>` from the contents of its YAML file
+    pub fn from_yaml(style: &str, name: &str, source: &str) -> Result<Self, StyleRuleError> {
+        let definition: Definition = serde_yaml::from_str(source)?;
+        let flags = if definition.ignorecase { "(?i)" } else { "" };
+        let bounded = |alternatives: &str| {
+            if definition.nonword {
+                format!("(?:{alternatives})")
+            } else {
+                format!(r"\b(?:{alternatives})\b")
+            }
+        };
+
+        let (pattern, check, default_message) = match definition.extends.as_str() {
+            "existence" => {
+                let mut parts = Vec::new();
+                if !definition.tokens.is_empty() {
+                    parts.push(bounded(&definition.tokens.join("|")));
+                }
+                if !definition.raw.is_empty() {
+                    parts.push(format!("(?:{})", definition.raw.concat()));
+                }
+                if parts.is_empty() {
+                    return Err(StyleRuleError::NoPatterns);
+                }
+                (parts.join("|"), Check::Existence, "'%s' found.")
+            }
+            "substitution" => {
+                if definition.swap.is_empty() {
+                    return Err(StyleRuleError::NoPatterns);
+                }
+                let swaps = definition
+                    .swap
+                    .iter()
+                    .map(|(key, replacement)| Ok((Regex::new(&format!("{flags}^(?:{key})$"))?, replacement.clone())))
+                    .collect::<Result<Vec<_>, StyleRuleError>>()?;
+                let keys: Vec<&str> = definition.swap.keys().map(String::as_str).collect();
+                (
+                    bounded(&keys.join("|")),
+                    Check::Substitution(swaps),
+                    "Use '%s' instead of '%s'.",
+                )
+            }
+            other => return Err(StyleRuleError::UnsupportedCheck(other.to_string())),
+        };
+
+        let scopes = match &definition.scope {
+            None => vec![Scope::Text],
+            Some(OneOrMany::One(scope)) => vec![Scope::parse(scope)?],
+            Some(OneOrMany::Many(scopes)) => scopes.iter().map(|s| Scope::parse(s)).collect::<Result<_, _>>()?,
+        };
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        source.hash(&mut hasher);
+        let kind = if matches!(check, Check::Existence) {
+            "Existence"
+        } else {
+            "Substitution"
+        };
+
+        Ok(Self {
+            id: intern_static(&format!("{style}.{name}")),
+            description: intern_static(&format!("{kind} check from the {style} style")),
+            severity: definition.level.into(),
+            message: definition.message.unwrap_or_else(|| default_message.to_string()),
+            scopes,
+            pattern: Regex::new(&format!("{flags}{pattern}"))?,
+            check,
+            ignorecase: definition.ignorecase,
+            exceptions: definition.exceptions,
+            fingerprint: format!("{:016x}", hasher.finish()),
+        })
+    }
+
+    fn applies_to(&self, ctx: &LintContext, idx: usize, info: &LineInfo) -> bool {
+        let table_row = || {
+            ctx.table_blocks()
+                .iter()
+                .find(|table| (table.start_line..=table.end_line).contains(&idx))
+                .map(|table| idx == table.header_line)
+        };
+        self.scopes.iter().any(|scope| match scope {
+            Scope::Text => true,
+            Scope::Heading(level) => info
+                .heading
+                .as_ref()
+                .is_some_and(|heading| level.is_none_or(|level| heading.level == level)),
+            Scope::List => info.in_list_block,
+            Scope::Blockquote => info.blockquote.is_some(),
+            Scope::TableHeader => table_row() == Some(true),
+            Scope::TableCell => table_row() == Some(false),
+            Scope::Paragraph => {
+                info.heading.is_none() && !info.in_list_block && info.blockquote.is_none() && table_row().is_none()
+            }
+        })
+    }
+
+    fn is_exception(&self, matched: &str) -> bool {
+        self.exceptions.iter().any(|exception| {
+            if self.ignorecase {
+                exception.eq_ignore_ascii_case(matched)
+            } else {
+                exception == matched
+            }
+        })
+    }
+
+    /// Fill the message's `%s` placeholders in order
+    fn format_message(&self, args: &[&str]) -> String {
+        let mut parts = self.message.split("%s");
+        let mut message = parts.next().unwrap_or_default().to_string();
+        for (i, part) in parts.enumerate() {
+            message.push_str(args.get(i).copied().unwrap_or_default());
+            message.push_str(part);
+        }
+        message
+    }
+
+    fn warning(
+        &self,
+        line_num: usize,
+        line: &str,
+        start: usize,
+        matched: &str,
+        line_start: usize,
+    ) -> Option<LintWarning> {
+        let (message, fix) = match &self.check {
+            Check::Existence => (self.format_message(&[matched]), None),
+            Check::Substitution(swaps) => {
+                let replacement = swaps
+                    .iter()
+                    .find(|(key, _)| key.is_match(matched).unwrap_or(false))
+                    .map(|(_, replacement)| replacement.as_str())?;
+                let options: Vec<&str> = replacement.split('|').collect();
+                if options.contains(&matched) {
+                    return None;
+                }
+                let fix = (options.len() == 1).then(|| {
+                    let range = line_start + start..line_start + start + matched.len();
+                    Fix::new(range, match_case(matched, replacement))
+                });
+                (self.format_message(&[&options.join("' or '"), matched]), fix)
+            }
+        };
+
+        let column = line[..start].chars().count() + 1;
+        Some(LintWarning {
+            rule_name: Some(self.id.to_string()),
+            line: line_num,
+            column,
+            end_line: line_num,
+            end_column: column + matched.chars().count(),
+            message,
+            severity: self.severity,
+            fix,
+        })
+    }
+}
+
+/// Capitalize `replacement` when it replaces a capitalized word
+fn match_case(matched: &str, replacement: &str) -> String {
+    let starts_upper = matched.chars().next().is_some_and(char::is_uppercase);
+    let mut chars = replacement.chars();
+    match chars.next() {
+        Some(first) if starts_upper && first.is_lowercase() => first.to_uppercase().chain(chars).collect(),
+        _ => replacement.to_string(),
+    }
+}
+
+impl Rule for StyleRule {
+    fn name(&self) -> &'static str {
+        self.id
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let spans = NonProseSpans::new(ctx);
+
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            let line = info.content(ctx.content);
+            if info.is_blank || is_non_prose_line(info, line, ctx.flavor) || !self.applies_to(ctx, idx, info) {
+                continue;
+            }
+            // A pattern that exhausts the backtracking limit ends the line's matches
+            for found in self.pattern.find_iter(line).map_while(Result::ok) {
+                let matched = found.as_str();
+                if matched.is_empty()
+                    || spans.contains(ctx, info.byte_offset + found.start())
+                    || self.is_exception(matched)
+                {
+                    continue;
+                }
+                warnings.extend(self.warning(idx + 1, line, found.start(), matched, info.byte_offset));
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        match self.check {
+            Check::Existence => FixCapability::Unfixable,
+            Check::Substitution(_) => FixCapability::ConditionallyFixable,
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn definition_fingerprint(&self) -> Option<String> {
+        Some(self.fingerprint.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &StyleRule, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fixed(rule: &StyleRule, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_existence() {
+        let rule = StyleRule::from_yaml(
+            "Acme",
+            "Weasel",
+            "extends: existence\nmessage: \"Avoid '%s'.\"\nlevel: warning\nignorecase: true\ntokens:\n  - very\n  - quite\n",
+        )
+        .unwrap();
+        assert_eq!(rule.name(), "Acme.Weasel");
+        assert_eq!(rule.fix_capability(), FixCapability::Unfixable);
+
+        let warnings = check(
+            &rule,
+            "It is Very fast.\n\n`very` and [very](https://very.example) quiet\n",
+        );
+        assert_eq!(warnings.len(), 2);
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 7));
+        assert_eq!(warnings[0].message, "Avoid 'Very'.");
+        assert_eq!(warnings[0].severity, Severity::Warning);
+        assert_eq!((warnings[1].line, warnings[1].column), (3, 13));
+    }
+
+    #[test]
+    fn test_substitution_fix() {
+        let rule = StyleRule::from_yaml(
+            "Acme",
+            "Terms",
+            "extends: substitution\nlevel: error\nignorecase: true\nswap:\n  e-mail: email\n  abort: cancel|stop\n  javascript: JavaScript\n",
+        )
+        .unwrap();
+        let content = "E-mail us to abort. We love JavaScript and javascript.\n";
+        let warnings = check(&rule, content);
+        let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Use 'email' instead of 'E-mail'.",
+                "Use 'cancel' or 'stop' instead of 'abort'.",
+                "Use 'JavaScript' instead of 'javascript'.",
+            ]
+        );
+        assert!(warnings[1].fix.is_none());
+        assert_eq!(
+            fixed(&rule, content),
+            "Email us to abort. We love JavaScript and JavaScript.\n"
+        );
+    }
+
+    #[test]
+    fn test_scope_and_exceptions() {
+        let rule = StyleRule::from_yaml(
+            "Acme",
+            "Headings",
+            "extends: existence\nscope: heading\nnonword: true\ntokens: ['!']\n",
+        )
+        .unwrap();
+        let warnings = check(&rule, "# Welcome!\n\nHello!\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(warnings[0].severity, Severity::Info);
+
+        let rule = StyleRule::from_yaml(
+            "Acme",
+            "Acronyms",
+            "extends: existence\nraw: ['\\b[A-Z]{3,}\\b']\nexceptions: [HTML]\n",
+        )
+        .unwrap();
+        let warnings = check(&rule, "Write HTML, not XHTML.\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "'XHTML' found.");
+    }
+
+    #[test]
+    fn test_unsupported_definitions() {
+        assert!(matches!(
+            StyleRule::from_yaml("Acme", "Spell", "extends: spelling\n"),
+            Err(StyleRuleError::UnsupportedCheck(_))
+        ));
+        assert!(matches!(
+            StyleRule::from_yaml("Acme", "Raw", "extends: existence\nscope: raw\ntokens: [x]\n"),
+            Err(StyleRuleError::UnsupportedScope(_))
+        ));
+        assert!(matches!(
+            StyleRule::from_yaml("Acme", "Empty", "extends: substitution\n"),
+            Err(StyleRuleError::NoPatterns)
+        ));
+    }
+}
@@ -0,0 +1,312 @@
+//! Machine-readable metadata for every rule.
+//!
+//! Embedders such as editor extensions use this to build settings UIs and
+//! rule pickers without parsing the output of `rumdl rule`.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::config::Config;
+use crate::rule::{FixCapability, FixSafety, Rule, RuleCategory};
+use crate::rule_config_serde::is_nullable_sentinel;
+
+/// Metadata of one rule
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RuleMetadata {
+    /// Rule ID (e.g. "MD013")
+    pub id: &'static str,
+    /// Primary name in kebab-case (e.g. "line-length")
+    pub name: String,
+    /// Other names the rule can be referred to by in config and inline comments
+    pub aliases: Vec<String>,
+    /// One-line description of what the rule checks
+    pub summary: &'static str,
+    pub category: RuleCategory,
+    pub fixable: FixCapability,
+    /// Whether the rule's fixes are only applied with `unsafe-fixes`
+    pub fix_safety: FixSafety,
+    /// Whether the rule only runs when enabled explicitly
+    pub opt_in: bool,
+    /// JSON Schema of the rule's config section. Keys every rule accepts
+    /// (`severity`, `enabled`, `explain-url`) are not listed.
+    pub config_schema: Value,
+    /// URL of the rule's documentation page
+    pub url: String,
+    /// Full documentation in Markdown
+    pub docs: &'static str,
+}
+
+/// Metadata of all rules, ordered by rule ID
+pub fn rules() -> Vec<RuleMetadata> {
+    let opt_in = crate::rules::opt_in_rules();
+    crate::rules::all_rules(&Config::default())
+        .iter()
+        .map(|rule| metadata(rule.as_ref(), opt_in.contains(rule.name())))
+        .collect()
+}
+
+/// Metadata of the rule with the given ID or alias (e.g. "MD013" or "line-length")
+pub fn rule(name: &str) -> Option<RuleMetadata> {
+    let id = crate::config::resolve_rule_name_alias(name)?;
+    rules().into_iter().find(|rule| rule.id == id)
+}
+
+/// A rule matching a [`search`] query
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub rule: RuleMetadata,
+    /// Relevance; higher scores rank first
+    pub score: u32,
+}
+
+/// Words too common in rule docs to tell rules apart
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "be", "for", "in", "is", "of", "on", "or", "the", "to",
+];
+
+/// Rules whose ID, names, summary or docs match the words of `query`, best
+/// match first.
+///
+/// A query word matches every word it is a prefix of, so "heading" also finds
+/// "headings". Matches in names weigh more than matches in the summary, which
+/// weigh more than matches in the docs, and rules matching more of the query
+/// words rank above rules matching fewer.
+pub fn search(query: &str) -> Vec<SearchMatch> {
+    let query = query.trim().to_lowercase();
+    let terms: Vec<&str> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty() && !STOP_WORDS.contains(term))
+        .collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<SearchMatch> = rules()
+        .into_iter()
+        .filter_map(|rule| {
+            let score = search_score(&rule, &query, &terms);
+            (score > 0).then_some(SearchMatch { rule, score })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.rule.id.cmp(b.rule.id)));
+    matches
+}
+
+fn search_score(rule: &RuleMetadata, query: &str, terms: &[&str]) -> u32 {
+    let words = |text: &str| -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(String::from)
+            .collect()
+    };
+    let names: Vec<String> = std::iter::once(rule.id.to_string())
+        .chain(std::iter::once(rule.name.clone()))
+        .chain(rule.aliases.iter().cloned())
+        .collect();
+    let name_words: Vec<String> = names.iter().flat_map(|name| words(name)).collect();
+    let summary_words = words(rule.summary);
+    let docs_words = words(rule.docs);
+
+    let mut score = 0;
+    let mut matched_terms = 0;
+    for term in terms {
+        let hits = |list: &[String]| list.iter().filter(|word| word.starts_with(term)).count();
+        let term_score = 8 * u32::from(hits(&name_words) > 0)
+            + 4 * u32::from(hits(&summary_words) > 0)
+            + hits(&docs_words).min(5) as u32;
+        if term_score > 0 {
+            matched_terms += 1;
+            score += term_score;
+        }
+    }
+    if matched_terms == 0 {
+        return 0;
+    }
+
+    // The whole query as a phrase, or as the rule's ID or name
+    let summary = rule.summary.to_lowercase();
+    if names.iter().any(|name| name.eq_ignore_ascii_case(query)) {
+        score += 100;
+    }
+    if summary.contains(query) {
+        score += 10;
+    }
+    if rule.docs.to_lowercase().contains(query) {
+        score += 5;
+    }
+    score * matched_terms
+}
+
+/// Fix safety of every rule, keyed by rule ID
+static FIX_SAFETY: LazyLock<HashMap<&'static str, FixSafety>> = LazyLock::new(|| {
+    crate::rules::all_rules(&Config::default())
+        .iter()
+        .map(|rule| (rule.name(), rule.fix_safety()))
+        .collect()
+});
+
+/// Fix safety of the rule with the given ID or alias, without building the
+/// rest of its metadata
+pub fn fix_safety(name: &str) -> Option<FixSafety> {
+    let id = crate::config::resolve_rule_name_alias(name)?;
+    FIX_SAFETY.get(id).copied()
+}
+
+fn metadata(rule: &dyn Rule, opt_in: bool) -> RuleMetadata {
+    let id = rule.name();
+    let (name, aliases) = match crate::config::rule_aliases(id) {
+        [] => (id.to_lowercase(), Vec::new()),
+        [primary, rest @ ..] => (primary.clone(), rest.to_vec()),
+    };
+    RuleMetadata {
+        id,
+        name,
+        aliases,
+        summary: rule.description(),
+        category: rule.category(),
+        fixable: rule.fix_capability(),
+        fix_safety: rule.fix_safety(),
+        opt_in,
+        config_schema: config_schema(rule),
+        url: format!("https://rumdl.dev/{}/", id.to_lowercase()),
+        docs: rule_docs(id).unwrap_or_default(),
+    }
+}
+
+/// Documentation page of the rule with the given ID, in Markdown
+pub fn rule_docs(id: &str) -> Option<&'static str> {
+    embedded_docs(&id.to_ascii_lowercase())
+}
+
+/// JSON Schema of a rule's config section, derived from its defaults
+fn config_schema(rule: &dyn Rule) -> Value {
+    let mut properties = serde_json::Map::new();
+    if let Some((_, toml::Value::Table(table))) = rule.default_config_section() {
+        let polymorphic = rule.polymorphic_config_keys();
+        for (key, default) in table {
+            let schema = if is_nullable_sentinel(&default) {
+                json!({ "default": null })
+            } else if polymorphic.contains(&key.as_str()) {
+                // Accepts several TOML types, so only the default is known
+                json!({ "default": default })
+            } else {
+                value_schema(&default)
+            };
+            properties.insert(key, schema);
+        }
+    }
+    json!({ "type": "object", "properties": properties })
+}
+
+fn value_schema(default: &toml::Value) -> Value {
+    let mut schema = json!({ "type": json_type(default) });
+    if let toml::Value::Array(items) = default
+        && let Some(first) = items.first()
+    {
+        schema["items"] = json!({ "type": json_type(first) });
+    }
+    schema["default"] = json!(default);
+    schema
+}
+
+fn json_type(value: &toml::Value) -> &'static str {
+    match value {
+        toml::Value::String(_) | toml::Value::Datetime(_) => "string",
+        toml::Value::Integer(_) => "integer",
+        toml::Value::Float(_) => "number",
+        toml::Value::Boolean(_) => "boolean",
+        toml::Value::Array(_) => "array",
+        toml::Value::Table(_) => "object",
+    }
+}
+
+macro_rules! rule_docs {
+    ($($id:literal),* $(,)?) => {
+        /// The `docs/<id>.md` page of a rule, embedded at build time
+        fn embedded_docs(id: &str) -> Option<&'static str> {
+            match id {
+                $($id => Some(include_str!(concat!("../docs/", $id, ".md"))),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+rule_docs!(
+    "md001", "md003", "md004", "md005", "md007", "md009", "md010", "md011", "md012", "md013", "md014", "md018",
+    "md019", "md020", "md021", "md022", "md023", "md024", "md025", "md026", "md027", "md028", "md029", "md030",
+    "md031", "md032", "md033", "md034", "md035", "md036", "md037", "md038", "md039", "md040", "md041", "md042",
+    "md043", "md044", "md045", "md046", "md047", "md048", "md049", "md050", "md051", "md052", "md053", "md054",
+    "md055", "md056", "md057", "md058", "md059", "md060", "md061", "md062", "md063", "md064", "md065", "md066",
+    "md067", "md068", "md069", "md070", "md071", "md072", "md073", "md074", "md075", "md076", "md077", "md078",
+    "md079", "md080", "md081", "md082", "md083", "md084", "md085", "md086", "md087", "md088", "md089", "md090",
+    "md091", "md092", "md093", "md094", "md095", "md096", "md097", "md098", "md099", "md100", "md101", "md102",
+    "md103", "md104", "md105", "md106", "md107",
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_rule_has_metadata_and_docs() {
+        let rules = rules();
+        assert_eq!(rules.len(), crate::rules::all_rules(&Config::default()).len());
+        for rule in &rules {
+            assert!(
+                rule.docs.to_uppercase().contains(&format!("# {}", rule.id)),
+                "{} has no embedded docs",
+                rule.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_lookup_by_alias() {
+        let md013 = rule("line-length").unwrap();
+        assert_eq!(md013.id, "MD013");
+        assert_eq!(md013.name, "line-length");
+        assert!(!md013.opt_in);
+        assert!(rule("MD999").is_none());
+    }
+
+    #[test]
+    fn test_search_ranks_rules_by_relevance() {
+        let ids = |query: &str| search(query).iter().map(|m| m.rule.id).collect::<Vec<_>>();
+
+        // Exact IDs and names win outright
+        assert_eq!(ids("MD013")[0], "MD013");
+        assert_eq!(ids("no-trailing-spaces")[0], "MD009");
+
+        let blank_lines = ids("blank lines around");
+        assert!(blank_lines.len() > 3);
+        assert!(
+            blank_lines[..6].contains(&"MD022") && blank_lines[..6].contains(&"MD031"),
+            "got {blank_lines:?}"
+        );
+
+        // Prefix matching: "heading" finds rules about "headings"
+        assert!(ids("heading").contains(&"MD001"));
+
+        assert!(search("the of").is_empty());
+        assert!(search("xyzzyplugh").is_empty());
+    }
+
+    #[test]
+    fn test_config_schema_from_defaults() {
+        let schema = rule("MD013").unwrap().config_schema;
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["line-length"]["type"], "integer");
+        assert_eq!(schema["properties"]["line-length"]["default"], 80);
+        assert_eq!(schema["properties"]["code-blocks"]["type"], "boolean");
+
+        let json = serde_json::to_value(rule("MD001").unwrap()).unwrap();
+        assert_eq!(json["category"], "heading");
+        assert_eq!(json["config-schema"]["type"], "object");
+    }
+}
@@ -3,7 +3,8 @@
 //! Groups files by their effective config, enabling subdirectory configs
 //! to override the root config for files within their scope. This follows
 //! the Ruff model: subdirectory configs are standalone by default, and
-//! users can use `extends` for inheritance.
+//! users can use `extends` for inheritance. With `nested-configs = "merge"`
+//! every config from the root down to a file's directory is layered instead.
 
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
@@ -21,6 +22,27 @@ pub struct ConfigGroup {
     pub rules: Vec<Box<dyn Rule>>,
     pub cache_hashes: Option<Arc<CacheHashes>>,
     pub files: Vec<String>,
+    /// Rules enabled for the files' directory that this group's files skip,
+    /// with the reason. Shown by `--explain-skip`.
+    pub skipped_rules: Vec<(String, SkipReason)>,
+}
+
+/// Why a rule enabled for a file's directory does not run on the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Disabled by the `[overrides]` entry with this pattern
+    Override(String),
+    /// Listed in `draft-disable` and the file is a draft
+    Draft,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Override(pattern) => write!(f, "[overrides] \"{pattern}\""),
+            Self::Draft => write!(f, "draft"),
+        }
+    }
 }
 
 /// The two roots that anchor config resolution for a run.
@@ -88,6 +110,10 @@ fn is_root_level_config(config_path: &Path, project_root: &Path) -> bool {
 /// merged into `root_config`; they are re-applied on top of each discovered
 /// subdirectory config so CLI precedence holds across every group, not just the root.
 ///
+/// Each group is then split by the `[overrides]` patterns its files match and
+/// by whether they are drafts, so every file is linted with its fully layered
+/// config.
+///
 /// See [`ResolutionRoots`] for how the grouping root and project root relate.
 pub fn resolve_config_groups(
     file_paths: &[String],
@@ -97,6 +123,109 @@ pub fn resolve_config_groups(
     inline_overrides: &[toml::Table],
     cache: &Option<Arc<LintCache>>,
     bypass_discovery: bool,
+) -> Vec<ConfigGroup> {
+    let groups = resolve_directory_groups(
+        file_paths,
+        root_config,
+        args,
+        roots,
+        inline_overrides,
+        cache,
+        bypass_discovery,
+    );
+    groups
+        .into_iter()
+        .flat_map(|group| split_by_file_settings(group, args, cache))
+        .collect()
+}
+
+/// Split a group by the `[overrides]` entries matching each of its files and
+/// by whether each file is a draft (see `draft-disable`). Files matching no
+/// entry that are not drafts keep the group's config and rules.
+fn split_by_file_settings(
+    group: ConfigGroup,
+    args: &crate::CheckArgs,
+    cache: &Option<Arc<LintCache>>,
+) -> Vec<ConfigGroup> {
+    let detect_drafts = !group.config.global.draft_disable.is_empty();
+    if group.config.overrides.is_empty() && !detect_drafts {
+        return vec![group];
+    }
+
+    // BTreeMap keeps group order deterministic
+    let mut files_by_settings: BTreeMap<(Vec<usize>, bool), Vec<String>> = BTreeMap::new();
+    for file in &group.files {
+        let is_draft =
+            detect_drafts && std::fs::read_to_string(file).is_ok_and(|content| group.config.is_draft(&content));
+        files_by_settings
+            .entry((group.config.matching_overrides(Path::new(file)), is_draft))
+            .or_default()
+            .push(file.clone());
+    }
+
+    let base_files = files_by_settings.remove(&(Vec::new(), false));
+    let mut groups: Vec<ConfigGroup> = files_by_settings
+        .into_iter()
+        .map(|((layers, is_draft), files)| {
+            let mut config = group.config.with_overrides(&layers);
+            if is_draft {
+                config = config.with_draft_relaxations();
+            }
+            let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, &config);
+            let cache_hashes = cache
+                .as_ref()
+                .map(|_| Arc::new(CacheHashes::new(&config, &enabled_rules)));
+            let skipped_rules = group
+                .rules
+                .iter()
+                .map(|rule| rule.name())
+                .filter(|name| !enabled_rules.iter().any(|rule| rule.name() == *name))
+                .filter_map(|name| {
+                    skip_reason(&group.config, &layers, is_draft, name).map(|reason| (name.to_string(), reason))
+                })
+                .collect();
+            ConfigGroup {
+                config,
+                rules: enabled_rules,
+                cache_hashes,
+                files,
+                skipped_rules,
+            }
+        })
+        .collect();
+    if let Some(files) = base_files {
+        groups.insert(0, ConfigGroup { files, ..group });
+    }
+
+    groups
+}
+
+/// Why `rule` is disabled by the given `[overrides]` layers or draft status
+fn skip_reason(config: &rumdl_config::Config, layers: &[usize], is_draft: bool, rule: &str) -> Option<SkipReason> {
+    if is_draft && config.global.draft_disable.iter().any(|name| name == rule) {
+        return Some(SkipReason::Draft);
+    }
+    // The last matching layer wins, as in `Config::with_overrides`
+    layers.iter().rev().find_map(|&idx| {
+        let (pattern, layer) = config.overrides.get_index(idx)?;
+        let disabled = layer.disable.iter().any(|name| name == rule)
+            || layer
+                .rules
+                .get(rule)
+                .is_some_and(|cfg| cfg.values.get("enabled") == Some(&toml::Value::Boolean(false)));
+        disabled.then(|| SkipReason::Override(pattern.clone()))
+    })
+}
+
+/// Group files by the config file governing their directory
+fn resolve_directory_groups(
+    file_paths: &[String],
+    root_config: &rumdl_config::Config,
+    args: &crate::CheckArgs,
+    roots: &ResolutionRoots<'_>,
+    inline_overrides: &[toml::Table],
+    cache: &Option<Arc<LintCache>>,
+    bypass_discovery: bool,
 ) -> Vec<ConfigGroup> {
     // Fast path: discovery bypassed or no grouping root; all files use the root config
     if bypass_discovery || roots.grouping_root.is_none() {
@@ -110,18 +239,22 @@ pub fn resolve_config_groups(
             rules: enabled_rules,
             cache_hashes,
             files: file_paths.to_vec(),
+            skipped_rules: Vec::new(),
         }];
     }
 
     let grouping_root = roots.grouping_root.unwrap();
 
+    let merge_nested = root_config.global.nested_configs == rumdl_config::NestedConfigs::Merge;
+
     // Cache: directory → Option<config file path>
     // None means "no subdirectory config found, use root"
     let mut dir_config_cache: HashMap<PathBuf, Option<PathBuf>> = HashMap::new();
 
-    // Map each file to its effective config path.
+    // Map each file to the configs it is linted with, outermost first.
+    // An empty chain means "use the root config".
     // BTreeMap ensures deterministic group ordering across runs.
-    let mut file_config_map: BTreeMap<Option<PathBuf>, Vec<String>> = BTreeMap::new();
+    let mut file_config_map: BTreeMap<Vec<PathBuf>, Vec<String>> = BTreeMap::new();
 
     for file_path in file_paths {
         let path = Path::new(file_path);
@@ -130,22 +263,32 @@ pub fn resolve_config_groups(
             _ => grouping_root.to_path_buf(),
         };
 
-        // Look up or discover the config for this directory
-        let config_path = discover_with_cache(&parent_dir, grouping_root, &mut dir_config_cache);
+        let chain = if merge_nested {
+            nested_config_chain(&parent_dir, grouping_root, &mut dir_config_cache)
+        } else {
+            // Look up or discover the config for this directory
+            discover_with_cache(&parent_dir, grouping_root, &mut dir_config_cache)
+                .into_iter()
+                .collect()
+        };
 
         // Configs at the grouping root level use the already-loaded root config
-        let effective_config = config_path.filter(|cp| !is_root_level_config(cp, grouping_root));
+        let effective_chain = if chain.iter().all(|cp| is_root_level_config(cp, grouping_root)) {
+            Vec::new()
+        } else {
+            chain
+        };
 
         file_config_map
-            .entry(effective_config)
+            .entry(effective_chain)
             .or_default()
             .push(file_path.clone());
     }
 
     let mut groups = Vec::new();
 
-    for (config_path, files) in file_config_map {
-        match config_path {
+    for (chain, files) in file_config_map {
+        match chain.last() {
             None => {
                 // Root config group
                 let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, root_config);
@@ -158,6 +301,7 @@ pub fn resolve_config_groups(
                     rules: enabled_rules,
                     cache_hashes,
                     files,
+                    skipped_rules: Vec::new(),
                 });
             }
             Some(path) => {
@@ -166,9 +310,9 @@ pub fn resolve_config_groups(
                 // none, never on the grouping anchor (which may sit above its scope).
                 let subconfig_root = roots
                     .project_root
-                    .or_else(|| config_scope_dir(&path))
+                    .or_else(|| config_scope_dir(&chain[0]))
                     .unwrap_or(grouping_root);
-                match rumdl_config::SourcedConfig::load_sourced_for_path(&path, subconfig_root) {
+                match rumdl_config::SourcedConfig::load_sourced_chain(&chain, subconfig_root) {
                     Ok(mut sourced) => {
                         // Layer inline `--config` overrides on top at CLI precedence
                         // (as the global config does), then convert and apply the
@@ -188,6 +332,7 @@ pub fn resolve_config_groups(
                             rules: enabled_rules,
                             cache_hashes,
                             files,
+                            skipped_rules: Vec::new(),
                         });
                     }
                     Err(e) => {
@@ -208,6 +353,7 @@ pub fn resolve_config_groups(
                             rules: enabled_rules,
                             cache_hashes,
                             files,
+                            skipped_rules: Vec::new(),
                         });
                     }
                 }
@@ -218,6 +364,30 @@ pub fn resolve_config_groups(
     groups
 }
 
+/// Collect every config governing `dir` for `nested-configs = "merge"`,
+/// outermost first: the nearest config, then the nearest one above its scope,
+/// and so on up to the grouping root.
+fn nested_config_chain(
+    dir: &Path,
+    grouping_root: &Path,
+    cache: &mut HashMap<PathBuf, Option<PathBuf>>,
+) -> Vec<PathBuf> {
+    let mut chain = Vec::new();
+    let mut current = Some(dir.to_path_buf());
+    while let Some(search_dir) = current.filter(|d| d.starts_with(grouping_root)) {
+        let Some(config_path) = discover_with_cache(&search_dir, grouping_root, cache) else {
+            break;
+        };
+        current = config_scope_dir(&config_path)
+            .filter(|scope| *scope != grouping_root)
+            .and_then(Path::parent)
+            .map(Path::to_path_buf);
+        chain.push(config_path);
+    }
+    chain.reverse();
+    chain
+}
+
 /// Discover the config file for a directory, using and populating the cache.
 ///
 /// Also caches intermediate directories traversed during the upward walk
@@ -280,6 +450,10 @@ fn apply_cli_config_overrides(config: &mut rumdl_config::Config, args: &crate::C
     if let Some(respect_gitignore) = args.respect_gitignore {
         config.global.respect_gitignore = respect_gitignore;
     }
+
+    if args.lint_code_blocks {
+        config.code_block_tools.enabled = true;
+    }
 }
 
 #[cfg(test)]
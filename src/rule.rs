@@ -53,6 +53,14 @@ pub struct LintWarning {
     pub rule_name: Option<String>,
 }
 
+impl LintWarning {
+    /// The fix to apply or count as fixable: `fix` unless it is only a
+    /// suggested edit
+    pub fn automatic_fix(&self) -> Option<&Fix> {
+        self.fix.as_ref().filter(|fix| fix.is_automatic())
+    }
+}
+
 /// One atomic fix attached to a `LintWarning`.
 ///
 /// `range`/`replacement` describe the primary edit. `additional_edits`
@@ -69,6 +77,14 @@ pub struct LintWarning {
 /// `Fix::new(range, replacement)`; only rules that need multi-location
 /// atomicity populate it via `Fix::with_additional_edits(...)`.
 ///
+/// A fix with [`Applicability::Suggested`] is a *suggested edit*: rules
+/// attach one where an automatic fix would be unsafe (renaming a duplicate
+/// heading, inventing alt text) but a starting point still helps. Suggested
+/// edits are offered as LSP code actions and shown in JSON output, and are
+/// never applied by `--fix`, `fmt`, or the LSP's fix-all. Use
+/// [`LintWarning::automatic_fix`] wherever a fix is about to be applied or
+/// counted as fixable.
+///
 /// `additional_edits` is intentionally a flat `Vec<Fix>` — nesting beyond
 /// one level isn't needed today and would complicate the apply contract.
 /// Apply order is "primary first, then additional in their declared order"
@@ -83,6 +99,26 @@ pub struct Fix {
     /// Empty for the common single-edit case. See struct docs for semantics.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub additional_edits: Vec<Fix>,
+    /// Whether the fix is applied automatically or only suggested
+    #[serde(default, skip_serializing_if = "Applicability::is_automatic")]
+    pub applicability: Applicability,
+}
+
+/// Whether a [`Fix`] may be applied without review
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Applicability {
+    /// Applied by `--fix`, `fmt`, and the LSP's fix-all
+    #[default]
+    Automatic,
+    /// Offered to the user but never applied automatically
+    Suggested,
+}
+
+impl Applicability {
+    pub fn is_automatic(&self) -> bool {
+        *self == Self::Automatic
+    }
 }
 
 impl Fix {
@@ -93,9 +129,24 @@ impl Fix {
             range,
             replacement,
             additional_edits: Vec::new(),
+            applicability: Applicability::Automatic,
         }
     }
 
+    /// Construct a suggested edit: offered in editors and JSON output, never
+    /// applied automatically. See [`Applicability::Suggested`].
+    pub fn suggestion(range: Range<usize>, replacement: String) -> Self {
+        Self {
+            applicability: Applicability::Suggested,
+            ..Self::new(range, replacement)
+        }
+    }
+
+    /// Whether the fix may be applied without review
+    pub fn is_automatic(&self) -> bool {
+        self.applicability.is_automatic()
+    }
+
     /// Construct a multi-edit fix bundle. The primary edit is applied first,
     /// followed by every entry in `additional_edits` as part of the same
     /// atomic operation.
@@ -104,6 +155,7 @@ impl Fix {
             range,
             replacement,
             additional_edits,
+            applicability: Applicability::Automatic,
         }
     }
 }
@@ -134,7 +186,8 @@ impl<'de> serde::Deserialize<'de> for Severity {
 }
 
 /// Type of rule for selective processing
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RuleCategory {
     Heading,
     List,
@@ -151,7 +204,8 @@ pub enum RuleCategory {
 }
 
 /// Capability of a rule to fix issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum FixCapability {
     /// Rule can automatically fix all violations it detects
     FullyFixable,
@@ -161,6 +215,44 @@ pub enum FixCapability {
     Unfixable,
 }
 
+/// Whether a rule's fixes keep what the document says
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FixSafety {
+    /// Only changes how the document is written (the default)
+    #[default]
+    Safe,
+    /// May change what the reader sees, such as the wording or capitalization
+    /// of text or where its lines break. Applied only with `--unsafe-fixes`
+    /// or `unsafe-fixes = true`.
+    Unsafe,
+}
+
+/// Where a rule's fixes run relative to other rules in a fix pass
+///
+/// The fix coordinator applies fixes in ascending priority, so rules that
+/// reshape the document go first and rules that only tidy the result go last.
+/// Explicit dependencies between rules still take precedence, and ties are
+/// broken by rule name, so the order never depends on registration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FixPriority {
+    /// Changes block structure: headings, list markers and nesting, code
+    /// fences, tables, front matter
+    Structure,
+    /// Rewrites inline content or style (the default)
+    #[default]
+    Content,
+    /// Only adds or removes spaces, tabs, or blank lines
+    Whitespace,
+    /// Rewraps paragraphs; runs after the content is settled, except where
+    /// a declared dependency says otherwise (MD013 still runs before the
+    /// whitespace rules MD009 and MD012, which clean up after it)
+    Reflow,
+    /// Converts line endings or the byte order mark; waits until no other
+    /// rule has fixes left, so it also converts the text they inserted
+    LineEndings,
+}
+
 /// Declares what cross-file data a rule needs
 ///
 /// Most rules only need single-file context and should use `None` (the default).
@@ -211,6 +303,12 @@ pub trait Rule: DynClone + Send + Sync {
         None
     }
 
+    /// Config keys this rule still accepts but that are deprecated, each paired
+    /// with the key that replaces it. `rumdl config lint` reports them.
+    fn deprecated_config_keys(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
     /// Returns the list of config keys whose deserializer accepts more than one TOML
     /// type (e.g. either a scalar or a list). The schema is built from a serialized
     /// default that can only encode one variant, so the validator would reject the
@@ -223,11 +321,36 @@ pub trait Rule: DynClone + Send + Sync {
         &[]
     }
 
+    /// Identifies the definition of a rule loaded at runtime, such as a prose
+    /// style rule, so cached results are dropped when its definition changes.
+    /// Built-in rules return `None`.
+    fn definition_fingerprint(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether each warning depends only on the block it is in, so a file
+    /// larger than `max-file-size` can be checked a chunk at a time. Rules
+    /// that compare blocks, track state across the document or look at its
+    /// start or end keep the default, `false`, and skip such files.
+    fn is_block_local(&self) -> bool {
+        false
+    }
+
     /// Declares the fix capability of this rule
     fn fix_capability(&self) -> FixCapability {
         FixCapability::FullyFixable // Safe default for backward compatibility
     }
 
+    /// Declares whether this rule's fixes may change what the document says
+    fn fix_safety(&self) -> FixSafety {
+        FixSafety::Safe
+    }
+
+    /// Declares where this rule's fixes run relative to other rules
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Content
+    }
+
     /// Declares cross-file analysis requirements for this rule
     ///
     /// Returns `CrossFileScope::None` by default, meaning the rule only needs
@@ -273,6 +396,19 @@ pub trait Rule: DynClone + Send + Sync {
         Ok(Vec::new()) // Default: no cross-file warnings
     }
 
+    /// Report issues that belong to the workspace rather than one Markdown file
+    ///
+    /// Called once after the entire workspace has been indexed, for rules that
+    /// return `CrossFileScope::Workspace`. Each entry is a path the rule reports
+    /// on, which need not be a Markdown file (MD086 reports image files nothing
+    /// references), with the warnings for that path.
+    fn workspace_check(
+        &self,
+        _workspace_index: &crate::workspace_index::WorkspaceIndex,
+    ) -> Result<Vec<(std::path::PathBuf, Vec<LintWarning>)>, LintError> {
+        Ok(Vec::new()) // Default: no workspace-level warnings
+    }
+
     /// Factory: create a rule from config (if present), or use defaults.
     fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
     where
@@ -0,0 +1,202 @@
+//! Time budget for rule checks (`rule-timeout-ms`)
+//!
+//! A check cannot be interrupted, so [`RuleWatchdog`] runs a file's checks one
+//! after another on a worker thread and waits for each with a timeout. When a
+//! check overruns, the worker is abandoned to finish on its own and a fresh one
+//! picks up the remaining rules. A pathological pattern therefore costs one
+//! stuck thread instead of a hung run.
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::rule::{LintResult, Rule};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Message from the worker thread
+enum Progress {
+    /// The next check is about to run; its budget starts now
+    Started,
+    Finished(LintResult),
+}
+
+struct Worker {
+    progress: Receiver<Progress>,
+    handle: JoinHandle<()>,
+}
+
+/// Runs the checks of one file under a per-rule time budget
+pub(crate) struct RuleWatchdog {
+    rules: Vec<Box<dyn Rule>>,
+    content: Arc<str>,
+    flavor: MarkdownFlavor,
+    source_file: Option<PathBuf>,
+    budget: Duration,
+    /// Index of the rule whose result comes next
+    next: usize,
+    worker: Option<Worker>,
+}
+
+impl RuleWatchdog {
+    /// Prepare to run `rules`, in order, over `content`
+    pub(crate) fn new(
+        rules: Vec<Box<dyn Rule>>,
+        content: &str,
+        flavor: MarkdownFlavor,
+        source_file: Option<PathBuf>,
+        budget: Duration,
+    ) -> Self {
+        Self {
+            rules,
+            content: Arc::from(content),
+            flavor,
+            source_file,
+            budget,
+            next: 0,
+            worker: None,
+        }
+    }
+
+    /// Result of the next rule, or `None` when it ran out of time.
+    ///
+    /// A panic in a check is passed on to the caller, as if the check had run
+    /// on the caller's thread.
+    pub(crate) fn next_result(&mut self) -> Option<LintResult> {
+        let index = self.next;
+        self.next += 1;
+        let worker = self.worker.get_or_insert_with(|| {
+            spawn_worker(
+                self.rules[index..]
+                    .iter()
+                    .map(|rule| dyn_clone::clone_box(&**rule))
+                    .collect(),
+                Arc::clone(&self.content),
+                self.flavor,
+                self.source_file.clone(),
+            )
+        });
+
+        // Parsing the file is not part of any rule's budget
+        if let Ok(Progress::Started) = worker.progress.recv() {
+            match worker.progress.recv_timeout(self.budget) {
+                Ok(Progress::Finished(result)) => return Some(result),
+                Ok(Progress::Started) => unreachable!("every check finishes before the next starts"),
+                Err(RecvTimeoutError::Timeout) => {
+                    let file = self
+                        .source_file
+                        .as_deref()
+                        .map_or_else(|| "<content>".to_string(), |path| path.display().to_string());
+                    log::warn!(
+                        "Rule {} exceeded the rule-timeout-ms budget of {}ms on {file} and was skipped",
+                        self.rules[index].name(),
+                        self.budget.as_millis()
+                    );
+                    // Left to finish in the background; its results are dropped
+                    self.worker = None;
+                    return None;
+                }
+                Err(RecvTimeoutError::Disconnected) => {}
+            }
+        }
+
+        // The worker hung up early, which only happens when a check panicked
+        let worker = self.worker.take().expect("worker was just used");
+        match worker.handle.join() {
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the worker sends a result for every rule"),
+        }
+    }
+}
+
+fn spawn_worker(
+    rules: Vec<Box<dyn Rule>>,
+    content: Arc<str>,
+    flavor: MarkdownFlavor,
+    source_file: Option<PathBuf>,
+) -> Worker {
+    let (sender, progress) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let ctx = LintContext::new(&content, flavor, source_file);
+        for rule in &rules {
+            if sender.send(Progress::Started).is_err() {
+                return;
+            }
+            let result = rule.check(&ctx);
+            if sender.send(Progress::Finished(result)).is_err() {
+                return;
+            }
+        }
+    });
+    Worker { progress, handle }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{LintWarning, Severity};
+
+    #[derive(Clone)]
+    struct SleepyRule {
+        name: &'static str,
+        sleep: Duration,
+    }
+
+    impl Rule for SleepyRule {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn description(&self) -> &'static str {
+            "Sleeps before warning once"
+        }
+
+        fn check(&self, _ctx: &LintContext) -> LintResult {
+            thread::sleep(self.sleep);
+            Ok(vec![LintWarning {
+                rule_name: Some(self.name.to_string()),
+                message: "slept".to_string(),
+                line: 1,
+                column: 1,
+                end_line: 1,
+                end_column: 1,
+                severity: Severity::Warning,
+                fix: None,
+            }])
+        }
+
+        fn fix(&self, ctx: &LintContext) -> Result<String, crate::rule::LintError> {
+            Ok(ctx.content.to_string())
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn sleepy(name: &'static str, millis: u64) -> Box<dyn Rule> {
+        Box::new(SleepyRule {
+            name,
+            sleep: Duration::from_millis(millis),
+        })
+    }
+
+    #[test]
+    fn slow_rule_is_skipped_and_later_rules_still_run() {
+        let rules = vec![sleepy("FAST1", 0), sleepy("SLOW", 5_000), sleepy("FAST2", 0)];
+        let mut watchdog = RuleWatchdog::new(
+            rules,
+            "# Title\n",
+            MarkdownFlavor::Standard,
+            None,
+            Duration::from_millis(200),
+        );
+
+        let first = watchdog.next_result().expect("fast rule finishes").unwrap();
+        assert_eq!(first[0].rule_name.as_deref(), Some("FAST1"));
+        assert!(watchdog.next_result().is_none(), "slow rule must be skipped");
+        let third = watchdog.next_result().expect("fast rule finishes").unwrap();
+        assert_eq!(third[0].rule_name.as_deref(), Some("FAST2"));
+    }
+}
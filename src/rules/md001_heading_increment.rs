@@ -1,5 +1,5 @@
 use crate::HeadingStyle;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::rules::heading_utils::HeadingUtils;
 use crate::utils::range_utils::calculate_heading_range;
@@ -227,8 +227,11 @@ impl Rule for MD001HeadingIncrement {
             if fix_info.needs_fix {
                 let line_content = line_info.content(ctx.content);
                 let original_indent = &line_content[..line_info.indent];
-                let replacement =
-                    HeadingUtils::convert_heading_style(&heading.raw_text, fix_info.fixed_level as u32, fix_info.style);
+                let replacement = HeadingUtils::convert_heading_style(
+                    heading.raw_text(ctx.content),
+                    fix_info.fixed_level as u32,
+                    fix_info.style,
+                );
 
                 let (start_line, start_col, end_line, end_col) =
                     calculate_heading_range(valid_heading.line_num, line_content);
@@ -272,6 +275,10 @@ impl Rule for MD001HeadingIncrement {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings
         if ctx.content.is_empty() || !ctx.likely_has_headings() {
@@ -3,7 +3,7 @@
 //!
 //! See [docs/md003.md](../../docs/md003.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rules::heading_utils::HeadingStyle;
 use crate::utils::range_utils::calculate_heading_range;
 use toml;
@@ -175,8 +175,11 @@ impl Rule for MD003HeadingStyle {
                         use crate::rules::heading_utils::HeadingUtils;
 
                         // Convert heading to target style, preserving inline attribute lists
-                        let converted_heading =
-                            HeadingUtils::convert_heading_style(&heading.raw_text, level as u32, expected_style);
+                        let converted_heading = HeadingUtils::convert_heading_style(
+                            heading.raw_text(ctx.content),
+                            level as u32,
+                            expected_style,
+                        );
 
                         // Preserve original indentation (including tabs)
                         let line = line_info.content(ctx.content);
@@ -263,6 +266,10 @@ impl Rule for MD003HeadingStyle {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings using character frequency
         if ctx.content.is_empty() || !ctx.likely_has_headings() {
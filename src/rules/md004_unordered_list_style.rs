@@ -49,7 +49,7 @@ use crate::LintContext;
 /// ## Rationale
 ///
 /// Consistent list markers improve readability and reduce distraction, especially in large documents or when collaborating with others. This rule helps enforce a uniform style across all unordered lists.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use toml;
 
 mod md004_config;
@@ -93,7 +93,7 @@ impl MD004UnorderedListStyle {
                 if let Some(line_info) = ctx.line_info(item_line)
                     && let Some(list_item) = &line_info.list_item
                     && !list_item.is_ordered
-                    && let Some(marker) = list_item.marker.chars().next()
+                    && let Some(marker) = list_item.marker(ctx.content).chars().next()
                 {
                     // Skip (rather than abort the whole count via `?`) on an
                     // empty marker, mirroring the guard in check(); aborting
@@ -170,7 +170,7 @@ impl Rule for MD004UnorderedListStyle {
                     // Get the marker character. The parser populates a non-empty
                     // marker for unordered items, but guard defensively so a
                     // future parse path producing an empty marker cannot panic.
-                    let Some(marker) = list_item.marker.chars().next() else {
+                    let Some(marker) = list_item.marker(ctx.content).chars().next() else {
                         continue;
                     };
 
@@ -281,6 +281,10 @@ impl Rule for MD004UnorderedListStyle {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_lists()
@@ -6,7 +6,7 @@
 use crate::utils::blockquote::effective_indent_in_blockquote;
 use crate::utils::range_utils::calculate_match_range;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 // No regex patterns needed for this rule
 use std::collections::HashMap;
 use toml;
@@ -555,7 +555,7 @@ impl MD005ListIndent {
 
             // Get parent's blockquote info for blockquote-aware continuation detection
             let parent_bq_level = line_info.blockquote.as_ref().map_or(0, |bq| bq.nesting_level);
-            let parent_bq_prefix_len = line_info.blockquote.as_ref().map_or(0, |bq| bq.prefix.len());
+            let parent_bq_prefix_len = line_info.blockquote.as_ref().map_or(0, |bq| bq.prefix_len());
 
             // Check if there are continuation lines between parent and current list.
             // Tight (lazy) continuation is valid at any indent past the marker; loose
@@ -934,6 +934,10 @@ impl Rule for MD005ListIndent {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if content is empty or has no list items
@@ -1,7 +1,7 @@
 /// Rule MD007: Unordered list indentation
 ///
 /// See [docs/md007.md](../../docs/md007.md) for full documentation, configuration, and examples.
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 
 pub mod md007_config;
@@ -621,7 +621,7 @@ impl Rule for MD007ULIndent {
                 let text = line_info
                     .blockquote
                     .as_ref()
-                    .map_or_else(|| line_info.content(ctx.content), |bq| bq.content.as_str());
+                    .map_or_else(|| line_info.content(ctx.content), |bq| bq.content(ctx.content));
                 let trimmed = text.trim_start();
                 let starts_like_list_marker = match trimmed.as_bytes().first() {
                     Some(b'-' | b'*' | b'+') => {
@@ -710,6 +710,10 @@ impl Rule for MD007ULIndent {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has lists
@@ -1,6 +1,6 @@
 use crate::lint_context::LintContext;
 use crate::lint_context::types::HeadingStyle;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_trailing_range;
 use crate::utils::regex_cache::{ORDERED_LIST_MARKER_REGEX, UNORDERED_LIST_MARKER_REGEX};
 
@@ -128,6 +128,10 @@ impl Rule for MD009TrailingSpaces {
         "Trailing spaces should be removed"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
         let line_index = &ctx.line_index;
@@ -318,6 +322,10 @@ impl Rule for MD009TrailingSpaces {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     crate::impl_rule_config_methods!(MD009Config);
 }
 
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 /// Rule MD010: No tabs
 ///
 /// See [docs/md010.md](../../docs/md010.md) for full documentation, configuration, and examples.
@@ -83,6 +83,10 @@ impl Rule for MD010NoHardTabs {
         "No tabs"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let line_index = &ctx.line_index;
 
@@ -198,6 +202,10 @@ impl Rule for MD010NoHardTabs {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     crate::impl_rule_config_methods!(MD010Config);
 }
 
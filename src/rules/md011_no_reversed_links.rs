@@ -96,6 +96,10 @@ impl Rule for MD011NoReversedLinks {
         "Reversed link syntax"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn category(&self) -> RuleCategory {
         RuleCategory::Link
     }
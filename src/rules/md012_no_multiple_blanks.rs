@@ -5,8 +5,7 @@ use crate::utils::LineIndex;
 use crate::utils::range_utils::calculate_line_range;
 use std::collections::HashSet;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
-use crate::rule_config_serde::RuleConfig;
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 mod md012_config;
 use md012_config::MD012Config;
@@ -42,6 +41,7 @@ impl MD012NoMultipleBlanks {
         Self {
             config: MD012Config {
                 maximum: PositiveUsize::new(maximum).unwrap_or(PositiveUsize::from_const(1)),
+                ..Default::default()
             },
             heading_blanks_above: 1,
             heading_blanks_below: 1,
@@ -96,33 +96,60 @@ impl MD012NoMultipleBlanks {
         for i in effective_max..blank_count {
             let excess_line_num = blank_start + i;
             if lines_to_check.contains(&excess_line_num) {
-                let excess_line = excess_line_num + 1;
-                let excess_line_content = lines.get(excess_line_num).unwrap_or(&"");
-                let (start_line, start_col, end_line, end_col) = calculate_line_range(excess_line, excess_line_content);
-                warnings.push(LintWarning {
-                    rule_name: Some(self.name().to_string()),
-                    severity: Severity::Warning,
-                    message: format!("Multiple consecutive blank lines {location}"),
-                    line: start_line,
-                    column: start_col,
-                    end_line,
-                    end_column: end_col,
-                    fix: Some(Fix::new(
-                        {
-                            let line_start = line_index.get_line_start_byte(excess_line).unwrap_or(0);
-                            let line_end = line_index
-                                .get_line_start_byte(excess_line + 1)
-                                .unwrap_or(line_start + 1);
-                            line_start..line_end
-                        },
-                        String::new(),
-                    )),
-                });
+                warnings.push(self.excess_blank_warning(excess_line_num, location, lines, line_index));
             }
         }
 
         warnings
     }
+
+    /// Warning for the excess blank line at 0-based `line_num`, fixed by
+    /// removing the line.
+    fn excess_blank_warning(
+        &self,
+        line_num: usize,
+        location: &str,
+        lines: &[&str],
+        line_index: &LineIndex,
+    ) -> LintWarning {
+        let excess_line = line_num + 1;
+        let excess_line_content = lines.get(line_num).unwrap_or(&"");
+        let (start_line, start_col, end_line, end_col) = calculate_line_range(excess_line, excess_line_content);
+        let line_start = line_index.get_line_start_byte(excess_line).unwrap_or(0);
+        let line_end = line_index
+            .get_line_start_byte(excess_line + 1)
+            .unwrap_or(line_start + 1);
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            severity: Severity::Warning,
+            message: format!("Multiple consecutive blank lines {location}"),
+            line: start_line,
+            column: start_col,
+            end_line,
+            end_column: end_col,
+            fix: Some(Fix::new(line_start..line_end, String::new())),
+        }
+    }
+
+    /// Warnings for blank runs inside code blocks longer than `maximum`.
+    ///
+    /// A run ends at a non-blank line or where the code block ends, so blanks
+    /// between two blocks (which are prose) are never counted here.
+    fn check_code_blocks(&self, ctx: &LintContext, maximum: usize, lines: &[&str]) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        let mut run = 0;
+        for (line_num, info) in ctx.lines.iter().enumerate() {
+            if info.in_code_block && !info.in_front_matter && lines.get(line_num).is_some_and(|l| l.trim().is_empty()) {
+                run += 1;
+                if run > maximum {
+                    warnings.push(self.excess_blank_warning(line_num, "in code block", lines, &ctx.line_index));
+                }
+            } else {
+                run = 0;
+            }
+        }
+        warnings
+    }
 }
 
 /// Check if the given 0-based line index is part of a heading.
@@ -174,6 +201,10 @@ impl Rule for MD012NoMultipleBlanks {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
 
@@ -193,7 +224,8 @@ impl Rule for MD012NoMultipleBlanks {
         // Content is normalized to LF at I/O boundary
         let ends_with_multiple_newlines = content.ends_with("\n\n");
 
-        if !has_potential_blanks && !ends_with_multiple_newlines {
+        // A zero `code-blocks` limit flags single blank lines too
+        if !has_potential_blanks && !ends_with_multiple_newlines && self.config.code_blocks != Some(0) {
             return Ok(Vec::new());
         }
 
@@ -302,27 +334,32 @@ impl Rule for MD012NoMultipleBlanks {
             }
         }
 
+        if let Some(maximum) = self.config.code_blocks {
+            warnings.extend(self.check_code_blocks(ctx, maximum, lines));
+        }
+
         // Handle trailing blanks at EOF
         // Main loop only reports mid-document blanks (between content)
-        // EOF handler reports trailing blanks with stricter rules (any blank at EOF is flagged)
+        // EOF handler reports trailing blanks beyond `end-of-file` (by default any blank at EOF)
         //
         // The blank_count at end of loop might include blanks BEFORE a code block at EOF,
         // which aren't truly "trailing blanks". We need to verify the actual last line is blank.
         let last_line_is_blank = lines.last().is_some_and(|l| l.trim().is_empty());
 
         // Check for trailing blank lines
-        // EOF semantics: ANY blank line at EOF should be flagged (stricter than mid-document)
+        // EOF semantics: blank lines beyond `end-of-file` are flagged, independent of `maximum`
         // Only fire if the actual last line(s) of the file are blank
-        if blank_count > 0 && last_line_is_blank {
+        let allowed_at_eof = self.config.end_of_file;
+        if blank_count > allowed_at_eof && last_line_is_blank {
             let location = "at end of file";
 
             // Report on the last line (which is blank)
             let report_line = lines.len();
 
-            // Calculate fix: remove all trailing blank lines
+            // Calculate fix: remove the trailing blank lines beyond the allowed ones
             // Find where the trailing blanks start (blank_count tells us how many consecutive blanks)
             let fix_start = line_index
-                .get_line_start_byte(report_line - blank_count + 1)
+                .get_line_start_byte(report_line - blank_count + 1 + allowed_at_eof)
                 .unwrap_or(0);
             let fix_end = content.len();
 
@@ -366,19 +403,8 @@ impl Rule for MD012NoMultipleBlanks {
     }
 
     fn default_config_section(&self) -> Option<(String, toml::Value)> {
-        let default_config = MD012Config::default();
-        let json_value = serde_json::to_value(&default_config).ok()?;
-        let toml_value = crate::rule_config_serde::json_to_toml_value(&json_value)?;
-
-        if let toml::Value::Table(table) = toml_value {
-            if !table.is_empty() {
-                Some((MD012Config::RULE_NAME.to_string(), toml::Value::Table(table)))
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        // Keep the unset `code-blocks` key visible to config validation
+        crate::rule_config_serde::nullable_config_section_for::<MD012Config>()
     }
 
     fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
@@ -476,6 +502,62 @@ mod tests {
         assert_eq!(fixed, "Before\n\n```\ncode\n\n\n\nmore code\n```\n\nAfter");
     }
 
+    fn rule_with(code_blocks: Option<usize>, end_of_file: usize) -> MD012NoMultipleBlanks {
+        MD012NoMultipleBlanks::from_config_struct(MD012Config {
+            code_blocks,
+            end_of_file,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_code_block_limit() {
+        let rule = rule_with(Some(1), 0);
+        let content = "Before\n\n```\ncode\n\n\n\nmore code\n\nend\n```\n\n    indented\n\n\n    code\n\nAfter\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![6, 7, 15]);
+        assert!(result.iter().all(|w| w.message.contains("in code block")));
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "Before\n\n```\ncode\n\nmore code\n\nend\n```\n\n    indented\n\n    code\n\nAfter\n"
+        );
+    }
+
+    #[test]
+    fn test_code_block_limit_zero_flags_single_blanks() {
+        let rule = rule_with(Some(0), 0);
+        let content = "```\na\n\nb\n```\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+    }
+
+    #[test]
+    fn test_code_block_limit_skips_front_matter() {
+        let rule = rule_with(Some(0), 0);
+        let content = "---\ntitle: Test\n\n\nauthor: Me\n---\n\nContent\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_end_of_file_allowance() {
+        let rule = rule_with(None, 1);
+        let ctx = LintContext::new("Content\n\n", crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+
+        let ctx = LintContext::new("Content\n\n\n\n", crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("at end of file"));
+        assert_eq!(rule.fix(&ctx).unwrap(), "Content\n\n");
+    }
+
     #[test]
     fn test_blank_lines_in_front_matter() {
         let rule = MD012NoMultipleBlanks::default();
@@ -8,10 +8,21 @@ use serde::{Deserialize, Serialize};
 pub struct MD012Config {
     /// Maximum number of consecutive blank lines allowed within the document (default: 1)
     ///
-    /// This setting controls blank lines within the document content.
-    /// Blank lines at EOF are always enforced to be 0 (following POSIX/Prettier standards).
+    /// This setting controls blank lines in prose. Code blocks and the end of
+    /// the file have their own limits (`code-blocks`, `end-of-file`); front
+    /// matter is never checked.
     #[serde(default = "default_maximum")]
     pub maximum: PositiveUsize,
+
+    /// Maximum number of consecutive blank lines allowed inside code blocks,
+    /// fenced or indented (default: unset, code blocks are not checked)
+    #[serde(default, alias = "code_blocks")]
+    pub code_blocks: Option<usize>,
+
+    /// Number of blank lines allowed at the end of the file (default: 0,
+    /// following POSIX/Prettier standards)
+    #[serde(default, alias = "end_of_file")]
+    pub end_of_file: usize,
 }
 
 fn default_maximum() -> PositiveUsize {
@@ -22,6 +33,8 @@ impl Default for MD012Config {
     fn default() -> Self {
         Self {
             maximum: default_maximum(),
+            code_blocks: None,
+            end_of_file: 0,
         }
     }
 }
@@ -1,7 +1,7 @@
 /// Rule MD013: Line length
 ///
 /// See [docs/md013.md](../../docs/md013.md) for full documentation, configuration, and examples.
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::mkdocs_admonitions;
 use crate::utils::mkdocs_attr_list::is_standalone_attr_list;
@@ -21,6 +21,7 @@ use toml;
 mod block_builder;
 mod helpers;
 pub mod md013_config;
+mod table_reflow;
 use crate::utils::is_template_directive_only;
 use block_builder::{Block, BlockBuilder};
 use helpers::{
@@ -29,15 +30,16 @@ use helpers::{
     trim_preserving_hard_break,
 };
 pub use md013_config::MD013Config;
-use md013_config::{LengthMode, ReflowMode};
+use md013_config::{AmbiguousWidth, LengthMode, ReflowMode, SemanticBreakRules, TableReflow};
 
 #[cfg(test)]
 mod tests;
-use unicode_width::UnicodeWidthStr;
 
 #[derive(Clone, Default)]
 pub struct MD013LineLength {
     pub(crate) config: MD013Config,
+    /// `config.no_break_patterns`, compiled once
+    no_break_patterns: Vec<regex::Regex>,
 }
 
 /// Blockquote paragraph line collected for reflow, with original line index for range computation.
@@ -64,15 +66,24 @@ impl MD013LineLength {
                 reflow: false,
                 reflow_mode: ReflowMode::default(),
                 length_mode: LengthMode::default(),
+                ambiguous_width: AmbiguousWidth::default(),
                 abbreviations: Vec::new(),
                 require_sentence_capital: true,
                 ignore_link_urls: true,
+                no_break_patterns: Vec::new(),
+                semantic_break_rules: SemanticBreakRules::default(),
+                table_reflow: TableReflow::default(),
             },
+            no_break_patterns: Vec::new(),
         }
     }
 
     pub fn from_config_struct(config: MD013Config) -> Self {
-        Self { config }
+        let no_break_patterns = config.compile_no_break_patterns();
+        Self {
+            config,
+            no_break_patterns,
+        }
     }
 
     /// Return a clone with code block checking disabled.
@@ -85,11 +96,7 @@ impl MD013LineLength {
 
     /// Convert MD013 LengthMode to text_reflow ReflowLengthMode
     fn reflow_length_mode(&self) -> ReflowLengthMode {
-        match self.config.length_mode {
-            LengthMode::Chars => ReflowLengthMode::Chars,
-            LengthMode::Visual => ReflowLengthMode::Visual,
-            LengthMode::Bytes => ReflowLengthMode::Bytes,
-        }
+        self.config.reflow_length_mode()
     }
 
     fn should_ignore_line(
@@ -193,6 +200,10 @@ impl Rule for MD013LineLength {
         "Line length should not be excessive"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         // Use pre-parsed inline config from LintContext
         let config_override = ctx.inline_config().get_rule_config("MD013");
@@ -316,7 +327,7 @@ impl Rule for MD013LineLength {
 
         // Use pre-computed table blocks from context
         // We need this for both the table skip check AND the paragraphs check
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
         let mut table_lines_set = std::collections::HashSet::new();
         for table in table_blocks {
             table_lines_set.insert(table.header_line + 1);
@@ -409,6 +420,19 @@ impl Rule for MD013LineLength {
                 }
             }
 
+            // Pandoc attribute blocks (`{#id .class key="value"}`) on div fences,
+            // headings, spans, images, and inline code are metadata, not prose,
+            // and cannot be wrapped. Suppress when the excess comes entirely from them.
+            if !effective_config.strict && ctx.flavor.is_pandoc_compatible() {
+                let attr_width: usize = crate::utils::pandoc::find_attribute_block_ranges(line)
+                    .iter()
+                    .map(|r| self.calculate_string_length(&line[r.start..r.end]))
+                    .sum();
+                if attr_width > 0 && effective_length.saturating_sub(attr_width) <= line_limit {
+                    continue;
+                }
+            }
+
             // Skip mkdocstrings and pymdown blocks (already handled by LintContext)
             if ctx.lines[line_idx].in_mkdocstrings || ctx.lines[line_idx].in_pymdown_block {
                 continue;
@@ -588,6 +612,14 @@ impl Rule for MD013LineLength {
             });
         }
 
+        // Tables cannot be wrapped; `table-reflow` offers to change their syntax instead
+        if effective_config.table_reflow != TableReflow::Off && !skip_length_checks {
+            for tw in self.generate_table_warnings(ctx, &effective_config, lines) {
+                warnings.retain(|w| w.line < tw.line || w.line > tw.end_line);
+                warnings.push(tw);
+            }
+        }
+
         // If reflow is enabled, generate paragraph-based fixes
         if effective_config.reflow {
             let paragraph_warnings = self.generate_paragraph_fixes(ctx, &effective_config, lines);
@@ -627,6 +659,15 @@ impl Rule for MD013LineLength {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Reflow
+    }
+
+    fn fix_safety(&self) -> FixSafety {
+        // Reflow moves where lines break, which can turn text into Markdown syntax
+        FixSafety::Unsafe
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         self.should_skip_with_config(ctx, &self.config)
     }
@@ -648,6 +689,13 @@ impl Rule for MD013LineLength {
         Some(aliases)
     }
 
+    fn deprecated_config_keys(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("enable-reflow", "reflow"),
+            ("strict-sentences", "require-sentence-capital"),
+        ]
+    }
+
     fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
     where
         Self: Sized,
@@ -657,6 +705,7 @@ impl Rule for MD013LineLength {
         if rule_config.line_length.get() == 80 {
             rule_config.line_length = config.global.line_length;
         }
+        rule_config.inherit_global_length_settings(config);
         Box::new(Self::from_config_struct(rule_config))
     }
 }
@@ -750,13 +799,16 @@ impl MD013LineLength {
                     break;
                 }
 
-                if self.is_blockquote_content_boundary(&bq.content, line_num, ctx, config.strict) {
+                if self.is_blockquote_content_boundary(bq.content(ctx.content), line_num, ctx, config.strict) {
                     break;
                 }
 
                 collected.push(CollectedBlockquoteLine {
                     line_idx: i,
-                    data: BlockquoteLineData::explicit(trim_preserving_hard_break(&bq.content), bq.prefix.clone()),
+                    data: BlockquoteLineData::explicit(
+                        trim_preserving_hard_break(bq.content(ctx.content)),
+                        bq.prefix(ctx.content).to_string(),
+                    ),
                 });
                 i += 1;
                 continue;
@@ -825,7 +877,7 @@ impl MD013LineLength {
             return (None, next_idx);
         }
 
-        let fallback_prefix = start_bq.prefix.clone();
+        let fallback_prefix = start_bq.prefix(ctx.content).to_string();
         let explicit_prefix = dominant_blockquote_prefix(&line_data, &fallback_prefix);
         let continuation_style = blockquote_continuation_style(&line_data);
 
@@ -855,6 +907,8 @@ impl MD013LineLength {
             } else {
                 None
             },
+            no_break_patterns: self.no_break_patterns.clone(),
+            semantic_break_rules: config.semantic_break_rules.clone(),
         };
 
         let reflowed_with_style =
@@ -972,9 +1026,9 @@ impl MD013LineLength {
 
         // The marker line carries the canonical blockquote prefix: its content begins
         // with the list marker, so no list indent has been folded into the prefix.
-        let bq_prefix = start_bq.prefix.clone();
+        let bq_prefix = start_bq.prefix(ctx.content).to_string();
 
-        let (marker, first_body) = extract_list_marker_and_content(&start_bq.content);
+        let (marker, first_body) = extract_list_marker_and_content(start_bq.content(ctx.content));
         if marker.is_empty() {
             return (None, start_idx + 1);
         }
@@ -1017,7 +1071,7 @@ impl MD013LineLength {
                 break;
             }
 
-            let content = bq.content.as_str();
+            let content = bq.content(ctx.content);
             if content.trim().is_empty() {
                 // Blank quoted line ends the tight paragraph. A following indented
                 // paragraph (loose item) is reflowed on its own by the prose path.
@@ -1110,6 +1164,8 @@ impl MD013LineLength {
             } else {
                 None
             },
+            no_break_patterns: self.no_break_patterns.clone(),
+            semantic_break_rules: config.semantic_break_rules.clone(),
         };
 
         let reflowed = crate::utils::text_reflow::reflow_line(body_text, &reflow_options);
@@ -1181,6 +1237,64 @@ impl MD013LineLength {
     }
 
     /// Generate paragraph-based fixes
+    /// One warning per pipe table with a row over the line length, carrying a
+    /// grid table (Pandoc flavors) or HTML table rewrite. The rewrite is only a
+    /// suggestion unless `table-reflow = "auto"`.
+    fn generate_table_warnings(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        config: &MD013Config,
+        lines: &[&str],
+    ) -> Vec<LintWarning> {
+        let line_limit = config.line_length.get();
+        let measure = |s: &str| config.measure(s);
+        let line_ending = crate::utils::line_ending::detect_line_ending(ctx.content);
+        let mut warnings = Vec::new();
+
+        for table in ctx.table_blocks() {
+            let longest = (table.start_line..=table.end_line)
+                .map(|i| measure(lines[i].trim_end_matches('\r')))
+                .max()
+                .unwrap_or(0);
+            if longest <= line_limit {
+                continue;
+            }
+            let Some(parsed) = table_reflow::ParsedTable::parse(lines, table, ctx.flavor) else {
+                continue;
+            };
+
+            let replacement = if ctx.flavor.is_pandoc_compatible() {
+                parsed.to_grid_table(line_limit, &measure)
+            } else {
+                parsed.to_html_table()
+            }
+            .replace('\n', line_ending);
+            let last_line = lines[table.end_line].trim_end_matches('\r');
+            let range =
+                ctx.lines[table.start_line].byte_offset..ctx.lines[table.end_line].byte_offset + last_line.len();
+            let fix = if config.table_reflow == TableReflow::Auto {
+                crate::rule::Fix::new(range, replacement)
+            } else {
+                crate::rule::Fix::suggestion(range, replacement)
+            };
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                message: format!(
+                    "Table row length {longest} exceeds {line_limit} characters (widest cell in column {})",
+                    parsed.widest_column(&measure)
+                ),
+                line: table.start_line + 1,
+                column: 1,
+                end_line: table.end_line + 1,
+                end_column: last_line.chars().count() + 1,
+                severity: Severity::Warning,
+                fix: Some(fix),
+            });
+        }
+        warnings
+    }
+
     fn generate_paragraph_fixes(
         &self,
         ctx: &crate::lint_context::LintContext,
@@ -1229,7 +1343,7 @@ impl MD013LineLength {
                 let is_bq_list_item = ctx.lines[i]
                     .blockquote
                     .as_deref()
-                    .is_some_and(|bq| is_list_item(&bq.content));
+                    .is_some_and(|bq| is_list_item(bq.content(ctx.content)));
                 let (warning, next_idx) = if is_bq_list_item {
                     self.generate_blockquote_list_item_fix(ctx, config, lines, &line_index, i, line_ending)
                 } else {
@@ -1568,6 +1682,8 @@ impl MD013LineLength {
                     myst_roles: ctx.flavor.supports_myst_roles(),
                     require_sentence_capital: config.require_sentence_capital,
                     max_list_continuation_indent: None,
+                    no_break_patterns: self.no_break_patterns.clone(),
+                    semantic_break_rules: config.semantic_break_rules.clone(),
                 };
 
                 let indent_str = " ".repeat(FN_INDENT);
@@ -1802,6 +1918,8 @@ impl MD013LineLength {
                     } else {
                         None
                     },
+                    no_break_patterns: self.no_break_patterns.clone(),
+                    semantic_break_rules: config.semantic_break_rules.clone(),
                 };
                 let reflowed = crate::utils::text_reflow::reflow_line(&paragraph_text, &reflow_options);
 
@@ -2334,6 +2452,8 @@ impl MD013LineLength {
                         } else {
                             None
                         },
+                        no_break_patterns: self.no_break_patterns.clone(),
+                        semantic_break_rules: config.semantic_break_rules.clone(),
                     };
 
                     let mut result: Vec<String> = Vec::new();
@@ -2741,6 +2861,8 @@ impl MD013LineLength {
                                     } else {
                                         None
                                     },
+                                    no_break_patterns: self.no_break_patterns.clone(),
+                                    semantic_break_rules: config.semantic_break_rules.clone(),
                                 };
 
                                 // Output each segment
@@ -3163,6 +3285,8 @@ impl MD013LineLength {
                     } else {
                         None
                     },
+                    no_break_patterns: self.no_break_patterns.clone(),
+                    semantic_break_rules: config.semantic_break_rules.clone(),
                 };
                 let mut reflowed = crate::utils::text_reflow::reflow_line(&paragraph_text, &reflow_options);
 
@@ -3281,11 +3405,7 @@ impl MD013LineLength {
 
     /// Calculate string length based on the configured length mode
     fn calculate_string_length(&self, s: &str) -> usize {
-        match self.config.length_mode {
-            LengthMode::Chars => s.chars().count(),
-            LengthMode::Visual => s.width(),
-            LengthMode::Bytes => s.len(),
-        }
+        self.config.measure(s)
     }
 
     /// Calculate effective line length
@@ -3316,8 +3436,8 @@ impl MD013LineLength {
         let mut constructs: Vec<(usize, usize, usize)> = Vec::new();
 
         // Binary search: links are sorted by byte_offset, so link.line is non-decreasing
-        let link_start = ctx.links.partition_point(|l| l.line < line_number);
-        for link in &ctx.links[link_start..] {
+        let link_start = ctx.links().partition_point(|l| l.line < line_number);
+        for link in &ctx.links()[link_start..] {
             if link.line != line_number {
                 break;
             }
@@ -3334,8 +3454,8 @@ impl MD013LineLength {
             constructs.push((link.byte_offset, link.byte_end, text_only_len));
         }
 
-        let img_start = ctx.images.partition_point(|i| i.line < line_number);
-        for image in &ctx.images[img_start..] {
+        let img_start = ctx.images().partition_point(|i| i.line < line_number);
+        for image in &ctx.images()[img_start..] {
             if image.line != line_number {
                 break;
             }
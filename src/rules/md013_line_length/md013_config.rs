@@ -1,5 +1,7 @@
 use crate::rule_config_serde::RuleConfig;
 use crate::types::LineLength;
+pub use crate::types::{AmbiguousWidth, LengthMode};
+pub use crate::utils::text_reflow::SemanticBreakRules;
 use serde::{Deserialize, Serialize};
 
 /// Reflow mode for MD013
@@ -23,21 +25,18 @@ pub enum ReflowMode {
     SemanticLineBreaks,
 }
 
-/// Length calculation mode for MD013
+/// How MD013 handles pipe tables with rows over the line length
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "kebab-case")]
-pub enum LengthMode {
-    /// Count Unicode characters (grapheme clusters)
-    /// Use this only if you need backward compatibility with character-based counting
-    #[serde(alias = "chars", alias = "characters")]
-    Chars,
-    /// Count visual display width (CJK characters = 2 columns, emoji = 2, etc.) - default
-    /// This is semantically correct: line-length = 80 means "80 columns on screen"
+pub enum TableReflow {
+    /// Leave tables alone (default)
     #[default]
-    #[serde(alias = "display", alias = "visual_width")]
-    Visual,
-    /// Count raw bytes (legacy mode, not recommended for Unicode text)
-    Bytes,
+    Off,
+    /// Report the table with a suggested rewrite that is never applied automatically
+    Suggest,
+    /// Rewrite the table when fixing: a grid table with wrapped cells for
+    /// Pandoc-compatible flavors, an HTML table otherwise
+    Auto,
 }
 
 /// Configuration for MD013 (Line length)
@@ -140,13 +139,21 @@ pub struct MD013Config {
     #[serde(default, alias = "reflow_mode")]
     pub reflow_mode: ReflowMode,
 
-    /// Length calculation mode (default: "chars")
+    /// Length calculation mode (default: "visual")
     /// - "chars": Count Unicode characters (emoji = 1, CJK = 1)
     /// - "visual": Count visual display width (emoji = 2, CJK = 2)
     /// - "bytes": Count raw bytes (not recommended for Unicode)
+    ///
+    /// Inherited from the global `length-mode`, can be overridden per-rule
     #[serde(default, alias = "length_mode")]
     pub length_mode: LengthMode,
 
+    /// Width of East Asian ambiguous-width characters in visual mode
+    /// (default: "narrow"). Inherited from the global `ambiguous-width`, can
+    /// be overridden per-rule
+    #[serde(default, alias = "ambiguous_width")]
+    pub ambiguous_width: AmbiguousWidth,
+
     /// Custom abbreviations for sentence-per-line mode
     /// Periods are optional - both "Dr" and "Dr." work the same
     /// Inherited from global config, can be overridden per-rule
@@ -165,6 +172,25 @@ pub struct MD013Config {
         alias = "strict-sentences"
     )]
     pub require_sentence_capital: bool,
+
+    /// Regular expressions for inline spans that reflow must keep intact
+    /// (default: none), e.g. `\{\{.*?\}\}` for Jinja expressions. Each match
+    /// is treated as a single unbreakable token: it is never split across
+    /// lines and its content is left untouched. Invalid patterns are ignored
+    /// with a warning.
+    #[serde(default, alias = "no_break_patterns")]
+    pub no_break_patterns: Vec<String>,
+
+    /// Break-point heuristics for `semantic-line-breaks` mode: minimum length
+    /// before a comma break, the break-word list, and whether numbered
+    /// citations stay attached to the text they follow
+    #[serde(default, alias = "semantic_break_rules")]
+    pub semantic_break_rules: SemanticBreakRules,
+
+    /// What to do with pipe tables whose rows exceed the line length
+    /// (default: "off"). Works independently of `tables` and `reflow`.
+    #[serde(default, alias = "table_reflow")]
+    pub table_reflow: TableReflow,
 }
 
 fn default_line_length() -> LineLength {
@@ -221,8 +247,12 @@ impl Default for MD013Config {
             reflow: false,
             reflow_mode: ReflowMode::default(),
             length_mode: LengthMode::default(),
+            ambiguous_width: AmbiguousWidth::default(),
             abbreviations: Vec::new(),
             require_sentence_capital: default_require_sentence_capital(),
+            no_break_patterns: Vec::new(),
+            semantic_break_rules: SemanticBreakRules::default(),
+            table_reflow: TableReflow::default(),
         }
     }
 }
@@ -271,16 +301,55 @@ impl MD013Config {
         }
     }
 
+    /// Compile `no_break_patterns`, skipping any that are not valid regexes
+    pub fn compile_no_break_patterns(&self) -> Vec<regex::Regex> {
+        self.no_break_patterns
+            .iter()
+            .filter_map(|pattern| match regex::Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(err) => {
+                    log::warn!("Invalid no-break-patterns regex for MD013 ('{pattern}'): {err}. Ignoring it.");
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Take `length-mode` and `ambiguous-width` from the global settings
+    /// unless the rule's own configuration sets them
+    pub fn inherit_global_length_settings(&mut self, config: &crate::config::Config) {
+        use crate::config::get_rule_config_value;
+        if get_rule_config_value::<LengthMode>(config, Self::RULE_NAME, "length-mode").is_none() {
+            self.length_mode = config.global.length_mode;
+        }
+        if get_rule_config_value::<AmbiguousWidth>(config, Self::RULE_NAME, "ambiguous-width").is_none() {
+            self.ambiguous_width = config.global.ambiguous_width;
+        }
+    }
+
+    /// Length of `s` under the configured length mode and ambiguous-width policy
+    pub fn measure(&self, s: &str) -> usize {
+        self.length_mode.measure(s, self.ambiguous_width)
+    }
+
+    /// The reflow engine's equivalent of `length_mode` and `ambiguous_width`
+    pub fn reflow_length_mode(&self) -> crate::utils::text_reflow::ReflowLengthMode {
+        use crate::utils::text_reflow::ReflowLengthMode;
+        match (self.length_mode, self.ambiguous_width) {
+            (LengthMode::Chars, _) => ReflowLengthMode::Chars,
+            (LengthMode::Visual, AmbiguousWidth::Narrow) => ReflowLengthMode::Visual,
+            (LengthMode::Visual, AmbiguousWidth::Wide) => ReflowLengthMode::VisualWide,
+            (LengthMode::Bytes, _) => ReflowLengthMode::Bytes,
+        }
+    }
+
     /// Build a `ReflowOptions` from this configuration.
     ///
-    /// Converts `reflow_mode`, `length_mode`, `abbreviations`, and `line_length`
-    /// into the unified `ReflowOptions` type used by the reflow engine.
+    /// Converts `reflow_mode`, `length_mode`, `ambiguous_width`, `abbreviations`,
+    /// `no_break_patterns`, `semantic_break_rules` and `line_length` into the unified `ReflowOptions` type used by the
+    /// reflow engine.
     pub fn to_reflow_options(&self) -> crate::utils::text_reflow::ReflowOptions {
-        let length_mode = match self.length_mode {
-            LengthMode::Chars => crate::utils::text_reflow::ReflowLengthMode::Chars,
-            LengthMode::Visual => crate::utils::text_reflow::ReflowLengthMode::Visual,
-            LengthMode::Bytes => crate::utils::text_reflow::ReflowLengthMode::Bytes,
-        };
+        let length_mode = self.reflow_length_mode();
         crate::utils::text_reflow::ReflowOptions {
             line_length: self.line_length.get(),
             break_on_sentences: true,
@@ -293,6 +362,8 @@ impl MD013Config {
             myst_roles: false,
             require_sentence_capital: self.require_sentence_capital,
             max_list_continuation_indent: None,
+            no_break_patterns: self.compile_no_break_patterns(),
+            semantic_break_rules: self.semantic_break_rules.clone(),
         }
     }
 }
@@ -395,6 +466,10 @@ mod tests {
             reflow: true,
             reflow_mode: ReflowMode::SentencePerLine,
             length_mode: LengthMode::default(),
+            ambiguous_width: AmbiguousWidth::default(),
+            no_break_patterns: Vec::new(),
+            semantic_break_rules: SemanticBreakRules::default(),
+            table_reflow: TableReflow::default(),
             abbreviations: Vec::new(),
             require_sentence_capital: true,
             ignore_link_urls: true,
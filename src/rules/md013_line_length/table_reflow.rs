@@ -0,0 +1,259 @@
+//! Rewrites of over-long pipe tables for MD013's `table-reflow` option.
+//!
+//! A pipe table row cannot be wrapped, so a table with long cells can only be
+//! shortened by changing its syntax: Pandoc grid tables allow multi-line cells,
+//! and an HTML table is exempt from line-length checks everywhere.
+
+use crate::config::MarkdownFlavor;
+use crate::utils::table_utils::{TableBlock, TableUtils};
+use pulldown_cmark::{Options, Parser, html};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Align {
+    /// No colon in the delimiter row
+    Unset,
+    Left,
+    Center,
+    Right,
+}
+
+/// A pipe table split into trimmed cells, every row padded to the header width
+#[derive(Debug)]
+pub(super) struct ParsedTable {
+    header: Vec<String>,
+    aligns: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+impl ParsedTable {
+    /// Parse the table at `table`. Tables inside lists or blockquotes are not
+    /// supported and yield `None`.
+    pub(super) fn parse(lines: &[&str], table: &TableBlock, flavor: MarkdownFlavor) -> Option<Self> {
+        if table.list_context.is_some() {
+            return None;
+        }
+        let row_lines = std::iter::once(table.header_line)
+            .chain(std::iter::once(table.delimiter_line))
+            .chain(table.content_lines.iter().copied());
+        let mut cells_per_line = Vec::new();
+        for idx in row_lines {
+            let line = lines.get(idx)?;
+            if line.trim_start().starts_with('>') {
+                return None;
+            }
+            let cells: Vec<String> = TableUtils::split_table_row_with_flavor(line, flavor)
+                .iter()
+                .map(|c| c.trim().to_string())
+                .collect();
+            cells_per_line.push(cells);
+        }
+
+        let mut cells_per_line = cells_per_line.into_iter();
+        let header = cells_per_line.next()?;
+        let delimiter = cells_per_line.next()?;
+        if header.is_empty() {
+            return None;
+        }
+        let aligns = (0..header.len())
+            .map(|i| {
+                let d = delimiter.get(i).map_or("", String::as_str);
+                match (d.starts_with(':'), d.ends_with(':') && d.len() > 1) {
+                    (true, true) => Align::Center,
+                    (true, false) => Align::Left,
+                    (false, true) => Align::Right,
+                    (false, false) => Align::Unset,
+                }
+            })
+            .collect();
+        // GFM drops excess cells and fills missing ones with empty cells
+        let rows = cells_per_line
+            .map(|mut row| {
+                row.resize(header.len(), String::new());
+                row
+            })
+            .collect();
+
+        Some(Self { header, aligns, rows })
+    }
+
+    /// 1-based column holding the widest cell
+    pub(super) fn widest_column(&self, measure: &dyn Fn(&str) -> usize) -> usize {
+        let mut best = (0, 0);
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                let width = measure(cell);
+                if width > best.1 {
+                    best = (i, width);
+                }
+            }
+        }
+        best.0 + 1
+    }
+
+    /// Render as a Pandoc grid table whose column widths are shrunk (down to
+    /// the longest unbreakable word of each column) until rows fit `line_length`
+    pub(super) fn to_grid_table(&self, line_length: usize, measure: &dyn Fn(&str) -> usize) -> String {
+        let columns = self.header.len();
+        let all_rows: Vec<&Vec<String>> = std::iter::once(&self.header).chain(&self.rows).collect();
+
+        let mut widths = vec![1; columns];
+        let mut min_widths = vec![1; columns];
+        for row in &all_rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(measure(cell));
+                for word in cell_words(cell) {
+                    min_widths[i] = min_widths[i].max(measure(&word));
+                }
+            }
+        }
+
+        // Each column costs "| " + content + " ", plus the closing "|"
+        let available = line_length.saturating_sub(3 * columns + 1);
+        while widths.iter().sum::<usize>() > available {
+            let Some(widest) = (0..columns)
+                .filter(|&i| widths[i] > min_widths[i])
+                .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)))
+            else {
+                break;
+            };
+            widths[widest] -= 1;
+        }
+
+        let border = |fill: char, aligns: Option<&[Align]>| {
+            let mut line = String::from("+");
+            for (i, &width) in widths.iter().enumerate() {
+                let align = aligns.map_or(Align::Unset, |a| a[i]);
+                let left = if matches!(align, Align::Left | Align::Center) {
+                    ':'
+                } else {
+                    fill
+                };
+                let right = if matches!(align, Align::Right | Align::Center) {
+                    ':'
+                } else {
+                    fill
+                };
+                line.push(left);
+                line.extend(std::iter::repeat_n(fill, width));
+                line.push(right);
+                line.push('+');
+            }
+            line
+        };
+
+        let render_row = |row: &[String], out: &mut Vec<String>| {
+            let wrapped: Vec<Vec<String>> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| wrap_cell(cell, width, measure))
+                .collect();
+            let height = wrapped.iter().map(Vec::len).max().unwrap_or(1).max(1);
+            for line_idx in 0..height {
+                let mut line = String::from("|");
+                for (cell_lines, &width) in wrapped.iter().zip(&widths) {
+                    let text = cell_lines.get(line_idx).map_or("", String::as_str);
+                    line.push(' ');
+                    line.push_str(text);
+                    line.extend(std::iter::repeat_n(' ', width.saturating_sub(measure(text))));
+                    line.push_str(" |");
+                }
+                out.push(line);
+            }
+        };
+
+        let mut out = vec![border('-', None)];
+        render_row(&self.header, &mut out);
+        out.push(border('=', Some(&self.aligns)));
+        for row in &self.rows {
+            render_row(row, &mut out);
+            out.push(border('-', None));
+        }
+        if self.rows.is_empty() {
+            // A grid table needs a closing border; a header-only table gets an empty body row
+            render_row(&vec![String::new(); columns], &mut out);
+            out.push(border('-', None));
+        }
+        out.join("\n")
+    }
+
+    /// Render as an HTML table, converting each cell's inline Markdown to HTML
+    pub(super) fn to_html_table(&self) -> String {
+        let attr = |align: Align| match align {
+            Align::Unset => "",
+            Align::Left => " style=\"text-align: left\"",
+            Align::Center => " style=\"text-align: center\"",
+            Align::Right => " style=\"text-align: right\"",
+        };
+
+        let mut out = vec!["<table>".to_string(), "  <thead>".to_string(), "    <tr>".to_string()];
+        for (cell, &align) in self.header.iter().zip(&self.aligns) {
+            out.push(format!("      <th{}>{}</th>", attr(align), inline_html(cell)));
+        }
+        out.push("    </tr>".to_string());
+        out.push("  </thead>".to_string());
+        if !self.rows.is_empty() {
+            out.push("  <tbody>".to_string());
+            for row in &self.rows {
+                out.push("    <tr>".to_string());
+                for (cell, &align) in row.iter().zip(&self.aligns) {
+                    out.push(format!("      <td{}>{}</td>", attr(align), inline_html(cell)));
+                }
+                out.push("    </tr>".to_string());
+            }
+            out.push("  </tbody>".to_string());
+        }
+        out.push("</table>".to_string());
+        out.join("\n")
+    }
+}
+
+/// Split a cell into words, keeping inline code spans whole
+fn cell_words(cell: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut open_code = false;
+    for token in cell.split_whitespace() {
+        match words.last_mut() {
+            Some(last) if open_code => {
+                last.push(' ');
+                last.push_str(token);
+            }
+            _ => words.push(token.to_string()),
+        }
+        if token.matches('`').count() % 2 == 1 {
+            open_code = !open_code;
+        }
+    }
+    words
+}
+
+/// Greedily wrap a cell's words into lines of at most `width`
+fn wrap_cell(cell: &str, width: usize, measure: &dyn Fn(&str) -> usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in cell_words(cell) {
+        if !current.is_empty() && measure(&current) + 1 + measure(&word) > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Render a cell's inline Markdown as HTML. Escaped pipes lose their backslash,
+/// as they do inside a pipe table.
+fn inline_html(cell: &str) -> String {
+    let cell = cell.replace("\\|", "|");
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, Parser::new_ext(&cell, Options::ENABLE_STRIKETHROUGH));
+    match rendered.strip_prefix("<p>").and_then(|r| r.strip_suffix("</p>\n")) {
+        Some(inline) if !inline.contains("<p>") => inline.to_string(),
+        // Cell text that parses as a block (`# x`, `- x`) is plain text in a table
+        _ => cell.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;"),
+    }
+}
@@ -2695,6 +2695,10 @@ fn test_paragraphs_false_skips_regular_text() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2732,6 +2736,10 @@ fn test_paragraphs_false_still_checks_code_blocks() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2770,6 +2778,10 @@ fn test_paragraphs_false_still_checks_headings() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2806,6 +2818,10 @@ fn test_paragraphs_false_with_reflow_sentence_per_line() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2842,6 +2858,10 @@ fn test_paragraphs_true_checks_regular_text() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2878,6 +2898,10 @@ fn test_line_length_zero_disables_all_checks() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2914,6 +2938,10 @@ fn test_line_length_zero_with_headings() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2950,6 +2978,10 @@ fn test_line_length_zero_with_code_blocks() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -2986,6 +3018,10 @@ fn test_line_length_zero_with_sentence_per_line_reflow() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3050,6 +3086,10 @@ Final paragraph.
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3118,6 +3158,10 @@ fn test_reflow_preserves_mkdocstrings_autodoc_block() {
         reflow: true,
         reflow_mode: ReflowMode::SemanticLineBreaks,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3152,6 +3196,10 @@ fn test_reflow_preserves_mkdocstrings_with_identifier() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3187,6 +3235,10 @@ fn test_reflow_preserves_mkdocstrings_surrounded_by_paragraphs() {
         reflow: true,
         reflow_mode: ReflowMode::SemanticLineBreaks,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3225,6 +3277,10 @@ fn test_reflow_mkdocstrings_not_detected_in_standard_flavor() {
         reflow: true,
         reflow_mode: ReflowMode::SemanticLineBreaks,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -3256,6 +3312,10 @@ fn test_reflow_preserves_mkdocstrings_with_blank_line_in_block() {
         reflow: true,
         reflow_mode: ReflowMode::SemanticLineBreaks,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5188,6 +5248,10 @@ fn test_reflow_admonition_in_list_item_basic() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5252,6 +5316,10 @@ fn test_reflow_collapsible_admonition_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5308,6 +5376,10 @@ fn test_reflow_multiple_admonitions_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5386,6 +5458,10 @@ fn test_reflow_admonition_short_content_preserved() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5433,6 +5509,10 @@ fn test_reflow_admonition_with_multiple_paragraphs() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5502,6 +5582,10 @@ fn test_reflow_admonition_not_in_standard_flavor() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5551,6 +5635,10 @@ fn test_reflow_admonition_idempotent() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5605,6 +5693,10 @@ fn test_reflow_admonition_only_in_list_no_long_text() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5664,6 +5756,10 @@ fn test_reflow_content_after_admonition_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5728,6 +5824,10 @@ fn test_reflow_content_after_admonition_short_lines() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5776,6 +5876,10 @@ fn test_reflow_multiple_blocks_after_admonition() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5832,6 +5936,10 @@ fn test_reflow_admonition_empty_body() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5882,6 +5990,10 @@ fn test_reflow_admonition_no_blank_line_before_body() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5936,6 +6048,10 @@ fn test_reflow_admonition_body_indent_preserved() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -5996,6 +6112,10 @@ fn test_reflow_admonition_with_code_block_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6061,6 +6181,10 @@ fn test_reflow_admonition_with_tilde_fence_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6120,6 +6244,10 @@ fn test_reflow_admonition_with_multiple_code_blocks_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6187,6 +6315,10 @@ fn test_reflow_admonition_code_block_idempotent() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6239,6 +6371,10 @@ fn test_reflow_tab_container_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Default,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6271,7 +6407,7 @@ fn test_reflow_tab_container_in_list_item() {
     );
 }
 
-/// Regression test: ctx.links must be sorted by line number for binary search
+/// Regression test: ctx.links() must be sorted by line number for binary search
 /// in length_without_inline_link_urls to work correctly. The link parser appends
 /// regex-fallback reference links (from earlier lines) after pulldown-cmark links,
 /// which can produce an unsorted vector.
@@ -6293,26 +6429,26 @@ Short text [link](https://github.com/example/repo/blob/master/keps/sig-node/very
     let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
 
     // Verify links are sorted by line number
-    for i in 1..ctx.links.len() {
+    for i in 1..ctx.links().len() {
         assert!(
-            ctx.links[i].line >= ctx.links[i - 1].line,
-            "ctx.links must be sorted by line; link[{}].line={} < link[{}].line={}",
+            ctx.links()[i].line >= ctx.links()[i - 1].line,
+            "ctx.links() must be sorted by line; link[{}].line={} < link[{}].line={}",
             i,
-            ctx.links[i].line,
+            ctx.links()[i].line,
             i - 1,
-            ctx.links[i - 1].line,
+            ctx.links()[i - 1].line,
         );
     }
 
     // Verify images are sorted by line number
-    for i in 1..ctx.images.len() {
+    for i in 1..ctx.images().len() {
         assert!(
-            ctx.images[i].line >= ctx.images[i - 1].line,
-            "ctx.images must be sorted by line; image[{}].line={} < image[{}].line={}",
+            ctx.images()[i].line >= ctx.images()[i - 1].line,
+            "ctx.images() must be sorted by line; image[{}].line={} < image[{}].line={}",
             i,
-            ctx.images[i].line,
+            ctx.images()[i].line,
             i - 1,
-            ctx.images[i - 1].line,
+            ctx.images()[i - 1].line,
         );
     }
 }
@@ -6884,6 +7020,10 @@ fn test_paragraphs_false_skips_blockquote_content() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6920,6 +7060,10 @@ fn test_blockquotes_false_skips_blockquote_content() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6956,6 +7100,10 @@ fn test_blockquotes_true_paragraphs_true_checks_blockquotes() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -6991,6 +7139,10 @@ fn test_blockquotes_false_still_checks_regular_paragraphs() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7026,6 +7178,10 @@ fn test_blockquotes_false_paragraphs_false_skips_blockquotes() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7077,6 +7233,10 @@ fn test_nested_blockquote_skipped_when_blockquotes_false() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7112,6 +7272,10 @@ fn test_paragraphs_false_skips_nested_blockquote() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7148,6 +7312,10 @@ fn test_blockquotes_false_skips_reflow_warnings() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7184,6 +7352,10 @@ fn test_paragraphs_false_skips_blockquote_reflow_warnings() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7220,6 +7392,10 @@ fn test_blockquotes_true_with_reflow_still_warns() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7255,6 +7431,10 @@ fn test_blockquotes_false_skips_lazy_continuation() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7290,6 +7470,10 @@ fn test_blockquotes_false_reflow_skips_lazy_continuation() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -7326,6 +7510,10 @@ fn test_blockquotes_false_paragraph_after_blockquote_still_warns() {
         reflow: false,
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
+        ambiguous_width: AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
+        table_reflow: TableReflow::default(),
         abbreviations: Vec::new(),
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -8350,7 +8538,7 @@ fn test_issue_125_tables_false_exempts_table_after_two_crlf_code_blocks() {
     // line byte offsets as `line.len() + 1`, but `str::lines()` strips `\r`, so on
     // CRLF input the offset drifts one byte short per line. The drift eventually
     // pushed the second table's header byte position inside the preceding code
-    // block's byte range, so the table was dropped from `ctx.table_blocks` and its
+    // block's byte range, so the table was dropped from `ctx.table_blocks()` and its
     // long rows were flagged despite `tables = false`. The first table (before any
     // code block) was unaffected, matching the reporter's "only the second table
     // fails".
@@ -8366,10 +8554,10 @@ fn test_issue_125_tables_false_exempts_table_after_two_crlf_code_blocks() {
 
     // Both wide tables must be detected; the byte-offset bug dropped the second.
     assert_eq!(
-        ctx.table_blocks.len(),
+        ctx.table_blocks().len(),
         2,
         "both wide tables should be detected in CRLF content; found {} table block(s)",
-        ctx.table_blocks.len()
+        ctx.table_blocks().len()
     );
 
     // line_length=120, code_blocks=false, tables=false, headings=true, strict=false
@@ -8651,3 +8839,183 @@ fn test_md013_reflow_consecutive_long_lines() {
     assert!(result[0].fix.is_some(), "First warning should have a fix");
     assert!(result[1].fix.is_some(), "Second warning should have a fix");
 }
+
+#[test]
+fn test_md013_no_break_patterns_protect_template_spans_during_fix() {
+    let toml_str = r#"
+        line-length = 40
+        reflow = true
+        no-break-patterns = ['\{\{.*?\}\}', '[invalid']
+    "#;
+    let config: MD013Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.no_break_patterns.len(), 2);
+    // The invalid pattern is dropped, the valid one still applies
+    let rule = MD013LineLength::from_config_struct(config);
+
+    let content = "Hello there {{ page.params.author or \"anonymous\" }} and welcome to the site.\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert!(
+        fixed.contains("{{ page.params.author or \"anonymous\" }}"),
+        "template expression must not be split, got:\n{fixed}"
+    );
+    assert!(
+        fixed.lines().count() > 1,
+        "paragraph should still be reflowed, got:\n{fixed}"
+    );
+}
+
+#[test]
+fn test_md013_semantic_break_rules_from_toml() {
+    let toml_str = r#"
+        line-length = 60
+        reflow = true
+        reflow-mode = "semantic-line-breaks"
+        [semantic-break-rules]
+        comma-min-length = 45
+        break-words = ["Thereby"]
+    "#;
+    let config: MD013Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.semantic_break_rules.comma_min_length, 45);
+    assert_eq!(
+        config.semantic_break_rules.break_words,
+        Some(vec!["Thereby".to_string()])
+    );
+    assert!(config.semantic_break_rules.keep_citations);
+    let rule = MD013LineLength::from_config_struct(config);
+
+    let content =
+        "The mutation destabilised the hydrophobic core thereby accelerating unfolding in every condition tested.\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert_eq!(
+        fixed,
+        "The mutation destabilised the hydrophobic core\nthereby accelerating unfolding in every condition tested.\n"
+    );
+}
+
+fn table_reflow_rule(mode: &str, line_length: usize) -> MD013LineLength {
+    let toml_str = format!("line-length = {line_length}\ntable-reflow = \"{mode}\"\n");
+    let config: MD013Config = toml::from_str(&toml_str).unwrap();
+    MD013LineLength::from_config_struct(config)
+}
+
+const LONG_TABLE: &str = "\
+Intro.
+
+| Option | Description |
+|:-------|------------:|
+| `fast` | Skips the expensive validation pass and trusts the cache contents |
+| `safe` | Runs every check |
+
+Outro.
+";
+
+#[test]
+fn test_table_reflow_off_by_default() {
+    let rule = MD013LineLength::from_config_struct(MD013Config {
+        line_length: crate::types::LineLength::from_const(40),
+        ..Default::default()
+    });
+    let ctx = LintContext::new(LONG_TABLE, MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
+
+#[test]
+fn test_table_reflow_suggest_is_not_applied() {
+    let rule = table_reflow_rule("suggest", 40);
+    let ctx = LintContext::new(LONG_TABLE, MarkdownFlavor::Standard, None);
+    let warnings = rule.check(&ctx).unwrap();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    let warning = &warnings[0];
+    assert_eq!((warning.line, warning.end_line), (3, 6));
+    assert_eq!(
+        warning.message,
+        "Table row length 78 exceeds 40 characters (widest cell in column 2)"
+    );
+    let fix = warning.fix.as_ref().unwrap();
+    assert!(!fix.is_automatic());
+    assert!(fix.replacement.starts_with("<table>"));
+    assert_eq!(rule.fix(&ctx).unwrap(), LONG_TABLE);
+}
+
+#[test]
+fn test_table_reflow_auto_converts_to_html_table() {
+    let rule = table_reflow_rule("auto", 40);
+    let ctx = LintContext::new(LONG_TABLE, MarkdownFlavor::Standard, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert_eq!(
+        fixed,
+        "\
+Intro.
+
+<table>
+  <thead>
+    <tr>
+      <th style=\"text-align: left\">Option</th>
+      <th style=\"text-align: right\">Description</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td style=\"text-align: left\"><code>fast</code></td>
+      <td style=\"text-align: right\">Skips the expensive validation pass and trusts the cache contents</td>
+    </tr>
+    <tr>
+      <td style=\"text-align: left\"><code>safe</code></td>
+      <td style=\"text-align: right\">Runs every check</td>
+    </tr>
+  </tbody>
+</table>
+
+Outro.
+"
+    );
+    let ctx = LintContext::new(&fixed, MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
+
+#[test]
+fn test_table_reflow_auto_converts_to_grid_table_for_pandoc() {
+    let rule = table_reflow_rule("auto", 40);
+    let ctx = LintContext::new(LONG_TABLE, MarkdownFlavor::Pandoc, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert_eq!(
+        fixed,
+        "\
+Intro.
+
++--------+-----------------------------+
+| Option | Description                 |
++:=======+============================:+
+| `fast` | Skips the expensive         |
+|        | validation pass and trusts  |
+|        | the cache contents          |
++--------+-----------------------------+
+| `safe` | Runs every check            |
++--------+-----------------------------+
+
+Outro.
+"
+    );
+    assert!(fixed.lines().all(|l| l.len() <= 40));
+}
+
+#[test]
+fn test_table_reflow_keeps_code_spans_whole_in_grid_cells() {
+    let rule = table_reflow_rule("auto", 30);
+    let content = "| Key | Value |\n|-----|-------|\n| a | use `cargo test --workspace` to run it |\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Pandoc, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert!(
+        fixed.lines().any(|l| l.contains("`cargo test --workspace`")),
+        "code span must not be split, got:\n{fixed}"
+    );
+}
+
+#[test]
+fn test_table_reflow_ignores_short_tables() {
+    let rule = table_reflow_rule("auto", 80);
+    let ctx = LintContext::new(LONG_TABLE, MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
@@ -6,7 +6,7 @@ mod md018_config;
 pub(super) use md018_config::MD018Config;
 
 use crate::config::MarkdownFlavor;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::{byte_to_char_count, calculate_single_line_range};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -187,6 +187,10 @@ impl Rule for MD018NoMissingSpaceAtx {
         "No space after hash in heading"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
@@ -231,13 +235,13 @@ impl Rule for MD018NoMissingSpaceAtx {
                         continue;
                     }
 
-                    if trimmed.len() > heading.marker.len() {
-                        let after_marker = &trimmed[heading.marker.len()..];
+                    if trimmed.len() > heading.marker_len() {
+                        let after_marker = &trimmed[heading.marker_len()..];
                         if !after_marker.is_empty() && !after_marker.starts_with(' ') && !after_marker.starts_with('\t')
                         {
                             // Missing space after ATX marker. The indent and '#' markers
                             // are ASCII, so convert the byte offset to a character column.
-                            let hash_end_col = byte_to_char_count(line, line_info.indent + heading.marker.len());
+                            let hash_end_col = byte_to_char_count(line, line_info.indent + heading.marker_len());
                             let (start_line, start_col, end_line, end_col) = calculate_single_line_range(
                                 line_num + 1, // Convert to 1-indexed
                                 hash_end_col,
@@ -256,7 +260,7 @@ impl Rule for MD018NoMissingSpaceAtx {
                                     // Preserve original indentation (including tabs)
                                     let line = line_info.content(ctx.content);
                                     let original_indent = &line[..line_info.indent];
-                                    format!("{original_indent}{} {after_marker}", heading.marker)
+                                    format!("{original_indent}{} {after_marker}", heading.marker(ctx.content))
                                 })),
                             });
                         }
@@ -331,14 +335,17 @@ impl Rule for MD018NoMissingSpaceAtx {
                     let is_tag = self.tags_enabled(ctx.flavor) && heading.level == 1 && Self::is_tag(line);
 
                     // Only attempt fix if not a special pattern
-                    if !is_emoji && !is_unicode && !is_magiclink && !is_tag && trimmed.len() > heading.marker.len() {
-                        let after_marker = &trimmed[heading.marker.len()..];
+                    if !is_emoji && !is_unicode && !is_magiclink && !is_tag && trimmed.len() > heading.marker_len() {
+                        let after_marker = &trimmed[heading.marker_len()..];
                         if !after_marker.is_empty() && !after_marker.starts_with(' ') && !after_marker.starts_with('\t')
                         {
                             // Add space after marker, preserving original indentation (including tabs)
                             let line = line_info.content(ctx.content);
                             let original_indent = &line[..line_info.indent];
-                            lines.push(format!("{original_indent}{} {after_marker}", heading.marker));
+                            lines.push(format!(
+                                "{original_indent}{} {after_marker}",
+                                heading.marker(ctx.content)
+                            ));
                             fixed = true;
                         }
                     }
@@ -375,6 +382,10 @@ impl Rule for MD018NoMissingSpaceAtx {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings
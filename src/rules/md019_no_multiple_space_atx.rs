@@ -1,7 +1,7 @@
 /// Rule MD019: No multiple spaces after ATX heading marker
 ///
 /// See [docs/md019.md](../../docs/md019.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_single_line_range;
 
 #[derive(Clone)]
@@ -34,6 +34,10 @@ impl Rule for MD019NoMultipleSpaceAtx {
         "Multiple spaces after hash in heading"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
@@ -44,11 +48,11 @@ impl Rule for MD019NoMultipleSpaceAtx {
                 if matches!(heading.style, crate::lint_context::HeadingStyle::ATX) {
                     let line = line_info.content(ctx.content);
                     let trimmed = line.trim_start();
-                    let marker_pos = line_info.indent + heading.marker.len();
+                    let marker_pos = line_info.indent + heading.marker_len();
 
                     // Count spaces after marker
-                    if trimmed.len() > heading.marker.len() {
-                        let space_count = self.count_spaces_after_marker(trimmed, heading.marker.len());
+                    if trimmed.len() > heading.marker_len() {
+                        let space_count = self.count_spaces_after_marker(trimmed, heading.marker_len());
 
                         if space_count > 1 {
                             // Calculate range for the extra spaces
@@ -63,10 +67,10 @@ impl Rule for MD019NoMultipleSpaceAtx {
 
                             // We need to work with the original line, not trimmed
                             let original_line = line_info.content(ctx.content);
-                            let marker_byte_pos = line_start_byte + line_info.indent + heading.marker.len();
+                            let marker_byte_pos = line_start_byte + line_info.indent + heading.marker_len();
 
                             // Get the actual byte length of the spaces/tabs after the marker
-                            let after_marker_start = line_info.indent + heading.marker.len();
+                            let after_marker_start = line_info.indent + heading.marker_len();
                             let after_marker = &original_line[after_marker_start..];
                             let space_bytes = after_marker
                                 .as_bytes()
@@ -119,6 +123,10 @@ impl Rule for MD019NoMultipleSpaceAtx {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_headings()
@@ -1,7 +1,7 @@
 /// Rule MD021: No multiple spaces inside closed ATX heading
 ///
 /// See [docs/md021.md](../../docs/md021.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_line_range;
 use regex::Regex;
 use std::sync::LazyLock;
@@ -73,6 +73,10 @@ impl Rule for MD021NoMultipleSpaceClosedAtx {
         "Multiple spaces inside hashes on closed heading"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
@@ -160,6 +164,10 @@ impl Rule for MD021NoMultipleSpaceClosedAtx {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_headings()
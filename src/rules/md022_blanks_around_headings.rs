@@ -1,7 +1,7 @@
 /// Rule MD022: Headings should be surrounded by blank lines
 ///
 /// See [docs/md022.md](../../docs/md022.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::kramdown_utils::is_kramdown_block_attribute;
 use crate::utils::pandoc;
 use crate::utils::range_utils::calculate_heading_range;
@@ -673,6 +673,10 @@ impl Rule for MD022BlanksAroundHeadings {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings
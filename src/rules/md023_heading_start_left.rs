@@ -1,7 +1,7 @@
 /// Rule MD023: Headings must start at the left margin
 ///
 /// See [docs/md023.md](../../docs/md023.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_single_line_range;
 
 #[derive(Clone)]
@@ -42,7 +42,7 @@ impl Rule for MD023HeadingStartLeft {
                 if heading.level == 1 && matches!(heading.style, crate::lint_context::HeadingStyle::ATX) {
                     // Get first "word" of heading text (up to space, comma, or closing paren)
                     let first_word: String = heading
-                        .text
+                        .text(ctx.content)
                         .trim()
                         .chars()
                         .take_while(|c| !c.is_whitespace() && *c != ',' && *c != ')')
@@ -173,6 +173,10 @@ impl Rule for MD023HeadingStartLeft {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast path: check if document likely has headings
@@ -1,6 +1,6 @@
 use toml;
 
-use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_match_range;
 use std::collections::{HashMap, HashSet};
 
@@ -54,6 +54,8 @@ impl Rule for MD024NoDuplicateHeading {
         let mut warnings = Vec::new();
         let mut seen_headings: HashSet<HeadingKey> = HashSet::new();
         let mut seen_headings_per_level: HashMap<u8, HashSet<HeadingKey>> = HashMap::new();
+        // Occurrences of each heading text so far, used to number rename suggestions
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
 
         // For siblings_only mode, track heading hierarchy
         let mut current_section_path: Vec<(u8, HeadingKey)> = Vec::new();
@@ -88,36 +90,60 @@ impl Rule for MD024NoDuplicateHeading {
                 }
 
                 // Skip empty headings
-                if heading.text.is_empty() {
+                if heading.text(ctx.content).is_empty() {
                     continue;
                 }
 
                 let heading_key: HeadingKey = if self.config.allow_different_link_anchors {
-                    (heading.text.clone(), heading.custom_id.clone())
+                    (
+                        heading.text(ctx.content).to_string(),
+                        heading.custom_id(ctx.content).map(str::to_string),
+                    )
                 } else {
-                    (heading.text.clone(), None)
+                    (heading.text(ctx.content).to_string(), None)
                 };
                 let level = heading.level;
 
                 // Calculate precise character range for the heading text content
-                let text_start_in_line = if let Some(pos) = line_info.content(ctx.content).find(&heading.text) {
-                    pos
-                } else {
-                    // Fallback: find after hash markers
-                    let trimmed = line_info.content(ctx.content).trim_start();
-                    let hash_count = trimmed.chars().take_while(|&c| c == '#').count();
-                    let after_hashes = &trimmed[hash_count..];
-                    let text_start_in_trimmed = after_hashes.find(&heading.text).unwrap_or(0);
-                    (line_info.byte_len - trimmed.len()) + hash_count + text_start_in_trimmed
-                };
+                let text_start_in_line =
+                    if let Some(pos) = line_info.content(ctx.content).find(heading.text(ctx.content)) {
+                        pos
+                    } else {
+                        // Fallback: find after hash markers
+                        let trimmed = line_info.content(ctx.content).trim_start();
+                        let hash_count = trimmed.chars().take_while(|&c| c == '#').count();
+                        let after_hashes = &trimmed[hash_count..];
+                        let text_start_in_trimmed = after_hashes.find(heading.text(ctx.content)).unwrap_or(0);
+                        (line_info.byte_len - trimmed.len()) + hash_count + text_start_in_trimmed
+                    };
 
                 let (start_line, start_col, end_line, end_col) = calculate_match_range(
                     line_num + 1,
                     line_info.content(ctx.content),
                     text_start_in_line,
-                    heading.text.len(),
+                    heading.text(ctx.content).len(),
                 );
 
+                // Suggested rename for a duplicate: append the occurrence number
+                // to the heading text, e.g. `Usage` -> `Usage (2)`
+                let occurrence = {
+                    let count = occurrences.entry(heading.text(ctx.content).to_string()).or_insert(0);
+                    *count += 1;
+                    *count
+                };
+                let rename_suggestion = || {
+                    line_info
+                        .content(ctx.content)
+                        .find(heading.text(ctx.content))
+                        .map(|pos| {
+                            let start = line_info.byte_offset + pos;
+                            Fix::suggestion(
+                                start..start + heading.text(ctx.content).len(),
+                                format!("{} ({occurrence})", heading.text(ctx.content)),
+                            )
+                        })
+                };
+
                 if self.config.siblings_only {
                     // Update the section path based on the current heading level
                     while !current_section_path.is_empty() && current_section_path.last().unwrap().0 >= level {
@@ -131,13 +157,13 @@ impl Rule for MD024NoDuplicateHeading {
                     if siblings.contains(&heading_key) {
                         warnings.push(LintWarning {
                             rule_name: Some(self.name().to_string()),
-                            message: format!("Duplicate heading: '{}'.", heading.text),
+                            message: format!("Duplicate heading: '{}'.", heading.text(ctx.content)),
                             line: start_line,
                             column: start_col,
                             end_line,
                             end_column: end_col,
                             severity: Severity::Error,
-                            fix: None,
+                            fix: rename_suggestion(),
                         });
                     } else {
                         siblings.insert(heading_key.clone());
@@ -151,13 +177,13 @@ impl Rule for MD024NoDuplicateHeading {
                     if seen.contains(&heading_key) {
                         warnings.push(LintWarning {
                             rule_name: Some(self.name().to_string()),
-                            message: format!("Duplicate heading: '{}'.", heading.text),
+                            message: format!("Duplicate heading: '{}'.", heading.text(ctx.content)),
                             line: start_line,
                             column: start_col,
                             end_line,
                             end_column: end_col,
                             severity: Severity::Error,
-                            fix: None,
+                            fix: rename_suggestion(),
                         });
                     } else {
                         seen.insert(heading_key.clone());
@@ -167,13 +193,13 @@ impl Rule for MD024NoDuplicateHeading {
                     if seen_headings.contains(&heading_key) {
                         warnings.push(LintWarning {
                             rule_name: Some(self.name().to_string()),
-                            message: format!("Duplicate heading: '{}'.", heading.text),
+                            message: format!("Duplicate heading: '{}'.", heading.text(ctx.content)),
                             line: start_line,
                             column: start_col,
                             end_line,
                             end_column: end_col,
                             severity: Severity::Error,
-                            fix: None,
+                            fix: rename_suggestion(),
                         });
                     } else {
                         seen_headings.insert(heading_key.clone());
@@ -1126,4 +1152,26 @@ All same text, different levels."#;
         assert_eq!(warnings.len(), 1);
         assert_eq!(warnings[0].message, "Duplicate heading: 'Foo#bar'.");
     }
+
+    #[test]
+    fn test_duplicate_heading_suggests_numbered_rename() {
+        let content = "# Usage\n\n## Usage\n\n### Usage\n";
+        let config = MD024Config {
+            allow_different_nesting: false,
+            siblings_only: false,
+            allow_different_link_anchors: true,
+        };
+        let warnings = run_test(content, config).unwrap();
+        assert_eq!(warnings.len(), 2);
+
+        let fix = warnings[0]
+            .fix
+            .as_ref()
+            .expect("duplicate should carry a suggested rename");
+        assert!(!fix.is_automatic());
+        assert!(warnings[0].automatic_fix().is_none());
+        assert_eq!(&content[fix.range.clone()], "Usage");
+        assert_eq!(fix.replacement, "Usage (2)");
+        assert_eq!(warnings[1].fix.as_ref().unwrap().replacement, "Usage (3)");
+    }
 }
@@ -1,7 +1,7 @@
 /// Rule MD025: Document must have a single top-level heading
 ///
 /// See [docs/md025.md](../../docs/md025.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::types::HeadingLevel;
 use crate::utils::range_utils::calculate_match_range;
 use crate::utils::thematic_break;
@@ -244,7 +244,7 @@ impl Rule for MD025SingleTitle {
         if !headings_to_flag.is_empty() {
             for &line_num in headings_to_flag {
                 if let Some(heading) = &ctx.lines[line_num].heading {
-                    let heading_text = &heading.text;
+                    let heading_text = heading.text(ctx.content);
 
                     // Check if this heading should be allowed
                     let should_allow = self.is_document_section_heading(heading_text)
@@ -302,7 +302,7 @@ impl Rule for MD025SingleTitle {
                     } else {
                         let leading_spaces = line_content.len() - line_content.trim_start().len();
                         let indentation = " ".repeat(leading_spaces);
-                        let raw = &heading.raw_text;
+                        let raw = heading.raw_text(ctx.content);
                         let hashes = "#".repeat(demoted_level);
                         let closing = if heading.has_closing_sequence {
                             format!(" {}", "#".repeat(demoted_level))
@@ -405,7 +405,7 @@ impl Rule for MD025SingleTitle {
                 let leading_spaces = line_content.len() - line_content.trim_start().len();
                 let indentation = " ".repeat(leading_spaces);
                 let hashes = "#".repeat(new_level);
-                let raw = &heading.raw_text;
+                let raw = heading.raw_text(ctx.content);
                 let closing = if heading.has_closing_sequence {
                     format!(" {}", "#".repeat(new_level))
                 } else {
@@ -445,6 +445,10 @@ impl Rule for MD025SingleTitle {
         RuleCategory::Heading
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped for performance
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if content is empty
@@ -35,6 +35,7 @@ impl MD026NoTrailingPunctuation {
         Self {
             config: MD026Config {
                 punctuation: punctuation.unwrap_or_else(|| DEFAULT_PUNCTUATION.to_string()),
+                ..Default::default()
             },
         }
     }
@@ -43,26 +44,35 @@ impl MD026NoTrailingPunctuation {
         Self { config }
     }
 
+    /// Get the trailing-punctuation regex for a heading level.
+    ///
+    /// Returns `None` when no punctuation is forbidden at that level
+    /// (e.g. every configured character is in `allowed-punctuation`).
     #[inline]
-    fn get_punctuation_regex(&self) -> Result<Regex, regex::Error> {
+    fn get_punctuation_regex(&self, level: u8) -> Result<Option<Regex>, regex::Error> {
+        let punctuation = self.config.forbidden_punctuation(level);
+        if punctuation.is_empty() {
+            return Ok(None);
+        }
+
         // Check cache first
         {
             let cache = PUNCTUATION_REGEX_CACHE.read().unwrap();
-            if let Some(cached_regex) = cache.get(&self.config.punctuation) {
-                return Ok(cached_regex.clone());
+            if let Some(cached_regex) = cache.get(&punctuation) {
+                return Ok(Some(cached_regex.clone()));
             }
         }
 
         // Compile and cache the regex
-        let pattern = format!(r"([{}]+)$", regex::escape(&self.config.punctuation));
+        let pattern = format!(r"([{}]+)$", regex::escape(&punctuation));
         let regex = Regex::new(&pattern)?;
 
         {
             let mut cache = PUNCTUATION_REGEX_CACHE.write().unwrap();
-            cache.insert(self.config.punctuation.clone(), regex.clone());
+            cache.insert(punctuation, regex.clone());
         }
 
-        Ok(regex)
+        Ok(Some(regex))
     }
 
     #[inline]
@@ -179,6 +189,10 @@ impl Rule for MD026NoTrailingPunctuation {
         "Trailing punctuation in heading"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn category(&self) -> RuleCategory {
         RuleCategory::Heading
     }
@@ -188,8 +202,8 @@ impl Rule for MD026NoTrailingPunctuation {
         if !ctx.likely_has_headings() {
             return true;
         }
-        // Skip if none of the configured punctuation exists
-        let punctuation = &self.config.punctuation;
+        // Skip if none of the forbidden punctuation (across all levels) exists
+        let punctuation = self.config.all_forbidden_punctuation();
         !punctuation.chars().any(|p| ctx.content.contains(p))
     }
 
@@ -203,13 +217,14 @@ impl Rule for MD026NoTrailingPunctuation {
 
         // Quick check for any punctuation we care about
         // For custom punctuation, we need to check differently
-        if self.config.punctuation == DEFAULT_PUNCTUATION {
+        let forbidden = self.config.all_forbidden_punctuation();
+        if forbidden == DEFAULT_PUNCTUATION {
             if !QUICK_PUNCTUATION_CHECK.is_match(content) {
                 return Ok(Vec::new());
             }
         } else {
             // For custom punctuation, check if any of those characters exist
-            let has_custom_punctuation = self.config.punctuation.chars().any(|c| content.contains(c));
+            let has_custom_punctuation = forbidden.chars().any(|c| content.contains(c));
             if !has_custom_punctuation {
                 return Ok(Vec::new());
             }
@@ -222,9 +237,14 @@ impl Rule for MD026NoTrailingPunctuation {
         }
 
         let mut warnings = Vec::new();
-        let Ok(re) = self.get_punctuation_regex() else {
-            return Ok(warnings);
-        };
+
+        // Regex per heading level; levels can forbid different characters
+        let mut level_regexes: [Option<Regex>; 6] = Default::default();
+        for (level, slot) in (1u8..=6).zip(level_regexes.iter_mut()) {
+            if let Ok(re) = self.get_punctuation_regex(level) {
+                *slot = re;
+            }
+        }
 
         // Create LineIndex for correct byte position calculations across all line ending types
         let line_index = &ctx.line_index;
@@ -242,19 +262,28 @@ impl Rule for MD026NoTrailingPunctuation {
                     continue;
                 }
 
+                let Some(re) = heading
+                    .level
+                    .checked_sub(1)
+                    .and_then(|idx| level_regexes.get(usize::from(idx)))
+                    .and_then(Option::as_ref)
+                else {
+                    continue;
+                };
+
                 // LintContext already strips Kramdown IDs from heading.text
                 // So we just check the heading text directly for trailing punctuation
                 // This correctly flags "# Heading." even if it has {#id}
-                let text_to_check = heading.text.clone();
+                let text_to_check = heading.text(ctx.content).to_string();
 
-                if self.has_trailing_punctuation(&text_to_check, &re) {
+                if self.has_trailing_punctuation(&text_to_check, re) {
                     // Find the trailing punctuation
                     if let Some(punctuation_match) = re.find(&text_to_check) {
                         let line = line_info.content(ctx.content);
 
                         // For ATX headings, find the punctuation position in the line
                         let punctuation_pos_in_text = punctuation_match.start();
-                        let text_pos_in_line = line.find(&heading.text).unwrap_or(heading.content_column);
+                        let text_pos_in_line = line.find(heading.text(ctx.content)).unwrap_or(heading.content_column);
                         let punctuation_start_in_line = text_pos_in_line + punctuation_pos_in_text;
                         let punctuation_len = punctuation_match.len();
 
@@ -277,9 +306,9 @@ impl Rule for MD026NoTrailingPunctuation {
                             fix: Some(Fix::new(
                                 self.get_line_byte_range(content, line_num + 1, line_index),
                                 if matches!(heading.style, crate::lint_context::HeadingStyle::ATX) {
-                                    self.fix_atx_heading(line, &re)
+                                    self.fix_atx_heading(line, re)
                                 } else {
-                                    self.fix_setext_heading(line, &re)
+                                    self.fix_setext_heading(line, re)
                                 },
                             )),
                         });
@@ -479,7 +508,7 @@ mod tests {
     #[test]
     fn test_get_punctuation_regex() {
         let rule = MD026NoTrailingPunctuation::new(Some("!?".to_string()));
-        let regex = rule.get_punctuation_regex().unwrap();
+        let regex = rule.get_punctuation_regex(1).unwrap().unwrap();
         assert!(regex.is_match("text!"));
         assert!(regex.is_match("text?"));
         assert!(!regex.is_match("text."));
@@ -491,8 +520,8 @@ mod tests {
         let rule2 = MD026NoTrailingPunctuation::new(Some("!".to_string()));
 
         // Both should get the same cached regex
-        let _regex1 = rule1.get_punctuation_regex().unwrap();
-        let _regex2 = rule2.get_punctuation_regex().unwrap();
+        let _regex1 = rule1.get_punctuation_regex(1).unwrap();
+        let _regex2 = rule2.get_punctuation_regex(1).unwrap();
 
         // Check cache has the entry
         let cache = PUNCTUATION_REGEX_CACHE.read().unwrap();
@@ -538,4 +567,58 @@ mod tests {
         let fixed2 = rule.fix(&ctx2).unwrap();
         assert_eq!(fixed2, "# Title");
     }
+
+    #[test]
+    fn test_allowed_punctuation_is_never_flagged() {
+        let config = MD026Config {
+            punctuation: ".,;:!?".to_string(),
+            allowed_punctuation: "?".to_string(),
+            ..Default::default()
+        };
+        let rule = MD026NoTrailingPunctuation::from_config_struct(config);
+        let content = "# What is this?\n## Overview:\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 2);
+    }
+
+    #[test]
+    fn test_level_punctuation_overrides() {
+        let mut config = MD026Config::default();
+        // H3+ headings introduce lists in this style guide, so colons are fine there
+        config.level_punctuation.insert("h3".to_string(), ".".to_string());
+        let rule = MD026NoTrailingPunctuation::from_config_struct(config);
+        let content = "# Setup:\n\n### Options:\n\n### Notes.\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![1, 5]);
+    }
+
+    #[test]
+    fn test_fix_strips_only_disallowed_characters() {
+        let config = MD026Config {
+            punctuation: ".,;:!".to_string(),
+            allowed_punctuation: "?".to_string(),
+            ..Default::default()
+        };
+        let rule = MD026NoTrailingPunctuation::from_config_struct(config);
+        let content = "# Really?!\n\nSetext?:\n=======\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "# Really?\n\nSetext?\n=======\n");
+    }
+
+    #[test]
+    fn test_level_with_everything_allowed_is_skipped() {
+        let mut config = MD026Config::default();
+        config.level_punctuation.insert("2".to_string(), String::new());
+        let rule = MD026NoTrailingPunctuation::from_config_struct(config);
+        let content = "## Summary.\n\n# Title.\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::rule_config_serde::RuleConfig;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Default punctuation to check for MD026
 /// Matches markdownlint's default: ".,;:!。，；：！"
@@ -7,15 +8,28 @@ use serde::{Deserialize, Serialize};
 pub(super) const DEFAULT_PUNCTUATION: &str = ".,;:!";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
 pub struct MD026Config {
     #[serde(default = "default_punctuation")]
     pub punctuation: String,
+
+    /// Characters that are never flagged, even when listed in `punctuation`
+    /// or in a per-level override (e.g. `"?"` for FAQ-style headings)
+    #[serde(default, alias = "allowed_punctuation")]
+    pub allowed_punctuation: String,
+
+    /// Per-level replacements for `punctuation`
+    /// Keys: heading level as `h1`..`h6` (or `1`..`6`), Values: punctuation string
+    #[serde(default, alias = "level_punctuation")]
+    pub level_punctuation: BTreeMap<String, String>,
 }
 
 impl Default for MD026Config {
     fn default() -> Self {
         Self {
             punctuation: default_punctuation(),
+            allowed_punctuation: String::new(),
+            level_punctuation: BTreeMap::new(),
         }
     }
 }
@@ -24,6 +38,99 @@ fn default_punctuation() -> String {
     DEFAULT_PUNCTUATION.to_string()
 }
 
+/// Parse a `level-punctuation` key (`h2`, `H2` or `2`) into a heading level
+fn parse_level_key(key: &str) -> Option<u8> {
+    let trimmed = key.trim();
+    let digits = trimmed
+        .strip_prefix('h')
+        .or_else(|| trimmed.strip_prefix('H'))
+        .unwrap_or(trimmed);
+    match digits.parse::<u8>() {
+        Ok(level @ 1..=6) => Some(level),
+        _ => None,
+    }
+}
+
+impl MD026Config {
+    /// Punctuation that is forbidden at the end of a heading of the given level.
+    ///
+    /// The level override (if any) replaces `punctuation`, then every character
+    /// in `allowed_punctuation` is removed from the result.
+    pub fn forbidden_punctuation(&self, level: u8) -> String {
+        let base = self
+            .level_punctuation
+            .iter()
+            .find(|(key, _)| parse_level_key(key) == Some(level))
+            .map_or(self.punctuation.as_str(), |(_, value)| value.as_str());
+        base.chars()
+            .filter(|c| !self.allowed_punctuation.contains(*c))
+            .collect()
+    }
+
+    /// Union of forbidden characters across all heading levels.
+    /// Used for quick content-based skipping.
+    pub fn all_forbidden_punctuation(&self) -> String {
+        let mut all = String::new();
+        for level in 1..=6 {
+            for c in self.forbidden_punctuation(level).chars() {
+                if !all.contains(c) {
+                    all.push(c);
+                }
+            }
+        }
+        all
+    }
+}
+
 impl RuleConfig for MD026Config {
     const RULE_NAME: &'static str = "MD026";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_forbidden_matches_punctuation() {
+        let config = MD026Config::default();
+        for level in 1..=6 {
+            assert_eq!(config.forbidden_punctuation(level), DEFAULT_PUNCTUATION);
+        }
+    }
+
+    #[test]
+    fn test_level_override_and_allowed() {
+        let toml_str = r#"
+            punctuation = ".,;:!"
+            allowed-punctuation = "!"
+            [level-punctuation]
+            h1 = ".:?!"
+            4 = "."
+        "#;
+        let config: MD026Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.forbidden_punctuation(1), ".:?");
+        assert_eq!(config.forbidden_punctuation(2), ".,;:");
+        assert_eq!(config.forbidden_punctuation(4), ".");
+        assert_eq!(config.all_forbidden_punctuation(), ".:?,;");
+    }
+
+    #[test]
+    fn test_snake_case_backwards_compatibility() {
+        let toml_str = r#"
+            allowed_punctuation = ":"
+            [level_punctuation]
+            h2 = ":"
+        "#;
+        let config: MD026Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.allowed_punctuation, ":");
+        assert_eq!(config.forbidden_punctuation(2), "");
+    }
+
+    #[test]
+    fn test_invalid_level_keys_ignored() {
+        let mut config = MD026Config::default();
+        config.level_punctuation.insert("h7".to_string(), "?".to_string());
+        config.level_punctuation.insert("title".to_string(), "?".to_string());
+        assert_eq!(config.all_forbidden_punctuation(), DEFAULT_PUNCTUATION);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::utils::range_utils::calculate_match_range;
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::{RuleConfig, load_rule_config};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -87,6 +87,10 @@ impl Rule for MD027MultipleSpacesBlockquote {
         RuleCategory::Blockquote
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
@@ -142,7 +146,7 @@ impl Rule for MD027MultipleSpacesBlockquote {
                     if extra_spaces_bytes > 0 {
                         // When blockquote content is empty, remove all spaces
                         // after the marker to avoid creating trailing whitespace
-                        let (fix_byte_pos, fix_bytes) = if blockquote.content.is_empty() {
+                        let (fix_byte_pos, fix_bytes) = if blockquote.content(ctx.content).is_empty() {
                             // Remove the first space too (byte_pos - 1 points to
                             // the first space we skipped)
                             let first_space_pos = byte_pos - 1;
@@ -15,7 +15,7 @@
 /// See [docs/md028.md](../../docs/md028.md) for full documentation, configuration, and examples.
 use crate::config::MarkdownFlavor;
 use crate::lint_context::LineInfo;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::{RuleConfig, load_rule_config};
 use crate::utils::range_utils::calculate_line_range;
 use serde::{Deserialize, Serialize};
@@ -521,6 +521,10 @@ impl Rule for MD028NoBlanksBlockquote {
         RuleCategory::Blockquote
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         !ctx.likely_has_blockquotes()
@@ -1,7 +1,7 @@
 /// Rule MD029: Ordered list item prefix
 ///
 /// See [docs/md029.md](../../docs/md029.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::byte_to_char_count;
 use crate::utils::regex_cache::ORDERED_LIST_MARKER_REGEX;
 use std::collections::HashMap;
@@ -74,6 +74,7 @@ impl MD029OrderedListPrefix {
     /// Detect the style being used in a list by checking all items for prevalence.
     /// The `start_value` parameter is the CommonMark-provided list start value.
     fn detect_list_style(
+        content: &str,
         items: &[(
             usize,
             &crate::lint_context::LineInfo,
@@ -85,15 +86,15 @@ impl MD029OrderedListPrefix {
             // With only one item, check if it matches the start value
             // If so, treat as Ordered (respects CommonMark start value)
             // Otherwise, check if it's 1 (OneOne style)
-            let first_num = Self::parse_marker_number(&items[0].2.marker);
+            let first_num = Self::parse_marker_number(items[0].2.marker(content));
             if first_num == Some(start_value as usize) {
                 return ListStyle::Ordered;
             }
             return ListStyle::OneOne;
         }
 
-        let first_num = Self::parse_marker_number(&items[0].2.marker);
-        let second_num = Self::parse_marker_number(&items[1].2.marker);
+        let first_num = Self::parse_marker_number(items[0].2.marker(content));
+        let second_num = Self::parse_marker_number(items[1].2.marker(content));
 
         // Fast path: Check for Ordered0 special case (starts with 0, 1)
         if matches!((first_num, second_num), (Some(0), Some(1))) {
@@ -110,7 +111,7 @@ impl MD029OrderedListPrefix {
         // This is necessary for lists like "1. 1. 1..." vs "1. 1. 2. 3..."
         let all_ones = items
             .iter()
-            .all(|(_, _, item)| Self::parse_marker_number(&item.marker) == Some(1));
+            .all(|(_, _, item)| Self::parse_marker_number(item.marker(content)) == Some(1));
 
         if all_ones {
             ListStyle::OneOne
@@ -226,24 +227,24 @@ impl MD029OrderedListPrefix {
             let detected_style = if let Some(doc_style) = document_wide_style {
                 Some(doc_style)
             } else if self.config.style == ListStyle::OneOrOrdered {
-                Some(Self::detect_list_style(&items, start_value))
+                Some(Self::detect_list_style(ctx.content, &items, start_value))
             } else {
                 None
             };
 
             // Check each item using the CommonMark start value
             for (idx, (line_num, line_info, list_item)) in items.iter().enumerate() {
-                if let Some(actual_num) = Self::parse_marker_number(&list_item.marker) {
+                if let Some(actual_num) = Self::parse_marker_number(list_item.marker(ctx.content)) {
                     let expected_num = self.get_expected_number(idx, detected_style, start_value);
 
                     if actual_num != expected_num {
                         let marker_start = line_info.byte_offset + list_item.marker_column;
-                        let number_len = if let Some(dot_pos) = list_item.marker.find('.') {
+                        let number_len = if let Some(dot_pos) = list_item.marker(ctx.content).find('.') {
                             dot_pos
-                        } else if let Some(paren_pos) = list_item.marker.find(')') {
+                        } else if let Some(paren_pos) = list_item.marker(ctx.content).find(')') {
                             paren_pos
                         } else {
-                            list_item.marker.len()
+                            list_item.marker_len()
                         };
 
                         let style_name = match detected_style.as_ref().unwrap_or(&ListStyle::Ordered) {
@@ -340,7 +341,7 @@ impl Rule for MD029OrderedListPrefix {
             }
             // Detect style across entire document (use 1 as default for pattern detection)
             if !all_document_items.is_empty() {
-                Some(Self::detect_list_style(&all_document_items, 1))
+                Some(Self::detect_list_style(ctx.content, &all_document_items, 1))
             } else {
                 None
             }
@@ -378,6 +379,10 @@ impl Rule for MD029OrderedListPrefix {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_lists()
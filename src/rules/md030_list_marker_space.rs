@@ -3,7 +3,7 @@
 //!
 //! See [docs/md030.md](../../docs/md030.md) for full documentation, configuration, and examples.
 
-use crate::rule::{LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::blockquote::{effective_indent_in_blockquote, parse_blockquote_prefix};
 use crate::utils::calculate_indentation_width_default;
 use crate::utils::range_utils::calculate_match_range;
@@ -200,7 +200,7 @@ impl Rule for MD030ListMarkerSpace {
             } else {
                 ListType::Unordered
             };
-            let marker_end = list_info.marker_column + list_info.marker.len();
+            let marker_end = list_info.marker_column + list_info.marker_len();
 
             // MD030 only applies when there is content after the marker.
             if !Self::has_content_after_marker(line, marker_end) {
@@ -216,7 +216,7 @@ impl Rule for MD030ListMarkerSpace {
                 // ol-multi: pad a narrow marker up to it, and let one too wide overflow
                 // with a single space. Capped at 4 spaces, since 5+ start an indented
                 // code block in CommonMark.
-                let marker_len = list_info.marker.len();
+                let marker_len = list_info.marker_len();
                 target_column.saturating_sub(marker_len).clamp(1, 4)
             } else {
                 // Default: a fixed number of spaces by list type and whether the item
@@ -303,6 +303,10 @@ impl Rule for MD030ListMarkerSpace {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         if ctx.content.is_empty() {
             return true;
@@ -411,7 +415,7 @@ impl MD030ListMarkerSpace {
         let info = ctx.line_info(line_num)?;
         let list = info.list_item.as_ref()?;
         let (bq_level, min_indent) = match &info.blockquote {
-            Some(bq) if bq.nesting_level > 0 => (bq.nesting_level, list.content_column.saturating_sub(bq.prefix.len())),
+            Some(bq) if bq.nesting_level > 0 => (bq.nesting_level, list.content_column.saturating_sub(bq.prefix_len())),
             _ => (0, list.content_column),
         };
         Some((list.marker_column, bq_level, min_indent))
@@ -1,7 +1,7 @@
 /// Rule MD031: Blank lines around fenced code blocks
 ///
 /// See [docs/md031.md](../../docs/md031.md) for full documentation, configuration, and examples.
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::calculate_indentation_width_default;
 use crate::utils::kramdown_utils::is_kramdown_block_attribute;
@@ -67,7 +67,7 @@ impl MD031BlanksAroundFences {
             && let Some(ref bq) = line_info.blockquote
         {
             // If the blockquote content is empty, this is effectively a blank line
-            return bq.content.trim().is_empty();
+            return bq.content(ctx.content).trim().is_empty();
         }
 
         false
@@ -530,6 +530,10 @@ impl Rule for MD031BlanksAroundFences {
         RuleCategory::CodeBlock
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         if ctx.content.is_empty() {
@@ -1,5 +1,5 @@
 use crate::lint_context::LazyContLine;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::blockquote::{content_after_blockquote, effective_indent_in_blockquote};
 use crate::utils::calculate_indentation_width_default;
 use crate::utils::pandoc;
@@ -822,6 +822,10 @@ impl Rule for MD032BlanksAroundLists {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -4,8 +4,9 @@
 //! See [docs/md033.md](../../docs/md033.md) for full documentation, configuration, and examples.
 
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::html_utils::{HtmlAttribute, parse_attributes};
 use crate::utils::regex_cache::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 mod md033_config;
 use md033_config::{MD033Config, MD033FixMode};
@@ -18,6 +19,8 @@ pub struct MD033NoInlineHtml {
     disallowed: HashSet<String>,
     drop_attributes: HashSet<String>,
     strip_wrapper_elements: HashSet<String>,
+    attribute_policy: HashMap<String, Vec<String>>,
+    disallowed_attributes: Vec<String>,
 }
 
 impl Default for MD033NoInlineHtml {
@@ -61,6 +64,8 @@ impl MD033NoInlineHtml {
         let disallowed = config.disallowed_set();
         let drop_attributes = config.drop_attributes_set();
         let strip_wrapper_elements = config.strip_wrapper_elements_set();
+        let attribute_policy = config.attribute_policy();
+        let disallowed_attributes = config.disallowed_attributes.iter().map(|a| a.to_lowercase()).collect();
         Self {
             config,
             allowed,
@@ -68,6 +73,8 @@ impl MD033NoInlineHtml {
             disallowed,
             drop_attributes,
             strip_wrapper_elements,
+            attribute_policy,
+            disallowed_attributes,
         }
     }
 
@@ -113,6 +120,62 @@ impl MD033NoInlineHtml {
         Self::tag_in_set(&self.disallowed, tag)
     }
 
+    /// Match an attribute name against a policy entry; entries ending in `*` match by prefix.
+    #[inline]
+    fn attribute_matches(pattern: &str, name: &str) -> bool {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => pattern == name,
+        }
+    }
+
+    /// Warnings for attributes of a permitted tag that break the attribute policy:
+    /// attributes in `disallowed-attributes`, and attributes missing from the
+    /// `allowed-attributes` entry of the element or of `"*"`.
+    fn attribute_warnings(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        html_tag: &crate::lint_context::HtmlTag,
+    ) -> Vec<LintWarning> {
+        if self.attribute_policy.is_empty() && self.disallowed_attributes.is_empty() {
+            return Vec::new();
+        }
+        let element = self.attribute_policy.get(&html_tag.tag_name);
+        let any_element = self.attribute_policy.get("*");
+        let restricted = element.is_some() || any_element.is_some();
+
+        let tag = &ctx.content[html_tag.byte_offset..html_tag.byte_end];
+        parse_attributes(tag)
+            .into_iter()
+            .filter(|attr| {
+                let disallowed = self
+                    .disallowed_attributes
+                    .iter()
+                    .any(|pattern| Self::attribute_matches(pattern, &attr.name));
+                let allowed = element
+                    .into_iter()
+                    .chain(any_element)
+                    .flatten()
+                    .any(|pattern| Self::attribute_matches(pattern, &attr.name));
+                disallowed || (restricted && !allowed)
+            })
+            .map(|attr| {
+                let (line, column) = ctx.offset_to_line_col(html_tag.byte_offset + attr.range.start);
+                let (end_line, end_column) = ctx.offset_to_line_col(html_tag.byte_offset + attr.range.end);
+                LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line,
+                    column,
+                    end_line,
+                    end_column,
+                    message: format!("Attribute '{}' is not allowed on <{}>", attr.name, html_tag.tag_name),
+                    severity: Severity::Warning,
+                    fix: None,
+                }
+            })
+            .collect()
+    }
+
     /// Check if operating in disallowed-only mode
     #[inline]
     fn is_disallowed_mode(&self) -> bool {
@@ -514,116 +577,22 @@ impl MD033NoInlineHtml {
         }
     }
 
-    /// Parse all attributes from an HTML tag into a list of (name, value) pairs.
-    /// This provides proper attribute parsing instead of naive string matching.
-    fn parse_attributes(tag: &str) -> Vec<(String, Option<String>)> {
-        let mut attrs = Vec::new();
-
-        // Remove < and > and tag name
-        let tag_content = tag.trim_start_matches('<').trim_end_matches('>').trim_end_matches('/');
-
-        // Find first whitespace to skip tag name. Advance by the full UTF-8 width
-        // of the whitespace char so multi-byte whitespace (e.g. U+00A0) does not
-        // leave attr_start in the middle of a codepoint.
-        let attr_start = tag_content
-            .char_indices()
-            .find(|(_, c)| c.is_whitespace())
-            .map_or(tag_content.len(), |(i, c)| i + c.len_utf8());
-
-        if attr_start >= tag_content.len() {
-            return attrs;
-        }
-
-        let attr_str = &tag_content[attr_start..];
-        let mut chars = attr_str.chars().peekable();
-
-        while chars.peek().is_some() {
-            // Skip whitespace
-            while chars.peek().is_some_and(|c| c.is_whitespace()) {
-                chars.next();
-            }
-
-            if chars.peek().is_none() {
-                break;
-            }
-
-            // Read attribute name
-            let mut attr_name = String::new();
-            while let Some(&c) = chars.peek() {
-                if c.is_whitespace() || c == '=' || c == '>' || c == '/' {
-                    break;
-                }
-                attr_name.push(c);
-                chars.next();
-            }
-
-            if attr_name.is_empty() {
-                break;
-            }
-
-            // Skip whitespace before =
-            while chars.peek().is_some_and(|c| c.is_whitespace()) {
-                chars.next();
-            }
-
-            // Check for = and value
-            if chars.peek() == Some(&'=') {
-                chars.next(); // consume =
-
-                // Skip whitespace after =
-                while chars.peek().is_some_and(|c| c.is_whitespace()) {
-                    chars.next();
-                }
-
-                // Read value
-                let mut value = String::new();
-                if let Some(&quote) = chars.peek() {
-                    if quote == '"' || quote == '\'' {
-                        chars.next(); // consume opening quote
-                        for c in chars.by_ref() {
-                            if c == quote {
-                                break;
-                            }
-                            value.push(c);
-                        }
-                    } else {
-                        // Unquoted value
-                        while let Some(&c) = chars.peek() {
-                            if c.is_whitespace() || c == '>' || c == '/' {
-                                break;
-                            }
-                            value.push(c);
-                            chars.next();
-                        }
-                    }
-                }
-                attrs.push((attr_name.to_ascii_lowercase(), Some(value)));
-            } else {
-                // Boolean attribute (no value)
-                attrs.push((attr_name.to_ascii_lowercase(), None));
-            }
-        }
-
-        attrs
-    }
-
     /// Extract an HTML attribute value from a tag string.
     /// Handles double quotes, single quotes, and unquoted values.
     /// Returns None if the attribute is not found.
     fn extract_attribute(tag: &str, attr_name: &str) -> Option<String> {
-        let attrs = Self::parse_attributes(tag);
         let attr_lower = attr_name.to_ascii_lowercase();
 
-        attrs
+        parse_attributes(tag)
             .into_iter()
-            .find(|(name, _)| name == &attr_lower)
-            .and_then(|(_, value)| value)
+            .find(|attr| attr.name == attr_lower)
+            .and_then(|attr| attr.value)
     }
 
     /// Check if an HTML tag has extra attributes beyond the specified allowed ones.
     /// Uses proper attribute parsing to avoid false positives from string matching.
     fn has_extra_attributes(&self, tag: &str, allowed_attrs: &[&str]) -> bool {
-        let attrs = Self::parse_attributes(tag);
+        let attrs = parse_attributes(tag);
 
         // All event handlers (on*) are dangerous
         // Plus common attributes that would be lost in markdown conversion
@@ -650,7 +619,7 @@ impl MD033NoInlineHtml {
             "data-*", // data attributes (checked separately)
         ];
 
-        for (attr_name, _) in attrs {
+        for HtmlAttribute { name: attr_name, .. } in attrs {
             // Skip allowed attributes (list is small, linear scan is efficient)
             if allowed_attrs.iter().any(|a| a.to_ascii_lowercase() == attr_name) {
                 continue;
@@ -1079,15 +1048,16 @@ impl Rule for MD033NoInlineHtml {
             // - Disallowed mode: only report tags in the disallowed list
             // - Default mode: report all tags except those in the allowed list,
             //   with `table_allowed` taking precedence inside GFM table cells.
-            if self.is_disallowed_mode() {
-                if !self.is_tag_disallowed(tag) {
-                    continue;
-                }
+            // Permitted tags are still held to the attribute policy.
+            let permitted = if self.is_disallowed_mode() {
+                !self.is_tag_disallowed(tag)
             } else if ctx.is_in_table_block(line_num) {
-                if self.is_tag_allowed_in_table(tag) {
-                    continue;
-                }
-            } else if self.is_tag_allowed(tag) {
+                self.is_tag_allowed_in_table(tag)
+            } else {
+                self.is_tag_allowed(tag)
+            };
+            if permitted {
+                warnings.extend(self.attribute_warnings(ctx, html_tag));
                 continue;
             }
 
@@ -3014,4 +2984,80 @@ tR += `# ${file}\n\nCreated: ${date}\nIn: ${folder}`;
             "table_allowed should be case-insensitive, got {result:?}"
         );
     }
+
+    // =========================================================================
+    // Attribute policies: `allowed-attributes` and `disallowed-attributes`
+    // apply to tags that are otherwise permitted.
+    // =========================================================================
+
+    #[test]
+    fn test_md033_allowed_attributes_per_element() {
+        let config = MD033Config {
+            allowed: vec!["img".to_string(), "details".to_string()],
+            allowed_attributes: [(
+                "IMG".to_string(),
+                vec!["src".to_string(), "alt".to_string(), "data-*".to_string()],
+            )]
+            .into_iter()
+            .collect(),
+            ..MD033Config::default()
+        };
+        let rule = MD033NoInlineHtml::from_config_struct(config);
+        let content = "<img src=\"a.png\" alt=\"A\" data-zoom>\n\n<img src=\"b.png\" class=\"wide\" width=200>\n\n<details open>x</details>\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let messages: Vec<&str> = result.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Attribute 'class' is not allowed on <img>",
+                "Attribute 'width' is not allowed on <img>",
+            ]
+        );
+        assert_eq!((result[0].line, result[0].column, result[0].end_column), (3, 18, 30));
+    }
+
+    #[test]
+    fn test_md033_allowed_attributes_wildcard_element() {
+        let config = MD033Config {
+            allowed: vec!["a".to_string(), "span".to_string()],
+            allowed_attributes: [
+                ("*".to_string(), vec!["id".to_string()]),
+                ("a".to_string(), vec!["href".to_string()]),
+            ]
+            .into_iter()
+            .collect(),
+            ..MD033Config::default()
+        };
+        let rule = MD033NoInlineHtml::from_config_struct(config);
+        let content = "<a href=\"x\" id=\"top\">x</a> <span id=\"s\" title=\"t\">y</span>\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].message, "Attribute 'title' is not allowed on <span>");
+    }
+
+    #[test]
+    fn test_md033_disallowed_attributes() {
+        let config = MD033Config {
+            disallowed: vec!["gfm".to_string()],
+            disallowed_attributes: vec!["style".to_string(), "on*".to_string()],
+            ..MD033Config::default()
+        };
+        let rule = MD033NoInlineHtml::from_config_struct(config);
+        let content =
+            "<div style=\"color: red\" onclick=\"go()\" class=\"note\">x</div>\n\n<script style=\"x\"></script>\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let messages: Vec<&str> = result.iter().map(|w| w.message.as_str()).collect();
+        // The disallowed <script> is reported once, not per attribute
+        assert_eq!(
+            messages,
+            vec![
+                "Attribute 'style' is not allowed on <div>",
+                "Attribute 'onclick' is not allowed on <div>",
+                "Inline HTML found: <script style=\"x\">",
+            ]
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::rule_config_serde::RuleConfig;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// GFM security tags that are filtered/disallowed by default in GitHub Flavored Markdown.
 /// These tags can execute scripts, load external content, or otherwise pose security risks.
@@ -136,6 +136,19 @@ pub struct MD033Config {
         alias = "table_allowed"
     )]
     pub table_allowed_elements: Option<Vec<String>>,
+
+    /// Attributes permitted on otherwise allowed elements, keyed by element name.
+    ///
+    /// An element with an entry may only carry the listed attributes. The `"*"`
+    /// entry lists attributes permitted on every element and restricts all of
+    /// them. Names ending in `*` match by prefix, e.g. `"aria-*"`.
+    #[serde(default, rename = "allowed-attributes", alias = "allowed_attributes")]
+    pub allowed_attributes: BTreeMap<String, Vec<String>>,
+
+    /// Attributes that are never permitted on any element, e.g. `["style", "on*"]`.
+    /// Names ending in `*` match by prefix.
+    #[serde(default, rename = "disallowed-attributes", alias = "disallowed_attributes")]
+    pub disallowed_attributes: Vec<String>,
 }
 
 impl Default for MD033Config {
@@ -149,6 +162,8 @@ impl Default for MD033Config {
             strip_wrapper_elements: default_strip_wrapper_elements(),
             br_style: BrStyle::default(),
             table_allowed_elements: None,
+            allowed_attributes: BTreeMap::new(),
+            disallowed_attributes: Vec::new(),
         }
     }
 }
@@ -182,6 +197,14 @@ impl MD033Config {
         }
     }
 
+    /// Lowercased `allowed-attributes` for efficient lookup by element name.
+    pub fn attribute_policy(&self) -> HashMap<String, Vec<String>> {
+        self.allowed_attributes
+            .iter()
+            .map(|(element, attrs)| (element.to_lowercase(), attrs.iter().map(|a| a.to_lowercase()).collect()))
+            .collect()
+    }
+
     /// Convert disallowed elements to HashSet for efficient lookup.
     /// If the list contains "gfm", expands to the GFM security tags.
     pub fn disallowed_set(&self) -> HashSet<String> {
@@ -469,6 +469,10 @@ impl Rule for MD034NoBareUrls {
         "No bare URLs - wrap URLs in angle brackets"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &LintContext) -> LintResult {
         let mut warnings = Vec::new();
         let content = ctx.content;
@@ -542,7 +546,7 @@ impl Rule for MD034NoBareUrls {
             line_warnings.retain(|warning| {
                 if let Some(fix) = &warning.fix {
                     // Check if the fix range falls inside any parsed link's byte range
-                    !ctx.links
+                    !ctx.links()
                         .iter()
                         .any(|link| fix.range.start >= link.byte_offset && fix.range.end <= link.byte_end)
                 } else {
@@ -3,7 +3,7 @@
 //!
 //! See [docs/md036.md](../../docs/md036.md) for full documentation, configuration, and examples.
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_emphasis_range;
 use regex::Regex;
 use std::sync::LazyLock;
@@ -208,6 +208,10 @@ impl Rule for MD036NoEmphasisAsHeading {
         RuleCategory::Emphasis
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
         // Fast path for empty content or content without emphasis markers
@@ -2,7 +2,7 @@
 ///
 /// See [docs/md037.md](../../docs/md037.md) for full documentation, configuration, and examples.
 use crate::filtered_lines::FilteredLinesExt;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::emphasis_utils::{
     EmphasisSpan, find_emphasis_markers, find_emphasis_spans, find_valid_emphasis_ranges, has_doc_patterns,
     replace_inline_code, replace_inline_math,
@@ -53,14 +53,14 @@ impl MD037NoSpaceInEmphasis {
     /// Check if a byte position is within a link (inline links, reference links, or reference definitions)
     fn is_in_link(&self, ctx: &crate::lint_context::LintContext, byte_pos: usize) -> bool {
         // Check inline and reference links
-        for link in &ctx.links {
+        for link in ctx.links() {
             if link.byte_offset <= byte_pos && byte_pos < link.byte_end {
                 return true;
             }
         }
 
         // Check images (which use similar syntax)
-        for image in &ctx.images {
+        for image in ctx.images() {
             if image.byte_offset <= byte_pos && byte_pos < image.byte_end {
                 return true;
             }
@@ -80,6 +80,10 @@ impl Rule for MD037NoSpaceInEmphasis {
         "Spaces inside emphasis markers"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
         let _timer = crate::profiling::ScopedTimer::new("MD037_check");
@@ -226,6 +230,10 @@ impl Rule for MD037NoSpaceInEmphasis {
         RuleCategory::Emphasis
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_emphasis()
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::mkdocs_extensions::is_inline_hilite_content;
 
 /// Rule MD038: No space inside code span markers
@@ -244,10 +244,18 @@ impl Rule for MD038NoSpaceInCode {
         "Spaces inside code span elements"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn category(&self) -> RuleCategory {
         RuleCategory::Other
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         if !self.enabled {
             return Ok(vec![]);
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::regex_cache::get_cached_regex;
 
 // Regex patterns
@@ -78,10 +78,18 @@ impl Rule for MD039NoSpaceInLinks {
         "Spaces inside link text"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn category(&self) -> RuleCategory {
         RuleCategory::Link
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_links_or_images()
     }
@@ -90,7 +98,7 @@ impl Rule for MD039NoSpaceInLinks {
         let mut warnings = Vec::new();
 
         // Use centralized link parsing from LintContext
-        for link in &ctx.links {
+        for link in ctx.links() {
             // Skip reference links (markdownlint doesn't check these)
             if link.is_reference {
                 continue;
@@ -153,7 +161,7 @@ impl Rule for MD039NoSpaceInLinks {
         }
 
         // Also check images
-        for image in &ctx.images {
+        for image in ctx.images() {
             // Skip reference images (markdownlint doesn't check these)
             if image.is_reference {
                 continue;
@@ -1,4 +1,4 @@
-use crate::linguist_data::{default_alias, get_aliases, is_valid_alias, resolve_canonical};
+use crate::linguist_data::{ALIAS_TO_CANONICAL, default_alias, get_aliases, is_valid_alias, resolve_canonical};
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::{RuleConfig, load_rule_config};
 use crate::utils::range_utils::calculate_line_range;
@@ -188,24 +188,110 @@ impl MD040FencedCodeLanguage {
         None
     }
 
+    /// Check if a label is one of the configured custom languages
+    fn is_custom_language(&self, label: &str) -> bool {
+        self.config
+            .custom_languages
+            .iter()
+            .any(|custom| custom.eq_ignore_ascii_case(label))
+    }
+
     /// Check for unknown language based on config
-    fn check_unknown_language(&self, label: &str) -> Option<(String, Severity)> {
+    ///
+    /// Returns the message, the severity, and the replacement label when the
+    /// label is an unambiguous typo of a known one.
+    fn check_unknown_language(&self, label: &str) -> Option<(String, Severity, Option<String>)> {
         if resolve_canonical(label).is_some() {
             return None;
         }
 
-        match self.config.unknown_language_action {
-            UnknownLanguageAction::Ignore => None,
-            UnknownLanguageAction::Warn => Some((
+        let (mut message, severity) = match self.config.unknown_language_action {
+            UnknownLanguageAction::Ignore => return None,
+            UnknownLanguageAction::Warn => (
                 format!("Unknown language '{label}' (not in GitHub Linguist). Syntax highlighting may not work."),
                 Severity::Warning,
-            )),
-            UnknownLanguageAction::Error => Some((
-                format!("Unknown language '{label}' (not in GitHub Linguist)"),
+            ),
+            UnknownLanguageAction::Error => (
+                format!("Unknown language '{label}' (not in GitHub Linguist)."),
                 Severity::Error,
-            )),
+            ),
+        };
+
+        let mut replacement = None;
+        if let Some((suggestion, unambiguous)) = suggest_language(label, &self.config.custom_languages) {
+            message.push_str(&format!(" Did you mean '{suggestion}'?"));
+            if unambiguous {
+                replacement = Some(suggestion);
+            }
+        }
+        Some((message, severity, replacement))
+    }
+}
+
+/// Find the known language label closest to a misspelled one
+///
+/// Candidates are the Linguist aliases and the custom languages, compared
+/// case-insensitively. Returns the nearest label within a small edit distance
+/// (one edit for labels of up to four characters, two otherwise) and whether
+/// it is the only label at that distance, i.e. an unambiguous typo that is
+/// safe to fix automatically.
+fn suggest_language(label: &str, custom_languages: &[String]) -> Option<(String, bool)> {
+    let label = label.to_lowercase();
+    let max_distance = if label.chars().count() <= 4 { 1 } else { 2 };
+
+    let mut best: Option<(usize, Vec<String>)> = None;
+    let candidates = ALIAS_TO_CANONICAL
+        .keys()
+        .map(|alias| (*alias).to_string())
+        .chain(custom_languages.iter().map(|custom| custom.to_lowercase()));
+    for candidate in candidates {
+        let distance = typo_distance(&label, &candidate);
+        if distance > max_distance {
+            continue;
+        }
+        match &mut best {
+            Some((best_distance, labels)) if distance == *best_distance => {
+                if !labels.contains(&candidate) {
+                    labels.push(candidate);
+                }
+            }
+            Some((best_distance, _)) if distance > *best_distance => {}
+            _ => best = Some((distance, vec![candidate])),
+        }
+    }
+
+    let (_, mut labels) = best?;
+    // HashMap order is arbitrary; suggest the same label on every run
+    labels.sort();
+    let unambiguous = labels.len() == 1;
+    Some((labels.swap_remove(0), unambiguous))
+}
+
+/// Edit distance counting insertions, deletions, substitutions and
+/// transpositions of adjacent characters (as in `pyhton`) as one edit each
+fn typo_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
         }
     }
+    rows[a.len()][b.len()]
 }
 
 impl Rule for MD040FencedCodeLanguage {
@@ -217,6 +303,11 @@ impl Rule for MD040FencedCodeLanguage {
         "Code blocks should have a language specified"
     }
 
+    fn is_block_local(&self) -> bool {
+        // Consistent labels are compared across the whole document
+        self.config.style != LanguageStyle::Consistent
+    }
+
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let content = ctx.content;
         let mut warnings = Vec::new();
@@ -348,6 +439,11 @@ impl Rule for MD040FencedCodeLanguage {
                 continue;
             }
 
+            // Custom languages are accepted as-is
+            if self.is_custom_language(&block.language) {
+                continue;
+            }
+
             let canonical = resolve_canonical(&block.language);
 
             // Check language restrictions (allowlist/denylist)
@@ -369,9 +465,19 @@ impl Rule for MD040FencedCodeLanguage {
 
             // Check for unknown language (only if not handled by allowlist)
             if canonical.is_none() {
-                if let Some((msg, severity)) = self.check_unknown_language(&block.language) {
+                if let Some((msg, severity, replacement)) = self.check_unknown_language(&block.language) {
                     let (start_line, start_col, end_line, end_col) = calculate_line_range(block.line_idx + 1, line);
 
+                    let fix = replacement.and_then(|replacement| {
+                        find_label_span(line, &block.fence_marker).map(|(label_start, label_end)| {
+                            let line_start_byte = ctx.line_offsets.get(block.line_idx).copied().unwrap_or(0);
+                            Fix::new(
+                                (line_start_byte + label_start)..(line_start_byte + label_end),
+                                replacement,
+                            )
+                        })
+                    });
+
                     warnings.push(LintWarning {
                         rule_name: Some(self.name().to_string()),
                         line: start_line,
@@ -380,7 +486,7 @@ impl Rule for MD040FencedCodeLanguage {
                         end_column: end_col,
                         message: msg,
                         severity,
-                        fix: None,
+                        fix,
                     });
                 }
                 continue;
@@ -1015,6 +1121,89 @@ echo again
         assert_eq!(result[0].severity, Severity::Error);
     }
 
+    #[test]
+    fn test_unknown_language_suggests_and_fixes_typo() {
+        let content = "```pyhton\nprint(1)\n```\n";
+        let config = MD040Config {
+            unknown_language_action: UnknownLanguageAction::Warn,
+            ..Default::default()
+        };
+        let result = run_check_with_config(content, config.clone()).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(
+            result[0].message.ends_with("Did you mean 'python'?"),
+            "{}",
+            result[0].message
+        );
+        assert!(result[0].fix.is_some());
+
+        let rule = MD040FencedCodeLanguage::with_config(config);
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), "```python\nprint(1)\n```\n");
+    }
+
+    #[test]
+    fn test_unknown_language_ambiguous_typo_not_fixed() {
+        let (suggestion, unambiguous) = suggest_language("jsn", &[]).unwrap();
+        assert!(!unambiguous, "'jsn' is one edit from several aliases, got {suggestion}");
+        assert_eq!(suggest_language("pyhton", &[]), Some(("python".to_string(), true)));
+        assert_eq!(suggest_language("qwertyuiop", &[]), None);
+    }
+
+    #[test]
+    fn test_unknown_language_without_close_match_has_no_fix() {
+        let content = "```mycustomlang\ncode\n```";
+        let config = MD040Config {
+            unknown_language_action: UnknownLanguageAction::Error,
+            ..Default::default()
+        };
+        let result = run_check_with_config(content, config).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].message,
+            "Unknown language 'mycustomlang' (not in GitHub Linguist)."
+        );
+        assert!(result[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_custom_languages_are_accepted() {
+        let content = "```dataview\nLIST\n```\n\n```dataveiw\nLIST\n```\n";
+        let config = MD040Config {
+            unknown_language_action: UnknownLanguageAction::Warn,
+            custom_languages: vec!["Dataview".to_string()],
+            ..Default::default()
+        };
+        let result = run_check_with_config(content, config).unwrap();
+        // Typos of custom languages are suggested too
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 5);
+        assert!(result[0].message.ends_with("Did you mean 'dataview'?"));
+        assert!(result[0].fix.is_some());
+    }
+
+    #[test]
+    fn test_custom_languages_skip_allowlist() {
+        let content = "```dataview\nLIST\n```\n\n```ruby\nputs 1\n```\n";
+        let config = MD040Config {
+            allowed_languages: vec!["Python".to_string()],
+            custom_languages: vec!["dataview".to_string()],
+            ..Default::default()
+        };
+        let result = run_check_with_config(content, config).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 5);
+        assert!(result[0].message.contains("not in the allowed list"));
+    }
+
+    #[test]
+    fn test_typo_distance_counts_transpositions_once() {
+        assert_eq!(typo_distance("pyhton", "python"), 1);
+        assert_eq!(typo_distance("rust", "rust"), 0);
+        assert_eq!(typo_distance("bash", "bsah"), 1);
+        assert_eq!(typo_distance("go", "rust"), 4);
+    }
+
     // =========================================================================
     // Config validation tests
     // =========================================================================
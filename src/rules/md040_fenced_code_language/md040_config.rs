@@ -52,6 +52,11 @@ pub struct MD040Config {
     /// Action for unknown language labels not in Linguist
     #[serde(default, alias = "unknown_language_action")]
     pub unknown_language_action: UnknownLanguageAction,
+
+    /// Additional language labels to accept, such as diagram renderers (case-insensitive)
+    /// Never reported as unknown and exempt from allowed-languages and disallowed-languages
+    #[serde(default, alias = "custom_languages")]
+    pub custom_languages: Vec<String>,
 }
 
 impl RuleConfig for MD040Config {
@@ -506,134 +506,8 @@ impl MD041FirstLineHeading {
         None
     }
 
-    /// Determine if this document can be auto-fixed.
-    fn can_fix(&self, ctx: &crate::lint_context::LintContext) -> bool {
-        self.fix_enabled && self.analyze_for_fix(ctx).is_some()
-    }
-}
-
-impl Rule for MD041FirstLineHeading {
-    fn name(&self) -> &'static str {
-        "MD041"
-    }
-
-    fn description(&self) -> &'static str {
-        "First line in file should be a top level heading"
-    }
-
-    fn fix_capability(&self) -> FixCapability {
-        FixCapability::Unfixable
-    }
-
-    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
-        let mut warnings = Vec::new();
-
-        // Check if we should skip this file
-        if self.should_skip(ctx) {
-            return Ok(warnings);
-        }
-
-        let Some(first_line_idx) = Self::first_content_line_idx(ctx) else {
-            return Ok(warnings);
-        };
-
-        // Check if the first non-blank line is a heading of the required level
-        let first_line_info = &ctx.lines[first_line_idx];
-        let is_correct_heading = if let Some(heading) = &first_line_info.heading {
-            heading.level as usize == self.level
-        } else {
-            // Check for HTML heading (both single-line and multi-line)
-            Self::is_html_heading(ctx, first_line_idx, self.level)
-        };
-
-        if !is_correct_heading {
-            // Calculate precise character range for the entire first line
-            let first_line = first_line_idx + 1; // Convert to 1-indexed
-            let first_line_content = first_line_info.content(ctx.content);
-            let (start_line, start_col, end_line, end_col) = calculate_line_range(first_line, first_line_content);
-
-            // Compute the actual replacement so that LSP quick-fix can apply it
-            // directly without calling fix(). For simple cases (releveling,
-            // promote-plain-text at the first content line), we use a targeted
-            // range. For complex cases (moving headings, inserting derived
-            // titles), we replace the entire document via fix().
-            let fix = if self.can_fix(ctx) {
-                self.analyze_for_fix(ctx).and_then(|plan| {
-                    let range_start = first_line_info.byte_offset;
-                    let range_end = range_start + first_line_info.byte_len;
-                    match &plan {
-                        FixPlan::MoveOrRelevel {
-                            heading_idx,
-                            current_level,
-                            needs_level_fix,
-                            is_setext,
-                            ..
-                        } if *heading_idx == first_line_idx => {
-                            // Heading is already at the correct position, just needs releveling
-                            let heading_line = ctx.lines[*heading_idx].content(ctx.content);
-                            let replacement = if *needs_level_fix || *is_setext {
-                                self.fix_heading_level(heading_line, *current_level, self.level)
-                            } else {
-                                heading_line.to_string()
-                            };
-                            Some(Fix::new(range_start..range_end, replacement))
-                        }
-                        FixPlan::PromotePlainText { title_line_idx, .. } if *title_line_idx == first_line_idx => {
-                            let replacement = format!(
-                                "{} {}",
-                                "#".repeat(self.level),
-                                ctx.lines[*title_line_idx].content(ctx.content).trim()
-                            );
-                            Some(Fix::new(range_start..range_end, replacement))
-                        }
-                        _ => {
-                            // Complex multi-line operations (moving headings, inserting
-                            // derived titles, promoting non-first-line text): replace
-                            // the entire document via fix().
-                            self.fix(ctx)
-                                .ok()
-                                .map(|fixed_content| Fix::new(0..ctx.content.len(), fixed_content))
-                        }
-                    }
-                })
-            } else {
-                None
-            };
-
-            warnings.push(LintWarning {
-                rule_name: Some(self.name().to_string()),
-                line: start_line,
-                column: start_col,
-                end_line,
-                end_column: end_col,
-                message: format!("First line in file should be a level {} heading", self.level),
-                severity: Severity::Warning,
-                fix,
-            });
-        }
-        Ok(warnings)
-    }
-
-    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
-        if !self.fix_enabled {
-            return Ok(ctx.content.to_string());
-        }
-
-        if self.should_skip(ctx) {
-            return Ok(ctx.content.to_string());
-        }
-
-        // Respect inline disable comments — use the same first-content-line
-        // logic as check() so both paths agree on which line to check.
-        let first_content_line = Self::first_content_line_idx(ctx).map_or(1, |i| i + 1);
-        if ctx.inline_config().is_rule_disabled(self.name(), first_content_line) {
-            return Ok(ctx.content.to_string());
-        }
-
-        let Some(plan) = self.analyze_for_fix(ctx) else {
-            return Ok(ctx.content.to_string());
-        };
-
+    /// Rewrite the document according to `plan`
+    fn apply_fix_plan(&self, ctx: &crate::lint_context::LintContext, plan: FixPlan) -> String {
         let lines = ctx.raw_lines();
 
         let mut result = String::new();
@@ -720,7 +594,132 @@ impl Rule for MD041FirstLineHeading {
             result.pop();
         }
 
-        Ok(result)
+        result
+    }
+}
+
+impl Rule for MD041FirstLineHeading {
+    fn name(&self) -> &'static str {
+        "MD041"
+    }
+
+    fn description(&self) -> &'static str {
+        "First line in file should be a top level heading"
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        // Check if we should skip this file
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let Some(first_line_idx) = Self::first_content_line_idx(ctx) else {
+            return Ok(warnings);
+        };
+
+        // Check if the first non-blank line is a heading of the required level
+        let first_line_info = &ctx.lines[first_line_idx];
+        let is_correct_heading = if let Some(heading) = &first_line_info.heading {
+            heading.level as usize == self.level
+        } else {
+            // Check for HTML heading (both single-line and multi-line)
+            Self::is_html_heading(ctx, first_line_idx, self.level)
+        };
+
+        if !is_correct_heading {
+            // Calculate precise character range for the entire first line
+            let first_line = first_line_idx + 1; // Convert to 1-indexed
+            let first_line_content = first_line_info.content(ctx.content);
+            let (start_line, start_col, end_line, end_col) = calculate_line_range(first_line, first_line_content);
+
+            // Compute the actual replacement so that LSP quick-fix can apply it
+            // directly without calling fix(). For simple cases (releveling,
+            // promote-plain-text at the first content line), we use a targeted
+            // range. For complex cases (moving headings, inserting derived
+            // titles), we replace the entire document. Without `fix = true`
+            // the same edit is only offered as a suggestion, and when no plan
+            // applies we suggest inserting a title heading above the content.
+            let make_fix = if self.fix_enabled { Fix::new } else { Fix::suggestion };
+            let range_start = first_line_info.byte_offset;
+            let range_end = range_start + first_line_info.byte_len;
+            let fix = match self.analyze_for_fix(ctx) {
+                Some(FixPlan::MoveOrRelevel {
+                    heading_idx,
+                    current_level,
+                    needs_level_fix,
+                    is_setext,
+                    ..
+                }) if heading_idx == first_line_idx => {
+                    // Heading is already at the correct position, just needs releveling
+                    let heading_line = ctx.lines[heading_idx].content(ctx.content);
+                    let replacement = if needs_level_fix || is_setext {
+                        self.fix_heading_level(heading_line, current_level, self.level)
+                    } else {
+                        heading_line.to_string()
+                    };
+                    make_fix(range_start..range_end, replacement)
+                }
+                Some(FixPlan::PromotePlainText { title_line_idx, .. }) if title_line_idx == first_line_idx => {
+                    let replacement = format!(
+                        "{} {}",
+                        "#".repeat(self.level),
+                        ctx.lines[title_line_idx].content(ctx.content).trim()
+                    );
+                    make_fix(range_start..range_end, replacement)
+                }
+                // Complex multi-line operations (moving headings, inserting
+                // derived titles, promoting non-first-line text)
+                Some(plan) => make_fix(0..ctx.content.len(), self.apply_fix_plan(ctx, plan)),
+                None => {
+                    let title = Self::derive_title(ctx).unwrap_or_else(|| "Title".to_string());
+                    Fix::suggestion(
+                        range_start..range_start,
+                        format!("{} {title}\n\n", "#".repeat(self.level)),
+                    )
+                }
+            };
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: start_line,
+                column: start_col,
+                end_line,
+                end_column: end_col,
+                message: format!("First line in file should be a level {} heading", self.level),
+                severity: Severity::Warning,
+                fix: Some(fix),
+            });
+        }
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
+        if !self.fix_enabled {
+            return Ok(ctx.content.to_string());
+        }
+
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+
+        // Respect inline disable comments — use the same first-content-line
+        // logic as check() so both paths agree on which line to check.
+        let first_content_line = Self::first_content_line_idx(ctx).map_or(1, |i| i + 1);
+        if ctx.inline_config().is_rule_disabled(self.name(), first_content_line) {
+            return Ok(ctx.content.to_string());
+        }
+
+        let Some(plan) = self.analyze_for_fix(ctx) else {
+            return Ok(ctx.content.to_string());
+        };
+
+        Ok(self.apply_fix_plan(ctx, plan))
     }
 
     /// Check if this rule should be skipped
@@ -1270,12 +1269,34 @@ mod tests {
     fn test_no_fix_suggestion() {
         let rule = MD041FirstLineHeading::default();
 
-        // Check that NO fix suggestion is provided (MD041 is now detection-only)
+        // Without `fix = true` the promotion is only a suggested edit, never applied automatically
         let content = "Not a heading\n\nContent.";
         let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
         let result = rule.check(&ctx).unwrap();
         assert_eq!(result.len(), 1);
-        assert!(result[0].fix.is_none(), "MD041 should not provide fix suggestions");
+        assert!(
+            result[0].automatic_fix().is_none(),
+            "MD041 should not provide automatic fixes"
+        );
+        let suggestion = result[0].fix.as_ref().expect("MD041 should suggest a title");
+        assert_eq!(suggestion.replacement, "# Not a heading");
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
+    #[test]
+    fn test_suggests_inserting_title_when_no_plan_applies() {
+        let rule = MD041FirstLineHeading::default();
+
+        // A paragraph that is not a title candidate cannot be promoted, so the
+        // suggestion inserts a placeholder title above it
+        let content = "This is a long paragraph of text, not a title.\nIt continues here.\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        let suggestion = result[0].fix.as_ref().unwrap();
+        assert!(!suggestion.is_automatic());
+        assert_eq!(suggestion.range, 0..0);
+        assert_eq!(suggestion.replacement, "# Title\n\n");
     }
 
     #[test]
@@ -1889,7 +1910,7 @@ mod tests {
         let warnings = rule.check(&ctx).unwrap();
         assert_eq!(warnings.len(), 1);
         assert!(
-            warnings[0].fix.is_none(),
+            warnings[0].automatic_fix().is_none(),
             "HTML heading should not be claimed as fixable"
         );
     }
@@ -1910,7 +1931,7 @@ mod tests {
         let warnings = rule.check(&ctx).unwrap();
         assert_eq!(warnings.len(), 1);
         assert!(
-            warnings[0].fix.is_none(),
+            warnings[0].automatic_fix().is_none(),
             "Document without heading should not be claimed as fixable"
         );
     }
@@ -1931,7 +1952,7 @@ mod tests {
         let warnings = rule.check(&ctx).unwrap();
         assert_eq!(warnings.len(), 1);
         assert!(
-            warnings[0].fix.is_none(),
+            warnings[0].automatic_fix().is_none(),
             "Document with content before heading should not be claimed as fixable"
         );
     }
@@ -2050,7 +2071,10 @@ mod tests {
         assert_eq!(fixed, content, "Sentence-ending line should not be promoted");
 
         let warnings = rule.check(&ctx).unwrap();
-        assert!(warnings[0].fix.is_none(), "No fix should be offered");
+        assert!(
+            warnings[0].automatic_fix().is_none(),
+            "No automatic fix should be offered"
+        );
     }
 
     #[test]
@@ -2125,7 +2149,10 @@ mod tests {
         );
 
         let warnings = rule.check(&ctx).unwrap();
-        assert!(warnings[0].fix.is_none(), "No fix should be offered");
+        assert!(
+            warnings[0].automatic_fix().is_none(),
+            "No automatic fix should be offered"
+        );
     }
 
     #[test]
@@ -2167,7 +2194,7 @@ mod tests {
             Some(PathBuf::from("setup-guide.md")),
         );
 
-        let can_fix = rule.can_fix(&ctx);
+        let can_fix = rule.analyze_for_fix(&ctx).is_some();
         assert!(can_fix, "Directive-only document with source file should be fixable");
 
         let fixed = rule.fix(&ctx).unwrap();
@@ -79,7 +79,7 @@ impl MD043RequiredHeadings {
                 }
 
                 // Reconstruct the full heading format with the hash symbols
-                let full_heading = format!("{} {}", heading.marker, heading.text.trim());
+                let full_heading = format!("{} {}", heading.marker(ctx.content), heading.text(ctx.content).trim());
                 result.push(full_heading);
             }
         }
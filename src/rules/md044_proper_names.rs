@@ -1,7 +1,11 @@
 use crate::utils::fast_hash;
 use crate::utils::regex_cache::{escape_regex, get_cached_regex};
 
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use super::md063_heading_capitalization::{HeadingCapStyle, MD063Config};
+use crate::lint_context::LineInfo;
+use crate::rule::{Fix, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rules::MD063HeadingCapitalization;
+use crate::utils::proper_names;
 use crate::utils::range_utils::byte_to_char_count;
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
@@ -88,6 +92,9 @@ pub struct MD044ProperNames {
     name_variants: Vec<String>,
     // Cache for name violations by content hash
     content_cache: Arc<Mutex<HashMap<u64, Vec<WarningPosition>>>>,
+    /// Heading levels MD063 keeps in ALL CAPS, where the uppercase form of a
+    /// name is accepted. Populated via `from_config` when MD063 is active.
+    all_caps_headings: Option<(u8, u8)>,
 }
 
 impl MD044ProperNames {
@@ -105,6 +112,7 @@ impl MD044ProperNames {
             combined_pattern,
             name_variants,
             content_cache: Arc::new(Mutex::new(HashMap::new())),
+            all_caps_headings: None,
         }
     }
 
@@ -127,9 +135,26 @@ impl MD044ProperNames {
             combined_pattern,
             name_variants,
             content_cache: Arc::new(Mutex::new(HashMap::new())),
+            all_caps_headings: None,
         }
     }
 
+    /// Accept the uppercase form of names in headings of these levels, which
+    /// MD063 writes in ALL CAPS
+    pub(crate) fn with_all_caps_headings(mut self, min_level: u8, max_level: u8) -> Self {
+        self.all_caps_headings = Some((min_level, max_level));
+        self
+    }
+
+    // Whether `found_name` is the uppercase form of `proper_name` in a heading
+    // that MD063 keeps in ALL CAPS
+    fn is_all_caps_heading_form(&self, line_info: &LineInfo, found_name: &str, proper_name: &str) -> bool {
+        let (Some((min_level, max_level)), Some(heading)) = (self.all_caps_headings, &line_info.heading) else {
+            return false;
+        };
+        (min_level..=max_level).contains(&heading.level) && proper_names::is_uppercase_form(found_name, proper_name)
+    }
+
     // Create a combined regex pattern for all proper names
     fn create_combined_pattern(config: &MD044Config) -> Option<String> {
         if config.names.is_empty() {
@@ -367,7 +392,7 @@ impl MD044ProperNames {
 
                 // Skip if inside the URL portion of a WikiLink followed by a
                 // parenthesised destination — [[text]](url). pulldown-cmark
-                // registers [[text]] as a WikiLink in ctx.links but leaves the
+                // registers [[text]] as a WikiLink in ctx.links() but leaves the
                 // (url) as plain text, so is_in_link() misses those bytes.
                 if Self::is_in_wikilink_url(ctx, byte_pos) {
                     continue;
@@ -376,7 +401,8 @@ impl MD044ProperNames {
                 // Find which proper name this matches
                 if let Some(proper_name) = self.get_proper_name_for(found_name) {
                     // Only flag if it's not already correct
-                    if found_name != proper_name {
+                    if found_name != proper_name && !self.is_all_caps_heading_form(line_info, found_name, &proper_name)
+                    {
                         violations.push((line_num, cap.start() + 1, found_name.to_string()));
                     }
                 }
@@ -400,9 +426,9 @@ impl MD044ProperNames {
         use pulldown_cmark::LinkType;
 
         // Binary search links (sorted by byte_offset) to find candidate containing byte_pos
-        let link_idx = ctx.links.partition_point(|link| link.byte_offset <= byte_pos);
+        let link_idx = ctx.links().partition_point(|link| link.byte_offset <= byte_pos);
         if link_idx > 0 {
-            let link = &ctx.links[link_idx - 1];
+            let link = &ctx.links()[link_idx - 1];
             if byte_pos < link.byte_end {
                 // WikiLinks [[text]] start with '[[', regular links [text] start with '['
                 let text_start = if matches!(link.link_type, LinkType::WikiLink { .. }) {
@@ -426,9 +452,9 @@ impl MD044ProperNames {
         }
 
         // Binary search images (sorted by byte_offset) to find candidate containing byte_pos
-        let image_idx = ctx.images.partition_point(|img| img.byte_offset <= byte_pos);
+        let image_idx = ctx.images().partition_point(|img| img.byte_offset <= byte_pos);
         if image_idx > 0 {
-            let image = &ctx.images[image_idx - 1];
+            let image = &ctx.images()[image_idx - 1];
             if byte_pos < image.byte_end {
                 // Image starts with '![' so alt text starts at byte_offset + 2
                 let alt_start = image.byte_offset + 2;
@@ -495,7 +521,7 @@ impl MD044ProperNames {
 
     /// Check if a position within a line falls inside an angle-bracket URL (`<scheme://...>`).
     ///
-    /// The link parser skips autolinks inside HTML comments, so `ctx.links` won't
+    /// The link parser skips autolinks inside HTML comments, so `ctx.links()` won't
     /// contain them. This function detects angle-bracket URLs directly in the line
     /// text, covering both HTML comments and regular text as a safety net.
     fn is_in_angle_bracket_url(line: &str, pos: usize) -> bool {
@@ -551,24 +577,24 @@ impl MD044ProperNames {
     /// Check if `byte_pos` falls inside the URL of a `[[text]](url)` construct.
     ///
     /// pulldown-cmark with WikiLinks enabled parses `[[text]]` as a WikiLink and
-    /// records it in `ctx.links`, but the immediately following `(url)` is left as
-    /// plain text and is therefore absent from `ctx.links`. This function detects
+    /// records it in `ctx.links()`, but the immediately following `(url)` is left as
+    /// plain text and is therefore absent from `ctx.links()`. This function detects
     /// that gap by looking for a WikiLink entry whose `byte_end` falls exactly on a
     /// `(` in the raw content, then checking whether `byte_pos` lies inside the
     /// matching parenthesised URL span.
     ///
     /// Unlike `is_in_markdown_link_url`, this function is anchored to real parser
-    /// output (`ctx.links`) and will not suppress violations in text that merely
+    /// output (`ctx.links()`) and will not suppress violations in text that merely
     /// looks like a link (e.g. `[foo](github x)` with a space in the URL).
     fn is_in_wikilink_url(ctx: &crate::lint_context::LintContext, byte_pos: usize) -> bool {
         use pulldown_cmark::LinkType;
         let content = ctx.content.as_bytes();
 
-        // ctx.links is sorted by byte_offset; only links that start at or before
+        // ctx.links() is sorted by byte_offset; only links that start at or before
         // byte_pos can have a URL that encloses it.
-        let end = ctx.links.partition_point(|l| l.byte_offset <= byte_pos);
+        let end = ctx.links().partition_point(|l| l.byte_offset <= byte_pos);
 
-        for link in &ctx.links[..end] {
+        for link in &ctx.links()[..end] {
             if !matches!(link.link_type, LinkType::WikiLink { .. }) {
                 continue;
             }
@@ -909,6 +935,11 @@ impl Rule for MD044ProperNames {
         RuleCategory::Other
     }
 
+    fn fix_safety(&self) -> FixSafety {
+        // Rewrites words in prose, which can be deliberate spellings
+        FixSafety::Unsafe
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         if self.config.names.is_empty() {
             return true;
@@ -995,7 +1026,27 @@ impl Rule for MD044ProperNames {
         self
     }
 
-    crate::impl_rule_config_methods!(MD044Config);
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD044Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD044Config>(config);
+        let rule = Self::from_config_struct(rule_config);
+
+        // Respect MD063's ALL CAPS headings so the two rules never rewrite each other's fixes
+        let md063_config = crate::rule_config_serde::load_rule_config::<MD063Config>(config);
+        let md063: Box<dyn Rule> = Box::new(MD063HeadingCapitalization::from_config_struct(md063_config.clone()));
+        let md063_active = !crate::rules::filter_rules(&[md063], &config.global).is_empty();
+        if md063_active && md063_config.style == HeadingCapStyle::AllCaps {
+            Box::new(rule.with_all_caps_headings(md063_config.min_level, md063_config.max_level))
+        } else {
+            Box::new(rule)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2934,7 +2985,7 @@ Some javascript text.
 
     // Double-bracket WikiLink + URL: [[text]](url)
     // pulldown-cmark parses [[text]] as a WikiLink but leaves the (url)
-    // as plain text, so ctx.links does not cover the URL portion.
+    // as plain text, so ctx.links() does not cover the URL portion.
     // MD044 must fall back to is_in_markdown_link_url for all lines.
 
     #[test]
@@ -3262,4 +3313,67 @@ Some javascript text.
             "Should not flag bare-domain text when destination URL has an uppercase scheme: {result:?}"
         );
     }
+
+    fn config_with_md063_style(style: &str, md063_enabled: bool) -> crate::config::Config {
+        use crate::config::{Config, RuleConfig};
+        use std::collections::BTreeMap;
+
+        let mut config = Config::default();
+        let mut md044_values = BTreeMap::new();
+        md044_values.insert(
+            "names".to_string(),
+            toml::Value::Array(vec![toml::Value::String("GitHub".to_string())]),
+        );
+        config.rules.insert(
+            "MD044".to_string(),
+            RuleConfig {
+                values: md044_values,
+                severity: None,
+            },
+        );
+        let mut md063_values = BTreeMap::new();
+        md063_values.insert("style".to_string(), toml::Value::String(style.to_string()));
+        md063_values.insert("max-level".to_string(), toml::Value::Integer(2));
+        config.rules.insert(
+            "MD063".to_string(),
+            RuleConfig {
+                values: md063_values,
+                severity: None,
+            },
+        );
+        if md063_enabled {
+            config.global.extend_enable.push("MD063".to_string());
+        }
+        config
+    }
+
+    #[test]
+    fn test_uppercase_name_accepted_in_md063_all_caps_headings() {
+        let rule = MD044ProperNames::from_config(&config_with_md063_style("all-caps", true));
+        let content = "# USING GITHUB\n\n### GITHUB TIPS\n\nPush to GITHUB.\n";
+        let ctx = create_context(content);
+        let result = rule.check(&ctx).unwrap();
+        // Only the heading outside MD063's levels and the body text are flagged
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![3, 5], "{result:?}");
+
+        // Other casings in an ALL CAPS heading are still fixed to the canonical form
+        let ctx = create_context("# USING Github\n");
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].fix.as_ref().unwrap().replacement, "GitHub");
+    }
+
+    #[test]
+    fn test_uppercase_name_flagged_without_md063_all_caps() {
+        let content = "# USING GITHUB\n";
+        for config in [
+            config_with_md063_style("all-caps", false),
+            config_with_md063_style("title-case", true),
+        ] {
+            let rule = MD044ProperNames::from_config(&config);
+            let ctx = create_context(content);
+            assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+        }
+    }
 }
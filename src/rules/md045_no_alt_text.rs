@@ -1,4 +1,4 @@
-use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 pub(super) mod md045_config;
 use md045_config::MD045Config;
@@ -8,9 +8,11 @@ use md045_config::MD045Config;
 /// See [docs/md045.md](../../docs/md045.md) for full documentation, configuration, and examples.
 ///
 /// This rule is triggered when an image is missing alternate text (alt text).
-/// This rule is diagnostic-only — it does not offer auto-fix because meaningful
-/// alt text requires human judgment. Automated placeholders are harmful for
-/// accessibility (screen readers would read fabricated text to users).
+/// It does not offer an auto-fix because meaningful alt text requires human
+/// judgment — automated placeholders are harmful for accessibility (screen
+/// readers would read fabricated text to users). Instead each warning carries
+/// a suggested edit with a `TODO:` template that editors offer but `--fix`
+/// never applies.
 #[derive(Clone, Default)]
 pub struct MD045NoAltText;
 
@@ -24,6 +26,24 @@ impl MD045NoAltText {
     pub fn from_config_struct(_config: MD045Config) -> Self {
         Self
     }
+
+    /// Suggested edit filling the empty alt text of the image starting at
+    /// `byte_offset` with a `TODO:` template naming the image file
+    fn alt_text_suggestion(content: &str, byte_offset: usize, url: &str) -> Option<Fix> {
+        let image = content.get(byte_offset..)?.strip_prefix("![")?;
+        let alt_start = byte_offset + 2;
+        let alt_end = alt_start + image.find(']')?;
+
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let name = std::path::Path::new(path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .map(|stem| stem.replace(['-', '_'], " "))
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| "image".to_string());
+
+        Some(Fix::suggestion(alt_start..alt_end, format!("TODO: describe {name}")))
+    }
 }
 
 impl Rule for MD045NoAltText {
@@ -35,6 +55,10 @@ impl Rule for MD045NoAltText {
         "Images should have alternate text (alt text)"
     }
 
+    fn is_block_local(&self) -> bool {
+        true
+    }
+
     fn category(&self) -> RuleCategory {
         RuleCategory::Image
     }
@@ -47,7 +71,7 @@ impl Rule for MD045NoAltText {
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
-        for image in &ctx.images {
+        for image in ctx.images() {
             if image.alt_text.trim().is_empty() {
                 warnings.push(LintWarning {
                     rule_name: Some(self.name().to_string()),
@@ -58,7 +82,7 @@ impl Rule for MD045NoAltText {
                     message: "Image missing alt text (add description for accessibility: ![description](url))"
                         .to_string(),
                     severity: Severity::Error,
-                    fix: None,
+                    fix: Self::alt_text_suggestion(ctx.content, image.byte_offset, &image.url),
                 });
             }
         }
@@ -135,9 +159,31 @@ mod tests {
 
         assert_eq!(result.len(), 1);
         assert!(
-            result[0].fix.is_none(),
+            result[0].automatic_fix().is_none(),
             "MD045 should not offer auto-fix (alt text requires human judgment)"
         );
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
+    #[test]
+    fn test_suggests_alt_text_template() {
+        let rule = MD045NoAltText::new();
+        let content = "See ![ ](img/build-pipeline_v2.png?raw=true) and ![][logo]\n\n[logo]: logo.svg\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        assert_eq!(result.len(), 2);
+        let first = result[0]
+            .fix
+            .as_ref()
+            .expect("MD045 should suggest an alt text template");
+        assert!(!first.is_automatic());
+        assert_eq!(&content[first.range.clone()], " ");
+        assert_eq!(first.replacement, "TODO: describe build pipeline v2");
+
+        let second = result[1].fix.as_ref().unwrap();
+        assert_eq!(second.range.start, second.range.end);
+        assert!(second.replacement.starts_with("TODO: describe "));
     }
 
     #[test]
@@ -149,7 +195,7 @@ mod tests {
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].line, 1);
-        assert!(result[0].fix.is_none());
+        assert!(result[0].automatic_fix().is_none());
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::calculate_indentation_width_default;
 use crate::utils::mkdocs_admonitions;
 use crate::utils::mkdocs_tabs;
@@ -1229,6 +1229,10 @@ impl Rule for MD046CodeBlockStyle {
         RuleCategory::CodeBlock
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if content is empty or unlikely to contain code blocks
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 /// Rule MD047: File should end with a single newline
 ///
@@ -20,6 +20,10 @@ impl Rule for MD047SingleTrailingNewline {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip empty files - they don't need trailing newlines
         ctx.content.is_empty()
@@ -1,6 +1,7 @@
 use crate::rule::{CrossFileScope, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::anchor_styles::AnchorStyle;
+use crate::utils::slug::Slugify;
 use crate::workspace_index::{CrossFileLinkIndex, FileIndex, HeadingIndex};
 use pulldown_cmark::LinkType;
 use regex::Regex;
@@ -93,6 +94,9 @@ pub struct MD051LinkFragments {
     /// option, or if the pattern failed to compile (a `log::warn!` is emitted
     /// once at construction time so the user can fix the config).
     ignored_pattern_regex: Option<Regex>,
+    /// Heading slug algorithm, resolved from `anchor-style`, the global
+    /// `slugify` option and the flavor
+    slugify: Slugify,
 }
 
 /// Anchor sets extracted from a single document, with parallel lowercase and
@@ -144,6 +148,7 @@ impl MD051LinkFragments {
                 }
             });
         Self {
+            slugify: config.anchor_style.clone().into(),
             config,
             ignored_pattern_regex,
         }
@@ -302,7 +307,7 @@ impl MD051LinkFragments {
             HashSet::new()
         };
         let mut fragment_counts = std::collections::HashMap::new();
-        let use_underscore_dedup = self.config.anchor_style == AnchorStyle::PythonMarkdown;
+        let use_underscore_dedup = self.slugify.is_style(&AnchorStyle::PythonMarkdown);
 
         for line_info in &ctx.lines {
             if line_info.in_front_matter {
@@ -373,7 +378,7 @@ impl MD051LinkFragments {
             // because the regex operates on the full line, but they still generate valid anchors
             if line_info.heading.is_none()
                 && let Some(bq) = &line_info.blockquote
-                && let Some((clean_text, custom_id)) = Self::parse_blockquote_heading(&bq.content)
+                && let Some((clean_text, custom_id)) = Self::parse_blockquote_heading(bq.content(ctx.content))
             {
                 if let Some(id) = custom_id {
                     markdown_headings.insert(id.to_lowercase());
@@ -381,7 +386,7 @@ impl MD051LinkFragments {
                         markdown_headings_exact.insert(id);
                     }
                 }
-                let fragment = self.config.anchor_style.generate_fragment(&clean_text);
+                let fragment = self.slugify.slugify(&clean_text);
                 Self::insert_deduplicated_fragment(
                     fragment,
                     &mut fragment_counts,
@@ -394,17 +399,17 @@ impl MD051LinkFragments {
             // Extract markdown heading anchors
             if let Some(heading) = &line_info.heading {
                 // Custom ID from {#custom-id} syntax
-                if let Some(custom_id) = &heading.custom_id {
+                if let Some(custom_id) = heading.custom_id(ctx.content) {
                     markdown_headings.insert(custom_id.to_lowercase());
                     if track_exact {
-                        markdown_headings_exact.insert(custom_id.clone());
+                        markdown_headings_exact.insert(custom_id.to_string());
                     }
                 }
 
                 // Generate fragment directly from heading text
                 // Note: HTML stripping was removed because it interfered with arrow patterns
                 // like <-> and placeholders like <FILE>. The anchor styles handle these correctly.
-                let fragment = self.config.anchor_style.generate_fragment(&heading.text);
+                let fragment = self.slugify.slugify(heading.text(ctx.content));
 
                 Self::insert_deduplicated_fragment(
                     fragment,
@@ -608,7 +613,7 @@ impl Rule for MD051LinkFragments {
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
         let mut warnings = Vec::new();
 
-        if ctx.content.is_empty() || ctx.links.is_empty() || self.should_skip(ctx) {
+        if ctx.content.is_empty() || ctx.links().is_empty() || self.should_skip(ctx) {
             return Ok(warnings);
         }
 
@@ -620,7 +625,7 @@ impl Rule for MD051LinkFragments {
         } = self.extract_headings_from_context(ctx);
         let ignored_pattern = self.ignored_pattern_regex.as_ref();
 
-        for link in &ctx.links {
+        for link in ctx.links() {
             if link.is_reference {
                 continue;
             }
@@ -762,23 +767,12 @@ impl Rule for MD051LinkFragments {
     where
         Self: Sized,
     {
-        let mut rule_config = crate::rule_config_serde::load_rule_config::<MD051Config>(config);
-
-        // When no explicit anchor style is configured (the user didn't override the default),
-        // and a flavor is active, fall back to the flavor's native anchor generation.
-        let explicit_style_present = config
-            .rules
-            .get("MD051")
-            .is_some_and(|rc| rc.values.contains_key("anchor-style") || rc.values.contains_key("anchor_style"));
-        if !explicit_style_present {
-            rule_config.anchor_style = match config.global.flavor {
-                crate::config::MarkdownFlavor::MkDocs => AnchorStyle::PythonMarkdown,
-                crate::config::MarkdownFlavor::Kramdown => AnchorStyle::KramdownGfm,
-                _ => AnchorStyle::GitHub,
-            };
-        }
-
-        Box::new(MD051LinkFragments::from_config_struct(rule_config))
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD051Config>(config);
+        let slugify = Slugify::for_rule(config, "MD051", &rule_config.anchor_style);
+        Box::new(MD051LinkFragments {
+            slugify,
+            ..MD051LinkFragments::from_config_struct(rule_config)
+        })
     }
 
     fn category(&self) -> RuleCategory {
@@ -791,7 +785,7 @@ impl Rule for MD051LinkFragments {
 
     fn contribute_to_index(&self, ctx: &crate::lint_context::LintContext, file_index: &mut FileIndex) {
         let mut fragment_counts = HashMap::new();
-        let use_underscore_dedup = self.config.anchor_style == AnchorStyle::PythonMarkdown;
+        let use_underscore_dedup = self.slugify.is_style(&AnchorStyle::PythonMarkdown);
 
         // Extract headings, HTML anchors, and attribute anchors (for other files to reference)
         for (line_idx, line_info) in ctx.lines.iter().enumerate() {
@@ -844,9 +838,9 @@ impl Rule for MD051LinkFragments {
             // Extract heading anchors from blockquote content
             if line_info.heading.is_none()
                 && let Some(bq) = &line_info.blockquote
-                && let Some((clean_text, custom_id)) = Self::parse_blockquote_heading(&bq.content)
+                && let Some((clean_text, custom_id)) = Self::parse_blockquote_heading(bq.content(ctx.content))
             {
-                let fragment = self.config.anchor_style.generate_fragment(&clean_text);
+                let fragment = self.slugify.slugify(&clean_text);
                 Self::add_heading_to_index(
                     &fragment,
                     &clean_text,
@@ -860,12 +854,12 @@ impl Rule for MD051LinkFragments {
 
             // Extract heading anchors
             if let Some(heading) = &line_info.heading {
-                let fragment = self.config.anchor_style.generate_fragment(&heading.text);
+                let fragment = self.slugify.slugify(heading.text(ctx.content));
 
                 Self::add_heading_to_index(
                     &fragment,
-                    &heading.text,
-                    heading.custom_id.clone(),
+                    heading.text(ctx.content),
+                    heading.custom_id(ctx.content).map(str::to_string),
                     line_idx + 1,
                     &mut fragment_counts,
                     file_index,
@@ -886,7 +880,7 @@ impl Rule for MD051LinkFragments {
         }
 
         // Extract cross-file links (for validation against other files)
-        for link in &ctx.links {
+        for link in ctx.links() {
             if link.is_reference {
                 continue;
             }
@@ -982,18 +976,18 @@ impl Rule for MD051LinkFragments {
             let target_paths_to_try = Self::resolve_path_with_extensions(&base_target_path, MARKDOWN_EXTENSIONS);
 
             // Try to find the target file in the workspace index
-            let mut target_file_index = None;
-
-            for target_path in &target_paths_to_try {
-                if let Some(index) = workspace_index.get_file(target_path) {
-                    target_file_index = Some(index);
-                    break;
-                }
-            }
-
-            if let Some(target_file_index) = target_file_index {
-                // Check if the fragment matches any heading in the target file (O(1) lookup)
-                if !target_file_index.has_anchor_with_case(&cross_link.fragment, ignore_case) {
+            let target_path = target_paths_to_try
+                .iter()
+                .find(|target_path| workspace_index.get_file(target_path).is_some());
+
+            if let Some(target_path) = target_path {
+                // Check if the fragment matches any heading in the target file (O(1) lookup),
+                // including headings pulled in through MkDocs snippet includes
+                let found = workspace_index
+                    .snippet_closure(target_path)
+                    .iter()
+                    .any(|(_, index)| index.has_anchor_with_case(&cross_link.fragment, ignore_case));
+                if !found {
                     warnings.push(LintWarning {
                         rule_name: Some(self.name().to_string()),
                         line: cross_link.line,
@@ -1258,6 +1252,41 @@ See [link](#nonexistent) for details."#;
         assert!(warnings.is_empty());
     }
 
+    #[test]
+    fn test_cross_file_check_fragment_in_snippet_include() {
+        use crate::workspace_index::WorkspaceIndex;
+
+        let rule = MD051LinkFragments::new();
+
+        // The target page pulls its "Options" heading in from a snippet
+        let mut workspace_index = WorkspaceIndex::new();
+        let mut snippet_index = FileIndex::new();
+        snippet_index.add_heading(HeadingIndex {
+            text: "Options".to_string(),
+            auto_anchor: "options".to_string(),
+            custom_anchor: None,
+            line: 1,
+            is_setext: false,
+        });
+        workspace_index.insert_file(PathBuf::from("docs/_snippets/options.md"), snippet_index);
+        let mut target_file_index = FileIndex::new();
+        target_file_index.snippet_includes = vec![PathBuf::from("docs/_snippets/options.md")];
+        workspace_index.insert_file(PathBuf::from("docs/install.md"), target_file_index);
+
+        let mut current_file_index = FileIndex::new();
+        current_file_index.add_cross_file_link(CrossFileLinkIndex {
+            target_path: "install.md".to_string(),
+            fragment: "options".to_string(),
+            line: 3,
+            column: 5,
+        });
+
+        let warnings = rule
+            .cross_file_check(Path::new("docs/readme.md"), &current_file_index, &workspace_index)
+            .unwrap();
+        assert!(warnings.is_empty());
+    }
+
     #[test]
     fn test_cross_file_check_target_not_in_workspace() {
         use crate::workspace_index::WorkspaceIndex;
@@ -73,12 +73,15 @@ impl MD054LinkImageStyle {
                 shortcut,
                 url_inline,
                 preferred_style: PreferredStyles::default(),
+                link_style: None,
             },
         }
     }
 
     pub fn from_config_struct(config: MD054Config) -> Self {
-        Self { config }
+        Self {
+            config: config.resolved(),
+        }
     }
 
     /// Convert a byte offset to a 1-indexed character column within its line.
@@ -155,7 +158,7 @@ impl Rule for MD054LinkImageStyle {
         };
 
         // Process links from pre-parsed data
-        for link in &ctx.links {
+        for link in ctx.links() {
             // Skip broken references (empty URL means unresolved reference)
             if matches!(
                 link.link_type,
@@ -207,7 +210,7 @@ impl Rule for MD054LinkImageStyle {
         }
 
         // Process images from pre-parsed data
-        for image in &ctx.images {
+        for image in ctx.images() {
             // Skip broken references (empty URL means unresolved reference)
             if matches!(
                 image.link_type,
@@ -283,7 +286,7 @@ impl Rule for MD054LinkImageStyle {
         self
     }
 
-    crate::impl_rule_config_methods!(MD054Config);
+    crate::impl_rule_config_methods!(MD054Config, nullable);
 
     fn polymorphic_config_keys(&self) -> &'static [&'static str] {
         // `preferred-style` accepts either a scalar string or a list of strings.
@@ -892,13 +895,13 @@ mod fix_tests {
     fn assert_round_trip_clean(rule: &MD054LinkImageStyle, content: &str) -> String {
         let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
         let before_link_urls: Vec<String> = ctx
-            .links
+            .links()
             .iter()
             .map(|l| canonical_link_url(l.link_type, &l.url))
             .filter(|u| !u.is_empty())
             .collect();
         let before_image_urls: Vec<String> = ctx
-            .images
+            .images()
             .iter()
             .map(|i| canonical_link_url(i.link_type, &i.url))
             .filter(|u| !u.is_empty())
@@ -914,13 +917,13 @@ mod fix_tests {
         );
 
         let mut after_link_urls: Vec<String> = ctx2
-            .links
+            .links()
             .iter()
             .map(|l| canonical_link_url(l.link_type, &l.url))
             .filter(|u| !u.is_empty())
             .collect();
         let mut after_image_urls: Vec<String> = ctx2
-            .images
+            .images()
             .iter()
             .map(|i| canonical_link_url(i.link_type, &i.url))
             .filter(|u| !u.is_empty())
@@ -1337,6 +1340,44 @@ mod fix_tests {
         assert!(fixed.contains("[anchor]: https://example.com"), "got:\n{fixed}");
     }
 
+    #[test]
+    fn fix_link_style_reference_and_back() {
+        let content = "See [the docs](https://example.com/docs), [Docs](https://example.com/docs) and \
+                       [the docs](https://example.com/other).\n\n![logo](logo.png \"Logo\")\n";
+        let to_reference = MD054LinkImageStyle::from_config_struct(md054_config::MD054Config {
+            link_style: Some(md054_config::LinkStyle::Reference),
+            ..Default::default()
+        });
+        let fixed = assert_round_trip_clean(&to_reference, content);
+        // One definition per URL; a repeated text with another URL gets a fresh label
+        assert_eq!(fixed.matches("]: https://example.com/docs").count(), 1, "got:\n{fixed}");
+        assert!(fixed.contains("[the docs][the-docs]"), "got:\n{fixed}");
+        assert!(fixed.contains("[Docs][the-docs]"), "got:\n{fixed}");
+        assert!(
+            fixed.contains("[the-docs-2]: https://example.com/other"),
+            "got:\n{fixed}"
+        );
+        assert!(fixed.contains("![logo][logo]"), "got:\n{fixed}");
+
+        let to_inline = MD054LinkImageStyle::from_config_struct(md054_config::MD054Config {
+            link_style: Some(md054_config::LinkStyle::Inline),
+            ..Default::default()
+        });
+        let inlined = assert_round_trip_clean(&to_inline, &fixed);
+        assert!(inlined.starts_with(content.trim_end()), "got:\n{inlined}");
+    }
+
+    #[test]
+    fn fix_link_style_collapsed_falls_back_to_full() {
+        let rule = MD054LinkImageStyle::from_config_struct(md054_config::MD054Config {
+            link_style: Some(md054_config::LinkStyle::Collapsed),
+            ..Default::default()
+        });
+        let content = "[api](https://example.com/api) and [api](https://example.com/v2).\n";
+        let fixed = assert_round_trip_clean(&rule, content);
+        assert!(fixed.starts_with("[api][] and [api][api-2]."), "got:\n{fixed}");
+    }
+
     #[test]
     fn fix_inline_to_shortcut_emits_matching_ref_def() {
         let config = md054_config::MD054Config {
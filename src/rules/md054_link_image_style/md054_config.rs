@@ -93,6 +93,22 @@ impl Serialize for PreferredStyles {
     }
 }
 
+/// Shorthand for converting every link and image to one family of styles.
+///
+/// Setting `link-style` disallows the styles it replaces and pins the
+/// auto-fix target, so `--fix` rewrites the whole document in one pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkStyle {
+    /// `[text](url)`; reference links are inlined from their definitions
+    Inline,
+    /// `[text][label]` with a generated `[label]: url` definition
+    Reference,
+    /// `[text][]` with a `[text]: url` definition, falling back to
+    /// `[text][label]` when the text cannot serve as its own label
+    Collapsed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MD054Config {
     #[serde(default = "default_true")]
@@ -113,6 +129,10 @@ pub struct MD054Config {
     /// list semantics, including `auto` as a wildcard fallback entry.
     #[serde(default, rename = "preferred-style", alias = "preferred_style")]
     pub preferred_style: PreferredStyles,
+    /// Convert all links and images to one family of styles. Takes
+    /// precedence over `preferred-style`; see [`MD054Config::resolved`].
+    #[serde(default, rename = "link-style", alias = "link_style")]
+    pub link_style: Option<LinkStyle>,
 }
 
 impl Default for MD054Config {
@@ -125,10 +145,41 @@ impl Default for MD054Config {
             shortcut: true,
             url_inline: true,
             preferred_style: PreferredStyles::default(),
+            link_style: None,
         }
     }
 }
 
+impl MD054Config {
+    /// Apply `link-style`, if set: turn off the styles it replaces and pin the
+    /// fix target. Styles it keeps still follow their own options.
+    pub fn resolved(mut self) -> Self {
+        let Some(link_style) = self.link_style else {
+            return self;
+        };
+        match link_style {
+            LinkStyle::Inline => {
+                self.full = false;
+                self.collapsed = false;
+                self.shortcut = false;
+                self.preferred_style = PreferredStyles::single(PreferredStyle::Inline);
+            }
+            LinkStyle::Reference => {
+                self.inline = false;
+                self.url_inline = false;
+                self.preferred_style = PreferredStyles::single(PreferredStyle::Full);
+            }
+            LinkStyle::Collapsed => {
+                self.inline = false;
+                self.url_inline = false;
+                self.shortcut = false;
+                self.preferred_style = PreferredStyles(vec![PreferredStyle::Collapsed, PreferredStyle::Full]);
+            }
+        }
+        self
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -198,6 +249,23 @@ mod tests {
         assert_eq!(cfg.preferred_style.as_slice(), &[PreferredStyle::UrlInline]);
     }
 
+    #[test]
+    fn link_style_overrides_allowed_styles_and_target() {
+        let cfg = parse_toml("link-style = \"collapsed\"\nfull = false\npreferred-style = \"inline\"").resolved();
+        assert!(!cfg.inline && !cfg.url_inline && !cfg.shortcut && !cfg.full);
+        assert!(cfg.collapsed && cfg.autolink);
+        assert_eq!(
+            cfg.preferred_style.as_slice(),
+            &[PreferredStyle::Collapsed, PreferredStyle::Full]
+        );
+
+        let cfg = parse_toml(r#"link_style = "inline""#).resolved();
+        assert!(!cfg.full && !cfg.collapsed && !cfg.shortcut && cfg.inline);
+        assert_eq!(cfg.preferred_style.as_slice(), &[PreferredStyle::Inline]);
+
+        assert_eq!(MD054Config::default().resolved(), MD054Config::default());
+    }
+
     #[test]
     fn single_element_list_round_trips_as_scalar() {
         let cfg = MD054Config {
@@ -32,7 +32,7 @@ use crate::lint_context::LintContext;
 use crate::lint_context::types::{ParsedImage, ParsedLink};
 
 use super::label::{LabelChoice, LabelGenerator, normalize_label};
-use super::md054_config::{MD054Config, PreferredStyle, PreferredStyles};
+use super::md054_config::{MD054Config, PreferredStyle};
 
 /// One in-place edit applied to the document.
 #[derive(Debug, Clone)]
@@ -171,22 +171,20 @@ struct LinkFacts {
     has_title: bool,
 }
 
-/// Pick the target style for a given source.
+/// Candidate target styles for a given source, in preference order.
 ///
 /// `cfg.preferred_style` is an ordered list of `PreferredStyle` entries. Each
 /// entry expands to one or more concrete styles (a single style for explicit
-/// values, the source-aware default ordering for `Auto`). The first style that
-/// is allowed by the config *and* reachable from the source wins.
-fn target_style(source: Style, facts: LinkFacts, cfg: &MD054Config) -> Option<Style> {
-    pick_target(source, facts, &cfg.preferred_style, cfg)
-}
-
-fn pick_target(source: Style, facts: LinkFacts, prefs: &PreferredStyles, cfg: &MD054Config) -> Option<Style> {
-    prefs
+/// values, the source-aware default ordering for `Auto`). Only styles that are
+/// allowed by the config *and* reachable from the source are yielded. The
+/// planner uses the first one whose conversion can actually be built, so a
+/// collapsed target whose label collides falls through to the next entry.
+fn target_styles<'a>(source: Style, facts: LinkFacts, cfg: &'a MD054Config) -> impl Iterator<Item = Style> + 'a {
+    cfg.preferred_style
         .as_slice()
         .iter()
-        .flat_map(|entry| entry_candidates(*entry, source).iter().copied())
-        .find(|t| *t != source && t.allowed(cfg) && reachable(source, *t, facts))
+        .flat_map(move |entry| entry_candidates(*entry, source).iter().copied())
+        .filter(move |t| *t != source && t.allowed(cfg) && reachable(source, *t, facts))
 }
 
 /// Whether a (source → target) conversion is implemented, well-defined, and
@@ -250,7 +248,7 @@ pub(super) fn plan(ctx: &LintContext, cfg: &MD054Config) -> FixPlan {
     // the link between an edit and the ref def it requires.
     let mut pending: Vec<(SpanEdit, Option<RefDefInsert>)> = Vec::new();
 
-    for link in &ctx.links {
+    for link in ctx.links() {
         if skip_link(ctx, link.line) {
             continue;
         }
@@ -279,15 +277,14 @@ pub(super) fn plan(ctx: &LintContext, cfg: &MD054Config) -> FixPlan {
             is_image: false,
             has_title: link.title.is_some(),
         };
-        let Some(target) = target_style(source, facts, cfg) else {
-            continue;
-        };
-        if let Some((edit, new_ref)) = convert_link(content, link, source, target, &mut labels) {
+        let converted = target_styles(source, facts, cfg)
+            .find_map(|target| convert_link(content, link, source, target, &mut labels));
+        if let Some((edit, new_ref)) = converted {
             pending.push((edit, new_ref));
         }
     }
 
-    for image in &ctx.images {
+    for image in ctx.images() {
         if skip_link(ctx, image.line) {
             continue;
         }
@@ -310,10 +307,9 @@ pub(super) fn plan(ctx: &LintContext, cfg: &MD054Config) -> FixPlan {
             is_image: true,
             has_title: image.title.is_some(),
         };
-        let Some(target) = target_style(source, facts, cfg) else {
-            continue;
-        };
-        if let Some((edit, new_ref)) = convert_image(content, image, source, target, &mut labels) {
+        let converted = target_styles(source, facts, cfg)
+            .find_map(|target| convert_image(content, image, source, target, &mut labels));
+        if let Some((edit, new_ref)) = converted {
             pending.push((edit, new_ref));
         }
     }
@@ -332,7 +332,7 @@ impl Rule for MD055TablePipeStyle {
         };
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         // Process each table block
         for table_block in table_blocks {
@@ -893,7 +893,7 @@ mod tests {
     //
     // These tests document that MD055 does not flag Pandoc-specific constructs
     // (grid tables, multi-line tables, line blocks, pipe-table captions) because
-    // `ctx.table_blocks` excludes them at the source:
+    // `ctx.table_blocks()` excludes them at the source:
     //
     // - Grid table delimiters use `+---+---+` (no `|`), so `is_delimiter_row`
     //   returns false and no `TableBlock` is created.
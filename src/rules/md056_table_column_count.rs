@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::calculate_line_range;
 use crate::utils::table_utils::TableUtils;
 
@@ -135,6 +135,10 @@ impl Rule for MD056TableColumnCount {
         RuleCategory::Table
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if no tables present
         !ctx.likely_has_tables()
@@ -153,7 +157,7 @@ impl Rule for MD056TableColumnCount {
         let lines = ctx.raw_lines();
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             // Collect all table lines for building the whole-table fix
@@ -659,7 +663,7 @@ Some text in between.
     // === Pandoc construct reachability tests ===
     //
     // These tests document that MD056 does not flag Pandoc-specific constructs
-    // because `ctx.table_blocks` excludes them at the source:
+    // because `ctx.table_blocks()` excludes them at the source:
     //
     // - Grid table delimiters use `+---+---+` (no `|`), so `is_delimiter_row`
     //   returns false and no `TableBlock` is created.
@@ -352,6 +352,69 @@ impl MD057ExistingRelativeLinks {
         paths
     }
 
+    /// Filesystem paths whose existence decides this file's results.
+    ///
+    /// Lists where each local link and image target recorded in `index` is
+    /// looked up: the file's own directory, the search paths and, for
+    /// root-relative targets, the configured roots or docs directory. The lint
+    /// cache stores these with a file's warnings so that renaming or deleting
+    /// a target invalidates the cached results of the files linking to it.
+    pub fn target_paths(
+        &self,
+        source_file: &Path,
+        index: &FileIndex,
+        flavor: crate::config::MarkdownFlavor,
+    ) -> Vec<PathBuf> {
+        let resolved_file = source_file.canonicalize().unwrap_or_else(|_| source_file.to_path_buf());
+        let base_path = resolved_file
+            .parent()
+            .map_or_else(|| CURRENT_DIR.clone(), Path::to_path_buf);
+        let project_root = PROJECT_ROOT.clone();
+
+        let mut relative_dirs = vec![base_path.clone()];
+        relative_dirs.extend(self.compute_search_paths(flavor, Some(source_file), &base_path, &project_root));
+        let absolute_dirs: Vec<PathBuf> = match self.config.absolute_links {
+            AbsoluteLinksOption::RelativeToRoots => self
+                .config
+                .roots
+                .iter()
+                .map(|root| Self::resolve_against_project_root(root, &project_root))
+                .chain(std::iter::once(project_root.clone()))
+                .collect(),
+            AbsoluteLinksOption::RelativeToDocs => resolve_docs_dir(&base_path).into_iter().collect(),
+            AbsoluteLinksOption::Ignore | AbsoluteLinksOption::Warn => Vec::new(),
+        };
+
+        let relative_targets = index
+            .cross_file_links
+            .iter()
+            .map(|link| Self::url_decode(&link.target_path))
+            .chain(index.asset_references.iter().filter(|r| !r.starts_with('/')).cloned());
+        let absolute_targets = index
+            .root_relative_links
+            .iter()
+            .map(|link| Self::url_decode(&link.target_path))
+            .chain(
+                index
+                    .asset_references
+                    .iter()
+                    .filter_map(|r| r.strip_prefix('/'))
+                    .map(str::to_string),
+            );
+
+        let mut paths: Vec<PathBuf> = relative_targets
+            .flat_map(|target| relative_dirs.iter().map(move |dir| dir.join(&target)))
+            .chain(
+                absolute_targets
+                    .filter(|target| !target.is_empty())
+                    .flat_map(|target| absolute_dirs.iter().map(move |dir| dir.join(&target))),
+            )
+            .collect();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
     /// Check if a link target exists in any of the additional search paths.
     fn exists_in_search_paths(decoded_path: &str, search_paths: &[PathBuf]) -> bool {
         search_paths.iter().any(|dir| {
@@ -627,7 +690,7 @@ impl Rule for MD057ExistingRelativeLinks {
             self.compute_search_paths(ctx.flavor, ctx.source_file.as_deref(), &base_path, &project_root);
 
         // Use LintContext links instead of expensive regex parsing
-        if !ctx.links.is_empty() {
+        if !ctx.links().is_empty() {
             // Use LineIndex for correct position calculation across all line ending types
             let line_index = &ctx.line_index;
 
@@ -635,10 +698,10 @@ impl Rule for MD057ExistingRelativeLinks {
             let lines = ctx.raw_lines();
 
             // Track which lines we've already processed to avoid duplicates
-            // (ctx.links may have multiple entries for the same line, especially with malformed markdown)
+            // (ctx.links() may have multiple entries for the same line, especially with malformed markdown)
             let mut processed_lines = std::collections::HashSet::new();
 
-            for link in &ctx.links {
+            for link in ctx.links() {
                 let line_idx = link.line - 1;
                 if line_idx >= lines.len() {
                     continue;
@@ -855,7 +918,7 @@ impl Rule for MD057ExistingRelativeLinks {
         }
 
         // Also process images - they have URLs already parsed
-        for image in &ctx.images {
+        for image in ctx.images() {
             // Skip images inside PyMdown blocks (MkDocs flavor)
             if ctx.line_info(image.line).is_some_and(|info| info.in_pymdown_block) {
                 continue;
@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::kramdown_utils::is_kramdown_block_attribute;
 use serde::{Deserialize, Serialize};
@@ -106,6 +106,10 @@ impl Rule for MD058BlanksAroundTables {
         RuleCategory::Table
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Skip if no tables present
         !ctx.likely_has_tables()
@@ -124,7 +128,7 @@ impl Rule for MD058BlanksAroundTables {
         let lines = ctx.raw_lines();
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             // Check for sufficient blank lines before table
@@ -1322,7 +1326,7 @@ Final paragraph.";
     // === Pandoc construct reachability tests ===
     //
     // These tests document that MD058 does not flag Pandoc-specific constructs
-    // because `ctx.table_blocks` excludes them at the source:
+    // because `ctx.table_blocks()` excludes them at the source:
     //
     // - Grid table delimiters use `+---+---+` (no `|`), so `is_delimiter_row`
     //   returns false and no `TableBlock` is created.
@@ -1,9 +1,9 @@
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
+use crate::types::AmbiguousWidth;
 use crate::utils::range_utils::calculate_line_range;
 use crate::utils::regex_cache::BLOCKQUOTE_PREFIX_RE;
 use crate::utils::table_utils::TableUtils;
-use unicode_width::UnicodeWidthStr;
 
 mod md060_config;
 use crate::md013_line_length::MD013Config;
@@ -48,6 +48,8 @@ struct RowFormatOptions {
     column_align_header: Option<ColumnAlign>,
     /// Body-specific column alignment (overrides column_align for body)
     column_align_body: Option<ColumnAlign>,
+    /// Display width of East Asian ambiguous-width characters
+    ambiguous_width: AmbiguousWidth,
 }
 
 /// Rule MD060: Table Column Alignment
@@ -250,9 +252,9 @@ impl MD060TableFormat {
             || text.contains('\u{2060}') // Word Joiner
     }
 
-    fn calculate_cell_display_width(cell_content: &str) -> usize {
+    fn calculate_cell_display_width(cell_content: &str, ambiguous: AmbiguousWidth) -> usize {
         let masked = TableUtils::mask_pipes_in_inline_code(cell_content);
-        masked.trim().width()
+        ambiguous.width(masked.trim())
     }
 
     /// Parse a table row into cells using Standard flavor (default behavior).
@@ -314,6 +316,7 @@ impl MD060TableFormat {
         table_lines: &[&str],
         flavor: crate::config::MarkdownFlavor,
         loose_last_column: bool,
+        ambiguous: AmbiguousWidth,
     ) -> Vec<usize> {
         let mut column_widths = Vec::new();
         let mut delimiter_cells: Option<Vec<String>> = None;
@@ -331,7 +334,7 @@ impl MD060TableFormat {
             }
 
             for (i, cell) in cells.iter().enumerate() {
-                let width = Self::calculate_cell_display_width(cell);
+                let width = Self::calculate_cell_display_width(cell, ambiguous);
                 if i >= column_widths.len() {
                     column_widths.push(width);
                 } else {
@@ -342,7 +345,7 @@ impl MD060TableFormat {
             // Record the header row's last column width
             if is_header && !cells.is_empty() {
                 let last_idx = cells.len() - 1;
-                header_last_col_width = Some(Self::calculate_cell_display_width(&cells[last_idx]));
+                header_last_col_width = Some(Self::calculate_cell_display_width(&cells[last_idx], ambiguous));
                 is_header = false;
             }
         }
@@ -429,7 +432,7 @@ impl MD060TableFormat {
                     }
                     RowType::Header | RowType::Body => {
                         let trimmed = cell.trim();
-                        let current_width = Self::calculate_cell_display_width(cell);
+                        let current_width = Self::calculate_cell_display_width(cell, options.ambiguous_width);
                         let padding = target_width.saturating_sub(current_width);
 
                         // Determine which alignment to use based on row type
@@ -531,10 +534,10 @@ impl MD060TableFormat {
 
     /// Returns display widths of each header cell's trimmed content.
     /// Used when `aligned_delimiter` is on to size the delimiter row.
-    fn header_cell_widths(header_cells: &[String]) -> Vec<usize> {
+    fn header_cell_widths(header_cells: &[String], ambiguous: AmbiguousWidth) -> Vec<usize> {
         header_cells
             .iter()
-            .map(|c| Self::calculate_cell_display_width(c))
+            .map(|c| Self::calculate_cell_display_width(c, ambiguous))
             .collect()
     }
 
@@ -553,6 +556,7 @@ impl MD060TableFormat {
         table_lines: &[&str],
         flavor: crate::config::MarkdownFlavor,
         compact_delimiter: bool,
+        ambiguous: AmbiguousWidth,
     ) -> bool {
         if table_lines.len() < 2 {
             return false;
@@ -561,11 +565,8 @@ impl MD060TableFormat {
         // Check 1: All rows must have the same display width
         // Use .width() instead of .len() to handle CJK characters correctly
         // (CJK chars are 3 bytes but 2 display columns)
-        let first_width = UnicodeWidthStr::width(table_lines[0]);
-        if !table_lines
-            .iter()
-            .all(|line| UnicodeWidthStr::width(*line) == first_width)
-        {
+        let first_width = ambiguous.width(table_lines[0]);
+        if !table_lines.iter().all(|line| ambiguous.width(line) == first_width) {
             return false;
         }
 
@@ -627,7 +628,7 @@ impl MD060TableFormat {
                     continue;
                 }
                 if let Some(cell) = row.get(col_idx) {
-                    widths.push(cell.width());
+                    widths.push(ambiguous.width(cell));
                 }
             }
             // All content cells in this column should have the same display width
@@ -750,6 +751,7 @@ impl MD060TableFormat {
         let mut result = Vec::new();
         let mut auto_compacted = false;
         let mut aligned_width = None;
+        let ambiguous = self.md013_config.ambiguous_width;
 
         let table_lines: Vec<&str> = std::iter::once(lines[table_block.header_line])
             .chain(std::iter::once(lines[table_block.delimiter_line]))
@@ -825,8 +827,12 @@ impl MD060TableFormat {
                         "tight" => result.push(Self::format_table_tight(&cells)),
                         "compact" => result.push(Self::format_table_compact(&cells)),
                         _ => {
-                            let column_widths =
-                                Self::calculate_column_widths(&stripped_lines, flavor, self.config.loose_last_column);
+                            let column_widths = Self::calculate_column_widths(
+                                &stripped_lines,
+                                flavor,
+                                self.config.loose_last_column,
+                                ambiguous,
+                            );
                             let row_type = match row_idx {
                                 0 => RowType::Header,
                                 1 => RowType::Delimiter,
@@ -838,6 +844,7 @@ impl MD060TableFormat {
                                 column_align: self.config.column_align,
                                 column_align_header: self.config.column_align_header,
                                 column_align_body: self.config.column_align_body,
+                                ambiguous_width: ambiguous,
                             };
                             result.push(Self::format_table_row(
                                 &cells,
@@ -853,7 +860,7 @@ impl MD060TableFormat {
                 let compact = style == "compact";
                 let header_widths = if self.config.aligned_delimiter && stripped_lines.len() >= 2 {
                     let header_cells = Self::parse_table_row_with_flavor(stripped_lines[0], flavor);
-                    Some(Self::header_cell_widths(&header_cells))
+                    Some(Self::header_cell_widths(&header_cells, ambiguous))
                 } else {
                     None
                 };
@@ -883,7 +890,9 @@ impl MD060TableFormat {
                     || self.config.column_align_body.is_some()
                     || self.config.loose_last_column;
 
-                if !needs_reformat && Self::is_table_already_aligned(&stripped_lines, flavor, compact_delimiter) {
+                if !needs_reformat
+                    && Self::is_table_already_aligned(&stripped_lines, flavor, compact_delimiter, ambiguous)
+                {
                     return TableFormatResult {
                         lines: table_lines.iter().map(std::string::ToString::to_string).collect(),
                         auto_compacted: false,
@@ -892,11 +901,40 @@ impl MD060TableFormat {
                 }
 
                 let column_widths =
-                    Self::calculate_column_widths(&stripped_lines, flavor, self.config.loose_last_column);
+                    Self::calculate_column_widths(&stripped_lines, flavor, self.config.loose_last_column, ambiguous);
 
-                // Calculate aligned table width: 1 (leading pipe) + num_columns * 3 (| cell |) + sum(column_widths)
-                let num_columns = column_widths.len();
-                let calc_aligned_width = 1 + (num_columns * 3) + column_widths.iter().sum::<usize>();
+                // Parse column alignments from delimiter row (always at index 1)
+                let delimiter_cells = Self::parse_table_row_with_flavor(stripped_lines[1], flavor);
+                let column_alignments = Self::parse_column_alignments(&delimiter_cells);
+
+                let aligned_rows: Vec<String> = stripped_lines
+                    .iter()
+                    .enumerate()
+                    .map(|(row_idx, line)| {
+                        let cells = Self::parse_table_row_with_flavor(line, flavor);
+                        let row_type = match row_idx {
+                            0 => RowType::Header,
+                            1 => RowType::Delimiter,
+                            _ => RowType::Body,
+                        };
+                        let options = RowFormatOptions {
+                            row_type,
+                            compact_delimiter,
+                            column_align: self.config.column_align,
+                            column_align_header: self.config.column_align_header,
+                            column_align_body: self.config.column_align_body,
+                            ambiguous_width: ambiguous,
+                        };
+                        Self::format_table_row(&cells, &column_widths, &column_alignments, &options)
+                    })
+                    .collect();
+
+                // Width of the widest aligned row, measured like MD013 measures lines
+                let calc_aligned_width = aligned_rows
+                    .iter()
+                    .map(|row| self.md013_config.measure(row))
+                    .max()
+                    .unwrap_or(0);
                 aligned_width = Some(calc_aligned_width);
 
                 // Auto-compact: if aligned table exceeds max width, use compact formatting instead.
@@ -907,7 +945,7 @@ impl MD060TableFormat {
                     auto_compacted = true;
                     let header_widths = if self.config.aligned_delimiter && stripped_lines.len() >= 2 {
                         let header_cells = Self::parse_table_row_with_flavor(stripped_lines[0], flavor);
-                        Some(Self::header_cell_widths(&header_cells))
+                        Some(Self::header_cell_widths(&header_cells, ambiguous))
                     } else {
                         None
                     };
@@ -923,31 +961,7 @@ impl MD060TableFormat {
                         result.push(Self::format_table_compact(&cells));
                     }
                 } else {
-                    // Parse column alignments from delimiter row (always at index 1)
-                    let delimiter_cells = Self::parse_table_row_with_flavor(stripped_lines[1], flavor);
-                    let column_alignments = Self::parse_column_alignments(&delimiter_cells);
-
-                    for (row_idx, line) in stripped_lines.iter().enumerate() {
-                        let cells = Self::parse_table_row_with_flavor(line, flavor);
-                        let row_type = match row_idx {
-                            0 => RowType::Header,
-                            1 => RowType::Delimiter,
-                            _ => RowType::Body,
-                        };
-                        let options = RowFormatOptions {
-                            row_type,
-                            compact_delimiter,
-                            column_align: self.config.column_align,
-                            column_align_header: self.config.column_align_header,
-                            column_align_body: self.config.column_align_body,
-                        };
-                        result.push(Self::format_table_row(
-                            &cells,
-                            &column_widths,
-                            &column_alignments,
-                            &options,
-                        ));
-                    }
+                    result = aligned_rows;
                 }
             }
             _ => {
@@ -999,6 +1013,10 @@ impl Rule for MD060TableFormat {
         RuleCategory::Table
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         !ctx.likely_has_tables()
     }
@@ -1008,7 +1026,7 @@ impl Rule for MD060TableFormat {
         let mut warnings = Vec::new();
 
         let lines = ctx.raw_lines();
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             let format_result = self.fix_table_block(lines, table_block, ctx.flavor);
@@ -1080,7 +1098,7 @@ impl Rule for MD060TableFormat {
     fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, LintError> {
         let content = ctx.content;
         let lines = ctx.raw_lines();
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         let mut result_lines: Vec<String> = lines.iter().map(|&s| s.to_string()).collect();
 
@@ -1128,7 +1146,8 @@ impl Rule for MD060TableFormat {
         Self: Sized,
     {
         let rule_config = crate::rule_config_serde::load_rule_config::<MD060Config>(config);
-        let md013_config = crate::rule_config_serde::load_rule_config::<MD013Config>(config);
+        let mut md013_config = crate::rule_config_serde::load_rule_config::<MD013Config>(config);
+        md013_config.inherit_global_length_settings(config);
 
         // Check if MD013 is globally disabled
         let md013_disabled = config.global.disable.iter().any(|r| r == "MD013");
@@ -1152,6 +1171,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_md060_ambiguous_width_wide_pads_for_two_columns() {
+        let md013_config = MD013Config {
+            ambiguous_width: AmbiguousWidth::Wide,
+            ..Default::default()
+        };
+        let config = MD060Config {
+            enabled: true,
+            style: "aligned".to_string(),
+            ..Default::default()
+        };
+        let rule = MD060TableFormat::from_config_struct(config, md013_config, false);
+
+        let content = "| A | B |\n|---|---|\n| ±… | x |";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "| A    | B   |\n| ---- | --- |\n| ±… | x   |");
+    }
+
+    #[test]
+    fn test_md060_max_width_uses_md013_length_mode() {
+        // Aligned, the rows are 19 columns wide but the last one is 25 bytes long
+        let content = "| 名前 | 年齢 |\n|---|---|\n| 山田太郎 | 三十 |";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let config = MD060Config {
+            enabled: true,
+            style: "aligned".to_string(),
+            ..Default::default()
+        };
+
+        let visual = MD060TableFormat::from_config_struct(config.clone(), md013_with_line_length(20), false);
+        assert_eq!(
+            visual.fix(&ctx).unwrap(),
+            "| 名前     | 年齢 |\n| -------- | ---- |\n| 山田太郎 | 三十 |"
+        );
+
+        let bytes_config = MD013Config {
+            length_mode: crate::types::LengthMode::Bytes,
+            ..md013_with_line_length(20)
+        };
+        let bytes = MD060TableFormat::from_config_struct(config, bytes_config, false);
+        assert_eq!(
+            bytes.fix(&ctx).unwrap(),
+            "| 名前 | 年齢 |\n| --- | --- |\n| 山田太郎 | 三十 |"
+        );
+    }
+
     #[test]
     fn test_md060_align_simple_ascii_table() {
         let rule = MD060TableFormat::new(true, "aligned".to_string());
@@ -1182,8 +1248,8 @@ mod tests {
         let cells_line1 = MD060TableFormat::parse_table_row(lines[0]);
         let cells_line3 = MD060TableFormat::parse_table_row(lines[2]);
 
-        let width1 = MD060TableFormat::calculate_cell_display_width(&cells_line1[0]);
-        let width3 = MD060TableFormat::calculate_cell_display_width(&cells_line3[0]);
+        let width1 = MD060TableFormat::calculate_cell_display_width(&cells_line1[0], AmbiguousWidth::Narrow);
+        let width3 = MD060TableFormat::calculate_cell_display_width(&cells_line3[0], AmbiguousWidth::Narrow);
 
         assert_eq!(width1, width3);
     }
@@ -2121,8 +2187,12 @@ mod tests {
         let table_lines = vec!["| 名前 | Age |", "|------|-----|", "| 田中 | 25  |"];
 
         // First check is raw line length equality (byte-based), which fails
-        let is_aligned =
-            MD060TableFormat::is_table_already_aligned(&table_lines, crate::config::MarkdownFlavor::Standard, false);
+        let is_aligned = MD060TableFormat::is_table_already_aligned(
+            &table_lines,
+            crate::config::MarkdownFlavor::Standard,
+            false,
+            AmbiguousWidth::Narrow,
+        );
         assert!(
             !is_aligned,
             "Table with uneven raw line lengths should NOT be considered aligned"
@@ -2133,18 +2203,18 @@ mod tests {
     fn test_cjk_width_calculation_in_aligned_check() {
         // calculate_cell_display_width trims content before calculating width
         // Verify CJK width is correctly calculated (2 per character)
-        let cjk_width = MD060TableFormat::calculate_cell_display_width("名前");
+        let cjk_width = MD060TableFormat::calculate_cell_display_width("名前", AmbiguousWidth::Narrow);
         assert_eq!(cjk_width, 4, "Two CJK characters should have display width 4");
 
-        let ascii_width = MD060TableFormat::calculate_cell_display_width("Age");
+        let ascii_width = MD060TableFormat::calculate_cell_display_width("Age", AmbiguousWidth::Narrow);
         assert_eq!(ascii_width, 3, "Three ASCII characters should have display width 3");
 
         // Test that spacing is trimmed before width calculation
-        let padded_cjk = MD060TableFormat::calculate_cell_display_width(" 名前 ");
+        let padded_cjk = MD060TableFormat::calculate_cell_display_width(" 名前 ", AmbiguousWidth::Narrow);
         assert_eq!(padded_cjk, 4, "Padded CJK should have same width after trim");
 
         // Test mixed content
-        let mixed = MD060TableFormat::calculate_cell_display_width(" 日本語ABC ");
+        let mixed = MD060TableFormat::calculate_cell_display_width(" 日本語ABC ", AmbiguousWidth::Narrow);
         // 3 CJK chars (width 6) + 3 ASCII (width 3) = 9
         assert_eq!(mixed, 9, "Mixed CJK/ASCII content");
     }
@@ -2577,7 +2647,12 @@ style = "aligned"
             "| 你好   | Test |",
         ];
 
-        let result = MD060TableFormat::is_table_already_aligned(&table_lines, MarkdownFlavor::Standard, false);
+        let result = MD060TableFormat::is_table_already_aligned(
+            &table_lines,
+            MarkdownFlavor::Standard,
+            false,
+            AmbiguousWidth::Narrow,
+        );
         assert!(
             result,
             "Table with CJK characters that is display-aligned should be recognized as aligned"
@@ -2600,7 +2675,7 @@ style = "aligned"
     // === Pandoc construct reachability tests ===
     //
     // These tests document that MD060 does not flag Pandoc-specific constructs
-    // because `ctx.table_blocks` excludes them at the source:
+    // because `ctx.table_blocks()` excludes them at the source:
     //
     // - Grid table delimiters use `+---+---+` (no `|`), so `is_delimiter_row`
     //   returns false and no `TableBlock` is created.
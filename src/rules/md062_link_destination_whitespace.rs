@@ -1,5 +1,5 @@
 use crate::lint_context::LintContext;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use pulldown_cmark::LinkType;
 
 /// Describes what type of whitespace issue was found
@@ -205,6 +205,10 @@ impl Rule for MD062LinkDestinationWhitespace {
         RuleCategory::Link
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &LintContext) -> bool {
         ctx.content.is_empty() || !ctx.likely_has_links_or_images()
     }
@@ -213,7 +217,7 @@ impl Rule for MD062LinkDestinationWhitespace {
         let mut warnings = Vec::new();
 
         // Process links
-        for link in &ctx.links {
+        for link in ctx.links() {
             // Only check inline links, not reference links
             if link.is_reference || !matches!(link.link_type, LinkType::Inline) {
                 continue;
@@ -246,7 +250,7 @@ impl Rule for MD062LinkDestinationWhitespace {
         }
 
         // Process images
-        for image in &ctx.images {
+        for image in ctx.images() {
             // Only check inline images, not reference images
             if image.is_reference || !matches!(image.link_type, LinkType::Inline) {
                 continue;
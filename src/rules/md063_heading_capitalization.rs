@@ -11,10 +11,13 @@
 /// enabled = true
 /// style = "title_case"
 /// ```
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::proper_names;
 use crate::utils::range_utils::{LineIndex, byte_to_char_count};
+use crate::utils::table_utils::TableUtils;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ops::Range;
 use std::sync::LazyLock;
 
@@ -101,113 +104,16 @@ impl MD063HeadingCapitalization {
         }
     }
 
-    /// Match `pattern_lower` at `start` in `text` using Unicode-aware lowercasing.
-    /// Returns the end byte offset in `text` when the match succeeds.
-    ///
-    /// This avoids converting the full `text` to lowercase and then reusing those
-    /// offsets on the original string, which can panic for case-fold expansions
-    /// (e.g. `İ` -> `i̇`).
-    fn match_case_insensitive_at(text: &str, start: usize, pattern_lower: &str) -> Option<usize> {
-        if start > text.len() || !text.is_char_boundary(start) || pattern_lower.is_empty() {
-            return None;
-        }
-
-        let mut matched_bytes = 0;
-
-        for (offset, ch) in text[start..].char_indices() {
-            if matched_bytes >= pattern_lower.len() {
-                break;
-            }
-
-            let lowered: String = ch.to_lowercase().collect();
-            if !pattern_lower[matched_bytes..].starts_with(&lowered) {
-                return None;
-            }
-
-            matched_bytes += lowered.len();
-
-            if matched_bytes == pattern_lower.len() {
-                return Some(start + offset + ch.len_utf8());
-            }
-        }
-
-        None
-    }
-
-    /// Find the next case-insensitive match of `pattern_lower` in `text`,
-    /// returning byte offsets in the ORIGINAL string.
-    fn find_case_insensitive_match(text: &str, pattern_lower: &str, search_start: usize) -> Option<(usize, usize)> {
-        if pattern_lower.is_empty() || search_start >= text.len() || !text.is_char_boundary(search_start) {
-            return None;
-        }
-
-        for (offset, _) in text[search_start..].char_indices() {
-            let start = search_start + offset;
-            if let Some(end) = Self::match_case_insensitive_at(text, start, pattern_lower) {
-                return Some((start, end));
-            }
-        }
-
-        None
+    /// Keep these multi-word proper names (from MD044) intact under sentence case
+    pub(crate) fn with_proper_names(mut self, names: Vec<String>) -> Self {
+        self.proper_names = names;
+        self
     }
 
-    /// Build a map from word byte-position → canonical form for all proper names
-    /// that appear in the heading text (case-insensitive phrase match).
-    ///
-    /// This is used in `apply_sentence_case` so that words belonging to a proper
-    /// name phrase are never lowercased to begin with.
-    fn proper_name_canonical_forms(&self, text: &str) -> std::collections::HashMap<usize, &str> {
-        let mut map = std::collections::HashMap::new();
-
-        for name in &self.proper_names {
-            if name.is_empty() {
-                continue;
-            }
-            let name_lower = name.to_lowercase();
-            let canonical_words: Vec<&str> = name.split_whitespace().collect();
-            if canonical_words.is_empty() {
-                continue;
-            }
-            let mut search_start = 0;
-
-            while search_start < text.len() {
-                let Some((abs_pos, end_pos)) = Self::find_case_insensitive_match(text, &name_lower, search_start)
-                else {
-                    break;
-                };
-
-                // Require word boundaries
-                let before_ok = abs_pos == 0 || !text[..abs_pos].chars().last().is_some_and(char::is_alphanumeric);
-                let after_ok =
-                    end_pos >= text.len() || !text[end_pos..].chars().next().is_some_and(char::is_alphanumeric);
-
-                if before_ok && after_ok {
-                    // Map each word in the matched region to its canonical form.
-                    // We zip the words found in the text slice with the words of the
-                    // canonical name so that every word gets the right casing.
-                    let text_slice = &text[abs_pos..end_pos];
-                    let mut word_idx = 0;
-                    let mut slice_offset = 0;
-
-                    for text_word in text_slice.split_whitespace() {
-                        if let Some(w_rel) = text_slice[slice_offset..].find(text_word) {
-                            let word_abs = abs_pos + slice_offset + w_rel;
-                            if let Some(&canonical_word) = canonical_words.get(word_idx) {
-                                map.insert(word_abs, canonical_word);
-                            }
-                            slice_offset += w_rel + text_word.len();
-                            word_idx += 1;
-                        }
-                    }
-                }
-
-                // Advance by one Unicode scalar value to allow overlapping matches
-                // while staying on a UTF-8 char boundary.
-                search_start = abs_pos + text[abs_pos..].chars().next().map_or(1, char::len_utf8);
-            }
-        }
-
-        map
+    /// Map word byte-positions in `text` to the canonical form of the MD044
+    /// proper name they belong to, so those words are never recased.
+    fn proper_name_canonical_forms(&self, text: &str) -> HashMap<usize, &str> {
+        proper_names::canonical_forms(text, &self.proper_names)
     }
 
     /// Check if a word has internal capitals (like "iPhone", "macOS", "GitHub", "iOS")
@@ -368,24 +274,6 @@ impl MD063HeadingCapitalization {
         self.capitalize_first(word)
     }
 
-    /// Apply canonical proper-name casing while preserving any trailing punctuation
-    /// attached to the original whitespace token (e.g. `javascript,` -> `JavaScript,`).
-    fn apply_canonical_form_to_word(word: &str, canonical: &str) -> String {
-        let canonical_lower = canonical.to_lowercase();
-        if canonical_lower.is_empty() {
-            return canonical.to_string();
-        }
-
-        if let Some(end_pos) = Self::match_case_insensitive_at(word, 0, &canonical_lower) {
-            let mut out = String::with_capacity(canonical.len() + word.len().saturating_sub(end_pos));
-            out.push_str(canonical);
-            out.push_str(&word[end_pos..]);
-            out
-        } else {
-            canonical.to_string()
-        }
-    }
-
     /// Capitalize the first letter of a word, handling Unicode properly
     fn capitalize_first(&self, word: &str) -> String {
         if word.is_empty() {
@@ -484,7 +372,7 @@ impl MD063HeadingCapitalization {
 
                 // Words that are part of an MD044 proper name use the canonical form directly.
                 if let Some(&canonical) = word_positions.get(i).and_then(|&p| canonical_forms.get(&p)) {
-                    return Self::apply_canonical_form_to_word(word, canonical);
+                    return proper_names::apply_canonical_form(word, canonical);
                 }
 
                 // Preserve words in ignore list or with internal capitals
@@ -545,7 +433,7 @@ impl MD063HeadingCapitalization {
                 // Words that are part of an MD044 proper name use the canonical form
                 // directly, bypassing sentence-case lowercasing entirely.
                 if let Some(&canonical) = canonical_forms.get(&abs_pos) {
-                    result.push_str(&Self::apply_canonical_form_to_word(word, canonical));
+                    result.push_str(&proper_names::apply_canonical_form(word, canonical));
                     is_first_word = false;
                 } else if is_first_word {
                     // Check if word should be preserved BEFORE any capitalization
@@ -601,10 +489,15 @@ impl MD063HeadingCapitalization {
                 // Preserve whitespace before this word
                 result.push_str(&text[current_pos..abs_pos]);
 
-                // Words that are part of an MD044 proper name use the canonical form directly.
-                // This prevents oscillation with MD044 when all-caps style is active.
+                // Words that are part of an MD044 proper name keep either their uppercase
+                // or their canonical form; anything else is fixed to the canonical form,
+                // which is what MD044 would write as well.
                 if let Some(&canonical) = canonical_forms.get(&abs_pos) {
-                    result.push_str(&Self::apply_canonical_form_to_word(word, canonical));
+                    if proper_names::is_uppercase_form(word, canonical) {
+                        result.push_str(word);
+                    } else {
+                        result.push_str(&proper_names::apply_canonical_form(word, canonical));
+                    }
                 } else if self.should_preserve_word(word) {
                     result.push_str(word);
                 } else {
@@ -716,7 +609,9 @@ impl MD063HeadingCapitalization {
     }
 
     /// Apply capitalization to heading text
-    fn apply_capitalization(&self, text: &str) -> String {
+    ///
+    /// Also used by MD085 for admonition and callout titles.
+    pub(crate) fn apply_capitalization(&self, text: &str) -> String {
         // Strip custom ID if present and re-add later
         let (main_text, custom_id) = if let Some(mat) = CUSTOM_ID_REGEX.find(text) {
             (&text[..mat.start()], Some(mat.as_str()))
@@ -851,7 +746,7 @@ impl MD063HeadingCapitalization {
 
                 // Words that are part of an MD044 proper name use the canonical form directly.
                 if let Some(&canonical) = word_positions.get(i).and_then(|&p| canonical_forms.get(&p)) {
-                    return Self::apply_canonical_form_to_word(word, canonical);
+                    return proper_names::apply_canonical_form(word, canonical);
                 }
 
                 // Handle hyphenated words
@@ -906,7 +801,7 @@ impl MD063HeadingCapitalization {
 
                 // Words that are part of an MD044 proper name use the canonical form directly.
                 if let Some(&canonical) = canonical_forms.get(&abs_pos) {
-                    result.push_str(&Self::apply_canonical_form_to_word(word, canonical));
+                    result.push_str(&proper_names::apply_canonical_form(word, canonical));
                 } else if self.should_preserve_word(word) {
                     result.push_str(word);
                 } else {
@@ -925,6 +820,67 @@ impl MD063HeadingCapitalization {
         result
     }
 
+    fn style_name(&self) -> &'static str {
+        match self.config.style {
+            HeadingCapStyle::TitleCase => "title case",
+            HeadingCapStyle::SentenceCase => "sentence case",
+            HeadingCapStyle::AllCaps => "ALL CAPS",
+        }
+    }
+
+    /// Warning for heading-like text outside a heading, fixed in place
+    fn text_warning(&self, ctx: &LintContext, line_num: usize, range: Range<usize>, kind: &str) -> Option<LintWarning> {
+        let original = &ctx.content[range.clone()];
+        let fixed = self.apply_capitalization(original);
+        if original == fixed {
+            return None;
+        }
+        let line_info = ctx.line_info(line_num)?;
+        let line = line_info.content(ctx.content);
+        let column = byte_to_char_count(line, range.start - line_info.byte_offset);
+        Some(LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: line_num,
+            column,
+            end_line: line_num,
+            end_column: column + original.chars().count(),
+            message: format!("{kind} should use {}: '{original}' -> '{fixed}'", self.style_name()),
+            severity: Severity::Warning,
+            fix: Some(Fix::new(range, fixed)),
+        })
+    }
+
+    /// Warnings for table header cells and bold lead-ins, when enabled
+    fn non_heading_warnings(&self, ctx: &LintContext) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+
+        if self.config.check_table_headers {
+            for table in ctx.table_blocks() {
+                let Some(line_info) = ctx.lines.get(table.header_line) else {
+                    continue;
+                };
+                let line = line_info.content(ctx.content);
+                let row = TableUtils::extract_table_row_content(line, table, 0);
+                let row_offset = line_info.byte_offset + (line.len() - row.len());
+                for cell in header_cell_ranges(row) {
+                    let range = row_offset + cell.start..row_offset + cell.end;
+                    warnings.extend(self.text_warning(ctx, table.header_line + 1, range, "Table header"));
+                }
+            }
+        }
+
+        if self.config.check_bold_lead_ins {
+            for (idx, line_info) in ctx.lines.iter().enumerate() {
+                if let Some(range) = bold_lead_in_range(ctx, idx) {
+                    let range = line_info.byte_offset + range.start..line_info.byte_offset + range.end;
+                    warnings.extend(self.text_warning(ctx, idx + 1, range, "Bold lead-in"));
+                }
+            }
+        }
+
+        warnings
+    }
+
     /// Get byte range for a line
     fn get_line_byte_range(&self, content: &str, line_num: usize, line_index: &LineIndex) -> Range<usize> {
         let start_pos = line_index.get_line_start_byte(line_num).unwrap_or(content.len());
@@ -936,16 +892,16 @@ impl MD063HeadingCapitalization {
     }
 
     /// Fix an ATX heading line
-    fn fix_atx_heading(&self, _line: &str, heading: &crate::lint_context::HeadingInfo) -> String {
+    fn fix_atx_heading(&self, content: &str, heading: &crate::lint_context::HeadingInfo) -> String {
         // Parse the line to preserve structure
         let indent = " ".repeat(heading.marker_column);
         let hashes = "#".repeat(heading.level as usize);
 
         // Apply capitalization to the text
-        let fixed_text = self.apply_capitalization(&heading.raw_text);
+        let fixed_text = self.apply_capitalization(heading.raw_text(content));
 
         // Reconstruct with closing sequence if present
-        let closing = &heading.closing_sequence;
+        let closing = heading.closing_sequence(content);
         if heading.has_closing_sequence {
             format!("{indent}{hashes} {fixed_text} {closing}")
         } else {
@@ -954,9 +910,9 @@ impl MD063HeadingCapitalization {
     }
 
     /// Fix a Setext heading line
-    fn fix_setext_heading(&self, line: &str, heading: &crate::lint_context::HeadingInfo) -> String {
+    fn fix_setext_heading(&self, content: &str, line: &str, heading: &crate::lint_context::HeadingInfo) -> String {
         // Apply capitalization to the text
-        let fixed_text = self.apply_capitalization(&heading.raw_text);
+        let fixed_text = self.apply_capitalization(heading.raw_text(content));
 
         // Preserve leading whitespace from original line
         let leading_ws: String = line.chars().take_while(|c| c.is_whitespace()).collect();
@@ -978,8 +934,16 @@ impl Rule for MD063HeadingCapitalization {
         RuleCategory::Heading
     }
 
+    fn fix_safety(&self) -> FixSafety {
+        // Recapitalizes heading text, which also changes its anchor
+        FixSafety::Unsafe
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
-        !ctx.likely_has_headings() || !ctx.lines.iter().any(|line| line.heading.is_some())
+        let has_headings = ctx.likely_has_headings() && ctx.lines.iter().any(|line| line.heading.is_some());
+        let has_tables = self.config.check_table_headers && ctx.content.contains('|');
+        let has_bold = self.config.check_bold_lead_ins && (ctx.content.contains("**") || ctx.content.contains("__"));
+        !has_headings && !has_tables && !has_bold
     }
 
     fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
@@ -1010,16 +974,12 @@ impl Rule for MD063HeadingCapitalization {
                 }
 
                 // Apply capitalization and compare
-                let original_text = &heading.raw_text;
+                let original_text = heading.raw_text(ctx.content);
                 let fixed_text = self.apply_capitalization(original_text);
 
-                if original_text != &fixed_text {
+                if original_text != fixed_text {
                     let line = line_info.content(ctx.content);
-                    let style_name = match self.config.style {
-                        HeadingCapStyle::TitleCase => "title case",
-                        HeadingCapStyle::SentenceCase => "sentence case",
-                        HeadingCapStyle::AllCaps => "ALL CAPS",
-                    };
+                    let style_name = self.style_name();
 
                     warnings.push(LintWarning {
                         rule_name: Some(self.name().to_string()),
@@ -1032,8 +992,8 @@ impl Rule for MD063HeadingCapitalization {
                         fix: Some(Fix::new(
                             self.get_line_byte_range(content, line_num + 1, line_index),
                             match heading.style {
-                                crate::lint_context::HeadingStyle::ATX => self.fix_atx_heading(line, heading),
-                                _ => self.fix_setext_heading(line, heading),
+                                crate::lint_context::HeadingStyle::ATX => self.fix_atx_heading(content, heading),
+                                _ => self.fix_setext_heading(content, line, heading),
                             },
                         )),
                     });
@@ -1041,6 +1001,9 @@ impl Rule for MD063HeadingCapitalization {
             }
         }
 
+        warnings.extend(self.non_heading_warnings(ctx));
+        warnings.sort_by_key(|warning| (warning.line, warning.column));
+
         Ok(warnings)
     }
 
@@ -1076,19 +1039,34 @@ impl Rule for MD063HeadingCapitalization {
                     continue;
                 }
 
-                let original_text = &heading.raw_text;
+                let original_text = heading.raw_text(ctx.content);
                 let fixed_text = self.apply_capitalization(original_text);
 
-                if original_text != &fixed_text {
+                if original_text != fixed_text {
                     let line = line_info.content(ctx.content);
                     fixed_lines[line_num] = match heading.style {
-                        crate::lint_context::HeadingStyle::ATX => self.fix_atx_heading(line, heading),
-                        _ => self.fix_setext_heading(line, heading),
+                        crate::lint_context::HeadingStyle::ATX => self.fix_atx_heading(ctx.content, heading),
+                        _ => self.fix_setext_heading(ctx.content, line, heading),
                     };
                 }
             }
         }
 
+        // Table header cells and bold lead-ins are fixed in place, right to left
+        let mut inline_fixes = self.non_heading_warnings(ctx);
+        inline_fixes.sort_by_key(|warning| std::cmp::Reverse((warning.line, warning.column)));
+        for warning in inline_fixes {
+            if ctx.is_rule_disabled(self.name(), warning.line) {
+                continue;
+            }
+            let (Some(fix), Some(line_info)) = (warning.fix, ctx.line_info(warning.line)) else {
+                continue;
+            };
+            let start = fix.range.start - line_info.byte_offset;
+            let end = fix.range.end - line_info.byte_offset;
+            fixed_lines[warning.line - 1].replace_range(start..end, &fix.replacement);
+        }
+
         // Reconstruct content preserving line endings
         let mut result = String::with_capacity(content.len());
         for (i, line) in fixed_lines.iter().enumerate() {
@@ -1120,16 +1098,92 @@ impl Rule for MD063HeadingCapitalization {
         let rule_config = crate::rule_config_serde::load_rule_config::<MD063Config>(config);
         let md044_config =
             crate::rule_config_serde::load_rule_config::<crate::rules::md044_proper_names::MD044Config>(config);
-        let mut rule = Self::from_config_struct(rule_config);
-        rule.proper_names = md044_config.names;
-        Box::new(rule)
+        Box::new(Self::from_config_struct(rule_config).with_proper_names(md044_config.names))
+    }
+}
+
+/// Byte ranges of the trimmed, non-empty cells of a table header row
+fn header_cell_ranges(row: &str) -> Vec<Range<usize>> {
+    let masked = TableUtils::mask_pipes_in_inline_code(&TableUtils::mask_pipes_for_table_parsing(row));
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for end in masked
+        .match_indices('|')
+        .map(|(i, _)| i)
+        .chain(std::iter::once(masked.len()))
+    {
+        let cell = &row[start..end];
+        let text = cell.trim();
+        if !text.is_empty() {
+            let offset = start + (cell.len() - cell.trim_start().len());
+            ranges.push(offset..offset + text.len());
+        }
+        start = end + 1;
+    }
+    ranges
+}
+
+/// Byte range, within the line, of the text of a bold run-in heading that
+/// opens the paragraph or list item on line index `idx`, as in
+/// `**Getting started:** Install the tool.`
+fn bold_lead_in_range(ctx: &LintContext, idx: usize) -> Option<Range<usize>> {
+    let info = &ctx.lines[idx];
+    if info.is_blank
+        || info.in_code_block
+        || info.in_front_matter
+        || info.in_html_block
+        || info.in_html_comment
+        || info.in_math_block
+        || info.heading.is_some()
+        || ctx.is_in_table_block(idx + 1)
+    {
+        return None;
+    }
+    let line = info.content(ctx.content);
+
+    let text_start = if let Some(item) = &info.list_item {
+        item.content_column
+    } else {
+        // Only the first line of a paragraph can hold a lead-in
+        let opens_paragraph = idx == 0
+            || ctx.lines.get(idx - 1).is_some_and(|prev| {
+                prev.is_blank
+                    || prev.heading.is_some()
+                    || prev
+                        .blockquote
+                        .as_ref()
+                        .is_some_and(|bq| bq.content(ctx.content).trim().is_empty() && info.blockquote.is_some())
+            });
+        if !opens_paragraph {
+            return None;
+        }
+        match &info.blockquote {
+            Some(bq) => line.len() - bq.content(ctx.content).len(),
+            None => line.len() - line.trim_start().len(),
+        }
+    };
+
+    let text = line.get(text_start..)?;
+    let delimiter = ["**", "__"].into_iter().find(|d| text.starts_with(d))?;
+    let inner_start = delimiter.len();
+    let inner_len = text[inner_start..].find(delimiter)?;
+    let inner = &text[inner_start..inner_start + inner_len];
+    let after = &text[inner_start + inner_len + delimiter.len()..];
+    if inner.trim().is_empty() || inner.starts_with(char::is_whitespace) || inner.ends_with(char::is_whitespace) {
+        return None;
+    }
+    // A bold line on its own is emphasis, not a run-in heading
+    if after.trim().is_empty() {
+        return None;
     }
+
+    let start = text_start + inner_start;
+    Some(start..start + inner.len())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lint_context::LintContext;
 
     fn create_rule() -> MD063HeadingCapitalization {
         let config = MD063Config {
@@ -2835,6 +2889,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_all_caps_accepts_uppercase_or_canonical_proper_names() {
+        let config = MD063Config {
+            enabled: true,
+            style: HeadingCapStyle::AllCaps,
+            ..Default::default()
+        };
+        let rule = MD063HeadingCapitalization::from_config_struct(config).with_proper_names(vec!["GitHub".to_string()]);
+
+        // Both forms MD044 accepts in an ALL CAPS heading are left alone
+        for content in ["# USING GITHUB ACTIONS\n", "# USING GitHub ACTIONS\n"] {
+            let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+            assert!(rule.check(&ctx).unwrap().is_empty(), "{content:?}");
+        }
+
+        // Any other casing is fixed to the canonical form, matching MD044's fix
+        let ctx = LintContext::new(
+            "# using github actions\n",
+            crate::config::MarkdownFlavor::Standard,
+            None,
+        );
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].fix.as_ref().unwrap().replacement, "# USING GitHub ACTIONS");
+    }
+
     // Numbered prefix tests — words following a period-terminated token must be capitalized
     #[test]
     fn test_title_case_numbered_prefix_lowercase_word() {
@@ -3102,4 +3182,46 @@ mod tests {
             assert!(result.is_empty(), "Should not flag {content:?}, got: {result:?}");
         }
     }
+
+    #[test]
+    fn test_table_headers_checked_when_enabled() {
+        let content = "| file name | `max_width` | Notes |\n| --- | --- | --- |\n| a b | c d | e f |\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(create_rule().check(&ctx).unwrap().is_empty());
+
+        let rule = MD063HeadingCapitalization::from_config_struct(MD063Config {
+            enabled: true,
+            check_table_headers: true,
+            ..Default::default()
+        });
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!((result[0].line, result[0].column), (1, 3));
+        assert!(result[0].message.starts_with("Table header should use title case"));
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "| File Name | `max_width` | Notes |\n| --- | --- | --- |\n| a b | c d | e f |\n"
+        );
+    }
+
+    #[test]
+    fn test_bold_lead_ins_checked_when_enabled() {
+        let rule = MD063HeadingCapitalization::from_config_struct(MD063Config {
+            enabled: true,
+            style: HeadingCapStyle::SentenceCase,
+            check_bold_lead_ins: true,
+            ..Default::default()
+        });
+        let content = "# Setup\n\n**Getting Started:** install the tool.\nThen **Run It** here.\n\n\
+                       - **Step One.** Do this.\n- **Emphasis On Its Own**\n\n> **Read This First** now.\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![3, 6, 9]);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Setup\n\n**Getting started:** install the tool.\nThen **Run It** here.\n\n\
+             - **Step one.** Do this.\n- **Emphasis On Its Own**\n\n> **Read this first** now.\n"
+        );
+    }
 }
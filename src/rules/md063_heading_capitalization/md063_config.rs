@@ -82,6 +82,15 @@ pub struct MD063Config {
     /// Maximum heading level to check (1-6)
     #[serde(default = "default_max_level", rename = "max-level", alias = "max_level")]
     pub max_level: u8,
+
+    /// Also check the header cells of tables
+    #[serde(default, rename = "check-table-headers", alias = "check_table_headers")]
+    pub check_table_headers: bool,
+
+    /// Also check bold run-in headings that open a paragraph or list item
+    /// (`**Getting started:** ...`)
+    #[serde(default, rename = "check-bold-lead-ins", alias = "check_bold_lead_ins")]
+    pub check_bold_lead_ins: bool,
 }
 
 fn default_lowercase_words() -> Vec<String> {
@@ -117,6 +126,8 @@ impl Default for MD063Config {
             preserve_cased_words: default_preserve_cased_words(),
             min_level: default_min_level(),
             max_level: default_max_level(),
+            check_table_headers: false,
+            check_bold_lead_ins: false,
         }
     }
 }
@@ -140,6 +151,8 @@ mod tests {
         assert!(config.preserve_cased_words);
         assert_eq!(config.min_level, 1);
         assert_eq!(config.max_level, 6);
+        assert!(!config.check_table_headers);
+        assert!(!config.check_bold_lead_ins);
     }
 
     #[test]
@@ -151,6 +164,8 @@ mod tests {
             preserve-cased-words = true
             min-level = 1
             max-level = 3
+            check-table-headers = true
+            check-bold-lead-ins = true
         "#;
         let config: MD063Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.style, HeadingCapStyle::TitleCase);
@@ -159,6 +174,8 @@ mod tests {
         assert!(config.preserve_cased_words);
         assert_eq!(config.min_level, 1);
         assert_eq!(config.max_level, 3);
+        assert!(config.check_table_headers);
+        assert!(config.check_bold_lead_ins);
     }
 
     #[test]
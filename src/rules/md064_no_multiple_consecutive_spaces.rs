@@ -26,7 +26,7 @@
 /// - Table rows (alignment padding is intentional)
 /// - Front matter content
 use crate::filtered_lines::FilteredLinesExt;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::blockquote::parse_blockquote_prefix;
 use crate::utils::sentence_utils::is_after_sentence_ending;
@@ -562,6 +562,10 @@ impl Rule for MD064NoMultipleConsecutiveSpaces {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     /// Check if this rule should be skipped
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.content.contains("  ")
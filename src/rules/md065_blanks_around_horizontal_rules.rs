@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 /// Rule MD065: Blanks around horizontal rules
 ///
@@ -101,6 +101,10 @@ impl Rule for MD065BlanksAroundHorizontalRules {
         RuleCategory::Whitespace
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty()
             || !ctx.content.contains("---") && !ctx.content.contains("***") && !ctx.content.contains("___")
@@ -72,8 +72,13 @@ impl MD068EmptyFootnoteDefinition {
                     continue;
                 }
 
-                // If next non-empty line has 4+ columns of indentation, it's a continuation
-                if calculate_indentation_width_default(next_stripped) >= 4 {
+                // If next non-empty line has 4+ columns of indentation, it's a continuation.
+                // The raw line is checked too: block content such as a blockquote
+                // (`    > quote`, common in kramdown footnotes) loses its indentation
+                // when the blockquote prefix is stripped.
+                if calculate_indentation_width_default(next_stripped) >= 4
+                    || calculate_indentation_width_default(next_line) >= 4
+                {
                     return true;
                 }
 
@@ -220,6 +225,18 @@ mod tests {
         assert!(warnings[0].message.contains("empty"));
     }
 
+    #[test]
+    fn test_block_content_on_next_line() {
+        // kramdown style: the definition's content starts on the next line and
+        // may be any indented block, including a blockquote or a list
+        let content = "Text with [^q] and [^l].\n\n[^q]:\n    > Quoted content.\n\n[^l]:\n    - item\n";
+        let warnings = check(content);
+        assert!(
+            warnings.is_empty(),
+            "Indented block content is a continuation: {warnings:?}"
+        );
+    }
+
     #[test]
     fn test_multi_line_footnote() {
         // Using explicit string to ensure proper spacing
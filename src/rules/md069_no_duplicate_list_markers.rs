@@ -24,7 +24,7 @@
 /// ```
 use crate::filtered_lines::FilteredLinesExt;
 use crate::lint_context::is_horizontal_rule_line;
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::range_utils::byte_to_char_count;
 use regex::Regex;
 use std::sync::LazyLock;
@@ -147,6 +147,10 @@ impl Rule for MD069NoDuplicateListMarkers {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Fast check: if no list markers exist at all, skip
         !ctx.content.contains('-') && !ctx.content.contains('*') && !ctx.content.contains('+')
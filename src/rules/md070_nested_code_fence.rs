@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 /// Rule MD070: Nested code fence collision detection
 ///
@@ -396,6 +396,10 @@ impl Rule for MD070NestedCodeFence {
         RuleCategory::CodeBlock
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || (!ctx.likely_has_code() && !ctx.has_char('~'))
     }
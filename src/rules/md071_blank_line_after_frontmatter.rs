@@ -1,4 +1,4 @@
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 /// Rule MD071: Blank line after frontmatter
 ///
@@ -113,6 +113,10 @@ impl Rule for MD071BlankLineAfterFrontmatter {
         RuleCategory::FrontMatter
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || !ctx.content.starts_with("---") && !ctx.content.starts_with("+++")
     }
@@ -4,7 +4,7 @@
 
 use crate::lint_context::LintContext;
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
-use crate::utils::anchor_styles::AnchorStyle;
+use crate::utils::slug::Slugify;
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -234,6 +234,8 @@ pub struct MD073TocValidation {
     enforce_order: bool,
     /// Indent size per nesting level (reads from MD007 config by default)
     pub indent: usize,
+    /// Heading slug algorithm (the global `slugify` option, GitHub by default)
+    slugify: Slugify,
 }
 
 impl Default for MD073TocValidation {
@@ -244,6 +246,7 @@ impl Default for MD073TocValidation {
             max_level: 4,
             enforce_order: true,
             indent: 2, // Default indent, can be overridden by MD007 config
+            slugify: Slugify::default(),
         }
     }
 }
@@ -256,6 +259,7 @@ impl std::fmt::Debug for MD073TocValidation {
             .field("max_level", &self.max_level)
             .field("enforce_order", &self.enforce_order)
             .field("indent", &self.indent)
+            .field("slugify", &self.slugify)
             .finish()
     }
 }
@@ -370,11 +374,11 @@ impl MD073TocValidation {
                     continue;
                 }
 
-                // Use custom ID if available, otherwise generate GitHub-style anchor
-                let base_anchor = if let Some(custom_id) = &heading.custom_id {
-                    custom_id.clone()
+                // Use custom ID if available, otherwise generate the configured slug
+                let base_anchor = if let Some(custom_id) = heading.custom_id(ctx.content) {
+                    custom_id.to_string()
                 } else {
-                    AnchorStyle::GitHub.generate_fragment(&heading.text)
+                    self.slugify.slugify(heading.text(ctx.content))
                 };
 
                 // Handle duplicate anchors
@@ -390,7 +394,7 @@ impl MD073TocValidation {
                 entries.push(ExpectedTocEntry {
                     heading_line: line_num,
                     level: heading.level,
-                    text: heading.text.clone(),
+                    text: heading.text(ctx.content).to_string(),
                     anchor,
                 });
             }
@@ -714,6 +718,9 @@ indent = 2
     {
         let mut rule = MD073TocValidation::default();
         let mut indent_from_md073 = false;
+        if let Some(slugify) = &config.global.slugify {
+            rule.slugify = slugify.clone();
+        }
 
         if let Some(rule_config) = config.rules.get("MD073") {
             // Parse enabled (opt-in rule, defaults to false)
@@ -3,10 +3,12 @@
 //!
 //! See [docs/md074.md](../../docs/md074.md) for full documentation, configuration, and examples.
 
-use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{CrossFileScope, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::mkdocs_config::find_mkdocs_yml;
+use crate::workspace_index::WorkspaceIndex;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::{LazyLock, Mutex};
@@ -20,11 +22,15 @@ static VALIDATED_PROJECTS: LazyLock<Mutex<HashMap<PathBuf, u64>>> = LazyLock::ne
 
 /// Rule MD074: MkDocs nav validation
 ///
-/// Validates that MkDocs nav entries in mkdocs.yml point to existing files.
+/// Validates that MkDocs nav entries in mkdocs.yml point to existing files,
+/// and (as a workspace check) that every Markdown file under docs_dir is
+/// either in nav or explicitly excluded.
 /// Only active when the markdown flavor is set to "mkdocs".
 #[derive(Debug, Clone)]
 pub struct MD074MkDocsNav {
     config: MD074Config,
+    /// Whether any file is linted with the MkDocs flavor; gates the workspace check
+    mkdocs_flavor: bool,
 }
 
 impl Default for MD074MkDocsNav {
@@ -35,13 +41,19 @@ impl Default for MD074MkDocsNav {
 
 impl MD074MkDocsNav {
     pub fn new() -> Self {
+        Self::from_config_struct(MD074Config::default())
+    }
+
+    pub fn from_config_struct(config: MD074Config) -> Self {
         Self {
-            config: MD074Config::default(),
+            config,
+            mkdocs_flavor: false,
         }
     }
 
-    pub fn from_config_struct(config: MD074Config) -> Self {
-        Self { config }
+    pub fn with_mkdocs_flavor(mut self, mkdocs_flavor: bool) -> Self {
+        self.mkdocs_flavor = mkdocs_flavor;
+        self
     }
 
     /// Clear the validation cache.
@@ -102,46 +114,6 @@ impl MD074MkDocsNav {
         paths
     }
 
-    /// Collect all markdown files in docs_dir recursively
-    fn collect_docs_files(docs_dir: &Path) -> HashSet<PathBuf> {
-        Self::collect_docs_files_recursive(docs_dir, docs_dir)
-    }
-
-    /// Recursive helper that preserves the original docs_dir for relative path calculation
-    fn collect_docs_files_recursive(current_dir: &Path, root_docs_dir: &Path) -> HashSet<PathBuf> {
-        let mut files = HashSet::new();
-
-        let Ok(entries) = std::fs::read_dir(current_dir) else {
-            return files;
-        };
-
-        for entry in entries.flatten() {
-            let path = entry.path();
-
-            // Skip hidden directories and files
-            if path.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.')) {
-                continue;
-            }
-
-            if path.is_dir() {
-                files.extend(Self::collect_docs_files_recursive(&path, root_docs_dir));
-            } else if path.is_file()
-                && let Some(ext) = path.extension()
-            {
-                let ext_lower = ext.to_string_lossy().to_lowercase();
-                if ext_lower == "md" || ext_lower == "markdown" {
-                    // Get path relative to docs_dir, normalized with forward slashes
-                    if let Ok(relative) = path.strip_prefix(root_docs_dir) {
-                        let normalized = Self::normalize_path(relative);
-                        files.insert(normalized);
-                    }
-                }
-            }
-        }
-
-        files
-    }
-
     /// Normalize a path to use forward slashes (for cross-platform consistency)
     fn normalize_path(path: &Path) -> PathBuf {
         let path_str = path.to_string_lossy();
@@ -199,6 +171,116 @@ impl MD074MkDocsNav {
         None
     }
 
+    /// Resolve docs_dir relative to the mkdocs.yml location
+    fn resolve_docs_dir(mkdocs_path: &Path, mkdocs_config: &MkDocsConfig) -> PathBuf {
+        let mkdocs_dir = mkdocs_path.parent().unwrap_or(Path::new("."));
+        if Path::new(&mkdocs_config.docs_dir).is_absolute() {
+            PathBuf::from(&mkdocs_config.docs_dir)
+        } else {
+            mkdocs_dir.join(&mkdocs_config.docs_dir)
+        }
+    }
+
+    /// Collect the docs_dir-relative files served by nav entries
+    ///
+    /// Directory entries (e.g., "api/") stand for their index.md.
+    fn referenced_docs(nav: &[NavItem], docs_dir: &Path) -> HashSet<PathBuf> {
+        Self::extract_nav_paths(nav, "")
+            .into_iter()
+            .filter(|(path, _)| !Self::is_external_url(path) && !Self::is_absolute_path(path))
+            .map(|(path, _)| {
+                let normalized = Self::normalize_nav_path(&path);
+                if path.ends_with('/') || docs_dir.join(&normalized).is_dir() {
+                    normalized.join("index.md")
+                } else {
+                    normalized
+                }
+            })
+            .collect()
+    }
+
+    /// Build a matcher for the gitignore-style `not_in_nav` and `exclude_docs`
+    /// patterns, which are relative to docs_dir
+    fn excluded_docs_matcher(docs_dir: &Path, mkdocs_config: &MkDocsConfig) -> Gitignore {
+        let mut builder = GitignoreBuilder::new(docs_dir);
+        for line in mkdocs_config
+            .not_in_nav
+            .lines()
+            .chain(mkdocs_config.exclude_docs.lines())
+        {
+            // Invalid patterns are skipped; MkDocs reports them itself
+            let _ = builder.add_line(None, line);
+        }
+        builder.build().unwrap_or_else(|_| Gitignore::empty())
+    }
+
+    /// Report the indexed Markdown files under docs_dir that no nav entry
+    /// references and that are not excluded via `not_in_nav` or `exclude_docs`
+    fn find_orphans(&self, mkdocs_path: &Path, workspace_index: &WorkspaceIndex) -> Vec<(PathBuf, Vec<LintWarning>)> {
+        let Ok(content) = std::fs::read_to_string(mkdocs_path) else {
+            return Vec::new();
+        };
+        // Parse errors are reported by check()
+        let Ok(mkdocs_config) = Self::parse_mkdocs_yml_from_str(&content, mkdocs_path) else {
+            return Vec::new();
+        };
+        // Without a nav key MkDocs builds the nav from every page
+        if !mkdocs_config.has_nav {
+            return Vec::new();
+        }
+        let Ok(docs_dir) = Self::resolve_docs_dir(mkdocs_path, &mkdocs_config).canonicalize() else {
+            return Vec::new();
+        };
+
+        let mkdocs_file = mkdocs_path
+            .file_name()
+            .map_or_else(|| "mkdocs.yml".to_string(), |n| n.to_string_lossy().to_string());
+        let referenced = Self::referenced_docs(&mkdocs_config.nav, &docs_dir);
+        let excluded = Self::excluded_docs_matcher(&docs_dir, &mkdocs_config);
+
+        workspace_index
+            .files_sorted()
+            .into_iter()
+            .filter_map(|(path, _)| {
+                let is_markdown = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"));
+                if !is_markdown {
+                    return None;
+                }
+                let relative = Self::normalize_path(path.strip_prefix(&docs_dir).ok()?);
+
+                // Skip hidden files and directories, as MkDocs does
+                if relative
+                    .components()
+                    .any(|c| c.as_os_str().to_string_lossy().starts_with('.'))
+                {
+                    return None;
+                }
+                if referenced.contains(&relative) || excluded.matched_path_or_any_parents(path, false).is_ignore() {
+                    return None;
+                }
+                // Skip index and README files in the docs root, often intentionally not in nav
+                let name_lower = relative.to_string_lossy().to_lowercase();
+                if name_lower == "index.md" || name_lower == "readme.md" {
+                    return None;
+                }
+
+                let warning = LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 1,
+                    message: format!("File not referenced in nav (in {mkdocs_file})"),
+                    severity: Severity::Info,
+                    fix: None,
+                };
+                Some((path.to_path_buf(), vec![warning]))
+            })
+            .collect()
+    }
+
     /// Perform the actual validation of mkdocs.yml nav entries
     fn validate_nav(&self, mkdocs_path: &Path, mkdocs_config: &MkDocsConfig, yaml_content: &str) -> Vec<LintWarning> {
         let mut warnings = Vec::new();
@@ -206,13 +288,7 @@ impl MD074MkDocsNav {
             .file_name()
             .map_or_else(|| "mkdocs.yml".to_string(), |n| n.to_string_lossy().to_string());
 
-        // Get docs_dir relative to mkdocs.yml location
-        let mkdocs_dir = mkdocs_path.parent().unwrap_or(Path::new("."));
-        let docs_dir = if Path::new(&mkdocs_config.docs_dir).is_absolute() {
-            PathBuf::from(&mkdocs_config.docs_dir)
-        } else {
-            mkdocs_dir.join(&mkdocs_config.docs_dir)
-        };
+        let docs_dir = Self::resolve_docs_dir(mkdocs_path, mkdocs_config);
 
         if !docs_dir.exists() {
             let yaml_line = Self::find_nav_line_in_yaml(yaml_content, &mkdocs_config.docs_dir);
@@ -238,9 +314,6 @@ impl MD074MkDocsNav {
         // Extract all nav paths
         let nav_paths = Self::extract_nav_paths(&mkdocs_config.nav, "");
 
-        // Track referenced files for omitted_files check (normalized paths)
-        let mut referenced_files: HashSet<PathBuf> = HashSet::new();
-
         // Validate each nav entry
         for (file_path, nav_location) in &nav_paths {
             // Skip external URLs
@@ -269,27 +342,18 @@ impl MD074MkDocsNav {
                 continue;
             }
 
-            let normalized_path = Self::normalize_nav_path(file_path);
-
             // Check if file exists
             if self.config.not_found == NavValidation::Warn {
+                let normalized_path = Self::normalize_nav_path(file_path);
                 let full_path = docs_dir.join(&normalized_path);
 
                 // Handle directory entries (e.g., "api/" -> "api/index.md")
                 let (actual_path, is_dir_entry) = if file_path.ends_with('/') || full_path.is_dir() {
-                    let index_path = normalized_path.join("index.md");
-                    (docs_dir.join(&index_path), true)
+                    (docs_dir.join(normalized_path.join("index.md")), true)
                 } else {
                     (full_path, false)
                 };
 
-                // Track the actual file that would be served
-                if is_dir_entry {
-                    referenced_files.insert(normalized_path.join("index.md"));
-                } else {
-                    referenced_files.insert(normalized_path.clone());
-                }
-
                 if !actual_path.exists() {
                     let display_path = if is_dir_entry {
                         format!(
@@ -315,45 +379,6 @@ impl MD074MkDocsNav {
                         fix: None,
                     });
                 }
-            } else {
-                // Still track referenced files even if not validating
-                if file_path.ends_with('/') {
-                    referenced_files.insert(normalized_path.join("index.md"));
-                } else {
-                    referenced_files.insert(normalized_path);
-                }
-            }
-        }
-
-        // Check for omitted files
-        if self.config.omitted_files == NavValidation::Warn {
-            let all_docs = Self::collect_docs_files(&docs_dir);
-
-            for doc_file in all_docs {
-                if !referenced_files.contains(&doc_file) {
-                    // Skip common files that are often intentionally not in nav
-                    let file_name = doc_file.file_name().map(|n| n.to_string_lossy());
-                    if let Some(name) = &file_name {
-                        let name_lower = name.to_lowercase();
-                        // Skip index files in root, README files, and other common non-nav files
-                        if (doc_file.parent().is_none() || doc_file.parent() == Some(Path::new("")))
-                            && (name_lower == "index.md" || name_lower == "readme.md")
-                        {
-                            continue;
-                        }
-                    }
-
-                    warnings.push(LintWarning {
-                        rule_name: Some(self.name().to_string()),
-                        line: 1,
-                        column: 1,
-                        end_line: 1,
-                        end_column: 1,
-                        message: format!("File not referenced in nav: {} (in {mkdocs_file})", doc_file.display()),
-                        severity: Severity::Info,
-                        fix: None,
-                    });
-                }
             }
         }
 
@@ -369,6 +394,15 @@ struct MkDocsConfig {
 
     /// Navigation structure
     nav: Vec<NavItem>,
+
+    /// Whether mkdocs.yml has a `nav` key (otherwise MkDocs generates the nav)
+    has_nav: bool,
+
+    /// Gitignore-style patterns for pages intentionally left out of nav
+    not_in_nav: String,
+
+    /// Gitignore-style patterns for files excluded from the site
+    exclude_docs: String,
 }
 
 fn default_docs_dir() -> String {
@@ -440,10 +474,15 @@ impl<'de> Deserialize<'de> for MkDocsConfig {
             docs_dir: String,
             #[serde(default)]
             nav: Option<serde_yaml::Value>,
+            #[serde(default)]
+            not_in_nav: Option<String>,
+            #[serde(default)]
+            exclude_docs: Option<String>,
         }
 
         let raw = RawMkDocsConfig::deserialize(deserializer)?;
 
+        let has_nav = raw.nav.is_some();
         let nav = match raw.nav {
             Some(serde_yaml::Value::Sequence(seq)) => seq.iter().filter_map(NavItem::from_yaml_value).collect(),
             _ => Vec::new(),
@@ -452,6 +491,9 @@ impl<'de> Deserialize<'de> for MkDocsConfig {
         Ok(MkDocsConfig {
             docs_dir: raw.docs_dir,
             nav,
+            has_nav,
+            not_in_nav: raw.not_in_nav.unwrap_or_default(),
+            exclude_docs: raw.exclude_docs.unwrap_or_default(),
         })
     }
 }
@@ -556,7 +598,40 @@ impl Rule for MD074MkDocsNav {
         self
     }
 
-    crate::impl_rule_config_methods!(MD074Config);
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD074Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let mkdocs = crate::config::MarkdownFlavor::MkDocs;
+        let mkdocs_flavor = config.global.flavor == mkdocs || config.per_file_flavor.values().any(|f| *f == mkdocs);
+        Box::new(
+            Self::from_config_struct(crate::rule_config_serde::load_rule_config::<MD074Config>(config))
+                .with_mkdocs_flavor(mkdocs_flavor),
+        )
+    }
+
+    fn cross_file_scope(&self) -> CrossFileScope {
+        CrossFileScope::Workspace
+    }
+
+    fn workspace_check(&self, workspace_index: &WorkspaceIndex) -> Result<Vec<(PathBuf, Vec<LintWarning>)>, LintError> {
+        if !self.mkdocs_flavor || self.config.omitted_files != NavValidation::Warn {
+            return Ok(Vec::new());
+        }
+
+        // Each project is checked once, however many of its files were indexed
+        let dirs: HashSet<&Path> = workspace_index.files().filter_map(|(path, _)| path.parent()).collect();
+        let mkdocs_paths: BTreeSet<PathBuf> = dirs.into_iter().filter_map(find_mkdocs_yml).collect();
+
+        Ok(mkdocs_paths
+            .iter()
+            .flat_map(|mkdocs_path| self.find_orphans(mkdocs_path, workspace_index))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -779,6 +854,39 @@ nav:
         assert!(result[0].message.contains("Absolute path"));
     }
 
+    /// Index the given files (relative to `root`) as the CLI does, by canonical path
+    fn index_files(root: &Path, files: &[&str]) -> WorkspaceIndex {
+        let mut index = WorkspaceIndex::new();
+        for file in files {
+            let path = root.join(file).canonicalize().unwrap();
+            index.insert_file(path, crate::workspace_index::FileIndex::new());
+        }
+        index
+    }
+
+    /// Reported orphans as docs_dir-relative paths
+    fn orphan_names(reports: &[(PathBuf, Vec<LintWarning>)], docs_dir: &Path) -> Vec<String> {
+        let docs_dir = docs_dir.canonicalize().unwrap();
+        reports
+            .iter()
+            .map(|(path, _)| {
+                path.strip_prefix(&docs_dir)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    fn omitted_files_rule() -> MD074MkDocsNav {
+        let config = MD074Config {
+            not_found: NavValidation::Ignore,
+            omitted_files: NavValidation::Warn,
+            absolute_links: NavValidation::Ignore,
+        };
+        MD074MkDocsNav::from_config_struct(config).with_mkdocs_flavor(true)
+    }
+
     #[test]
     fn test_omitted_files_validation() {
         setup_test();
@@ -793,39 +901,26 @@ nav:
         fs::write(temp_dir.path().join("mkdocs.yml"), mkdocs_content).unwrap();
 
         let docs_dir = temp_dir.path().join("docs");
-        fs::create_dir_all(&docs_dir).unwrap();
+        fs::create_dir_all(docs_dir.join("subdir")).unwrap();
         fs::write(docs_dir.join("index.md"), "# Home").unwrap();
         fs::write(docs_dir.join("unlisted.md"), "# Unlisted").unwrap();
+        fs::write(docs_dir.join("subdir/nested.md"), "# Nested").unwrap();
 
-        // Create subdirectory with file
-        let subdir = docs_dir.join("subdir");
-        fs::create_dir_all(&subdir).unwrap();
-        fs::write(subdir.join("nested.md"), "# Nested").unwrap();
-
-        let test_file = docs_dir.join("test.md");
-        fs::write(&test_file, "# Test").unwrap();
-
-        let config = MD074Config {
-            not_found: NavValidation::Ignore,
-            omitted_files: NavValidation::Warn,
-            absolute_links: NavValidation::Ignore,
-        };
-        let rule = MD074MkDocsNav::from_config_struct(config);
-
-        let ctx =
-            crate::lint_context::LintContext::new("# Test", crate::config::MarkdownFlavor::MkDocs, Some(test_file));
-
-        let result = rule.check(&ctx).unwrap();
-
-        // Should warn about unlisted.md, test.md, and subdir/nested.md
-        // (index.md in root is skipped)
-        assert!(result.len() >= 2, "Should warn about omitted files. Got: {result:?}");
+        let index = index_files(
+            temp_dir.path(),
+            &["docs/index.md", "docs/unlisted.md", "docs/subdir/nested.md"],
+        );
+        let reports = omitted_files_rule().workspace_check(&index).unwrap();
 
-        let messages: Vec<&str> = result.iter().map(|w| w.message.as_str()).collect();
-        assert!(
-            messages.iter().any(|m| m.contains("unlisted.md")),
-            "Should mention unlisted.md"
+        // index.md in the docs root is skipped; orphans are reported on their own paths
+        assert_eq!(
+            orphan_names(&reports, &docs_dir),
+            vec!["subdir/nested.md", "unlisted.md"]
         );
+        let warning = &reports[0].1[0];
+        assert_eq!(warning.line, 1);
+        assert_eq!(warning.message, "File not referenced in nav (in mkdocs.yml)");
+        assert_eq!(warning.severity, Severity::Info);
     }
 
     #[test]
@@ -840,46 +935,124 @@ nav:
   - Home: index.md
   - API:
     - Overview: api/overview.md
+  - Guide: guide/
 "#;
         fs::write(temp_dir.path().join("mkdocs.yml"), mkdocs_content).unwrap();
 
         let docs_dir = temp_dir.path().join("docs");
-        fs::create_dir_all(&docs_dir).unwrap();
+        fs::create_dir_all(docs_dir.join("api")).unwrap();
+        fs::create_dir_all(docs_dir.join("guide")).unwrap();
         fs::write(docs_dir.join("index.md"), "# Home").unwrap();
+        fs::write(docs_dir.join("api/overview.md"), "# Overview").unwrap();
+        fs::write(docs_dir.join("api/unlisted.md"), "# Unlisted API").unwrap();
+        fs::write(docs_dir.join("guide/index.md"), "# Guide").unwrap();
+
+        let index = index_files(
+            temp_dir.path(),
+            &[
+                "docs/index.md",
+                "docs/api/overview.md",
+                "docs/api/unlisted.md",
+                "docs/guide/index.md",
+            ],
+        );
+        let reports = omitted_files_rule().workspace_check(&index).unwrap();
 
-        let api_dir = docs_dir.join("api");
-        fs::create_dir_all(&api_dir).unwrap();
-        fs::write(api_dir.join("overview.md"), "# Overview").unwrap();
-        fs::write(api_dir.join("unlisted.md"), "# Unlisted API").unwrap();
+        // api/overview.md is in nav and guide/ resolves to guide/index.md
+        assert_eq!(orphan_names(&reports, &docs_dir), vec!["api/unlisted.md"]);
+    }
 
-        let test_file = docs_dir.join("index.md");
+    #[test]
+    fn test_omitted_files_honor_not_in_nav_and_exclude_docs() {
+        setup_test();
+        let temp_dir = tempdir().unwrap();
 
-        let config = MD074Config {
-            not_found: NavValidation::Warn,
-            omitted_files: NavValidation::Warn,
-            absolute_links: NavValidation::Ignore,
-        };
-        let rule = MD074MkDocsNav::from_config_struct(config);
+        let mkdocs_content = r#"
+site_name: Test
+nav:
+  - index.md
+not_in_nav: |
+  /drafts/
+  release-*.md
+exclude_docs: |
+  /internal.md
+"#;
+        fs::write(temp_dir.path().join("mkdocs.yml"), mkdocs_content).unwrap();
 
-        let ctx =
-            crate::lint_context::LintContext::new("# Home", crate::config::MarkdownFlavor::MkDocs, Some(test_file));
+        let docs_dir = temp_dir.path().join("docs");
+        fs::create_dir_all(docs_dir.join("drafts")).unwrap();
+        fs::create_dir_all(docs_dir.join("news")).unwrap();
+        for file in [
+            "index.md",
+            "drafts/idea.md",
+            "news/release-1.0.md",
+            "internal.md",
+            "orphan.md",
+        ] {
+            fs::write(docs_dir.join(file), "# Page").unwrap();
+        }
 
-        let result = rule.check(&ctx).unwrap();
+        let index = index_files(
+            temp_dir.path(),
+            &[
+                "docs/index.md",
+                "docs/drafts/idea.md",
+                "docs/news/release-1.0.md",
+                "docs/internal.md",
+                "docs/orphan.md",
+            ],
+        );
+        let reports = omitted_files_rule().workspace_check(&index).unwrap();
 
-        // Should only warn about api/unlisted.md, not api/overview.md
-        let messages: Vec<&str> = result.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(orphan_names(&reports, &docs_dir), vec!["orphan.md"]);
+    }
 
-        // api/overview.md should NOT be reported (it's in nav)
-        assert!(
-            !messages.iter().any(|m| m.contains("overview.md")),
-            "Should NOT warn about api/overview.md (it's in nav). Got: {messages:?}"
-        );
+    #[test]
+    fn test_omitted_files_only_checks_indexed_files() {
+        setup_test();
+        let temp_dir = tempdir().unwrap();
 
-        // api/unlisted.md SHOULD be reported
-        assert!(
-            messages.iter().any(|m| m.contains("unlisted.md")),
-            "Should warn about api/unlisted.md. Got: {messages:?}"
-        );
+        fs::write(
+            temp_dir.path().join("mkdocs.yml"),
+            "site_name: Test\nnav:\n  - index.md\n",
+        )
+        .unwrap();
+        let docs_dir = temp_dir.path().join("docs");
+        fs::create_dir_all(&docs_dir).unwrap();
+        fs::write(docs_dir.join("index.md"), "# Home").unwrap();
+        fs::write(docs_dir.join("linted.md"), "# Linted").unwrap();
+        fs::write(docs_dir.join("not-linted.md"), "# Not linted").unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Outside docs_dir").unwrap();
+
+        let index = index_files(temp_dir.path(), &["docs/index.md", "docs/linted.md", "README.md"]);
+        let reports = omitted_files_rule().workspace_check(&index).unwrap();
+
+        assert_eq!(orphan_names(&reports, &docs_dir), vec!["linted.md"]);
+    }
+
+    #[test]
+    fn test_omitted_files_skipped_without_nav_or_mkdocs_flavor() {
+        setup_test();
+        let temp_dir = tempdir().unwrap();
+
+        let docs_dir = temp_dir.path().join("docs");
+        fs::create_dir_all(&docs_dir).unwrap();
+        fs::write(docs_dir.join("page.md"), "# Page").unwrap();
+        let index = index_files(temp_dir.path(), &["docs/page.md"]);
+
+        // MkDocs generates the nav from every page when there is no nav key
+        fs::write(temp_dir.path().join("mkdocs.yml"), "site_name: Test\n").unwrap();
+        assert!(omitted_files_rule().workspace_check(&index).unwrap().is_empty());
+
+        fs::write(
+            temp_dir.path().join("mkdocs.yml"),
+            "site_name: Test\nnav:\n  - index.md\n",
+        )
+        .unwrap();
+        assert_eq!(omitted_files_rule().workspace_check(&index).unwrap().len(), 1);
+
+        let rule = omitted_files_rule().with_mkdocs_flavor(false);
+        assert!(rule.workspace_check(&index).unwrap().is_empty());
     }
 
     #[test]
@@ -1133,13 +1306,13 @@ nav:
         fs::create_dir_all(&hidden_dir).unwrap();
         fs::write(hidden_dir.join("secret.md"), "# Secret").unwrap();
 
-        let collected = MD074MkDocsNav::collect_docs_files(&docs_dir);
-
-        assert_eq!(collected.len(), 1, "Should only find index.md");
-        assert!(
-            !collected.iter().any(|p| p.to_string_lossy().contains("hidden")),
-            "Should not include hidden files"
+        let index = index_files(
+            temp_dir.path(),
+            &["docs/index.md", "docs/.hidden.md", "docs/.hidden_dir/secret.md"],
         );
+        let reports = omitted_files_rule().workspace_check(&index).unwrap();
+
+        assert!(reports.is_empty(), "Should not report hidden files. Got: {reports:?}");
     }
 
     #[test]
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use super::md060_table_format::{MD060Config, MD060TableFormat};
 use crate::md013_line_length::MD013Config;
-use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixCapability, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::blockquote::strip_blockquote_prefix;
 use crate::utils::ensure_consistent_line_endings;
 use crate::utils::fix_utils::apply_warning_fixes;
@@ -242,7 +242,7 @@ impl MD075OrphanedTableRows {
     ) -> Vec<OrphanedGroup> {
         let mut groups = Vec::new();
 
-        for table_block in &ctx.table_blocks {
+        for table_block in ctx.table_blocks() {
             let end = table_block.end_line;
             let header_content =
                 TableUtils::extract_table_row_content(content_lines[table_block.start_line], table_block, 0);
@@ -321,7 +321,7 @@ impl MD075OrphanedTableRows {
     ) -> HashSet<usize> {
         let mut continuation_rows = HashSet::new();
 
-        for table_block in &ctx.table_blocks {
+        for table_block in ctx.table_blocks() {
             let mut i = table_block.end_line + 1;
             while i < content_lines.len() {
                 if self.should_skip_line(ctx, i) || table_line_set.contains(&i) {
@@ -464,7 +464,7 @@ impl MD075OrphanedTableRows {
         ctx: &crate::lint_context::LintContext,
         content_lines: &[&str],
     ) -> bool {
-        if !ctx.table_blocks.is_empty() {
+        if !ctx.table_blocks().is_empty() {
             return false;
         }
 
@@ -589,6 +589,10 @@ impl Rule for MD075OrphanedTableRows {
         RuleCategory::Table
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         // Need at least 2 pipe characters for two minimal rows like:
         // a | b
@@ -602,7 +606,7 @@ impl Rule for MD075OrphanedTableRows {
 
         // Build set of all lines belonging to existing table blocks
         let mut table_line_set = HashSet::new();
-        for table_block in &ctx.table_blocks {
+        for table_block in ctx.table_blocks() {
             for line_idx in table_block.start_line..=table_block.end_line {
                 table_line_set.insert(line_idx);
             }
@@ -1931,7 +1935,7 @@ Notes ||
     // === Pandoc construct reachability tests ===
     //
     // These tests document that MD075 does not flag Pandoc-specific constructs
-    // because `ctx.table_blocks` (used by detect_orphaned_rows and
+    // because `ctx.table_blocks()` (used by detect_orphaned_rows and
     // detect_table_continuation_rows) and `is_table_row_line` (used by
     // detect_headerless_tables) both exclude them:
     //
@@ -1,5 +1,5 @@
 use crate::lint_context::LintContext;
-use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::skip_context::is_table_line;
 
 /// Rule MD076: Enforce consistent blank lines between list items
@@ -97,7 +97,7 @@ impl MD076ListItemSpacing {
             }
             // In a blockquote, a line containing only markers (e.g., ">", "> ") is blank
             if let Some(ref bq) = info.blockquote {
-                return bq.content.trim().is_empty();
+                return bq.content(ctx.content).trim().is_empty();
             }
             false
         } else {
@@ -125,7 +125,7 @@ impl MD076ListItemSpacing {
             let content = info.content(ctx.content);
             // Strip blockquote prefix and list continuation indent before checking table syntax
             let effective = if let Some(ref bq) = info.blockquote {
-                bq.content.as_str()
+                bq.content(ctx.content)
             } else {
                 content
             };
@@ -312,6 +312,10 @@ impl Rule for MD076ListItemSpacing {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Whitespace
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || ctx.list_blocks.is_empty()
     }
@@ -8,7 +8,7 @@ use std::ops::ControlFlow;
 use serde::{Deserialize, Serialize};
 
 use crate::lint_context::{LineInfo, LintContext};
-use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule::{Fix, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 
 mod md077_config;
 use md077_config::MD077Config;
@@ -755,6 +755,10 @@ impl Rule for MD077ListContinuationIndent {
         RuleCategory::List
     }
 
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::Structure
+    }
+
     fn should_skip(&self, ctx: &crate::lint_context::LintContext) -> bool {
         ctx.content.is_empty() || ctx.list_blocks.is_empty()
     }
@@ -2590,7 +2594,7 @@ mod tests {
     #[test]
     fn aligned_latent_table_in_continuation_is_idempotent() {
         // Regression (fuzzer-found): a table-looking line that the parser
-        // absorbs as lazy continuation (so it is NOT yet `in_table_block`) can
+        // absorbs as lazy continuation (so it is NOT yet in a table block) can
         // be promoted to a real table once an earlier line is reindented. Like
         // a latent marker, this re-parses the block, so the item is left alone.
         let content = "- \n![`]()\n|  | ` |\n| --- | --- |";
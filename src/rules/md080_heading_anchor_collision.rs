@@ -22,6 +22,7 @@ use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleC
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::anchor_styles::AnchorStyle;
 use crate::utils::range_utils::calculate_match_range;
+use crate::utils::slug::Slugify;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -60,6 +61,7 @@ impl RuleConfig for MD080Config {
 #[derive(Debug, Clone, Default)]
 pub struct MD080HeadingAnchorCollision {
     config: MD080Config,
+    slugify: Slugify,
 }
 
 impl MD080HeadingAnchorCollision {
@@ -68,7 +70,8 @@ impl MD080HeadingAnchorCollision {
     }
 
     pub fn from_config_struct(config: MD080Config) -> Self {
-        Self { config }
+        let slugify = config.anchor_style.clone().into();
+        Self { config, slugify }
     }
 
     /// The anchor a heading actually resolves to. An explicit `{#custom-id}`
@@ -79,7 +82,7 @@ impl MD080HeadingAnchorCollision {
     fn effective_anchor(&self, text: &str, custom_id: Option<&str>) -> String {
         match custom_id {
             Some(id) => id.to_string(),
-            None => self.config.anchor_style.generate_fragment(text),
+            None => self.slugify.slugify(text),
         }
     }
 
@@ -151,10 +154,10 @@ impl Rule for MD080HeadingAnchorCollision {
 
             // Regular ATX/Setext headings parsed by the line scanner.
             if let Some(heading) = &line_info.heading {
-                if heading.is_valid && !heading.text.is_empty() {
+                if heading.is_valid && !heading.text(ctx.content).is_empty() {
                     self.record(
-                        &heading.text,
-                        heading.custom_id.as_deref(),
+                        heading.text(ctx.content),
+                        heading.custom_id(ctx.content),
                         heading.level,
                         line_num,
                         content,
@@ -170,11 +173,11 @@ impl Rule for MD080HeadingAnchorCollision {
             // two rules agree on what targets exist.
             if let Some(bq) = &line_info.blockquote
                 && let Some((clean_text, custom_id)) =
-                    crate::utils::header_id_utils::parse_blockquote_atx_heading(&bq.content)
+                    crate::utils::header_id_utils::parse_blockquote_atx_heading(bq.content(ctx.content))
                 && !clean_text.is_empty()
             {
                 let level = bq
-                    .content
+                    .content(ctx.content)
                     .trim_start()
                     .bytes()
                     .take_while(|&b| b == b'#')
@@ -227,23 +230,12 @@ impl Rule for MD080HeadingAnchorCollision {
     where
         Self: Sized,
     {
-        let mut rule_config = crate::rule_config_serde::load_rule_config::<MD080Config>(config);
-
-        // Mirror MD051: when the user has not pinned an anchor style, follow
-        // the active flavor's native anchor generation.
-        let explicit_style_present = config
-            .rules
-            .get("MD080")
-            .is_some_and(|rc| rc.values.contains_key("anchor-style") || rc.values.contains_key("anchor_style"));
-        if !explicit_style_present {
-            rule_config.anchor_style = match config.global.flavor {
-                crate::config::MarkdownFlavor::MkDocs => AnchorStyle::PythonMarkdown,
-                crate::config::MarkdownFlavor::Kramdown => AnchorStyle::KramdownGfm,
-                _ => AnchorStyle::GitHub,
-            };
-        }
-
-        Box::new(MD080HeadingAnchorCollision::from_config_struct(rule_config))
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD080Config>(config);
+        let slugify = Slugify::for_rule(config, "MD080", &rule_config.anchor_style);
+        Box::new(MD080HeadingAnchorCollision {
+            config: rule_config,
+            slugify,
+        })
     }
 }
 
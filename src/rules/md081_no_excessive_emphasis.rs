@@ -182,7 +182,7 @@ impl MD081NoExcessiveEmphasis {
     /// The trimmed text of a line, ignoring any blockquote markers.
     fn line_inner<'a>(line: &'a crate::lint_context::LineInfo, source: &'a str) -> &'a str {
         match line.blockquote.as_ref() {
-            Some(bq) => bq.content.trim(),
+            Some(bq) => bq.content(source).trim(),
             None => line.content(source).trim(),
         }
     }
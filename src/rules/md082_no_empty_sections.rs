@@ -151,11 +151,11 @@ impl Rule for MD082NoEmptySections {
                 is_setext: matches!(h.heading.style, HeadingStyle::Setext1 | HeadingStyle::Setext2),
                 // The id was folded from the next line when the heading has an id
                 // but its own text carries no inline `{#id}`.
-                id_from_next_line: h.heading.custom_id.is_some()
-                    && crate::utils::header_id_utils::extract_header_id(&h.heading.raw_text)
+                id_from_next_line: h.heading.custom_id(ctx.content).is_some()
+                    && crate::utils::header_id_utils::extract_header_id(h.heading.raw_text(ctx.content))
                         .1
                         .is_none(),
-                text: h.heading.text.clone(),
+                text: h.heading.text(ctx.content).to_string(),
             })
             .collect();
 
@@ -0,0 +1,362 @@
+//! Rule MD083: JSX components should be imported or defined in the document.
+//!
+//! MDX compiles capitalized tags (`<Card>`) and member expressions
+//! (`<Tabs.Item>`) to references to JavaScript bindings. A component that is
+//! neither imported nor exported in the document fails at render time unless
+//! it is provided globally, so this rule reports every such usage.
+//!
+//! MDX flavor only; a no-op for every other flavor.
+//!
+//! See [docs/md083.md](../../docs/md083.md) for full documentation, configuration, and examples.
+
+use std::collections::HashSet;
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::range_utils::byte_to_char_count;
+
+mod md083_config;
+pub(super) use md083_config::MD083Config;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD083UndefinedJsxComponents {
+    config: MD083Config,
+}
+
+impl MD083UndefinedJsxComponents {
+    pub fn new(allowed_components: Vec<String>) -> Self {
+        Self {
+            config: MD083Config { allowed_components },
+        }
+    }
+
+    pub fn from_config_struct(config: MD083Config) -> Self {
+        Self { config }
+    }
+}
+
+/// Whether `c` can continue a JavaScript identifier (ASCII subset).
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+/// The leading identifier of `s`, if any.
+fn leading_ident(s: &str) -> Option<&str> {
+    let s = s.trim_start();
+    let len = s.find(|c: char| !is_ident_char(c)).unwrap_or(s.len());
+    let ident = &s[..len];
+    (!ident.is_empty() && !ident.starts_with(|c: char| c.is_ascii_digit())).then_some(ident)
+}
+
+/// Collect the local names bound by an `import { a, b as c }` or
+/// `const { a, b: c }` specifier list (the text between the braces).
+fn collect_specifiers(list: &str, rename_separator: &str, names: &mut HashSet<String>) {
+    for spec in list.split(',') {
+        let spec = spec.trim();
+        if spec.is_empty() || spec.starts_with("type ") {
+            continue;
+        }
+        let local = match spec.split_once(rename_separator) {
+            Some((_, local)) => local,
+            None => spec,
+        };
+        // Strip default values in destructuring (`a = 1`) and rest syntax (`...rest`)
+        let local = local
+            .split('=')
+            .next()
+            .unwrap_or(local)
+            .trim()
+            .trim_start_matches("...");
+        if let Some(ident) = leading_ident(local) {
+            names.insert(ident.to_string());
+        }
+    }
+}
+
+/// Text between the first `{` and its matching `}` in `s`.
+fn braced(s: &str) -> Option<&str> {
+    let open = s.find('{')?;
+    let close = s[open..].find('}')? + open;
+    Some(&s[open + 1..close])
+}
+
+/// Collect the bindings an ESM statement introduces into the module scope.
+fn collect_esm_bindings(statement: &str, names: &mut HashSet<String>) {
+    let statement = statement.trim();
+
+    if let Some(rest) = statement.strip_prefix("import") {
+        let rest = rest.trim_start();
+        // Type-only imports and side-effect imports bind no values
+        if rest.starts_with("type ") || rest.starts_with(['\'', '"']) {
+            return;
+        }
+        let clause = match rest.rfind(" from ") {
+            Some(idx) => &rest[..idx],
+            None => rest,
+        };
+        // Default import: `import Foo from` / `import Foo, { ... } from`
+        if !clause.starts_with(['{', '*'])
+            && let Some(ident) = leading_ident(clause)
+        {
+            names.insert(ident.to_string());
+        }
+        // Namespace import: `import * as Foo from`
+        if let Some(idx) = clause.find("* as ")
+            && let Some(ident) = leading_ident(&clause[idx + 5..])
+        {
+            names.insert(ident.to_string());
+        }
+        if let Some(list) = braced(clause) {
+            collect_specifiers(list, " as ", names);
+        }
+        return;
+    }
+
+    let Some(rest) = statement.strip_prefix("export") else {
+        return;
+    };
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix("default ").unwrap_or(rest).trim_start();
+    for keyword in [
+        "const ",
+        "let ",
+        "var ",
+        "function* ",
+        "function ",
+        "async function ",
+        "class ",
+    ] {
+        if let Some(decl) = rest.strip_prefix(keyword) {
+            let decl = decl.trim_start();
+            if decl.starts_with('{') {
+                if let Some(list) = braced(decl) {
+                    collect_specifiers(list, ":", names);
+                }
+            } else if let Some(ident) = leading_ident(decl) {
+                names.insert(ident.to_string());
+            }
+            return;
+        }
+    }
+    // `export { a }` without `from` refers to existing bindings; re-exports
+    // (`export { a } from '...'`) bind nothing locally.
+}
+
+/// Gather the ESM statements of the document (one string per statement).
+fn esm_statements(ctx: &LintContext) -> Vec<String> {
+    let mut statements: Vec<String> = Vec::new();
+    for line in &ctx.lines {
+        if !line.in_esm_block {
+            continue;
+        }
+        let text = line.content(ctx.content).trim();
+        if text.starts_with("import ") || text.starts_with("export ") || statements.is_empty() {
+            statements.push(text.to_string());
+        } else if let Some(last) = statements.last_mut() {
+            last.push(' ');
+            last.push_str(text);
+        }
+    }
+    statements
+}
+
+/// A JSX component usage found on a line.
+struct ComponentUsage<'a> {
+    /// Full tag name, e.g. `Tabs.Item`
+    name: &'a str,
+    /// Root binding the tag refers to, e.g. `Tabs`
+    root: &'a str,
+    /// Byte offset of `<` within the line
+    start: usize,
+    /// Byte offset just past the name within the line
+    end: usize,
+}
+
+/// Find opening JSX component tags on a line: capitalized names and member
+/// expressions (`<motion.div>`). Closing tags and fragments are ignored.
+fn find_component_usages(line: &str) -> Vec<ComponentUsage<'_>> {
+    let mut usages = Vec::new();
+    for (start, _) in line.match_indices('<') {
+        let after = &line[start + 1..];
+        let name_len = after
+            .find(|c: char| !(is_ident_char(c) || c == '.'))
+            .unwrap_or(after.len());
+        let name = after[..name_len].trim_end_matches('.');
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        // A tag name must be followed by whitespace, `/`, `>`, or end of line;
+        // this rules out autolinks (`<Https://...>`) and comparisons.
+        let next = after[name.len()..].chars().next();
+        if !matches!(next, None | Some(' ' | '\t' | '/' | '>')) {
+            continue;
+        }
+        let root = name.split('.').next().unwrap_or(name);
+        let is_component = name.contains('.') || root.starts_with(|c: char| c.is_ascii_uppercase());
+        if is_component {
+            usages.push(ComponentUsage {
+                name,
+                root,
+                start,
+                end: start + 1 + name.len(),
+            });
+        }
+    }
+    usages
+}
+
+impl Rule for MD083UndefinedJsxComponents {
+    fn name(&self) -> &'static str {
+        "MD083"
+    }
+
+    fn description(&self) -> &'static str {
+        "JSX components should be imported or defined"
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if ctx.flavor != MarkdownFlavor::MDX {
+            return Ok(Vec::new());
+        }
+
+        let mut defined: HashSet<String> = self.config.allowed_components.iter().cloned().collect();
+        for statement in esm_statements(ctx) {
+            collect_esm_bindings(&statement, &mut defined);
+        }
+
+        let mut warnings = Vec::new();
+        for (idx, line) in ctx.lines.iter().enumerate() {
+            if line.in_code_block || line.in_front_matter || line.in_html_comment || line.in_esm_block {
+                continue;
+            }
+            let content = line.content(ctx.content);
+            if !content.contains('<') {
+                continue;
+            }
+            for usage in find_component_usages(content) {
+                if defined.contains(usage.root) || ctx.is_in_code_span_byte(line.byte_offset + usage.start) {
+                    continue;
+                }
+                let message = if usage.name == usage.root {
+                    format!("JSX component `{}` is used but not imported or defined", usage.name)
+                } else {
+                    format!(
+                        "JSX component `{}` is used but `{}` is not imported or defined",
+                        usage.name, usage.root
+                    )
+                };
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column: byte_to_char_count(content, usage.start),
+                    end_line: idx + 1,
+                    end_column: byte_to_char_count(content, usage.end),
+                    severity: Severity::Warning,
+                    message,
+                    fix: None,
+                });
+            }
+        }
+        Ok(warnings)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        // The import source is unknown; the rule is diagnostic-only.
+        FixCapability::Unfixable
+    }
+
+    fn fix(&self, _ctx: &LintContext) -> Result<String, LintError> {
+        // MD083 has no auto-fix: the module a component comes from is unknown.
+        Err(LintError::FixFailed("MD083 has no auto-fix".to_string()))
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Html
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.flavor != MarkdownFlavor::MDX || !ctx.likely_has_html()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD083Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let rule = MD083UndefinedJsxComponents::default();
+        let ctx = LintContext::new(content, MarkdownFlavor::MDX, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_imported_components_are_not_flagged() {
+        let content = "import Card from './card'\nimport { Tabs, TabItem as Item } from '@theme/Tabs'\nimport * as UI from 'ui'\n\n# Title\n\n<Card title=\"x\" />\n\n<Tabs>\n<Item>One</Item>\n</Tabs>\n\n<UI.Button />\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_missing_import_is_flagged() {
+        let content = "import Card from './card'\n\n# Title\n\n<Card />\n\n<Alert type=\"info\">Careful</Alert>\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 7);
+        assert_eq!(warnings[0].column, 1);
+        assert!(warnings[0].message.contains("`Alert`"));
+    }
+
+    #[test]
+    fn test_member_expression_checks_root() {
+        let content = "# Title\n\n<motion.div animate={{ x: 1 }} />\n<Tabs.Item />\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].message.contains("`motion`"));
+        assert!(warnings[1].message.contains("`Tabs`"));
+    }
+
+    #[test]
+    fn test_exported_definitions_count_as_defined() {
+        let content = "export const Highlight = ({children}) => <mark>{children}</mark>\nexport function Note() { return null }\nexport const { Box, Grid: Layout } = components\n\n<Highlight>hi</Highlight> <Note /> <Box /> <Layout />\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_multiline_import() {
+        let content = "import {\n  Alpha,\n  Beta,\n} from './components'\n\n<Alpha />\n<Beta />\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_type_and_side_effect_imports_do_not_define() {
+        let content = "import type { Props } from './types'\nimport './styles.css'\n\n<Props />\n";
+        assert_eq!(check(content).len(), 1);
+    }
+
+    #[test]
+    fn test_code_and_html_are_ignored() {
+        let content = "# Title\n\n`<Missing />` in code\n\n```jsx\n<Missing />\n```\n\n<div>plain html</div>\n\n<https://example.com>\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_allowed_components() {
+        let rule = MD083UndefinedJsxComponents::new(vec!["Admonition".to_string()]);
+        let content = "<Admonition type=\"tip\">Hi</Admonition>\n<Admonition.Title />\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::MDX, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_non_mdx_flavor_is_noop() {
+        let rule = MD083UndefinedJsxComponents::default();
+        let ctx = LintContext::new("<Alert />\n", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
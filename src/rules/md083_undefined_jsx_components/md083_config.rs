@@ -0,0 +1,16 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD083Config {
+    /// Components available without an import, e.g. those registered globally
+    /// through `MDXProvider` or a framework's component map. Member expressions
+    /// are matched by their root (`Tabs` allows `<Tabs.Item>`).
+    #[serde(default, alias = "allowed_components")]
+    pub allowed_components: Vec<String>,
+}
+
+impl RuleConfig for MD083Config {
+    const RULE_NAME: &'static str = "MD083";
+}
@@ -0,0 +1,330 @@
+//! Rule MD084: Shell code blocks should use a consistent prompt style.
+//!
+//! Documentation either writes shell commands bare (copy-paste friendly) or
+//! prefixes them with `$ ` so that command and output can be told apart. This
+//! rule enforces one convention across the fenced code blocks whose language
+//! is a shell, and its fix adds or strips the `$ ` prefix.
+//!
+//! See [docs/md084.md](../../docs/md084.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::code_block_utils::CodeBlockDetail;
+
+mod md084_config;
+pub(super) use md084_config::MD084Config;
+pub use md084_config::ShellPromptStyle;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD084ShellPromptStyle {
+    config: MD084Config,
+}
+
+/// A non-blank line in the body of a shell code block.
+struct BodyLine<'a> {
+    line_num: usize,
+    byte_offset: usize,
+    indent: &'a str,
+    text: &'a str,
+    /// The previous line ended with `\`, so this line continues its command.
+    is_continuation: bool,
+}
+
+impl BodyLine<'_> {
+    fn has_prompt(&self) -> bool {
+        prompt_len(self.text).is_some()
+    }
+
+    fn is_comment(&self) -> bool {
+        self.text.starts_with('#')
+    }
+}
+
+/// Length of the `$` prompt marker plus one following space, if `text` has one.
+fn prompt_len(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('$')?;
+    if rest.is_empty() {
+        Some(1)
+    } else if rest.starts_with([' ', '\t']) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+impl MD084ShellPromptStyle {
+    pub fn new(style: ShellPromptStyle) -> Self {
+        Self {
+            config: MD084Config {
+                style,
+                ..MD084Config::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD084Config) -> Self {
+        Self { config }
+    }
+
+    fn is_shell_block(&self, detail: &CodeBlockDetail) -> bool {
+        if !detail.is_fenced {
+            return false;
+        }
+        let lang = detail
+            .info_string
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .trim_start_matches('{')
+            .trim_start_matches('.')
+            .trim_end_matches('}');
+        !lang.is_empty() && self.config.languages.iter().any(|l| l.eq_ignore_ascii_case(lang))
+    }
+
+    /// Non-blank lines between the opening and closing fences of `detail`.
+    /// Blocks inside blockquotes are skipped: their lines carry `>` markers
+    /// that would be mistaken for command text.
+    fn body_lines<'a>(ctx: &'a LintContext, detail: &CodeBlockDetail) -> Vec<BodyLine<'a>> {
+        let open_idx = ctx
+            .line_offsets
+            .binary_search(&detail.start)
+            .unwrap_or_else(|i| i.saturating_sub(1));
+        if ctx.lines.get(open_idx).is_none_or(|info| info.blockquote.is_some()) {
+            return Vec::new();
+        }
+
+        let mut body = Vec::new();
+        let mut continues = false;
+        for (idx, info) in ctx.lines.iter().enumerate().skip(open_idx + 1) {
+            if info.byte_offset >= detail.end || !info.in_code_block {
+                break;
+            }
+            let raw = info.content(ctx.content);
+            let text = raw.trim_start();
+            if text.starts_with("```") || text.starts_with("~~~") {
+                break;
+            }
+            if text.trim_end().is_empty() {
+                continues = false;
+                continue;
+            }
+            let indent = &raw[..raw.len() - text.len()];
+            body.push(BodyLine {
+                line_num: idx + 1,
+                byte_offset: info.byte_offset,
+                indent,
+                text,
+                is_continuation: continues,
+            });
+            continues = text.trim_end().ends_with('\\');
+        }
+        body
+    }
+
+    fn missing_prompt_warning(&self, line: &BodyLine<'_>) -> LintWarning {
+        let column = line.indent.chars().count() + 1;
+        let insert_at = line.byte_offset + line.indent.len();
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: line.line_num,
+            column,
+            end_line: line.line_num,
+            end_column: column + line.text.chars().count(),
+            message: "Shell command should have a `$` prompt".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix::new(insert_at..insert_at, "$ ".to_string())),
+        }
+    }
+
+    fn extra_prompt_warning(&self, line: &BodyLine<'_>, len: usize) -> LintWarning {
+        let column = line.indent.chars().count() + 1;
+        let start = line.byte_offset + line.indent.len();
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: line.line_num,
+            column,
+            end_line: line.line_num,
+            end_column: column + len,
+            message: "Shell command should not have a `$` prompt".to_string(),
+            severity: Severity::Warning,
+            fix: Some(Fix::new(start..start + len, String::new())),
+        }
+    }
+}
+
+impl Rule for MD084ShellPromptStyle {
+    fn name(&self) -> &'static str {
+        "MD084"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shell code blocks should use a consistent prompt style"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let mut style = match self.config.style {
+            ShellPromptStyle::Consistent => None,
+            style => Some(style),
+        };
+
+        for detail in &ctx.code_block_details {
+            if !self.is_shell_block(detail) {
+                continue;
+            }
+            let body = Self::body_lines(ctx, detail);
+            if body.iter().all(BodyLine::is_comment) {
+                continue;
+            }
+            let has_prompt = body.iter().any(BodyLine::has_prompt);
+
+            // In consistent mode the first shell block with a command decides.
+            let style = *style.get_or_insert(if has_prompt {
+                ShellPromptStyle::Prompt
+            } else {
+                ShellPromptStyle::NoPrompt
+            });
+
+            match style {
+                ShellPromptStyle::NoPrompt => {
+                    for line in &body {
+                        if let Some(len) = prompt_len(line.text) {
+                            warnings.push(self.extra_prompt_warning(line, len));
+                        }
+                    }
+                }
+                // A block that already has prompts is read as commands plus
+                // output, so only a block without any prompt is reported.
+                ShellPromptStyle::Prompt if !has_prompt => {
+                    for line in body.iter().filter(|line| !line.is_comment() && !line.is_continuation) {
+                        warnings.push(self.missing_prompt_warning(line));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_code() || ctx.code_block_details.is_empty()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD084Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD084ShellPromptStyle, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD084ShellPromptStyle, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_no_prompt_flags_and_strips_prompts() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::NoPrompt);
+        let content = "```bash\n$ cargo build\n$ cargo test\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].line, 2);
+        assert_eq!((warnings[0].column, warnings[0].end_column), (1, 3));
+        assert_eq!(fix(&rule, content), "```bash\ncargo build\ncargo test\n```\n");
+    }
+
+    #[test]
+    fn test_prompt_adds_prompts_to_bare_commands() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::Prompt);
+        let content = "```sh\n# Build the project\ncargo build \\\n  --release\n\ncargo test\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(
+            fix(&rule, content),
+            "```sh\n# Build the project\n$ cargo build \\\n  --release\n\n$ cargo test\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_prompt_treats_unprefixed_lines_as_output() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::Prompt);
+        let content = "```console\n$ echo hello\nhello\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_consistent_follows_first_shell_block() {
+        let rule = MD084ShellPromptStyle::default();
+        let content = "```bash\n$ make\n```\n\n```bash\nmake install\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 6);
+        assert!(warnings[0].message.contains("should have"));
+
+        let content = "```bash\nmake\n```\n\n```bash\n$ make install\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("should not have"));
+    }
+
+    #[test]
+    fn test_non_shell_and_unlabeled_blocks_are_ignored() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::NoPrompt);
+        let content = "```python\n$ not a prompt\n```\n\n```\n$ ls\n```\n\n    $ ls\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_languages_are_configurable_and_case_insensitive() {
+        let rule = MD084ShellPromptStyle::from_config_struct(MD084Config {
+            style: ShellPromptStyle::NoPrompt,
+            languages: vec!["PowerShell".to_string()],
+        });
+        assert_eq!(check(&rule, "```powershell\n$ Get-Item .\n```\n").len(), 1);
+        assert!(check(&rule, "```bash\n$ ls\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_dollar_without_space_is_not_a_prompt() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::NoPrompt);
+        assert!(check(&rule, "```bash\n$HOME/bin/tool\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_indented_block_in_list_keeps_indentation() {
+        let rule = MD084ShellPromptStyle::new(ShellPromptStyle::NoPrompt);
+        let content = "- Install:\n\n  ```bash\n  $ cargo install rumdl\n  ```\n";
+        assert_eq!(
+            fix(&rule, content),
+            "- Install:\n\n  ```bash\n  cargo install rumdl\n  ```\n"
+        );
+    }
+}
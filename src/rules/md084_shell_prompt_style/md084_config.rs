@@ -0,0 +1,66 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Prompt convention for shell code blocks (MD084)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ShellPromptStyle {
+    /// Follow the convention of the first shell block in the document
+    #[default]
+    Consistent,
+    /// Commands are written bare, without a `$ ` prefix
+    #[serde(alias = "no_prompt")]
+    NoPrompt,
+    /// Commands carry a `$ ` prefix; unprefixed lines are output
+    Prompt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD084Config {
+    /// Prompt convention to enforce: `consistent`, `no-prompt`, or `prompt`
+    #[serde(default)]
+    pub style: ShellPromptStyle,
+
+    /// Code block languages treated as shell sessions (case-insensitive)
+    #[serde(default = "default_languages")]
+    pub languages: Vec<String>,
+}
+
+fn default_languages() -> Vec<String> {
+    ["bash", "console", "sh", "shell", "shell-session", "terminal", "zsh"]
+        .iter()
+        .map(|s| (*s).to_string())
+        .collect()
+}
+
+impl Default for MD084Config {
+    fn default() -> Self {
+        Self {
+            style: ShellPromptStyle::default(),
+            languages: default_languages(),
+        }
+    }
+}
+
+impl RuleConfig for MD084Config {
+    const RULE_NAME: &'static str = "MD084";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_accepts_kebab_and_snake_case() {
+        let config: MD084Config = toml::from_str(r#"style = "no-prompt""#).unwrap();
+        assert_eq!(config.style, ShellPromptStyle::NoPrompt);
+
+        let config: MD084Config = toml::from_str(r#"style = "no_prompt""#).unwrap();
+        assert_eq!(config.style, ShellPromptStyle::NoPrompt);
+
+        let config: MD084Config = toml::from_str(r#"style = "prompt""#).unwrap();
+        assert_eq!(config.style, ShellPromptStyle::Prompt);
+        assert!(config.languages.contains(&"console".to_string()));
+    }
+}
@@ -0,0 +1,304 @@
+//! Rule MD085: Admonition and callout titles should follow a capitalization style.
+//!
+//! Titles on MkDocs admonitions (`!!! note "Title"`) and blockquote callouts
+//! (`> [!note] Title`) read like headings, so this rule runs them through the
+//! same capitalization engine as MD063, configured independently of it.
+//! GitHub alerts carry no title, so for `> [!NOTE]` the first line of the
+//! alert body is checked instead. Fixes rewrite only the title text.
+//!
+//! See [docs/md085.md](../../docs/md085.md) for full documentation, configuration, and examples.
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rules::md063_heading_capitalization::{HeadingCapStyle, MD063HeadingCapitalization};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+mod md085_config;
+pub(super) use md085_config::MD085Config;
+
+/// `!!! type "Title"`, `??? type "Title"`, `???+ type inline end "Title"`;
+/// group 1 is the title between the quotes
+static ADMONITION_TITLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*(?:!!!|\?\?\?\+?)\s+[A-Za-z][\w-]*(?:\s+inline(?:\s+end)?)?\s+"([^"]*)""#).unwrap()
+});
+
+/// `> [!type]` with an optional fold marker and title; group 1 is the title
+static CALLOUT_MARKER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(?:>\s*)+\[![A-Za-z][\w-]*\][+-]?(?:[ \t]+(\S.*?))?[ \t]*$").unwrap());
+
+/// Blockquote markers in front of an alert body line
+static BLOCKQUOTE_PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(?:>\s*)+").unwrap());
+
+/// Where a checked title came from, for the warning message
+#[derive(Clone, Copy)]
+enum TitleKind {
+    Admonition,
+    Callout,
+    AlertFirstLine,
+}
+
+impl TitleKind {
+    fn label(self) -> &'static str {
+        match self {
+            TitleKind::Admonition => "Admonition title",
+            TitleKind::Callout => "Callout title",
+            TitleKind::AlertFirstLine => "First line of alert",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MD085CalloutTitleCapitalization {
+    config: MD085Config,
+    engine: MD063HeadingCapitalization,
+}
+
+impl Default for MD085CalloutTitleCapitalization {
+    fn default() -> Self {
+        Self::from_config_struct(MD085Config::default())
+    }
+}
+
+impl MD085CalloutTitleCapitalization {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD085Config) -> Self {
+        let engine = MD063HeadingCapitalization::from_config_struct(config.capitalization_config());
+        Self { config, engine }
+    }
+
+    fn style_name(&self) -> &'static str {
+        match self.config.style {
+            HeadingCapStyle::TitleCase => "title case",
+            HeadingCapStyle::SentenceCase => "sentence case",
+            HeadingCapStyle::AllCaps => "ALL CAPS",
+        }
+    }
+
+    /// Title byte range within `line`, if the line carries a title to check.
+    /// `pending_alert` tracks a title-less alert marker on the previous line.
+    fn title_range(
+        &self,
+        ctx: &LintContext,
+        line: &str,
+        pending_alert: &mut bool,
+    ) -> Option<(Range<usize>, TitleKind)> {
+        let alert_body = std::mem::take(pending_alert);
+
+        if self.config.admonitions
+            && ctx.flavor == MarkdownFlavor::MkDocs
+            && let Some(title) = ADMONITION_TITLE.captures(line).and_then(|caps| caps.get(1))
+        {
+            return Some((title.range(), TitleKind::Admonition));
+        }
+
+        if !self.config.alerts {
+            return None;
+        }
+        if let Some(caps) = CALLOUT_MARKER.captures(line) {
+            return match caps.get(1) {
+                Some(title) => Some((title.range(), TitleKind::Callout)),
+                None => {
+                    *pending_alert = true;
+                    None
+                }
+            };
+        }
+        if alert_body {
+            let prefix = BLOCKQUOTE_PREFIX.find(line)?;
+            let body = line[prefix.end()..].trim_end();
+            if !body.is_empty() {
+                return Some((prefix.end()..prefix.end() + body.len(), TitleKind::AlertFirstLine));
+            }
+        }
+        None
+    }
+}
+
+impl Rule for MD085CalloutTitleCapitalization {
+    fn name(&self) -> &'static str {
+        "MD085"
+    }
+
+    fn description(&self) -> &'static str {
+        "Admonition and callout titles should use a consistent capitalization style"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn fix_safety(&self) -> FixSafety {
+        // Recapitalizes callout titles written by the author
+        FixSafety::Unsafe
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let mut pending_alert = false;
+
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            if info.in_code_block || info.in_front_matter || info.in_html_comment {
+                pending_alert = false;
+                continue;
+            }
+            let line = info.content(ctx.content);
+            let Some((range, kind)) = self.title_range(ctx, line, &mut pending_alert) else {
+                continue;
+            };
+
+            let title = &line[range.clone()];
+            let fixed = self.engine.apply_capitalization(title);
+            if fixed == title {
+                continue;
+            }
+
+            let column = line[..range.start].chars().count() + 1;
+            let start = info.byte_offset + range.start;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column,
+                end_line: idx + 1,
+                end_column: column + title.chars().count(),
+                message: format!(
+                    "{} should use {}: '{title}' -> '{fixed}'",
+                    kind.label(),
+                    self.style_name()
+                ),
+                severity: Severity::Warning,
+                fix: Some(Fix::new(start..start + range.len(), fixed)),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("[!") && !ctx.content.contains("!!!") && !ctx.content.contains("???")
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD085Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD085Config>(config);
+        let md044_config =
+            crate::rule_config_serde::load_rule_config::<crate::rules::md044_proper_names::MD044Config>(config);
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.engine = rule.engine.with_proper_names(md044_config.names);
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(rule: &MD085CalloutTitleCapitalization, content: &str, flavor: MarkdownFlavor) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, flavor, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD085CalloutTitleCapitalization, content: &str, flavor: MarkdownFlavor) -> String {
+        let ctx = LintContext::new(content, flavor, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    fn rule_with_style(style: HeadingCapStyle) -> MD085CalloutTitleCapitalization {
+        MD085CalloutTitleCapitalization::from_config_struct(MD085Config {
+            style,
+            ..MD085Config::default()
+        })
+    }
+
+    #[test]
+    fn test_admonition_title_fix_rewrites_only_title() {
+        let rule = MD085CalloutTitleCapitalization::new();
+        let content = "!!! note \"getting started with the api\"\n    Body text stays as is.\n";
+        let warnings = check(&rule, content, MarkdownFlavor::MkDocs);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].column, warnings[0].end_column), (11, 39));
+        assert_eq!(
+            fix(&rule, content, MarkdownFlavor::MkDocs),
+            "!!! note \"Getting Started with the Api\"\n    Body text stays as is.\n"
+        );
+    }
+
+    #[test]
+    fn test_admonitions_only_checked_in_mkdocs_flavor() {
+        let rule = MD085CalloutTitleCapitalization::new();
+        let content = "??? tip \"read this\"\n";
+        assert_eq!(check(&rule, content, MarkdownFlavor::MkDocs).len(), 1);
+        assert!(check(&rule, content, MarkdownFlavor::Standard).is_empty());
+        assert!(check(&rule, "!!! note\n    No title.\n", MarkdownFlavor::MkDocs).is_empty());
+    }
+
+    #[test]
+    fn test_callout_title_with_fold_marker() {
+        let rule = rule_with_style(HeadingCapStyle::SentenceCase);
+        let content = "> [!tip]- Read This First\n> Body.\n";
+        assert_eq!(
+            fix(&rule, content, MarkdownFlavor::Obsidian),
+            "> [!tip]- Read this first\n> Body.\n"
+        );
+    }
+
+    #[test]
+    fn test_github_alert_checks_first_body_line() {
+        let rule = rule_with_style(HeadingCapStyle::SentenceCase);
+        let content = "> [!NOTE]\n> Useful Information For Users\n> Second Line Is Not Checked\n";
+        let warnings = check(&rule, content, MarkdownFlavor::Standard);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+        assert!(warnings[0].message.starts_with("First line of alert"));
+        assert_eq!(
+            fix(&rule, content, MarkdownFlavor::Standard),
+            "> [!NOTE]\n> Useful information for users\n> Second Line Is Not Checked\n"
+        );
+    }
+
+    #[test]
+    fn test_targets_can_be_disabled() {
+        let rule = MD085CalloutTitleCapitalization::from_config_struct(MD085Config {
+            admonitions: false,
+            alerts: false,
+            ..MD085Config::default()
+        });
+        let content = "!!! note \"lower title\"\n\n> [!note] lower title\n";
+        assert!(check(&rule, content, MarkdownFlavor::MkDocs).is_empty());
+    }
+
+    #[test]
+    fn test_code_blocks_and_inline_code_are_preserved() {
+        let rule = MD085CalloutTitleCapitalization::new();
+        let content = "```markdown\n> [!note] lower title\n```\n\n> [!note] Using `cargo test` Locally\n";
+        assert!(check(&rule, content, MarkdownFlavor::Standard).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::rule_config_serde::RuleConfig;
+use crate::rules::md063_heading_capitalization::{HeadingCapStyle, MD063Config};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD085Config {
+    /// Capitalization style to enforce: `title_case`, `sentence_case`, or `all_caps`
+    #[serde(default)]
+    pub style: HeadingCapStyle,
+
+    /// Words that stay lowercase in title case
+    #[serde(default = "default_lowercase_words", alias = "lowercase_words")]
+    pub lowercase_words: Vec<String>,
+
+    /// Words kept exactly as written (brand names like iPhone, macOS)
+    #[serde(default, alias = "ignore_words")]
+    pub ignore_words: Vec<String>,
+
+    /// Keep existing mixed-case words even if not in `ignore-words`
+    #[serde(default = "default_true", alias = "preserve_cased_words")]
+    pub preserve_cased_words: bool,
+
+    /// Check MkDocs admonition titles (`!!! note "Title"`, MkDocs flavor only)
+    #[serde(default = "default_true")]
+    pub admonitions: bool,
+
+    /// Check callout titles (`> [!note] Title`) and the first line of
+    /// title-less GitHub alerts (`> [!NOTE]`)
+    #[serde(default = "default_true")]
+    pub alerts: bool,
+}
+
+fn default_lowercase_words() -> Vec<String> {
+    MD063Config::default().lowercase_words
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for MD085Config {
+    fn default() -> Self {
+        Self {
+            style: HeadingCapStyle::default(),
+            lowercase_words: default_lowercase_words(),
+            ignore_words: Vec::new(),
+            preserve_cased_words: true,
+            admonitions: true,
+            alerts: true,
+        }
+    }
+}
+
+impl MD085Config {
+    /// Settings for the MD063 capitalization engine. Heading levels don't
+    /// apply to titles, so the full range is kept.
+    pub(super) fn capitalization_config(&self) -> MD063Config {
+        MD063Config {
+            enabled: true,
+            style: self.style,
+            lowercase_words: self.lowercase_words.clone(),
+            ignore_words: self.ignore_words.clone(),
+            preserve_cased_words: self.preserve_cased_words,
+            ..MD063Config::default()
+        }
+    }
+}
+
+impl RuleConfig for MD085Config {
+    const RULE_NAME: &'static str = "MD085";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_accepts_kebab_and_snake_case() {
+        let config: MD085Config =
+            toml::from_str("style = \"sentence-case\"\nignore_words = [\"macOS\"]\nalerts = false").unwrap();
+        assert_eq!(config.style, HeadingCapStyle::SentenceCase);
+        assert_eq!(config.ignore_words, vec!["macOS".to_string()]);
+        assert!(config.admonitions);
+        assert!(!config.alerts);
+    }
+}
@@ -0,0 +1,262 @@
+//! Rule MD086: Image files should be referenced by at least one Markdown file.
+//!
+//! An opt-in workspace rule. While files are linted, the workspace index
+//! records every local asset each document references. Once the whole
+//! workspace is indexed, the configured image directories are scanned and
+//! every image no document references is reported against the image's own
+//! path, so repositories can prune dead assets.
+//!
+//! See [docs/md086.md](../../docs/md086.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{CrossFileScope, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::project_root::discover_project_root_from;
+use crate::workspace_index::WorkspaceIndex;
+use globset::{Glob, GlobSetBuilder};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+mod md086_config;
+pub(super) use md086_config::MD086Config;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD086UnusedImages {
+    config: MD086Config,
+    /// Root that `directories`, `exclude` and root-relative links resolve
+    /// against; discovered from the working directory when unset
+    project_root: Option<PathBuf>,
+}
+
+impl MD086UnusedImages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD086Config) -> Self {
+        Self {
+            config,
+            project_root: None,
+        }
+    }
+
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(project_root);
+        self
+    }
+
+    fn is_image(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| self.config.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+    }
+
+    /// Every image file under the configured directories, sorted
+    fn scan_images(&self, root: &Path) -> BTreeSet<PathBuf> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &self.config.exclude {
+            if let Ok(glob) = Glob::new(pattern) {
+                builder.add(glob);
+            }
+        }
+        let exclude = builder.build().unwrap_or_default();
+
+        let mut images = BTreeSet::new();
+        for dir in &self.config.directories {
+            let base = root.join(dir);
+            if !base.is_dir() {
+                continue;
+            }
+            for entry in ignore::WalkBuilder::new(&base).build().flatten() {
+                let path = entry.path();
+                if !entry.file_type().is_some_and(|t| t.is_file()) || !self.is_image(path) {
+                    continue;
+                }
+                let path = normalize_path(path);
+                if exclude.is_match(path.strip_prefix(root).unwrap_or(&path)) {
+                    continue;
+                }
+                images.insert(path);
+            }
+        }
+        images
+    }
+}
+
+impl Rule for MD086UnusedImages {
+    fn name(&self) -> &'static str {
+        "MD086"
+    }
+
+    fn description(&self) -> &'static str {
+        "Image files should be referenced by at least one Markdown file"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Image
+    }
+
+    fn check(&self, _ctx: &LintContext) -> LintResult {
+        // Nothing to report per document; see workspace_check
+        Ok(Vec::new())
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD086Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD086Config>(config);
+        Box::new(Self {
+            config: rule_config,
+            project_root: config.project_root.clone(),
+        })
+    }
+
+    fn cross_file_scope(&self) -> CrossFileScope {
+        CrossFileScope::Workspace
+    }
+
+    fn workspace_check(&self, workspace_index: &WorkspaceIndex) -> Result<Vec<(PathBuf, Vec<LintWarning>)>, LintError> {
+        let root = match &self.project_root {
+            Some(root) => root.clone(),
+            None => discover_project_root_from(&std::env::current_dir()?),
+        };
+        let root = root.canonicalize().unwrap_or(root);
+
+        let mut roots = vec![root.clone()];
+        roots.extend(
+            self.config
+                .directories
+                .iter()
+                .map(|dir| normalize_path(&root.join(dir))),
+        );
+        let referenced = workspace_index.referenced_assets(&roots);
+
+        Ok(self
+            .scan_images(&root)
+            .into_iter()
+            .filter(|image| !referenced.contains(image))
+            .map(|image| {
+                let warning = LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 1,
+                    message: "Image is not referenced by any Markdown file".to_string(),
+                    severity: Severity::Warning,
+                    fix: None,
+                };
+                (image, vec![warning])
+            })
+            .collect())
+    }
+}
+
+/// Normalize a path by resolving . and .. components
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                components.pop();
+            }
+            std::path::Component::CurDir => {}
+            _ => components.push(component),
+        }
+    }
+    components.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workspace_index::FileIndex;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn touch(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    fn index_with(root: &Path, file: &str, references: &[&str]) -> WorkspaceIndex {
+        let mut index = WorkspaceIndex::new();
+        let mut file_index = FileIndex::new();
+        file_index.asset_references = references.iter().map(ToString::to_string).collect();
+        index.insert_file(root.join(file), file_index);
+        index
+    }
+
+    fn unused(rule: &MD086UnusedImages, index: &WorkspaceIndex, root: &Path) -> Vec<String> {
+        rule.workspace_check(index)
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_reports_only_unreferenced_images() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        for file in [
+            "docs/img/used.png",
+            "docs/img/unused.PNG",
+            "assets/logo.svg",
+            "notes.txt",
+        ] {
+            touch(&root, file);
+        }
+        let index = index_with(&root, "docs/guide.md", &["img/used.png", "/assets/logo.svg"]);
+
+        let rule = MD086UnusedImages::new().with_project_root(root.clone());
+        assert_eq!(unused(&rule, &index, &root), vec!["docs/img/unused.PNG"]);
+
+        let warnings = rule.workspace_check(&index).unwrap().remove(0).1;
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 1));
+        assert_eq!(warnings[0].rule_name.as_deref(), Some("MD086"));
+    }
+
+    #[test]
+    fn test_directories_and_exclude_limit_the_scan() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        for file in ["static/images/a.png", "static/favicon.ico", "src/icon.png"] {
+            touch(&root, file);
+        }
+        // Hugo-style: `/images/a.png` is served from `static/`
+        let index = index_with(&root, "content/post.md", &["/images/a.png"]);
+
+        let rule = MD086UnusedImages::from_config_struct(MD086Config {
+            directories: vec!["static".to_string()],
+            exclude: vec!["**/favicon.*".to_string()],
+            ..MD086Config::default()
+        })
+        .with_project_root(root.clone());
+        assert!(unused(&rule, &index, &root).is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_nothing_per_file() {
+        let rule = MD086UnusedImages::new();
+        let ctx = LintContext::new("![logo](missing.png)\n", crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD086 (unreferenced image assets)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD086Config {
+    /// Directories to scan for image files, relative to the project root.
+    /// The default `["."]` scans the whole project. `.gitignore`d and hidden
+    /// files are skipped.
+    pub directories: Vec<String>,
+
+    /// File extensions treated as images (case-insensitive, without the dot)
+    pub extensions: Vec<String>,
+
+    /// Glob patterns, relative to the project root, for images that are used
+    /// outside Markdown and must never be reported (e.g. `"**/favicon.*"`)
+    pub exclude: Vec<String>,
+}
+
+impl Default for MD086Config {
+    fn default() -> Self {
+        Self {
+            directories: vec![".".to_string()],
+            extensions: [
+                "apng", "avif", "bmp", "gif", "ico", "jpeg", "jpg", "png", "svg", "tif", "tiff", "webp",
+            ]
+            .map(String::from)
+            .to_vec(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl RuleConfig for MD086Config {
+    const RULE_NAME: &'static str = "MD086";
+}
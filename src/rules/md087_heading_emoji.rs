@@ -0,0 +1,450 @@
+//! Rule MD087: Headings should follow the configured emoji policy.
+//!
+//! Depending on `style`, headings must contain no emoji, only emoji from an
+//! allow list, or must start with an emoji. Both Unicode emoji (including
+//! ZWJ sequences, flags and keycaps) and `:shortcode:` emoji are recognized;
+//! anything inside inline code is ignored. Disallowed emoji are removed
+//! together with the space that separated them from the heading text.
+//!
+//! See [docs/md087.md](../../docs/md087.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::anchor_styles::common::is_emoji_or_symbol_extended;
+use crate::utils::regex_cache::EMOJI_SHORTCODE_REGEX;
+use std::ops::Range;
+
+mod md087_config;
+use md087_config::HeadingEmojiStyle;
+pub(super) use md087_config::MD087Config;
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+/// Byte length of the Unicode emoji sequence starting at `text[start..]`, or
+/// `None` if no emoji starts there. A sequence is an emoji with its variation
+/// selector, skin tone and ZWJ-joined parts, a flag, or a keycap.
+fn unicode_emoji_len(text: &str, start: usize) -> Option<usize> {
+    let mut chars = text[start..].char_indices().peekable();
+    let (_, first) = chars.next()?;
+
+    if matches!(first, '0'..='9' | '#' | '*') {
+        // Keycap: base character, optional variation selector, combining keycap
+        let mut rest = text[start + first.len_utf8()..].chars();
+        let mut next = rest.next();
+        let mut len = first.len_utf8();
+        if next == Some(VARIATION_SELECTOR) {
+            len += VARIATION_SELECTOR.len_utf8();
+            next = rest.next();
+        }
+        return (next == Some(KEYCAP)).then_some(len + KEYCAP.len_utf8());
+    }
+
+    if is_regional_indicator(first) {
+        return match chars.peek() {
+            Some(&(offset, second)) if is_regional_indicator(second) => Some(offset + second.len_utf8()),
+            _ => Some(first.len_utf8()),
+        };
+    }
+
+    if !is_emoji_or_symbol_extended(first) {
+        return None;
+    }
+
+    let mut len = first.len_utf8();
+    while let Some(&(offset, c)) = chars.peek() {
+        if c == VARIATION_SELECTOR || is_skin_tone(c) {
+            chars.next();
+            len = offset + c.len_utf8();
+        } else if c == ZWJ {
+            chars.next();
+            match chars.next() {
+                Some((joined_offset, joined)) if is_emoji_or_symbol_extended(joined) => {
+                    len = joined_offset + joined.len_utf8();
+                }
+                _ => break,
+            }
+        } else {
+            break;
+        }
+    }
+    Some(len)
+}
+
+/// Length of the backtick run starting at `text[start..]`
+fn backtick_run(text: &str, start: usize) -> usize {
+    text[start..].len() - text[start..].trim_start_matches('`').len()
+}
+
+/// Byte ranges of inline code spans in `text`
+fn code_span_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find('`') {
+        let open = pos + offset;
+        let run = backtick_run(text, open);
+        pos = open + run;
+        // The span closes at the next run of the same length; an unmatched
+        // run is literal text
+        let mut search = pos;
+        while let Some(offset) = text[search..].find('`') {
+            let close = search + offset;
+            let close_run = backtick_run(text, close);
+            if close_run == run {
+                ranges.push(open..close + run);
+                pos = close + run;
+                break;
+            }
+            search = close + close_run;
+        }
+    }
+    ranges
+}
+
+/// Strip variation selectors so `⚠` and `⚠️` compare equal
+fn emoji_key(emoji: &str) -> String {
+    if emoji.starts_with(':') {
+        emoji.to_lowercase()
+    } else {
+        emoji.chars().filter(|&c| c != VARIATION_SELECTOR).collect()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct MD087HeadingEmoji {
+    config: MD087Config,
+}
+
+impl MD087HeadingEmoji {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD087Config) -> Self {
+        Self { config }
+    }
+
+    /// Byte ranges of every emoji in heading `text`, in order
+    fn find_emoji(&self, text: &str) -> Vec<Range<usize>> {
+        let code_spans = code_span_ranges(text);
+        let in_code = |pos: usize| code_spans.iter().any(|span| span.contains(&pos));
+
+        let mut found = Vec::new();
+        let mut iter = text.char_indices();
+        while let Some((start, _)) = iter.next() {
+            if in_code(start) {
+                continue;
+            }
+            if let Some(len) = unicode_emoji_len(text, start) {
+                found.push(start..start + len);
+                // Skip the rest of the sequence
+                while iter.clone().next().is_some_and(|(next, _)| next < start + len) {
+                    iter.next();
+                }
+            }
+        }
+
+        if self.config.shortcodes {
+            for caps in EMOJI_SHORTCODE_REGEX.captures_iter(text) {
+                let whole = caps.get(0).unwrap();
+                let name = &caps[1];
+                let before = text[..whole.start()].chars().next_back();
+                let after = text[whole.end()..].chars().next();
+                let bounded = !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric);
+                if bounded && !name.chars().all(|c| c.is_ascii_digit()) && !in_code(whole.start()) {
+                    found.push(whole.range());
+                }
+            }
+            found.sort_by_key(|range| range.start);
+        }
+
+        found
+    }
+
+    fn is_allowed(&self, emoji: &str) -> bool {
+        let key = emoji_key(emoji);
+        self.config.allowed.iter().any(|allowed| emoji_key(allowed) == key)
+    }
+
+    /// Range to delete when removing `emoji` from `text`: the emoji plus the
+    /// whitespace before it, or after it when it starts the text or the
+    /// whitespace before it was already taken by an earlier removal.
+    fn removal_range(text: &str, emoji: &Range<usize>, consumed: usize) -> Range<usize> {
+        let before = text[..emoji.start].trim_end_matches([' ', '\t']).len();
+        if before < emoji.start && before > 0 && before >= consumed {
+            return before..emoji.end;
+        }
+        let after = text.len() - text[emoji.end..].trim_start_matches([' ', '\t']).len();
+        emoji.start..after
+    }
+
+    fn check_heading(&self, text: &str) -> Vec<(Range<usize>, String, Option<Range<usize>>)> {
+        let emoji = self.find_emoji(text);
+
+        if self.config.style == HeadingEmojiStyle::RequireLeading {
+            let leading = emoji
+                .first()
+                .filter(|range| text[..range.start].trim().is_empty())
+                .map(|range| &text[range.clone()]);
+            let message = match leading {
+                None if self.config.allowed.is_empty() => "Heading should start with an emoji".to_string(),
+                None => format!(
+                    "Heading should start with one of the allowed emoji: {}",
+                    self.config.allowed.join(" ")
+                ),
+                Some(first) if !self.config.allowed.is_empty() && !self.is_allowed(first) => {
+                    format!("Leading emoji '{first}' is not in the allowed list")
+                }
+                Some(_) => return Vec::new(),
+            };
+            let range = emoji.first().filter(|_| leading.is_some()).cloned().unwrap_or(0..0);
+            return vec![(range, message, None)];
+        }
+
+        let disallowed: Vec<Range<usize>> = emoji
+            .into_iter()
+            .filter(|range| self.config.style == HeadingEmojiStyle::Forbid || !self.is_allowed(&text[range.clone()]))
+            .collect();
+
+        let mut consumed = 0;
+        let mut removals: Vec<Range<usize>> = Vec::new();
+        for range in &disallowed {
+            let removal = Self::removal_range(text, range, consumed);
+            consumed = removal.end;
+            removals.push(removal);
+        }
+
+        // Removing every emoji would leave an empty heading; report only
+        let leaves_text = {
+            let mut rest = String::with_capacity(text.len());
+            let mut last = 0;
+            for removal in &removals {
+                rest.push_str(&text[last..removal.start]);
+                last = removal.end;
+            }
+            rest.push_str(&text[last..]);
+            !rest.trim().is_empty()
+        };
+
+        disallowed
+            .into_iter()
+            .zip(removals)
+            .map(|(range, removal)| {
+                let emoji = &text[range.clone()];
+                let message = match self.config.style {
+                    HeadingEmojiStyle::AllowList => format!("Emoji '{emoji}' in heading is not in the allowed list"),
+                    _ => format!("Heading should not contain emoji '{emoji}'"),
+                };
+                (range, message, leaves_text.then_some(removal))
+            })
+            .collect()
+    }
+}
+
+impl Rule for MD087HeadingEmoji {
+    fn name(&self) -> &'static str {
+        "MD087"
+    }
+
+    fn description(&self) -> &'static str {
+        "Headings should follow the configured emoji policy"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for valid in ctx.valid_headings() {
+            let heading = valid.heading;
+            if heading.level < self.config.min_level || heading.level > self.config.max_level {
+                continue;
+            }
+            let line = valid.line_info.content(ctx.content);
+            let text_start = heading.content_column;
+            if !line
+                .get(text_start..)
+                .is_some_and(|rest| rest.starts_with(heading.raw_text(ctx.content)))
+            {
+                continue;
+            }
+            let text = heading.raw_text(ctx.content);
+
+            for (range, message, removal) in self.check_heading(text) {
+                let column = line[..text_start + range.start].chars().count() + 1;
+                let fix = removal.map(|removal| {
+                    let start = valid.line_info.byte_offset + text_start;
+                    Fix::new(start + removal.start..start + removal.end, String::new())
+                });
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: valid.line_num,
+                    column,
+                    end_line: valid.line_num,
+                    end_column: column + text[range].chars().count(),
+                    message,
+                    severity: Severity::Warning,
+                    fix,
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings: Vec<LintWarning> = self.check(ctx)?.into_iter().filter(|w| w.fix.is_some()).collect();
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn fix_safety(&self) -> FixSafety {
+        // Removes emoji from heading text and its anchor
+        FixSafety::Unsafe
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_headings()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD087Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD087Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD087HeadingEmoji, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD087HeadingEmoji, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    fn rule_with(style: HeadingEmojiStyle, allowed: &[&str]) -> MD087HeadingEmoji {
+        MD087HeadingEmoji::from_config_struct(MD087Config {
+            style,
+            allowed: allowed.iter().map(ToString::to_string).collect(),
+            ..MD087Config::default()
+        })
+    }
+
+    #[test]
+    fn test_forbid_removes_emoji_and_spacing() {
+        let rule = MD087HeadingEmoji::new();
+        let content = "# 🚀 Launch\n\n## Go 🔥 now :tada:\n\nBody 🚀 text\n\n### A ✅ ✅ #\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 5);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (1, 3, 4)
+        );
+        assert_eq!(warnings[2].message, "Heading should not contain emoji ':tada:'");
+        assert_eq!(
+            fix(&rule, content),
+            "# Launch\n\n## Go now\n\nBody 🚀 text\n\n### A #\n"
+        );
+    }
+
+    #[test]
+    fn test_sequences_are_one_emoji() {
+        let rule = MD087HeadingEmoji::new();
+        let content = "# 👩‍💻 Dev 👍🏽 🇳🇴 1️⃣ ⚠️\n";
+        let warnings = check(&rule, content);
+        let found: Vec<&str> = warnings.iter().map(|w| w.message.split('\'').nth(1).unwrap()).collect();
+        assert_eq!(found, ["👩‍💻", "👍🏽", "🇳🇴", "1️⃣", "⚠️"]);
+        assert_eq!(fix(&rule, content), "# Dev\n");
+    }
+
+    #[test]
+    fn test_ignores_code_spans_times_and_plain_text() {
+        let rule = MD087HeadingEmoji::new();
+        let content = "# Run `:wq:` at 10:30:00\n\nSetext 2024\n===========\n\n# Version 1.0 #1\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_emoji_only_heading_is_reported_without_fix() {
+        let rule = MD087HeadingEmoji::new();
+        let content = "# 🚀 🔥\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_allow_list_matches_with_or_without_variation_selector() {
+        let rule = rule_with(HeadingEmojiStyle::AllowList, &["⚠", ":Rocket:"]);
+        let content = "# ⚠️ Careful :rocket:\n\n## Party 🎉\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Emoji '🎉' in heading is not in the allowed list");
+        assert_eq!(fix(&rule, content), "# ⚠️ Careful :rocket:\n\n## Party\n");
+    }
+
+    #[test]
+    fn test_require_leading() {
+        let rule = rule_with(HeadingEmojiStyle::RequireLeading, &[]);
+        let warnings = check(&rule, "# 🚀 Launch\n\n## Setup 🔧\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].message, "Heading should start with an emoji");
+        assert!(warnings[0].fix.is_none());
+
+        let rule = rule_with(HeadingEmojiStyle::RequireLeading, &["📘", ":book:"]);
+        let warnings = check(&rule, "# :book: Guide\n\n## 🚀 Launch\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Leading emoji '🚀' is not in the allowed list");
+    }
+
+    #[test]
+    fn test_level_range_and_shortcodes_toggle() {
+        let rule = MD087HeadingEmoji::from_config_struct(MD087Config {
+            shortcodes: false,
+            min_level: 2,
+            ..MD087Config::default()
+        });
+        assert_eq!(check(&rule, "# Top 🚀\n\n## Sub :tada:\n\n### Deep 🔥\n").len(), 1);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// What the rule enforces about emoji in headings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeadingEmojiStyle {
+    /// Headings must not contain emoji
+    #[default]
+    Forbid,
+    /// Headings may only contain emoji listed in `allowed`
+    #[serde(alias = "allow_list")]
+    AllowList,
+    /// Headings must start with an emoji (one from `allowed`, when set)
+    #[serde(alias = "require_leading")]
+    RequireLeading,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD087Config {
+    /// Policy to enforce: `forbid`, `allow-list`, or `require-leading`
+    #[serde(default)]
+    pub style: HeadingEmojiStyle,
+
+    /// Emoji permitted by `allow-list`, or accepted as the leading emoji by
+    /// `require-leading`. Unicode emoji and `:shortcodes:` may be mixed.
+    #[serde(default)]
+    pub allowed: Vec<String>,
+
+    /// Treat `:shortcode:` emoji like Unicode emoji
+    #[serde(default = "default_true")]
+    pub shortcodes: bool,
+
+    /// Minimum heading level to check (1-6)
+    #[serde(default = "default_min_level", alias = "min_level")]
+    pub min_level: u8,
+
+    /// Maximum heading level to check (1-6)
+    #[serde(default = "default_max_level", alias = "max_level")]
+    pub max_level: u8,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_min_level() -> u8 {
+    1
+}
+
+fn default_max_level() -> u8 {
+    6
+}
+
+impl Default for MD087Config {
+    fn default() -> Self {
+        Self {
+            style: HeadingEmojiStyle::default(),
+            allowed: Vec::new(),
+            shortcodes: true,
+            min_level: default_min_level(),
+            max_level: default_max_level(),
+        }
+    }
+}
+
+impl RuleConfig for MD087Config {
+    const RULE_NAME: &'static str = "MD087";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_accepts_kebab_and_snake_case() {
+        let config: MD087Config =
+            toml::from_str("style = \"allow_list\"\nallowed = [\"🚀\", \":tada:\"]\nmax_level = 2").unwrap();
+        assert_eq!(config.style, HeadingEmojiStyle::AllowList);
+        assert_eq!(config.allowed, vec!["🚀".to_string(), ":tada:".to_string()]);
+        assert!(config.shortcodes);
+        assert_eq!((config.min_level, config.max_level), (1, 2));
+    }
+}
@@ -0,0 +1,470 @@
+//! Rule MD088: Lists in configured sections should be sorted.
+//!
+//! For every heading matching one of the configured `headings` patterns, the
+//! lists between that heading and the next heading must have their top-level
+//! items in order: first the `custom-order` entries in the order given, then
+//! the rest alphabetically under the configured collation. The fix reorders
+//! the items, carrying nested lists and continuation lines with their item.
+//!
+//! See [docs/md088.md](../../docs/md088.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+use std::sync::LazyLock;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+mod md088_config;
+pub(super) use md088_config::MD088Config;
+
+/// Inline and reference links and images, keeping only their text
+static LINK_TEXT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!?\[([^\]]*)\](?:\([^)]*\)|\[[^\]]*\])").unwrap());
+
+/// A task list checkbox at the start of an item
+static TASK_CHECKBOX_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\[[ xX]\]\s+").unwrap());
+
+/// One top-level list item: the lines it spans, without trailing blank lines
+struct Item {
+    lines: RangeInclusive<usize>,
+    key: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD088SortedLists {
+    config: MD088Config,
+    headings: Vec<Regex>,
+}
+
+impl MD088SortedLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD088Config) -> Self {
+        let headings = config
+            .headings
+            .iter()
+            .filter_map(|pattern| match Regex::new(&format!("(?i)^(?:{pattern})$")) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    log::warn!("Invalid MD088 headings pattern '{pattern}': {e}");
+                    None
+                }
+            })
+            .collect();
+        Self { config, headings }
+    }
+
+    /// Line ranges (1-indexed, inclusive) of sections whose heading matches
+    fn sorted_sections(&self, ctx: &LintContext) -> Vec<(String, RangeInclusive<usize>)> {
+        let headings: Vec<_> = ctx.valid_headings().collect();
+        let last_line = ctx.lines.len();
+        headings
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| {
+                self.headings
+                    .iter()
+                    .any(|re| re.is_match(h.heading.text(ctx.content).trim()))
+            })
+            .map(|(i, h)| {
+                // Setext headings occupy the text line and the underline
+                let start = h.line_num + 1;
+                let end = headings.get(i + 1).map_or(last_line, |next| next.line_num - 1);
+                (h.heading.text(ctx.content).trim().to_string(), start..=end)
+            })
+            .collect()
+    }
+
+    /// Top-level items of each list that starts inside `section`
+    fn lists_in(&self, ctx: &LintContext, section: &RangeInclusive<usize>) -> Vec<Vec<Item>> {
+        let mut lists = Vec::new();
+        for block in &ctx.list_blocks {
+            if block.nesting_level != 0 || !section.contains(&block.start_line) {
+                continue;
+            }
+            let Some(base_column) = ctx
+                .line_info(block.start_line)
+                .and_then(|info| info.list_item.as_ref())
+                .map(|item| item.marker_column)
+            else {
+                continue;
+            };
+            let starts: Vec<usize> = block
+                .item_lines
+                .iter()
+                .copied()
+                .filter(|&line| {
+                    ctx.line_info(line)
+                        .and_then(|info| info.list_item.as_ref())
+                        .is_some_and(|item| item.marker_column == base_column)
+                })
+                .collect();
+            let end = block.end_line.min(*section.end());
+
+            let items = starts
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &start)| {
+                    let mut last = starts.get(i + 1).map_or(end, |next| next - 1);
+                    while last > start && ctx.line_info(last).is_some_and(|info| info.is_blank) {
+                        last -= 1;
+                    }
+                    let info = ctx.line_info(start)?;
+                    let item = info.list_item.as_ref()?;
+                    let text = info.content(ctx.content).get(item.content_column..).unwrap_or("");
+                    Some(Item {
+                        lines: start..=last,
+                        key: sort_key(text),
+                    })
+                })
+                .collect::<Vec<_>>();
+            if items.len() > 1 {
+                lists.push(items);
+            }
+        }
+        lists
+    }
+
+    /// Position of a key among the `custom-order` entries, if listed
+    fn custom_position(&self, key: &str) -> Option<usize> {
+        let key = key.to_lowercase();
+        self.config
+            .custom_order
+            .iter()
+            .position(|entry| key.starts_with(&entry.to_lowercase()))
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        match (self.custom_position(a), self.custom_position(b)) {
+            (Some(x), Some(y)) if x != y => return x.cmp(&y),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            _ => {}
+        }
+        let (a, b) = (self.collate(a), self.collate(b));
+        let ordering = if self.config.numeric {
+            compare_numeric(&a, &b)
+        } else {
+            a.cmp(&b)
+        };
+        if self.config.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    fn collate(&self, key: &str) -> String {
+        let folded: String = if self.config.ignore_diacritics {
+            key.nfd().filter(|c| !is_combining_mark(*c)).collect()
+        } else {
+            key.to_string()
+        };
+        if self.config.case_sensitive {
+            folded
+        } else {
+            folded.to_lowercase()
+        }
+    }
+
+    /// Replacement text for the lines of `items`, with the items reordered.
+    /// Ordered lists keep their numbering: each position keeps its marker.
+    fn sorted_text(&self, ctx: &LintContext, items: &[Item]) -> String {
+        let mut order: Vec<&Item> = items.iter().collect();
+        order.sort_by(|a, b| self.compare(&a.key, &b.key));
+
+        let line_text = |line: usize| ctx.line_info(line).map_or("", |info| info.content(ctx.content));
+        let marker_end = |line: usize| {
+            ctx.line_info(line)
+                .and_then(|info| info.list_item.as_ref())
+                .filter(|item| item.is_ordered)
+                .map(|item| item.content_column)
+        };
+
+        let mut lines: Vec<String> = Vec::new();
+        for (position, item) in order.iter().enumerate() {
+            let first = *item.lines.start();
+            let slot = *items[position].lines.start();
+            match (marker_end(slot), marker_end(first)) {
+                (Some(slot_end), Some(first_end)) => lines.push(format!(
+                    "{}{}",
+                    &line_text(slot)[..slot_end],
+                    &line_text(first)[first_end..]
+                )),
+                _ => lines.push(line_text(first).to_string()),
+            }
+            lines.extend((first + 1..=*item.lines.end()).map(|line| line_text(line).to_string()));
+            // Blank lines between items stay between the same positions
+            if let Some(next) = items.get(position + 1) {
+                lines.extend(
+                    (items[position].lines.end() + 1..*next.lines.start()).map(|line| line_text(line).to_string()),
+                );
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Text an item sorts by: its first line with Markdown markup removed
+fn sort_key(text: &str) -> String {
+    let text = TASK_CHECKBOX_REGEX.replace(text.trim(), "");
+    let text = LINK_TEXT_REGEX.replace_all(&text, "$1");
+    text.chars()
+        .filter(|c| !matches!(c, '*' | '_' | '`' | '~'))
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Compare with runs of ASCII digits ordered by their numeric value
+pub(super) fn compare_numeric(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(ca), Some(cb)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        if ca.is_ascii_digit() && cb.is_ascii_digit() {
+            let da = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+            let db = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+            let (na, nb) = (a[..da].trim_start_matches('0'), b[..db].trim_start_matches('0'));
+            let ordering = na.len().cmp(&nb.len()).then_with(|| na.cmp(nb));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            (a, b) = (&a[da..], &b[db..]);
+        } else {
+            if ca != cb {
+                return ca.cmp(&cb);
+            }
+            (a, b) = (&a[ca.len_utf8()..], &b[cb.len_utf8()..]);
+        }
+    }
+}
+
+impl Rule for MD088SortedLists {
+    fn name(&self) -> &'static str {
+        "MD088"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lists in configured sections should be sorted"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for (heading, section) in self.sorted_sections(ctx) {
+            for items in self.lists_in(ctx, &section) {
+                let Some(i) =
+                    (1..items.len()).find(|&i| self.compare(&items[i].key, &items[i - 1].key) == Ordering::Less)
+                else {
+                    continue;
+                };
+
+                let first = *items[0].lines.start();
+                let last = *items[items.len() - 1].lines.end();
+                let (Some(start), Some(end)) = (ctx.line_info(first), ctx.line_info(last)) else {
+                    continue;
+                };
+                let range = start.byte_offset..end.byte_offset + end.content(ctx.content).len();
+
+                let line = *items[i].lines.start();
+                let column = ctx
+                    .line_info(line)
+                    .and_then(|info| info.list_item.as_ref())
+                    .map_or(1, |item| item.marker_column + 1);
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line,
+                    column,
+                    end_line: line,
+                    end_column: column + 1,
+                    message: format!(
+                        "List under '{heading}' is not sorted: '{}' should come before '{}'",
+                        items[i].key,
+                        items[i - 1].key
+                    ),
+                    severity: Severity::Warning,
+                    fix: Some(Fix::new(range, self.sorted_text(ctx, &items))),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        self.headings.is_empty() || ctx.list_blocks.is_empty() || !ctx.likely_has_headings()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD088Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD088Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule(headings: &[&str]) -> MD088SortedLists {
+        rule_with(MD088Config {
+            headings: headings.iter().map(ToString::to_string).collect(),
+            ..MD088Config::default()
+        })
+    }
+
+    fn rule_with(config: MD088Config) -> MD088SortedLists {
+        MD088SortedLists::from_config_struct(config)
+    }
+
+    fn check(rule: &MD088SortedLists, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD088SortedLists, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_only_matching_sections_are_checked() {
+        let content = "# Project\n\n- zeta\n- alpha\n\n## Contributors\n\n- Zoë\n- [Adam](https://a.example)\n\n## Other\n\n- b\n- a\n";
+        let rule = rule(&["contributors"]);
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (9, 1));
+        assert_eq!(
+            warnings[0].message,
+            "List under 'Contributors' is not sorted: 'Adam' should come before 'Zoë'"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "# Project\n\n- zeta\n- alpha\n\n## Contributors\n\n- [Adam](https://a.example)\n- Zoë\n\n## Other\n\n- b\n- a\n"
+        );
+        assert!(check(&MD088SortedLists::new(), content).is_empty());
+    }
+
+    #[test]
+    fn test_fix_carries_continuation_content_and_keeps_spacing() {
+        let content = "\
+## Glossary
+
+- **Tarball**: an archive
+  spanning two lines
+
+- *Artifact*: a build output
+  - nested detail
+- `Cache`: stored results
+";
+        let fixed = fix(&rule(&["Glossary"]), content);
+        assert_eq!(
+            fixed,
+            "\
+## Glossary
+
+- *Artifact*: a build output
+  - nested detail
+
+- `Cache`: stored results
+- **Tarball**: an archive
+  spanning two lines
+"
+        );
+        assert!(check(&rule(&["Glossary"]), &fixed).is_empty());
+    }
+
+    #[test]
+    fn test_collation_options() {
+        let content = "## Items\n\n- item 10\n- Item 2\n- Éclair\n- apple\n";
+
+        let warnings = check(&rule(&["items"]), content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            fix(&rule(&["items"]), content),
+            "## Items\n\n- apple\n- Éclair\n- item 10\n- Item 2\n"
+        );
+
+        let numeric = rule_with(MD088Config {
+            headings: vec!["items".to_string()],
+            numeric: true,
+            ..MD088Config::default()
+        });
+        assert_eq!(
+            fix(&numeric, content),
+            "## Items\n\n- apple\n- Éclair\n- Item 2\n- item 10\n"
+        );
+
+        let case_sensitive = rule_with(MD088Config {
+            headings: vec!["items".to_string()],
+            case_sensitive: true,
+            ignore_diacritics: false,
+            ..MD088Config::default()
+        });
+        assert_eq!(
+            fix(&case_sensitive, content),
+            "## Items\n\n- Item 2\n- apple\n- item 10\n- Éclair\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_order_and_descending() {
+        let config = MD088Config {
+            headings: vec!["Team.*".to_string()],
+            custom_order: vec!["Lead".to_string(), "Maintainers".to_string()],
+            descending: true,
+            ..MD088Config::default()
+        };
+        let content = "## Team members\n\n1. Alice\n2. Maintainers: Bob\n3. Zed\n4. Lead: Carol\n";
+        assert_eq!(
+            fix(&rule_with(config), content),
+            "## Team members\n\n1. Lead: Carol\n2. Maintainers: Bob\n3. Zed\n4. Alice\n"
+        );
+    }
+
+    #[test]
+    fn test_sorted_lists_and_invalid_patterns_report_nothing() {
+        let content = "## Contributors\n\n- Ada\n- Grace\n- Linus\n";
+        assert!(check(&rule(&["Contributors"]), content).is_empty());
+        assert!(check(&rule(&["(unclosed"]), "## (unclosed\n\n- b\n- a\n").is_empty());
+    }
+}
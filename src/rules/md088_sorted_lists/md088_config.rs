@@ -0,0 +1,69 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD088Config {
+    /// Regular expressions for the headings whose lists must be sorted,
+    /// matched case-insensitively against the whole heading text
+    #[serde(default)]
+    pub headings: Vec<String>,
+
+    /// Items that come first, in this order, before the sorted remainder.
+    /// An entry matches an item whose text starts with it (case-insensitive).
+    #[serde(default, alias = "custom_order")]
+    pub custom_order: Vec<String>,
+
+    /// Sort in descending order
+    #[serde(default)]
+    pub descending: bool,
+
+    /// Compare letter case (`Zebra` sorts before `apple`)
+    #[serde(default, alias = "case_sensitive")]
+    pub case_sensitive: bool,
+
+    /// Compare accented letters as their base letter (`Émile` sorts with `E`)
+    #[serde(default = "default_true", alias = "ignore_diacritics")]
+    pub ignore_diacritics: bool,
+
+    /// Compare runs of digits by value (`item 2` sorts before `item 10`)
+    #[serde(default)]
+    pub numeric: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for MD088Config {
+    fn default() -> Self {
+        Self {
+            headings: Vec::new(),
+            custom_order: Vec::new(),
+            descending: false,
+            case_sensitive: false,
+            ignore_diacritics: true,
+            numeric: false,
+        }
+    }
+}
+
+impl RuleConfig for MD088Config {
+    const RULE_NAME: &'static str = "MD088";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_accepts_kebab_and_snake_case() {
+        let config: MD088Config =
+            toml::from_str("headings = [\"Contributors\"]\ncustom_order = [\"Core team\"]\nignore-diacritics = false")
+                .unwrap();
+        assert_eq!(config.headings, vec!["Contributors".to_string()]);
+        assert_eq!(config.custom_order, vec!["Core team".to_string()]);
+        assert!(!config.ignore_diacritics);
+        assert!(!config.case_sensitive && !config.numeric && !config.descending);
+    }
+}
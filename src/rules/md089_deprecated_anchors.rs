@@ -0,0 +1,329 @@
+//! Rule MD089: Links should not target deprecated anchors.
+//!
+//! A heading is marked as deprecated by putting an annotation on the first
+//! line below it:
+//!
+//! ```markdown
+//! ## Old setup
+//! <!-- rumdl-deprecated-anchor: install.md#setup -->
+//! ```
+//!
+//! Every link to `#old-setup`, in this file or in any file linking to it, is
+//! reported and fixed to point at the replacement. Targets are read from disk
+//! relative to the linking file, so the rule works the same for every file in
+//! a workspace run, for stdin with `--stdin-filename`, and in the editor.
+//!
+//! See [docs/md089.md](../../docs/md089.md) for full documentation, configuration, and examples.
+
+use crate::link_rewrite::{is_rewritable, link_destinations, relative_path, split_destination};
+use crate::lint_context::LintContext;
+use crate::lint_context::types::HeadingStyle;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use crate::utils::slug::Slugify;
+use crate::workspace_index::{WorkspaceIndex, url_decode};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+static ANNOTATION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*<!--\s*rumdl-deprecated-anchor:\s*(\S+?)\s*-->\s*$").unwrap());
+
+/// Configuration for MD089 (Deprecated anchors)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD089Config {
+    /// Anchor generation style used to compute the anchors of deprecated headings
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+}
+
+impl RuleConfig for MD089Config {
+    const RULE_NAME: &'static str = "MD089";
+}
+
+/// Deprecated anchors of one document: lowercased anchor -> replacement as written
+type Deprecations = HashMap<String, String>;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD089DeprecatedAnchors {
+    slugify: Slugify,
+}
+
+impl MD089DeprecatedAnchors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD089Config) -> Self {
+        Self {
+            slugify: config.anchor_style.into(),
+        }
+    }
+
+    /// Anchors of the headings annotated as deprecated in `ctx`
+    fn deprecations(&self, ctx: &LintContext) -> Deprecations {
+        let mut deprecations = Deprecations::new();
+        for valid in ctx.valid_headings() {
+            // The annotation sits on the first line below the heading (below the
+            // underline for Setext headings)
+            let below = match valid.heading.style {
+                HeadingStyle::ATX => valid.line_num,
+                HeadingStyle::Setext1 | HeadingStyle::Setext2 => valid.line_num + 1,
+            };
+            let Some(line) = ctx.lines.get(below) else {
+                continue;
+            };
+            let Some(caps) = ANNOTATION_REGEX.captures(line.content(ctx.content)) else {
+                continue;
+            };
+            let anchor = match valid.heading.custom_id(ctx.content) {
+                Some(id) => id.to_string(),
+                None => self.slugify.slugify(valid.heading.text(ctx.content)),
+            };
+            deprecations.insert(anchor.to_lowercase(), caps[1].to_string());
+        }
+        deprecations
+    }
+
+    /// Deprecations of the Markdown file at `path`, read from disk once per check
+    fn file_deprecations<'a>(
+        &self,
+        path: &Path,
+        ctx: &LintContext,
+        cache: &'a mut HashMap<PathBuf, Deprecations>,
+    ) -> &'a Deprecations {
+        cache.entry(path.to_path_buf()).or_insert_with(|| {
+            std::fs::read_to_string(path)
+                .map(|content| {
+                    let target_ctx = LintContext::new(&content, ctx.flavor, Some(path.to_path_buf()));
+                    self.deprecations(&target_ctx)
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// The Markdown file a link path resolves to, trying extensions for
+    /// extension-less links like MD051 does
+    fn resolve_target(dir: &Path, path: &str) -> Option<PathBuf> {
+        let target = WorkspaceIndex::normalize_path(&dir.join(url_decode(path)));
+        if target.extension().is_some() {
+            return crate::discovery::has_markdown_extension(&target).then_some(target);
+        }
+        crate::discovery::MARKDOWN_EXTENSIONS
+            .iter()
+            .map(|ext| target.with_extension(ext))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// The destination replacing a link into `target` once `replacement`
+    /// (written relative to `target`) applies, expressed from `file`
+    fn retarget(file: &Path, target: &Path, link_path: &str, query: &str, replacement: &str) -> String {
+        let (replacement_path, fragment) = replacement.split_once('#').unwrap_or((replacement, ""));
+        let fragment = if fragment.is_empty() {
+            String::new()
+        } else {
+            format!("#{fragment}")
+        };
+        if replacement_path.is_empty() {
+            return format!("{link_path}{query}{fragment}");
+        }
+
+        let target_dir = target.parent().unwrap_or(Path::new(""));
+        let new_target = WorkspaceIndex::normalize_path(&target_dir.join(url_decode(replacement_path)));
+        if new_target == WorkspaceIndex::normalize_path(file) && !fragment.is_empty() {
+            return fragment;
+        }
+        let dir = file.parent().unwrap_or(Path::new(""));
+        let url = relative_path(dir, &new_target)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+            .replace(' ', "%20");
+        format!("{url}{fragment}")
+    }
+}
+
+impl Rule for MD089DeprecatedAnchors {
+    fn name(&self) -> &'static str {
+        "MD089"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links should not target deprecated anchors"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let own = self.deprecations(ctx);
+        let mut cache = HashMap::new();
+        let dir = ctx
+            .source_file
+            .as_deref()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new(""));
+
+        for range in link_destinations(ctx) {
+            let destination = &ctx.content[range.clone()];
+            let (path, suffix) = split_destination(destination);
+            let Some((query, fragment)) = suffix.split_once('#') else {
+                continue;
+            };
+            let fragment = fragment.to_lowercase();
+
+            let new_destination = if path.is_empty() {
+                // A replacement declared in this file is already relative to it
+                let Some(replacement) = own.get(&fragment) else {
+                    continue;
+                };
+                replacement.clone()
+            } else {
+                let Some(file) = ctx.source_file.as_deref() else {
+                    continue;
+                };
+                if !is_rewritable(path) {
+                    continue;
+                }
+                let Some(target) = Self::resolve_target(dir, path) else {
+                    continue;
+                };
+                let Some(replacement) = self.file_deprecations(&target, ctx, &mut cache).get(&fragment) else {
+                    continue;
+                };
+                Self::retarget(file, &target, path, query, replacement)
+            };
+
+            let (line, column) = ctx.offset_to_line_col(range.start);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column,
+                end_line: line,
+                end_column: column + destination.chars().count(),
+                message: format!("Link target '{destination}' is deprecated; use '{new_destination}'"),
+                severity: Severity::Warning,
+                fix: Some(Fix::new(range, new_destination)),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains('#')
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD089Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD089Config>(config);
+        Box::new(Self {
+            slugify: Slugify::for_rule(config, "MD089", &rule_config.anchor_style),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn check_file(path: &Path) -> (Vec<LintWarning>, String) {
+        let content = fs::read_to_string(path).unwrap();
+        let ctx = LintContext::new(&content, MarkdownFlavor::Standard, Some(path.to_path_buf()));
+        let rule = MD089DeprecatedAnchors::new();
+        (rule.check(&ctx).unwrap(), rule.fix(&ctx).unwrap())
+    }
+
+    #[test]
+    fn test_same_file_links_follow_the_annotation() {
+        let content = "# Guide\n\n## Old setup\n<!-- rumdl-deprecated-anchor: #setup -->\n\n## Setup\n\nSee [setup](#old-setup) or [again](#Old-Setup).\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let rule = MD089DeprecatedAnchors::new();
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].message,
+            "Link target '#old-setup' is deprecated; use '#setup'"
+        );
+        assert_eq!((warnings[0].line, warnings[0].column), (8, 13));
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "# Guide\n\n## Old setup\n<!-- rumdl-deprecated-anchor: #setup -->\n\n## Setup\n\nSee [setup](#setup) or [again](#setup).\n"
+        );
+    }
+
+    #[test]
+    fn test_annotation_must_directly_follow_heading() {
+        let content = "# Guide\n\n## Old\n\n<!-- rumdl-deprecated-anchor: #new -->\n\nSetext\n------\n<!-- rumdl-deprecated-anchor: #new -->\n\n[a](#old) [b](#setext)\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let warnings = MD089DeprecatedAnchors::new().check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Link target '#setext' is deprecated; use '#new'");
+    }
+
+    #[test]
+    fn test_links_from_other_files_are_rewritten_relative_to_the_linking_file() {
+        let dir = tempdir().unwrap();
+        let docs = dir.path().join("docs");
+        fs::create_dir_all(docs.join("guide")).unwrap();
+        fs::write(
+            docs.join("guide/old.md"),
+            "# Old\n\n## Install {#install}\n<!-- rumdl-deprecated-anchor: ../setup.md#installing -->\n\n## Local\n<!-- rumdl-deprecated-anchor: #usage -->\n",
+        )
+        .unwrap();
+        fs::write(
+            docs.join("index.md"),
+            "# Index\n\n- [install](guide/old.md#install)\n- [local](guide/old#local)\n- [kept](guide/old.md#old)\n\n[ref]: guide/old.md?x=1#local\n",
+        )
+        .unwrap();
+        fs::write(docs.join("setup.md"), "# Setup\n\nSee [old](guide/old.md#install).\n").unwrap();
+
+        let (warnings, fixed) = check_file(&docs.join("index.md"));
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            fixed,
+            "# Index\n\n- [install](setup.md#installing)\n- [local](guide/old#usage)\n- [kept](guide/old.md#old)\n\n[ref]: guide/old.md?x=1#usage\n"
+        );
+
+        // A replacement pointing back at the linking file becomes a same-file link
+        let (_, fixed) = check_file(&docs.join("setup.md"));
+        assert_eq!(fixed, "# Setup\n\nSee [old](#installing).\n");
+    }
+}
@@ -0,0 +1,260 @@
+//! Rule MD090: Code spans should use ASCII punctuation.
+//!
+//! Word processors and chat tools replace `--` with a dash, straight quotes
+//! with curly ones, and CJK input methods produce full-width punctuation. When
+//! such text is pasted into inline code (`—help`, `key：value`), the snippet
+//! no longer works when copied. A code span is only checked when everything
+//! else in it is ASCII, so code spans written in another script keep their
+//! native punctuation.
+//!
+//! See [docs/md090.md](../../docs/md090.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, FixSafety, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Typographic characters flagged by default, with the ASCII they replace.
+/// Full-width forms of printable ASCII (U+FF01-U+FF5E) are added separately.
+const TYPOGRAPHIC_REPLACEMENTS: &[(char, &str)] = &[
+    ('\u{201C}', "\""),  // “
+    ('\u{201D}', "\""),  // ”
+    ('\u{201E}', "\""),  // „
+    ('\u{2018}', "'"),   // ‘
+    ('\u{2019}', "'"),   // ’
+    ('\u{201A}', "'"),   // ‚
+    ('\u{2014}', "--"),  // — em dash
+    ('\u{2013}', "--"),  // – en dash
+    ('\u{2212}', "-"),   // − minus sign
+    ('\u{2010}', "-"),   // ‐ hyphen
+    ('\u{2011}', "-"),   // ‑ non-breaking hyphen
+    ('\u{2026}', "..."), // …
+    ('\u{3001}', ","),   // 、 ideographic comma
+    ('\u{3002}', "."),   // 。 ideographic full stop
+    ('\u{3000}', " "),   // ideographic space
+    ('\u{00A0}', " "),   // no-break space
+];
+
+/// Full-width grave accent; mapping it to a backtick would end the code span
+const FULLWIDTH_GRAVE: char = '\u{FF40}';
+
+/// Configuration for MD090 (Code span ASCII)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD090Config {
+    /// Extra characters to flag, mapped to their ASCII replacement. Entries
+    /// override the built-in map.
+    #[serde(default)]
+    pub replacements: BTreeMap<String, String>,
+
+    /// Characters that are never flagged, even if the built-in map lists them
+    #[serde(default)]
+    pub allowed: Vec<String>,
+}
+
+impl RuleConfig for MD090Config {
+    const RULE_NAME: &'static str = "MD090";
+}
+
+#[derive(Debug, Clone)]
+pub struct MD090CodeSpanAscii {
+    replacements: HashMap<char, String>,
+}
+
+impl Default for MD090CodeSpanAscii {
+    fn default() -> Self {
+        Self::from_config_struct(MD090Config::default())
+    }
+}
+
+impl MD090CodeSpanAscii {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD090Config) -> Self {
+        let mut replacements: HashMap<char, String> = TYPOGRAPHIC_REPLACEMENTS
+            .iter()
+            .map(|&(c, ascii)| (c, ascii.to_string()))
+            .collect();
+        for code in 0xFF01..=0xFF5E_u32 {
+            let (Some(c), Some(ascii)) = (char::from_u32(code), char::from_u32(code - 0xFEE0)) else {
+                continue;
+            };
+            if c != FULLWIDTH_GRAVE {
+                replacements.insert(c, ascii.to_string());
+            }
+        }
+
+        // Keys must be single characters; anything else cannot match one
+        for (key, ascii) in config.replacements {
+            let mut chars = key.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                replacements.insert(c, ascii);
+            }
+        }
+        for allowed in &config.allowed {
+            for c in allowed.chars() {
+                replacements.remove(&c);
+            }
+        }
+
+        Self { replacements }
+    }
+
+    /// Whether ASCII was clearly intended for `code`: it contains ASCII
+    /// letters or digits, and every other character is ASCII or mapped
+    fn is_ascii_snippet(&self, code: &str) -> bool {
+        code.chars().any(|c| c.is_ascii_alphanumeric())
+            && code.chars().all(|c| c.is_ascii() || self.replacements.contains_key(&c))
+    }
+}
+
+impl Rule for MD090CodeSpanAscii {
+    fn name(&self) -> &'static str {
+        "MD090"
+    }
+
+    fn description(&self) -> &'static str {
+        "Code spans should use ASCII punctuation"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for span in ctx.code_spans().iter() {
+            let start = span.byte_offset + span.backtick_count;
+            let end = span.byte_end - span.backtick_count;
+            if start >= end {
+                continue;
+            }
+            let code = &ctx.content[start..end];
+            if code.is_ascii() || !self.is_ascii_snippet(code) {
+                continue;
+            }
+
+            for (offset, c) in code.char_indices() {
+                let Some(ascii) = self.replacements.get(&c) else {
+                    continue;
+                };
+                let byte = start + offset;
+                let (line, column) = ctx.offset_to_line_col(byte);
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line,
+                    column,
+                    end_line: line,
+                    end_column: column + 1,
+                    message: format!("Non-ASCII '{c}' (U+{:04X}) in code span; use '{ascii}'", c as u32),
+                    severity: Severity::Warning,
+                    // A backtick would close the code span early
+                    fix: (!ascii.contains('`')).then(|| Fix::new(byte..byte + c.len_utf8(), ascii.clone())),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn fix_safety(&self) -> FixSafety {
+        // Changes the characters inside code, which readers may copy
+        FixSafety::Unsafe
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.content.is_ascii() || !ctx.likely_has_code()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD090Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD090Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD090CodeSpanAscii, content: &str) -> (Vec<LintWarning>, String) {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        (rule.check(&ctx).unwrap(), rule.fix(&ctx).unwrap())
+    }
+
+    #[test]
+    fn test_typographic_and_full_width_punctuation_is_fixed() {
+        let rule = MD090CodeSpanAscii::new();
+        let (warnings, fixed) = check(
+            &rule,
+            "Run `rumdl check —fix` or set `key：value` and `name=“x”`.\n\nPlain — prose is fine.\n",
+        );
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(warnings[0].message, "Non-ASCII '—' (U+2014) in code span; use '--'");
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 18));
+        assert_eq!(
+            fixed,
+            "Run `rumdl check --fix` or set `key:value` and `name=\"x\"`.\n\nPlain — prose is fine.\n"
+        );
+    }
+
+    #[test]
+    fn test_spans_in_other_scripts_are_left_alone() {
+        let rule = MD090CodeSpanAscii::new();
+        let (warnings, _) = check(
+            &rule,
+            "Use `名前：値`, `café—bar`, `“` and `——`.\n\n```\necho “x”\n```\n",
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn test_configured_map_overrides_and_allows() {
+        let rule = MD090CodeSpanAscii::from_config_struct(MD090Config {
+            replacements: BTreeMap::from([("–".to_string(), "-".to_string()), ("×".to_string(), "x".to_string())]),
+            allowed: vec!["’".to_string()],
+        });
+        let (warnings, fixed) = check(&rule, "`ls –l`, `3×4` and `don’t`\n");
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(fixed, "`ls -l`, `3x4` and `don’t`\n");
+    }
+
+    #[test]
+    fn test_full_width_grave_is_not_mapped_to_a_backtick() {
+        let rule = MD090CodeSpanAscii::new();
+        let (warnings, _) = check(&rule, "`a｀b`\n");
+        assert!(warnings.is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Rule MD091: Link reference definitions with the same label should not
+//! point to different URLs.
+//!
+//! CommonMark uses the first definition of a label and silently ignores the
+//! rest, so a conflicting redefinition usually means one of the links goes
+//! somewhere the author did not intend. Every definition taking part in a
+//! conflict is reported, each naming one of the definitions it disagrees
+//! with. With `include-snippets`, definitions are also compared across the
+//! files MkDocs joins into one page through snippet includes.
+//!
+//! See [docs/md091.md](../../docs/md091.md) for full documentation, configuration, and examples.
+
+use crate::link_rewrite::relative_path;
+use crate::lint_context::LintContext;
+use crate::rule::{CrossFileScope, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::workspace_index::{FileIndex, ReferenceDefinitionIndex, WorkspaceIndex, extract_reference_definitions};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+mod md091_config;
+pub(super) use md091_config::MD091Config;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD091ConflictingReferenceDefinitions {
+    config: MD091Config,
+}
+
+impl MD091ConflictingReferenceDefinitions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD091Config) -> Self {
+        Self { config }
+    }
+
+    fn warning(&self, def: &ReferenceDefinitionIndex, other_url: &str, location: &str) -> LintWarning {
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: def.line,
+            column: def.column,
+            end_line: def.line,
+            // Highlight the `[label]` part of the definition
+            end_column: def.column + def.label.chars().count() + 2,
+            message: format!(
+                "Conflicting reference definition [{}]: '{}' here, '{other_url}' {location}",
+                def.label, def.url
+            ),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+}
+
+impl Rule for MD091ConflictingReferenceDefinitions {
+    fn name(&self) -> &'static str {
+        "MD091"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reference definitions with the same label should not point to different URLs"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.reference_defs.len() < 2
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let definitions = extract_reference_definitions(ctx);
+
+        let mut by_label: HashMap<&str, Vec<&ReferenceDefinitionIndex>> = HashMap::new();
+        for def in &definitions {
+            by_label.entry(def.label.as_str()).or_default().push(def);
+        }
+
+        let mut warnings = Vec::new();
+        for def in &definitions {
+            // Name the first definition of the label that disagrees with this one
+            if let Some(other) = by_label[def.label.as_str()].iter().find(|other| other.url != def.url) {
+                warnings.push(self.warning(def, &other.url, &format!("on line {}", other.line)));
+            }
+        }
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD091Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD091Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn cross_file_scope(&self) -> CrossFileScope {
+        if self.config.include_snippets {
+            CrossFileScope::Workspace
+        } else {
+            CrossFileScope::None
+        }
+    }
+
+    fn cross_file_check(
+        &self,
+        file_path: &Path,
+        file_index: &FileIndex,
+        workspace_index: &WorkspaceIndex,
+    ) -> LintResult {
+        if file_index.reference_definitions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Every page this file is rendered into: the file itself plus any page
+        // that includes it, directly or through other snippets
+        let mut pages = vec![file_path];
+        for (page, index) in workspace_index.files_sorted() {
+            if page != file_path
+                && !index.snippet_includes.is_empty()
+                && workspace_index
+                    .snippet_closure(page)
+                    .iter()
+                    .any(|(included, _)| *included == file_path)
+            {
+                pages.push(page);
+            }
+        }
+
+        // (line, other file, other line) -> warning, deduplicated across pages
+        let mut conflicts = BTreeSet::new();
+        let mut warnings = Vec::new();
+        let base_dir = file_path.parent().unwrap_or(Path::new(""));
+        for page in pages {
+            for (other_path, other_index) in workspace_index.snippet_closure(page) {
+                if other_path == file_path {
+                    continue;
+                }
+                for def in &file_index.reference_definitions {
+                    let Some(other) = other_index
+                        .reference_definitions
+                        .iter()
+                        .find(|other| other.label == def.label && other.url != def.url)
+                    else {
+                        continue;
+                    };
+                    if conflicts.insert((def.line, other_path, other.line)) {
+                        let location = format!("in {}:{}", relative_path(base_dir, other_path).display(), other.line);
+                        warnings.push(self.warning(def, &other.url, &location));
+                    }
+                }
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD091ConflictingReferenceDefinitions::new().check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_conflicting_definitions_report_both() {
+        let content = "See [docs].\n\n[docs]: https://a.example\n[Docs]: https://b.example\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Conflicting reference definition [docs]: 'https://a.example' here, 'https://b.example' on line 4"
+        );
+        assert_eq!(warnings[1].line, 4);
+        assert!(warnings[1].message.ends_with("'https://a.example' on line 3"));
+    }
+
+    #[test]
+    fn test_identical_redefinition_and_distinct_labels_are_fine() {
+        let content = "[a]: https://a.example\n[a]: https://a.example\n[b]: https://b.example\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_labels_match_ignoring_case_and_whitespace() {
+        let content = "[Read  the Docs]: https://a.example\n[read the docs]: https://b.example\n";
+        assert_eq!(check(content).len(), 2);
+    }
+
+    #[test]
+    fn test_definitions_in_code_blocks_are_ignored() {
+        let content = "[a]: https://a.example\n\n```markdown\n[a]: https://b.example\n```\n";
+        assert!(check(content).is_empty());
+    }
+
+    fn index_for(content: &str, path: &str, snippets: &[&str]) -> FileIndex {
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, Some(PathBuf::from(path)));
+        let mut index = FileIndex::new();
+        index.reference_definitions = extract_reference_definitions(&ctx);
+        index.snippet_includes = snippets.iter().map(PathBuf::from).collect();
+        index
+    }
+
+    #[test]
+    fn test_conflicts_across_snippet_includes_are_reported_in_both_files() {
+        let rule = MD091ConflictingReferenceDefinitions::from_config_struct(MD091Config { include_snippets: true });
+        assert_eq!(rule.cross_file_scope(), CrossFileScope::Workspace);
+
+        let mut workspace = WorkspaceIndex::new();
+        let page = index_for(
+            "# Page\n\n--8<-- \"_snippets/links.md\"\n\n[api]: https://old.example\n",
+            "/docs/page.md",
+            &["/docs/_snippets/links.md"],
+        );
+        let snippet = index_for("[API]: https://new.example\n", "/docs/_snippets/links.md", &[]);
+        let unrelated = index_for("[api]: https://other.example\n", "/docs/other.md", &[]);
+        workspace.insert_file(PathBuf::from("/docs/page.md"), page.clone());
+        workspace.insert_file(PathBuf::from("/docs/_snippets/links.md"), snippet.clone());
+        workspace.insert_file(PathBuf::from("/docs/other.md"), unrelated.clone());
+
+        let warnings = rule
+            .cross_file_check(Path::new("/docs/page.md"), &page, &workspace)
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(
+            warnings[0].message,
+            "Conflicting reference definition [api]: 'https://old.example' here, 'https://new.example' in _snippets/links.md:1"
+        );
+
+        let warnings = rule
+            .cross_file_check(Path::new("/docs/_snippets/links.md"), &snippet, &workspace)
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.ends_with("'https://old.example' in ../page.md:5"));
+
+        // Files that are not joined into one page never conflict
+        let warnings = rule
+            .cross_file_check(Path::new("/docs/other.md"), &unrelated, &workspace)
+            .unwrap();
+        assert!(warnings.is_empty());
+    }
+}
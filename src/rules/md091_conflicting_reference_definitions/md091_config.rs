@@ -0,0 +1,16 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD091 (conflicting reference definitions)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD091Config {
+    /// Also compare definitions across files joined into one page through
+    /// MkDocs snippet includes (`--8<--`). Needs the workspace index, so it
+    /// only applies to `rumdl check` runs over a directory and to the LSP.
+    pub include_snippets: bool,
+}
+
+impl RuleConfig for MD091Config {
+    const RULE_NAME: &'static str = "MD091";
+}
@@ -0,0 +1,441 @@
+//! Rule MD092: The front matter title and the first H1 should match.
+//!
+//! Static site generators use the front matter `title` for navigation, page
+//! titles and feeds, while readers see the H1. When both are present and say
+//! different things, the fix rewrites one side from the other; `source`
+//! decides which one is authoritative.
+//!
+//! See [docs/md092.md](../../docs/md092.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::range_utils::byte_to_char_count;
+use std::ops::Range;
+
+mod md092_config;
+pub(super) use md092_config::{MD092Config, TitleSource};
+
+/// The title field found in the front matter
+struct TitleField {
+    /// 1-indexed line number
+    line: usize,
+    /// Byte range of the value in the document, including any quotes
+    range: Range<usize>,
+    /// Unquoted value
+    value: String,
+    /// Quote character the value is written with, if any
+    quote: Option<char>,
+    /// Whether the front matter is TOML (`+++`) rather than YAML (`---`)
+    toml: bool,
+}
+
+/// The first H1 of the document
+struct FirstHeading {
+    /// 1-indexed line number
+    line: usize,
+    /// Byte range of the heading text in the document, if it can be located
+    range: Option<Range<usize>>,
+    text: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD092FrontMatterTitleMatch {
+    config: MD092Config,
+}
+
+impl MD092FrontMatterTitleMatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD092Config) -> Self {
+        Self { config }
+    }
+
+    /// Find the top-level title field in YAML or TOML front matter
+    fn find_title_field(&self, ctx: &LintContext) -> Option<TitleField> {
+        let key = self.config.front_matter_title.as_str();
+        if key.is_empty() {
+            return None;
+        }
+
+        let lines = ctx.raw_lines();
+        let delimiter = lines.first()?.trim_end();
+        let toml = match delimiter {
+            "---" => false,
+            "+++" => true,
+            _ => return None,
+        };
+
+        for (idx, line) in lines.iter().enumerate().skip(1) {
+            if line.trim_end() == delimiter || !ctx.lines.get(idx).is_some_and(|info| info.in_front_matter) {
+                break;
+            }
+
+            // Only top-level keys: nested keys are indented
+            let Some(rest) = line.strip_prefix(key) else {
+                continue;
+            };
+            let after_separator = if toml {
+                rest.trim_start().strip_prefix('=')
+            } else {
+                rest.strip_prefix(':')
+            };
+            let Some(after_separator) = after_separator else {
+                continue;
+            };
+            if !after_separator.is_empty() && !after_separator.starts_with([' ', '\t']) {
+                continue;
+            }
+
+            let value_part = after_separator.trim_start();
+            let value_start = line.len() - value_part.len();
+            let (len, value, quote) = Self::parse_scalar(value_part, toml)?;
+            let line_start = ctx.lines[idx].byte_offset;
+            return Some(TitleField {
+                line: idx + 1,
+                range: line_start + value_start..line_start + value_start + len,
+                value,
+                quote,
+                toml,
+            });
+        }
+        None
+    }
+
+    /// Parse a single-line scalar, returning its length in bytes (including
+    /// quotes), its unquoted value and the quote character used
+    fn parse_scalar(text: &str, toml: bool) -> Option<(usize, String, Option<char>)> {
+        let quote = text.chars().next()?;
+        if quote == '"' || quote == '\'' {
+            let mut value = String::new();
+            let mut chars = text.char_indices().skip(1).peekable();
+            while let Some((pos, ch)) = chars.next() {
+                if quote == '"' && ch == '\\' {
+                    let (_, escaped) = chars.next()?;
+                    value.push(escaped);
+                } else if ch == quote {
+                    // YAML escapes a single quote by doubling it
+                    if quote == '\'' && !toml && chars.peek().is_some_and(|&(_, next)| next == '\'') {
+                        chars.next();
+                        value.push('\'');
+                    } else {
+                        return Some((pos + 1, value, Some(quote)));
+                    }
+                } else {
+                    value.push(ch);
+                }
+            }
+            return None;
+        }
+
+        // TOML strings are always quoted; YAML block scalars span several lines
+        if toml || text.starts_with(['|', '>']) {
+            return None;
+        }
+        let plain = text.find(" #").map_or(text, |pos| &text[..pos]).trim_end();
+        if plain.is_empty() {
+            return None;
+        }
+        Some((plain.len(), plain.to_string(), None))
+    }
+
+    /// Write `value` back in the style of the existing title field
+    fn encode_value(value: &str, field: &TitleField) -> String {
+        let double_quoted = || format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+        match field.quote {
+            Some('\'') if field.toml && value.contains('\'') => double_quoted(),
+            Some('\'') if field.toml => format!("'{value}'"),
+            Some('\'') => format!("'{}'", value.replace('\'', "''")),
+            None if !field.toml && Self::is_plain_safe(value) => value.to_string(),
+            _ => double_quoted(),
+        }
+    }
+
+    /// Whether `value` can be written as a YAML plain scalar and read back unchanged
+    fn is_plain_safe(value: &str) -> bool {
+        !value.is_empty()
+            && value == value.trim()
+            && !value.starts_with([
+                '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%', '@', '`',
+            ])
+            && !value.contains(": ")
+            && !value.contains(" #")
+            && !value.ends_with(':')
+    }
+
+    fn first_h1(ctx: &LintContext) -> Option<FirstHeading> {
+        let heading = ctx.valid_headings().find(|h| h.heading.level == 1)?;
+        let text = heading.heading.text(ctx.content).to_string();
+        if text.is_empty() {
+            return None;
+        }
+
+        let line = heading.line_info.content(ctx.content);
+        let start = heading.heading.content_column.min(line.len());
+        let range = line.get(start..).and_then(|rest| rest.find(&text)).map(|pos| {
+            let begin = heading.line_info.byte_offset + start + pos;
+            begin..begin + text.len()
+        });
+        Some(FirstHeading {
+            line: heading.line_num,
+            range,
+            text,
+        })
+    }
+
+    fn normalize(&self, text: &str) -> String {
+        let text = if self.config.normalize_whitespace {
+            text.split_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            text.to_string()
+        };
+        if self.config.ignore_case {
+            text.to_lowercase()
+        } else {
+            text
+        }
+    }
+
+    fn warning(
+        &self,
+        ctx: &LintContext,
+        line: usize,
+        range: Range<usize>,
+        message: String,
+        fix: Option<Fix>,
+    ) -> LintWarning {
+        let line_start = ctx.line_index.get_line_start_byte(line).unwrap_or(0);
+        let line_text = ctx.line_info(line).map_or("", |info| info.content(ctx.content));
+        let column = byte_to_char_count(line_text, range.start - line_start);
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line: line,
+            end_column: column + ctx.content[range].chars().count(),
+            message,
+            severity: Severity::Warning,
+            fix,
+        }
+    }
+}
+
+impl Rule for MD092FrontMatterTitleMatch {
+    fn name(&self) -> &'static str {
+        "MD092"
+    }
+
+    fn description(&self) -> &'static str {
+        "Front matter title should match the first H1"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.lines.first().is_some_and(|line| line.in_front_matter) || !ctx.has_valid_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let (Some(title), Some(heading)) = (self.find_title_field(ctx), Self::first_h1(ctx)) else {
+            return Ok(Vec::new());
+        };
+        if self.normalize(&title.value) == self.normalize(&heading.text) {
+            return Ok(Vec::new());
+        }
+
+        let warning = match self.config.source {
+            TitleSource::Title => {
+                let message = format!(
+                    "H1 '{}' does not match front matter title '{}'",
+                    heading.text, title.value
+                );
+                match heading.range {
+                    Some(range) => {
+                        let fix = Fix::new(range.clone(), title.value.clone());
+                        self.warning(ctx, heading.line, range, message, Some(fix))
+                    }
+                    None => {
+                        let start = ctx.line_index.get_line_start_byte(heading.line).unwrap_or(0);
+                        self.warning(ctx, heading.line, start..start, message, None)
+                    }
+                }
+            }
+            TitleSource::Heading => {
+                let message = format!(
+                    "Front matter title '{}' does not match H1 '{}'",
+                    title.value, heading.text
+                );
+                let fix = Fix::new(title.range.clone(), Self::encode_value(&heading.text, &title));
+                self.warning(ctx, title.line, title.range.clone(), message, Some(fix))
+            }
+        };
+        Ok(vec![warning])
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD092Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD092Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(config: MD092Config, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD092FrontMatterTitleMatch::from_config_struct(config)
+            .check(&ctx)
+            .unwrap()
+    }
+
+    fn fix_with(config: MD092Config, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD092FrontMatterTitleMatch::from_config_struct(config)
+            .fix(&ctx)
+            .unwrap()
+    }
+
+    fn heading_source() -> MD092Config {
+        MD092Config {
+            source: TitleSource::Heading,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_matching_title_and_h1() {
+        let content = "---\ntitle: Getting  Started\n---\n\n# Getting Started\n";
+        assert!(check_with(MD092Config::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_mismatch_fixes_h1_from_title() {
+        let content = "---\ntitle: \"Getting Started\"\n---\n\n# Setup {#setup}\n";
+        let warnings = check_with(MD092Config::default(), content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(warnings[0].column, 3);
+        assert_eq!(
+            warnings[0].message,
+            "H1 'Setup' does not match front matter title 'Getting Started'"
+        );
+        assert_eq!(
+            fix_with(MD092Config::default(), content),
+            "---\ntitle: \"Getting Started\"\n---\n\n# Getting Started {#setup}\n"
+        );
+    }
+
+    #[test]
+    fn test_mismatch_fixes_title_from_h1() {
+        let content = "---\ntitle: Setup # old\ndate: 2024-01-01\n---\n\n# Getting Started\n";
+        let warnings = check_with(heading_source(), content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+        assert_eq!(warnings[0].column, 8);
+        assert_eq!(
+            fix_with(heading_source(), content),
+            "---\ntitle: Getting Started # old\ndate: 2024-01-01\n---\n\n# Getting Started\n"
+        );
+    }
+
+    #[test]
+    fn test_title_fix_keeps_quote_style_and_escapes() {
+        let content = "---\ntitle: 'Old'\n---\n\n# It's: Here\n";
+        assert_eq!(
+            fix_with(heading_source(), content),
+            "---\ntitle: 'It''s: Here'\n---\n\n# It's: Here\n"
+        );
+
+        // Plain scalars that would change meaning get double quotes
+        let content = "---\ntitle: Old\n---\n\n# Note: \"quoted\"\n";
+        assert_eq!(
+            fix_with(heading_source(), content),
+            "---\ntitle: \"Note: \\\"quoted\\\"\"\n---\n\n# Note: \"quoted\"\n"
+        );
+    }
+
+    #[test]
+    fn test_toml_front_matter() {
+        let content = "+++\ntitle = \"Old\"\n+++\n\n# New\n";
+        assert_eq!(
+            fix_with(heading_source(), content),
+            "+++\ntitle = \"New\"\n+++\n\n# New\n"
+        );
+        assert_eq!(
+            fix_with(MD092Config::default(), content),
+            "+++\ntitle = \"Old\"\n+++\n\n# Old\n"
+        );
+    }
+
+    #[test]
+    fn test_normalization_policy() {
+        let content = "---\ntitle: getting   started\n---\n\n# Getting Started\n";
+        assert_eq!(check_with(MD092Config::default(), content).len(), 1);
+
+        let config = MD092Config {
+            ignore_case: true,
+            ..Default::default()
+        };
+        assert!(check_with(config, content).is_empty());
+
+        let config = MD092Config {
+            ignore_case: true,
+            normalize_whitespace: false,
+            ..Default::default()
+        };
+        assert_eq!(check_with(config, content).len(), 1);
+    }
+
+    #[test]
+    fn test_nothing_to_compare() {
+        // No title field, nested title key, block scalar, or no H1
+        for content in [
+            "---\ndate: 2024-01-01\n---\n\n# Heading\n",
+            "---\nseo:\n  title: Other\n---\n\n# Heading\n",
+            "---\ntitle: >\n  Folded\n---\n\n# Heading\n",
+            "---\ntitle: Title\n---\n\n## Section\n",
+            "# Heading\n\ntitle: Other\n",
+        ] {
+            assert!(check_with(MD092Config::default(), content).is_empty(), "{content:?}");
+        }
+    }
+
+    #[test]
+    fn test_custom_title_key_and_setext_heading() {
+        let config = MD092Config {
+            front_matter_title: "name".to_string(),
+            ..Default::default()
+        };
+        let content = "---\nname: Intro\ntitle: Ignored\n---\n\nWelcome\n=======\n";
+        assert_eq!(
+            fix_with(config, content),
+            "---\nname: Intro\ntitle: Ignored\n---\n\nIntro\n=======\n"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Which side of a mismatch is authoritative; the other one is rewritten by the fix
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitleSource {
+    /// The front matter title wins: the fix rewrites the H1
+    #[default]
+    Title,
+    /// The H1 wins: the fix rewrites the front matter title
+    Heading,
+}
+
+/// Configuration for MD092 (front matter title matches H1)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD092Config {
+    /// Front matter key holding the page title
+    pub front_matter_title: String,
+
+    /// Treat titles that differ only in letter case as matching
+    pub ignore_case: bool,
+
+    /// Collapse runs of whitespace before comparing
+    pub normalize_whitespace: bool,
+
+    /// Which side the fix keeps: `title` rewrites the H1, `heading` rewrites the front matter
+    pub source: TitleSource,
+}
+
+impl Default for MD092Config {
+    fn default() -> Self {
+        Self {
+            front_matter_title: "title".to_string(),
+            ignore_case: false,
+            normalize_whitespace: true,
+            source: TitleSource::default(),
+        }
+    }
+}
+
+impl RuleConfig for MD092Config {
+    const RULE_NAME: &'static str = "MD092";
+}
@@ -0,0 +1,258 @@
+//! Rule MD093: Image alt text should describe the image.
+//!
+//! MD045 only checks that alt text exists. This rule looks at what it says:
+//! empty text, the image's file name, generic placeholders such as "image" or
+//! "screenshot", and text too long for a screen reader to announce comfortably
+//! are all reported. There is no fix, since only the author knows what the
+//! image shows.
+//!
+//! See [docs/md093.md](../../docs/md093.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::{LintContext, ParsedImage};
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use regex::Regex;
+
+mod md093_config;
+pub(super) use md093_config::MD093Config;
+
+#[derive(Debug, Clone)]
+pub struct MD093AltTextQuality {
+    config: MD093Config,
+    /// Compiled `allowed-patterns`; invalid patterns are logged and dropped
+    allowed_patterns: Vec<Regex>,
+}
+
+impl Default for MD093AltTextQuality {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD093AltTextQuality {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD093Config::default())
+    }
+
+    pub fn from_config_struct(config: MD093Config) -> Self {
+        let allowed_patterns = config
+            .allowed_patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(err) => {
+                    log::warn!("Invalid allowed-patterns regex for MD093 ('{pattern}'): {err}. Ignoring it.");
+                    None
+                }
+            })
+            .collect();
+        Self {
+            config,
+            allowed_patterns,
+        }
+    }
+
+    /// Why the alt text of `image` is not useful, or `None` when it is fine
+    fn problem(&self, image: &ParsedImage) -> Option<String> {
+        let alt = image.alt_text.trim();
+        if self.allowed_patterns.iter().any(|re| re.is_match(alt)) {
+            return None;
+        }
+
+        if alt.is_empty() {
+            return (!self.config.allow_empty).then(|| "Image alt text is empty".to_string());
+        }
+
+        let normalized = alt.trim_end_matches(['.', ':']).to_lowercase();
+        if self
+            .config
+            .placeholders
+            .iter()
+            .any(|placeholder| placeholder.to_lowercase() == normalized)
+        {
+            return Some(format!(
+                "Image alt text '{alt}' is a placeholder (describe what the image shows)"
+            ));
+        }
+
+        if Self::is_file_name(&normalized, &image.url) {
+            return Some(format!(
+                "Image alt text '{alt}' is the file name (describe what the image shows)"
+            ));
+        }
+
+        let length = alt.chars().count();
+        if self.config.max_length > 0 && length > self.config.max_length {
+            return Some(format!(
+                "Image alt text is {length} characters long (max {}); move details into the surrounding text",
+                self.config.max_length
+            ));
+        }
+
+        None
+    }
+
+    /// Whether the lowercased alt text is the image's file name, with or
+    /// without its extension
+    fn is_file_name(alt_lower: &str, url: &str) -> bool {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let path = std::path::Path::new(path);
+        [path.file_name(), path.file_stem()]
+            .into_iter()
+            .flatten()
+            .filter_map(|name| name.to_str())
+            .any(|name| name.to_lowercase() == alt_lower)
+    }
+}
+
+impl Rule for MD093AltTextQuality {
+    fn name(&self) -> &'static str {
+        "MD093"
+    }
+
+    fn description(&self) -> &'static str {
+        "Image alt text should describe the image"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Image
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_links_or_images()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let warnings = ctx
+            .images()
+            .iter()
+            .filter_map(|image| {
+                let message = self.problem(image)?;
+                Some(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: image.line,
+                    column: image.start_col + 1,
+                    end_line: image.line,
+                    end_column: image.end_col + 1,
+                    message,
+                    severity: Severity::Warning,
+                    fix: None,
+                })
+            })
+            .collect();
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD093Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD093Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(config: MD093Config, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD093AltTextQuality::from_config_struct(config).check(&ctx).unwrap()
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        check_with(MD093Config::default(), content)
+    }
+
+    #[test]
+    fn test_descriptive_alt_text() {
+        assert!(check("![Build pipeline with three stages](img/pipeline.png)\n").is_empty());
+    }
+
+    #[test]
+    fn test_empty_alt_text() {
+        let warnings = check("Intro ![ ](logo.png)\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Image alt text is empty");
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 7));
+        assert!(warnings[0].fix.is_none());
+
+        let config = MD093Config {
+            allow_empty: true,
+            ..Default::default()
+        };
+        assert!(check_with(config, "![](divider.svg)\n").is_empty());
+    }
+
+    #[test]
+    fn test_placeholder_alt_text() {
+        let warnings = check("![Screenshot](a.png)\n![image.](b.png)\n![Alt text:](c.png)\n");
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].message.contains("'Screenshot' is a placeholder"));
+
+        let config = MD093Config {
+            placeholders: vec!["Diagram".to_string()],
+            ..Default::default()
+        };
+        let warnings = check_with(config, "![diagram](a.png)\n![Screenshot](b.png)\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 1);
+    }
+
+    #[test]
+    fn test_file_name_alt_text() {
+        let content = "![Pipeline.PNG](img/pipeline.png)\n![pipeline](img/pipeline.png?raw=true)\n\n![deploy][ref]\n\n[ref]: https://example.com/deploy.svg\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.iter().all(|w| w.message.contains("is the file name")));
+    }
+
+    #[test]
+    fn test_alt_text_length() {
+        let long = "word ".repeat(30);
+        let content = format!("![{}](a.png)\n", long.trim());
+        let warnings = check(&content);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("149 characters long (max 125)"));
+
+        let config = MD093Config {
+            max_length: 0,
+            ..Default::default()
+        };
+        assert!(check_with(config, &content).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_patterns() {
+        let config = MD093Config {
+            allowed_patterns: vec!["^TODO:".to_string(), "^image$".to_string(), "(".to_string()],
+            ..Default::default()
+        };
+        let content = "![TODO: describe chart](chart.png)\n![image](a.png)\n![Screenshot](b.png)\n";
+        let warnings = check_with(config, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+    }
+
+    #[test]
+    fn test_images_in_code_are_ignored() {
+        assert!(check("```markdown\n![image](a.png)\n```\n\n`![image](b.png)`\n").is_empty());
+    }
+}
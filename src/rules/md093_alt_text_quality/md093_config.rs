@@ -0,0 +1,37 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD093 (alt text quality)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD093Config {
+    /// Longest acceptable alt text in characters; `0` disables the length check
+    pub max_length: usize,
+
+    /// Accept empty alt text, which marks an image as decorative
+    pub allow_empty: bool,
+
+    /// Alt texts that say nothing about the image, compared case-insensitively
+    pub placeholders: Vec<String>,
+
+    /// Regexes for alt texts that are always accepted, e.g. `^TODO:`
+    pub allowed_patterns: Vec<String>,
+}
+
+impl Default for MD093Config {
+    fn default() -> Self {
+        Self {
+            max_length: 125,
+            allow_empty: false,
+            placeholders: ["image", "img", "picture", "photo", "screenshot", "graphic", "alt text"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            allowed_patterns: Vec::new(),
+        }
+    }
+}
+
+impl RuleConfig for MD093Config {
+    const RULE_NAME: &'static str = "MD093";
+}
@@ -0,0 +1,465 @@
+//! Rule MD094: Callouts should use one syntax.
+//!
+//! The same note can be written as a MkDocs admonition (`!!! note`), a GitHub
+//! alert (`> [!NOTE]`) or an Obsidian callout (`> [!note] Title`), and only
+//! the platform's own syntax renders as a callout. This rule reports callouts
+//! written in another syntax and converts them when nothing is lost: GitHub
+//! alerts have no titles, no fold state and only five types, and titles with
+//! double quotes cannot be written as MkDocs titles.
+//!
+//! Only top-level callouts are checked; ones nested in lists or other
+//! containers are left alone.
+//!
+//! See [docs/md094.md](../../docs/md094.md) for full documentation, configuration, and examples.
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use regex::Regex;
+use std::sync::LazyLock;
+
+mod md094_config;
+pub(super) use md094_config::{CalloutStyle, MD094Config};
+
+/// `!!! type`, `??? type` or `???+ type` with optional `inline [end]` and
+/// `"title"`; groups: marker, type, inline modifier, title
+static MKDOCS_OPENER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(!!!|\?\?\?\+?)[ \t]+([A-Za-z][\w-]*)((?:[ \t]+inline(?:[ \t]+end)?)?)(?:[ \t]+"([^"]*)")?[ \t]*$"#)
+        .unwrap()
+});
+
+/// `> [!type]` with an optional fold marker and title; groups: type, fold, title
+static BLOCKQUOTE_OPENER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^>[ \t]?\[!([A-Za-z][\w-]*)\]([+-])?(?:[ \t]+(\S.*?))?[ \t]*$").unwrap());
+
+/// The alert types GitHub renders
+const GITHUB_TYPES: [&str; 5] = ["NOTE", "TIP", "IMPORTANT", "WARNING", "CAUTION"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Syntax {
+    MkDocs,
+    GitHub,
+    Obsidian,
+}
+
+impl Syntax {
+    fn label(self) -> &'static str {
+        match self {
+            Syntax::MkDocs => "MkDocs admonition",
+            Syntax::GitHub => "GitHub alert",
+            Syntax::Obsidian => "Obsidian callout",
+        }
+    }
+}
+
+/// Whether a callout starts collapsed, open, or cannot be collapsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fold {
+    None,
+    Collapsed,
+    Open,
+}
+
+/// A top-level callout in any of the three syntaxes
+struct Callout {
+    syntax: Syntax,
+    /// 0-indexed line of the opener
+    start_line: usize,
+    /// 0-indexed line after the last body line
+    end_line: usize,
+    kind: String,
+    title: Option<String>,
+    fold: Fold,
+    /// MkDocs `inline` / `inline end` modifier
+    inline: bool,
+    /// Body lines without the indentation or `>` prefix
+    body: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD094CalloutStyle {
+    config: MD094Config,
+}
+
+impl MD094CalloutStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD094Config) -> Self {
+        Self { config }
+    }
+
+    fn line<'a>(ctx: &LintContext<'a>, idx: usize) -> &'a str {
+        ctx.lines[idx].content(ctx.content).trim_end_matches('\r')
+    }
+
+    /// All top-level callouts in document order
+    fn callouts(ctx: &LintContext) -> Vec<Callout> {
+        let mut callouts = Vec::new();
+        let mut idx = 0;
+        while idx < ctx.lines.len() {
+            let info = &ctx.lines[idx];
+            let skipped = info.in_code_block || info.in_front_matter || info.in_html_comment || info.in_html_block;
+            let callout = if skipped {
+                None
+            } else {
+                Self::parse_mkdocs(ctx, idx).or_else(|| Self::parse_blockquote(ctx, idx))
+            };
+            match callout {
+                Some(callout) => {
+                    idx = callout.end_line;
+                    callouts.push(callout);
+                }
+                None => idx += 1,
+            }
+        }
+        callouts
+    }
+
+    fn parse_mkdocs(ctx: &LintContext, start: usize) -> Option<Callout> {
+        let caps = MKDOCS_OPENER.captures(Self::line(ctx, start))?;
+        let fold = match &caps[1] {
+            "???" => Fold::Collapsed,
+            "???+" => Fold::Open,
+            _ => Fold::None,
+        };
+
+        let mut body = Vec::new();
+        let mut end = start + 1;
+        for idx in start + 1..ctx.lines.len() {
+            let line = Self::line(ctx, idx);
+            if line.trim().is_empty() {
+                body.push(String::new());
+                continue;
+            }
+            let Some(content) = line.strip_prefix("    ").or_else(|| line.strip_prefix('\t')) else {
+                break;
+            };
+            body.push(content.to_string());
+            end = idx + 1;
+        }
+        body.truncate(end - start - 1);
+
+        Some(Callout {
+            syntax: Syntax::MkDocs,
+            start_line: start,
+            end_line: end,
+            kind: caps[2].to_string(),
+            title: caps.get(4).map(|title| title.as_str().to_string()),
+            fold,
+            inline: !caps[3].is_empty(),
+            body,
+        })
+    }
+
+    fn parse_blockquote(ctx: &LintContext, start: usize) -> Option<Callout> {
+        let caps = BLOCKQUOTE_OPENER.captures(Self::line(ctx, start))?;
+        let kind = caps[1].to_string();
+        let title = caps.get(3).map(|title| title.as_str().to_string());
+        let fold = match caps.get(2).map(|fold| fold.as_str()) {
+            Some("-") => Fold::Collapsed,
+            Some("+") => Fold::Open,
+            _ => Fold::None,
+        };
+
+        let mut body = Vec::new();
+        let mut end = start + 1;
+        while end < ctx.lines.len() {
+            let Some(rest) = Self::line(ctx, end).strip_prefix('>') else {
+                break;
+            };
+            body.push(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+            end += 1;
+        }
+
+        let is_github = title.is_none() && fold == Fold::None && kind.chars().all(|c| !c.is_lowercase());
+        Some(Callout {
+            syntax: if is_github { Syntax::GitHub } else { Syntax::Obsidian },
+            start_line: start,
+            end_line: end,
+            kind,
+            title,
+            fold,
+            inline: false,
+            body,
+        })
+    }
+
+    /// The syntax callouts should use in this document
+    fn target(&self, ctx: &LintContext, first: &Callout) -> Syntax {
+        match self.config.style {
+            CalloutStyle::Flavor => match ctx.flavor {
+                MarkdownFlavor::MkDocs => Syntax::MkDocs,
+                MarkdownFlavor::Obsidian => Syntax::Obsidian,
+                _ => Syntax::GitHub,
+            },
+            CalloutStyle::Consistent => first.syntax,
+            CalloutStyle::Mkdocs => Syntax::MkDocs,
+            CalloutStyle::Github => Syntax::GitHub,
+            CalloutStyle::Obsidian => Syntax::Obsidian,
+        }
+    }
+
+    /// `callout` written in `target` syntax, or why it cannot be converted
+    /// without losing something
+    fn convert(callout: &Callout, target: Syntax, line_ending: &str) -> Result<String, &'static str> {
+        if callout.inline {
+            return Err("inline admonitions have no equivalent");
+        }
+
+        let mut lines = Vec::with_capacity(callout.body.len() + 1);
+        match target {
+            Syntax::MkDocs => {
+                let marker = match callout.fold {
+                    Fold::None => "!!!",
+                    Fold::Collapsed => "???",
+                    Fold::Open => "???+",
+                };
+                let mut opener = format!("{marker} {}", callout.kind.to_lowercase());
+                if let Some(title) = &callout.title {
+                    if title.contains('"') {
+                        return Err("MkDocs titles cannot contain double quotes");
+                    }
+                    opener.push_str(&format!(" \"{title}\""));
+                }
+                lines.push(opener);
+                lines.extend(callout.body.iter().map(|line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("    {line}")
+                    }
+                }));
+                return Ok(lines.join(line_ending));
+            }
+            Syntax::GitHub => {
+                if callout.title.is_some() {
+                    return Err("GitHub alerts have no titles");
+                }
+                if callout.fold != Fold::None {
+                    return Err("GitHub alerts cannot be collapsed");
+                }
+                let kind = callout.kind.to_uppercase();
+                if !GITHUB_TYPES.contains(&kind.as_str()) {
+                    return Err("GitHub only supports NOTE, TIP, IMPORTANT, WARNING and CAUTION");
+                }
+                lines.push(format!("> [!{kind}]"));
+            }
+            Syntax::Obsidian => {
+                let fold = match callout.fold {
+                    Fold::None => "",
+                    Fold::Collapsed => "-",
+                    Fold::Open => "+",
+                };
+                let mut opener = format!("> [!{}]{fold}", callout.kind.to_lowercase());
+                match callout.title.as_deref() {
+                    Some("") => return Err("an empty title has no equivalent"),
+                    Some(title) => opener.push_str(&format!(" {title}")),
+                    None => {}
+                }
+                lines.push(opener);
+            }
+        }
+        lines.extend(callout.body.iter().map(|line| {
+            if line.trim().is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        }));
+        Ok(lines.join(line_ending))
+    }
+}
+
+impl Rule for MD094CalloutStyle {
+    fn name(&self) -> &'static str {
+        "MD094"
+    }
+
+    fn description(&self) -> &'static str {
+        "Callouts should use a consistent syntax"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("[!") && !ctx.content.contains("!!!") && !ctx.content.contains("???")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let callouts = Self::callouts(ctx);
+        let Some(first) = callouts.first() else {
+            return Ok(Vec::new());
+        };
+        let target = self.target(ctx, first);
+        let line_ending = crate::utils::line_ending::detect_line_ending(ctx.content);
+
+        let mut warnings = Vec::new();
+        for callout in callouts.iter().filter(|callout| callout.syntax != target) {
+            let opener = Self::line(ctx, callout.start_line);
+            let mut message = format!(
+                "Callout uses {} syntax; expected {}",
+                callout.syntax.label(),
+                target.label()
+            );
+            let fix = match Self::convert(callout, target, line_ending) {
+                Ok(replacement) => {
+                    let last = callout.end_line - 1;
+                    let start = ctx.lines[callout.start_line].byte_offset;
+                    let end = ctx.lines[last].byte_offset + Self::line(ctx, last).len();
+                    Some(Fix::new(start..end, replacement))
+                }
+                Err(reason) => {
+                    message.push_str(&format!(" (not converted: {reason})"));
+                    None
+                }
+            };
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: callout.start_line + 1,
+                column: 1,
+                end_line: callout.start_line + 1,
+                end_column: opener.chars().count() + 1,
+                message,
+                severity: Severity::Warning,
+                fix,
+            });
+        }
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD094Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD094Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(style: CalloutStyle) -> MD094CalloutStyle {
+        MD094CalloutStyle::from_config_struct(MD094Config { style })
+    }
+
+    fn check(style: CalloutStyle, flavor: MarkdownFlavor, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, flavor, None);
+        rule(style).check(&ctx).unwrap()
+    }
+
+    fn fix(style: CalloutStyle, flavor: MarkdownFlavor, content: &str) -> String {
+        let ctx = LintContext::new(content, flavor, None);
+        rule(style).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_style_follows_flavor_by_default() {
+        let content = "!!! note\n    Body.\n\n> [!NOTE]\n> Body.\n";
+        let standard = check(CalloutStyle::Flavor, MarkdownFlavor::Standard, content);
+        assert_eq!(standard.len(), 1);
+        assert_eq!(standard[0].line, 1);
+        assert_eq!(
+            standard[0].message,
+            "Callout uses MkDocs admonition syntax; expected GitHub alert"
+        );
+
+        let mkdocs = check(CalloutStyle::Flavor, MarkdownFlavor::MkDocs, content);
+        assert_eq!(mkdocs.len(), 1);
+        assert_eq!(mkdocs[0].line, 4);
+    }
+
+    #[test]
+    fn test_mkdocs_to_github() {
+        let content = "Intro.\n\n!!! warning\n    First line.\n\n    ```sh\n    rm -rf build\n    ```\n\nAfter.\n";
+        assert_eq!(
+            fix(CalloutStyle::Github, MarkdownFlavor::Standard, content),
+            "Intro.\n\n> [!WARNING]\n> First line.\n>\n> ```sh\n> rm -rf build\n> ```\n\nAfter.\n"
+        );
+    }
+
+    #[test]
+    fn test_obsidian_to_mkdocs_keeps_title_and_fold() {
+        let content = "> [!tip]- Read this\n> Folded body.\n";
+        assert_eq!(
+            fix(CalloutStyle::Mkdocs, MarkdownFlavor::MkDocs, content),
+            "??? tip \"Read this\"\n    Folded body.\n"
+        );
+    }
+
+    #[test]
+    fn test_github_and_mkdocs_to_obsidian() {
+        let content = "> [!NOTE]\n> Plain.\n\n???+ info \"Details\"\n    Open by default.\n";
+        assert_eq!(
+            fix(CalloutStyle::Obsidian, MarkdownFlavor::Obsidian, content),
+            "> [!note]\n> Plain.\n\n> [!info]+ Details\n> Open by default.\n"
+        );
+    }
+
+    #[test]
+    fn test_lossy_conversions_are_reported_without_fix() {
+        let content = "> [!note] Custom title\n> Body.\n\n> [!bug]\n> Body.\n\n!!! note inline\n    Body.\n";
+        let warnings = check(CalloutStyle::Github, MarkdownFlavor::Standard, content);
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert!(
+            warnings[0]
+                .message
+                .ends_with("(not converted: GitHub alerts have no titles)")
+        );
+        assert!(warnings[1].message.contains("GitHub only supports"));
+        assert!(warnings[2].message.contains("inline admonitions"));
+    }
+
+    #[test]
+    fn test_consistent_uses_first_callout() {
+        let content = "> [!note] First\n> Body.\n\n> [!WARNING]\n> Body.\n";
+        let warnings = check(CalloutStyle::Consistent, MarkdownFlavor::Standard, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(
+            fix(CalloutStyle::Consistent, MarkdownFlavor::Standard, content),
+            "> [!note] First\n> Body.\n\n> [!warning]\n> Body.\n"
+        );
+    }
+
+    #[test]
+    fn test_crlf_and_code_blocks() {
+        let content = "```markdown\n!!! note\n    Example.\n```\n\r\n!!! tip\r\n    Body.\r\n";
+        let content = content.replace("\r\n", "\n").replace('\n', "\r\n");
+        assert_eq!(
+            fix(CalloutStyle::Github, MarkdownFlavor::Standard, &content),
+            "```markdown\r\n!!! note\r\n    Example.\r\n```\r\n\r\n> [!TIP]\r\n> Body.\r\n"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Which callout syntax the document should use
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CalloutStyle {
+    /// The flavor's native syntax: MkDocs admonitions for `mkdocs`, Obsidian
+    /// callouts for `obsidian`, GitHub alerts otherwise
+    #[default]
+    Flavor,
+    /// Whatever syntax the first callout in the document uses
+    Consistent,
+    /// `!!! note "Title"` with an indented body
+    Mkdocs,
+    /// `> [!NOTE]` with one of GitHub's five alert types
+    Github,
+    /// `> [!note] Title` with optional fold markers
+    Obsidian,
+}
+
+/// Configuration for MD094 (callout style)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD094Config {
+    /// Callout syntax to enforce
+    pub style: CalloutStyle,
+}
+
+impl RuleConfig for MD094Config {
+    const RULE_NAME: &'static str = "MD094";
+}
@@ -0,0 +1,305 @@
+//! Rule MD095: Reference definitions should be sorted.
+//!
+//! Link reference definitions collected at the bottom of a file are easier to
+//! scan and merge when they follow a fixed order. This rule checks the block
+//! of definitions at the end of the file, or at the end of every section, and
+//! reorders it alphabetically or by first use.
+//!
+//! See [docs/md095.md](../../docs/md095.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use std::collections::HashMap;
+
+mod md095_config;
+pub(super) use md095_config::{DefinitionOrder, DefinitionPlacement, MD095Config};
+
+/// One definition in a block; lines are 1-indexed and inclusive
+struct Definition<'a> {
+    id: &'a str,
+    label: &'a str,
+    first_line: usize,
+    last_line: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD095SortedReferenceDefinitions {
+    config: MD095Config,
+}
+
+impl MD095SortedReferenceDefinitions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD095Config) -> Self {
+        Self { config }
+    }
+
+    fn line<'a>(ctx: &LintContext<'a>, line: usize) -> &'a str {
+        ctx.lines[line - 1].content(ctx.content).trim_end_matches('\r')
+    }
+
+    /// Runs of top-level definitions separated only by blank lines
+    fn blocks<'a>(ctx: &'a LintContext) -> Vec<Vec<Definition<'a>>> {
+        let mut blocks: Vec<Vec<Definition>> = Vec::new();
+        for def in &ctx.reference_defs {
+            let text = Self::line(ctx, def.line);
+            let Some(label) = text
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once("]:"))
+                .map(|(l, _)| l)
+            else {
+                // Indented, quoted or listed definitions end the current block
+                blocks.push(Vec::new());
+                continue;
+            };
+            let last_line = ctx.offset_to_line_col(def.byte_end.saturating_sub(1)).0.max(def.line);
+            let definition = Definition {
+                id: &def.id,
+                label,
+                first_line: def.line,
+                last_line,
+            };
+
+            let continues = blocks.last().and_then(|block| block.last()).is_some_and(|prev| {
+                (prev.last_line + 1..definition.first_line).all(|line| Self::line(ctx, line).trim().is_empty())
+            });
+            match blocks.last_mut() {
+                Some(block) if continues => block.push(definition),
+                _ => blocks.push(vec![definition]),
+            }
+        }
+        blocks.retain(|block| block.len() > 1);
+        blocks
+    }
+
+    /// Whether the block ends where `placement` expects definitions
+    fn is_checked(&self, ctx: &LintContext, block: &[Definition]) -> bool {
+        let after = block.last().map_or(0, |def| def.last_line) + 1;
+        let next = (after..=ctx.lines.len()).find(|&line| !Self::line(ctx, line).trim().is_empty());
+        match (self.config.placement, next) {
+            (_, None) => true,
+            (DefinitionPlacement::EndOfFile, Some(_)) => false,
+            (DefinitionPlacement::EndOfSection, Some(line)) => ctx.lines[line - 1].heading.is_some(),
+        }
+    }
+
+    /// Byte offset of the first use of every reference label
+    fn first_uses(ctx: &LintContext) -> HashMap<String, usize> {
+        let mut uses: HashMap<String, usize> = HashMap::new();
+        let links = ctx.links().iter().map(|link| (&link.reference_id, link.byte_offset));
+        let images = ctx
+            .images()
+            .iter()
+            .map(|image| (&image.reference_id, image.byte_offset));
+        for (id, offset) in links.chain(images) {
+            if let Some(id) = id {
+                let first = uses.entry(id.to_lowercase()).or_insert(offset);
+                *first = (*first).min(offset);
+            }
+        }
+        uses
+    }
+
+    fn order_name(&self) -> &'static str {
+        match self.config.order {
+            DefinitionOrder::Alphabetical => "alphabetically",
+            DefinitionOrder::FirstUse => "by first use",
+        }
+    }
+
+    /// Replacement text for the block with the definitions reordered. Blank
+    /// lines between definitions stay between the same positions.
+    fn sorted_text(ctx: &LintContext, block: &[Definition], order: &[&Definition], line_ending: &str) -> String {
+        let mut lines: Vec<&str> = Vec::new();
+        for (position, def) in order.iter().enumerate() {
+            lines.extend((def.first_line..=def.last_line).map(|line| Self::line(ctx, line)));
+            if let Some(next) = block.get(position + 1) {
+                lines.extend((block[position].last_line + 1..next.first_line).map(|line| Self::line(ctx, line)));
+            }
+        }
+        lines.join(line_ending)
+    }
+}
+
+impl Rule for MD095SortedReferenceDefinitions {
+    fn name(&self) -> &'static str {
+        "MD095"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reference definitions should be sorted"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.reference_defs.len() < 2
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let first_uses = match self.config.order {
+            DefinitionOrder::FirstUse => Self::first_uses(ctx),
+            DefinitionOrder::Alphabetical => HashMap::new(),
+        };
+        let line_ending = crate::utils::line_ending::detect_line_ending(ctx.content);
+
+        let mut warnings = Vec::new();
+        for block in Self::blocks(ctx) {
+            if !self.is_checked(ctx, &block) {
+                continue;
+            }
+
+            let mut order: Vec<&Definition> = block.iter().collect();
+            match self.config.order {
+                DefinitionOrder::Alphabetical => {
+                    order.sort_by(|a, b| super::md088_sorted_lists::compare_numeric(a.id, b.id));
+                }
+                DefinitionOrder::FirstUse => {
+                    order.sort_by_key(|def| first_uses.get(def.id).copied().unwrap_or(usize::MAX));
+                }
+            }
+            let Some((found, expected)) = block
+                .iter()
+                .zip(&order)
+                .find(|(found, expected)| found.first_line != expected.first_line)
+            else {
+                continue;
+            };
+
+            let last = block.last().map_or(found.last_line, |def| def.last_line);
+            let start = ctx.lines[block[0].first_line - 1].byte_offset;
+            let end = ctx.lines[last - 1].byte_offset + Self::line(ctx, last).len();
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: found.first_line,
+                column: 1,
+                end_line: found.first_line,
+                end_column: Self::line(ctx, found.first_line).chars().count() + 1,
+                message: format!(
+                    "Reference definitions should be sorted {}: expected '[{}]' before '[{}]'",
+                    self.order_name(),
+                    expected.label,
+                    found.label
+                ),
+                severity: Severity::Warning,
+                fix: Some(Fix::new(
+                    start..end,
+                    Self::sorted_text(ctx, &block, &order, line_ending),
+                )),
+            });
+        }
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD095Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD095Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule(order: DefinitionOrder, placement: DefinitionPlacement) -> MD095SortedReferenceDefinitions {
+        MD095SortedReferenceDefinitions::from_config_struct(MD095Config { order, placement })
+    }
+
+    fn check(rule: &MD095SortedReferenceDefinitions, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD095SortedReferenceDefinitions, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_alphabetical_end_of_file() {
+        let rule = MD095SortedReferenceDefinitions::new();
+        let content = "See [b], [a] and [item 10].\n\n[b]: https://b.example\n[Item 10]: https://10.example\n\n[a]: https://a.example \"A\"\n[item 2]: https://2.example\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Reference definitions should be sorted alphabetically: expected '[a]' before '[b]'"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "See [b], [a] and [item 10].\n\n[a]: https://a.example \"A\"\n[b]: https://b.example\n\n[item 2]: https://2.example\n[Item 10]: https://10.example\n"
+        );
+    }
+
+    #[test]
+    fn test_sorted_definitions_and_other_blocks_are_accepted() {
+        let rule = MD095SortedReferenceDefinitions::new();
+        // The first block is followed by more content, so it is not at the end of the file
+        let content = "[z]: https://z.example\n[y]: https://y.example\n\nText.\n\n[a]: https://a.example\n[b]: https://b.example\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_first_use_order_puts_unused_last() {
+        let rule = rule(DefinitionOrder::FirstUse, DefinitionPlacement::EndOfFile);
+        let content = "![logo][Logo] then [docs] and [api][].\n\n[api]: https://api.example\n[unused]: https://u.example\n[docs]: https://docs.example\n[logo]: logo.svg\n";
+        assert_eq!(
+            fix(&rule, content),
+            "![logo][Logo] then [docs] and [api][].\n\n[logo]: logo.svg\n[docs]: https://docs.example\n[api]: https://api.example\n[unused]: https://u.example\n"
+        );
+    }
+
+    #[test]
+    fn test_end_of_section_placement() {
+        let content = "# One\n\n[b]: https://b.example\n[a]: https://a.example\n\n# Two\n\n[d]: https://d.example\n[c]: https://c.example\n";
+        let end_of_file = check(&MD095SortedReferenceDefinitions::new(), content);
+        assert_eq!(end_of_file.len(), 1);
+        assert_eq!(end_of_file[0].line, 8);
+
+        let rule = rule(DefinitionOrder::Alphabetical, DefinitionPlacement::EndOfSection);
+        assert_eq!(
+            fix(&rule, content),
+            "# One\n\n[a]: https://a.example\n[b]: https://b.example\n\n# Two\n\n[c]: https://c.example\n[d]: https://d.example\n"
+        );
+    }
+
+    #[test]
+    fn test_multiline_definitions_and_crlf() {
+        let rule = MD095SortedReferenceDefinitions::new();
+        let content = "Text.\r\n\r\n[b]: https://b.example\r\n  \"Title B\"\r\n[a]: https://a.example\r\n";
+        assert_eq!(
+            fix(&rule, content),
+            "Text.\r\n\r\n[a]: https://a.example\r\n[b]: https://b.example\r\n  \"Title B\"\r\n"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// How reference definitions are ordered
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DefinitionOrder {
+    /// By label, case-insensitively, with numbers compared by value
+    #[default]
+    Alphabetical,
+    /// In the order the labels are first used; unused definitions go last
+    FirstUse,
+}
+
+/// Which blocks of definitions are checked
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DefinitionPlacement {
+    /// The block at the end of the file
+    #[default]
+    EndOfFile,
+    /// The block at the end of each section, before the next heading
+    EndOfSection,
+}
+
+/// Configuration for MD095 (sorted reference definitions)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD095Config {
+    /// Sort order for definitions
+    pub order: DefinitionOrder,
+
+    /// Where the checked definition blocks are
+    pub placement: DefinitionPlacement,
+}
+
+impl RuleConfig for MD095Config {
+    const RULE_NAME: &'static str = "MD095";
+}
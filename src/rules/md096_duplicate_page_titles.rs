@@ -0,0 +1,335 @@
+//! Rule MD096: Page titles should be unique across the workspace.
+//!
+//! An opt-in workspace rule. MkDocs, Docusaurus and similar generators use a
+//! page's first level-1 heading as its title in navigation and search, so two
+//! pages with the same H1 are hard to tell apart. While files are linted, the
+//! workspace index records each file's first H1; once the whole workspace is
+//! indexed, every page whose title another page of the same documentation
+//! root shares is reported.
+//!
+//! See [docs/md096.md](../../docs/md096.md) for full documentation, configuration, and examples.
+
+use crate::link_rewrite::relative_path;
+use crate::lint_context::LintContext;
+use crate::rule::{CrossFileScope, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::project_root::discover_project_root_from;
+use crate::workspace_index::{FileIndex, WorkspaceIndex};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+
+mod md096_config;
+pub(super) use md096_config::MD096Config;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD096DuplicatePageTitles {
+    config: MD096Config,
+    include: GlobSet,
+    exclude: GlobSet,
+    /// Root that `roots`, `include` and `exclude` resolve against; discovered
+    /// from the working directory when unset
+    project_root: Option<PathBuf>,
+}
+
+impl MD096DuplicatePageTitles {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD096Config::default())
+    }
+
+    pub fn from_config_struct(config: MD096Config) -> Self {
+        Self {
+            include: glob_set(&config.include),
+            exclude: glob_set(&config.exclude),
+            config,
+            project_root: None,
+        }
+    }
+
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(project_root);
+        self
+    }
+
+    /// Resolve the documentation roots against the project root
+    fn scope(&self) -> Result<Scope, LintError> {
+        let project_root = match &self.project_root {
+            Some(root) => root.clone(),
+            None => discover_project_root_from(&std::env::current_dir()?),
+        };
+        let project_root = project_root.canonicalize().unwrap_or(project_root);
+        let roots = self
+            .config
+            .roots
+            .iter()
+            .map(|dir| normalize_path(&project_root.join(dir)))
+            .collect();
+        Ok(Scope { project_root, roots })
+    }
+
+    /// The deepest documentation root containing `path`, or `None` when the
+    /// file is outside every root or scoped out by `include`/`exclude`
+    fn site_of<'a>(&self, scope: &'a Scope, path: &Path) -> Option<&'a Path> {
+        let relative = path.strip_prefix(&scope.project_root).unwrap_or(path);
+        if self.exclude.is_match(relative) || (!self.config.include.is_empty() && !self.include.is_match(relative)) {
+            return None;
+        }
+        scope
+            .roots
+            .iter()
+            .filter(|root| path.starts_with(root))
+            .max_by_key(|root| root.components().count())
+            .map(PathBuf::as_path)
+    }
+
+    fn is_ignored(&self, key: &str) -> bool {
+        self.config.ignore.iter().any(|title| title_key(title) == key)
+    }
+}
+
+impl Rule for MD096DuplicatePageTitles {
+    fn name(&self) -> &'static str {
+        "MD096"
+    }
+
+    fn description(&self) -> &'static str {
+        "Page titles should be unique across the workspace"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn check(&self, _ctx: &LintContext) -> LintResult {
+        // Nothing to report per document; see cross_file_check
+        Ok(Vec::new())
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD096Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD096Config>(config);
+        Box::new(Self {
+            project_root: config.project_root.clone(),
+            ..Self::from_config_struct(rule_config)
+        })
+    }
+
+    fn cross_file_scope(&self) -> CrossFileScope {
+        CrossFileScope::Workspace
+    }
+
+    fn cross_file_check(
+        &self,
+        file_path: &Path,
+        file_index: &FileIndex,
+        workspace_index: &WorkspaceIndex,
+    ) -> LintResult {
+        let Some(title) = &file_index.page_title else {
+            return Ok(Vec::new());
+        };
+        let key = title_key(&title.text);
+        if key.is_empty() || self.is_ignored(&key) {
+            return Ok(Vec::new());
+        }
+        let scope = self.scope()?;
+        let Some(site) = self.site_of(&scope, file_path) else {
+            return Ok(Vec::new());
+        };
+
+        let others: Vec<(&Path, usize)> = workspace_index
+            .files_sorted()
+            .into_iter()
+            .filter(|(path, _)| *path != file_path)
+            .filter_map(|(path, index)| {
+                let other = index.page_title.as_ref()?;
+                (title_key(&other.text) == key && self.site_of(&scope, path) == Some(site))
+                    .then_some((path, other.line))
+            })
+            .collect();
+        let Some((first_path, first_line)) = others.first() else {
+            return Ok(Vec::new());
+        };
+
+        let base_dir = file_path.parent().unwrap_or(Path::new(""));
+        let mut location = format!("{}:{first_line}", relative_path(base_dir, first_path).display());
+        if others.len() > 1 {
+            location.push_str(&format!(" and {} other file(s)", others.len() - 1));
+        }
+        Ok(vec![LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: title.line,
+            column: title.column,
+            end_line: title.line,
+            end_column: title.column + title.text.chars().count() + 2,
+            message: format!("Duplicate page title '{}' (also in {location})", title.text),
+            severity: Severity::Warning,
+            fix: None,
+        }])
+    }
+}
+
+/// Documentation roots resolved for one check
+struct Scope {
+    project_root: PathBuf,
+    roots: Vec<PathBuf>,
+}
+
+/// Titles compare case-insensitively with internal whitespace collapsed
+fn title_key(title: &str) -> String {
+    title.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn glob_set(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_default()
+}
+
+/// Normalize a path by resolving . and .. components
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                components.pop();
+            }
+            std::path::Component::CurDir => {}
+            _ => components.push(component),
+        }
+    }
+    components.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use crate::workspace_index::extract_page_title;
+
+    /// Index each `(path, content)` under `root`, returning the workspace index
+    fn workspace(root: &Path, files: &[(&str, &str)]) -> WorkspaceIndex {
+        let mut index = WorkspaceIndex::new();
+        for (path, content) in files {
+            let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+            let mut file_index = FileIndex::new();
+            file_index.page_title = extract_page_title(&ctx);
+            index.insert_file(root.join(path), file_index);
+        }
+        index
+    }
+
+    /// Warnings of every file, as `path:line: message`, in path order
+    fn check(rule: &MD096DuplicatePageTitles, root: &Path, files: &[(&str, &str)]) -> Vec<String> {
+        let index = workspace(root, files);
+        let mut reports = Vec::new();
+        for (path, file_index) in index.files_sorted() {
+            for warning in rule.cross_file_check(path, file_index, &index).unwrap() {
+                let relative = path.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/");
+                reports.push(format!("{relative}:{}: {}", warning.line, warning.message));
+            }
+        }
+        reports
+    }
+
+    #[test]
+    fn test_page_title_is_first_h1() {
+        let ctx = LintContext::new(
+            "---\ntitle: Ignored\n---\n\n## Intro\n\n```md\n# Not a heading\n```\n\n# Guide  \n\n# Second\n",
+            MarkdownFlavor::Standard,
+            None,
+        );
+        let title = extract_page_title(&ctx).unwrap();
+        assert_eq!((title.text.as_str(), title.line, title.column), ("Guide", 11, 1));
+
+        let ctx = LintContext::new("## Only sections\n", MarkdownFlavor::Standard, None);
+        assert!(extract_page_title(&ctx).is_none());
+    }
+
+    #[test]
+    fn test_reports_every_page_sharing_a_title() {
+        let root = Path::new("/project");
+        let rule = MD096DuplicatePageTitles::new().with_project_root(root.to_path_buf());
+        let reports = check(
+            &rule,
+            root,
+            &[
+                ("docs/a.md", "# Getting  Started\n"),
+                ("docs/b.md", "# getting started\n"),
+                ("docs/c.md", "# Unique\n"),
+                ("docs/d.md", "Text without a title\n"),
+            ],
+        );
+        assert_eq!(
+            reports,
+            vec![
+                "docs/a.md:1: Duplicate page title 'Getting  Started' (also in b.md:1)",
+                "docs/b.md:1: Duplicate page title 'getting started' (also in a.md:1)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_roots_scope_and_ignore() {
+        let root = Path::new("/project");
+        let files = [
+            ("docs/intro.md", "# Intro\n"),
+            ("docs/blog/intro.md", "# Intro\n"),
+            ("docs/archive/intro.md", "# Intro\n"),
+            ("notes/intro.md", "# Intro\n"),
+            ("docs/overview.md", "# Overview\n"),
+            ("docs/guide/overview.md", "# Overview\n"),
+        ];
+
+        // With one root everything under it is one site
+        let rule = MD096DuplicatePageTitles::from_config_struct(MD096Config {
+            roots: vec!["docs".to_string()],
+            exclude: vec!["docs/archive/**".to_string()],
+            ignore: vec!["overview".to_string()],
+            ..MD096Config::default()
+        })
+        .with_project_root(root.to_path_buf());
+        assert_eq!(
+            check(&rule, root, &files),
+            vec![
+                "docs/blog/intro.md:1: Duplicate page title 'Intro' (also in ../intro.md:1)",
+                "docs/intro.md:1: Duplicate page title 'Intro' (also in blog/intro.md:1)",
+            ]
+        );
+
+        // A nested root is a separate site
+        let rule = MD096DuplicatePageTitles::from_config_struct(MD096Config {
+            roots: vec!["docs".to_string(), "docs/blog".to_string()],
+            include: vec!["docs/intro.md".to_string(), "docs/blog/**".to_string()],
+            ..MD096Config::default()
+        })
+        .with_project_root(root.to_path_buf());
+        assert!(check(&rule, root, &files).is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_nothing_per_file() {
+        let rule = MD096DuplicatePageTitles::new();
+        let ctx = LintContext::new("# Title\n\n# Title\n", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD096 (duplicate page titles across the workspace)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD096Config {
+    /// Documentation roots, relative to the project root. Each root is one
+    /// site: titles are only compared between files of the same root, and a
+    /// file belongs to the deepest root containing it. Files outside every
+    /// root are not checked.
+    pub roots: Vec<String>,
+
+    /// Glob patterns, relative to the project root, limiting the files that
+    /// are checked and compared. Empty means every file under the roots.
+    pub include: Vec<String>,
+
+    /// Glob patterns, relative to the project root, for files that are never
+    /// checked or compared (e.g. `"**/index.md"`)
+    pub exclude: Vec<String>,
+
+    /// Titles that may repeat, compared case-insensitively (e.g. `"Overview"`)
+    pub ignore: Vec<String>,
+}
+
+impl Default for MD096Config {
+    fn default() -> Self {
+        Self {
+            roots: vec![".".to_string()],
+            include: Vec::new(),
+            exclude: Vec::new(),
+            ignore: Vec::new(),
+        }
+    }
+}
+
+impl RuleConfig for MD096Config {
+    const RULE_NAME: &'static str = "MD096";
+}
@@ -0,0 +1,583 @@
+//! Rule MD097: Diagram code blocks should be syntactically valid.
+//!
+//! An opt-in rule. Mermaid and PlantUML blocks are rendered by the site
+//! generator or hosting platform, which typically shows a broken diagram
+//! (or nothing) instead of reporting the error. This rule performs basic
+//! checks without running a renderer: Mermaid blocks must start with a known
+//! diagram type and keep their brackets balanced, and PlantUML `@start…`
+//! markers must be closed by the matching `@end…`.
+//!
+//! Validation is pluggable through [`DiagramValidator`], so a validator that
+//! shells out to a renderer such as `mmdc` can replace the built-in checks
+//! for a language via [`MD097DiagramSyntax::with_validator`].
+//!
+//! See [docs/md097.md](../../docs/md097.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::code_block_utils::CodeBlockDetail;
+use std::sync::Arc;
+
+mod md097_config;
+pub(super) use md097_config::MD097Config;
+
+/// Diagram types a Mermaid block may start with
+const MERMAID_DIAGRAM_TYPES: &[&str] = &[
+    "architecture",
+    "architecture-beta",
+    "block",
+    "block-beta",
+    "C4Component",
+    "C4Container",
+    "C4Context",
+    "C4Deployment",
+    "C4Dynamic",
+    "classDiagram",
+    "classDiagram-v2",
+    "erDiagram",
+    "flowchart",
+    "flowchart-elk",
+    "gantt",
+    "gitGraph",
+    "graph",
+    "info",
+    "journey",
+    "kanban",
+    "mindmap",
+    "packet",
+    "packet-beta",
+    "pie",
+    "quadrantChart",
+    "radar-beta",
+    "requirementDiagram",
+    "sankey",
+    "sankey-beta",
+    "sequenceDiagram",
+    "stateDiagram",
+    "stateDiagram-v2",
+    "timeline",
+    "treemap",
+    "treemap-beta",
+    "xychart",
+    "xychart-beta",
+    "zenuml",
+];
+
+/// A problem found in the source of a diagram block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagramIssue {
+    /// 1-indexed line within the block body (the line after the opening fence is 1)
+    pub line: usize,
+    /// 1-indexed character column within that line
+    pub column: usize,
+    pub message: String,
+}
+
+impl DiagramIssue {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+/// Syntax checker for the diagram blocks of one or more languages
+///
+/// Implement this to plug in another validator, such as one that runs an
+/// external renderer, and register it with [`MD097DiagramSyntax::with_validator`].
+pub trait DiagramValidator: std::fmt::Debug + Send + Sync {
+    /// Diagram language named in messages (e.g. "Mermaid")
+    fn name(&self) -> &str;
+
+    /// Whether this validator checks blocks with the given info-string language
+    fn handles(&self, language: &str) -> bool;
+
+    /// Check a block body, given as the lines between its fences joined with `\n`
+    fn validate(&self, source: &str) -> Vec<DiagramIssue>;
+}
+
+/// Built-in Mermaid checks: a known diagram type on the first line, and
+/// balanced brackets in diagrams where brackets are structural
+#[derive(Debug, Clone, Default)]
+pub struct MermaidValidator {
+    extra_diagram_types: Vec<String>,
+}
+
+impl MermaidValidator {
+    pub fn new(extra_diagram_types: Vec<String>) -> Self {
+        Self { extra_diagram_types }
+    }
+
+    fn is_known_type(&self, diagram_type: &str) -> bool {
+        MERMAID_DIAGRAM_TYPES.contains(&diagram_type) || self.extra_diagram_types.iter().any(|t| t == diagram_type)
+    }
+}
+
+impl DiagramValidator for MermaidValidator {
+    fn name(&self) -> &'static str {
+        "Mermaid"
+    }
+
+    fn handles(&self, language: &str) -> bool {
+        language.eq_ignore_ascii_case("mermaid")
+    }
+
+    fn validate(&self, source: &str) -> Vec<DiagramIssue> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut idx = 0;
+
+        // Optional YAML front matter (title, config) before the diagram type
+        if lines
+            .iter()
+            .find(|line| !line.trim().is_empty())
+            .map(|line| line.trim())
+            == Some("---")
+        {
+            idx = lines.iter().position(|line| line.trim() == "---").unwrap_or(0) + 1;
+            match lines[idx..].iter().position(|line| line.trim() == "---") {
+                Some(end) => idx += end + 1,
+                None => return vec![DiagramIssue::new(idx, 1, "front matter is not closed by '---'")],
+            }
+        }
+
+        // The first line that is neither blank nor a %% comment or directive
+        let Some(first) = (idx..lines.len()).find(|&i| {
+            let trimmed = lines[i].trim();
+            !trimmed.is_empty() && !trimmed.starts_with("%%")
+        }) else {
+            return vec![DiagramIssue::new(1, 1, "diagram is empty")];
+        };
+
+        let line = lines[first];
+        let diagram_type = line
+            .split(|c: char| c.is_whitespace() || c == ';' || c == ':')
+            .find(|token| !token.is_empty())
+            .unwrap_or("");
+        if !self.is_known_type(diagram_type) {
+            let column = line.len() - line.trim_start().len() + 1;
+            return vec![DiagramIssue::new(
+                first + 1,
+                column,
+                format!("unknown diagram type '{diagram_type}'"),
+            )];
+        }
+
+        let brackets: &[(char, char)] = match diagram_type {
+            "graph" | "flowchart" | "flowchart-elk" => &[('(', ')'), ('[', ']'), ('{', '}')],
+            "classDiagram" | "classDiagram-v2" | "stateDiagram" | "stateDiagram-v2" => &[('{', '}')],
+            _ => return Vec::new(),
+        };
+        check_brackets(
+            &lines,
+            first + 1,
+            brackets,
+            diagram_type.starts_with("graph") || diagram_type.starts_with("flowchart"),
+        )
+    }
+}
+
+/// Report unmatched, mismatched and unclosed brackets in `lines[start..]`.
+///
+/// Quoted strings and `%%` comment lines are skipped. With `flowchart_shapes`,
+/// a `>` right after a node id opens the asymmetric shape `id>label]`.
+fn check_brackets(
+    lines: &[&str],
+    start: usize,
+    brackets: &[(char, char)],
+    flowchart_shapes: bool,
+) -> Vec<DiagramIssue> {
+    let mut issues = Vec::new();
+    // (expected closer, shown opener, line, column)
+    let mut open: Vec<(char, char, usize, usize)> = Vec::new();
+
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        if line.trim_start().starts_with("%%") {
+            continue;
+        }
+        let mut in_quotes = false;
+        let mut prev: Option<char> = None;
+        for (col, ch) in line.chars().enumerate() {
+            let (line_num, column) = (idx + 1, col + 1);
+            if ch == '"' {
+                in_quotes = !in_quotes;
+            } else if in_quotes {
+            } else if let Some(&(_, closer)) = brackets.iter().find(|(opener, _)| *opener == ch) {
+                open.push((closer, ch, line_num, column));
+            } else if flowchart_shapes
+                && ch == '>'
+                && open.is_empty()
+                && prev.is_some_and(|p| p.is_alphanumeric() || p == '_')
+            {
+                open.push((']', '>', line_num, column));
+            } else if brackets.iter().any(|(_, closer)| *closer == ch) {
+                match open.pop() {
+                    Some((expected, _, _, _)) if expected == ch => {}
+                    Some((expected, opener, open_line, _)) => issues.push(DiagramIssue::new(
+                        line_num,
+                        column,
+                        format!("'{ch}' does not match '{opener}' on line {open_line} (expected '{expected}')"),
+                    )),
+                    None => issues.push(DiagramIssue::new(line_num, column, format!("unmatched '{ch}'"))),
+                }
+            }
+            prev = Some(ch);
+        }
+    }
+
+    issues.extend(
+        open.into_iter()
+            .map(|(_, opener, line, column)| DiagramIssue::new(line, column, format!("unclosed '{opener}'"))),
+    );
+    issues.sort_by_key(|issue| (issue.line, issue.column));
+    issues
+}
+
+/// Built-in PlantUML checks: every `@start…` marker is closed by the matching `@end…`
+#[derive(Debug, Clone, Default)]
+pub struct PlantUmlValidator;
+
+impl DiagramValidator for PlantUmlValidator {
+    fn name(&self) -> &'static str {
+        "PlantUML"
+    }
+
+    fn handles(&self, language: &str) -> bool {
+        language.eq_ignore_ascii_case("plantuml") || language.eq_ignore_ascii_case("puml")
+    }
+
+    fn validate(&self, source: &str) -> Vec<DiagramIssue> {
+        let mut issues = Vec::new();
+        // (diagram kind, line, column) of the open @start marker
+        let mut open: Option<(&str, usize, usize)> = None;
+
+        for (idx, line) in source.lines().enumerate() {
+            let trimmed = line.trim_start();
+            let column = line.len() - trimmed.len() + 1;
+            let marker = trimmed.split_whitespace().next().unwrap_or("");
+            if let Some(kind) = marker.strip_prefix("@start") {
+                if let Some((open_kind, open_line, open_column)) = open.replace((kind, idx + 1, column)) {
+                    issues.push(DiagramIssue::new(
+                        open_line,
+                        open_column,
+                        format!("'@start{open_kind}' is not closed by '@end{open_kind}'"),
+                    ));
+                }
+            } else if let Some(kind) = marker.strip_prefix("@end") {
+                match open.take() {
+                    Some((open_kind, _, _)) if open_kind == kind => {}
+                    Some((open_kind, _, _)) => issues.push(DiagramIssue::new(
+                        idx + 1,
+                        column,
+                        format!("'@end{kind}' does not match '@start{open_kind}'"),
+                    )),
+                    None => issues.push(DiagramIssue::new(
+                        idx + 1,
+                        column,
+                        format!("'@end{kind}' without a matching '@start{kind}'"),
+                    )),
+                }
+            }
+        }
+
+        if let Some((kind, line, column)) = open {
+            issues.push(DiagramIssue::new(
+                line,
+                column,
+                format!("'@start{kind}' is not closed by '@end{kind}'"),
+            ));
+        }
+        issues
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MD097DiagramSyntax {
+    /// Consulted in order; the first validator handling a block's language checks it
+    validators: Vec<Arc<dyn DiagramValidator>>,
+}
+
+impl Default for MD097DiagramSyntax {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD097DiagramSyntax {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD097Config::default())
+    }
+
+    pub fn from_config_struct(config: MD097Config) -> Self {
+        Self {
+            validators: vec![
+                Arc::new(MermaidValidator::new(config.mermaid_diagram_types)),
+                Arc::new(PlantUmlValidator),
+            ],
+        }
+    }
+
+    /// Register a validator ahead of the existing ones, so it takes over the
+    /// languages it handles from the built-in checks
+    pub fn with_validator(mut self, validator: Arc<dyn DiagramValidator>) -> Self {
+        self.validators.insert(0, validator);
+        self
+    }
+
+    fn validator_for(&self, detail: &CodeBlockDetail) -> Option<&dyn DiagramValidator> {
+        if !detail.is_fenced {
+            return None;
+        }
+        let language = detail.info_string.split_whitespace().next()?;
+        self.validators
+            .iter()
+            .find(|validator| validator.handles(language))
+            .map(AsRef::as_ref)
+    }
+
+    /// The 0-indexed line of the opening fence and the lines between the
+    /// fences. Blocks inside blockquotes are skipped: their lines carry `>`
+    /// markers that are not part of the diagram.
+    fn body_lines<'a>(ctx: &'a LintContext, detail: &CodeBlockDetail) -> Option<(usize, Vec<&'a str>)> {
+        let open_idx = ctx
+            .line_offsets
+            .binary_search(&detail.start)
+            .unwrap_or_else(|i| i.saturating_sub(1));
+        if ctx.lines.get(open_idx).is_none_or(|info| info.blockquote.is_some()) {
+            return None;
+        }
+
+        let mut body = Vec::new();
+        for info in ctx.lines.iter().skip(open_idx + 1) {
+            if info.byte_offset >= detail.end || !info.in_code_block {
+                break;
+            }
+            let raw = info.content(ctx.content);
+            let text = raw.trim_start();
+            if text.starts_with("```") || text.starts_with("~~~") {
+                break;
+            }
+            body.push(raw);
+        }
+        Some((open_idx, body))
+    }
+}
+
+impl Rule for MD097DiagramSyntax {
+    fn name(&self) -> &'static str {
+        "MD097"
+    }
+
+    fn description(&self) -> &'static str {
+        "Diagram code blocks should be syntactically valid"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for detail in &ctx.code_block_details {
+            let Some(validator) = self.validator_for(detail) else {
+                continue;
+            };
+            let Some((open_idx, body)) = Self::body_lines(ctx, detail) else {
+                continue;
+            };
+
+            for issue in validator.validate(&body.join("\n")) {
+                // Issues on a line past the body (such as an empty diagram)
+                // are reported on the opening fence
+                let (line, column) = if issue.line >= 1 && issue.line <= body.len() {
+                    (open_idx + 1 + issue.line, issue.column)
+                } else {
+                    (open_idx + 1, 1)
+                };
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line,
+                    column,
+                    end_line: line,
+                    end_column: column + 1,
+                    message: format!(
+                        "{} syntax: {} (diagram line {})",
+                        validator.name(),
+                        issue.message,
+                        issue.line
+                    ),
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_code() || ctx.code_block_details.is_empty()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD097Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD097DiagramSyntax, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn messages(warnings: &[LintWarning]) -> Vec<(usize, usize, &str)> {
+        warnings
+            .iter()
+            .map(|w| (w.line, w.column, w.message.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_diagrams_pass() {
+        let content = "# Diagrams\n\n```mermaid\n---\ntitle: Flow\n---\n%% comment (\nflowchart LR\n    A[\"Start (here\"] --> B{Choice}\n    B -->|yes| C((Done))\n    B --> D>Flag]\n```\n\n```mermaid\nsequenceDiagram\n    A-)B: async (no brackets checked\n```\n\n```plantuml\n@startuml\nA -> B\n@enduml\n```\n\n```puml\nA -> B\n```\n";
+        let warnings = check(&MD097DiagramSyntax::new(), content);
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn test_unknown_mermaid_diagram_type() {
+        let content = "```mermaid\n\n  grpah TD\n  A --> B\n```\n";
+        let warnings = check(&MD097DiagramSyntax::new(), content);
+        assert_eq!(
+            messages(&warnings),
+            vec![(3, 3, "Mermaid syntax: unknown diagram type 'grpah' (diagram line 2)")]
+        );
+    }
+
+    #[test]
+    fn test_extra_mermaid_diagram_types() {
+        let rule = MD097DiagramSyntax::from_config_struct(MD097Config {
+            mermaid_diagram_types: vec!["venn-beta".to_string()],
+        });
+        assert!(check(&rule, "```mermaid\nvenn-beta\n  A\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_unbalanced_flowchart_brackets() {
+        let content = "Text\n\n```mermaid\ngraph TD\n    A[Start --> B\n    B --> C)\n    C --> D{End\n```\n";
+        let warnings = check(&MD097DiagramSyntax::new(), content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                (
+                    6,
+                    12,
+                    "Mermaid syntax: ')' does not match '[' on line 2 (expected ']') (diagram line 3)"
+                ),
+                (7, 12, "Mermaid syntax: unclosed '{' (diagram line 4)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_class_diagram_checks_braces_only() {
+        let content = "```mermaid\nclassDiagram\n    class Animal {\n        +eat(food\n    }\n    }\n```\n";
+        let warnings = check(&MD097DiagramSyntax::new(), content);
+        assert_eq!(
+            messages(&warnings),
+            vec![(6, 5, "Mermaid syntax: unmatched '}' (diagram line 5)")]
+        );
+    }
+
+    #[test]
+    fn test_empty_mermaid_block_reported_on_fence() {
+        let warnings = check(&MD097DiagramSyntax::new(), "```mermaid\n```\n");
+        assert_eq!(
+            messages(&warnings),
+            vec![(1, 1, "Mermaid syntax: diagram is empty (diagram line 1)")]
+        );
+    }
+
+    #[test]
+    fn test_plantuml_markers() {
+        let content = "```plantuml\n@startuml\nA -> B\n```\n\n```plantuml\n@startmindmap\n* root\n@enduml\n```\n\n```plantuml\nA -> B\n@enduml\n```\n";
+        let warnings = check(&MD097DiagramSyntax::new(), content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                (
+                    2,
+                    1,
+                    "PlantUML syntax: '@startuml' is not closed by '@enduml' (diagram line 1)"
+                ),
+                (
+                    9,
+                    1,
+                    "PlantUML syntax: '@enduml' does not match '@startmindmap' (diagram line 3)"
+                ),
+                (
+                    14,
+                    1,
+                    "PlantUML syntax: '@enduml' without a matching '@startuml' (diagram line 2)"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blockquoted_and_other_blocks_skipped() {
+        let content = "> ```mermaid\n> grpah\n> ```\n\n```text\ngrpah\n```\n\n    mermaid\n";
+        assert!(check(&MD097DiagramSyntax::new(), content).is_empty());
+    }
+
+    #[derive(Debug)]
+    struct RejectAll;
+
+    impl DiagramValidator for RejectAll {
+        fn name(&self) -> &'static str {
+            "External"
+        }
+
+        fn handles(&self, language: &str) -> bool {
+            language == "mermaid"
+        }
+
+        fn validate(&self, source: &str) -> Vec<DiagramIssue> {
+            vec![DiagramIssue::new(source.lines().count(), 2, "rejected")]
+        }
+    }
+
+    #[test]
+    fn test_plugged_in_validator_takes_over_language() {
+        let rule = MD097DiagramSyntax::new().with_validator(Arc::new(RejectAll));
+        let content = "```mermaid\ngraph TD\n  A --> B\n```\n\n```plantuml\n@startuml\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                (3, 2, "External syntax: rejected (diagram line 2)"),
+                (
+                    7,
+                    1,
+                    "PlantUML syntax: '@startuml' is not closed by '@enduml' (diagram line 1)"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD097 (diagram syntax)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD097Config {
+    /// Mermaid diagram types accepted in addition to the built-in ones, for
+    /// diagrams newer than this release (e.g. `"venn-beta"`)
+    #[serde(alias = "mermaid_diagram_types")]
+    pub mermaid_diagram_types: Vec<String>,
+}
+
+impl RuleConfig for MD097Config {
+    const RULE_NAME: &'static str = "MD097";
+}
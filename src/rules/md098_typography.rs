@@ -0,0 +1,418 @@
+//! Rule MD098: Prose should use consistent typography.
+//!
+//! An opt-in rule that enforces one way of writing quotes, ellipses and
+//! dashes in prose: typographic characters (`“…”`, `…`, `–`, `—`) or their
+//! ASCII stand-ins (`"..."`, `...`, `--`, `---`). Curly quotes follow the
+//! configured locale, so German text gets `„…“` and French text `«…»`.
+//!
+//! Only prose is checked. Code blocks and spans, URLs and link destinations,
+//! HTML, math, templates and attribute lists keep their ASCII characters.
+//!
+//! See [docs/md098.md](../../docs/md098.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::mkdocs_attr_list::ATTR_LIST_PATTERN;
+use crate::utils::prose::{NonProseSpans, is_non_prose_line};
+
+mod md098_config;
+pub(super) use md098_config::MD098Config;
+pub use md098_config::{DashStyle, EllipsisStyle, QuoteStyle};
+
+const APOSTROPHE: char = '\u{2019}';
+const ELLIPSIS: char = '\u{2026}';
+const EN_DASH: char = '\u{2013}';
+const EM_DASH: char = '\u{2014}';
+
+/// Curly quotes that `quotes = "straight"` replaces with `"`
+const CURLY_DOUBLE: &[char] = &['\u{201C}', '\u{201D}', '\u{201E}', '\u{201F}'];
+/// Curly quotes that `quotes = "straight"` replaces with `'`
+const CURLY_SINGLE: &[char] = &['\u{2018}', '\u{2019}', '\u{201A}', '\u{201B}'];
+
+/// Characters after which a quote opens rather than closes
+const OPENING_CONTEXT: &[char] = &[
+    '(', '[', '{', '<', '>', '/', '-', EN_DASH, EM_DASH, '\u{201C}', '\u{201E}', '\u{2018}', '\u{201A}', '«', '‹',
+    '「', '『',
+];
+
+/// Opening and closing quotes of a locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LocaleQuotes {
+    double_open: char,
+    double_close: char,
+    single_open: char,
+    single_close: char,
+}
+
+impl LocaleQuotes {
+    /// Quotes for a language tag such as `de` or `fr-CA`; unknown languages use English quotes
+    fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        let (double_open, double_close, single_open, single_close) = match language.as_str() {
+            "de" | "cs" | "sk" => ('„', '“', '‚', '‘'),
+            "fr" => ('«', '»', '‹', '›'),
+            "es" | "it" | "pt" => ('«', '»', '“', '”'),
+            "ru" | "uk" => ('«', '»', '„', '“'),
+            "pl" => ('„', '”', '«', '»'),
+            "sv" | "fi" => ('”', '”', '’', '’'),
+            "da" => ('»', '«', '›', '‹'),
+            "ja" | "zh" => ('「', '」', '『', '』'),
+            _ => ('“', '”', '‘', '’'),
+        };
+        Self {
+            double_open,
+            double_close,
+            single_open,
+            single_close,
+        }
+    }
+}
+
+/// Quotes opened earlier on the line and not yet closed
+#[derive(Debug, Default)]
+struct OpenQuotes {
+    double: bool,
+    single: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MD098Typography {
+    config: MD098Config,
+    quotes: LocaleQuotes,
+}
+
+impl Default for MD098Typography {
+    fn default() -> Self {
+        Self::from_config_struct(MD098Config::default())
+    }
+}
+
+impl MD098Typography {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD098Config) -> Self {
+        let quotes = LocaleQuotes::for_locale(&config.locale);
+        Self { config, quotes }
+    }
+
+    /// Whether `c` could start a warning under the current configuration
+    fn is_candidate(&self, c: char) -> bool {
+        match c {
+            '"' | '\'' => self.config.quotes == QuoteStyle::Curly,
+            '.' => self.config.ellipsis == EllipsisStyle::Character,
+            ELLIPSIS => self.config.ellipsis == EllipsisStyle::Dots,
+            '-' => self.config.dashes == DashStyle::Typographic,
+            EN_DASH | EM_DASH => self.config.dashes == DashStyle::Ascii,
+            _ => self.config.quotes == QuoteStyle::Straight && (CURLY_DOUBLE.contains(&c) || CURLY_SINGLE.contains(&c)),
+        }
+    }
+
+    /// The curly replacement for a straight quote, or `None` where the quote
+    /// has no clear role, such as an inch mark after a number
+    fn curly_quote(&self, c: char, prev: Option<char>, next: Option<char>, open: &mut OpenQuotes) -> Option<char> {
+        let opens = prev.is_none_or(|p| p.is_whitespace() || OPENING_CONTEXT.contains(&p));
+        let next_is_space = next.is_none_or(char::is_whitespace);
+
+        if c == '"' {
+            if opens {
+                if next_is_space {
+                    return None;
+                }
+                open.double = true;
+                return Some(self.quotes.double_open);
+            }
+            if prev.is_some_and(|p| p.is_ascii_digit()) && !open.double {
+                return None;
+            }
+            open.double = false;
+            return Some(self.quotes.double_close);
+        }
+
+        if prev.is_some_and(char::is_alphanumeric) && next.is_some_and(char::is_alphabetic) {
+            return Some(APOSTROPHE);
+        }
+        if opens {
+            if next_is_space {
+                return None;
+            }
+            // Elided digits, as in '90s
+            if next.is_some_and(|n| n.is_ascii_digit()) {
+                return Some(APOSTROPHE);
+            }
+            open.single = true;
+            return Some(self.quotes.single_open);
+        }
+        if open.single {
+            open.single = false;
+            return Some(self.quotes.single_close);
+        }
+        // A foot mark after a number; otherwise a trailing apostrophe, as in dogs'
+        (!prev.is_some_and(|p| p.is_ascii_digit())).then_some(APOSTROPHE)
+    }
+
+    /// Whether a run of `len` hyphens is a dash rather than markup, a command
+    /// line option or an arrow
+    fn is_dash_run(len: usize, prev: Option<char>, next: Option<char>) -> bool {
+        if len != 2 && len != 3 {
+            return false;
+        }
+        if prev.is_some_and(|p| matches!(p, '<' | '!' | '|')) || next.is_some_and(|n| matches!(n, '>' | '|')) {
+            return false;
+        }
+        // `--option`
+        !(prev.is_none_or(char::is_whitespace) && next.is_some_and(char::is_alphabetic))
+    }
+
+    fn check_line(
+        &self,
+        ctx: &LintContext,
+        spans: &NonProseSpans,
+        line_num: usize,
+        line_start: usize,
+        line: &str,
+        warnings: &mut Vec<LintWarning>,
+    ) {
+        let attr_lists: Vec<(usize, usize)> = if line.contains('{') {
+            ATTR_LIST_PATTERN
+                .find_iter(line)
+                .map(|m| (m.start(), m.end()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        let mut open = OpenQuotes::default();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let (offset, c) = chars[i];
+            if !self.is_candidate(c)
+                || attr_lists.iter().any(|&(start, end)| offset >= start && offset < end)
+                || spans.contains(ctx, line_start + offset)
+            {
+                i += 1;
+                continue;
+            }
+
+            let prev = i.checked_sub(1).map(|j| chars[j].1);
+            let run = chars[i..].iter().take_while(|&&(_, r)| r == c).count();
+            let next = chars.get(i + run).map(|&(_, n)| n);
+            let single_next = chars.get(i + 1).map(|&(_, n)| n);
+
+            let replacement: Option<String> = match c {
+                '"' | '\'' => self.curly_quote(c, prev, single_next, &mut open).map(String::from),
+                '.' => (run == 3 && prev != Some('/') && next != Some('/')).then(|| ELLIPSIS.to_string()),
+                ELLIPSIS => Some("...".to_string()),
+                '-' => Self::is_dash_run(run, prev, next).then(|| if run == 3 { EM_DASH } else { EN_DASH }.to_string()),
+                EN_DASH => Some("--".to_string()),
+                EM_DASH => Some("---".to_string()),
+                _ if CURLY_DOUBLE.contains(&c) => Some("\"".to_string()),
+                _ => Some("'".to_string()),
+            };
+
+            // Dots and hyphens are replaced as a whole run
+            let len = if matches!(c, '.' | '-') { run } else { 1 };
+            if let Some(replacement) = replacement {
+                let start = line_start + offset;
+                let end = start + len * c.len_utf8();
+                let original = &ctx.content[start..end];
+                let column = line[..offset].chars().count() + 1;
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: line_num,
+                    column,
+                    end_line: line_num,
+                    end_column: column + len,
+                    message: format!("Use `{replacement}` instead of `{original}`"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix::new(start..end, replacement)),
+                });
+            }
+            i += if matches!(c, '.' | '-') { run } else { 1 };
+        }
+    }
+}
+
+impl Rule for MD098Typography {
+    fn name(&self) -> &'static str {
+        "MD098"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prose should use consistent typography"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let spans = NonProseSpans::new(ctx);
+
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            let line = info.content(ctx.content);
+            if !line.chars().any(|c| self.is_candidate(c)) || is_non_prose_line(info, line, ctx.flavor) {
+                continue;
+            }
+            self.check_line(ctx, &spans, idx + 1, info.byte_offset, line, &mut warnings);
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.chars().any(|c| self.is_candidate(c))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD098Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule(config: MD098Config) -> MD098Typography {
+        MD098Typography::from_config_struct(config)
+    }
+
+    fn fixed(rule: &MD098Typography, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    fn check(rule: &MD098Typography, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_curly_quotes_and_apostrophes() {
+        let rule = MD098Typography::new();
+        assert_eq!(
+            fixed(&rule, "She said \"don't\" and 'maybe'.\n"),
+            "She said “don’t” and ‘maybe’.\n"
+        );
+        assert_eq!(
+            fixed(&rule, "In the '90s the dogs' bowls held 5' and 12\" of water.\n"),
+            "In the ’90s the dogs’ bowls held 5' and 12\" of water.\n"
+        );
+        assert_eq!(fixed(&rule, "(\"quoted\") and \"2024\".\n"), "(“quoted”) and “2024”.\n");
+    }
+
+    #[test]
+    fn test_warning_position_and_message() {
+        let warnings = check(&MD098Typography::new(), "# Title\n\nWait... what -- really?\n");
+        let found: Vec<_> = warnings
+            .iter()
+            .map(|w| (w.line, w.column, w.end_column, w.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, 5, 8, "Use `…` instead of `...`"),
+                (3, 14, 16, "Use `–` instead of `--`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locale_quotes() {
+        let german = rule(MD098Config {
+            locale: "de-CH".to_string(),
+            ..MD098Config::default()
+        });
+        assert_eq!(fixed(&german, "Er sagte \"Hallo\".\n"), "Er sagte „Hallo“.\n");
+
+        let french = rule(MD098Config {
+            locale: "fr".to_string(),
+            ..MD098Config::default()
+        });
+        assert_eq!(fixed(&french, "Il a dit \"bonjour\".\n"), "Il a dit «bonjour».\n");
+    }
+
+    #[test]
+    fn test_straight_and_ascii_styles() {
+        let rule = rule(MD098Config {
+            quotes: QuoteStyle::Straight,
+            ellipsis: EllipsisStyle::Dots,
+            dashes: DashStyle::Ascii,
+            ..MD098Config::default()
+        });
+        assert_eq!(
+            fixed(&rule, "“It’s” — the end… 1990–2000\n"),
+            "\"It's\" --- the end... 1990--2000\n"
+        );
+        assert!(check(&rule, "She said \"don't\" -- wait...\n").is_empty());
+    }
+
+    #[test]
+    fn test_dashes() {
+        let rule = MD098Typography::new();
+        assert_eq!(fixed(&rule, "Pages 10--20 --- or more.\n"), "Pages 10–20 — or more.\n");
+        // Options, arrows, comments and longer runs are left alone
+        let content = "Run it with --fix. A --> B. Not ---- this.\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_ignored_styles() {
+        let rule = rule(MD098Config {
+            quotes: QuoteStyle::Ignore,
+            ellipsis: EllipsisStyle::Ignore,
+            dashes: DashStyle::Ignore,
+            ..MD098Config::default()
+        });
+        assert!(check(&rule, "\"a\" -- b... “c” — d…\n").is_empty());
+    }
+
+    #[test]
+    fn test_non_prose_is_skipped() {
+        let rule = MD098Typography::new();
+        let content = "---\ntitle: \"A -- B\"\n---\n\n\
+            Use `\"x\" -- y...` or [the \"docs\"](https://example.com/a--b...c \"Title\").\n\n\
+            ![\"alt\"](img.png) <span title=\"a\">it's</span> https://example.com/x--y\n\n\
+            ```text\n\"quoted\" -- ...\n```\n\n\
+            <!-- \"comment\" -- -->\n\n\
+            | a | b |\n|---|---|\n| \"c\" | d |\n\n\
+            Heading\n-------\n\n\
+            [ref]: https://example.com/a--b \"Ref title\"\n";
+        let fixed = fixed(&rule, content);
+        let expected = content
+            .replace("[the \"docs\"]", "[the “docs”]")
+            .replace("it's", "it’s")
+            .replace("| \"c\" |", "| “c” |");
+        assert_eq!(fixed, expected);
+    }
+
+    #[test]
+    fn test_attribute_lists_are_skipped() {
+        let rule = MD098Typography::new();
+        let content = "## Title {: #id title=\"x\" }\n";
+        assert!(check(&rule, content).is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Quote characters to enforce in prose (MD098)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum QuoteStyle {
+    /// Typographic quotes for the configured locale, and `’` for apostrophes
+    #[default]
+    Curly,
+    /// ASCII `"` and `'`
+    Straight,
+    /// Quotes are not checked
+    Ignore,
+}
+
+/// How an ellipsis is written in prose (MD098)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum EllipsisStyle {
+    /// The single character `…`
+    #[default]
+    Character,
+    /// Three periods, `...`
+    Dots,
+    /// Ellipses are not checked
+    Ignore,
+}
+
+/// How en and em dashes are written in prose (MD098)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DashStyle {
+    /// `–` and `—` instead of `--` and `---`
+    #[default]
+    Typographic,
+    /// `--` and `---` instead of `–` and `—`
+    Ascii,
+    /// Dashes are not checked
+    Ignore,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD098Config {
+    /// Quote characters to enforce: `curly`, `straight`, or `ignore`
+    #[serde(default)]
+    pub quotes: QuoteStyle,
+
+    /// Ellipsis form to enforce: `character`, `dots`, or `ignore`
+    #[serde(default)]
+    pub ellipsis: EllipsisStyle,
+
+    /// Dash form to enforce: `typographic`, `ascii`, or `ignore`
+    #[serde(default)]
+    pub dashes: DashStyle,
+
+    /// Language whose curly quotes are used, as a language tag (e.g. `en`, `de-CH`)
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+fn default_locale() -> String {
+    "en".to_string()
+}
+
+impl Default for MD098Config {
+    fn default() -> Self {
+        Self {
+            quotes: QuoteStyle::default(),
+            ellipsis: EllipsisStyle::default(),
+            dashes: DashStyle::default(),
+            locale: default_locale(),
+        }
+    }
+}
+
+impl RuleConfig for MD098Config {
+    const RULE_NAME: &'static str = "MD098";
+}
@@ -0,0 +1,399 @@
+//! Rule MD099: List items should be punctuated consistently.
+//!
+//! An opt-in rule that checks whether sibling list items end with a period.
+//! By default each list follows the style of most of its items, so a list of
+//! fragments and a list of sentences are both fine, but a list that mixes the
+//! two is flagged. The style can also be fixed to `period` or `no-period`.
+//!
+//! Only the item's first paragraph is read. Items ending with `!`, `?`, `:`,
+//! an ellipsis or markup other than inline formatting are left alone.
+//!
+//! See [docs/md099.md](../../docs/md099.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use std::collections::HashSet;
+use std::ops::Range;
+
+mod md099_config;
+pub use md099_config::ListPunctuationStyle;
+pub(super) use md099_config::MD099Config;
+
+/// Characters that may follow the final punctuation of an item
+const CLOSERS: &[char] = &['*', '_', '~', '"', '\'', '\u{201D}', '\u{2019}', ')', ']'];
+
+/// Words that keep their period even when a list drops it
+const ABBREVIATIONS: &[&str] = &[
+    "al", "approx", "co", "corp", "dept", "etc", "inc", "jr", "ltd", "misc", "no", "sr", "vs",
+];
+
+/// How an item's text ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ending {
+    Period,
+    Bare,
+}
+
+/// The end of a list item's first paragraph
+#[derive(Debug, Clone)]
+struct ItemEnd {
+    ending: Ending,
+    line: usize,
+    column: usize,
+    /// The period for `Period`, or the empty insertion point for `Bare`
+    range: Range<usize>,
+    /// Whether the period can be added or removed without changing meaning
+    fixable: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD099ListItemPunctuation {
+    config: MD099Config,
+}
+
+impl MD099ListItemPunctuation {
+    pub fn new(style: ListPunctuationStyle) -> Self {
+        Self {
+            config: MD099Config { style },
+        }
+    }
+
+    pub fn from_config_struct(config: MD099Config) -> Self {
+        Self { config }
+    }
+
+    /// Items of each list, grouped by parent so that only siblings are compared
+    fn sibling_groups(ctx: &LintContext) -> Vec<Vec<Option<ItemEnd>>> {
+        let mut groups = Vec::new();
+        let mut seen = HashSet::new();
+
+        for block in &ctx.list_blocks {
+            let mut stack: Vec<(usize, Vec<Option<ItemEnd>>)> = Vec::new();
+            for (i, &start) in block.item_lines.iter().enumerate() {
+                let Some(column) = ctx
+                    .line_info(start)
+                    .and_then(|info| info.list_item.as_ref())
+                    .map(|item| item.marker_column)
+                else {
+                    continue;
+                };
+                if !seen.insert(start) {
+                    continue;
+                }
+                let limit = block.item_lines.get(i + 1).copied().unwrap_or(block.end_line + 1);
+                let end = Self::item_end(ctx, start, limit);
+
+                while stack.last().is_some_and(|(col, _)| *col > column) {
+                    groups.extend(stack.pop().map(|(_, items)| items));
+                }
+                match stack.last_mut() {
+                    Some((col, items)) if *col == column => items.push(end),
+                    _ => stack.push((column, vec![end])),
+                }
+            }
+            groups.extend(stack.into_iter().rev().map(|(_, items)| items));
+        }
+
+        groups
+    }
+
+    /// How the first paragraph of the item starting on `start` ends; lines from
+    /// `limit` on belong to the next item
+    fn item_end(ctx: &LintContext, start: usize, limit: usize) -> Option<ItemEnd> {
+        let info = ctx.line_info(start)?;
+        if info.in_code_block || info.in_html_block || info.heading.is_some() {
+            return None;
+        }
+        let item = info.list_item.as_ref()?;
+        let line = info.content(ctx.content);
+        let text = line.get(item.content_column..).unwrap_or("");
+        let first = strip_task_marker(text).trim();
+        if first.is_empty()
+            || first.starts_with(['|', '<', '>'])
+            || ["```", "~~~", "$$", "# ", "## ", "### "]
+                .iter()
+                .any(|prefix| first.starts_with(prefix))
+        {
+            return None;
+        }
+
+        let mut last = start;
+        while last + 1 < limit {
+            let Some(next) = ctx.line_info(last + 1) else { break };
+            let content = next.content(ctx.content).trim_start();
+            if next.is_blank
+                || next.list_item.is_some()
+                || next.in_code_block
+                || next.in_html_block
+                || content.starts_with(['|', '<'])
+                || content.starts_with("```")
+                || content.starts_with("~~~")
+            {
+                break;
+            }
+            last += 1;
+        }
+
+        let info = ctx.line_info(last)?;
+        let line = info.content(ctx.content);
+        let text_start = if last == start {
+            item.content_column
+        } else {
+            line.len() - line.trim_start_matches([' ', '\t', '>']).len()
+        };
+        let text = line.get(text_start..)?.trim_end();
+        if text.ends_with('\\') {
+            return None;
+        }
+
+        let core = text.trim_end_matches(CLOSERS);
+        let (ending, offset, fixable) = match core.chars().last()? {
+            '.' if core.ends_with("..") => return None,
+            '.' => (
+                Ending::Period,
+                text_start + core.len() - 1,
+                is_removable(&core[..core.len() - 1]),
+            ),
+            c if c.is_alphanumeric() || c == '`' => {
+                let last_word = text.rsplit(char::is_whitespace).next().unwrap_or("");
+                (Ending::Bare, text_start + text.len(), !last_word.contains("://"))
+            }
+            _ => return None,
+        };
+
+        let byte = info.byte_offset + offset;
+        let range = match ending {
+            Ending::Period => byte..byte + 1,
+            Ending::Bare => byte..byte,
+        };
+        Some(ItemEnd {
+            ending,
+            line: last,
+            column: line[..offset].chars().count() + 1,
+            range,
+            fixable,
+        })
+    }
+
+    /// The ending a group of siblings should use, if it has enough items to tell
+    fn target(&self, items: &[&ItemEnd]) -> Option<Ending> {
+        match self.config.style {
+            ListPunctuationStyle::Period => Some(Ending::Period),
+            ListPunctuationStyle::NoPeriod => Some(Ending::Bare),
+            ListPunctuationStyle::Consistent => {
+                if items.len() < 2 {
+                    return None;
+                }
+                let periods = items.iter().filter(|item| item.ending == Ending::Period).count();
+                let bare = items.len() - periods;
+                Some(match periods.cmp(&bare) {
+                    std::cmp::Ordering::Greater => Ending::Period,
+                    std::cmp::Ordering::Less => Ending::Bare,
+                    std::cmp::Ordering::Equal => items[0].ending,
+                })
+            }
+        }
+    }
+
+    fn message(&self, target: Ending) -> String {
+        let base = match target {
+            Ending::Period => "List item should end with a period",
+            Ending::Bare => "List item should not end with a period",
+        };
+        if self.config.style == ListPunctuationStyle::Consistent {
+            format!("{base} to match its siblings")
+        } else {
+            base.to_string()
+        }
+    }
+}
+
+/// Text after a `[ ]` or `[x]` task list marker
+fn strip_task_marker(text: &str) -> &str {
+    ["[ ] ", "[x] ", "[X] "]
+        .iter()
+        .find_map(|marker| text.strip_prefix(marker))
+        .unwrap_or(text)
+}
+
+/// Whether the final period after `body` only ends the sentence, so removing
+/// it loses nothing: not after an abbreviation and not in a multi-sentence item
+fn is_removable(body: &str) -> bool {
+    if [". ", "! ", "? "].iter().any(|end| body.contains(end)) {
+        return false;
+    }
+    let last_word = body
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or("")
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    !last_word.contains('.') && !ABBREVIATIONS.contains(&last_word.to_lowercase().as_str())
+}
+
+impl Rule for MD099ListItemPunctuation {
+    fn name(&self) -> &'static str {
+        "MD099"
+    }
+
+    fn description(&self) -> &'static str {
+        "List items should be punctuated consistently"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        for group in Self::sibling_groups(ctx) {
+            let items: Vec<&ItemEnd> = group.iter().flatten().collect();
+            let Some(target) = self.target(&items) else {
+                continue;
+            };
+            for item in items.into_iter().filter(|item| item.ending != target) {
+                let (end_column, replacement) = match target {
+                    Ending::Period => (item.column, "."),
+                    Ending::Bare => (item.column + 1, ""),
+                };
+                warnings.push(LintWarning {
+                    message: self.message(target),
+                    line: item.line,
+                    column: item.column,
+                    end_line: item.line,
+                    end_column,
+                    severity: Severity::Warning,
+                    fix: item
+                        .fixable
+                        .then(|| Fix::new(item.range.clone(), replacement.to_string())),
+                    rule_name: Some(self.name().to_string()),
+                });
+            }
+        }
+
+        warnings.sort_by_key(|warning| (warning.line, warning.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.list_blocks.is_empty()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD099Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD099ListItemPunctuation, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fixed(rule: &MD099ListItemPunctuation, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_lists_pass() {
+        let rule = MD099ListItemPunctuation::default();
+        assert!(check(&rule, "- Apples\n- Pears\n- Plums\n").is_empty());
+        assert!(check(&rule, "1. Install it.\n2. Run it.\n").is_empty());
+        assert!(check(&rule, "- Single item.\n").is_empty());
+    }
+
+    #[test]
+    fn test_minority_is_flagged_and_fixed() {
+        let rule = MD099ListItemPunctuation::default();
+        let content = "- Apples\n- Pears.\n- Plums\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (2, 8));
+        assert_eq!(
+            warnings[0].message,
+            "List item should not end with a period to match its siblings"
+        );
+        assert_eq!(fixed(&rule, content), "- Apples\n- Pears\n- Plums\n");
+
+        let content = "- Read the docs.\n- Run the **tests**\n- Ship it.\n";
+        assert_eq!(
+            fixed(&rule, content),
+            "- Read the docs.\n- Run the **tests**.\n- Ship it.\n"
+        );
+    }
+
+    #[test]
+    fn test_tie_follows_first_item() {
+        let rule = MD099ListItemPunctuation::default();
+        let warnings = check(&rule, "- One.\n- Two\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+    }
+
+    #[test]
+    fn test_siblings_are_grouped_by_parent() {
+        let rule = MD099ListItemPunctuation::default();
+        let content = "- First item.\n  - a\n  - b\n- Second item.\n  - c\n  - d.\n  - e\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 6);
+    }
+
+    #[test]
+    fn test_last_line_of_first_paragraph() {
+        let rule = MD099ListItemPunctuation::default();
+        let content = "- First item\n  wraps here.\n\n  More text\n- Second item.\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_explicit_styles() {
+        let period = MD099ListItemPunctuation::new(ListPunctuationStyle::Period);
+        assert_eq!(fixed(&period, "- One\n- `two`\n"), "- One.\n- `two`.\n");
+
+        let no_period = MD099ListItemPunctuation::new(ListPunctuationStyle::NoPeriod);
+        assert_eq!(fixed(&no_period, "- One.\n- *Two.*\n"), "- One\n- *Two*\n");
+    }
+
+    #[test]
+    fn test_ambiguous_endings_are_not_fixed() {
+        let rule = MD099ListItemPunctuation::new(ListPunctuationStyle::NoPeriod);
+        let content = "- Fruit, etc.\n- Use it. Then stop.\n- See e.g.\n";
+        assert_eq!(check(&rule, content).len(), 3);
+        assert_eq!(fixed(&rule, content), content);
+
+        let rule = MD099ListItemPunctuation::new(ListPunctuationStyle::Period);
+        assert!(check(&rule, "- https://example.com\n")[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_neutral_endings_are_ignored() {
+        let rule = MD099ListItemPunctuation::new(ListPunctuationStyle::Period);
+        let content = "- Why?\n- Stop!\n- Wait...\n- Steps:\n- Line break\\\n- <br>\n-\n- ```\n  code\n  ```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Whether list items end with a period (MD099)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListPunctuationStyle {
+    /// Items follow the majority of their siblings
+    #[default]
+    Consistent,
+    /// Every item ends with a period
+    Period,
+    /// No item ends with a period
+    NoPeriod,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD099Config {
+    /// Punctuation to enforce: `consistent`, `period`, or `no-period`
+    #[serde(default)]
+    pub style: ListPunctuationStyle,
+}
+
+impl RuleConfig for MD099Config {
+    const RULE_NAME: &'static str = "MD099";
+}
@@ -0,0 +1,364 @@
+//! Rule MD100: Task list checkboxes should be written consistently.
+//!
+//! An opt-in rule for GitHub-style task lists. Every checkbox is written as
+//! `[ ]` or `[x]`, followed by a single space, and completed tasks all use
+//! the same letter. Checkboxes outside a list item, which render as literal
+//! brackets, are reported too. Setting `allowed = false`, usually in an
+//! `[overrides]` entry, forbids task lists in matching files.
+//!
+//! See [docs/md100.md](../../docs/md100.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::blockquote::strip_blockquote_prefix;
+
+mod md100_config;
+pub use md100_config::CheckedMarkerStyle;
+pub(super) use md100_config::MD100Config;
+
+/// Longest text accepted between the brackets of a malformed checkbox
+const MAX_INNER_LEN: usize = 4;
+
+/// A checkbox at the start of a list item's content
+#[derive(Debug, Clone)]
+struct Checkbox<'a> {
+    /// Text between the brackets
+    inner: &'a str,
+    /// Whitespace between `]` and the task text
+    gap: &'a str,
+    /// Whether the item has text after the checkbox
+    has_text: bool,
+}
+
+impl Checkbox<'_> {
+    /// The `x` or `X` of a completed task
+    fn checked_marker(&self) -> Option<char> {
+        self.inner.trim().chars().next()
+    }
+
+    /// Whether the brackets hold exactly one space or one letter
+    fn is_well_formed(&self) -> bool {
+        matches!(self.inner, " " | "x" | "X")
+    }
+
+    /// Byte length of the `[...]`
+    fn len(&self) -> usize {
+        self.inner.len() + 2
+    }
+}
+
+/// Parse a checkbox at the start of `text`
+///
+/// A well-formed box is also recognized when the text follows it without a
+/// space, unless it starts a link (`[x](...)`, `[x][...]`) or a reference
+/// definition (`[x]: ...`). Malformed boxes such as `[]` or `[ x]` must be
+/// followed by whitespace so that ordinary bracketed text is left alone.
+fn parse_checkbox(text: &str) -> Option<Checkbox<'_>> {
+    let rest = text.strip_prefix('[')?;
+    let close = rest.find(']').filter(|&close| close <= MAX_INNER_LEN)?;
+    let inner = &rest[..close];
+    let letters = inner.chars().filter(|c| !matches!(c, ' ' | '\t')).count();
+    if letters > 1 || inner.chars().any(|c| !matches!(c, ' ' | '\t' | 'x' | 'X')) {
+        return None;
+    }
+
+    let after = &rest[close + 1..];
+    let body = after.trim_start_matches([' ', '\t']);
+    let gap = &after[..after.len() - body.len()];
+    let checkbox = Checkbox {
+        inner,
+        gap,
+        has_text: !body.trim_end().is_empty(),
+    };
+    if !checkbox.has_text {
+        return None;
+    }
+    if gap.is_empty() && (!checkbox.is_well_formed() || body.starts_with(['(', '[', ':'])) {
+        return None;
+    }
+    Some(checkbox)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD100TaskListStyle {
+    config: MD100Config,
+}
+
+impl MD100TaskListStyle {
+    pub fn new(checked_marker: CheckedMarkerStyle, allowed: bool) -> Self {
+        Self {
+            config: MD100Config {
+                checked_marker,
+                allowed,
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD100Config) -> Self {
+        Self { config }
+    }
+
+    /// Whether the line is prose that may hold a checkbox
+    fn is_checkable(ctx: &LintContext, line_num: usize) -> bool {
+        let line = &ctx.lines[line_num];
+        !(line.in_code_block
+            || line.in_front_matter
+            || line.in_html_block
+            || line.in_html_comment
+            || line.in_mdx_comment
+            || line.in_math_block
+            || line.in_esm_block
+            || line.in_jsx_block)
+    }
+
+    /// Checkboxes of all task items: line number, byte offset of `[`, checkbox
+    fn task_items<'a>(ctx: &'a LintContext) -> Vec<(usize, usize, Checkbox<'a>)> {
+        let mut items = Vec::new();
+        for (line_num, line) in ctx.lines.iter().enumerate() {
+            let Some(item) = &line.list_item else {
+                continue;
+            };
+            if !Self::is_checkable(ctx, line_num) {
+                continue;
+            }
+            let content = line.content(ctx.content);
+            let Some(text) = content.get(item.content_column..) else {
+                continue;
+            };
+            if let Some(checkbox) = parse_checkbox(text) {
+                items.push((line_num, line.byte_offset + item.content_column, checkbox));
+            }
+        }
+        items
+    }
+
+    /// The letter completed tasks should use
+    fn target_marker(&self, items: &[(usize, usize, Checkbox)]) -> char {
+        match self.config.checked_marker {
+            CheckedMarkerStyle::Lowercase => 'x',
+            CheckedMarkerStyle::Uppercase => 'X',
+            CheckedMarkerStyle::Consistent => items
+                .iter()
+                .find_map(|(_, _, checkbox)| checkbox.checked_marker())
+                .unwrap_or('x'),
+        }
+    }
+
+    fn warning(
+        &self,
+        ctx: &LintContext,
+        line_num: usize,
+        start: usize,
+        message: String,
+        fix: Option<Fix>,
+    ) -> LintWarning {
+        let line_start = ctx.lines[line_num].byte_offset;
+        let column = ctx.content[line_start..start].chars().count() + 1;
+        let end_column = fix.as_ref().map_or(column + 3, |fix| {
+            column + ctx.content[fix.range.clone()].chars().count()
+        });
+        LintWarning {
+            message,
+            line: line_num + 1,
+            column,
+            end_line: line_num + 1,
+            end_column,
+            severity: Severity::Warning,
+            fix,
+            rule_name: Some(self.name().to_string()),
+        }
+    }
+}
+
+impl Rule for MD100TaskListStyle {
+    fn name(&self) -> &'static str {
+        "MD100"
+    }
+
+    fn description(&self) -> &'static str {
+        "Task list checkboxes should be consistent"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let items = Self::task_items(ctx);
+
+        if self.config.allowed {
+            let target = self.target_marker(&items);
+            for (line_num, start, checkbox) in &items {
+                let expected_inner = if checkbox.checked_marker().is_some() {
+                    target.to_string()
+                } else {
+                    " ".to_string()
+                };
+                let message = if !checkbox.is_well_formed() {
+                    format!("Task list checkbox should be written as '[{expected_inner}]'")
+                } else if checkbox.inner != expected_inner {
+                    format!("Completed task should be marked with '[{target}]'")
+                } else if checkbox.gap != " " {
+                    "Task list checkbox should be followed by a single space".to_string()
+                } else {
+                    continue;
+                };
+                let range = *start..start + checkbox.len() + checkbox.gap.len();
+                let fix = Fix::new(range, format!("[{expected_inner}] "));
+                warnings.push(self.warning(ctx, *line_num, *start, message, Some(fix)));
+            }
+        } else {
+            for (line_num, start, _) in &items {
+                let message = "Task lists are not allowed here".to_string();
+                warnings.push(self.warning(ctx, *line_num, *start, message, None));
+            }
+        }
+
+        for (line_num, line) in ctx.lines.iter().enumerate() {
+            if line.list_item.is_some() || line.is_blank || !Self::is_checkable(ctx, line_num) {
+                continue;
+            }
+            let content = line.content(ctx.content);
+            let text = strip_blockquote_prefix(content).trim_start();
+            if parse_checkbox(text).is_some_and(|checkbox| checkbox.is_well_formed() && !checkbox.gap.is_empty()) {
+                let start = line.byte_offset + (content.len() - text.len());
+                let message = "Task list checkbox should start a list item".to_string();
+                warnings.push(self.warning(ctx, line_num, start, message, None));
+            }
+        }
+
+        warnings.sort_by_key(|warning| (warning.line, warning.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains('[')
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD100Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD100TaskListStyle, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fixed(rule: &MD100TaskListStyle, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_task_list_passes() {
+        let rule = MD100TaskListStyle::default();
+        assert!(check(&rule, "- [ ] Write docs\n- [x] Write code\n").is_empty());
+        assert!(check(&rule, "1. [X] First\n2. [X] Second\n").is_empty());
+        assert!(check(&rule, "> - [ ] Quoted task\n").is_empty());
+    }
+
+    #[test]
+    fn test_malformed_checkboxes_are_normalized() {
+        let rule = MD100TaskListStyle::default();
+        let content = "- [] Empty\n- [  ] Wide\n- [ x] Offset\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 3));
+        assert_eq!(warnings[0].message, "Task list checkbox should be written as '[ ]'");
+        assert_eq!(warnings[2].message, "Task list checkbox should be written as '[x]'");
+        assert_eq!(fixed(&rule, content), "- [ ] Empty\n- [ ] Wide\n- [x] Offset\n");
+    }
+
+    #[test]
+    fn test_spacing_after_checkbox() {
+        let rule = MD100TaskListStyle::default();
+        let content = "- [ ]  Two spaces\n- [x]\tTab\n- [ ]Missing\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 3);
+        assert!(
+            warnings
+                .iter()
+                .all(|w| w.message == "Task list checkbox should be followed by a single space")
+        );
+        assert_eq!(fixed(&rule, content), "- [ ] Two spaces\n- [x] Tab\n- [ ] Missing\n");
+    }
+
+    #[test]
+    fn test_checked_marker_follows_first_task() {
+        let rule = MD100TaskListStyle::default();
+        let content = "- [X] Done\n- [x] Also done\n- [ ] Open\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+        assert_eq!(warnings[0].message, "Completed task should be marked with '[X]'");
+        assert_eq!(fixed(&rule, content), "- [X] Done\n- [X] Also done\n- [ ] Open\n");
+    }
+
+    #[test]
+    fn test_checked_marker_style_can_be_fixed() {
+        let rule = MD100TaskListStyle::new(CheckedMarkerStyle::Lowercase, true);
+        assert_eq!(
+            fixed(&rule, "- [X] Done\n- [X] Done too\n"),
+            "- [x] Done\n- [x] Done too\n"
+        );
+        let rule = MD100TaskListStyle::new(CheckedMarkerStyle::Uppercase, true);
+        assert_eq!(fixed(&rule, "- [x] Done\n"), "- [X] Done\n");
+    }
+
+    #[test]
+    fn test_links_and_plain_brackets_are_ignored() {
+        let rule = MD100TaskListStyle::default();
+        assert!(check(&rule, "- [x](https://example.com) link\n").is_empty());
+        assert!(check(&rule, "- [x][ref] reference\n\n[ref]: https://example.com\n").is_empty());
+        assert!(check(&rule, "- [note] Bracketed text\n- []\n").is_empty());
+        assert!(check(&rule, "```\n- [X] Done\n- [ ]  Open\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_checkbox_outside_list_item() {
+        let rule = MD100TaskListStyle::default();
+        let content = "[ ] Not a task\n\n> [x] Quoted\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Task list checkbox should start a list item");
+        assert_eq!((warnings[1].line, warnings[1].column), (3, 3));
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert_eq!(fixed(&rule, content), content);
+    }
+
+    #[test]
+    fn test_task_lists_can_be_forbidden() {
+        let rule = MD100TaskListStyle::new(CheckedMarkerStyle::Consistent, false);
+        let content = "- [ ] Open\n- [x] Done\n- Plain item\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Task lists are not allowed here");
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+    }
+}
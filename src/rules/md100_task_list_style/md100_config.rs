@@ -0,0 +1,45 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Letter used to mark a completed task (MD100)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckedMarkerStyle {
+    /// Follow the first checked task in the document
+    #[default]
+    Consistent,
+    /// Completed tasks use `[x]`
+    Lowercase,
+    /// Completed tasks use `[X]`
+    Uppercase,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD100Config {
+    /// Marker for completed tasks: `consistent`, `lowercase`, or `uppercase`
+    #[serde(default)]
+    pub checked_marker: CheckedMarkerStyle,
+
+    /// Whether task lists may be used at all; set to `false` in an override
+    /// to keep them out of a directory
+    #[serde(default = "default_allowed")]
+    pub allowed: bool,
+}
+
+fn default_allowed() -> bool {
+    true
+}
+
+impl Default for MD100Config {
+    fn default() -> Self {
+        Self {
+            checked_marker: CheckedMarkerStyle::default(),
+            allowed: default_allowed(),
+        }
+    }
+}
+
+impl RuleConfig for MD100Config {
+    const RULE_NAME: &'static str = "MD100";
+}
@@ -0,0 +1,334 @@
+//! Rule MD101: Heading anchors recorded in the anchor manifest should not change.
+//!
+//! An opt-in rule for documentation that others deep-link to. `rumdl anchors
+//! --write` records every heading's anchor in a manifest committed with the
+//! docs; this rule then reports each recorded anchor that a file no longer
+//! provides. When a heading was reworded, the warning points at it and the
+//! fix pins the old anchor with an explicit `{#custom-id}`.
+//!
+//! See [docs/md101.md](../../docs/md101.md) for full documentation, configuration, and examples.
+
+use crate::anchor_manifest::{AnchorManifest, HeadingAnchor, heading_anchors};
+use crate::config::MarkdownFlavor;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::project_root::discover_project_root_from;
+use crate::utils::slug::Slugify;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+
+mod md101_config;
+pub(super) use md101_config::MD101Config;
+
+/// `id` or `name` attribute of an HTML element, which keeps an anchor alive
+static HTML_ANCHOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<[A-Za-z][^>]*?\s(?:id|name)\s*=\s*["']([^"']+)["']"#).unwrap());
+
+/// Loaded manifests by path, so each is read once per run
+type ManifestCache = Arc<Mutex<HashMap<PathBuf, Result<Option<Arc<AnchorManifest>>, String>>>>;
+
+#[derive(Debug, Clone, Default)]
+pub struct MD101StableAnchors {
+    config: MD101Config,
+    slugify: Slugify,
+    /// Root the manifest path resolves against; discovered from the linted
+    /// file when unset
+    project_root: Option<PathBuf>,
+    manifests: ManifestCache,
+}
+
+impl MD101StableAnchors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD101Config) -> Self {
+        Self {
+            slugify: config.anchor_style.clone().into(),
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// The rule as configured in `config`, for callers that need more than a
+    /// boxed [`Rule`]
+    pub fn for_config(config: &crate::config::Config) -> Self {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD101Config>(config);
+        Self {
+            slugify: Slugify::for_rule(config, "MD101", &rule_config.anchor_style),
+            project_root: config.project_root.clone(),
+            ..Self::from_config_struct(rule_config)
+        }
+    }
+
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(project_root);
+        self
+    }
+
+    /// Location of the anchor manifest for a project
+    pub fn manifest_path(&self, project_root: &Path) -> PathBuf {
+        project_root.join(&self.config.manifest)
+    }
+
+    /// Anchors of every heading in `ctx`, as recorded in the manifest
+    pub fn anchors(&self, ctx: &LintContext) -> Vec<HeadingAnchor> {
+        heading_anchors(ctx, &self.slugify)
+    }
+
+    fn project_root_for(&self, file: &Path) -> PathBuf {
+        let root = match &self.project_root {
+            Some(root) => root.clone(),
+            None => discover_project_root_from(file.parent().unwrap_or(Path::new("."))),
+        };
+        root.canonicalize().unwrap_or(root)
+    }
+
+    fn manifest(&self, project_root: &Path) -> Result<Option<Arc<AnchorManifest>>, LintError> {
+        let path = self.manifest_path(project_root);
+        let mut cache = self.manifests.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        cache
+            .entry(path.clone())
+            .or_insert_with(|| match AnchorManifest::load(&path) {
+                Ok(manifest) => Ok(manifest.map(Arc::new)),
+                Err(e) => Err(format!("invalid anchor manifest {}: {e}", path.display())),
+            })
+            .clone()
+            .map_err(LintError::ParsingError)
+    }
+
+    /// The fix pinning `old` on a heading, inserted after its text
+    fn pin_fix(ctx: &LintContext, heading: &HeadingAnchor, old: &str) -> Option<Fix> {
+        let line = &ctx.lines[heading.line_index];
+        let info = line.heading.as_ref()?;
+        let start = line.byte_offset + info.content_column;
+        if info.raw_text(ctx.content).is_empty() || !ctx.content.get(start..)?.starts_with(info.raw_text(ctx.content)) {
+            return None;
+        }
+        let end = start + info.raw_text(ctx.content).len();
+        let replacement = format!(" {{#{old}}}");
+        Some(if supports_heading_ids(ctx.flavor) {
+            Fix::new(end..end, replacement)
+        } else {
+            Fix::suggestion(end..end, replacement)
+        })
+    }
+}
+
+/// Flavors whose renderers honor `{#custom-id}` on headings
+fn supports_heading_ids(flavor: MarkdownFlavor) -> bool {
+    flavor.supports_attr_lists() || matches!(flavor, MarkdownFlavor::Pandoc | MarkdownFlavor::Quarto)
+}
+
+/// Anchors defined by HTML `id` and `name` attributes outside code
+fn html_anchors(ctx: &LintContext) -> HashSet<String> {
+    ctx.lines
+        .iter()
+        .filter(|line| !line.in_code_block && !line.in_front_matter)
+        .flat_map(|line| HTML_ANCHOR_REGEX.captures_iter(line.content(ctx.content)))
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+impl Rule for MD101StableAnchors {
+    fn name(&self) -> &'static str {
+        "MD101"
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading anchors recorded in the anchor manifest should not change"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let Some(file) = ctx.source_file.as_deref() else {
+            return Ok(Vec::new());
+        };
+        let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        let root = self.project_root_for(&file);
+        let Some(manifest) = self.manifest(&root)? else {
+            return Ok(Vec::new());
+        };
+        let Some(recorded) = file.strip_prefix(&root).ok().and_then(|path| manifest.anchors(path)) else {
+            return Ok(Vec::new());
+        };
+
+        let headings = self.anchors(ctx);
+        let mut present = html_anchors(ctx);
+        present.extend(headings.iter().map(|heading| heading.anchor.clone()));
+        let mut missing = recorded.iter().filter(|anchor| !present.contains(*anchor));
+
+        // Headings with an anchor the manifest does not know were most likely
+        // reworded from the missing ones, in document order
+        let renamed = headings
+            .iter()
+            .filter(|heading| !heading.is_custom && !recorded.contains(&heading.anchor));
+
+        let mut warnings = Vec::new();
+        for heading in renamed {
+            let Some(old) = missing.next() else {
+                break;
+            };
+            let line = &ctx.lines[heading.line_index];
+            let content = line.content(ctx.content);
+            let content_column = line.heading.as_ref().map_or(0, |info| info.content_column);
+            let column = content.get(..content_column).map_or(0, |s| s.chars().count()) + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: heading.line_index + 1,
+                column,
+                end_line: heading.line_index + 1,
+                end_column: content.chars().count() + 1,
+                message: format!(
+                    "Heading anchor changed from '#{old}' to '#{}'; add '{{#{old}}}' to keep existing links working",
+                    heading.anchor
+                ),
+                severity: Severity::Warning,
+                fix: Self::pin_fix(ctx, heading, old),
+            });
+        }
+        for old in missing {
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: 1,
+                column: 1,
+                end_line: 1,
+                end_column: 1,
+                message: format!("Anchor '#{old}' recorded in the anchor manifest no longer exists"),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        warnings.sort_by_key(|warning| (warning.line, warning.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD101Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(Self::for_config(config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    /// Record `recorded` as the anchors of `guide.md`, then check `content` as
+    /// that file
+    fn check_with(flavor: MarkdownFlavor, recorded: &[&str], content: &str) -> (Vec<LintWarning>, String) {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let mut manifest = AnchorManifest::new();
+        manifest.insert(
+            Path::new("guide.md"),
+            recorded.iter().map(ToString::to_string).collect(),
+        );
+        manifest.save(&root.join(".rumdl-anchors.json")).unwrap();
+        let file = root.join("guide.md");
+        fs::write(&file, content).unwrap();
+
+        let rule = MD101StableAnchors::new().with_project_root(root);
+        let ctx = LintContext::new(content, flavor, Some(file));
+        let warnings = rule.check(&ctx).unwrap();
+        let fixed = rule.fix(&ctx).unwrap();
+        (warnings, fixed)
+    }
+
+    fn check(recorded: &[&str], content: &str) -> (Vec<LintWarning>, String) {
+        check_with(MarkdownFlavor::MkDocs, recorded, content)
+    }
+
+    #[test]
+    fn test_unchanged_anchors_pass() {
+        let (warnings, _) = check(&["guide", "setup"], "# Guide\n\n## Setup\n\n## New section\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_reworded_heading_is_pinned_to_its_old_anchor() {
+        let (warnings, fixed) = check(&["guide", "setup"], "# Guide\n\n## Getting set up\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 4));
+        assert_eq!(
+            warnings[0].message,
+            "Heading anchor changed from '#setup' to '#getting-set-up'; add '{#setup}' to keep existing links working"
+        );
+        assert_eq!(fixed, "# Guide\n\n## Getting set up {#setup}\n");
+    }
+
+    #[test]
+    fn test_closing_sequence_and_setext_headings() {
+        let (_, fixed) = check(&["guide", "setup"], "Guide\n=====\n\n## Installing ##\n");
+        assert_eq!(fixed, "Guide\n=====\n\n## Installing {#setup} ##\n");
+        let (_, fixed) = check(&["guide"], "Handbook\n========\n");
+        assert_eq!(fixed, "Handbook {#guide}\n========\n");
+    }
+
+    #[test]
+    fn test_custom_ids_and_html_anchors_keep_anchors_alive() {
+        let content = "# Guide\n\n## Getting set up {#setup}\n\n<a id=\"usage\"></a>\n\n## How to use it\n";
+        let (warnings, _) = check(&["guide", "setup", "usage"], content);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_removed_heading_is_reported_without_fix() {
+        let (warnings, fixed) = check(&["guide", "setup"], "# Guide\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(
+            warnings[0].message,
+            "Anchor '#setup' recorded in the anchor manifest no longer exists"
+        );
+        assert_eq!(fixed, "# Guide\n");
+    }
+
+    #[test]
+    fn test_fix_is_only_suggested_where_custom_ids_do_not_render() {
+        let (warnings, fixed) = check_with(MarkdownFlavor::Standard, &["setup"], "## Installing\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].fix.as_ref().is_some_and(|fix| !fix.is_automatic()));
+        assert_eq!(fixed, "## Installing\n");
+    }
+
+    #[test]
+    fn test_files_outside_the_manifest_are_skipped() {
+        let rule = MD101StableAnchors::new();
+        let ctx = LintContext::new("## Anything\n", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        let (warnings, _) = check(&[], "## Anything\n");
+        assert!(warnings.is_empty());
+    }
+}
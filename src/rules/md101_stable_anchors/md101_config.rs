@@ -0,0 +1,35 @@
+use crate::anchor_manifest::DEFAULT_MANIFEST;
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD101 (stable heading anchors)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD101Config {
+    /// Anchor manifest written by `rumdl anchors --write`, relative to the
+    /// project root
+    #[serde(default = "default_manifest")]
+    pub manifest: String,
+
+    /// Anchor generation style used to compute heading anchors
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+}
+
+fn default_manifest() -> String {
+    DEFAULT_MANIFEST.to_string()
+}
+
+impl Default for MD101Config {
+    fn default() -> Self {
+        Self {
+            manifest: default_manifest(),
+            anchor_style: AnchorStyle::default(),
+        }
+    }
+}
+
+impl RuleConfig for MD101Config {
+    const RULE_NAME: &'static str = "MD101";
+}
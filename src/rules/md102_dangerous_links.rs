@@ -0,0 +1,343 @@
+//! Rule MD102: Links should not use protocols that run script.
+//!
+//! Documentation rendered to HTML turns `javascript:` and `vbscript:` links
+//! into script, and `data:` URLs can carry whole HTML documents. This rule
+//! reports such URLs in Markdown links, images and reference definitions, in
+//! the URL attributes of inline HTML, and `on*` event handler attributes in
+//! inline HTML. There is no fix: removing a link changes the text, so the
+//! author has to decide what it should be.
+//!
+//! See [docs/md102.md](../../docs/md102.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::html_utils::{URL_ATTRIBUTES, decode_char_references, parse_attributes};
+
+mod md102_config;
+pub(super) use md102_config::MD102Config;
+
+#[derive(Debug, Clone)]
+pub struct MD102DangerousLinks {
+    config: MD102Config,
+}
+
+impl Default for MD102DangerousLinks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD102DangerousLinks {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD102Config::default())
+    }
+
+    pub fn from_config_struct(config: MD102Config) -> Self {
+        Self { config }
+    }
+
+    /// The configured protocol `url` uses, or `None` when it is harmless or allowed.
+    ///
+    /// Browsers ignore leading whitespace and control characters and drop tabs
+    /// and newlines anywhere in a URL, so `" java\tscript:"` is still a
+    /// `javascript:` URL.
+    fn dangerous_protocol(&self, url: &str) -> Option<String> {
+        let normalized: String = url
+            .trim_start_matches(|c: char| c <= ' ')
+            .chars()
+            .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let (scheme, _) = normalized.split_once(':')?;
+        if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            || !scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        {
+            return None;
+        }
+        if !self.config.protocols.iter().any(|p| p.eq_ignore_ascii_case(scheme)) {
+            return None;
+        }
+        if self
+            .config
+            .allowed
+            .iter()
+            .any(|prefix| normalized.starts_with(&prefix.to_ascii_lowercase()))
+        {
+            return None;
+        }
+        Some(scheme.to_string())
+    }
+
+    fn is_reported_handler(&self, attr_name: &str) -> bool {
+        self.config.event_handlers
+            && attr_name.len() > 2
+            && attr_name.starts_with("on")
+            && !self
+                .config
+                .allowed_handlers
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(attr_name))
+    }
+
+    fn warning(&self, ctx: &LintContext, start: usize, end: usize, message: String) -> LintWarning {
+        let (line, column) = ctx.offset_to_line_col(start);
+        let (end_line, end_column) = ctx.offset_to_line_col(end);
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line,
+            end_column,
+            message,
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    fn check_markdown(&self, ctx: &LintContext, warnings: &mut Vec<LintWarning>) {
+        // Reference links and images are reported at their definition
+        for link in ctx.links().iter().filter(|link| !link.is_reference) {
+            if let Some(scheme) = self.dangerous_protocol(&link.url) {
+                warnings.push(self.warning(
+                    ctx,
+                    link.byte_offset,
+                    link.byte_end,
+                    format!("Link uses the '{scheme}:' protocol, which can run script when rendered"),
+                ));
+            }
+        }
+        for image in ctx.images().iter().filter(|image| !image.is_reference) {
+            if let Some(scheme) = self.dangerous_protocol(&image.url) {
+                warnings.push(self.warning(
+                    ctx,
+                    image.byte_offset,
+                    image.byte_end,
+                    format!("Image uses the '{scheme}:' protocol, which can run script when rendered"),
+                ));
+            }
+        }
+        for def in &ctx.reference_defs {
+            // Definition URLs are raw source text, so character references are still encoded
+            let Some(scheme) = self.dangerous_protocol(&decode_char_references(&def.url)) else {
+                continue;
+            };
+            let url = def.url_range(ctx.content);
+            warnings.push(self.warning(
+                ctx,
+                url.start,
+                url.end,
+                format!(
+                    "Reference definition [{}] uses the '{scheme}:' protocol, which can run script when rendered",
+                    def.id
+                ),
+            ));
+        }
+    }
+
+    fn check_html(&self, ctx: &LintContext, warnings: &mut Vec<LintWarning>) {
+        for tag in ctx.html_tags().iter() {
+            if tag.is_closing
+                || ctx.is_in_html_comment(tag.byte_offset)
+                || ctx.is_byte_offset_in_code_span(tag.byte_offset)
+            {
+                continue;
+            }
+            let source = &ctx.content[tag.byte_offset..tag.byte_end];
+            for attr in parse_attributes(source) {
+                let start = tag.byte_offset + attr.range.start;
+                let end = tag.byte_offset + attr.range.end;
+                if self.is_reported_handler(&attr.name) {
+                    warnings.push(self.warning(
+                        ctx,
+                        start,
+                        end,
+                        format!(
+                            "Event handler attribute '{}' on <{}> runs script when rendered",
+                            attr.name, tag.tag_name
+                        ),
+                    ));
+                } else if URL_ATTRIBUTES.contains(&attr.name.as_str())
+                    && let Some(value) = &attr.value
+                    && let Some(scheme) = self.dangerous_protocol(&decode_char_references(value))
+                {
+                    warnings.push(self.warning(
+                        ctx,
+                        start,
+                        end,
+                        format!(
+                            "Attribute '{}' on <{}> uses the '{scheme}:' protocol, which can run script when rendered",
+                            attr.name, tag.tag_name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl Rule for MD102DangerousLinks {
+    fn name(&self) -> &'static str {
+        "MD102"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links should not use protocols that run script"
+    }
+
+    fn category(&self) -> RuleCategory {
+        // Links and raw HTML both count, so the content prefilter must not
+        // skip documents that have only one of them
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_links_or_images() && !ctx.likely_has_html()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        self.check_markdown(ctx, &mut warnings);
+        self.check_html(ctx, &mut warnings);
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD102Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD102Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(config: MD102Config, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD102DangerousLinks::from_config_struct(config).check(&ctx).unwrap()
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        check_with(MD102Config::default(), content)
+    }
+
+    #[test]
+    fn test_safe_links() {
+        let content = "[docs](https://example.com) [local](./a.md#x) [mail](mailto:a@b.c)\n\n![logo](img/logo.png)\n\n<a href=\"/about\" title=\"javascript: a guide\">About</a>\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_markdown_links_and_images() {
+        let content =
+            "Click [here](javascript:alert(1)) or [there](VBScript:run).\n\n![x](data:text/html;base64,PHNjcmlwdD4=)\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            warnings[0].message,
+            "Link uses the 'javascript:' protocol, which can run script when rendered"
+        );
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 7));
+        assert!(warnings[1].message.contains("'vbscript:'"));
+        assert!(warnings[2].message.starts_with("Image uses the 'data:' protocol"));
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+    }
+
+    #[test]
+    fn test_reference_definitions() {
+        let content = "See [the docs][docs].\n\n[docs]: javascript&#58;alert(1)\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 9);
+        assert!(warnings[0].message.contains("Reference definition [docs]"));
+    }
+
+    #[test]
+    fn test_inline_html() {
+        let content = "<a href=\"&#x6A;avascript:go()\">x</a>\n\n<img src=\"a.png\" onerror=\"steal()\">\n\n<div\n  onClick='x()'>y</div>\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            warnings[0].message,
+            "Attribute 'href' on <a> uses the 'javascript:' protocol, which can run script when rendered"
+        );
+        assert_eq!((warnings[0].column, warnings[0].end_column), (4, 31));
+        assert_eq!(
+            warnings[1].message,
+            "Event handler attribute 'onerror' on <img> runs script when rendered"
+        );
+        assert_eq!((warnings[2].line, warnings[2].column), (6, 3));
+    }
+
+    #[test]
+    fn test_obfuscated_schemes() {
+        let warnings = check("<a href=\" java\tscript:x\">a</a> <a href=\"javascript&colon;x\">b</a>\n");
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_allowlist_and_options() {
+        let config = MD102Config {
+            allowed: vec!["data:image/".to_string()],
+            allowed_handlers: vec!["onclick".to_string()],
+            ..Default::default()
+        };
+        let content = "![dot](data:image/png;base64,iVBORw0KGgo=)\n\n<button onclick=\"track()\">x</button>\n\n[bad](data:text/html,hi)\n";
+        let warnings = check_with(config, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+
+        let config = MD102Config {
+            protocols: vec!["file".to_string()],
+            event_handlers: false,
+            ..Default::default()
+        };
+        let content = "[a](javascript:x) [b](FILE:///etc/passwd)\n\n<b onmouseover=\"x()\">x</b>\n";
+        let warnings = check_with(config, content);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("'file:'"));
+    }
+
+    #[test]
+    fn test_code_and_comments_are_ignored() {
+        let content = "```html\n<a href=\"javascript:x\" onclick=\"y()\">a</a>\n```\n\n`[a](javascript:x)`\n\n<!-- <b onclick=\"x()\">old</b> -->\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_html_in_code_span_after_reference_definition() {
+        let content = "[g]\n\n[g]: /x\n\nUse `<a onclick=x>`.\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_html_only_document() {
+        let content = "<a href=\"javascript:alert(1)\">x</a>\n\n<img src=x onerror=alert(1)>\n";
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD102DangerousLinks::default())];
+        let warnings = crate::lint(content, &rules, false, MarkdownFlavor::Standard, None, None).unwrap();
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+    }
+}
@@ -0,0 +1,37 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD102 (dangerous links)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD102Config {
+    /// URL schemes to report, without the colon; compared case-insensitively
+    pub protocols: Vec<String>,
+
+    /// URL prefixes that are always accepted, e.g. `data:image/png;base64,`
+    pub allowed: Vec<String>,
+
+    /// Report `on*` event handler attributes in inline HTML
+    pub event_handlers: bool,
+
+    /// Event handler attributes that are accepted, e.g. `onclick`
+    pub allowed_handlers: Vec<String>,
+}
+
+impl Default for MD102Config {
+    fn default() -> Self {
+        Self {
+            protocols: ["javascript", "vbscript", "data"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            allowed: Vec::new(),
+            event_handlers: true,
+            allowed_handlers: Vec::new(),
+        }
+    }
+}
+
+impl RuleConfig for MD102Config {
+    const RULE_NAME: &'static str = "MD102";
+}
@@ -0,0 +1,345 @@
+//! Rule MD103: Links should not point to internal hosts.
+//!
+//! Documentation written inside a company often links to staging servers,
+//! intranet wikis or a developer's `localhost`. Those links are dead for
+//! readers of the published docs and reveal internal infrastructure. This
+//! rule reports links, images, reference definitions and inline HTML URL
+//! attributes whose host is a private IP address, a host name without a dot,
+//! or matches one of the configured host patterns.
+//!
+//! See [docs/md103.md](../../docs/md103.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::html_utils::{URL_ATTRIBUTES, decode_char_references, parse_attributes};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::net::IpAddr;
+
+mod md103_config;
+pub(super) use md103_config::MD103Config;
+
+#[derive(Debug, Clone)]
+pub struct MD103InternalUrls {
+    config: MD103Config,
+    /// Compiled `hosts`; invalid patterns are logged and dropped
+    hosts: GlobSet,
+    /// Compiled `allowed`; invalid patterns are logged and dropped
+    allowed: GlobSet,
+}
+
+impl Default for MD103InternalUrls {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD103InternalUrls {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD103Config::default())
+    }
+
+    pub fn from_config_struct(config: MD103Config) -> Self {
+        let hosts = Self::build_globs(&config.hosts, "hosts");
+        let allowed = Self::build_globs(&config.allowed, "allowed");
+        Self { config, hosts, allowed }
+    }
+
+    fn build_globs(patterns: &[String], option: &str) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            match GlobBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(err) => log::warn!("Invalid {option} pattern for MD103 ('{pattern}'): {err}. Ignoring it."),
+            }
+        }
+        builder.build().unwrap_or_else(|_| GlobSet::empty())
+    }
+
+    /// The host of an absolute (`scheme://host`) or protocol-relative
+    /// (`//host`) URL, without user info, port or IPv6 brackets
+    fn url_host(url: &str) -> Option<&str> {
+        let url = url.trim();
+        let rest = match url.strip_prefix("//") {
+            Some(rest) => rest,
+            None => {
+                let (scheme, rest) = url.split_once("://")?;
+                if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    || !scheme
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                {
+                    return None;
+                }
+                rest
+            }
+        };
+        let authority = rest.split(['/', '?', '#']).next()?;
+        let authority = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+        let host = match authority.strip_prefix('[') {
+            Some(bracketed) => bracketed.split_once(']')?.0,
+            None => authority.split_once(':').map_or(authority, |(host, _)| host),
+        };
+        let host = host.trim_end_matches('.');
+        (!host.is_empty()).then_some(host)
+    }
+
+    fn is_internal_ip(ip: IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(v4) => {
+                let [a, b, ..] = v4.octets();
+                // 100.64.0.0/10 is carrier-grade NAT space, also used by VPN overlays
+                v4.is_loopback()
+                    || v4.is_private()
+                    || v4.is_link_local()
+                    || v4.is_unspecified()
+                    || (a == 100 && (64..128).contains(&b))
+            }
+            IpAddr::V6(v6) => {
+                v6.is_loopback()
+                    || v6.is_unspecified()
+                    || v6.is_unique_local()
+                    || v6.is_unicast_link_local()
+                    || v6
+                        .to_ipv4_mapped()
+                        .is_some_and(|v4| Self::is_internal_ip(IpAddr::V4(v4)))
+            }
+        }
+    }
+
+    /// Describe why `url` is internal, or `None` when it is public or allowed
+    fn problem(&self, url: &str) -> Option<String> {
+        let host = Self::url_host(url)?;
+        if self.allowed.is_match(host) {
+            return None;
+        }
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return (self.config.private_ips && Self::is_internal_ip(ip))
+                .then(|| format!("private IP address '{host}'"));
+        }
+        if self.hosts.is_match(host) || (self.config.single_label_hosts && !host.contains('.')) {
+            return Some(format!("internal host '{host}'"));
+        }
+        None
+    }
+
+    fn warning(&self, ctx: &LintContext, start: usize, end: usize, message: String) -> LintWarning {
+        let (line, column) = ctx.offset_to_line_col(start);
+        let (end_line, end_column) = ctx.offset_to_line_col(end);
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line,
+            end_column,
+            message,
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+}
+
+impl Rule for MD103InternalUrls {
+    fn name(&self) -> &'static str {
+        "MD103"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links should not point to internal hosts"
+    }
+
+    fn category(&self) -> RuleCategory {
+        // Links and raw HTML both count, so the content prefilter must not
+        // skip documents that have only one of them
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("//")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        // Reference links and images are reported at their definition
+        for link in ctx.links().iter().filter(|link| !link.is_reference) {
+            if let Some(problem) = self.problem(&link.url) {
+                let message = format!("Link points to {problem}");
+                warnings.push(self.warning(ctx, link.byte_offset, link.byte_end, message));
+            }
+        }
+        for image in ctx.images().iter().filter(|image| !image.is_reference) {
+            if let Some(problem) = self.problem(&image.url) {
+                let message = format!("Image points to {problem}");
+                warnings.push(self.warning(ctx, image.byte_offset, image.byte_end, message));
+            }
+        }
+        for def in &ctx.reference_defs {
+            if let Some(problem) = self.problem(&decode_char_references(&def.url)) {
+                let url = def.url_range(ctx.content);
+                let message = format!("Reference definition [{}] points to {problem}", def.id);
+                warnings.push(self.warning(ctx, url.start, url.end, message));
+            }
+        }
+
+        for tag in ctx.html_tags().iter() {
+            if tag.is_closing
+                || ctx.is_in_html_comment(tag.byte_offset)
+                || ctx.is_byte_offset_in_code_span(tag.byte_offset)
+            {
+                continue;
+            }
+            let source = &ctx.content[tag.byte_offset..tag.byte_end];
+            for attr in parse_attributes(source) {
+                if !URL_ATTRIBUTES.contains(&attr.name.as_str()) {
+                    continue;
+                }
+                if let Some(value) = &attr.value
+                    && let Some(problem) = self.problem(&decode_char_references(value))
+                {
+                    let message = format!("Attribute '{}' on <{}> points to {problem}", attr.name, tag.tag_name);
+                    let start = tag.byte_offset + attr.range.start;
+                    let end = tag.byte_offset + attr.range.end;
+                    warnings.push(self.warning(ctx, start, end, message));
+                }
+            }
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD103Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD103Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(config: MD103Config, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD103InternalUrls::from_config_struct(config).check(&ctx).unwrap()
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        check_with(MD103Config::default(), content)
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(
+            MD103InternalUrls::url_host("https://example.com/a"),
+            Some("example.com")
+        );
+        assert_eq!(
+            MD103InternalUrls::url_host("http://user:pw@10.0.0.1:8080?q"),
+            Some("10.0.0.1")
+        );
+        assert_eq!(MD103InternalUrls::url_host("//[::1]:3000/"), Some("::1"));
+        assert_eq!(MD103InternalUrls::url_host("HTTP://Wiki./x"), Some("Wiki"));
+        assert_eq!(MD103InternalUrls::url_host("mailto:a@localhost"), None);
+        assert_eq!(MD103InternalUrls::url_host("./docs/a.md"), None);
+        assert_eq!(MD103InternalUrls::url_host("file:///etc/hosts"), None);
+    }
+
+    #[test]
+    fn test_public_links() {
+        let content = "[docs](https://docs.example.com/a) [rel](../b.md) [mail](mailto:me@example.com)\n\n![x](https://8.8.8.8/logo.png)\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_private_ips() {
+        let content = "[a](http://10.1.2.3/) [b](https://192.168.0.10:8443) [c](http://[fd00::1]/)\n\n![d](http://127.0.0.1/x.png)\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(warnings[0].message, "Link points to private IP address '10.1.2.3'");
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 1));
+        assert_eq!(warnings[3].message, "Image points to private IP address '127.0.0.1'");
+
+        let config = MD103Config {
+            private_ips: false,
+            ..Default::default()
+        };
+        assert!(check_with(config, content).is_empty());
+    }
+
+    #[test]
+    fn test_internal_host_names() {
+        let content = "See [the wiki](http://wiki/Setup), [dev](http://localhost:3000) and [ci][ci].\n\n<a href=\"https://build.corp/job\">CI</a>\n\n[ci]: https://jenkins.internal/job/x\n";
+        let warnings = check(content);
+        let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Link points to internal host 'wiki'",
+                "Link points to internal host 'localhost'",
+                "Attribute 'href' on <a> points to internal host 'build.corp'",
+                "Reference definition [ci] points to internal host 'jenkins.internal'",
+            ]
+        );
+        assert_eq!((warnings[3].line, warnings[3].column), (5, 7));
+    }
+
+    #[test]
+    fn test_host_patterns_and_allowlist() {
+        let config = MD103Config {
+            hosts: vec!["*.corp.example.com".to_string()],
+            single_label_hosts: false,
+            allowed: vec!["localhost".to_string(), "127.0.0.1".to_string()],
+            ..Default::default()
+        };
+        let content =
+            "[a](https://git.CORP.example.com/x) [b](http://localhost:8080) [c](http://127.0.0.1) [d](http://wiki/)\n";
+        let warnings = check_with(config, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Link points to internal host 'git.CORP.example.com'"
+        );
+    }
+
+    #[test]
+    fn test_code_is_ignored() {
+        let content = "```bash\ncurl http://localhost:8080\n```\n\nRun `open http://10.0.0.1` locally.\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_html_in_code_span_after_reference_definition() {
+        let content = "[g]\n\n[g]: /x\n\nUse `<a href=\"http://10.0.0.1/\">`.\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_html_only_document() {
+        let content = "<img src=\"//10.0.0.1/x.png\">\n";
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD103InternalUrls::default())];
+        let warnings = crate::lint(content, &rules, false, MarkdownFlavor::Standard, None, None).unwrap();
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+    }
+}
@@ -0,0 +1,46 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD103 (internal URLs)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD103Config {
+    /// Host name globs that are internal, e.g. `*.corp.example.com`; compared case-insensitively
+    pub hosts: Vec<String>,
+
+    /// Report loopback, private, link-local and unspecified IP addresses
+    pub private_ips: bool,
+
+    /// Report host names without a dot, such as `http://wiki/`
+    pub single_label_hosts: bool,
+
+    /// Host name globs that are always accepted, e.g. `localhost` in a tutorial
+    pub allowed: Vec<String>,
+}
+
+impl Default for MD103Config {
+    fn default() -> Self {
+        Self {
+            hosts: [
+                "localhost",
+                "*.localhost",
+                "*.local",
+                "*.internal",
+                "*.intranet",
+                "*.corp",
+                "*.lan",
+                "*.home.arpa",
+            ]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+            private_ips: true,
+            single_label_hosts: true,
+            allowed: Vec::new(),
+        }
+    }
+}
+
+impl RuleConfig for MD103Config {
+    const RULE_NAME: &'static str = "MD103";
+}
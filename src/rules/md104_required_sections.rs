@@ -0,0 +1,346 @@
+//! Rule MD104: Documents should contain the sections their template requires.
+//!
+//! Templates map file globs to a list of required headings, so architecture
+//! decision records under `adr/**` can require `## Status`, `## Context` and
+//! `## Decision` while RFCs require a different set. Unlike MD043, other
+//! headings may appear anywhere: only missing required sections and required
+//! sections in the wrong order are reported.
+//!
+//! See [docs/md104.md](../../docs/md104.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::{LintContext, ValidHeading};
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use globset::{GlobBuilder, GlobMatcher};
+use std::path::{Path, PathBuf};
+
+mod md104_config;
+pub(super) use md104_config::MD104Config;
+
+/// A required section such as `## Status`; without leading `#` any level matches
+#[derive(Debug, Clone)]
+struct Section {
+    level: Option<u8>,
+    /// Heading text, lowercased for comparison
+    text: String,
+    /// The section as written in the config, for messages
+    label: String,
+}
+
+impl Section {
+    fn parse(spec: &str) -> Self {
+        let spec = spec.trim();
+        let hashes = spec.chars().take_while(|&c| c == '#').count();
+        let level = (1..=6).contains(&hashes).then_some(hashes as u8);
+        Self {
+            level,
+            text: spec[hashes..].trim().to_lowercase(),
+            label: spec.to_string(),
+        }
+    }
+
+    fn matches(&self, content: &str, heading: &ValidHeading) -> bool {
+        self.level.is_none_or(|level| level == heading.heading.level)
+            && heading.heading.text(content).trim().to_lowercase() == self.text
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Template {
+    matcher: GlobMatcher,
+    sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MD104RequiredSections {
+    config: MD104Config,
+    /// Compiled `templates` in config order; invalid globs are logged and dropped
+    templates: Vec<Template>,
+    /// Canonical project root that template globs are relative to
+    project_root: Option<PathBuf>,
+}
+
+impl Default for MD104RequiredSections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD104RequiredSections {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD104Config::default())
+    }
+
+    pub fn from_config_struct(config: MD104Config) -> Self {
+        let templates = config
+            .templates
+            .iter()
+            .filter_map(
+                |(pattern, sections)| match GlobBuilder::new(pattern).literal_separator(true).build() {
+                    Ok(glob) => Some(Template {
+                        matcher: glob.compile_matcher(),
+                        sections: sections.iter().map(|spec| Section::parse(spec)).collect(),
+                    }),
+                    Err(err) => {
+                        log::warn!("Invalid templates glob for MD104 ('{pattern}'): {err}. Ignoring it.");
+                        None
+                    }
+                },
+            )
+            .collect();
+        Self {
+            config,
+            templates,
+            project_root: None,
+        }
+    }
+
+    /// The template of the first glob matching the linted file
+    fn template_for(&self, ctx: &LintContext) -> Option<&Template> {
+        let path = ctx.source_file.as_deref()?;
+        let cwd = std::env::current_dir().ok();
+        let path = crate::config::normalize_match_path(path, self.project_root.as_deref(), cwd.as_deref());
+        self.templates
+            .iter()
+            .find(|template| template.matcher.is_match(path.as_ref()))
+    }
+
+    /// Indices into `positions` that form the longest run of sections already
+    /// in template order; every other present section is out of order
+    fn longest_ordered_run(positions: &[Option<usize>]) -> Vec<usize> {
+        let present: Vec<usize> = (0..positions.len()).filter(|&i| positions[i].is_some()).collect();
+        let mut best_len = vec![1usize; present.len()];
+        let mut prev = vec![None; present.len()];
+        for i in 0..present.len() {
+            for j in 0..i {
+                if positions[present[j]] < positions[present[i]] && best_len[j] + 1 > best_len[i] {
+                    best_len[i] = best_len[j] + 1;
+                    prev[i] = Some(j);
+                }
+            }
+        }
+        let mut run = Vec::new();
+        let mut cursor = (0..present.len()).max_by_key(|&i| (best_len[i], std::cmp::Reverse(i)));
+        while let Some(i) = cursor {
+            run.push(present[i]);
+            cursor = prev[i];
+        }
+        run.reverse();
+        run
+    }
+}
+
+impl Rule for MD104RequiredSections {
+    fn name(&self) -> &'static str {
+        "MD104"
+    }
+
+    fn description(&self) -> &'static str {
+        "Documents should contain the sections their template requires"
+    }
+
+    fn category(&self) -> RuleCategory {
+        // A document without headings misses every required section, so the
+        // content prefilter must not skip it
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        self.templates.is_empty() || ctx.source_file.is_none()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let Some(template) = self.template_for(ctx) else {
+            return Ok(Vec::new());
+        };
+        let headings: Vec<ValidHeading> = ctx.valid_headings().collect();
+
+        // Each heading satisfies at most one required section
+        let mut used = vec![false; headings.len()];
+        let positions: Vec<Option<usize>> = template
+            .sections
+            .iter()
+            .map(|section| {
+                let idx = (0..headings.len()).find(|&i| !used[i] && section.matches(ctx.content, &headings[i]))?;
+                used[idx] = true;
+                Some(idx)
+            })
+            .collect();
+
+        let mut warnings = Vec::new();
+        for (section, position) in template.sections.iter().zip(&positions) {
+            if position.is_none() {
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 2,
+                    message: format!("Missing required section '{}'", section.label),
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+            }
+        }
+
+        if self.config.enforce_order {
+            let ordered = Self::longest_ordered_run(&positions);
+            for (idx, position) in positions.iter().enumerate() {
+                let Some(heading_idx) = *position else { continue };
+                if ordered.contains(&idx) {
+                    continue;
+                }
+                let anchor = match ordered.iter().rev().find(|&&o| o < idx) {
+                    Some(&before) => format!("after '{}'", template.sections[before].label),
+                    None => match ordered.iter().find(|&&o| o > idx) {
+                        Some(&after) => format!("before '{}'", template.sections[after].label),
+                        None => continue,
+                    },
+                };
+                let heading = &headings[heading_idx];
+                let line_len = heading.line_info.content(ctx.content).chars().count();
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: heading.line_num,
+                    column: 1,
+                    end_line: heading.line_num,
+                    end_column: line_len + 1,
+                    message: format!(
+                        "Section '{}' is out of order; it should come {anchor}",
+                        template.sections[idx].label
+                    ),
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+            }
+        }
+
+        warnings.sort_by_key(|w| w.line);
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD104Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD104Config>(config);
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.project_root = config.canonical_project_root().map(Path::to_path_buf);
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use indexmap::IndexMap;
+
+    fn adr_rule() -> MD104RequiredSections {
+        let mut templates = IndexMap::new();
+        templates.insert(
+            "adr/**".to_string(),
+            vec![
+                "## Status".to_string(),
+                "## Context".to_string(),
+                "## Decision".to_string(),
+            ],
+        );
+        templates.insert("rfcs/*.md".to_string(), vec!["Summary".to_string()]);
+        MD104RequiredSections::from_config_struct(MD104Config {
+            templates,
+            ..Default::default()
+        })
+    }
+
+    fn check_at(rule: &MD104RequiredSections, path: &str, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from(path)));
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_complete_document() {
+        let content = "# ADR\n\n## Status\n\nAccepted\n\n## Context\n\n### Notes\n\n## Decision\n\n## Consequences\n";
+        assert!(check_at(&adr_rule(), "adr/0001-use-rust.md", content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_sections() {
+        let content = "# ADR\n\n## status\n\n## Decision\n";
+        let warnings = check_at(&adr_rule(), "adr/0002.md", content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Missing required section '## Context'");
+        assert_eq!(warnings[0].line, 1);
+    }
+
+    #[test]
+    fn test_out_of_order_sections() {
+        let content = "# ADR\n\n## Decision\n\n## Status\n\n## Context\n";
+        let warnings = check_at(&adr_rule(), "adr/0003.md", content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Section '## Decision' is out of order; it should come after '## Context'"
+        );
+        assert_eq!((warnings[0].line, warnings[0].end_column), (3, 12));
+
+        let rule = MD104RequiredSections::from_config_struct(MD104Config {
+            enforce_order: false,
+            ..adr_rule().config
+        });
+        assert!(check_at(&rule, "adr/0003.md", content).is_empty());
+    }
+
+    #[test]
+    fn test_level_is_checked_when_given() {
+        let content = "# ADR\n\n### Status\n\n## Context\n\n## Decision\n";
+        let warnings = check_at(&adr_rule(), "adr/0004.md", content);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("'## Status'"));
+
+        // Without leading `#` any level matches
+        assert!(check_at(&adr_rule(), "rfcs/0001.md", "# RFC\n\n#### summary\n").is_empty());
+    }
+
+    #[test]
+    fn test_template_selection() {
+        let rule = adr_rule();
+        // Only the first matching template applies, and `*` does not cross directories
+        assert!(check_at(&rule, "rfcs/drafts/0002.md", "# Draft\n").is_empty());
+        assert_eq!(check_at(&rule, "rfcs/0002.md", "# RFC\n").len(), 1);
+        assert!(check_at(&rule, "README.md", "# Readme\n").is_empty());
+
+        let ctx = LintContext::new("# x\n", MarkdownFlavor::Standard, None);
+        assert!(rule.should_skip(&ctx));
+    }
+
+    #[test]
+    fn test_document_without_headings() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(adr_rule())];
+        let warnings = crate::lint(
+            "Just a paragraph.\n",
+            &rules,
+            false,
+            MarkdownFlavor::Standard,
+            Some(PathBuf::from("adr/a.md")),
+            None,
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 3, "{warnings:?}");
+    }
+}
@@ -0,0 +1,28 @@
+use crate::rule_config_serde::RuleConfig;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD104 (required sections)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD104Config {
+    /// Required sections per file glob, e.g. `"adr/**" = ["## Status", "## Context"]`.
+    /// Globs match paths relative to the project root; the first matching glob wins.
+    pub templates: IndexMap<String, Vec<String>>,
+
+    /// Report required sections that appear in a different order than listed
+    pub enforce_order: bool,
+}
+
+impl Default for MD104Config {
+    fn default() -> Self {
+        Self {
+            templates: IndexMap::new(),
+            enforce_order: true,
+        }
+    }
+}
+
+impl RuleConfig for MD104Config {
+    const RULE_NAME: &'static str = "MD104";
+}
@@ -0,0 +1,778 @@
+//! Rule MD105: Changelogs should follow the Keep a Changelog format.
+//!
+//! Checks the structure described at <https://keepachangelog.com>: an
+//! `## [Unreleased]` section on top, one `## [version] - YYYY-MM-DD` heading
+//! per release with the newest first, and `###` subsections drawn from a fixed
+//! set of change categories. Dates in other unambiguous formats, miscased
+//! categories, a missing Unreleased section and releases in the wrong order
+//! are fixed automatically.
+//!
+//! See [docs/md105.md](../../docs/md105.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+mod md105_config;
+pub(super) use md105_config::MD105Config;
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// A level-2 heading for a release or for the Unreleased section
+struct Release<'a> {
+    line: usize,
+    /// Parsed version; `None` for the Unreleased section
+    version: Option<Version>,
+    /// Version as written, or `Unreleased`
+    label: &'a str,
+    /// Date as written and its byte range in the document
+    date: Option<(&'a str, Range<usize>)>,
+}
+
+/// A semantic-style version such as `1.2.0` or `2.0.0-rc.1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version {
+    numbers: Vec<u64>,
+    pre: Vec<String>,
+}
+
+impl Version {
+    fn parse(label: &str) -> Option<Self> {
+        let label = label.strip_prefix(['v', 'V']).unwrap_or(label);
+        let label = label.split_once('+').map_or(label, |(version, _)| version);
+        let (core, pre) = label.split_once('-').unwrap_or((label, ""));
+        let numbers = core
+            .split('.')
+            .map(|part| {
+                if part.bytes().all(|b| b.is_ascii_digit()) {
+                    part.parse().ok()
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<u64>>>()?;
+        let pre = pre.split('.').filter(|id| !id.is_empty()).map(String::from).collect();
+        Some(Self { numbers, pre })
+    }
+
+    /// Semver precedence; missing trailing numbers count as zero
+    fn precedence(&self, other: &Self) -> Ordering {
+        let len = self.numbers.len().max(other.numbers.len());
+        for i in 0..len {
+            let a = self.numbers.get(i).copied().unwrap_or(0);
+            let b = other.numbers.get(i).copied().unwrap_or(0);
+            if a != b {
+                return a.cmp(&b);
+            }
+        }
+        match (self.pre.is_empty(), other.pre.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+        for (a, b) in self.pre.iter().zip(&other.pre) {
+            let order = match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a.cmp(b),
+            };
+            if order != Ordering::Equal {
+                return order;
+            }
+        }
+        self.pre.len().cmp(&other.pre.len())
+    }
+}
+
+/// Problem with a release date as written
+#[derive(Debug, PartialEq)]
+enum DateIssue {
+    /// An unambiguous date in another format, with its ISO 8601 spelling
+    Reformat(String),
+    /// Looks like a date but does not exist, such as `2024-02-30`
+    Invalid,
+    /// Not a date this rule can read
+    Unrecognized,
+}
+
+#[derive(Debug, Clone)]
+pub struct MD105ChangelogFormat {
+    config: MD105Config,
+    /// Compiled `files`; invalid globs are logged and dropped
+    files: GlobSet,
+    /// Canonical project root that `files` globs are relative to
+    project_root: Option<PathBuf>,
+}
+
+impl Default for MD105ChangelogFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD105ChangelogFormat {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD105Config::default())
+    }
+
+    pub fn from_config_struct(config: MD105Config) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &config.files {
+            match GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .case_insensitive(true)
+                .build()
+            {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(err) => log::warn!("Invalid files glob for MD105 ('{pattern}'): {err}. Ignoring it."),
+            }
+        }
+        Self {
+            config,
+            files: builder.build().unwrap_or_else(|_| GlobSet::empty()),
+            project_root: None,
+        }
+    }
+
+    fn is_changelog(&self, ctx: &LintContext) -> bool {
+        let Some(path) = ctx.source_file.as_deref() else {
+            return false;
+        };
+        let cwd = std::env::current_dir().ok();
+        let path = crate::config::normalize_match_path(path, self.project_root.as_deref(), cwd.as_deref());
+        self.files.is_match(path.as_ref())
+    }
+
+    /// Parse `[1.2.0] - 2024-01-02`, `1.2.0 (2024-01-02)`, `[1.2.0](url) - 2024-01-02`
+    /// or `[Unreleased]`
+    fn parse_release<'a>(ctx: &'a LintContext, line: usize, text: &'a str) -> Option<Release<'a>> {
+        let text = text.trim();
+        let (label, rest) = match text.strip_prefix('[') {
+            Some(inner) => {
+                let (label, rest) = inner.split_once(']')?;
+                // A linked version such as `[1.2.0](https://...)` or `[1.2.0][ref]`
+                let rest = match rest.chars().next() {
+                    Some('(') => rest.split_once(')').map_or(rest, |(_, after)| after),
+                    Some('[') => rest.split_once(']').map_or(rest, |(_, after)| after),
+                    _ => rest,
+                };
+                (label, rest)
+            }
+            None => text.split_once(char::is_whitespace).unwrap_or((text, "")),
+        };
+        let label = label.trim();
+        let version = if label.eq_ignore_ascii_case("unreleased") {
+            None
+        } else {
+            Some(Version::parse(label)?)
+        };
+
+        let date = rest
+            .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '–' | '—' | ':' | '('))
+            .trim_end();
+        let date = if date.len() >= 8 && date[date.len() - 8..].eq_ignore_ascii_case("[yanked]") {
+            date[..date.len() - 8].trim_end()
+        } else {
+            date
+        };
+        let date = date.strip_suffix(')').unwrap_or(date).trim_end();
+
+        let line_info = &ctx.lines[line - 1];
+        let line_text = line_info.content(ctx.content);
+        let date = if date.is_empty() {
+            None
+        } else {
+            let after_label = line_text.find(label).map_or(0, |i| i + label.len());
+            line_text[after_label..].find(date).map(|i| {
+                let start = line_info.byte_offset + after_label + i;
+                (date, start..start + date.len())
+            })
+        };
+        Some(Release {
+            line,
+            version,
+            label,
+            date,
+        })
+    }
+
+    /// `None` when `date` is a valid ISO 8601 calendar date
+    fn check_date(date: &str) -> Option<DateIssue> {
+        let Some((year, month, day)) = Self::parse_date(date) else {
+            return Some(DateIssue::Unrecognized);
+        };
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return Some(DateIssue::Invalid),
+        };
+        if day == 0 || day > days_in_month {
+            return Some(DateIssue::Invalid);
+        }
+        let iso = format!("{year:04}-{month:02}-{day:02}");
+        (iso != date).then_some(DateIssue::Reformat(iso))
+    }
+
+    /// Year, month and day of `2024-01-02`, `2024/1/2`, `2024.01.02`,
+    /// `January 2, 2024` or `2 Jan 2024`. Day-first and month-first numeric
+    /// dates are ambiguous and not read.
+    fn parse_date(date: &str) -> Option<(u32, u32, u32)> {
+        let numbers: Vec<&str> = date.split(['-', '/', '.']).collect();
+        if numbers.len() == 3 {
+            if numbers[0].len() == 4
+                && numbers[1..].iter().all(|n| (1..=2).contains(&n.len()))
+                && numbers.iter().all(|n| n.bytes().all(|b| b.is_ascii_digit()))
+            {
+                return Some((
+                    numbers[0].parse().ok()?,
+                    numbers[1].parse().ok()?,
+                    numbers[2].parse().ok()?,
+                ));
+            }
+            return None;
+        }
+
+        let words: Vec<&str> = date.split([' ', ',']).filter(|w| !w.is_empty()).collect();
+        let [a, b, year] = words.as_slice() else {
+            return None;
+        };
+        let month_number = |word: &str| {
+            let word = word.trim_end_matches('.').to_lowercase();
+            (word.len() >= 3)
+                .then(|| MONTHS.iter().position(|month| month.starts_with(&word)))
+                .flatten()
+                .map(|i| i as u32 + 1)
+        };
+        let day_number = |word: &str| {
+            word.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .parse::<u32>()
+                .ok()
+        };
+        let (month, day) = match month_number(a) {
+            Some(month) => (month, day_number(b)?),
+            None => (month_number(b)?, day_number(a)?),
+        };
+        if year.len() != 4 {
+            return None;
+        }
+        Some((year.parse().ok()?, month, day))
+    }
+
+    fn warning(&self, ctx: &LintContext, line: usize, message: String, fix: Option<Fix>) -> LintWarning {
+        let line_len = ctx.lines[line - 1].content(ctx.content).chars().count();
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column: 1,
+            end_line: line,
+            end_column: line_len + 1,
+            message,
+            severity: Severity::Warning,
+            fix,
+        }
+    }
+
+    fn check_dates(&self, ctx: &LintContext, release: &Release, warnings: &mut Vec<LintWarning>) {
+        if release.version.is_none() {
+            return;
+        }
+        let Some((date, range)) = &release.date else {
+            if self.config.require_dates {
+                let message = format!(
+                    "Release {} has no date; expected '## [{}] - YYYY-MM-DD'",
+                    release.label, release.label
+                );
+                warnings.push(self.warning(ctx, release.line, message, None));
+            }
+            return;
+        };
+        let (message, fix) = match Self::check_date(date) {
+            None => return,
+            Some(DateIssue::Reformat(iso)) => (
+                format!("Release date '{date}' should be written as {iso}"),
+                Some(Fix::new(range.clone(), iso)),
+            ),
+            Some(DateIssue::Invalid) => (format!("Release date '{date}' is not a valid date"), None),
+            Some(DateIssue::Unrecognized) => (
+                format!("Release date '{date}' should be an ISO 8601 date (YYYY-MM-DD)"),
+                None,
+            ),
+        };
+        let (line, column) = ctx.offset_to_line_col(range.start);
+        let (end_line, end_column) = ctx.offset_to_line_col(range.end);
+        warnings.push(LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line,
+            end_column,
+            message,
+            severity: Severity::Warning,
+            fix,
+        });
+    }
+
+    /// Replacement for the release sections sorted newest first, keeping any
+    /// fixes already proposed inside each section
+    fn sorted_sections(
+        ctx: &LintContext,
+        sections: &[Range<usize>],
+        order: &[usize],
+        warnings: &[LintWarning],
+    ) -> String {
+        let line_ending = crate::utils::line_ending::detect_line_ending(ctx.content);
+        let section_text = |range: &Range<usize>| {
+            let mut text = ctx.content[range.clone()].to_string();
+            let mut fixes: Vec<&Fix> = warnings
+                .iter()
+                .filter_map(|w| w.fix.as_ref())
+                .filter(|fix| !fix.range.is_empty() && fix.range.start >= range.start && fix.range.end <= range.end)
+                .collect();
+            fixes.sort_by_key(|fix| std::cmp::Reverse(fix.range.start));
+            for fix in fixes {
+                text.replace_range(
+                    fix.range.start - range.start..fix.range.end - range.start,
+                    &fix.replacement,
+                );
+            }
+            text.trim_end().to_string()
+        };
+        let region = sections[0].start..sections[sections.len() - 1].end;
+        let trailing = &ctx.content[region.clone()][ctx.content[region].trim_end().len()..];
+        let mut sorted = order
+            .iter()
+            .map(|&i| section_text(&sections[i]))
+            .collect::<Vec<_>>()
+            .join(&format!("{line_ending}{line_ending}"));
+        sorted.push_str(trailing);
+        sorted
+    }
+
+    /// Byte ranges of the release sections, or `None` when other level-1 or
+    /// level-2 headings sit between them and sorting could move unrelated text
+    fn section_ranges(ctx: &LintContext, releases: &[Release]) -> Option<Vec<Range<usize>>> {
+        let first = releases.first()?.line;
+        let boundaries: Vec<usize> = ctx
+            .valid_headings()
+            .filter(|h| h.heading.level <= 2 && h.line_num >= first)
+            .map(|h| h.line_num)
+            .collect();
+        if boundaries.len() != releases.len() {
+            return None;
+        }
+
+        // The last section stops before trailing reference definitions, which
+        // conventionally hold the compare links for every release
+        let mut definition_lines = std::collections::HashSet::new();
+        for def in &ctx.reference_defs {
+            let last = ctx.offset_to_line_col(def.byte_end.saturating_sub(1)).0.max(def.line);
+            definition_lines.extend(def.line..=last);
+        }
+        let mut end_line = ctx.lines.len();
+        while end_line > first
+            && (definition_lines.contains(&end_line) || ctx.lines[end_line - 1].content(ctx.content).trim().is_empty())
+        {
+            end_line -= 1;
+        }
+        let end = ctx
+            .lines
+            .get(end_line)
+            .map_or(ctx.content.len(), |line| line.byte_offset);
+
+        let starts: Vec<usize> = releases.iter().map(|r| ctx.lines[r.line - 1].byte_offset).collect();
+        Some(
+            starts
+                .iter()
+                .enumerate()
+                .map(|(i, &start)| start..starts.get(i + 1).copied().unwrap_or(end))
+                .collect(),
+        )
+    }
+
+    fn check_order(&self, ctx: &LintContext, releases: &[Release], warnings: &mut Vec<LintWarning>) {
+        let mut order: Vec<usize> = (0..releases.len()).collect();
+        order.sort_by(|&a, &b| match (&releases[a].version, &releases[b].version) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) => b.precedence(a),
+        });
+        let Some((found, expected)) = order.iter().enumerate().find(|&(i, &expected)| i != expected) else {
+            return;
+        };
+        let (found, expected) = (&releases[found], &releases[*expected]);
+        let message = match expected.version {
+            None => format!("The Unreleased section should come before release {}", found.label),
+            Some(_) => format!(
+                "Releases should be listed newest first: expected {} before {}",
+                expected.label, found.label
+            ),
+        };
+        let fix = Self::section_ranges(ctx, releases).map(|sections| {
+            let range = sections[0].start..sections[sections.len() - 1].end;
+            Fix::new(range, Self::sorted_sections(ctx, &sections, &order, warnings))
+        });
+        warnings.push(self.warning(ctx, found.line, message, fix));
+    }
+}
+
+impl Rule for MD105ChangelogFormat {
+    fn name(&self) -> &'static str {
+        "MD105"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelogs should follow the Keep a Changelog format"
+    }
+
+    fn category(&self) -> RuleCategory {
+        // A changelog without headings misses its Unreleased section, so the
+        // content prefilter must not skip it
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        self.files.is_empty() || ctx.source_file.is_none()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if !self.is_changelog(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+        let mut releases: Vec<Release> = Vec::new();
+        // Index into `releases` of the section the current heading is in, and
+        // the categories seen there
+        let mut current: Option<usize> = None;
+        let mut seen_categories: Vec<String> = Vec::new();
+
+        for heading in ctx.valid_headings() {
+            let line = heading.line_num;
+            match heading.heading.level {
+                1 => current = None,
+                2 => {
+                    current = None;
+                    let Some(release) = Self::parse_release(ctx, line, heading.heading.text(ctx.content)) else {
+                        continue;
+                    };
+                    let duplicate = releases.iter().any(|r| match (&r.version, &release.version) {
+                        (None, None) => true,
+                        (Some(a), Some(b)) => a.precedence(b) == Ordering::Equal,
+                        _ => false,
+                    });
+                    if duplicate {
+                        let message = match release.version {
+                            None => "Duplicate Unreleased section".to_string(),
+                            Some(_) => format!("Duplicate release {}", release.label),
+                        };
+                        warnings.push(self.warning(ctx, line, message, None));
+                    }
+                    self.check_dates(ctx, &release, &mut warnings);
+                    releases.push(release);
+                    current = Some(releases.len() - 1);
+                    seen_categories.clear();
+                }
+                3 if !self.config.categories.is_empty() => {
+                    let Some(index) = current else { continue };
+                    let text = heading.heading.text(ctx.content).trim();
+                    let Some(category) = self.config.categories.iter().find(|c| c.eq_ignore_ascii_case(text)) else {
+                        let message = format!(
+                            "Unknown change category '{text}'; expected one of {}",
+                            self.config.categories.join(", ")
+                        );
+                        warnings.push(self.warning(ctx, line, message, None));
+                        continue;
+                    };
+                    if seen_categories.contains(category) {
+                        let message = format!("Duplicate '{category}' section in {}", releases[index].label);
+                        warnings.push(self.warning(ctx, line, message, None));
+                    }
+                    seen_categories.push(category.clone());
+                    if category != text {
+                        let line_info = &ctx.lines[line - 1];
+                        let line_text = line_info.content(ctx.content);
+                        let fix = line_text.rfind(text).map(|i| {
+                            let start = line_info.byte_offset + i;
+                            Fix::new(start..start + text.len(), category.clone())
+                        });
+                        let message = format!("Change category '{text}' should be written '{category}'");
+                        warnings.push(self.warning(ctx, line, message, fix));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if self.config.require_unreleased && !releases.iter().any(|r| r.version.is_none()) {
+            let (line, fix) = match releases.first() {
+                Some(first) => {
+                    let line_ending = crate::utils::line_ending::detect_line_ending(ctx.content);
+                    let start = ctx.lines[first.line - 1].byte_offset;
+                    let insert = format!("## [Unreleased]{line_ending}{line_ending}");
+                    (first.line, Some(Fix::new(start..start, insert)))
+                }
+                None => (1, None),
+            };
+            let message = "Missing '## [Unreleased]' section above the releases".to_string();
+            warnings.push(self.warning(ctx, line, message, fix));
+        }
+
+        if releases.len() > 1 {
+            self.check_order(ctx, &releases, &mut warnings);
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let mut warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+
+        // The reordering fix already carries the fixes inside the sections it moves
+        let ranges: Vec<Range<usize>> = warnings
+            .iter()
+            .filter_map(|w| w.fix.as_ref().map(|fix| fix.range.clone()))
+            .collect();
+        warnings.retain(|w| {
+            !w.fix.as_ref().is_some_and(|fix| {
+                !fix.range.is_empty()
+                    && ranges
+                        .iter()
+                        .any(|r| *r != fix.range && r.start <= fix.range.start && fix.range.end <= r.end)
+            })
+        });
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD105Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD105Config>(config);
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.project_root = config.canonical_project_root().map(Path::to_path_buf);
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(rule: &MD105ChangelogFormat, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from("CHANGELOG.md")));
+        rule.check(&ctx).unwrap()
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        check_with(&MD105ChangelogFormat::new(), content)
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from("CHANGELOG.md")));
+        MD105ChangelogFormat::new().fix(&ctx).unwrap()
+    }
+
+    fn messages(warnings: &[LintWarning]) -> Vec<&str> {
+        warnings.iter().map(|w| w.message.as_str()).collect()
+    }
+
+    const VALID: &str = "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- Dark mode\n\n## [1.10.0] - 2024-03-01\n\n### Fixed\n\n- Crash\n\n## [1.9.0] - 2024-01-15 [YANKED]\n\n## [1.0.0-rc.1] - 2023-12-01\n\n[Unreleased]: https://example.com/compare/v1.10.0...HEAD\n[1.10.0]: https://example.com/compare/v1.9.0...v1.10.0\n";
+
+    #[test]
+    fn test_valid_changelog() {
+        assert!(check(VALID).is_empty());
+    }
+
+    #[test]
+    fn test_only_changelog_files_are_checked() {
+        let content = "## [1.0.0] - yesterday\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from("docs/notes.md")));
+        assert!(MD105ChangelogFormat::new().check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new(
+            content,
+            MarkdownFlavor::Standard,
+            Some(PathBuf::from("crates/a/changelog.md")),
+        );
+        assert_eq!(MD105ChangelogFormat::new().check(&ctx).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_version_parsing_and_order() {
+        let v = |s: &str| Version::parse(s).unwrap();
+        assert_eq!(v("1.10.0").precedence(&v("1.9.0")), Ordering::Greater);
+        assert_eq!(v("v2.0").precedence(&v("2.0.0")), Ordering::Equal);
+        assert_eq!(v("1.0.0").precedence(&v("1.0.0-rc.1")), Ordering::Greater);
+        assert_eq!(v("1.0.0-rc.10").precedence(&v("1.0.0-rc.2")), Ordering::Greater);
+        assert_eq!(v("1.0.0-beta").precedence(&v("1.0.0-alpha.1")), Ordering::Greater);
+        assert!(Version::parse("Older").is_none());
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(MD105ChangelogFormat::check_date("2024-02-29"), None);
+        assert_eq!(
+            MD105ChangelogFormat::check_date("2024/3/5"),
+            Some(DateIssue::Reformat("2024-03-05".to_string()))
+        );
+        assert_eq!(
+            MD105ChangelogFormat::check_date("March 5th, 2024"),
+            Some(DateIssue::Reformat("2024-03-05".to_string()))
+        );
+        assert_eq!(
+            MD105ChangelogFormat::check_date("5 Mar 2024"),
+            Some(DateIssue::Reformat("2024-03-05".to_string()))
+        );
+        assert_eq!(MD105ChangelogFormat::check_date("2023-02-29"), Some(DateIssue::Invalid));
+        assert_eq!(
+            MD105ChangelogFormat::check_date("05/03/2024"),
+            Some(DateIssue::Unrecognized)
+        );
+
+        let content = "## [Unreleased]\n\n## [1.1.0] - 2024/3/5\n\n## 1.0.0 (2023-02-30)\n\n## [0.2.0](https://example.com/v0.2.0) - 2022-12-01\n\n## [0.1.0]\n";
+        let warnings = check(content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                "Release date '2024/3/5' should be written as 2024-03-05",
+                "Release date '2023-02-30' is not a valid date",
+                "Release 0.1.0 has no date; expected '## [0.1.0] - YYYY-MM-DD'",
+            ]
+        );
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (3, 14, 22)
+        );
+        assert!(fix(content).contains("## [1.1.0] - 2024-03-05\n"));
+    }
+
+    #[test]
+    fn test_categories() {
+        let content = "## [Unreleased]\n\n### added\n\n### Improvements\n\n### Added\n";
+        let warnings = check(content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                "Change category 'added' should be written 'Added'",
+                "Unknown change category 'Improvements'; expected one of Added, Changed, Deprecated, Removed, Fixed, Security",
+                "Duplicate 'Added' section in Unreleased",
+            ]
+        );
+        assert!(fix(content).starts_with("## [Unreleased]\n\n### Added\n"));
+
+        let rule = MD105ChangelogFormat::from_config_struct(MD105Config {
+            categories: Vec::new(),
+            ..Default::default()
+        });
+        assert!(check_with(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_unreleased() {
+        let content = "# Changelog\n\n## [1.0.0] - 2024-01-01\n";
+        let warnings = check(content);
+        assert_eq!(
+            messages(&warnings),
+            vec!["Missing '## [Unreleased]' section above the releases"]
+        );
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            fix(content),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.0.0] - 2024-01-01\n"
+        );
+
+        let rule = MD105ChangelogFormat::from_config_struct(MD105Config {
+            require_unreleased: false,
+            ..Default::default()
+        });
+        assert!(check_with(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_release_order_fix() {
+        let content = "# Changelog\n\n## [1.0.0] - 2024-01-01\n\n- First\n\n## [Unreleased]\n\n## [1.2.0] - 2024/3/1\n\n### fixed\n\n- Bug\n\n## [1.1.0] - 2024-02-01\n\n[1.2.0]: https://example.com/1.2.0\n";
+        let warnings = check(content);
+        assert!(messages(&warnings).contains(&"The Unreleased section should come before release 1.0.0"));
+        assert_eq!(
+            fix(content),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.2.0] - 2024-03-01\n\n### Fixed\n\n- Bug\n\n## [1.1.0] - 2024-02-01\n\n## [1.0.0] - 2024-01-01\n\n- First\n\n[1.2.0]: https://example.com/1.2.0\n"
+        );
+    }
+
+    #[test]
+    fn test_order_without_fix_and_duplicates() {
+        // A non-release heading between releases blocks the reordering fix
+        let content = "## [Unreleased]\n\n## [1.0.0] - 2024-01-01\n\n## Older releases\n\n## [1.1.0] - 2024-02-01\n\n## [1.0.0] - 2024-01-01\n";
+        let warnings = check(content);
+        assert_eq!(
+            messages(&warnings),
+            vec![
+                "Releases should be listed newest first: expected 1.1.0 before 1.0.0",
+                "Duplicate release 1.0.0",
+            ]
+        );
+        assert!(warnings[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_changelog_without_headings() {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD105ChangelogFormat::new())];
+        let warnings = crate::lint(
+            "All notable changes are listed here.\n",
+            &rules,
+            false,
+            MarkdownFlavor::Standard,
+            Some(PathBuf::from("CHANGELOG.md")),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            messages(&warnings),
+            vec!["Missing '## [Unreleased]' section above the releases"]
+        );
+    }
+}
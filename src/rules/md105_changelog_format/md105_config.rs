@@ -0,0 +1,38 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD105 (changelog format)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD105Config {
+    /// Globs selecting the changelog files to check, relative to the project
+    /// root and matched case-insensitively
+    pub files: Vec<String>,
+
+    /// Require an `## [Unreleased]` section above the releases
+    pub require_unreleased: bool,
+
+    /// Require every release heading to carry a date
+    pub require_dates: bool,
+
+    /// Allowed `###` change categories; an empty list allows any
+    pub categories: Vec<String>,
+}
+
+impl Default for MD105Config {
+    fn default() -> Self {
+        Self {
+            files: vec!["**/CHANGELOG.md".to_string()],
+            require_unreleased: true,
+            require_dates: true,
+            categories: ["Added", "Changed", "Deprecated", "Removed", "Fixed", "Security"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+impl RuleConfig for MD105Config {
+    const RULE_NAME: &'static str = "MD105";
+}
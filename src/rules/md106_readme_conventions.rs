@@ -0,0 +1,396 @@
+//! Rule MD106: READMEs should follow the project's README conventions.
+//!
+//! Organizations often share a README template: one title naming the
+//! project, badges grouped at the top, and sections such as License and
+//! Usage that every repository must have. This rule checks README files
+//! against those conventions, all of which are configured in TOML.
+//!
+//! See [docs/md106.md](../../docs/md106.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::html_utils::parse_attributes;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+mod md106_config;
+pub(super) use md106_config::MD106Config;
+
+/// A top-level heading, written in Markdown or as an HTML `<h1>` element
+struct Title {
+    line: usize,
+    text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MD106ReadmeConventions {
+    config: MD106Config,
+    /// Compiled `files`; invalid globs are logged and dropped
+    files: GlobSet,
+    /// Compiled `title-pattern`; `None` when unset or invalid
+    title_pattern: Option<Regex>,
+    /// Canonical project root that `files` globs are relative to
+    project_root: Option<PathBuf>,
+}
+
+impl Default for MD106ReadmeConventions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MD106ReadmeConventions {
+    pub fn new() -> Self {
+        Self::from_config_struct(MD106Config::default())
+    }
+
+    pub fn from_config_struct(config: MD106Config) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &config.files {
+            match GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .case_insensitive(true)
+                .build()
+            {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(err) => log::warn!("Invalid files glob for MD106 ('{pattern}'): {err}. Ignoring it."),
+            }
+        }
+        let title_pattern = config
+            .title_pattern
+            .as_deref()
+            .and_then(|pattern| match Regex::new(pattern) {
+                Ok(regex) => Some(regex),
+                Err(err) => {
+                    log::warn!("Invalid title-pattern regex for MD106 ('{pattern}'): {err}. Ignoring it.");
+                    None
+                }
+            });
+        Self {
+            config,
+            files: builder.build().unwrap_or_else(|_| GlobSet::empty()),
+            title_pattern,
+            project_root: None,
+        }
+    }
+
+    fn is_readme(&self, ctx: &LintContext) -> bool {
+        let Some(path) = ctx.source_file.as_deref() else {
+            return false;
+        };
+        let cwd = std::env::current_dir().ok();
+        let path = crate::config::normalize_match_path(path, self.project_root.as_deref(), cwd.as_deref());
+        self.files.is_match(path.as_ref())
+    }
+
+    /// Markdown H1 headings and HTML `<h1>` elements, in document order
+    fn titles(ctx: &LintContext) -> Vec<Title> {
+        let mut titles: Vec<Title> = ctx
+            .valid_headings()
+            .filter(|h| h.heading.level == 1)
+            .map(|h| Title {
+                line: h.line_num,
+                text: h.heading.text(ctx.content).trim().to_string(),
+            })
+            .collect();
+
+        let tags = ctx.html_tags();
+        for (i, tag) in tags.iter().enumerate() {
+            if tag.tag_name != "h1" || tag.is_closing || ctx.is_in_html_comment(tag.byte_offset) {
+                continue;
+            }
+            let end = tags[i + 1..]
+                .iter()
+                .find(|t| t.tag_name == "h1" && t.is_closing)
+                .map_or(tag.byte_end, |t| t.byte_offset);
+            titles.push(Title {
+                line: tag.line,
+                text: Self::strip_tags(&ctx.content[tag.byte_end..end]),
+            });
+        }
+        titles.sort_by_key(|title| title.line);
+        titles
+    }
+
+    /// Text content of an HTML fragment, with tags removed and whitespace collapsed
+    fn strip_tags(html: &str) -> String {
+        let mut text = String::with_capacity(html.len());
+        let mut in_tag = false;
+        for c in html.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' if in_tag => in_tag = false,
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn is_badge(&self, url: &str) -> bool {
+        let url = url.to_lowercase();
+        self.config
+            .badge_urls
+            .iter()
+            .any(|pattern| !pattern.is_empty() && url.contains(&pattern.to_lowercase()))
+    }
+
+    /// Start offsets of badge images, in Markdown or as HTML `<img>` elements
+    fn badges(&self, ctx: &LintContext) -> Vec<usize> {
+        let mut badges: Vec<usize> = ctx
+            .images()
+            .iter()
+            .filter(|image| self.is_badge(&image.url))
+            .map(|image| image.byte_offset)
+            .collect();
+        for tag in ctx.html_tags().iter() {
+            if tag.tag_name != "img" || tag.is_closing || ctx.is_in_html_comment(tag.byte_offset) {
+                continue;
+            }
+            let source = &ctx.content[tag.byte_offset..tag.byte_end];
+            let is_badge = parse_attributes(source)
+                .iter()
+                .any(|attr| attr.name == "src" && attr.value.as_deref().is_some_and(|src| self.is_badge(src)));
+            if is_badge {
+                badges.push(tag.byte_offset);
+            }
+        }
+        badges.sort_unstable();
+        badges
+    }
+
+    fn line_warning(&self, ctx: &LintContext, line: usize, message: String) -> LintWarning {
+        let line_len = ctx
+            .lines
+            .get(line - 1)
+            .map_or(0, |info| info.content(ctx.content).chars().count());
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column: 1,
+            end_line: line,
+            end_column: line_len + 1,
+            message,
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+}
+
+impl Rule for MD106ReadmeConventions {
+    fn name(&self) -> &'static str {
+        "MD106"
+    }
+
+    fn description(&self) -> &'static str {
+        "READMEs should follow the project's README conventions"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        self.files.is_empty() || ctx.source_file.is_none()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if !self.is_readme(ctx) {
+            return Ok(Vec::new());
+        }
+        let mut warnings = Vec::new();
+
+        let titles = Self::titles(ctx);
+        match titles.first() {
+            None => warnings.push(self.line_warning(ctx, 1, "README should have a top-level heading".to_string())),
+            Some(title) => {
+                if let Some(pattern) = &self.title_pattern
+                    && !pattern.is_match(&title.text)
+                {
+                    let message = format!(
+                        "README title '{}' does not match the pattern '{}'",
+                        title.text,
+                        pattern.as_str()
+                    );
+                    warnings.push(self.line_warning(ctx, title.line, message));
+                }
+            }
+        }
+        for title in titles.iter().skip(1) {
+            let message = format!("README should have a single top-level heading; found '{}'", title.text);
+            warnings.push(self.line_warning(ctx, title.line, message));
+        }
+
+        if self.config.badge_lines > 0 {
+            for offset in self.badges(ctx) {
+                let (line, column) = ctx.offset_to_line_col(offset);
+                if line > self.config.badge_lines {
+                    let mut warning = self.line_warning(
+                        ctx,
+                        line,
+                        format!("Badges should be in the first {} lines", self.config.badge_lines),
+                    );
+                    warning.column = column;
+                    warnings.push(warning);
+                }
+            }
+        }
+
+        let headings: Vec<String> = ctx
+            .valid_headings()
+            .map(|h| h.heading.text(ctx.content).trim().to_lowercase())
+            .collect();
+        for required in &self.config.required_sections {
+            let alternatives: Vec<&str> = required.split('|').map(str::trim).filter(|a| !a.is_empty()).collect();
+            if alternatives.is_empty() || alternatives.iter().any(|a| headings.contains(&a.to_lowercase())) {
+                continue;
+            }
+            let names = match alternatives.split_last() {
+                Some((last, [])) => format!("'{last}'"),
+                Some((last, rest)) => format!(
+                    "{} or '{last}'",
+                    rest.iter().map(|a| format!("'{a}'")).collect::<Vec<_>>().join(", ")
+                ),
+                None => continue,
+            };
+            let mut warning = self.line_warning(ctx, 1, format!("README is missing a {names} section"));
+            warning.end_column = 2;
+            warnings.push(warning);
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        crate::rule_config_serde::default_config_section_for::<MD106Config>()
+    }
+
+    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD106Config>(config);
+        let mut rule = Self::from_config_struct(rule_config);
+        rule.project_root = config.canonical_project_root().map(Path::to_path_buf);
+        Box::new(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_with(rule: &MD106ReadmeConventions, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from("README.md")));
+        rule.check(&ctx).unwrap()
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        check_with(&MD106ReadmeConventions::new(), content)
+    }
+
+    fn messages(warnings: &[LintWarning]) -> Vec<&str> {
+        warnings.iter().map(|w| w.message.as_str()).collect()
+    }
+
+    const VALID: &str = "# rumdl\n\n[![CI](https://github.com/o/r/actions/workflows/ci.yml/badge.svg)](https://ci)\n![Crates](https://img.shields.io/crates/v/rumdl)\n\nA linter.\n\n![Screenshot](docs/screenshot.png)\n\n## Getting started\n\n## License\n\nMIT\n";
+
+    #[test]
+    fn test_valid_readme() {
+        assert!(check(VALID).is_empty());
+    }
+
+    #[test]
+    fn test_only_readme_files_are_checked() {
+        let ctx = LintContext::new("Text\n", MarkdownFlavor::Standard, Some(PathBuf::from("docs/guide.md")));
+        assert!(MD106ReadmeConventions::new().check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new(
+            "Text\n",
+            MarkdownFlavor::Standard,
+            Some(PathBuf::from("crates/a/readme.md")),
+        );
+        assert_eq!(MD106ReadmeConventions::new().check(&ctx).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_title() {
+        let content = "Intro\n\n## Usage\n\n## License\n";
+        assert_eq!(
+            messages(&check(content)),
+            vec!["README should have a top-level heading"]
+        );
+
+        let content =
+            "<h1 align=\"center\">\n  <img src=\"logo.svg\"> rumdl\n</h1>\n\n# Other\n\n## Usage\n\n## License\n";
+        let warnings = check(content);
+        assert_eq!(
+            messages(&warnings),
+            vec!["README should have a single top-level heading; found 'Other'"]
+        );
+        assert_eq!(warnings[0].line, 5);
+
+        let rule = MD106ReadmeConventions::from_config_struct(MD106Config {
+            title_pattern: Some("^rumdl$".to_string()),
+            ..Default::default()
+        });
+        assert!(check_with(&rule, VALID).is_empty());
+        assert_eq!(
+            messages(&check_with(&rule, "# My Project\n\n## Usage\n\n## License\n")),
+            vec!["README title 'My Project' does not match the pattern '^rumdl$'"]
+        );
+    }
+
+    #[test]
+    fn test_badges_below_the_top() {
+        let content = format!(
+            "{VALID}\n## Status\n{}![Coverage](https://codecov.io/gh/o/r/badge.svg) <img src=\"https://img.shields.io/x\">\n",
+            "\n".repeat(6)
+        );
+        let warnings = check(&content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Badges should be in the first 20 lines");
+        assert_eq!((warnings[0].line, warnings[0].column), (23, 1));
+        assert_eq!((warnings[1].line, warnings[1].column), (23, 50));
+
+        let rule = MD106ReadmeConventions::from_config_struct(MD106Config {
+            badge_lines: 0,
+            ..Default::default()
+        });
+        assert!(check_with(&rule, &content).is_empty());
+    }
+
+    #[test]
+    fn test_required_sections() {
+        let content = "# rumdl\n\n### licence\n";
+        assert_eq!(
+            messages(&check(content)),
+            vec!["README is missing a 'Usage', 'Getting Started', 'Quick Start' or 'Quickstart' section"]
+        );
+
+        let rule = MD106ReadmeConventions::from_config_struct(MD106Config {
+            required_sections: vec!["Contributing".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(
+            messages(&check_with(&rule, content)),
+            vec!["README is missing a 'Contributing' section"]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD106 (README conventions)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD106Config {
+    /// Globs selecting the README files to check, relative to the project
+    /// root and matched case-insensitively
+    pub files: Vec<String>,
+
+    /// Regex the text of the single top-level heading must match
+    pub title_pattern: Option<String>,
+
+    /// Badges must appear within this many lines from the top; 0 disables the check
+    pub badge_lines: usize,
+
+    /// Case-insensitive substrings of an image URL that mark it as a badge
+    pub badge_urls: Vec<String>,
+
+    /// Sections the README must contain, matched case-insensitively at any
+    /// heading level; alternatives are separated by `|`, e.g. `"Usage|Getting Started"`
+    pub required_sections: Vec<String>,
+}
+
+impl Default for MD106Config {
+    fn default() -> Self {
+        Self {
+            files: vec!["**/README.md".to_string()],
+            title_pattern: None,
+            badge_lines: 20,
+            badge_urls: vec!["shields.io".to_string(), "badge".to_string()],
+            required_sections: vec![
+                "License|Licence|Licensing".to_string(),
+                "Usage|Getting Started|Quick Start|Quickstart".to_string(),
+            ],
+        }
+    }
+}
+
+impl RuleConfig for MD106Config {
+    const RULE_NAME: &'static str = "MD106";
+}
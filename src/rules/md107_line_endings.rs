@@ -0,0 +1,262 @@
+//! Rule MD107: Files should use one line ending style and no byte order mark.
+//!
+//! An opt-in rule for repositories that pin their line endings. Every line
+//! must end with LF, with CRLF, or with whichever of the two most lines of
+//! the file already use, and a UTF-8 byte order mark at the start of the
+//! file is reported. Lone carriage returns are left alone.
+//!
+//! The fix converts the line endings only once every other rule's fixes are
+//! in, so their byte-range edits never have to account for the conversion.
+//!
+//! See [docs/md107.md](../../docs/md107.md) for full documentation, configuration, and examples.
+
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, FixPriority, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+
+mod md107_config;
+pub use md107_config::LineEndingStyle;
+pub(super) use md107_config::MD107Config;
+
+/// The UTF-8 byte order mark
+const BOM: char = '\u{feff}';
+
+/// A line ending in the content: byte offset of its first byte and whether it is CRLF
+#[derive(Debug, Clone, Copy)]
+struct LineEnd {
+    offset: usize,
+    crlf: bool,
+}
+
+/// All LF and CRLF line endings of `content`, in order
+fn line_ends(content: &str) -> Vec<LineEnd> {
+    let bytes = content.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b == b'\n')
+        .map(|(i, _)| {
+            let crlf = i > 0 && bytes[i - 1] == b'\r';
+            LineEnd {
+                offset: if crlf { i - 1 } else { i },
+                crlf,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MD107LineEndings {
+    config: MD107Config,
+}
+
+impl MD107LineEndings {
+    pub fn new(style: LineEndingStyle, allow_bom: bool) -> Self {
+        Self {
+            config: MD107Config { style, allow_bom },
+        }
+    }
+
+    pub fn from_config_struct(config: MD107Config) -> Self {
+        Self { config }
+    }
+
+    /// Whether lines should end with CRLF rather than LF
+    fn wants_crlf(&self, ends: &[LineEnd]) -> bool {
+        match self.config.style {
+            LineEndingStyle::Lf => false,
+            LineEndingStyle::Crlf => true,
+            LineEndingStyle::Consistent => {
+                let crlf = ends.iter().filter(|end| end.crlf).count();
+                crlf > ends.len() - crlf
+            }
+        }
+    }
+
+    /// A warning at byte `offset`, spanning `len` characters
+    fn warning(ctx: &LintContext, offset: usize, len: usize, message: String, fix: Fix) -> LintWarning {
+        let (line, column) = ctx.offset_to_line_col(offset);
+        LintWarning {
+            message,
+            line,
+            column,
+            end_line: line,
+            end_column: column + len,
+            severity: Severity::Warning,
+            fix: Some(fix),
+            rule_name: Some("MD107".to_string()),
+        }
+    }
+}
+
+impl Rule for MD107LineEndings {
+    fn name(&self) -> &'static str {
+        "MD107"
+    }
+
+    fn description(&self) -> &'static str {
+        "Line endings should be consistent and files should not start with a BOM"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Whitespace
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let content = ctx.content;
+        let mut warnings = Vec::new();
+
+        if !self.config.allow_bom && content.starts_with(BOM) {
+            let fix = Fix::new(0..BOM.len_utf8(), String::new());
+            let message = "File should not start with a byte order mark (BOM)".to_string();
+            warnings.push(Self::warning(ctx, 0, 1, message, fix));
+        }
+
+        // One warning covers the whole file, placed at the first wrong line
+        // ending, so a file saved with the other style is a single finding
+        let ends = line_ends(content);
+        let crlf = self.wants_crlf(&ends);
+        let wrong: Vec<&LineEnd> = ends.iter().filter(|end| end.crlf != crlf).collect();
+        if let Some((first, rest)) = wrong.split_first() {
+            let edit = |end: &LineEnd| {
+                if crlf {
+                    Fix::new(end.offset..end.offset, "\r".to_string())
+                } else {
+                    Fix::new(end.offset..end.offset + 1, String::new())
+                }
+            };
+            let (expected, found) = if crlf { ("CRLF", "LF") } else { ("LF", "CRLF") };
+            let count = wrong.len();
+            let message = if count == 1 {
+                format!("Line ending should be {expected}, found {found}")
+            } else {
+                format!("Line endings should be {expected}, found {count} {found} line endings")
+            };
+            let fix = Fix {
+                additional_edits: rest.iter().map(|end| edit(end)).collect(),
+                ..edit(first)
+            };
+            let len = if first.crlf { 2 } else { 1 };
+            warnings.push(Self::warning(ctx, first.offset, len, message, fix));
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+        let warnings =
+            crate::utils::fix_utils::filter_warnings_by_inline_config(warnings, ctx.inline_config(), self.name());
+        crate::utils::fix_utils::apply_warning_fixes(ctx.content, &warnings).map_err(LintError::InvalidInput)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn fix_priority(&self) -> FixPriority {
+        FixPriority::LineEndings
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.content.is_empty()
+            || (self.config.style != LineEndingStyle::Crlf
+                && !ctx.content.contains('\r')
+                && (self.config.allow_bom || !ctx.content.starts_with(BOM)))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    crate::impl_rule_config_methods!(MD107Config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD107LineEndings, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fixed(rule: &MD107LineEndings, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_files_pass() {
+        let rule = MD107LineEndings::default();
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+        assert!(check(&rule, "# Title\r\n\r\nText\r\n").is_empty());
+        assert!(check(&rule, "").is_empty());
+    }
+
+    #[test]
+    fn test_consistent_follows_dominant_line_ending() {
+        let rule = MD107LineEndings::default();
+        let content = "# Title\r\n\r\nOne\nTwo\r\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Line ending should be CRLF, found LF");
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 4));
+        assert_eq!(fixed(&rule, content), "# Title\r\n\r\nOne\r\nTwo\r\n");
+
+        // A tie goes to LF
+        assert_eq!(fixed(&rule, "One\r\nTwo\n"), "One\nTwo\n");
+    }
+
+    #[test]
+    fn test_configured_style_reports_whole_file_once() {
+        let rule = MD107LineEndings::new(LineEndingStyle::Lf, false);
+        let content = "# Title\r\n\r\nText\r\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Line endings should be LF, found 3 CRLF line endings"
+        );
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(fixed(&rule, content), "# Title\n\nText\n");
+
+        let rule = MD107LineEndings::new(LineEndingStyle::Crlf, false);
+        assert_eq!(fixed(&rule, "# Title\n\nText"), "# Title\r\n\r\nText");
+    }
+
+    #[test]
+    fn test_lone_carriage_returns_are_left_alone() {
+        let rule = MD107LineEndings::new(LineEndingStyle::Lf, false);
+        assert!(check(&rule, "One\rTwo\n").is_empty());
+    }
+
+    #[test]
+    fn test_byte_order_mark() {
+        let rule = MD107LineEndings::default();
+        let content = "\u{feff}# Title\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "File should not start with a byte order mark (BOM)"
+        );
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 1));
+        assert_eq!(fixed(&rule, content), "# Title\n");
+
+        let rule = MD107LineEndings::new(LineEndingStyle::Consistent, true);
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_bom_and_line_endings_fixed_together() {
+        let rule = MD107LineEndings::new(LineEndingStyle::Lf, false);
+        assert_eq!(fixed(&rule, "\u{feff}# Title\r\n\r\nText\r\n"), "# Title\n\nText\n");
+    }
+}
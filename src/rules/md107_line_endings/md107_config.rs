@@ -0,0 +1,30 @@
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Line ending every line must use (MD107)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineEndingStyle {
+    /// Follow the line ending most lines of the file already use, LF on a tie
+    #[default]
+    Consistent,
+    /// Unix line endings (`\n`)
+    Lf,
+    /// Windows line endings (`\r\n`)
+    Crlf,
+}
+
+/// Configuration for MD107 (line endings)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MD107Config {
+    /// Line ending style: `consistent`, `lf`, or `crlf`
+    pub style: LineEndingStyle,
+
+    /// Whether a UTF-8 byte order mark may start the file
+    pub allow_bom: bool,
+}
+
+impl RuleConfig for MD107Config {
+    const RULE_NAME: &'static str = "MD107";
+}
@@ -72,6 +72,31 @@ mod md079_chunk_label_spaces;
 mod md080_heading_anchor_collision;
 mod md081_no_excessive_emphasis;
 mod md082_no_empty_sections;
+mod md083_undefined_jsx_components;
+mod md084_shell_prompt_style;
+mod md085_callout_title_capitalization;
+mod md086_unused_images;
+mod md087_heading_emoji;
+mod md088_sorted_lists;
+mod md089_deprecated_anchors;
+mod md090_code_span_ascii;
+mod md091_conflicting_reference_definitions;
+mod md092_front_matter_title_match;
+mod md093_alt_text_quality;
+mod md094_callout_style;
+mod md095_sorted_reference_definitions;
+mod md096_duplicate_page_titles;
+mod md097_diagram_syntax;
+mod md098_typography;
+mod md099_list_item_punctuation;
+mod md100_task_list_style;
+mod md101_stable_anchors;
+mod md102_dangerous_links;
+mod md103_internal_urls;
+mod md104_required_sections;
+mod md105_changelog_format;
+mod md106_readme_conventions;
+mod md107_line_endings;
 
 pub use code_fence_utils::CodeFenceStyle;
 pub use md001_heading_increment::MD001HeadingIncrement;
@@ -146,6 +171,33 @@ pub use md079_chunk_label_spaces::MD079ChunkLabelSpaces;
 pub use md080_heading_anchor_collision::MD080HeadingAnchorCollision;
 pub use md081_no_excessive_emphasis::MD081NoExcessiveEmphasis;
 pub use md082_no_empty_sections::MD082NoEmptySections;
+pub use md083_undefined_jsx_components::MD083UndefinedJsxComponents;
+pub use md084_shell_prompt_style::{MD084ShellPromptStyle, ShellPromptStyle};
+pub use md085_callout_title_capitalization::MD085CalloutTitleCapitalization;
+pub use md086_unused_images::MD086UnusedImages;
+pub use md087_heading_emoji::MD087HeadingEmoji;
+pub use md088_sorted_lists::MD088SortedLists;
+pub use md089_deprecated_anchors::MD089DeprecatedAnchors;
+pub use md090_code_span_ascii::MD090CodeSpanAscii;
+pub use md091_conflicting_reference_definitions::MD091ConflictingReferenceDefinitions;
+pub use md092_front_matter_title_match::MD092FrontMatterTitleMatch;
+pub use md093_alt_text_quality::MD093AltTextQuality;
+pub use md094_callout_style::MD094CalloutStyle;
+pub use md095_sorted_reference_definitions::MD095SortedReferenceDefinitions;
+pub use md096_duplicate_page_titles::MD096DuplicatePageTitles;
+pub use md097_diagram_syntax::{
+    DiagramIssue, DiagramValidator, MD097DiagramSyntax, MermaidValidator, PlantUmlValidator,
+};
+pub use md098_typography::{DashStyle, EllipsisStyle, MD098Typography, QuoteStyle};
+pub use md099_list_item_punctuation::{ListPunctuationStyle, MD099ListItemPunctuation};
+pub use md100_task_list_style::{CheckedMarkerStyle, MD100TaskListStyle};
+pub use md101_stable_anchors::MD101StableAnchors;
+pub use md102_dangerous_links::MD102DangerousLinks;
+pub use md103_internal_urls::MD103InternalUrls;
+pub use md104_required_sections::MD104RequiredSections;
+pub use md105_changelog_format::MD105ChangelogFormat;
+pub use md106_readme_conventions::MD106ReadmeConventions;
+pub use md107_line_endings::{LineEndingStyle, MD107LineEndings};
 
 mod md012_no_multiple_blanks;
 pub use md012_no_multiple_blanks::MD012NoMultipleBlanks;
@@ -571,11 +623,139 @@ const RULES: &[RuleEntry] = &[
         ctor: MD082NoEmptySections::from_config,
         opt_in: true,
     },
+    RuleEntry {
+        name: "MD083",
+        ctor: MD083UndefinedJsxComponents::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD084",
+        ctor: MD084ShellPromptStyle::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD085",
+        ctor: MD085CalloutTitleCapitalization::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD086",
+        ctor: MD086UnusedImages::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD087",
+        ctor: MD087HeadingEmoji::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD088",
+        ctor: MD088SortedLists::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD089",
+        ctor: MD089DeprecatedAnchors::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD090",
+        ctor: MD090CodeSpanAscii::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD091",
+        ctor: MD091ConflictingReferenceDefinitions::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD092",
+        ctor: MD092FrontMatterTitleMatch::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD093",
+        ctor: MD093AltTextQuality::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD094",
+        ctor: MD094CalloutStyle::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD095",
+        ctor: MD095SortedReferenceDefinitions::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD096",
+        ctor: MD096DuplicatePageTitles::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD097",
+        ctor: MD097DiagramSyntax::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD098",
+        ctor: MD098Typography::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD099",
+        ctor: MD099ListItemPunctuation::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD100",
+        ctor: MD100TaskListStyle::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD101",
+        ctor: MD101StableAnchors::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD102",
+        ctor: MD102DangerousLinks::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD103",
+        ctor: MD103InternalUrls::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD104",
+        ctor: MD104RequiredSections::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD105",
+        ctor: MD105ChangelogFormat::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD106",
+        ctor: MD106ReadmeConventions::from_config,
+        opt_in: true,
+    },
+    RuleEntry {
+        name: "MD107",
+        ctor: MD107LineEndings::from_config,
+        opt_in: true,
+    },
 ];
 
-/// Returns all rule instances (including opt-in) for config validation and CLI
+/// Returns all rule instances (including opt-in) for config validation and CLI,
+/// followed by any prose style rules loaded from `[prose-styles]`
 pub fn all_rules(config: &crate::config::Config) -> Vec<Box<dyn Rule>> {
-    RULES.iter().map(|entry| (entry.ctor)(config)).collect()
+    let mut rules: Vec<Box<dyn Rule>> = RULES.iter().map(|entry| (entry.ctor)(config)).collect();
+    rules.extend(crate::prose_styles::load_style_rules(config));
+    rules
 }
 
 /// Returns the set of rule names that require explicit opt-in
@@ -3,8 +3,8 @@
 use crate::file_processor;
 use colored::*;
 use rumdl_lib::config as rumdl_config;
-use rumdl_lib::exit_codes::exit;
-use rumdl_lib::rule::{Rule, Severity};
+use rumdl_lib::exit_codes::{IssueCounts, exit};
+use rumdl_lib::rule::Rule;
 use std::io::{self, Read};
 
 /// Process markdown content from stdin
@@ -31,6 +31,8 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
             exit::tool_error();
         }
     };
+    let rule_names: rumdl_lib::output::RuleNameDisplay = args.rule_names.into();
+    let docs_links = rumdl_lib::output::DocsLinks::from_config(config);
 
     // Read all content from stdin
     let mut content = String::new();
@@ -41,11 +43,20 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
         exit::violations_found();
     }
 
-    // Detect original line ending before any processing (I/O boundary)
-    let original_line_ending = rumdl_lib::utils::detect_line_ending_enum(&content);
+    // MD107 checks and fixes the input's own line endings, so with it enabled
+    // the content is linted as read and output as the fixes leave it
+    // (`Mixed` leaves line endings unchanged on output)
+    let keep_line_endings = rules.iter().any(|rule| rule.name() == "MD107");
+    let original_line_ending = if keep_line_endings {
+        rumdl_lib::utils::LineEnding::Mixed
+    } else {
+        // Detect original line ending before any processing (I/O boundary)
+        let original_line_ending = rumdl_lib::utils::detect_line_ending_enum(&content);
 
-    // Normalize to LF for all internal processing
-    content = rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf).into_owned();
+        // Normalize to LF for all internal processing
+        content = rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf).into_owned();
+        original_line_ending
+    };
 
     // Validate inline config comments and warn about unknown rules
     if !silent {
@@ -71,17 +82,31 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
 
     // Lint through the same engine as the file path, so inline config
     // overrides, kramdown suppression, inline-disable ranges, and severity
-    // overrides behave identically to `rumdl check <file>`.
-    let mut all_warnings =
-        match rumdl_lib::lint(&content, rules, args.verbose, flavor, source_file.clone(), Some(config)) {
-            Ok(warnings) => warnings,
-            Err(e) => {
-                if !silent {
-                    eprintln!("{}: {}", "Error".red().bold(), e);
-                }
-                exit::tool_error();
+    // overrides behave identically to `rumdl check <file>`. With a filename,
+    // cross-file rules also check links against the files they point to.
+    let (lint_result, file_index) =
+        rumdl_lib::lint_and_index(&content, rules, args.verbose, flavor, source_file.clone(), Some(config));
+    let lint_result = lint_result.and_then(|mut warnings| {
+        if let Some(path) = source_file.as_deref() {
+            warnings.extend(rumdl_lib::run_cross_file_checks_for_links(
+                path,
+                &file_index,
+                rules,
+                flavor,
+                Some(config),
+            )?);
+        }
+        Ok(warnings)
+    });
+    let mut all_warnings = match lint_result {
+        Ok(warnings) => warnings,
+        Err(e) => {
+            if !silent {
+                eprintln!("{}: {}", "Error".red().bold(), e);
             }
-        };
+            exit::tool_error();
+        }
+    };
 
     // Sort warnings by line/column
     all_warnings.sort_by(|a, b| {
@@ -93,25 +118,35 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
     });
 
     let has_issues = !all_warnings.is_empty();
-    let has_warnings = all_warnings
-        .iter()
-        .any(|w| matches!(w.severity, Severity::Warning | Severity::Error));
-    let has_errors = all_warnings.iter().any(|w| w.severity == Severity::Error);
+    let counts = IssueCounts::of(&all_warnings);
 
     // Apply fixes if requested
     if args.fix_mode != crate::FixMode::Check {
         if has_issues {
             let mut fixed_content = content.clone();
             let file_path = args.stdin_filename.as_ref().map(std::path::Path::new);
-            let _warnings_fixed = file_processor::apply_fixes_coordinated(
-                rules,
-                &all_warnings,
-                &mut fixed_content,
-                quiet,
-                silent,
-                config,
-                file_path,
-            );
+            // With --range, only fixes inside those lines are applied and the
+            // rest of the input is passed through unchanged
+            let _warnings_fixed = match args.range {
+                Some(range) => file_processor::apply_fixes_in_lines(
+                    rules,
+                    &all_warnings,
+                    &mut fixed_content,
+                    range.start..=range.end,
+                    silent,
+                    config,
+                    file_path,
+                ),
+                None => file_processor::apply_fixes_coordinated(
+                    rules,
+                    &all_warnings,
+                    &mut fixed_content,
+                    quiet,
+                    silent,
+                    config,
+                    file_path,
+                ),
+            };
 
             // Denormalize back to original line ending before output (I/O boundary)
             let output_content =
@@ -149,7 +184,12 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                 // Batch formats: remaining-only warnings
                 let batch_file_warnings = vec![(display_filename.to_string(), remaining_warnings.clone())];
                 let batch_all_files = vec![display_filename.to_string()];
-                if let Some(output) = output_format.format_batch(&batch_file_warnings, &batch_all_files, 0) {
+                if let Some(output) = output_format.format_batch(
+                    &rule_names.apply_batch(&batch_file_warnings),
+                    &batch_all_files,
+                    0,
+                    &docs_links,
+                ) {
                     fix_writer.writeln(&output).unwrap_or_else(|e| {
                         eprintln!("Error writing output: {e}");
                     });
@@ -161,7 +201,7 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                             for warning in &all_warnings {
                                 let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
                                 let was_fixed = file_processor::is_rule_cli_fixable(rules, config, rule_name)
-                                    && warning.fix.is_some()
+                                    && warning.automatic_fix().is_some()
                                     && !remaining_warnings.iter().any(|w| {
                                         w.line == warning.line
                                             && w.column == warning.column
@@ -182,7 +222,7 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                                     display_filename.blue().underline(),
                                     warning.line.to_string().cyan(),
                                     warning.column.to_string().cyan(),
-                                    format!("[{rule_name:5}]").yellow(),
+                                    format!("[{:5}]", rule_names.name(rule_name)).yellow(),
                                     warning.message,
                                     fix_indicator
                                 )
@@ -198,10 +238,14 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                         }
                         // Other streaming formats: use their formatter with remaining-only
                         _ => {
-                            let formatter = output_format.create_formatter();
-                            let formatted = formatter.format_warnings_with_content(
-                                &remaining_warnings,
+                            let mut formatter = output_format.formatter_with_source(
+                                &docs_links,
+                                args.show_docs_links,
+                                args.show_source,
+                            );
+                            let formatted = formatter.file(
                                 display_filename,
+                                &rule_names.apply(&remaining_warnings),
                                 &fixed_content,
                             );
                             fix_writer.writeln(&formatted).unwrap_or_else(|e| {
@@ -222,17 +266,8 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
             }
 
             if args.fix_mode != crate::FixMode::Format {
-                let remaining_has_warnings = remaining_warnings
-                    .iter()
-                    .any(|w| matches!(w.severity, Severity::Warning | Severity::Error));
-                let remaining_has_errors = remaining_warnings.iter().any(|w| w.severity == Severity::Error);
-                let should_fail = match args.fail_on_mode {
-                    crate::FailOn::Never => false,
-                    crate::FailOn::Error => remaining_has_errors,
-                    crate::FailOn::Warning => remaining_has_warnings,
-                    crate::FailOn::Any => !remaining_warnings.is_empty(),
-                };
-                if should_fail {
+                let remaining = IssueCounts::of(&remaining_warnings);
+                if crate::cli_utils::resolve_exit_policy(args, config).should_fail(&remaining) {
                     exit::violations_found();
                 }
             }
@@ -248,16 +283,21 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
     // emit per-warning lines plus a human-readable summary.
     let batch_file_warnings = vec![(display_filename.to_string(), all_warnings)];
     let batch_all_files = vec![display_filename.to_string()];
-    if let Some(output) = output_format.format_batch(&batch_file_warnings, &batch_all_files, 0) {
+    if let Some(output) = output_format.format_batch(
+        &rule_names.apply_batch(&batch_file_warnings),
+        &batch_all_files,
+        0,
+        &docs_links,
+    ) {
         output_writer.writeln(&output).unwrap_or_else(|e| {
             eprintln!("Error writing output: {e}");
         });
     } else {
         let all_warnings = &batch_file_warnings[0].1;
         // Use formatter for line-by-line output
-        let formatter = output_format.create_formatter();
+        let mut formatter = output_format.formatter_with_source(&docs_links, args.show_docs_links, args.show_source);
         if !all_warnings.is_empty() {
-            let formatted = formatter.format_warnings_with_content(all_warnings, display_filename, &content);
+            let formatted = formatter.file(display_filename, &rule_names.apply(all_warnings), &content);
             output_writer.writeln(&formatted).unwrap_or_else(|e| {
                 eprintln!("Error writing output: {e}");
             });
@@ -281,14 +321,8 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
         }
     }
 
-    // Exit with error code based on --fail-on setting
-    let should_fail = match args.fail_on_mode {
-        crate::FailOn::Never => false,
-        crate::FailOn::Error => has_errors,
-        crate::FailOn::Warning => has_warnings,
-        crate::FailOn::Any => has_issues,
-    };
-    if should_fail {
+    // Exit with error code based on --fail-on/--max-warnings or [ci]
+    if crate::cli_utils::resolve_exit_policy(args, config).should_fail(&counts) {
         exit::violations_found();
     }
 }
@@ -0,0 +1,224 @@
+//! Checking files too large to check whole (`max-file-size`)
+//!
+//! A large file is read a chunk at a time instead of all at once. A chunk ends
+//! at a blank line outside fenced code once it holds [`CHUNK_SIZE`] bytes, so
+//! blocks stay whole, and each chunk is linted on its own by the rules that are
+//! [`Rule::is_block_local`]. Warnings are moved back to their line in the file
+//! and lose their fixes. Rules disabled by an inline comment stay disabled in
+//! the chunks that follow.
+
+use crate::config::{Config, MarkdownFlavor};
+use crate::inline_config::InlineConfig;
+use crate::rule::{LintResult, Rule};
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Size a chunk grows to before it ends at the next blank line
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+/// Chunks end at the next blank line past this many times [`CHUNK_SIZE`], even
+/// inside what looks like an unclosed code fence
+const MAX_CHUNK_FACTOR: usize = 8;
+
+/// A run of whole lines from a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    /// 1-indexed line of the file the chunk starts at
+    pub first_line: usize,
+    /// The lines, with `\n` line endings
+    pub content: String,
+}
+
+/// Splits a reader into [`Chunk`]s at blank lines outside fenced code
+pub struct Chunks<R> {
+    reader: R,
+    target: usize,
+    next_line: usize,
+    /// Marker character and length of the open code fence
+    fence: Option<(char, usize)>,
+    done: bool,
+}
+
+impl<R: BufRead> Chunks<R> {
+    /// Split `reader` into chunks of about `target` bytes
+    pub fn new(reader: R, target: usize) -> Self {
+        Self {
+            reader,
+            target,
+            next_line: 1,
+            fence: None,
+            done: false,
+        }
+    }
+
+    fn track_fence(&mut self, line: &str) {
+        let trimmed = line.trim_start();
+        let Some(marker) = trimmed.chars().next().filter(|c| matches!(c, '`' | '~')) else {
+            return;
+        };
+        let len = trimmed.chars().take_while(|&c| c == marker).count();
+        if len < 3 {
+            return;
+        }
+        match self.fence {
+            Some((open, open_len)) => {
+                if marker == open && len >= open_len && trimmed[len..].trim().is_empty() {
+                    self.fence = None;
+                }
+            }
+            None => self.fence = Some((marker, len)),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Chunks<R> {
+    type Item = io::Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let first_line = self.next_line;
+        let mut content = String::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+            self.next_line += 1;
+            if line.ends_with("\r\n") {
+                line.truncate(line.len() - 2);
+                line.push('\n');
+            }
+            self.track_fence(&line);
+            content.push_str(&line);
+
+            let full = content.len() >= self.target
+                && (self.fence.is_none() || content.len() >= self.target * MAX_CHUNK_FACTOR);
+            if full && line.trim().is_empty() {
+                self.fence = None;
+                break;
+            }
+        }
+        (!content.is_empty()).then_some(Ok(Chunk { first_line, content }))
+    }
+}
+
+/// Lint the Markdown read from `reader` a chunk at a time
+///
+/// Only the rules that are [`Rule::is_block_local`] run, and warnings carry no
+/// fixes.
+pub fn lint_chunked<R: BufRead>(
+    reader: R,
+    rules: &[Box<dyn Rule>],
+    flavor: MarkdownFlavor,
+    source_file: Option<&Path>,
+    config: Option<&Config>,
+) -> LintResult {
+    lint_chunks(Chunks::new(reader, CHUNK_SIZE), rules, flavor, source_file, config)
+}
+
+fn lint_chunks<R: BufRead>(
+    chunks: Chunks<R>,
+    rules: &[Box<dyn Rule>],
+    flavor: MarkdownFlavor,
+    source_file: Option<&Path>,
+    config: Option<&Config>,
+) -> LintResult {
+    let rules: Vec<Box<dyn Rule>> = rules
+        .iter()
+        .filter(|rule| rule.is_block_local())
+        .map(|rule| dyn_clone::clone_box(&**rule))
+        .collect();
+    let mut warnings = Vec::new();
+    // Rules an inline comment left disabled at the end of the previous chunk
+    let mut disabled: Vec<&'static str> = Vec::new();
+
+    for chunk in chunks {
+        let Chunk { first_line, content } = chunk?;
+        let (content, prefix_lines) = if disabled.is_empty() {
+            (content, 0)
+        } else {
+            (format!("<!-- rumdl-disable {} -->\n{content}", disabled.join(" ")), 1)
+        };
+
+        for mut warning in crate::lint(
+            &content,
+            &rules,
+            false,
+            flavor,
+            source_file.map(Path::to_path_buf),
+            config,
+        )? {
+            warning.line = warning.line + first_line - 1 - prefix_lines;
+            if warning.end_line > 0 {
+                warning.end_line = warning.end_line + first_line - 1 - prefix_lines;
+            }
+            warning.fix = None;
+            warnings.push(warning);
+        }
+
+        let inline_config = InlineConfig::from_content(&content);
+        let after_last_line = content.lines().count() + 1;
+        disabled = rules
+            .iter()
+            .map(|rule| rule.name())
+            .filter(|name| inline_config.is_rule_disabled(name, after_last_line))
+            .collect();
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(content: &str, target: usize) -> Vec<Chunk> {
+        Chunks::new(content.as_bytes(), target)
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn chunks_end_at_blank_lines_outside_fences() {
+        let content = "# One\n\nText\r\n\n```\na\n\nb\n```\n\nEnd\n";
+        let parts = chunks(content, 1);
+        let starts: Vec<_> = parts.iter().map(|c| c.first_line).collect();
+        assert_eq!(starts, vec![1, 3, 5, 11]);
+        assert_eq!(parts[1].content, "Text\n\n");
+        assert_eq!(parts[2].content, "```\na\n\nb\n```\n\n");
+        assert_eq!(
+            parts.iter().map(|c| c.content.as_str()).collect::<String>(),
+            content.replace("\r\n", "\n")
+        );
+    }
+
+    #[test]
+    fn chunked_lint_reports_file_lines_and_carries_disables() {
+        let rules = crate::rules::all_rules(&Config::default());
+        let content = "# Title\n\nTrailing   \n\nMore text\n\n<!-- rumdl-disable MD009 -->\n\nTrailing   \n\nLast   \n";
+        let warnings = lint_chunks(
+            Chunks::new(content.as_bytes(), 1),
+            &rules,
+            MarkdownFlavor::Standard,
+            None,
+            None,
+        )
+        .unwrap();
+
+        // MD009 stays disabled in the chunks after the comment; MD047 is not
+        // block-local and does not run
+        let lines: Vec<_> = warnings.iter().map(|w| (w.line, w.rule_name.as_deref())).collect();
+        assert_eq!(lines, vec![(3, Some("MD009"))]);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+    }
+}
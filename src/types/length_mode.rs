@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use unicode_width::UnicodeWidthStr;
+
+/// How the length of a line is measured by length-sensitive rules
+/// (MD013, MD060 and reflow)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum LengthMode {
+    /// Count Unicode characters (grapheme clusters)
+    /// Use this only if you need backward compatibility with character-based counting
+    #[serde(alias = "chars", alias = "characters")]
+    Chars,
+    /// Count visual display width (CJK characters = 2 columns, emoji = 2, etc.) - default
+    /// This is semantically correct: line-length = 80 means "80 columns on screen"
+    #[default]
+    #[serde(alias = "display", alias = "visual_width")]
+    Visual,
+    /// Count raw bytes (legacy mode, not recommended for Unicode text)
+    Bytes,
+}
+
+impl LengthMode {
+    /// Length of `s` in this mode. `ambiguous` decides the display width of
+    /// East Asian ambiguous-width characters in visual mode.
+    pub fn measure(self, s: &str, ambiguous: AmbiguousWidth) -> usize {
+        match self {
+            LengthMode::Chars => s.chars().count(),
+            LengthMode::Visual => ambiguous.width(s),
+            LengthMode::Bytes => s.len(),
+        }
+    }
+}
+
+impl fmt::Display for LengthMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LengthMode::Chars => "chars",
+            LengthMode::Visual => "visual",
+            LengthMode::Bytes => "bytes",
+        })
+    }
+}
+
+impl FromStr for LengthMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "chars" | "characters" => Ok(LengthMode::Chars),
+            "visual" | "display" | "visual-width" | "visual_width" => Ok(LengthMode::Visual),
+            "bytes" => Ok(LengthMode::Bytes),
+            _ => Err(format!("unknown length-mode '{s}' (expected chars, visual or bytes)")),
+        }
+    }
+}
+
+/// Display width of characters whose East Asian Width is "ambiguous"
+/// (`±`, `…`, `°`, `×`, box drawing, circled digits, ...)
+///
+/// Western terminals and fonts draw them one column wide, CJK ones two.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AmbiguousWidth {
+    /// One column (default)
+    #[default]
+    Narrow,
+    /// Two columns, as in CJK environments
+    Wide,
+}
+
+impl AmbiguousWidth {
+    /// Display width of `s` under this policy
+    pub fn width(self, s: &str) -> usize {
+        match self {
+            AmbiguousWidth::Narrow => s.width(),
+            AmbiguousWidth::Wide => s.width_cjk(),
+        }
+    }
+}
+
+impl fmt::Display for AmbiguousWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AmbiguousWidth::Narrow => "narrow",
+            AmbiguousWidth::Wide => "wide",
+        })
+    }
+}
+
+impl FromStr for AmbiguousWidth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "narrow" => Ok(AmbiguousWidth::Narrow),
+            "wide" => Ok(AmbiguousWidth::Wide),
+            _ => Err(format!("unknown ambiguous-width '{s}' (expected narrow or wide)")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_by_mode() {
+        let s = "日本語 ok";
+        assert_eq!(LengthMode::Chars.measure(s, AmbiguousWidth::Narrow), 6);
+        assert_eq!(LengthMode::Visual.measure(s, AmbiguousWidth::Narrow), 9);
+        assert_eq!(LengthMode::Bytes.measure(s, AmbiguousWidth::Narrow), 12);
+    }
+
+    #[test]
+    fn test_ambiguous_width_only_affects_visual_mode() {
+        let s = "±…①";
+        assert_eq!(LengthMode::Visual.measure(s, AmbiguousWidth::Narrow), 3);
+        assert_eq!(LengthMode::Visual.measure(s, AmbiguousWidth::Wide), 6);
+        assert_eq!(LengthMode::Chars.measure(s, AmbiguousWidth::Wide), 3);
+        // Wide and narrow characters are unaffected
+        assert_eq!(AmbiguousWidth::Wide.width("漢a"), 3);
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!("characters".parse::<LengthMode>(), Ok(LengthMode::Chars));
+        assert_eq!("Visual".parse::<LengthMode>(), Ok(LengthMode::Visual));
+        assert_eq!("display".parse::<LengthMode>(), Ok(LengthMode::Visual));
+        assert!("columns".parse::<LengthMode>().is_err());
+        assert_eq!("wide".parse::<AmbiguousWidth>(), Ok(AmbiguousWidth::Wide));
+        assert!("double".parse::<AmbiguousWidth>().is_err());
+    }
+}
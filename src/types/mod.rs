@@ -6,6 +6,7 @@
 mod br_spaces;
 mod heading_level;
 mod indent_size;
+mod length_mode;
 mod line_length;
 mod non_negative_usize;
 mod ol_align_column;
@@ -14,6 +15,7 @@ mod positive_usize;
 pub use br_spaces::{BrSpaces, BrSpacesError};
 pub use heading_level::{HeadingLevel, HeadingLevelError};
 pub use indent_size::{IndentSize, IndentSizeError};
+pub use length_mode::{AmbiguousWidth, LengthMode};
 pub use line_length::LineLength;
 pub use non_negative_usize::{NonNegativeUsize, NonNegativeUsizeError};
 pub use ol_align_column::{OlAlignColumn, OlAlignColumnError};
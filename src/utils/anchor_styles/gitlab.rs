@@ -0,0 +1,105 @@
+//! GitLab anchor generation
+//!
+//! This module implements the heading ID algorithm of GitLab Flavored
+//! Markdown, as done by GitLab's table of contents filter.
+//!
+//! Algorithm:
+//! 1. Take the rendered heading text, strip it and lowercase it
+//! 2. Remove every character that is not a word character, hyphen, or space
+//! 3. Convert each space to a hyphen
+//! 4. Collapse runs of hyphens into one
+//!
+//! Unlike GitHub, consecutive hyphens are collapsed and Unicode letters are
+//! kept as they are.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+use super::common::{MAX_INPUT_LENGTH, truncate_at_char_boundary};
+
+// HTML comments and tags never reach the rendered heading text
+static HTML_COMMENT_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<!--.*?-->").unwrap());
+static HTML_TAG_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)</?[a-z][^>]*>").unwrap());
+
+// Inline markup whose text is kept: code spans, images, links and underscore
+// emphasis (asterisks are removed as punctuation anyway)
+static CODE_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`+([^`]*?)`+").unwrap());
+static LINK_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"!?\[([^\]]*)\]\([^)]*\)").unwrap());
+static EMPHASIS_UNDERSCORE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b_{1,2}([^_\s][^_]*?)_{1,2}\b").unwrap());
+
+// GitLab: PUNCTUATION_REGEXP = /[^\p{Word}\- ]/u
+static PUNCTUATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[^\w\- ]").unwrap());
+static HYPHEN_RUNS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"-{2,}").unwrap());
+
+/// Generate a GitLab style anchor fragment from heading text.
+///
+/// # Examples
+/// ```
+/// use rumdl_lib::utils::anchor_styles::gitlab;
+///
+/// assert_eq!(gitlab::heading_to_fragment("Hello World"), "hello-world");
+/// assert_eq!(gitlab::heading_to_fragment("API -- Overview"), "api-overview");
+/// assert_eq!(gitlab::heading_to_fragment("Überblick"), "überblick");
+/// ```
+pub fn heading_to_fragment(heading: &str) -> String {
+    let input = truncate_at_char_boundary(heading, MAX_INPUT_LENGTH);
+
+    let input = HTML_COMMENT_PATTERN.replace_all(input, "");
+    let input = CODE_PATTERN.replace_all(&input, "$1");
+    let input = LINK_PATTERN.replace_all(&input, "$1");
+    let input = HTML_TAG_PATTERN.replace_all(&input, "");
+    let input = EMPHASIS_UNDERSCORE.replace_all(&input, "$1");
+
+    let lowered = input.trim().to_lowercase();
+    let cleaned = PUNCTUATION.replace_all(&lowered, "");
+    let hyphenated = cleaned.replace(' ', "-");
+    HYPHEN_RUNS.replace_all(&hyphenated, "-").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(heading_to_fragment("Hello World"), "hello-world");
+        assert_eq!(heading_to_fragment("Step 1: Setup"), "step-1-setup");
+    }
+
+    #[test]
+    fn test_punctuation_removed_and_hyphens_collapsed() {
+        assert_eq!(heading_to_fragment("This -- is a heading!"), "this-is-a-heading");
+        assert_eq!(heading_to_fragment("A & B"), "a-b");
+        assert_eq!(heading_to_fragment("C++ Guide"), "c-guide");
+        assert_eq!(heading_to_fragment("well-known"), "well-known");
+    }
+
+    #[test]
+    fn test_underscores_and_unicode_kept() {
+        assert_eq!(heading_to_fragment("snake_case_name"), "snake_case_name");
+        assert_eq!(heading_to_fragment("Café résumé"), "café-résumé");
+        assert_eq!(heading_to_fragment("日本語 Test"), "日本語-test");
+    }
+
+    #[test]
+    fn test_inline_markup_uses_rendered_text() {
+        assert_eq!(heading_to_fragment("Using `cargo run`"), "using-cargo-run");
+        assert_eq!(
+            heading_to_fragment("See [the docs](https://example.com)"),
+            "see-the-docs"
+        );
+        assert_eq!(heading_to_fragment("_Important_ **note**"), "important-note");
+        assert_eq!(heading_to_fragment("Title <!-- hidden -->"), "title");
+    }
+
+    #[test]
+    fn test_emoji_removed() {
+        assert_eq!(heading_to_fragment("🚀 Launch"), "-launch");
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(heading_to_fragment(""), "");
+        assert_eq!(heading_to_fragment("   "), "");
+    }
+}
@@ -4,8 +4,10 @@
 //! the behavior of various Markdown platforms:
 //!
 //! - **GitHub**: GitHub.com's official anchor generation algorithm
+//! - **GitLab**: GitLab Flavored Markdown heading IDs
 //! - **KramdownGfm**: Kramdown with GFM input (used by Jekyll/GitHub Pages)
 //! - **Kramdown**: Pure kramdown without GFM extensions
+//! - **PythonMarkdown**: Python-Markdown's `toc` extension (used by MkDocs)
 //!
 //! Each style is implemented in a separate module with comprehensive tests
 //! verified against the official tools/platforms.
@@ -14,6 +16,7 @@
 
 pub mod common;
 pub mod github;
+pub mod gitlab;
 pub mod kramdown;
 pub mod kramdown_gfm; // Renamed from jekyll for clarity
 pub mod python_markdown;
@@ -21,7 +24,7 @@ pub mod python_markdown;
 use serde::{Deserialize, Serialize};
 
 /// Anchor generation style for heading fragments
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Default)]
 pub enum AnchorStyle {
@@ -29,6 +32,9 @@ pub enum AnchorStyle {
     #[default]
     #[serde(rename = "github")]
     GitHub,
+    /// GitLab style: keeps Unicode letters, collapses consecutive hyphens
+    #[serde(rename = "gitlab")]
+    GitLab,
     /// Kramdown with GFM input: matches Jekyll/GitHub Pages behavior
     /// Accepts "kramdown-gfm", "kramdown_gfm", and "jekyll" (for backward compatibility)
     #[serde(rename = "kramdown-gfm", alias = "kramdown_gfm", alias = "jekyll")]
@@ -37,7 +43,12 @@ pub enum AnchorStyle {
     #[serde(rename = "kramdown")]
     Kramdown,
     /// Python-Markdown style: used by MkDocs (NFKD → ASCII, collapse separators)
-    #[serde(rename = "python-markdown", alias = "python_markdown", alias = "mkdocs")]
+    #[serde(
+        rename = "python-markdown",
+        alias = "python_markdown",
+        alias = "mkdocs",
+        alias = "mkdocs-python-markdown"
+    )]
     PythonMarkdown,
 }
 
@@ -46,6 +57,7 @@ impl AnchorStyle {
     pub fn generate_fragment(&self, heading: &str) -> String {
         match self {
             AnchorStyle::GitHub => github::heading_to_fragment(heading),
+            AnchorStyle::GitLab => gitlab::heading_to_fragment(heading),
             AnchorStyle::KramdownGfm => kramdown_gfm::heading_to_fragment(heading),
             AnchorStyle::Kramdown => kramdown::heading_to_fragment(heading),
             AnchorStyle::PythonMarkdown => python_markdown::heading_to_fragment(heading),
@@ -61,6 +73,7 @@ mod tests {
     fn test_anchor_style_serde() {
         // Test serialization (uses primary names)
         assert_eq!(serde_json::to_string(&AnchorStyle::GitHub).unwrap(), "\"github\"");
+        assert_eq!(serde_json::to_string(&AnchorStyle::GitLab).unwrap(), "\"gitlab\"");
         assert_eq!(
             serde_json::to_string(&AnchorStyle::KramdownGfm).unwrap(),
             "\"kramdown-gfm\""
@@ -108,6 +121,10 @@ mod tests {
             serde_json::from_str::<AnchorStyle>("\"mkdocs\"").unwrap(),
             AnchorStyle::PythonMarkdown
         );
+        assert_eq!(
+            serde_json::from_str::<AnchorStyle>("\"mkdocs-python-markdown\"").unwrap(),
+            AnchorStyle::PythonMarkdown
+        );
     }
 
     #[test]
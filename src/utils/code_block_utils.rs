@@ -289,7 +289,7 @@ impl CodeBlockUtils {
                 if let Some(list_item) = &line_info.list_item {
                     // Calculate minimum continuation indent for this list item
                     return if list_item.is_ordered {
-                        list_item.marker_column + list_item.marker.len() + 1 // +1 for space after marker
+                        list_item.marker_column + list_item.marker_len() + 1 // +1 for space after marker
                     } else {
                         list_item.marker_column + 2 // Unordered lists need marker + space (min 2)
                     };
@@ -42,7 +42,7 @@ pub fn apply_warning_fixes(content: &str, warnings: &[LintWarning]) -> Result<St
     let mut fixes: Vec<(usize, &Fix)> = warnings
         .iter()
         .enumerate()
-        .filter_map(|(i, w)| w.fix.as_ref().map(|fix| (i, fix)))
+        .filter_map(|(i, w)| w.automatic_fix().map(|fix| (i, fix)))
         .flat_map(|(i, fix)| {
             // A logical fix may carry additional edits at separate ranges
             // (e.g. MD054 ref-emit fixes that rewrite a link in place AND
@@ -137,6 +137,10 @@ pub fn apply_warning_fixes(content: &str, warnings: &[LintWarning]) -> Result<St
         b.range.end.cmp(&a.range.end)
     });
 
+    // Edits that convert line endings on purpose (MD107) must not be undone
+    // by the normalization below
+    let converts_line_endings = applicable.iter().any(|edit| is_line_ending_edit(content, edit));
+
     let mut result = content.to_string();
 
     // Track the lowest byte offset touched by an already-applied fix.
@@ -181,10 +185,26 @@ pub fn apply_warning_fixes(content: &str, warnings: &[LintWarning]) -> Result<St
         min_applied_start = edit.range.start;
     }
 
+    if converts_line_endings {
+        return Ok(result);
+    }
+
     // Ensure line endings are consistent with the original document
     Ok(ensure_consistent_line_endings(content, &result))
 }
 
+/// Whether the edit turns a CRLF line ending into LF or an LF into CRLF, by
+/// removing or inserting the `\r` before a `\n`
+fn is_line_ending_edit(content: &str, edit: &ApplicableEdit) -> bool {
+    let before_newline = content.as_bytes().get(edit.range.end) == Some(&b'\n');
+    before_newline
+        && match content.get(edit.range.clone()) {
+            Some("\r") => edit.replacement.is_empty(),
+            Some("") => edit.replacement == "\r",
+            _ => false,
+        }
+}
+
 /// One physical edit ready to apply. Either passes through a single `Fix`'s
 /// replacement borrow or holds the concatenation of several same-offset
 /// zero-width inserts.
@@ -249,6 +269,119 @@ pub fn validate_fix_range(content: &str, fix: &Fix) -> Result<(), String> {
     Ok(())
 }
 
+/// Compute the replacements that turn `original` into `modified`.
+///
+/// Lines are matched first, then each changed block is narrowed to the bytes
+/// that actually differ, so an edit covers little more than what a fix touched.
+pub fn plan_edits(original: &str, modified: &str) -> Vec<(std::ops::Range<usize>, String)> {
+    if original == modified {
+        return Vec::new();
+    }
+
+    let old_lines: Vec<&str> = original.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = modified.split_inclusive('\n').collect();
+    let line_starts = |lines: &[&str]| -> Vec<usize> {
+        std::iter::once(0)
+            .chain(lines.iter().scan(0, |offset, line| {
+                *offset += line.len();
+                Some(*offset)
+            }))
+            .collect()
+    };
+    let old_starts = line_starts(&old_lines);
+    let new_starts = line_starts(&new_lines);
+
+    let prefix = old_lines.iter().zip(&new_lines).take_while(|(a, b)| a == b).count();
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_changed = &old_lines[prefix..old_lines.len() - suffix];
+    let new_changed = &new_lines[prefix..new_lines.len() - suffix];
+
+    let mut edits = Vec::new();
+    let (mut old_idx, mut new_idx) = (0, 0);
+    let matches = common_lines(old_changed, new_changed);
+    for (old_match, new_match) in matches
+        .into_iter()
+        .chain(std::iter::once((old_changed.len(), new_changed.len())))
+    {
+        if old_match > old_idx || new_match > new_idx {
+            let range = old_starts[prefix + old_idx]..old_starts[prefix + old_match];
+            let replacement = &modified[new_starts[prefix + new_idx]..new_starts[prefix + new_match]];
+            edits.push(narrow_edit(original, range, replacement));
+        }
+        old_idx = old_match + 1;
+        new_idx = new_match + 1;
+    }
+    edits
+}
+
+/// Index pairs of the lines kept by a longest common subsequence, in order.
+/// Blocks too large for the quadratic table are treated as one replacement.
+fn common_lines(old: &[&str], new: &[&str]) -> Vec<(usize, usize)> {
+    const MAX_TABLE_CELLS: usize = 4_000_000;
+
+    let (rows, cols) = (old.len(), new.len());
+    if rows == 0 || cols == 0 || (rows + 1) * (cols + 1) > MAX_TABLE_CELLS {
+        return Vec::new();
+    }
+
+    // table[i][j] is the LCS length of old[i..] and new[j..]
+    let width = cols + 1;
+    let mut table = vec![0u32; (rows + 1) * width];
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            table[i * width + j] = if old[i] == new[j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut matches = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < rows && j < cols {
+        if old[i] == new[j] {
+            matches.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+/// Drop the characters a replacement shares with the start and end of the
+/// text it replaces
+fn narrow_edit(original: &str, range: std::ops::Range<usize>, replacement: &str) -> (std::ops::Range<usize>, String) {
+    let old = &original[range.clone()];
+    let prefix: usize = old
+        .chars()
+        .zip(replacement.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let (old, new) = (&old[prefix..], &replacement[prefix..]);
+    let suffix: usize = old
+        .chars()
+        .rev()
+        .zip(new.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    (
+        range.start + prefix..range.end - suffix,
+        new[..new.len() - suffix].to_string(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -988,4 +1121,46 @@ mod tests {
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].line, 5);
     }
+
+    fn apply_edits(original: &str, edits: &[(std::ops::Range<usize>, String)]) -> String {
+        let mut result = original.to_string();
+        for (range, replacement) in edits.iter().rev() {
+            result.replace_range(range.clone(), replacement);
+        }
+        result
+    }
+
+    #[test]
+    fn test_plan_edits_narrows_to_changed_bytes() {
+        let original = "# Title\n\nSome text   \n* item\n";
+        let modified = "# Title\n\nSome text\n- item\n";
+        let edits = plan_edits(original, modified);
+        assert_eq!(edits, vec![(18..23, "\n-".to_string())]);
+        assert_eq!(apply_edits(original, &edits), modified);
+    }
+
+    #[test]
+    fn test_plan_edits_inserted_and_removed_lines() {
+        let original = "# A\ntext\n\n\n## B\nmore\n";
+        let modified = "# A\n\ntext\n\n## B\n\nmore\n";
+        let edits = plan_edits(original, modified);
+        assert_eq!(apply_edits(original, &edits), modified);
+        assert_eq!(
+            edits,
+            vec![
+                (4..9, String::new()),
+                (10..10, "text\n".to_string()),
+                (16..16, "\n".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_edits_keeps_char_boundaries() {
+        let original = "Caf\u{e9} \u{e9}t\u{e9}\n";
+        let modified = "Caf\u{e8} \u{e9}t\u{e9}\n";
+        let edits = plan_edits(original, modified);
+        assert_eq!(edits, vec![(3..5, "\u{e8}".to_string())]);
+        assert!(plan_edits(original, original).is_empty());
+    }
 }
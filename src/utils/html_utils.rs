@@ -0,0 +1,202 @@
+//! Lightweight helpers for raw HTML found in Markdown.
+//!
+//! These are not a full HTML parser: they work on a single tag as matched by
+//! the HTML tag scanner in `lint_context`, which is all rules need.
+
+use std::ops::Range;
+
+/// HTML attributes whose value a browser loads or navigates to
+pub const URL_ATTRIBUTES: &[&str] = &[
+    "href",
+    "src",
+    "action",
+    "formaction",
+    "poster",
+    "background",
+    "cite",
+    "data",
+    "xlink:href",
+];
+
+/// An attribute of an HTML start tag
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlAttribute {
+    /// Attribute name, lowercased
+    pub name: String,
+    /// Raw value without quotes; `None` for boolean attributes such as `hidden`
+    pub value: Option<String>,
+    /// Byte range of the whole attribute (`name="value"`) within the tag text
+    pub range: Range<usize>,
+}
+
+/// Parse the attributes of an HTML start tag such as `<a href="x" hidden>`.
+///
+/// Quoted values may contain whitespace, `>` and `/`; an unterminated quote
+/// runs to the end of the tag. Closing tags and tags without attributes yield
+/// an empty list.
+pub fn parse_attributes(tag: &str) -> Vec<HtmlAttribute> {
+    let mut attrs = Vec::new();
+    let bytes = tag.as_bytes();
+    let len = bytes.len();
+    if !tag.starts_with('<') || tag.starts_with("</") {
+        return attrs;
+    }
+
+    // Skip `<` and the tag name
+    let mut pos = 1;
+    while pos < len && !bytes[pos].is_ascii_whitespace() && bytes[pos] != b'>' && bytes[pos] != b'/' {
+        pos += 1;
+    }
+
+    loop {
+        while pos < len && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'/') {
+            pos += 1;
+        }
+        if pos >= len || bytes[pos] == b'>' {
+            break;
+        }
+
+        let start = pos;
+        while pos < len && !bytes[pos].is_ascii_whitespace() && !matches!(bytes[pos], b'=' | b'>' | b'/') {
+            pos += 1;
+        }
+        let name = tag[start..pos].to_ascii_lowercase();
+        let name_end = pos;
+
+        let mut after_name = pos;
+        while after_name < len && bytes[after_name].is_ascii_whitespace() {
+            after_name += 1;
+        }
+        if after_name >= len || bytes[after_name] != b'=' {
+            attrs.push(HtmlAttribute {
+                name,
+                value: None,
+                range: start..name_end,
+            });
+            continue;
+        }
+
+        pos = after_name + 1;
+        while pos < len && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        let value = match bytes.get(pos) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let value_start = pos + 1;
+                let value_end = tag[value_start..]
+                    .find(quote as char)
+                    .map_or(len, |offset| value_start + offset);
+                pos = (value_end + 1).min(len);
+                &tag[value_start..value_end]
+            }
+            _ => {
+                let value_start = pos;
+                while pos < len && !bytes[pos].is_ascii_whitespace() && bytes[pos] != b'>' {
+                    pos += 1;
+                }
+                &tag[value_start..pos]
+            }
+        };
+        attrs.push(HtmlAttribute {
+            name,
+            value: Some(value.to_string()),
+            range: start..pos,
+        });
+    }
+
+    attrs
+}
+
+/// Decode the character references a browser resolves in attribute values:
+/// numeric references (`&#58;`, `&#x3A;`) and the named references that can
+/// hide a URL scheme (`&colon;`, `&Tab;`, `&NewLine;`, `&amp;` and friends).
+///
+/// Unknown or malformed references are kept as written.
+pub fn decode_char_references(value: &str) -> String {
+    const NAMED: &[(&str, char)] = &[
+        ("colon", ':'),
+        ("tab", '\t'),
+        ("newline", '\n'),
+        ("amp", '&'),
+        ("lt", '<'),
+        ("gt", '>'),
+        ("quot", '"'),
+        ("apos", '\''),
+        ("sol", '/'),
+        ("lpar", '('),
+        ("rpar", ')'),
+    ];
+
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let body_end = rest[1..]
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+            .map_or(rest.len(), |i| i + 1);
+        let body = &rest[1..body_end];
+        let decoded = if let Some(number) = body.strip_prefix('#') {
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => number.parse::<u32>().ok(),
+            };
+            code.and_then(char::from_u32)
+        } else {
+            NAMED
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(body))
+                .map(|&(_, c)| c)
+        };
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                // The trailing `;` is optional for numeric references
+                rest = rest[body_end..].strip_prefix(';').unwrap_or(&rest[body_end..]);
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_attributes() {
+        let tag = r#"<a href="x y" class='c' hidden data-x=1 onclick = "go()">"#;
+        let attrs = parse_attributes(tag);
+        let pairs: Vec<(&str, Option<&str>)> = attrs.iter().map(|a| (a.name.as_str(), a.value.as_deref())).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("href", Some("x y")),
+                ("class", Some("c")),
+                ("hidden", None),
+                ("data-x", Some("1")),
+                ("onclick", Some("go()")),
+            ]
+        );
+        assert_eq!(&tag[attrs[0].range.clone()], r#"href="x y""#);
+        assert_eq!(&tag[attrs[4].range.clone()], r#"onclick = "go()""#);
+
+        assert!(parse_attributes("<br/>").is_empty());
+        assert!(parse_attributes("</a>").is_empty());
+        let attrs = parse_attributes("<img\n  SRC=a.png />");
+        assert_eq!(attrs[0].name, "src");
+        assert_eq!(attrs[0].value.as_deref(), Some("a.png"));
+    }
+
+    #[test]
+    fn test_decode_char_references() {
+        assert_eq!(decode_char_references("javascript&#58;x"), "javascript:x");
+        assert_eq!(decode_char_references("&#x6A;ava&Tab;script&colon;x"), "java\tscript:x");
+        assert_eq!(decode_char_references("a&amp;b &unknown; &"), "a&b &unknown; &");
+    }
+}
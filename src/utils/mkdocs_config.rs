@@ -87,6 +87,62 @@ pub fn resolve_docs_dir(start_path: &Path) -> Option<PathBuf> {
     Some(docs_dir)
 }
 
+/// Cache: canonicalized mkdocs.yml path -> resolved snippet base paths (absolute)
+static SNIPPET_BASE_PATHS_CACHE: LazyLock<Mutex<HashMap<PathBuf, Vec<PathBuf>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Extract the `base_path` option of `pymdownx.snippets` from the
+/// `markdown_extensions` section, which is either a list of names and
+/// single-key maps or a plain map.
+fn snippet_base_path_option(config: &serde_yaml::Value) -> Option<Vec<String>> {
+    let extensions = config.get("markdown_extensions")?;
+    let options = match extensions {
+        serde_yaml::Value::Sequence(items) => items.iter().find_map(|item| item.get("pymdownx.snippets")),
+        serde_yaml::Value::Mapping(_) => extensions.get("pymdownx.snippets"),
+        _ => None,
+    }?;
+    match options.get("base_path")? {
+        serde_yaml::Value::String(path) => Some(vec![path.clone()]),
+        serde_yaml::Value::Sequence(paths) => Some(
+            paths
+                .iter()
+                .filter_map(|p| p.as_str().map(ToString::to_string))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Resolve the directories that `pymdownx.snippets` resolves include paths
+/// against, by finding and parsing mkdocs.yml.
+///
+/// Relative `base_path` entries are taken relative to the mkdocs.yml location,
+/// where `mkdocs build` runs. Without a `base_path` option the extension's
+/// default (`"."`) applies. Returns None if no mkdocs.yml is found.
+pub fn resolve_snippet_base_paths(start_path: &Path) -> Option<Vec<PathBuf>> {
+    let mkdocs_path = find_mkdocs_yml(start_path)?;
+
+    if let Ok(cache) = SNIPPET_BASE_PATHS_CACHE.lock()
+        && let Some(base_paths) = cache.get(&mkdocs_path)
+    {
+        return Some(base_paths.clone());
+    }
+
+    let mkdocs_dir = mkdocs_path.parent()?;
+    let option = std::fs::read_to_string(&mkdocs_path)
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+        .and_then(|config| snippet_base_path_option(&config))
+        .unwrap_or_else(|| vec![".".to_string()]);
+    let base_paths: Vec<PathBuf> = option.iter().map(|path| mkdocs_dir.join(path)).collect();
+
+    if let Ok(mut cache) = SNIPPET_BASE_PATHS_CACHE.lock() {
+        cache.insert(mkdocs_path, base_paths.clone());
+    }
+
+    Some(base_paths)
+}
+
 /// Clear the docs_dir cache. Useful for testing.
 #[cfg(test)]
 pub fn clear_docs_dir_cache() {
@@ -101,6 +157,29 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_resolve_snippet_base_paths() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        fs::write(
+            root.join("mkdocs.yml"),
+            "site_name: test\nmarkdown_extensions:\n  - admonition\n  - pymdownx.snippets:\n      base_path: [docs, includes]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            resolve_snippet_base_paths(&root).unwrap(),
+            vec![root.join("docs"), root.join("includes")]
+        );
+
+        let other = tempdir().unwrap();
+        let other_root = other.path().canonicalize().unwrap();
+        fs::write(other_root.join("mkdocs.yml"), "site_name: test\n").unwrap();
+        assert_eq!(
+            resolve_snippet_base_paths(&other_root).unwrap(),
+            vec![other_root.join(".")]
+        );
+    }
+
     #[test]
     fn test_find_mkdocs_yml() {
         let temp_dir = tempdir().unwrap();
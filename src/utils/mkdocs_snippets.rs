@@ -10,6 +10,7 @@ use regex::Regex;
 /// - `<!-- --8<-- [start:section] -->` - Start marker for section
 /// - `<!-- --8<-- [end:section] -->` - End marker for section
 ///
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 /// Pattern to match valid snippet markers: -{1,}8<-{1,}
@@ -41,6 +42,16 @@ static INVALID_ASYMMETRIC: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// Pattern to match a single-line include: `--8<-- "path"` with an optional
+/// `;` escape prefix (group 1) and the quoted path (group 3)
+static SNIPPET_INCLUDE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^[ \t]*(;*)-+8<-+[ \t]+(["'])(.+?)["'][ \t]*$"#).unwrap());
+
+/// Pattern to split a line selector (`:1:3`, `:4:,-2`) or section selector
+/// (`:name`) off a snippet path
+static SNIPPET_SELECTOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(.+?)(?::-?\d*(?::-?\d*)?(?:,-?\d*(?::-?\d*)?)*|:[A-Za-z_][\w-]*)$").unwrap());
+
 /// Check if a line contains MkDocs snippet syntax
 pub fn is_snippet_marker(line: &str) -> bool {
     // PyMdown Snippets spec says: -{1,}8<-{1,} (symmetric dashes)
@@ -293,10 +304,142 @@ pub fn is_within_snippet_block(content: &str, position: usize) -> bool {
     false
 }
 
+/// A file included through the Snippets extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetInclude {
+    /// Path as written, without any line or section selector
+    pub path: String,
+    /// 1-indexed line holding the path
+    pub line: usize,
+}
+
+/// Strip a line or section selector from a snippet path: `a.md:2:5` and
+/// `a.md:intro` both name `a.md`.
+fn strip_snippet_selector(path: &str) -> &str {
+    SNIPPET_SELECTOR
+        .captures(path)
+        .and_then(|caps| caps.get(1))
+        .map_or(path, |m| m.as_str())
+}
+
+/// Extract the local files included by snippet markers.
+///
+/// Handles single-line includes (`--8<-- "file.md"`) and multi-line blocks
+/// delimited by bare markers. Escaped markers (`;--8<--`), commented block
+/// entries (`;file.md`), and URL includes are skipped. Snippets are expanded
+/// before Markdown is parsed, so markers inside code blocks count too.
+pub fn extract_snippet_includes(content: &str) -> Vec<SnippetInclude> {
+    let mut includes = Vec::new();
+    let mut in_block = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        if is_snippet_block_delimiter(line) {
+            if !line.trim_start().starts_with(';') {
+                in_block = !in_block;
+            }
+            continue;
+        }
+
+        let path = if in_block {
+            let entry = line.trim();
+            (!entry.is_empty() && !entry.starts_with(';')).then_some(entry)
+        } else {
+            SNIPPET_INCLUDE
+                .captures(line)
+                .filter(|caps| caps[1].is_empty())
+                .and_then(|caps| caps.get(3))
+                .map(|m| m.as_str())
+        };
+
+        if let Some(path) = path
+            && !path.contains("://")
+        {
+            includes.push(SnippetInclude {
+                path: strip_snippet_selector(path).to_string(),
+                line: idx + 1,
+            });
+        }
+    }
+
+    includes
+}
+
+/// Resolve a snippet path written in `including_file` to an existing file.
+///
+/// Tries each `pymdownx.snippets` base path from mkdocs.yml first, then the
+/// including file's directory. Returns the canonicalized path.
+pub fn resolve_snippet_path(including_file: &Path, snippet: &str) -> Option<PathBuf> {
+    let base_paths = crate::utils::mkdocs_config::resolve_snippet_base_paths(including_file).unwrap_or_default();
+    base_paths
+        .iter()
+        .map(PathBuf::as_path)
+        .chain(including_file.parent())
+        .map(|base| base.join(snippet))
+        .find(|candidate| candidate.is_file())
+        .and_then(|candidate| candidate.canonicalize().ok())
+}
+
+/// Resolve every snippet included by `content` (written in `including_file`),
+/// dropping includes that don't resolve to an existing file.
+pub fn resolve_snippet_includes(content: &str, including_file: &Path) -> Vec<PathBuf> {
+    let mut resolved: Vec<PathBuf> = extract_snippet_includes(content)
+        .iter()
+        .filter_map(|include| resolve_snippet_path(including_file, &include.path))
+        .collect();
+    resolved.sort();
+    resolved.dedup();
+    resolved
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_snippet_includes() {
+        let content = "\
+--8<-- \"intro.md\"
+;--8<-- \"escaped.md\"
+Text with --8<-- \"inline.md\" in prose
+-8<- 'lines.md:2:5'
+--8<-- \"section.md:usage\"
+--8<-- \"https://example.com/remote.md\"
+
+--8<--
+block/one.md
+;block/skipped.md
+
+block/two.md:1:3
+--8<--
+";
+        let includes = extract_snippet_includes(content);
+        let paths: Vec<(&str, usize)> = includes.iter().map(|i| (i.path.as_str(), i.line)).collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("intro.md", 1),
+                ("lines.md", 4),
+                ("section.md", 5),
+                ("block/one.md", 9),
+                ("block/two.md", 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_snippet_path_falls_back_to_including_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let docs = temp_dir.path().join("docs");
+        std::fs::create_dir_all(docs.join("parts")).unwrap();
+        std::fs::write(docs.join("parts/a.md"), "A\n").unwrap();
+        let page = docs.join("index.md");
+        std::fs::write(&page, "--8<-- \"parts/a.md\"\n").unwrap();
+
+        let resolved = resolve_snippet_path(&page, "parts/a.md").unwrap();
+        assert_eq!(resolved, docs.join("parts/a.md").canonicalize().unwrap());
+        assert!(resolve_snippet_path(&page, "missing.md").is_none());
+    }
+
     #[test]
     fn test_snippet_marker_detection() {
         // Valid snippets with file paths
@@ -8,6 +8,7 @@ pub mod code_block_utils;
 pub mod emphasis_utils;
 pub mod fix_utils;
 pub mod header_id_utils;
+pub mod html_utils;
 pub mod jinja_utils;
 pub mod kramdown_utils;
 pub mod line_ending;
@@ -29,12 +30,15 @@ pub mod obsidian_config;
 pub mod pandoc;
 pub mod parser_options;
 pub mod project_root;
+pub mod proper_names;
+pub mod prose;
 pub mod pymdown_blocks;
 pub mod quarto_chunks;
 pub mod range_utils;
 pub mod regex_cache;
 pub mod sentence_utils;
 pub mod skip_context;
+pub mod slug;
 pub mod string_interner;
 pub mod table_utils;
 pub mod text_reflow;
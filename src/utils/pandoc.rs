@@ -61,6 +61,34 @@ pub fn has_pandoc_attributes(line: &str) -> bool {
     PANDOC_ATTR_PATTERN.is_match(line)
 }
 
+/// Pattern to match an attribute block whose first entry is an identifier,
+/// class, raw-format marker, or `key=value` pair: `{#id}`, `{.class}`,
+/// `{=html}`, `{width=50%}`. Excludes plain braces such as `{x}` in prose.
+static ATTR_BLOCK_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\s*(?:[#.=][^\s}]|[A-Za-z_][\w-]*=)[^}]*\}").unwrap());
+
+/// Find attribute blocks attached to a Pandoc element on a single line.
+///
+/// An attribute block counts when it directly follows the element it
+/// decorates — a bracketed span or link (`]`), an image or link destination
+/// (`)`), inline code (`` ` ``) — or when it is preceded by whitespace, as on
+/// headings and fenced div openers (`::: {.note}`).
+pub fn find_attribute_block_ranges(line: &str) -> Vec<ByteRange> {
+    ATTR_BLOCK_PATTERN
+        .find_iter(line)
+        .filter(|m| {
+            line[..m.start()]
+                .chars()
+                .next_back()
+                .is_some_and(|c| matches!(c, ']' | ')' | '`') || c.is_whitespace())
+        })
+        .map(|m| ByteRange {
+            start: m.start(),
+            end: m.end(),
+        })
+        .collect()
+}
+
 /// Return true if `lang` is a Pandoc raw-format declaration: `{=html}`,
 /// `{=latex}`, etc. The format name must be non-empty and consist only of
 /// ASCII alphanumeric characters, underscores, or hyphens.
@@ -990,6 +1018,35 @@ pub fn find_citation_ranges(content: &str) -> Vec<ByteRange> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_find_attribute_block_ranges() {
+        let spans = |line: &str| -> Vec<String> {
+            find_attribute_block_ranges(line)
+                .iter()
+                .map(|r| line[r.start..r.end].to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            spans("::: {.callout-note title=\"T\"}"),
+            vec!["{.callout-note title=\"T\"}"]
+        );
+        assert_eq!(
+            spans("# Heading {#sec-intro .unnumbered}"),
+            vec!["{#sec-intro .unnumbered}"]
+        );
+        assert_eq!(spans("![Cap](a.png){width=50%}"), vec!["{width=50%}"]);
+        assert_eq!(
+            spans("a [span]{.smallcaps} and `x`{.py}"),
+            vec!["{.smallcaps}", "{.py}"]
+        );
+        assert_eq!(spans("```{=html}"), vec!["{=html}"]);
+
+        // Plain braces and unattached attribute-like text are not attribute blocks
+        assert!(spans("Set {x} to a value").is_empty());
+        assert!(spans("template{.class}").is_empty());
+    }
+
     #[test]
     fn test_div_open_detection() {
         // Valid div openings
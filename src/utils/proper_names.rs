@@ -0,0 +1,180 @@
+//! Proper name canonicalization shared by MD044 and MD063
+//!
+//! MD044 enforces the configured spelling of proper names everywhere, while
+//! MD063 recases heading text. Both rules decide what counts as the correct
+//! form of a name through these helpers, so a heading fixed by one rule is
+//! never rewritten back by the other.
+
+use std::collections::HashMap;
+
+/// Match `pattern_lower` at `start` in `text` using Unicode-aware lowercasing.
+/// Returns the end byte offset in `text` when the match succeeds.
+///
+/// This avoids converting the full `text` to lowercase and then reusing those
+/// offsets on the original string, which can panic for case-fold expansions
+/// (e.g. `İ` -> `i̇`).
+pub fn match_case_insensitive_at(text: &str, start: usize, pattern_lower: &str) -> Option<usize> {
+    if start > text.len() || !text.is_char_boundary(start) || pattern_lower.is_empty() {
+        return None;
+    }
+
+    let mut matched_bytes = 0;
+
+    for (offset, ch) in text[start..].char_indices() {
+        if matched_bytes >= pattern_lower.len() {
+            break;
+        }
+
+        let lowered: String = ch.to_lowercase().collect();
+        if !pattern_lower[matched_bytes..].starts_with(&lowered) {
+            return None;
+        }
+
+        matched_bytes += lowered.len();
+
+        if matched_bytes == pattern_lower.len() {
+            return Some(start + offset + ch.len_utf8());
+        }
+    }
+
+    None
+}
+
+/// Find the next case-insensitive match of `pattern_lower` in `text`,
+/// returning byte offsets in the ORIGINAL string.
+pub fn find_case_insensitive_match(text: &str, pattern_lower: &str, search_start: usize) -> Option<(usize, usize)> {
+    if pattern_lower.is_empty() || search_start >= text.len() || !text.is_char_boundary(search_start) {
+        return None;
+    }
+
+    for (offset, _) in text[search_start..].char_indices() {
+        let start = search_start + offset;
+        if let Some(end) = match_case_insensitive_at(text, start, pattern_lower) {
+            return Some((start, end));
+        }
+    }
+
+    None
+}
+
+/// Build a map from word byte-position → canonical form for all proper names
+/// that appear in `text` (case-insensitive phrase match on word boundaries).
+///
+/// Multi-word names map each of their words, so callers that transform text
+/// word by word can leave every part of the name in its canonical casing.
+pub fn canonical_forms<'a>(text: &str, names: &'a [String]) -> HashMap<usize, &'a str> {
+    let mut map = HashMap::new();
+
+    for name in names {
+        if name.is_empty() {
+            continue;
+        }
+        let name_lower = name.to_lowercase();
+        let canonical_words: Vec<&str> = name.split_whitespace().collect();
+        if canonical_words.is_empty() {
+            continue;
+        }
+        let mut search_start = 0;
+
+        while search_start < text.len() {
+            let Some((abs_pos, end_pos)) = find_case_insensitive_match(text, &name_lower, search_start) else {
+                break;
+            };
+
+            // Require word boundaries
+            let before_ok = abs_pos == 0 || !text[..abs_pos].chars().last().is_some_and(char::is_alphanumeric);
+            let after_ok = end_pos >= text.len() || !text[end_pos..].chars().next().is_some_and(char::is_alphanumeric);
+
+            if before_ok && after_ok {
+                // Map each word in the matched region to its canonical form.
+                // We zip the words found in the text slice with the words of the
+                // canonical name so that every word gets the right casing.
+                let text_slice = &text[abs_pos..end_pos];
+                let mut word_idx = 0;
+                let mut slice_offset = 0;
+
+                for text_word in text_slice.split_whitespace() {
+                    if let Some(w_rel) = text_slice[slice_offset..].find(text_word) {
+                        let word_abs = abs_pos + slice_offset + w_rel;
+                        if let Some(&canonical_word) = canonical_words.get(word_idx) {
+                            map.insert(word_abs, canonical_word);
+                        }
+                        slice_offset += w_rel + text_word.len();
+                        word_idx += 1;
+                    }
+                }
+            }
+
+            // Advance by one Unicode scalar value to allow overlapping matches
+            // while staying on a UTF-8 char boundary.
+            search_start = abs_pos + text[abs_pos..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    map
+}
+
+/// Replace the leading occurrence of `canonical` in `word` with its canonical
+/// casing, keeping any trailing punctuation (e.g. `github:` -> `GitHub:`).
+pub fn apply_canonical_form(word: &str, canonical: &str) -> String {
+    let canonical_lower = canonical.to_lowercase();
+    if canonical_lower.is_empty() {
+        return canonical.to_string();
+    }
+
+    if let Some(end_pos) = match_case_insensitive_at(word, 0, &canonical_lower) {
+        let mut out = String::with_capacity(canonical.len() + word.len().saturating_sub(end_pos));
+        out.push_str(canonical);
+        out.push_str(&word[end_pos..]);
+        out
+    } else {
+        canonical.to_string()
+    }
+}
+
+/// Whether `word` starts with the fully uppercased form of `canonical`
+/// (e.g. `GITHUB` or `GITHUB:` for `GitHub`).
+///
+/// Inside ALL CAPS headings this form is accepted alongside the canonical
+/// spelling, so neither rule rewrites a name the other considers correct.
+pub fn is_uppercase_form(word: &str, canonical: &str) -> bool {
+    let upper = canonical.to_uppercase();
+    !upper.is_empty() && upper != canonical && word.starts_with(&upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_forms_maps_every_word_of_a_name() {
+        let names = vec!["Good Application".to_string(), "GitHub".to_string()];
+        let text = "using good application on github";
+        let forms = canonical_forms(text, &names);
+        assert_eq!(forms.get(&6), Some(&"Good"));
+        assert_eq!(forms.get(&11), Some(&"Application"));
+        assert_eq!(forms.get(&26), Some(&"GitHub"));
+        assert_eq!(forms.len(), 3);
+    }
+
+    #[test]
+    fn test_canonical_forms_requires_word_boundaries() {
+        let names = vec!["Git".to_string()];
+        assert!(canonical_forms("GitHub and digital", &names).is_empty());
+    }
+
+    #[test]
+    fn test_apply_canonical_form_keeps_suffix() {
+        assert_eq!(apply_canonical_form("github:", "GitHub"), "GitHub:");
+        assert_eq!(apply_canonical_form("GITHUB", "GitHub"), "GitHub");
+    }
+
+    #[test]
+    fn test_is_uppercase_form() {
+        assert!(is_uppercase_form("GITHUB", "GitHub"));
+        assert!(is_uppercase_form("GITHUB:", "GitHub"));
+        assert!(!is_uppercase_form("Github", "GitHub"));
+        // A name that is already all caps has no separate uppercase form
+        assert!(!is_uppercase_form("NASA", "NASA"));
+    }
+}
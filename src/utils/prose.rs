@@ -0,0 +1,93 @@
+//! Locating prose in a document
+//!
+//! Rules that check running text, such as MD098 and prose style rules, skip
+//! what is markup rather than prose: code, front matter, HTML, math, link
+//! destinations, URLs, templates and similar syntax. [`is_non_prose_line`]
+//! rules out whole lines; [`NonProseSpans`] rules out spans within a line.
+
+use crate::config::MarkdownFlavor;
+use crate::lint_context::{LineInfo, LintContext};
+use crate::utils::skip_context::{is_mkdocs_admonition_line, is_mkdocs_snippet_line, is_mkdocs_tab_line};
+
+/// Whether a line holds no prose at all
+pub fn is_non_prose_line(info: &LineInfo, line: &str, flavor: MarkdownFlavor) -> bool {
+    if info.in_code_block
+        || info.in_front_matter
+        || info.in_html_block
+        || info.in_html_comment
+        || info.in_math_block
+        || info.in_esm_block
+        || info.in_jsx_expression
+        || info.in_mdx_comment
+        || info.in_mkdocstrings
+        || info.is_horizontal_rule
+        || info.is_div_marker
+    {
+        return true;
+    }
+    let trimmed = line.trim();
+    // Setext underlines, table delimiter rows and similar markup-only lines
+    trimmed.chars().all(|c| matches!(c, '-' | '=' | ':' | '|' | '+' | ' '))
+        || is_mkdocs_admonition_line(line, flavor)
+        || is_mkdocs_tab_line(line, flavor)
+        || is_mkdocs_snippet_line(line, flavor)
+}
+
+/// Byte ranges within prose lines that are not prose
+#[derive(Debug, Default)]
+pub struct NonProseSpans {
+    /// Sorted, merged ranges of links, images, URLs and math
+    ranges: Vec<(usize, usize)>,
+}
+
+impl NonProseSpans {
+    /// Collect the spans of a document: everything but the text of a link, and
+    /// the whole of images, autolinks, bare URLs and math spans
+    pub fn new(ctx: &LintContext) -> Self {
+        let mut ranges = Vec::new();
+        for link in ctx.links() {
+            let text_start = link.byte_offset + 1;
+            let text_end = text_start + link.text.len();
+            let text_is_prose = link.text != link.url
+                && ctx.content.as_bytes().get(link.byte_offset) == Some(&b'[')
+                && ctx.content.get(text_start..text_end) == Some(link.text.as_ref());
+            if text_is_prose {
+                ranges.push((link.byte_offset, text_start));
+                ranges.push((text_end, link.byte_end));
+            } else {
+                ranges.push((link.byte_offset, link.byte_end));
+            }
+        }
+        ranges.extend(ctx.images().iter().map(|image| (image.byte_offset, image.byte_end)));
+        ranges.extend(ctx.bare_urls().iter().map(|url| (url.byte_offset, url.byte_end)));
+        ranges.extend(ctx.math_spans().iter().map(|span| (span.byte_offset, span.byte_end)));
+        ranges.sort_unstable();
+
+        // Merge overlaps (a bare URL inside link text) so lookups can binary search
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// Whether the byte at `pos` is outside prose: in one of the collected
+    /// spans, or in code, HTML, a reference definition, a template or MDX
+    pub fn contains(&self, ctx: &LintContext, pos: usize) -> bool {
+        let idx = self.ranges.partition_point(|&(start, _)| start <= pos);
+        (idx > 0 && pos < self.ranges[idx - 1].1)
+            || ctx.is_in_code_span_byte(pos)
+            || ctx.is_in_html_tag(pos)
+            || ctx.is_in_html_comment(pos)
+            || ctx.is_in_reference_def(pos)
+            || ctx.is_in_jinja_range(pos)
+            || ctx.is_in_shortcode(pos)
+            || ctx.is_in_jsx_expression(pos)
+            || ctx.is_in_jsx_component_tag(pos)
+            || ctx.is_in_obsidian_comment(pos)
+            || ctx.is_in_myst_role(pos)
+    }
+}
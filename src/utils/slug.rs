@@ -0,0 +1,158 @@
+//! Heading slug generation shared by the anchor-aware rules
+//!
+//! MD051, MD073, MD080 and MD089 all turn heading text into anchors. MD051,
+//! MD080 and MD089 pick the algorithm the same way: the rule's own
+//! `anchor-style` when set, then the global `slugify` option, then the native
+//! style of the Markdown flavor. MD073 has no `anchor-style` and always
+//! generated GitHub slugs, so it only follows the global `slugify` option.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Config, MarkdownFlavor};
+use crate::utils::regex_cache::get_cached_regex;
+
+pub use super::anchor_styles::AnchorStyle;
+
+/// One step of a custom slug algorithm: every match of `pattern` is replaced
+/// with `replacement` (`$1`-style group references are allowed)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct SlugSubstitution {
+    pub pattern: String,
+    #[serde(default)]
+    pub replacement: String,
+}
+
+/// The algorithm turning heading text into an anchor slug
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum Slugify {
+    /// A built-in platform style, e.g. `slugify = "gitlab"`
+    Style(AnchorStyle),
+    /// Substitutions applied in order to the trimmed, lowercased heading text
+    Custom(Vec<SlugSubstitution>),
+}
+
+impl Default for Slugify {
+    fn default() -> Self {
+        Slugify::Style(AnchorStyle::default())
+    }
+}
+
+impl From<AnchorStyle> for Slugify {
+    fn from(style: AnchorStyle) -> Self {
+        Slugify::Style(style)
+    }
+}
+
+impl Slugify {
+    /// Generate the anchor slug for a heading
+    pub fn slugify(&self, heading: &str) -> String {
+        match self {
+            Slugify::Style(style) => style.generate_fragment(heading),
+            Slugify::Custom(substitutions) => {
+                let mut slug = heading.trim().to_lowercase();
+                for substitution in substitutions {
+                    // Invalid patterns are reported when the config is loaded
+                    if let Ok(re) = get_cached_regex(&substitution.pattern) {
+                        slug = re.replace_all(&slug, substitution.replacement.as_str()).into_owned();
+                    }
+                }
+                slug
+            }
+        }
+    }
+
+    /// Whether this is the given built-in style
+    pub fn is_style(&self, style: &AnchorStyle) -> bool {
+        matches!(self, Slugify::Style(own) if own == style)
+    }
+
+    /// Check that every custom substitution pattern compiles
+    pub fn validate(&self) -> Result<(), String> {
+        let Slugify::Custom(substitutions) = self else {
+            return Ok(());
+        };
+        for substitution in substitutions {
+            if let Err(e) = get_cached_regex(&substitution.pattern) {
+                return Err(format!("invalid slugify pattern '{}': {e}", substitution.pattern));
+            }
+        }
+        Ok(())
+    }
+
+    /// The slug algorithm `rule_name` uses. `rule_style` is the rule's own
+    /// `anchor-style`, which wins when the user set it explicitly.
+    pub fn for_rule(config: &Config, rule_name: &str, rule_style: &AnchorStyle) -> Self {
+        let explicit_style = config
+            .rules
+            .get(rule_name)
+            .is_some_and(|rc| rc.values.contains_key("anchor-style") || rc.values.contains_key("anchor_style"));
+        if explicit_style {
+            return Slugify::Style(rule_style.clone());
+        }
+        if let Some(slugify) = &config.global.slugify {
+            return slugify.clone();
+        }
+        Slugify::Style(match config.global.flavor {
+            MarkdownFlavor::MkDocs => AnchorStyle::PythonMarkdown,
+            MarkdownFlavor::Kramdown => AnchorStyle::KramdownGfm,
+            _ => AnchorStyle::GitHub,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_parses_style_or_substitution_list() {
+        let style: Slugify = toml::from_str::<toml::Table>("slugify = \"gitlab\"").unwrap()["slugify"]
+            .clone()
+            .try_into()
+            .unwrap();
+        assert_eq!(style, Slugify::Style(AnchorStyle::GitLab));
+
+        let custom: Slugify = toml::from_str::<toml::Table>(
+            r#"slugify = [{ pattern = "[^a-z0-9 ]", replacement = "" }, { pattern = " +", replacement = "_" }]"#,
+        )
+        .unwrap()["slugify"]
+            .clone()
+            .try_into()
+            .unwrap();
+        assert_eq!(custom.slugify("  Hello, World 2!  "), "hello_world_2");
+    }
+
+    #[test]
+    fn test_validate_reports_bad_patterns() {
+        let custom = Slugify::Custom(vec![SlugSubstitution {
+            pattern: "(".to_string(),
+            replacement: String::new(),
+        }]);
+        assert!(custom.validate().unwrap_err().contains("invalid slugify pattern '('"));
+        assert!(Slugify::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_for_rule_precedence() {
+        let mut config = Config::default();
+        assert_eq!(
+            Slugify::for_rule(&config, "MD051", &AnchorStyle::Kramdown),
+            Slugify::Style(AnchorStyle::GitHub)
+        );
+
+        config.global.flavor = MarkdownFlavor::MkDocs;
+        assert!(Slugify::for_rule(&config, "MD051", &AnchorStyle::GitHub).is_style(&AnchorStyle::PythonMarkdown));
+
+        config.global.slugify = Some(Slugify::Style(AnchorStyle::GitLab));
+        assert!(Slugify::for_rule(&config, "MD051", &AnchorStyle::GitHub).is_style(&AnchorStyle::GitLab));
+
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("anchor-style".to_string(), toml::Value::String("kramdown".to_string()));
+        config.rules.insert("MD051".to_string(), rule_config);
+        assert!(Slugify::for_rule(&config, "MD051", &AnchorStyle::Kramdown).is_style(&AnchorStyle::Kramdown));
+        assert!(Slugify::for_rule(&config, "MD080", &AnchorStyle::GitHub).is_style(&AnchorStyle::GitLab));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, LazyLock, Mutex};
 
 /// String interner for reducing memory allocations of common strings
@@ -51,6 +51,27 @@ pub fn intern_string(s: &str) -> Arc<str> {
     }
 }
 
+/// Strings promoted to `'static` by [`intern_static`]
+static STATIC_STRINGS: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Intern a string for the lifetime of the process
+///
+/// For names that must be `&'static str` but are only known at runtime, such
+/// as the IDs of rules loaded from style files. Each distinct string is leaked
+/// once, so reloading the same definitions does not grow memory.
+pub fn intern_static(s: &str) -> &'static str {
+    let mut strings = match STATIC_STRINGS.lock() {
+        Ok(strings) => strings,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(&existing) = strings.get(s) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    strings.insert(leaked);
+    leaked
+}
+
 /// Common interned strings for performance
 pub mod common {
     use super::*;
@@ -112,4 +133,13 @@ mod tests {
 
         assert!(Arc::ptr_eq(&s1, &s2));
     }
+
+    #[test]
+    fn test_intern_static() {
+        let s1 = intern_static(&String::from("Acme.Avoid"));
+        let s2 = intern_static("Acme.Avoid");
+
+        assert_eq!(s1, "Acme.Avoid");
+        assert!(std::ptr::eq(s1, s2));
+    }
 }
@@ -1704,7 +1704,10 @@ But no delimiter row
 
         let content = "`code`\n\n| A | B |\n|---|---|\n| 1 | 2 |\n";
         let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
-        assert!(!ctx.table_blocks.is_empty(), "Table after code span should be detected");
+        assert!(
+            !ctx.table_blocks().is_empty(),
+            "Table after code span should be detected"
+        );
     }
 
     #[test]
@@ -1714,7 +1717,7 @@ But no delimiter row
         let content = "<!--\n| A | B |\n|---|---|\n| 1 | 2 |\n-->\n";
         let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
         assert!(
-            ctx.table_blocks.is_empty(),
+            ctx.table_blocks().is_empty(),
             "Table inside HTML comment should not be detected"
         );
     }
@@ -18,6 +18,7 @@ use crate::utils::sentence_utils::{
     text_ends_with_abbreviation,
 };
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use unicode_width::UnicodeWidthStr;
 
@@ -29,6 +30,9 @@ pub enum ReflowLengthMode {
     /// Count visual display width (CJK = 2 columns, emoji = 2, etc.)
     #[default]
     Visual,
+    /// Visual display width, with East Asian ambiguous-width characters
+    /// counted as 2 columns
+    VisualWide,
     /// Count raw bytes
     Bytes,
 }
@@ -38,6 +42,7 @@ fn display_len(s: &str, mode: ReflowLengthMode) -> usize {
     match mode {
         ReflowLengthMode::Chars => s.chars().count(),
         ReflowLengthMode::Visual => s.width(),
+        ReflowLengthMode::VisualWide => s.width_cjk(),
         ReflowLengthMode::Bytes => s.len(),
     }
 }
@@ -77,6 +82,43 @@ pub struct ReflowOptions {
     /// Used by mkdocs flavor where continuation is always 4 spaces
     /// regardless of checkbox markers.
     pub max_list_continuation_indent: Option<usize>,
+    /// User-supplied patterns (e.g. Jinja `{{ ... }}` expressions) whose matches
+    /// are treated as atomic elements: never split across lines and rendered
+    /// verbatim.
+    pub no_break_patterns: Vec<regex::Regex>,
+    /// Break-point heuristics for semantic line breaks mode
+    pub semantic_break_rules: SemanticBreakRules,
+}
+
+/// Tunable break-point heuristics for semantic line breaks mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct SemanticBreakRules {
+    /// Minimum length of the text before a comma for the comma to be used as a
+    /// break point. 0 (default) leaves only the built-in minimum of 30% of the
+    /// line length. Other clause punctuation is unaffected.
+    #[serde(alias = "comma_min_length")]
+    pub comma_min_length: usize,
+    /// Words (or phrases) a line may be broken before, matched case-insensitively.
+    /// `None` (default) uses the built-in English conjunctions; an empty list
+    /// disables break-word splitting.
+    #[serde(alias = "break_words")]
+    pub break_words: Option<Vec<String>>,
+    /// Keep numbered citations (`[1]`, `[2, 5-7]`, `[@key]`, `[^note]`) attached
+    /// to the word or sentence they follow instead of starting a line with them
+    /// (default: true)
+    #[serde(alias = "keep_citations")]
+    pub keep_citations: bool,
+}
+
+impl Default for SemanticBreakRules {
+    fn default() -> Self {
+        Self {
+            comma_min_length: 0,
+            break_words: None,
+            keep_citations: true,
+        }
+    }
 }
 
 impl Default for ReflowOptions {
@@ -93,6 +135,8 @@ impl Default for ReflowOptions {
             myst_roles: false,
             require_sentence_capital: true,
             max_list_continuation_indent: None,
+            no_break_patterns: Vec::new(),
+            semantic_break_rules: SemanticBreakRules::default(),
         }
     }
 }
@@ -330,7 +374,7 @@ pub fn split_into_sentences(text: &str) -> Vec<String> {
 /// Split text into sentences with custom abbreviations
 pub fn split_into_sentences_custom(text: &str, custom_abbreviations: &Option<Vec<String>>) -> Vec<String> {
     let abbreviations = get_abbreviations(custom_abbreviations);
-    split_into_sentences_with_set(text, &abbreviations, true)
+    split_into_sentences_with_set(text, &abbreviations, true, &[])
 }
 
 /// Internal function to split text into sentences with a pre-computed abbreviations set
@@ -339,9 +383,21 @@ fn split_into_sentences_with_set(
     text: &str,
     abbreviations: &HashSet<String>,
     require_sentence_capital: bool,
+    protected: &[(usize, usize)],
 ) -> Vec<String> {
-    // Pre-compute which character positions are inside inline code spans
-    let in_code = compute_inline_code_mask(text);
+    // Pre-compute which character positions are inside inline code spans or
+    // protected byte ranges (atomic elements) and so can never end a sentence
+    let mut in_code = compute_inline_code_mask(text);
+    if !protected.is_empty() {
+        for (i, (byte_pos, _)) in text.char_indices().enumerate() {
+            if protected
+                .iter()
+                .any(|&(start, end)| byte_pos >= start && byte_pos < end)
+            {
+                in_code[i] = true;
+            }
+        }
+    }
     // Collect chars once and share the slice with is_sentence_boundary, which
     // would otherwise re-collect the whole text on every position it checks.
     let char_vec: Vec<char> = text.chars().collect();
@@ -524,14 +580,19 @@ fn trim_preserving_hard_break(s: &str) -> String {
 
 /// Parse markdown elements using the appropriate parser based on options.
 fn parse_elements(text: &str, options: &ReflowOptions) -> Vec<Element> {
-    parse_markdown_elements_inner(text, options.attr_lists, options.myst_roles)
+    parse_markdown_elements_inner(text, options.attr_lists, options.myst_roles, &options.no_break_patterns)
 }
 
 pub fn reflow_line(line: &str, options: &ReflowOptions) -> Vec<String> {
     // For sentence-per-line mode, always process regardless of length
     if options.sentence_per_line {
         let elements = parse_elements(line, options);
-        return reflow_elements_sentence_per_line(&elements, &options.abbreviations, options.require_sentence_capital);
+        return reflow_elements_sentence_per_line(
+            &elements,
+            &options.abbreviations,
+            options.require_sentence_capital,
+            false,
+        );
     }
 
     // For semantic line breaks mode, use cascading split strategy
@@ -626,6 +687,8 @@ enum Element {
     /// Stored as the raw matched text and rendered verbatim so it round-trips
     /// exactly; treated as atomic so it is never split mid-role.
     MystRole(String),
+    /// Match of a user-configured no-break pattern, rendered verbatim
+    NoBreak(String),
     /// Inline code `code`
     Code(String),
     /// Bold text **text** or __text__
@@ -681,6 +744,7 @@ impl std::fmt::Display for Element {
             Element::HugoShortcode(s) => write!(f, "{s}"),
             Element::AttrList(s) => write!(f, "{s}"),
             Element::MystRole(s) => write!(f, "{s}"),
+            Element::NoBreak(s) => write!(f, "{s}"),
             Element::Code(s) => write!(f, "`{s}`"),
             Element::Bold { content, underscore } => {
                 if *underscore {
@@ -886,7 +950,12 @@ fn myst_role_len_at(text: &str) -> Option<usize> {
 /// 5. Reference links [text][ref] - before shortcut references
 /// 6. Shortcut reference links [ref] - detected last to avoid false positives
 /// 7. Other elements (code, bold, italic, MyST roles, etc.) - processed normally
-fn parse_markdown_elements_inner(text: &str, attr_lists: bool, myst_roles: bool) -> Vec<Element> {
+fn parse_markdown_elements_inner(
+    text: &str,
+    attr_lists: bool,
+    myst_roles: bool,
+    no_break_patterns: &[regex::Regex],
+) -> Vec<Element> {
     let mut elements = Vec::new();
     let mut remaining = text;
 
@@ -900,6 +969,17 @@ fn parse_markdown_elements_inner(text: &str, attr_lists: bool, myst_roles: bool)
         // Store (start, end, pattern_name) to unify standard Regex and FancyRegex match results
         let mut earliest_match: Option<(usize, usize, &str)> = None;
 
+        // Check user-configured no-break patterns first so they win ties with
+        // the built-in patterns. Empty matches would never advance, so skip them.
+        for pattern in no_break_patterns {
+            if let Some(m) = pattern.find(remaining)
+                && !m.is_empty()
+                && earliest_match.as_ref().is_none_or(|(start, _, _)| m.start() < *start)
+            {
+                earliest_match = Some((m.start(), m.end(), "no_break"));
+            }
+        }
+
         // Check for linked images FIRST (all 4 variants)
         // Quick literal check: only run expensive regexes if we might have a linked image
         // Pattern starts with "[!" so check for that first
@@ -1333,6 +1413,10 @@ fn parse_markdown_elements_inner(text: &str, attr_lists: bool, myst_roles: bool)
                     elements.push(Element::HugoShortcode(remaining[pos..match_end].to_string()));
                     remaining = &remaining[match_end..];
                 }
+                "no_break" => {
+                    elements.push(Element::NoBreak(remaining[pos..match_end].to_string()));
+                    remaining = &remaining[match_end..];
+                }
                 "autolink" => {
                     // Autolinks are atomic elements - preserve them exactly
                     elements.push(Element::Autolink(remaining[pos..match_end].to_string()));
@@ -1424,15 +1508,56 @@ fn should_insert_space_before_join(current: &str) -> bool {
         && !current.ends_with('-')
 }
 
+/// Whether `element` is a citation marker: a bracketed numeric reference
+/// (`[1]`, `[2, 5-7]`), a Pandoc citation (`[@key]`) or a footnote reference
+fn is_citation(element: &Element) -> bool {
+    match element {
+        Element::FootnoteReference { .. } => true,
+        Element::ShortcutReference { reference } => is_citation_label(reference),
+        _ => false,
+    }
+}
+
+/// Whether the text between the brackets of `[...]` reads as a citation
+fn is_citation_label(label: &str) -> bool {
+    let r = label.trim();
+    r.starts_with('@')
+        || r.starts_with('^')
+        || (r.chars().any(|c| c.is_ascii_digit())
+            && r.chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, ',' | ';' | '-' | '\u{2013}' | ' ')))
+}
+
+/// Strip citation markers glued to the end of `text` (`done.[1][2]` -> `done.`)
+fn strip_trailing_citations(mut text: &str) -> &str {
+    while let Some(rest) = text.strip_suffix(']')
+        && let Some(open) = rest.rfind('[')
+    {
+        if !is_citation_label(&rest[open + 1..]) {
+            break;
+        }
+        text = &rest[..open];
+    }
+    text
+}
+
 /// Reflow elements for sentence-per-line mode
+///
+/// With `keep_citations`, a citation directly following a sentence's closing
+/// punctuation (`done.[1]`) stays on that sentence's line.
 fn reflow_elements_sentence_per_line(
     elements: &[Element],
     custom_abbreviations: &Option<Vec<String>>,
     require_sentence_capital: bool,
+    keep_citations: bool,
 ) -> Vec<String> {
     let abbreviations = get_abbreviations(custom_abbreviations);
     let mut lines = Vec::new();
     let mut current_line = String::new();
+    // Byte ranges of atomic elements (links, code, no-break matches, ...) in
+    // `current_line`. Punctuation inside them must not end a sentence when the
+    // line is re-split together with the text that follows.
+    let mut protected: Vec<(usize, usize)> = Vec::new();
 
     for (idx, element) in elements.iter().enumerate() {
         let element_str = format!("{element}");
@@ -1442,7 +1567,8 @@ fn reflow_elements_sentence_per_line(
             // Simply append text - it already has correct spacing from tokenization
             let combined = format!("{current_line}{text}");
             // Use the pre-computed abbreviations set to avoid redundant computation
-            let sentences = split_into_sentences_with_set(&combined, &abbreviations, require_sentence_capital);
+            let sentences =
+                split_into_sentences_with_set(&combined, &abbreviations, require_sentence_capital, &protected);
 
             if sentences.len() > 1 {
                 // We found sentence boundaries
@@ -1478,6 +1604,20 @@ fn reflow_elements_sentence_per_line(
                         lines.push(sentence.clone());
                     }
                 }
+
+                // Only a held-back first sentence still contains the protected
+                // elements; re-base their ranges onto it
+                let lead = combined.len() - combined.trim_start().len();
+                if current_line.is_empty() || !combined[lead..].starts_with(current_line.as_str()) {
+                    protected.clear();
+                } else {
+                    let kept_end = lead + current_line.len();
+                    protected = protected
+                        .iter()
+                        .filter(|&&(start, end)| start >= lead && end <= kept_end)
+                        .map(|&(start, end)| (start - lead, end - lead))
+                        .collect();
+                }
             } else {
                 // Single sentence - check if it's complete
                 let trimmed = combined.trim();
@@ -1495,12 +1635,14 @@ fn reflow_elements_sentence_per_line(
                     // Complete single sentence - emit it
                     lines.push(trimmed.to_string());
                     current_line.clear();
+                    protected.clear();
                 } else {
                     // Incomplete sentence - continue accumulating
                     current_line = combined;
                 }
             }
         } else if let Element::Italic { content, underscore } = element {
+            let emitted = lines.len();
             // Handle italic elements - may contain multiple sentences that need continuation
             let marker = if *underscore { "_" } else { "*" };
             handle_emphasis_sentence_split(
@@ -1511,7 +1653,11 @@ fn reflow_elements_sentence_per_line(
                 &mut current_line,
                 &mut lines,
             );
+            if lines.len() != emitted {
+                protected.clear();
+            }
         } else if let Element::Bold { content, underscore } = element {
+            let emitted = lines.len();
             // Handle bold elements - may contain multiple sentences that need continuation
             let marker = if *underscore { "__" } else { "**" };
             handle_emphasis_sentence_split(
@@ -1522,7 +1668,11 @@ fn reflow_elements_sentence_per_line(
                 &mut current_line,
                 &mut lines,
             );
+            if lines.len() != emitted {
+                protected.clear();
+            }
         } else if let Element::Strikethrough(content) = element {
+            let emitted = lines.len();
             // Handle strikethrough elements - may contain multiple sentences that need continuation
             handle_emphasis_sentence_split(
                 content,
@@ -1532,6 +1682,9 @@ fn reflow_elements_sentence_per_line(
                 &mut current_line,
                 &mut lines,
             );
+            if lines.len() != emitted {
+                protected.clear();
+            }
         } else {
             // Non-text, non-emphasis elements (Code, Links, etc.)
             // Check if this element is adjacent to the preceding text (no space between)
@@ -1544,11 +1697,24 @@ fn reflow_elements_sentence_per_line(
                 false
             };
 
+            // A citation closing an already emitted sentence belongs to it
+            if keep_citations
+                && is_adjacent
+                && current_line.is_empty()
+                && is_citation(element)
+                && let Some(last) = lines.last_mut()
+            {
+                last.push_str(&element_str);
+                continue;
+            }
+
             // Add space before element if needed, but not for adjacent elements
             if !is_adjacent && should_insert_space_before_join(&current_line) {
                 current_line.push(' ');
             }
+            let start = current_line.len();
             current_line.push_str(&element_str);
+            protected.push((start, current_line.len()));
         }
     }
 
@@ -1569,7 +1735,7 @@ fn handle_emphasis_sentence_split(
     lines: &mut Vec<String>,
 ) {
     // Split the emphasis content into sentences
-    let sentences = split_into_sentences_with_set(content, abbreviations, require_sentence_capital);
+    let sentences = split_into_sentences_with_set(content, abbreviations, require_sentence_capital, &[]);
 
     if sentences.len() <= 1 {
         // Single sentence or no boundaries - treat as atomic
@@ -1863,6 +2029,7 @@ fn split_at_clause_punctuation(
     line_length: usize,
     element_spans: &[(usize, usize)],
     length_mode: ReflowLengthMode,
+    comma_min_length: usize,
 ) -> Option<(String, String)> {
     let chars: Vec<char> = text.chars().collect();
     let min_first_len = ((line_length as f64) * MIN_SPLIT_RATIO) as usize;
@@ -1905,6 +2072,9 @@ fn split_at_clause_punctuation(
             && is_clause_punctuation(chars[i])
             && clause_break_allowed_after(&chars, i)
             && !is_inside_element(byte_after, element_spans)
+            && (chars[i] != ','
+                || comma_min_length == 0
+                || display_len(&text[..byte_after], length_mode) >= comma_min_length)
         {
             best_pos = Some(i);
             break;
@@ -2003,6 +2173,7 @@ fn split_at_break_word(
     line_length: usize,
     element_spans: &[(usize, usize)],
     length_mode: ReflowLengthMode,
+    break_words: Option<&[String]>,
 ) -> Option<(String, String)> {
     let lower = text.to_lowercase();
     let min_first_len = ((line_length as f64) * MIN_SPLIT_RATIO) as usize;
@@ -2012,7 +2183,20 @@ fn split_at_break_word(
     // parenthetical groups (matching the protection added to split_at_clause_punctuation).
     let depth_map = paren_depth_map(text, element_spans);
 
-    for &word in BREAK_WORDS {
+    let custom_words: Vec<String>;
+    let words: Vec<&str> = match break_words {
+        Some(words) => {
+            custom_words = words
+                .iter()
+                .map(|w| w.trim().to_lowercase())
+                .filter(|w| !w.is_empty())
+                .collect();
+            custom_words.iter().map(String::as_str).collect()
+        }
+        None => BREAK_WORDS.to_vec(),
+    };
+
+    for word in words {
         let mut search_start = 0;
         while let Some(pos) = lower[search_start..].find(word) {
             let abs_pos = search_start + pos;
@@ -2059,79 +2243,57 @@ fn split_at_break_word(
 
 /// Recursively cascade-split a line that exceeds line_length.
 /// Tries clause punctuation first, then break-words, then word wrap.
-fn cascade_split_line(
-    text: &str,
-    line_length: usize,
-    abbreviations: &Option<Vec<String>>,
-    length_mode: ReflowLengthMode,
-    attr_lists: bool,
-    myst_roles: bool,
-) -> Vec<String> {
+fn cascade_split_line(text: &str, options: &ReflowOptions) -> Vec<String> {
+    let line_length = options.line_length;
+    let length_mode = options.length_mode;
     if line_length == 0 || display_len(text, length_mode) <= line_length {
         return vec![text.to_string()];
     }
 
-    let elements = parse_markdown_elements_inner(text, attr_lists, myst_roles);
+    let elements = parse_elements(text, options);
     let element_spans = compute_element_spans(&elements);
+    let rules = &options.semantic_break_rules;
 
     // Try parenthetical boundary split (before clause punctuation so that
     // multi-word parentheticals are kept intact as semantic units)
     if let Some((first, rest)) = split_at_parenthetical(text, line_length, &element_spans, length_mode) {
         let mut result = vec![first];
-        result.extend(cascade_split_line(
-            &rest,
-            line_length,
-            abbreviations,
-            length_mode,
-            attr_lists,
-            myst_roles,
-        ));
+        result.extend(cascade_split_line(&rest, options));
         return result;
     }
 
     // Try clause punctuation split
-    if let Some((first, rest)) = split_at_clause_punctuation(text, line_length, &element_spans, length_mode) {
+    if let Some((first, rest)) =
+        split_at_clause_punctuation(text, line_length, &element_spans, length_mode, rules.comma_min_length)
+    {
         let mut result = vec![first];
-        result.extend(cascade_split_line(
-            &rest,
-            line_length,
-            abbreviations,
-            length_mode,
-            attr_lists,
-            myst_roles,
-        ));
+        result.extend(cascade_split_line(&rest, options));
         return result;
     }
 
     // Try break-word split
-    if let Some((first, rest)) = split_at_break_word(text, line_length, &element_spans, length_mode) {
+    if let Some((first, rest)) = split_at_break_word(
+        text,
+        line_length,
+        &element_spans,
+        length_mode,
+        rules.break_words.as_deref(),
+    ) {
         let mut result = vec![first];
-        result.extend(cascade_split_line(
-            &rest,
-            line_length,
-            abbreviations,
-            length_mode,
-            attr_lists,
-            myst_roles,
-        ));
+        result.extend(cascade_split_line(&rest, options));
         return result;
     }
 
     // Fallback: word wrap using existing reflow_elements
-    let options = ReflowOptions {
-        line_length,
+    let wrap_options = ReflowOptions {
         break_on_sentences: false,
         preserve_breaks: false,
         sentence_per_line: false,
-        semantic_line_breaks: false,
-        abbreviations: abbreviations.clone(),
-        length_mode,
-        attr_lists,
-        myst_roles,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        ..options.clone()
     };
-    reflow_elements(&elements, &options)
+    reflow_elements(&elements, &wrap_options)
 }
 
 /// Reflow elements using semantic line breaks strategy:
@@ -2139,8 +2301,12 @@ fn cascade_split_line(
 /// 2. For lines exceeding line_length, cascade through clause punct → break-words → word wrap
 fn reflow_elements_semantic(elements: &[Element], options: &ReflowOptions) -> Vec<String> {
     // Step 1: Split into sentences using existing sentence-per-line logic
-    let sentence_lines =
-        reflow_elements_sentence_per_line(elements, &options.abbreviations, options.require_sentence_capital);
+    let sentence_lines = reflow_elements_sentence_per_line(
+        elements,
+        &options.abbreviations,
+        options.require_sentence_capital,
+        options.semantic_break_rules.keep_citations,
+    );
 
     // Step 2: For each sentence line, apply cascading splits if it exceeds line_length
     // When line_length is 0 (unlimited), skip cascading — sentence splits only
@@ -2154,14 +2320,7 @@ fn reflow_elements_semantic(elements: &[Element], options: &ReflowOptions) -> Ve
         if display_len(&line, length_mode) <= options.line_length {
             result.push(line);
         } else {
-            result.extend(cascade_split_line(
-                &line,
-                options.line_length,
-                &options.abbreviations,
-                length_mode,
-                options.attr_lists,
-                options.myst_roles,
-            ));
+            result.extend(cascade_split_line(&line, options));
         }
     }
 
@@ -2180,7 +2339,10 @@ fn reflow_elements_semantic(elements: &[Element], options: &ReflowOptions) -> Ve
 
             // Don't merge across sentence boundaries — sentence splits are intentional
             let prev_ends_at_sentence = {
-                let trimmed = merged.last().unwrap().trim_end();
+                let mut trimmed = merged.last().unwrap().trim_end();
+                if options.semantic_break_rules.keep_citations {
+                    trimmed = strip_trailing_citations(trimmed);
+                }
                 trimmed
                     .chars()
                     .rev()
@@ -2225,6 +2387,8 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
     // Track byte spans of non-Text elements in current_line for safe splitting
     let mut current_line_element_spans: Vec<(usize, usize)> = Vec::new();
     let length_mode = options.length_mode;
+    // Citation gluing is a semantic-line-breaks heuristic
+    let keep_citations = options.semantic_line_breaks && options.semantic_break_rules.keep_citations;
 
     for (idx, element) in elements.iter().enumerate() {
         // Derive the display width from the already-formatted string rather than
@@ -2406,6 +2570,23 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
                     current_length += element_len;
                     current_line_element_spans.push((start, current_line.len()));
                 }
+            } else if current_length > 0
+                && current_length + 1 + element_len > options.line_length
+                && keep_citations
+                && is_citation(element)
+                && let Some(last_space) = rfind_safe_space(&current_line, &current_line_element_spans)
+                && last_space > 0
+            {
+                // Would exceed, but a citation must not start a line — carry the
+                // word it cites over with it
+                let before = current_line[..last_space].trim_end().to_string();
+                let after = current_line[last_space + 1..].to_string();
+                lines.push(before);
+                current_line = format!("{after} {element_str}");
+                current_length = display_len(&current_line, length_mode);
+                current_line_element_spans.clear();
+                let start = after.len() + 1;
+                current_line_element_spans.push((start, start + element_str.len()));
             } else if current_length > 0 && current_length + 1 + element_len > options.line_length {
                 // Not adjacent, would exceed — start new line
                 lines.push(current_line.trim().to_string());
@@ -82,6 +82,16 @@ pub fn byte_offset_to_char_offset(content: &str, byte_offset: usize) -> usize {
         .count()
 }
 
+/// Convert a character offset to a byte offset in a UTF-8 string, the
+/// inverse of [`byte_offset_to_char_offset`]. Offsets past the end map to
+/// the end of the string.
+pub fn char_offset_to_byte_offset(content: &str, char_offset: usize) -> usize {
+    content
+        .char_indices()
+        .nth(char_offset)
+        .map_or(content.len(), |(byte_idx, _)| byte_idx)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +123,19 @@ mod tests {
         assert_eq!(byte_offset_to_char_offset(content, 2), 1); // End of string
     }
 
+    #[test]
+    fn test_char_offset_to_byte_offset() {
+        let content = "Hello æ world";
+        assert_eq!(char_offset_to_byte_offset(content, 0), 0);
+        assert_eq!(char_offset_to_byte_offset(content, 7), 8);
+        assert_eq!(char_offset_to_byte_offset(content, 13), 14);
+        assert_eq!(char_offset_to_byte_offset(content, 100), 14);
+        for char_offset in 0..=13 {
+            let byte_offset = char_offset_to_byte_offset(content, char_offset);
+            assert_eq!(byte_offset_to_char_offset(content, byte_offset), char_offset);
+        }
+    }
+
     #[test]
     fn test_byte_offset_to_char_offset_mixed() {
         // Mixed ASCII and multi-byte: "Hello æ world"
@@ -25,6 +25,17 @@
 //! const fixed = linter.fix(content, "docs/page.md");
 //! ```
 //!
+//! # Editing Sessions
+//!
+//! Editors that lint on every keystroke can open a document once and send
+//! only the edits:
+//!
+//! ```javascript
+//! const doc = linter.createDocument(content, "docs/page.md");
+//! doc.applyEdit({ start: 10, end: 12 }, "new text");
+//! const warnings = JSON.parse(doc.lint());
+//! ```
+//!
 //! # Rule-specific Configuration
 //!
 //! Rules can be configured individually using their rule name as a key:
@@ -49,13 +60,17 @@
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
-use crate::config::{Config, MarkdownFlavor};
+use crate::block_cache::BlockCache;
+use crate::config::{Config, MarkdownFlavor, resolve_rule_name};
 use crate::fix_coordinator::FixCoordinator;
-use crate::rule::{LintWarning, Severity};
+use crate::rule::{Applicability, LintWarning, Severity};
 use crate::rule_config_serde::{is_rule_name, json_to_rule_config_with_warnings, toml_value_to_json};
 use crate::rules::{all_rules, filter_rules};
-use crate::types::LineLength;
-use crate::utils::utf8_offsets::{byte_column_to_char_column, byte_offset_to_char_offset, get_line_content};
+use crate::types::{AmbiguousWidth, LengthMode, LineLength};
+use crate::utils::fix_utils::plan_edits;
+use crate::utils::utf8_offsets::{
+    byte_column_to_char_column, byte_offset_to_char_offset, char_offset_to_byte_offset, get_line_content,
+};
 
 /// Warning with fix range converted to character offsets for JavaScript
 #[derive(Serialize)]
@@ -79,10 +94,12 @@ struct JsFix {
     replacement: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     additional_edits: Vec<JsFix>,
+    #[serde(skip_serializing_if = "Applicability::is_automatic")]
+    applicability: Applicability,
 }
 
 /// Range with character offsets for JavaScript
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct JsRange {
     start: usize,
     end: usize,
@@ -98,6 +115,7 @@ fn convert_warning_for_js(warning: &LintWarning, content: &str) -> JsWarning {
             },
             replacement: fix.replacement.clone(),
             additional_edits: fix.additional_edits.iter().map(|e| fix_to_js(e, content)).collect(),
+            applicability: fix.applicability,
         }
     }
     let js_fix = warning.fix.as_ref().map(|fix| fix_to_js(fix, content));
@@ -194,6 +212,12 @@ pub struct LinterConfig {
     /// Line length limit (default: 80)
     pub line_length: Option<u64>,
 
+    /// How line lengths are measured: "visual", "chars", or "bytes" (default: "visual")
+    pub length_mode: Option<LengthMode>,
+
+    /// Width of East Asian ambiguous characters: "narrow" or "wide" (default: "narrow")
+    pub ambiguous_width: Option<AmbiguousWidth>,
+
     /// Markdown flavor: "standard", "mkdocs", "mdx", "pandoc", "quarto", "obsidian", "kramdown", "azure_devops", or "myst"
     pub flavor: Option<String>,
 
@@ -203,6 +227,9 @@ pub struct LinterConfig {
     /// Rules that should never apply fixes (takes precedence over fixable)
     pub unfixable: Option<Vec<String>>,
 
+    /// Whether to apply fixes that may change what the document says (default: false)
+    pub unsafe_fixes: Option<bool>,
+
     /// File path patterns to exclude from linting. Matched against the optional
     /// `path` argument of `check`/`fix`. Bare directory names (e.g. `.git`) are
     /// expanded to also match their contents.
@@ -249,6 +276,12 @@ impl LinterConfig {
         if let Some(line_length) = self.line_length {
             config.global.line_length = LineLength::new(line_length as usize);
         }
+        if let Some(length_mode) = self.length_mode {
+            config.global.length_mode = length_mode;
+        }
+        if let Some(ambiguous_width) = self.ambiguous_width {
+            config.global.ambiguous_width = ambiguous_width;
+        }
 
         // Apply flavor
         config.global.flavor = self.markdown_flavor();
@@ -260,6 +293,9 @@ impl LinterConfig {
         if let Some(ref unfixable) = self.unfixable {
             config.global.unfixable = unfixable.clone();
         }
+        if let Some(unsafe_fixes) = self.unsafe_fixes {
+            config.global.unsafe_fixes = unsafe_fixes;
+        }
 
         // Apply exclude patterns
         if let Some(ref exclude) = self.exclude {
@@ -404,15 +440,7 @@ impl Linter {
 
         let all = all_rules(&self.config);
         let rules = filter_rules(&all, &self.config.global);
-
-        match crate::lint(content, &rules, false, self.flavor, None, Some(&self.config)) {
-            Ok(warnings) => {
-                // Convert byte offsets to character offsets for JavaScript
-                let js_warnings: Vec<JsWarning> = warnings.iter().map(|w| convert_warning_for_js(w, content)).collect();
-                serde_json::to_string(&js_warnings).unwrap_or_else(|_| "[]".to_string())
-            }
-            Err(e) => format!(r#"[{{"error": "{}"}}]"#, e),
-        }
+        lint_to_json(content, &rules, self.flavor, &self.config)
     }
 
     /// Apply all auto-fixes to the content and return the fixed content
@@ -430,21 +458,30 @@ impl Linter {
             return content.to_string();
         }
 
-        let all = all_rules(&self.config);
-        let rules = filter_rules(&all, &self.config.global);
-
-        let warnings = match crate::lint(content, &rules, false, self.flavor, None, Some(&self.config)) {
-            Ok(w) => w,
-            Err(_) => return content.to_string(),
-        };
-
-        let coordinator = FixCoordinator::new();
-        let mut fixed_content = content.to_string();
+        apply_fixes(content, &self.config, self.flavor)
+    }
 
-        // WASM doesn't have file paths, so use None (falls back to global flavor)
-        match coordinator.apply_fixes_iterative(&rules, &warnings, &mut fixed_content, &self.config, 10, None) {
-            Ok(_) => fixed_content,
-            Err(_) => content.to_string(),
+    /// Open an editing session on a document
+    ///
+    /// The session keeps the document text and the configured rules, so an
+    /// editor sends only its edits through `applyEdit()` instead of the
+    /// whole text on every keystroke. A `path` matching an `exclude`
+    /// pattern makes `lint()` return `"[]"`, as with `check()`.
+    #[wasm_bindgen(js_name = createDocument)]
+    #[allow(clippy::needless_pass_by_value)] // wasm-bindgen exports can't take `Option<&str>`
+    pub fn create_document(&self, content: &str, path: Option<String>) -> Document {
+        let excluded = path
+            .as_deref()
+            .is_some_and(|p| path_matches_exclude(&self.config.global.exclude, p));
+        let all = all_rules(&self.config);
+        Document {
+            rules: filter_rules(&all, &self.config.global),
+            config: self.config.clone(),
+            flavor: self.flavor,
+            excluded,
+            content: content.to_string(),
+            block_cache: BlockCache::new(),
+            last_lint: None,
         }
     }
 
@@ -474,7 +511,10 @@ impl Linter {
             "extend_disable": self.config.global.extend_disable,
             "fixable": self.config.global.fixable,
             "unfixable": self.config.global.unfixable,
+            "unsafe_fixes": self.config.global.unsafe_fixes,
             "line_length": self.config.global.line_length.get(),
+            "length_mode": self.config.global.length_mode,
+            "ambiguous_width": self.config.global.ambiguous_width,
             "flavor": self.flavor.to_string(),
             "rules": rules_json
         })
@@ -482,6 +522,214 @@ impl Linter {
     }
 }
 
+/// A document being edited, created with `Linter.createDocument()`
+///
+/// The session keeps the rules and the results of the last lint. Block-local
+/// rules check only the blocks an edit changed, through a [`BlockCache`];
+/// rules that look at document-wide structure (heading levels, reference
+/// definitions, list nesting) re-run over the whole text. Several edits
+/// between two lints cost one lint, and a lint without edits returns the
+/// previous result.
+#[wasm_bindgen]
+pub struct Document {
+    config: Config,
+    flavor: MarkdownFlavor,
+    rules: Vec<Box<dyn crate::rule::Rule>>,
+    excluded: bool,
+    content: String,
+    /// Warnings of block-local rules per block, as of the previous lint
+    block_cache: BlockCache,
+    /// JSON of the previous lint, valid until the next edit
+    last_lint: Option<String>,
+}
+
+#[wasm_bindgen]
+impl Document {
+    /// Replace the text in `range` with `text`
+    ///
+    /// `range` is `{ start, end }` in character offsets, the same form as a
+    /// warning's `fix.range`, so `doc.applyEdit(w.fix.range, w.fix.replacement)`
+    /// applies a fix.
+    #[wasm_bindgen(js_name = applyEdit)]
+    pub fn apply_edit(&mut self, range: JsValue, text: &str) -> Result<(), JsValue> {
+        let range: JsRange =
+            serde_wasm_bindgen::from_value(range).map_err(|e| JsValue::from_str(&format!("Invalid range: {e}")))?;
+        self.replace_range(range.start, range.end, text)
+            .map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// Lint the document and return warnings as JSON, in the same form as
+    /// `Linter.check()`
+    pub fn lint(&mut self) -> String {
+        if let Some(result) = &self.last_lint {
+            return result.clone();
+        }
+        let result = if self.excluded {
+            "[]".to_string()
+        } else {
+            let result = crate::lint_with_block_cache(
+                &self.content,
+                &self.rules,
+                false,
+                self.flavor,
+                None,
+                Some(&self.config),
+                &mut self.block_cache,
+            );
+            warnings_to_json(&self.content, result)
+        };
+        self.last_lint = Some(result.clone());
+        result
+    }
+
+    /// The current document text
+    pub fn content(&self) -> String {
+        self.content.clone()
+    }
+}
+
+impl Document {
+    fn replace_range(&mut self, start: usize, end: usize, text: &str) -> Result<(), String> {
+        if start > end || end > self.content.chars().count() {
+            return Err(format!("Invalid edit range {start}..{end}"));
+        }
+        let byte_start = char_offset_to_byte_offset(&self.content, start);
+        let byte_end = char_offset_to_byte_offset(&self.content, end);
+        if self.content[byte_start..byte_end] != *text {
+            self.content.replace_range(byte_start..byte_end, text);
+            self.last_lint = None;
+        }
+        Ok(())
+    }
+}
+
+/// Apply all auto-fixes the config allows, returning `content` unchanged on error
+fn apply_fixes(content: &str, config: &Config, flavor: MarkdownFlavor) -> String {
+    let all = all_rules(config);
+    let rules = filter_rules(&all, &config.global);
+
+    let Ok(warnings) = crate::lint(content, &rules, false, flavor, None, Some(config)) else {
+        return content.to_string();
+    };
+
+    let coordinator = FixCoordinator::new();
+    let mut fixed_content = content.to_string();
+
+    // WASM doesn't have file paths, so use None (falls back to global flavor)
+    match coordinator.apply_fixes_iterative(&rules, &warnings, &mut fixed_content, config, 10, None) {
+        Ok(_) => fixed_content,
+        Err(_) => content.to_string(),
+    }
+}
+
+/// Replacement turning the original text into the fixed text
+#[derive(Serialize)]
+struct JsEdit {
+    range: JsRange,
+    replacement: String,
+}
+
+/// Fixed text together with the edits that produce it
+#[derive(Serialize)]
+struct JsFixResult {
+    content: String,
+    /// Non-overlapping edits in character offsets into the original, in order
+    edits: Vec<JsEdit>,
+}
+
+/// JSON of the fixed text with the edits that turn `content` into it
+fn fix_result_json(content: &str, fixed: String) -> String {
+    let edits = plan_edits(content, &fixed)
+        .into_iter()
+        .map(|(range, replacement)| JsEdit {
+            range: JsRange {
+                start: byte_offset_to_char_offset(content, range.start),
+                end: byte_offset_to_char_offset(content, range.end),
+            },
+            replacement,
+        })
+        .collect();
+    serde_json::to_string(&JsFixResult { content: fixed, edits }).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Fix `content` with the default configuration, applying only the fixes of
+/// the rules in `rule_filter` when given
+fn fix_with_filter(content: &str, rule_filter: Option<&[String]>) -> String {
+    let mut config = Config::default();
+    let fixed = match rule_filter {
+        Some([]) => content.to_string(),
+        Some(filter) => {
+            config.global.fixable = filter.iter().map(|name| resolve_rule_name(name)).collect();
+            apply_fixes(content, &config, MarkdownFlavor::default())
+        }
+        None => apply_fixes(content, &config, MarkdownFlavor::default()),
+    };
+    fix_result_json(content, fixed)
+}
+
+/// Apply auto-fixes with the default configuration, like "Fix all" in an editor
+///
+/// # Arguments
+/// * `content` - Markdown content to fix
+/// * `rule_filter` - Optional array of rule names or aliases (e.g.
+///   `["MD009", "no-hard-tabs"]`); only their fixes are applied
+///
+/// Returns JSON `{ content, edits }`: the fixed text and the replacements
+/// that turn the input into it, each `{ range: { start, end }, replacement }`
+/// in character offsets into the input.
+#[wasm_bindgen]
+pub fn fix(content: &str, rule_filter: JsValue) -> Result<String, JsValue> {
+    let rule_filter: Option<Vec<String>> = serde_wasm_bindgen::from_value(rule_filter)
+        .map_err(|e| JsValue::from_str(&format!("Invalid rule filter: {e}")))?;
+    Ok(fix_with_filter(content, rule_filter.as_deref()))
+}
+
+/// Format `content` like `rumdl fmt`, applying every fix the configuration allows
+///
+/// # Arguments
+/// * `content` - Markdown content to format
+/// * `config_json` - Optional configuration as a JSON string, in the same
+///   form as the `Linter` options
+///
+/// Returns JSON `{ content, edits }`, as `fix()` does.
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)] // wasm-bindgen exports can't take `Option<&str>`
+pub fn format(content: &str, config_json: Option<String>) -> Result<String, JsValue> {
+    format_with_config(content, config_json.as_deref()).map_err(|e| JsValue::from_str(&e))
+}
+
+fn format_with_config(content: &str, config_json: Option<&str>) -> Result<String, String> {
+    let linter_config: LinterConfig = match config_json {
+        Some(json) => serde_json::from_str(json).map_err(|e| format!("Invalid config: {e}"))?,
+        None => LinterConfig::default(),
+    };
+    let (config, _) = linter_config.to_config_with_warnings();
+    let fixed = apply_fixes(content, &config, linter_config.markdown_flavor());
+    Ok(fix_result_json(content, fixed))
+}
+
+/// Lint `content` and return the warnings as JSON with character offsets
+fn lint_to_json(
+    content: &str,
+    rules: &[Box<dyn crate::rule::Rule>],
+    flavor: MarkdownFlavor,
+    config: &Config,
+) -> String {
+    warnings_to_json(content, crate::lint(content, rules, false, flavor, None, Some(config)))
+}
+
+/// Warnings as JSON with character offsets, or an error entry
+fn warnings_to_json(content: &str, result: crate::rule::LintResult) -> String {
+    match result {
+        Ok(warnings) => {
+            // Convert byte offsets to character offsets for JavaScript
+            let js_warnings: Vec<JsWarning> = warnings.iter().map(|w| convert_warning_for_js(w, content)).collect();
+            serde_json::to_string(&js_warnings).unwrap_or_else(|_| "[]".to_string())
+        }
+        Err(e) => format!(r#"[{{"error": "{e}"}}]"#),
+    }
+}
+
 /// Get the rumdl version
 #[wasm_bindgen]
 pub fn get_version() -> String {
@@ -553,6 +801,13 @@ mod tests {
         let internal = config.to_config();
         assert!(internal.global.disable.contains(&"MD041".to_string()));
         assert_eq!(internal.global.line_length.get(), 100);
+        assert_eq!(internal.global.length_mode, LengthMode::Visual);
+
+        let config: LinterConfig =
+            serde_json::from_value(serde_json::json!({ "length-mode": "chars", "ambiguous-width": "wide" })).unwrap();
+        let internal = config.to_config();
+        assert_eq!(internal.global.length_mode, LengthMode::Chars);
+        assert_eq!(internal.global.ambiguous_width, AmbiguousWidth::Wide);
     }
 
     #[test]
@@ -1492,6 +1747,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_document_edits_and_lints() {
+        let config = LinterConfig::default();
+        let linter = Linter {
+            config: config.to_config(),
+            flavor: config.markdown_flavor(),
+            config_warnings: Vec::new(),
+        };
+
+        let mut doc = linter.create_document("# Tïtle\n\n## Level 2\n", None);
+        assert_eq!(doc.lint(), "[]");
+
+        // Turn "## Level 2" into "#### Level 4", offsets counted in characters
+        doc.replace_range(9, 11, "####").unwrap();
+        doc.replace_range(20, 21, "4").unwrap();
+        assert_eq!(doc.content(), "# Tïtle\n\n#### Level 4\n");
+        let warnings: Vec<serde_json::Value> = serde_json::from_str(&doc.lint()).unwrap();
+        assert!(warnings.iter().any(|w| w["rule_name"] == "MD001"));
+        assert_eq!(doc.lint(), linter.check(&doc.content(), None));
+
+        assert!(doc.replace_range(5, 4, "").is_err());
+        assert!(doc.replace_range(0, 100, "").is_err());
+    }
+
+    /// A block-local rule that counts how many documents and blocks it checks
+    #[derive(Clone)]
+    struct CountingRule(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+    impl crate::rule::Rule for CountingRule {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn description(&self) -> &'static str {
+            "Counts checks"
+        }
+
+        fn check(&self, _ctx: &crate::lint_context::LintContext) -> crate::rule::LintResult {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+
+        fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, crate::rule::LintError> {
+            Ok(ctx.content.to_string())
+        }
+
+        fn is_block_local(&self) -> bool {
+            true
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_document_lint_checks_only_changed_blocks() {
+        let config = LinterConfig::default();
+        let linter = Linter {
+            config: config.to_config(),
+            flavor: config.markdown_flavor(),
+            config_warnings: Vec::new(),
+        };
+        let checks = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let checked = || checks.load(std::sync::atomic::Ordering::Relaxed);
+
+        let mut doc = linter.create_document("# Title\n\nOne\n\nTwo\n\nThree\n", None);
+        doc.rules = vec![Box::new(CountingRule(checks.clone()))];
+        doc.lint();
+        assert_eq!(checked(), 4, "every block is checked once");
+
+        // Replace "Two": only its block is checked again
+        doc.replace_range(14, 17, "2").unwrap();
+        doc.lint();
+        assert_eq!(checked(), 5);
+    }
+
+    #[test]
+    fn test_document_excluded_path_returns_no_warnings() {
+        let linter = exclude_linter(vec!["drafts/**"]);
+        let mut doc = linter.create_document(LINT_TRIGGERING, Some("drafts/a.md".to_string()));
+        assert_eq!(doc.lint(), "[]");
+    }
+
+    #[test]
+    fn test_fix_reports_edits_in_char_offsets() {
+        let content = "# Tïtle\n\nTrailing   \nTab\there\n";
+        let result: serde_json::Value = serde_json::from_str(&fix_with_filter(content, None)).unwrap();
+        assert_eq!(result["content"], "# Tïtle\n\nTrailing\nTab    here\n");
+        assert_eq!(
+            result["edits"],
+            serde_json::json!([{ "range": { "start": 17, "end": 25 }, "replacement": "\nTab    " }])
+        );
+    }
+
+    #[test]
+    fn test_fix_rule_filter_limits_fixes() {
+        let content = "Trailing   \nTab\there\n";
+        let only_tabs: serde_json::Value =
+            serde_json::from_str(&fix_with_filter(content, Some(&["no-hard-tabs".to_string()]))).unwrap();
+        assert_eq!(only_tabs["content"], "Trailing   \nTab    here\n");
+
+        let nothing: serde_json::Value = serde_json::from_str(&fix_with_filter(content, Some(&[]))).unwrap();
+        assert_eq!(nothing["content"], content);
+        assert_eq!(nothing["edits"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_format_uses_config_json() {
+        let content = "Tab\there\n";
+        let result: serde_json::Value =
+            serde_json::from_str(&format_with_config(content, Some(r#"{"disable": ["MD010"]}"#)).unwrap()).unwrap();
+        assert_eq!(result["content"], content);
+
+        let result: serde_json::Value = serde_json::from_str(&format_with_config(content, None).unwrap()).unwrap();
+        assert_eq!(result["content"], "Tab    here\n");
+        assert!(format_with_config(content, Some("{")).is_err());
+    }
+
     // ========== Exclude pattern tests ==========
 
     fn exclude_linter(patterns: Vec<&str>) -> Linter {
@@ -16,8 +16,9 @@ pub enum ChangeKind {
     SourceFile,
 }
 
-/// Detects what kind of change occurred based on the file extension
-pub fn change_detected(event: &Event) -> Option<ChangeKind> {
+/// Detects what kind of change occurred based on the file extension, with
+/// `extensions` the configured markdown extensions
+pub fn change_detected(event: &Event, extensions: &[String]) -> Option<ChangeKind> {
     // Skip access and other non-modification events
     if !matches!(
         event.kind,
@@ -38,7 +39,7 @@ pub fn change_detected(event: &Event) -> Option<ChangeKind> {
 
         // Check for markdown files
         if let Some(extension) = path.extension()
-            && matches!(extension.to_str(), Some("md" | "markdown" | "mdown" | "mkd" | "mdx"))
+            && rumdl_lib::discovery::is_extension_in(extension, extensions)
         {
             source_file = true;
         }
@@ -73,8 +74,9 @@ pub fn run_watch_mode(
     // Load initial configuration
     let mut sourced = crate::load_config_with_cli_error_handling_with_dir(global_config_path, isolated, discovery_dir);
 
-    // Apply inline `--config` rule overrides at CLI precedence
-    crate::cli_config_override::apply_inline_overrides(&mut sourced, inline_overrides);
+    // Apply the `--rule-profile` table and inline `--config` rule overrides at CLI precedence
+    let mut overrides = crate::commands::check::resolve_rule_profile(&sourced, args, inline_overrides);
+    crate::cli_config_override::apply_inline_overrides(&mut sourced, &overrides);
 
     // Apply CLI argument overrides (e.g., --flavor)
     crate::apply_cli_overrides(&mut sourced, args);
@@ -145,7 +147,7 @@ pub fn run_watch_mode(
         workspace_cache_dir: None,
         project_root: project_root.as_deref(),
         grouping_root: project_root.as_deref(),
-        inline_overrides,
+        inline_overrides: &overrides,
         explicit_config,
         isolated,
     });
@@ -162,7 +164,7 @@ pub fn run_watch_mode(
                 match event_result {
                     Ok(first_event) => {
                         // Check what kind of change occurred
-                        let Some(mut change_kind) = change_detected(&first_event) else {
+                        let Some(mut change_kind) = change_detected(&first_event, &config.global.extensions) else {
                             continue;
                         };
 
@@ -172,7 +174,7 @@ pub fn run_watch_mode(
                             // Try to receive more events with a short timeout
                             if let Ok(Ok(event)) = rx.recv_timeout(Duration::from_millis(10)) {
                                 // If we get a config change, that takes priority
-                                if let Some(kind) = change_detected(&event)
+                                if let Some(kind) = change_detected(&event, &config.global.extensions)
                                     && matches!(kind, ChangeKind::Configuration)
                                 {
                                     change_kind = ChangeKind::Configuration;
@@ -189,8 +191,9 @@ pub fn run_watch_mode(
                                 discovery_dir,
                             );
 
-                            // Re-apply inline `--config` rule overrides
-                            crate::cli_config_override::apply_inline_overrides(&mut sourced, inline_overrides);
+                            // Re-resolve the profile and re-apply inline `--config` rule overrides
+                            overrides = crate::commands::check::resolve_rule_profile(&sourced, args, inline_overrides);
+                            crate::cli_config_override::apply_inline_overrides(&mut sourced, &overrides);
 
                             // Re-apply CLI argument overrides (e.g., --flavor)
                             crate::apply_cli_overrides(&mut sourced, args);
@@ -237,7 +240,7 @@ pub fn run_watch_mode(
                             workspace_cache_dir: None,
                             project_root: project_root.as_deref(),
                             grouping_root: project_root.as_deref(),
-                            inline_overrides,
+                            inline_overrides: &overrides,
                             explicit_config,
                             isolated,
                         });
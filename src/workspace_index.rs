@@ -44,7 +44,7 @@ fn hex_digit_to_value(c: u8) -> Option<u8> {
 /// URL-decode a string, handling percent-encoded characters.
 /// Returns the decoded string, or the original if decoding fails.
 /// Used for matching URL-encoded CJK fragments against raw anchors.
-fn url_decode(s: &str) -> String {
+pub(crate) fn url_decode(s: &str) -> String {
     // Fast path: no percent signs means no encoding
     if !s.contains('%') {
         return s.to_string();
@@ -154,10 +154,10 @@ pub fn extract_cross_file_links(ctx: &LintContext) -> ExtractedCrossFileLinks {
     let line_index = &ctx.line_index;
 
     // Track which lines we've already processed to avoid duplicates
-    // (ctx.links may have multiple entries for the same line)
+    // (ctx.links() may have multiple entries for the same line)
     let mut processed_lines = HashSet::new();
 
-    for link in &ctx.links {
+    for link in ctx.links() {
         let line_idx = link.line - 1;
         if line_idx >= lines.len() {
             continue;
@@ -259,6 +259,111 @@ pub fn extract_cross_file_links(ctx: &LintContext) -> ExtractedCrossFileLinks {
     links
 }
 
+/// `src`-style attributes of HTML media tags that point at a file
+static HTML_ASSET_ATTR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\b(src|srcset|poster|href)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+
+/// HTML tags whose attributes can reference an asset file
+const HTML_ASSET_TAGS: &[&str] = &["img", "source", "video", "audio", "a", "link", "object", "embed"];
+
+/// Extract every local, non-Markdown file a document references.
+///
+/// Covers inline and reference links and images, reference definitions, and
+/// `src`/`srcset`/`poster`/`href` attributes of HTML media tags. Targets are
+/// returned as written (percent-decoded, query and fragment stripped), in
+/// document order without duplicates. Root-relative targets keep their leading
+/// `/` so callers can resolve them against their own roots.
+pub fn extract_asset_references(ctx: &LintContext) -> Vec<String> {
+    let mut targets: Vec<&str> = Vec::new();
+    targets.extend(ctx.links().iter().map(|link| link.url.as_ref()));
+    targets.extend(ctx.images().iter().map(|image| image.url.as_ref()));
+    targets.extend(ctx.reference_defs.iter().map(|def| def.url.as_str()));
+
+    let html_tags = ctx.html_tags();
+    for tag in html_tags.iter() {
+        if tag.is_closing || !HTML_ASSET_TAGS.contains(&tag.tag_name.to_ascii_lowercase().as_str()) {
+            continue;
+        }
+        let Some(tag_text) = ctx.content.get(tag.byte_offset..tag.byte_end) else {
+            continue;
+        };
+        for caps in HTML_ASSET_ATTR_REGEX.captures_iter(tag_text) {
+            let Some(value) = caps.get(2).or_else(|| caps.get(3)) else {
+                continue;
+            };
+            if caps[1].eq_ignore_ascii_case("srcset") {
+                // `a.png 1x, b.png 2x`: the URL is the first token of each candidate
+                targets.extend(value.as_str().split(',').filter_map(|c| c.split_whitespace().next()));
+            } else {
+                targets.push(value.as_str());
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut references = Vec::new();
+    for target in targets {
+        let target = target.trim().trim_start_matches('<').trim_end_matches('>');
+        if target.is_empty()
+            || target.starts_with('#')
+            || target.starts_with("//")
+            || target.starts_with("{{")
+            || target.starts_with("{%")
+            || target.starts_with('~')
+            || target.starts_with('@')
+            || PROTOCOL_DOMAIN_REGEX.is_match(target)
+        {
+            continue;
+        }
+        let path = url_decode(strip_query_and_fragment(target));
+        if path.is_empty() || path.ends_with('/') || is_markdown_file(&path) {
+            continue;
+        }
+        if seen.insert(path.clone()) {
+            references.push(path);
+        }
+    }
+    references
+}
+
+/// Extract a document's link reference definitions (`[label]: url`), in
+/// document order.
+///
+/// Labels are normalized per CommonMark §4.7 (case-folded, internal
+/// whitespace collapsed). Definitions inside front matter or HTML comments
+/// are skipped, since they never define links.
+pub fn extract_reference_definitions(ctx: &LintContext) -> Vec<ReferenceDefinitionIndex> {
+    ctx.reference_defs
+        .iter()
+        .filter(|def| {
+            ctx.lines
+                .get(def.line - 1)
+                .is_none_or(|line| !line.in_front_matter && !line.in_html_comment)
+        })
+        .map(|def| {
+            let (line, column) = ctx.offset_to_line_col(def.byte_offset);
+            ReferenceDefinitionIndex {
+                label: def.id.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase(),
+                url: def.url.clone(),
+                line,
+                column,
+            }
+        })
+        .collect()
+}
+
+/// The first level-1 heading of a file, which documentation generators such
+/// as MkDocs and Docusaurus show as the page title in navigation
+pub fn extract_page_title(ctx: &LintContext) -> Option<PageTitleIndex> {
+    ctx.valid_headings()
+        .find(|heading| heading.heading.level == 1)
+        .map(|heading| PageTitleIndex {
+            text: heading.heading.text(ctx.content).trim().to_string(),
+            line: heading.line_num,
+            column: heading.heading.marker_column.min(heading.heading.content_column) + 1,
+        })
+}
+
 /// Magic bytes identifying a workspace index cache file
 #[cfg(feature = "native")]
 const CACHE_MAGIC: &[u8; 4] = b"RWSI";
@@ -267,9 +372,13 @@ const CACHE_MAGIC: &[u8; 4] = b"RWSI";
 /// or when the meaning of persisted fields changes such that older caches are
 /// no longer correct. Version 8 forces a rebuild so the new `root_relative_links`
 /// field is populated; earlier caches lack it, leaving find-references unable to
-/// discover root-relative (`/path`) links until a rescan.
+/// discover root-relative (`/path`) links until a rescan. Version 10 does the
+/// same for `asset_references`, which would otherwise make every asset look
+/// unreferenced to MD086. Version 11 does the same for `reference_definitions`,
+/// which MD091 compares across snippet includes. Version 12 does the same for
+/// `page_title`, which MD096 compares across the workspace.
 #[cfg(feature = "native")]
-const CACHE_FORMAT_VERSION: u32 = 8;
+const CACHE_FORMAT_VERSION: u32 = 12;
 
 /// Cache file name within the version directory
 #[cfg(feature = "native")]
@@ -305,9 +414,25 @@ pub struct FileIndex {
     /// not use these, so they never affect diagnostics.
     #[serde(default)]
     pub root_relative_links: Vec<CrossFileLinkIndex>,
+    /// Files this file includes through MkDocs snippet markers (`--8<--`),
+    /// resolved to canonical paths. Their content becomes part of this page
+    /// at build time.
+    #[serde(default)]
+    pub snippet_includes: Vec<PathBuf>,
+    /// Local non-Markdown files this file references (images and other
+    /// assets), as written. See [`extract_asset_references`].
+    #[serde(default)]
+    pub asset_references: Vec<String>,
     /// Defined reference IDs (e.g., from `[ref]: url` definitions)
     /// Used to filter out reference links that have explicit definitions
     pub defined_references: HashSet<String>,
+    /// Reference definitions with their destinations, in document order
+    /// (for MD091 conflict detection across snippet includes)
+    #[serde(default)]
+    pub reference_definitions: Vec<ReferenceDefinitionIndex>,
+    /// The first level-1 heading, if any (for MD096 duplicate page titles)
+    #[serde(default)]
+    pub page_title: Option<PageTitleIndex>,
     /// Content hash for change detection
     pub content_hash: String,
     /// O(1) anchor lookup: lowercased anchor → heading index
@@ -383,6 +508,30 @@ pub struct CrossFileLinkIndex {
     pub column: usize,
 }
 
+/// A link reference definition (`[label]: url`) for cross-file comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceDefinitionIndex {
+    /// Normalized label: case-folded with internal whitespace collapsed
+    pub label: String,
+    /// Destination URL
+    pub url: String,
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// Column number (1-indexed)
+    pub column: usize,
+}
+
+/// The first level-1 heading of a file, for cross-file comparison
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PageTitleIndex {
+    /// Heading text, without markers or custom ID syntax
+    pub text: String,
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// Column number (1-indexed)
+    pub column: usize,
+}
+
 /// Information about a vulnerable anchor (heading without custom ID)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VulnerableAnchor {
@@ -508,16 +657,44 @@ impl WorkspaceIndex {
         // Note: We don't remove it as a target - other files may still link to it
         self.clear_reverse_deps_as_source(path);
 
-        // Build new reverse deps from cross_file_links
+        // Build new reverse deps from cross_file_links and snippet includes
         for link in &index.cross_file_links {
             let target = self.resolve_target_path(path, &link.target_path);
             self.reverse_deps.entry(target).or_default().insert(path.to_path_buf());
         }
+        for snippet in &index.snippet_includes {
+            self.reverse_deps
+                .entry(snippet.clone())
+                .or_default()
+                .insert(path.to_path_buf());
+        }
 
         self.files.insert(path.to_path_buf(), index);
         self.version = self.version.wrapping_add(1);
     }
 
+    /// Every asset path referenced by an indexed file, resolved and normalized.
+    ///
+    /// Relative references resolve against the referencing file's directory.
+    /// Root-relative references (`/images/a.png`) resolve against each of
+    /// `roots`, since any of them may be the site root the link is written for.
+    pub fn referenced_assets(&self, roots: &[PathBuf]) -> HashSet<PathBuf> {
+        let mut referenced = HashSet::new();
+        for (path, index) in &self.files {
+            for reference in &index.asset_references {
+                match reference.strip_prefix('/') {
+                    Some(rooted) => {
+                        referenced.extend(roots.iter().map(|root| Self::normalize_path(&root.join(rooted))));
+                    }
+                    None => {
+                        referenced.insert(self.resolve_target_path(path, reference));
+                    }
+                }
+            }
+        }
+        referenced
+    }
+
     /// Get files that depend on (link to) the given file
     ///
     /// Returns a list of file paths that contain links targeting this file.
@@ -529,6 +706,34 @@ impl WorkspaceIndex {
             .unwrap_or_default()
     }
 
+    /// Files that include `path` through MkDocs snippet markers
+    pub fn get_snippet_includers(&self, path: &Path) -> Vec<&Path> {
+        self.files
+            .iter()
+            .filter(|(_, index)| index.snippet_includes.iter().any(|p| p == path))
+            .map(|(file, _)| file.as_path())
+            .collect()
+    }
+
+    /// The file at `path` followed by every snippet it includes, transitively.
+    ///
+    /// These files together make up the page MkDocs renders for `path`. Cycles
+    /// and snippets missing from the index are skipped.
+    pub fn snippet_closure(&self, path: &Path) -> Vec<(&Path, &FileIndex)> {
+        let mut result: Vec<(&Path, &FileIndex)> = Vec::new();
+        let mut pending = vec![path];
+        while let Some(current) = pending.pop() {
+            if result.iter().any(|(seen, _)| *seen == current) {
+                continue;
+            }
+            if let Some((file, index)) = self.files.get_key_value(current) {
+                result.push((file.as_path(), index));
+                pending.extend(index.snippet_includes.iter().rev().map(PathBuf::as_path));
+            }
+        }
+        result
+    }
+
     /// Check if a file needs re-indexing based on its content hash
     ///
     /// Returns `true` if the file is not in the index or has a different hash.
@@ -679,6 +884,7 @@ impl WorkspaceIndex {
                 .cross_file_links
                 .iter()
                 .map(|link| self.resolve_target_path(path, &link.target_path))
+                .chain(index.snippet_includes.iter().cloned())
                 .collect(),
             None => return,
         };
@@ -717,7 +923,7 @@ impl WorkspaceIndex {
     }
 
     /// Normalize a path by resolving . and .. components
-    fn normalize_path(path: &Path) -> PathBuf {
+    pub(crate) fn normalize_path(path: &Path) -> PathBuf {
         let mut components = Vec::new();
 
         for component in path.components() {
@@ -1589,6 +1795,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_asset_references_covers_markdown_and_html() {
+        use crate::config::MarkdownFlavor;
+
+        let content = "![a](img/a.png?raw=true) [pdf](files/report.pdf) [doc](other.md)\n\n\
+![b][logo] ![c](https://example.com/c.png) `![d](d.png)`\n\n\
+<img src=\"/assets/e%20f.svg\" srcset=\"g.png 1x, h.png 2x\">\n\n\
+[logo]: img/logo.png\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::default(), None);
+        let references = extract_asset_references(&ctx);
+
+        for expected in [
+            "img/a.png",
+            "files/report.pdf",
+            "img/logo.png",
+            "/assets/e f.svg",
+            "g.png",
+            "h.png",
+        ] {
+            assert!(
+                references.contains(&expected.to_string()),
+                "{expected} in {references:?}"
+            );
+        }
+        assert!(
+            !references
+                .iter()
+                .any(|r| r.ends_with(".md") || r.contains("example.com") || r == "d.png")
+        );
+        assert_eq!(references.iter().filter(|r| *r == "img/logo.png").count(), 1);
+    }
+
+    #[test]
+    fn test_referenced_assets_resolves_relative_and_root_relative() {
+        let mut index = WorkspaceIndex::new();
+        let file_index = FileIndex {
+            asset_references: vec!["../img/a.png".to_string(), "/static/b.png".to_string()],
+            ..FileIndex::default()
+        };
+        index.insert_file(PathBuf::from("/project/docs/guide.md"), file_index);
+
+        let referenced = index.referenced_assets(&[PathBuf::from("/project"), PathBuf::from("/project/site")]);
+        assert!(referenced.contains(Path::new("/project/img/a.png")));
+        assert!(referenced.contains(Path::new("/project/static/b.png")));
+        assert!(referenced.contains(Path::new("/project/site/static/b.png")));
+    }
+
     // =============================================================================
     // Tests for extract_cross_file_links utility
     // =============================================================================
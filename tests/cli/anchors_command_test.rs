@@ -0,0 +1,70 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn setup_workspace(root: &Path) {
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join(".rumdl.toml"), "[global]\nextend-enable = [\"MD101\"]\n").unwrap();
+    fs::write(root.join("README.md"), "# Home\n").unwrap();
+    fs::write(root.join("docs/guide.md"), "# Guide\n\n## Setup\n\n## Usage {#use}\n").unwrap();
+}
+
+#[test]
+fn test_anchors_prints_manifest_without_writing() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    setup_workspace(root);
+
+    let output = cargo_bin_cmd!("rumdl")
+        .current_dir(root.join("docs"))
+        .arg("anchors")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let manifest: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        manifest,
+        serde_json::json!({ "README.md": ["home"], "docs/guide.md": ["guide", "setup", "use"] })
+    );
+    assert!(!root.join(".rumdl-anchors.json").exists());
+}
+
+#[test]
+fn test_anchors_write_then_check_flags_changed_slug() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    setup_workspace(root);
+
+    cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["anchors", "--write"])
+        .assert()
+        .success();
+    assert!(root.join(".rumdl-anchors.json").exists());
+
+    cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["check", "."])
+        .assert()
+        .success();
+
+    fs::write(
+        root.join("docs/guide.md"),
+        "# Guide\n\n## Getting set up\n\n## Usage {#use}\n",
+    )
+    .unwrap();
+    let output = cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["check", "."])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("docs/guide.md:3:4: [MD101] Heading anchor changed from '#setup' to '#getting-set-up'"),
+        "{stdout}"
+    );
+}
@@ -122,3 +122,129 @@ fn per_file_ignores_suppresses_cross_file_rule_on_cache_hit() {
         "per-file-ignores must suppress cross-file MD051 on a cache hit, got:\n{out}"
     );
 }
+
+/// MD086 reports images nothing references against the image path itself. The
+/// references come from the workspace index, so they must survive the
+/// lint-cache-hit + index-rebuild path, and `per-file-ignores` applies to the
+/// reported image path.
+#[test]
+fn unused_images_survive_index_rebuild_and_honor_per_file_ignores() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[global]\nextend-enable = [\"MD086\"]\n\n[per-file-ignores]\n\"img/keep.png\" = [\"MD086\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("img")).unwrap();
+    for image in ["used.png", "unused.png", "keep.png"] {
+        fs::write(dir.join("img").join(image), "").unwrap();
+    }
+    fs::write(dir.join("a.md"), "# Source\n\n![Used](img/used.png)\n").unwrap();
+
+    let first = run(dir, &["check", "."]);
+    assert!(first.contains("img/unused.png:1:1: [MD086]"), "got:\n{first}");
+    assert!(!first.contains("img/used.png"), "got:\n{first}");
+    assert!(!first.contains("img/keep.png"), "got:\n{first}");
+
+    delete_workspace_index(dir);
+
+    let second = run(dir, &["check", "."]);
+    assert!(second.contains("img/unused.png:1:1: [MD086]"), "got:\n{second}");
+    assert!(!second.contains("img/used.png"), "got:\n{second}");
+}
+
+/// MD096 compares page titles recorded in the workspace index, so they must
+/// survive the lint-cache-hit + index-rebuild path. Titles are only compared
+/// within one documentation root, and inline disables apply.
+#[test]
+fn duplicate_page_titles_survive_index_rebuild() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[global]\nextend-enable = [\"MD096\"]\ndisable = [\"MD041\"]\n\n[MD096]\nroots = [\"docs\", \"blog\"]\nignore = [\"Overview\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("docs/guide")).unwrap();
+    fs::create_dir_all(dir.join("blog")).unwrap();
+    fs::write(dir.join("docs/install.md"), "# Installation\n").unwrap();
+    fs::write(dir.join("docs/guide/setup.md"), "Setup\n=====\n\n# installation\n").unwrap();
+    fs::write(dir.join("docs/guide/steps.md"), "Installation\n============\n").unwrap();
+    fs::write(
+        dir.join("docs/guide/quiet.md"),
+        "<!-- rumdl-disable MD096 -->\n# Installation\n",
+    )
+    .unwrap();
+    fs::write(dir.join("docs/a.md"), "# Overview\n").unwrap();
+    fs::write(dir.join("docs/b.md"), "# Overview\n").unwrap();
+    fs::write(dir.join("blog/post.md"), "# Installation\n").unwrap();
+
+    for pass in 0..2 {
+        let output = run(dir, &["check", "."]);
+        assert!(
+            output.contains("docs/install.md:1:1: [MD096] Duplicate page title 'Installation' (also in guide/quiet.md:2 and 1 other file(s))"),
+            "pass {pass}, got:\n{output}"
+        );
+        // The first H1 is the title, whichever heading style it uses
+        assert!(
+            output.contains("docs/guide/steps.md:1:1: [MD096] Duplicate page title 'Installation' (also in quiet.md:2 and 1 other file(s))"),
+            "pass {pass}, got:\n{output}"
+        );
+        assert!(!output.contains("setup.md:4:1: [MD096]"), "pass {pass}, got:\n{output}");
+        assert!(!output.contains("quiet.md:2:1: [MD096]"), "pass {pass}, got:\n{output}");
+        assert!(!output.contains("blog/post.md"), "pass {pass}, got:\n{output}");
+        assert!(!output.contains("'Overview'"), "pass {pass}, got:\n{output}");
+        if pass == 0 {
+            delete_workspace_index(dir);
+        }
+    }
+}
+
+/// MD074 reports Markdown files under docs_dir that `mkdocs.yml` nav leaves
+/// out against the orphaned file itself, using the workspace file list, so the
+/// orphans must survive the lint-cache-hit + index-rebuild path. Pages matching
+/// `not_in_nav` are explicitly excluded.
+#[test]
+fn mkdocs_nav_orphans_survive_index_rebuild() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[global]\nflavor = \"mkdocs\"\nextend-enable = [\"MD074\"]\ndisable = [\"MD041\"]\n\n[MD074]\nomitted-files = \"warn\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("mkdocs.yml"),
+        "site_name: Test\nnav:\n  - Home: index.md\n  - Guide: guide.md\n  - Missing: missing.md\nnot_in_nav: |\n  /drafts/\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("docs/drafts")).unwrap();
+    fs::write(dir.join("docs/index.md"), "# Home\n").unwrap();
+    fs::write(dir.join("docs/guide.md"), "# Guide\n").unwrap();
+    fs::write(dir.join("docs/orphan.md"), "# Orphan\n").unwrap();
+    fs::write(dir.join("docs/drafts/idea.md"), "# Idea\n").unwrap();
+
+    for pass in 0..2 {
+        let output = run(dir, &["check", "."]);
+        assert!(
+            output.contains("docs/orphan.md:1:1: [MD074] File not referenced in nav (in mkdocs.yml)"),
+            "pass {pass}, got:\n{output}"
+        );
+        assert_eq!(
+            output.matches("File not referenced in nav").count(),
+            1,
+            "pass {pass}, got:\n{output}"
+        );
+        if pass == 0 {
+            assert!(
+                output.contains("points to non-existent file: missing.md"),
+                "pass {pass}, got:\n{output}"
+            );
+            delete_workspace_index(dir);
+        }
+    }
+}
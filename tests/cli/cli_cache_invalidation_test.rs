@@ -0,0 +1,139 @@
+//! The lint cache must never return results computed under different
+//! settings: entries are invalidated by config edits, and files sharing
+//! content share an entry only when their per-file settings match. Renaming
+//! a linked file or image invalidates the entries of the files linking to it.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check .` in `dir` with the cache pinned to `dir/.rumdl_cache`,
+/// returning combined stdout+stderr.
+fn check(dir: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .env("RUMDL_CACHE_DIR", dir.join(".rumdl_cache"))
+        .args(["check", "."])
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    combined
+}
+
+const TRAILING_SPACES: &str = "# Title\n\nSome text   \n";
+
+#[test]
+fn identical_files_with_different_per_file_ignores_do_not_share_results() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[per-file-ignores]\n\"ignored.md\" = [\"MD009\"]\n",
+    )
+    .unwrap();
+    fs::write(dir.join("ignored.md"), TRAILING_SPACES).unwrap();
+    fs::write(dir.join("checked.md"), TRAILING_SPACES).unwrap();
+
+    for run in ["cold", "warm"] {
+        let output = check(dir);
+        assert!(
+            output.contains("checked.md:3"),
+            "{run} run: MD009 must be reported for checked.md, got:\n{output}"
+        );
+        assert!(
+            !output.contains("ignored.md:3"),
+            "{run} run: MD009 must stay ignored for ignored.md, got:\n{output}"
+        );
+    }
+}
+
+#[test]
+fn identical_files_with_different_flavors_do_not_share_results() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    // An indented admonition body is a code block (MD046) except in MkDocs
+    let content = "# Title\n\n!!! note\n\n    Admonition body text.\n\n```python\ncode\n```\n";
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[per-file-flavor]\n\"mkdocs.md\" = \"mkdocs\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join("mkdocs.md"), content).unwrap();
+    fs::write(dir.join("standard.md"), content).unwrap();
+
+    for run in ["cold", "warm"] {
+        let output = check(dir);
+        assert!(
+            output.contains("standard.md:5:1: [MD046]"),
+            "{run} run: MD046 must be reported for standard.md, got:\n{output}"
+        );
+        assert!(
+            !output.contains("mkdocs.md:5:1: [MD046]"),
+            "{run} run: MD046 must not be reported for the MkDocs file, got:\n{output}"
+        );
+    }
+}
+
+#[test]
+fn editing_config_invalidates_cached_results() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::write(dir.join(".rumdl.toml"), "").unwrap();
+    fs::write(dir.join("doc.md"), TRAILING_SPACES).unwrap();
+
+    let before = check(dir);
+    assert!(before.contains("MD009"), "baseline should report MD009, got:\n{before}");
+
+    fs::write(dir.join(".rumdl.toml"), "[global]\ndisable = [\"MD009\"]\n").unwrap();
+    let after = check(dir);
+    assert!(
+        !after.contains("MD009"),
+        "disabling MD009 in config must not return cached results, got:\n{after}"
+    );
+
+    fs::write(dir.join(".rumdl.toml"), "[MD009]\nbr-spaces = 3\n").unwrap();
+    let reconfigured = check(dir);
+    assert!(
+        !reconfigured.contains("MD009"),
+        "three trailing spaces are a line break with br-spaces = 3, got:\n{reconfigured}"
+    );
+}
+
+#[test]
+fn renaming_a_link_target_invalidates_cached_results() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[MD057]\nabsolute-links = \"relative_to_roots\"\nroots = [\"static\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("img")).unwrap();
+    fs::create_dir_all(dir.join("static/img")).unwrap();
+    fs::write(dir.join("img/diagram.png"), b"png").unwrap();
+    fs::write(dir.join("static/img/logo.png"), b"png").unwrap();
+    fs::write(
+        dir.join("index.md"),
+        "# Title\n\n![Diagram](img/diagram.png)\n\n![Logo](/img/logo.png)\n",
+    )
+    .unwrap();
+
+    for run in ["cold", "warm"] {
+        let output = check(dir);
+        assert!(!output.contains("[MD057]"), "{run} run: targets exist, got:\n{output}");
+    }
+
+    fs::rename(dir.join("img/diagram.png"), dir.join("img/chart.png")).unwrap();
+    fs::rename(dir.join("static/img/logo.png"), dir.join("static/img/brand.png")).unwrap();
+    let output = check(dir);
+    assert!(
+        output.contains("'img/diagram.png'") && output.contains("'/img/logo.png'"),
+        "renamed targets must be reported despite the unchanged file, got:\n{output}"
+    );
+
+    fs::rename(dir.join("img/chart.png"), dir.join("img/diagram.png")).unwrap();
+    fs::rename(dir.join("static/img/brand.png"), dir.join("static/img/logo.png")).unwrap();
+    let output = check(dir);
+    assert!(!output.contains("[MD057]"), "restored targets, got:\n{output}");
+}
@@ -0,0 +1,91 @@
+//! The `[ci]` config section sets the exit policy; `--fail-on` and
+//! `--max-warnings` override it.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check` in `dir` with `extra` arguments, returning the exit code and output
+fn check(dir: &Path, extra: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    (output.status.code().unwrap_or(-1), combined)
+}
+
+/// A project with three MD009 warnings and one MD042 error
+fn project(ci: &str) -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join(".rumdl.toml"),
+        format!("[global]\nenable = [\"MD009\", \"MD042\"]\n\n{ci}"),
+    )
+    .unwrap();
+    fs::write(temp.path().join("a.md"), "# A   \n\none   \n\ntwo   \n").unwrap();
+    fs::write(temp.path().join("b.md"), "# B\n\n[empty]()\n").unwrap();
+    temp
+}
+
+#[test]
+fn max_warnings_tolerates_violations_up_to_the_limit() {
+    let temp = project("[ci]\nmax-warnings = 4\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{output}");
+    // Violations are still reported
+    assert!(output.contains("[MD042]"), "got:\n{output}");
+
+    let temp = project("[ci]\nmax-warnings = 3\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 1, "got:\n{output}");
+}
+
+#[test]
+fn max_warnings_counts_only_the_fail_on_severity() {
+    let temp = project("[ci]\nfail-on = \"error\"\nmax-warnings = 1\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{output}");
+
+    let temp = project("[ci]\nfail-on = \"error\"\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 1, "got:\n{output}");
+}
+
+#[test]
+fn cli_flags_override_ci_section() {
+    let temp = project("[ci]\nfail-on = \"never\"\nmax-warnings = 10\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{output}");
+
+    let (code, output) = check(temp.path(), &["--fail-on", "error"]);
+    assert_eq!(code, 0, "max-warnings from [ci] still applies\ngot:\n{output}");
+
+    let (code, output) = check(temp.path(), &["--fail-on", "any", "--max-warnings", "3"]);
+    assert_eq!(code, 1, "got:\n{output}");
+}
+
+#[test]
+fn ci_section_in_pyproject() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join("pyproject.toml"),
+        "[tool.rumdl]\nenable = [\"MD009\"]\n\n[tool.rumdl.ci]\nmax_warnings = 1\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("a.md"), "# A\n\none   \n").unwrap();
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{output}");
+}
+
+#[test]
+fn invalid_ci_section_is_reported() {
+    let temp = project("[ci]\nmax-warning = 10\n");
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 1, "got:\n{output}");
+    assert!(output.contains("Invalid [ci] section"), "got:\n{output}");
+    assert!(output.contains("max-warning"), "got:\n{output}");
+}
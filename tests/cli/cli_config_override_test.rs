@@ -161,6 +161,7 @@ fn inline_override_enables_md013_reflow() {
         .args([
             "check",
             "--fix",
+            "--unsafe-fixes",
             "--no-config",
             "--config",
             "MD013.line_length=40",
@@ -393,3 +393,55 @@ fn test_config_get_alias_reflects_project_config_override() {
         "Alias query should show project config provenance, got:\n{stdout}"
     );
 }
+
+/// rumdl config lint reports errors and deprecations and fails only on errors
+#[test]
+fn test_config_lint_reports_errors_and_deprecations() {
+    let temp_dir = tempdir().unwrap();
+    let lint = |config: &str| {
+        fs::write(temp_dir.path().join(".rumdl.toml"), config).unwrap();
+        let output = Command::new(rumdl_bin())
+            .current_dir(temp_dir.path())
+            .args(["config", "lint", "--color", "never"])
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stdout).into_owned(),
+        )
+    };
+
+    let (code, stdout) = lint("[MD013]\nline_lenght = 100\nenable_reflow = true\n\n[MD0133]\nfoo = 1\n");
+    assert_eq!(code, Some(1), "errors should fail the lint, got:\n{stdout}");
+    assert!(
+        stdout.contains("error: Unknown option for rule MD013: line-lenght (did you mean: line-length?)"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("error: Unknown rule in .rumdl.toml: MD0133"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("warning: MD013.enable-reflow in .rumdl.toml is deprecated; use reflow instead"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Checked 1 config file: 2 errors, 1 warning"),
+        "{stdout}"
+    );
+
+    let (code, stdout) = lint("[MD013]\nstrict-sentences = true\n");
+    assert_eq!(code, Some(0), "deprecations alone should not fail, got:\n{stdout}");
+    assert!(stdout.contains("use require-sentence-capital instead"), "{stdout}");
+
+    let (code, stdout) = lint("[MD013]\nline-length = \"long\"\n");
+    assert_eq!(code, Some(1));
+    assert!(
+        stdout.contains("error: Type mismatch for MD013.line-length: expected integer, got string"),
+        "{stdout}"
+    );
+
+    let (code, _) = lint("[MD013\n");
+    assert_eq!(code, Some(1), "a config that fails to parse should fail the lint");
+}
@@ -0,0 +1,125 @@
+//! `rumdl daemon` serves lint requests from a warm process and is managed
+//! through `start`, `status` and `stop`.
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Run `rumdl daemon ACTION` in `dir`, returning the exit code and output.
+/// The socket lives in the test's own temp directory.
+fn daemon(dir: &Path, args: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .env("XDG_RUNTIME_DIR", dir)
+        .arg("daemon")
+        .args(args)
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    (output.status.code().unwrap_or(-1), combined)
+}
+
+/// Stops the daemon even when an assertion fails
+struct StopOnDrop<'a>(&'a Path);
+
+impl Drop for StopOnDrop<'_> {
+    fn drop(&mut self) {
+        daemon(self.0, &["stop"]);
+    }
+}
+
+#[test]
+fn daemon_check_status_and_stop() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::create_dir(dir.join(".git")).unwrap();
+    fs::create_dir(dir.join("docs")).unwrap();
+    fs::write(
+        dir.join("README.md"),
+        "# Title\n\nSee [other](docs/other.md#missing).\n",
+    )
+    .unwrap();
+    fs::write(dir.join("docs/other.md"), "# Other\n\nText.\n").unwrap();
+
+    let (code, output) = daemon(dir, &["status"]);
+    assert_eq!(code, 1, "{output}");
+    assert!(output.contains("No rumdl daemon is running"), "{output}");
+
+    let _guard = StopOnDrop(dir);
+    let (code, output) = daemon(dir, &["check", "README.md"]);
+    assert_eq!(code, 1, "{output}");
+    assert!(
+        output.contains("README.md:3:5: [MD051] Link fragment 'missing' not found"),
+        "{output}"
+    );
+
+    fs::write(dir.join("docs/other.md"), "# Other\n\n## Missing\n").unwrap();
+    let (code, output) = daemon(dir, &["check"]);
+    assert_eq!(code, 0, "cross-file checks see the edited file: {output}");
+
+    let (code, output) = daemon(dir, &["status"]);
+    assert_eq!(code, 0, "{output}");
+    assert!(output.contains("rumdl daemon is running"), "{output}");
+    assert!(output.contains("requests served: 3"), "{output}");
+    assert!(output.contains("indexed files:   2"), "{output}");
+
+    let (code, output) = daemon(dir, &["stop"]);
+    assert_eq!(code, 0, "{output}");
+    let (code, _) = daemon(dir, &["status"]);
+    assert_eq!(code, 1);
+}
+
+/// The daemon's socket in `dir`
+fn socket(dir: &Path) -> PathBuf {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "sock"))
+        .expect("daemon socket")
+}
+
+#[test]
+fn daemon_is_not_blocked_by_a_silent_client() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::create_dir(dir.join(".git")).unwrap();
+    fs::write(dir.join("README.md"), "# Title\n").unwrap();
+
+    let _guard = StopOnDrop(dir);
+    let (code, output) = daemon(dir, &["start"]);
+    assert_eq!(code, 0, "{output}");
+
+    // A client that connects and never sends a request is dropped after
+    // the daemon's request timeout
+    let _silent = UnixStream::connect(socket(dir)).unwrap();
+    let (code, output) = daemon(dir, &["check"]);
+    assert_eq!(code, 0, "{output}");
+}
+
+#[test]
+fn socket_falls_back_to_private_temp_directory() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    fs::create_dir(dir.join(".git")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .env_remove("XDG_RUNTIME_DIR")
+        .env("TMPDIR", dir)
+        .args(["daemon", "status"])
+        .output()
+        .expect("failed to execute rumdl");
+    assert_eq!(output.status.code(), Some(1));
+
+    let private = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.file_name().unwrap().to_string_lossy().starts_with("rumdl-"))
+        .expect("private socket directory");
+    let mode = fs::metadata(&private).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o700);
+}
@@ -0,0 +1,107 @@
+//! `--diff-base REF` reports only warnings on lines changed since `REF`, while
+//! links on changed lines are still checked against unchanged files.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=rumdl", "-c", "user.email=rumdl@example.com"])
+        .args(args)
+        .status()
+        .expect("failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Run `rumdl check` in `dir` with `extra` arguments, returning the exit code and output
+fn check(dir: &Path, extra: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    (output.status.code().unwrap_or(-1), combined)
+}
+
+#[test]
+fn diff_base_reports_only_changed_lines() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    git(dir, &["init", "-q", "-b", "main"]);
+    fs::write(dir.join("a.md"), "# A\n\nold   \n\nSee [b](b.md#missing).\n").unwrap();
+    fs::write(dir.join("b.md"), "# B\n\n## Section\n\nUnchanged   \n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-q", "-m", "init"]);
+    git(dir, &["checkout", "-q", "-b", "feature"]);
+
+    // One changed line with trailing spaces, a new link into an unchanged
+    // file, and an untracked file
+    fs::write(
+        dir.join("a.md"),
+        "# A\n\nold   \nnew   \n\nSee [b](b.md#missing).\n\n[ok](b.md#section) and [bad](b.md#gone)\n",
+    )
+    .unwrap();
+    fs::write(dir.join("c.md"), "# C\n\n* one\n+ two\n").unwrap();
+
+    let (code, output) = check(dir, &["--diff-base", "main"]);
+    assert_eq!(code, 1, "got:\n{output}");
+    let diagnostics: Vec<&str> = output.lines().filter(|line| line.contains(": [")).collect();
+    assert_eq!(diagnostics.len(), 3, "got:\n{output}");
+    assert!(output.contains("a.md:4:4: [MD009]"), "got:\n{output}");
+    assert!(
+        output.contains("a.md:8:") && output.contains("'gone'"),
+        "got:\n{output}"
+    );
+    assert!(output.contains("c.md:4:1: [MD004]"), "got:\n{output}");
+
+    // Everything is fine once the changed lines are
+    fs::write(dir.join("a.md"), "# A\n\nold   \nnew\n\nSee [b](b.md#missing).\n").unwrap();
+    fs::remove_file(dir.join("c.md")).unwrap();
+    let (code, output) = check(dir, &["--diff-base", "main"]);
+    assert_eq!(code, 0, "got:\n{output}");
+}
+
+#[test]
+fn diff_base_rejects_unknown_revision() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    git(dir, &["init", "-q", "-b", "main"]);
+    fs::write(dir.join("a.md"), "# A\n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-q", "-m", "init"]);
+
+    let (code, output) = check(dir, &["--diff-base", "no-such-branch"]);
+    assert_ne!(code, 0);
+    assert!(output.contains("--diff-base"), "got:\n{output}");
+}
+
+#[test]
+fn fail_on_new_reports_everything_but_fails_only_on_changed_lines() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    git(dir, &["init", "-q", "-b", "main"]);
+    fs::write(dir.join(".rumdl.toml"), "[ci]\nfail-on-new = true\n").unwrap();
+    fs::write(dir.join("a.md"), "# A\n\nold   \n").unwrap();
+    fs::write(dir.join("b.md"), "# B\n\nUnchanged   \n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-q", "-m", "init"]);
+    git(dir, &["checkout", "-q", "-b", "feature"]);
+
+    // Existing warnings in both files are reported, but do not fail the run
+    fs::write(dir.join("a.md"), "# A\n\nold   \nnew\n").unwrap();
+    let (code, output) = check(dir, &["--diff-base", "main"]);
+    assert_eq!(code, 0, "got:\n{output}");
+    assert!(output.contains("a.md:3:4: [MD009]"), "got:\n{output}");
+    assert!(output.contains("b.md:3:10: [MD009]"), "got:\n{output}");
+
+    // A warning on a changed line does
+    fs::write(dir.join("a.md"), "# A\n\nold   \nnew   \n").unwrap();
+    let (code, output) = check(dir, &["--diff-base", "main"]);
+    assert_eq!(code, 1, "got:\n{output}");
+    assert!(output.contains("a.md:4:4: [MD009]"), "got:\n{output}");
+}
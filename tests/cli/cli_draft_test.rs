@@ -0,0 +1,90 @@
+//! Files whose front matter marks them as drafts skip the `draft-disable`
+//! rules, and `--explain-skip` lists what each file skips and why.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check .` in `dir` with `extra` arguments, returning stdout and stderr
+fn check(dir: &Path, extra: &[&str]) -> (String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    )
+}
+
+const LONG_LINE: &str = "This sentence is long enough to exceed the default limit of eighty characters.";
+
+fn write_posts(dir: &Path) {
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[global]\ndraft-disable = [\"MD013\", \"first-line-heading\"]\n\n[overrides.\"posts/**\"]\ndisable = [\"MD047\"]\n\n[per-file-ignores]\n\"README.md\" = [\"MD033\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("posts")).unwrap();
+    fs::write(
+        dir.join("posts/wip.md"),
+        format!("---\ndraft: true\n---\n\n{LONG_LINE} More words.\n"),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("posts/done.md"),
+        format!("---\ndraft: false\n---\n\n{LONG_LINE} More words.\n"),
+    )
+    .unwrap();
+    fs::write(dir.join("README.md"), "# Readme\n\n<b>bold</b>\n").unwrap();
+}
+
+#[test]
+fn drafts_skip_the_draft_disable_rules() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    write_posts(dir);
+
+    let (stdout, _) = check(dir, &[]);
+    assert!(stdout.contains("posts/done.md:5:1: [MD041]"), "got:\n{stdout}");
+    assert!(stdout.contains("posts/done.md:5:81: [MD013]"), "got:\n{stdout}");
+    assert!(!stdout.contains("posts/wip.md"), "got:\n{stdout}");
+}
+
+#[test]
+fn explain_skip_lists_skipped_rules_with_reasons() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    write_posts(dir);
+
+    let (stdout, stderr) = check(dir, &["--explain-skip", "--output-format", "json"]);
+    assert!(stderr.contains("Skipped rules:"), "got:\n{stderr}");
+    assert!(
+        stderr.contains("  README.md: MD033 (per-file-ignores)"),
+        "got:\n{stderr}"
+    );
+    assert!(
+        stderr.contains("  posts/done.md: MD047 ([overrides] \"posts/**\")"),
+        "got:\n{stderr}"
+    );
+    assert!(
+        stderr.contains("  posts/wip.md: MD013 (draft), MD041 (draft), MD047 ([overrides] \"posts/**\")"),
+        "got:\n{stderr}"
+    );
+    // The explanation stays out of machine-readable output
+    assert!(
+        serde_json::from_str::<serde_json::Value>(&stdout).is_ok(),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn explain_skip_without_skips() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "# Title\n").unwrap();
+
+    let (_, stderr) = check(temp.path(), &["--explain-skip"]);
+    assert!(stderr.contains("No rules skipped"), "got:\n{stderr}");
+}
@@ -112,3 +112,72 @@ fn test_explain_command_different_rules() {
         cmd.assert().success().stdout(predicate::str::contains(rule));
     }
 }
+
+#[test]
+fn test_explain_location_shows_rules_fixes_and_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(&file, "# Title\n\nTrailing   \n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(dir.path())
+        .arg("explain")
+        .arg("doc.md:3")
+        .arg("--config")
+        .arg("MD009.br-spaces = 4");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Inline config at this line: no rules disabled",
+        ))
+        .stdout(predicate::str::contains("MD009 - Trailing spaces should be removed"))
+        .stdout(predicate::str::contains("3:9 [Warning] 3 trailing spaces found"))
+        .stdout(predicate::str::contains(r#"Fix: replace "   " with """#))
+        .stdout(predicate::str::contains("br-spaces = 4\n"))
+        .stdout(predicate::str::contains("strict = false (default)"));
+}
+
+#[test]
+fn test_explain_location_reports_inline_suppression() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("doc.md");
+    std::fs::write(
+        &file,
+        "# Title\n\n<!-- rumdl-disable-next-line MD009 -->\nTrailing   \n\nClean line\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(dir.path())
+        .arg("explain")
+        .arg("doc.md:4:1")
+        .arg("--no-config");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Inline config at this line: disabled MD009"))
+        .stdout(predicate::str::contains(
+            "Suppressed: MD009 is disabled by an inline comment at line 4",
+        ));
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(dir.path())
+        .arg("explain")
+        .arg("doc.md:6")
+        .arg("--no-config");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No enabled rule reports line 6."));
+}
+
+#[test]
+fn test_explain_location_past_end_of_file() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(dir.path()).arg("explain").arg("doc.md:5");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("doc.md has 1 lines; line 5 does not exist."));
+}
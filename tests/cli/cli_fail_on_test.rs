@@ -680,3 +680,38 @@ fn test_stdin_applies_config_severity_overrides() {
         String::from_utf8_lossy(&output.stderr)
     );
 }
+
+#[test]
+fn test_fail_on_warning_ignores_rule_downgraded_to_info() {
+    // MD042 is Error by default; an `info` severity (set here through the
+    // rule's alias) takes it below the --fail-on warning threshold.
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join(".rumdl.toml"),
+        "[global]\nenable = [\"MD042\"]\n\n[no-empty-links]\nseverity = \"info\"\n",
+    )
+    .unwrap();
+    let file = create_error_file(temp_dir.path());
+
+    let output = Command::new(rumdl_bin())
+        .args(["check", file.to_str().unwrap(), "--fail-on", "warning", "--no-cache"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute command");
+    assert!(
+        output.status.success(),
+        "info-level MD042 should not fail with --fail-on warning\nstdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("MD042"));
+
+    let output = Command::new(rumdl_bin())
+        .args(["check", file.to_str().unwrap(), "--fail-on", "any", "--no-cache"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute command");
+    assert!(
+        !output.status.success(),
+        "info-level findings still fail with --fail-on any"
+    );
+}
@@ -0,0 +1,74 @@
+//! `--fix --dry-run --output-format json` reports the planned edits per file
+//! without writing anything.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn run(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache"])
+        .args(args)
+        .output()
+        .expect("failed to execute rumdl")
+}
+
+fn apply_edits(original: &str, edits: &[serde_json::Value]) -> String {
+    let mut result = original.to_string();
+    for edit in edits.iter().rev() {
+        let start = edit["range"]["start"].as_u64().unwrap() as usize;
+        let end = edit["range"]["end"].as_u64().unwrap() as usize;
+        result.replace_range(start..end, edit["replacement"].as_str().unwrap());
+    }
+    result
+}
+
+#[test]
+fn planned_edits_match_fix_and_leave_files_untouched() {
+    let temp = tempfile::tempdir().unwrap();
+    let original = "# Title\r\n\r\nSome text   \r\n* item\r\n";
+    fs::write(temp.path().join("a.md"), original).unwrap();
+    fs::write(temp.path().join("clean.md"), "# Clean\n\nNothing to do.\n").unwrap();
+    fs::write(temp.path().join("broken.md"), "# Broken\n\nSee [docs](missing.md).\n").unwrap();
+
+    let output = run(temp.path(), &["--fix", "--dry-run", "--output-format", "json", "."]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let plans: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_else(|e| panic!("{e}: {stdout}"));
+    let plans = plans.as_array().unwrap();
+
+    // Clean files are not listed
+    let files: Vec<&str> = plans.iter().map(|p| p["file"].as_str().unwrap()).collect();
+    assert_eq!(files, ["a.md", "broken.md"]);
+
+    // Nothing was written
+    assert_eq!(fs::read_to_string(temp.path().join("a.md")).unwrap(), original);
+
+    let plan = &plans[0];
+    assert_eq!(plan["fixed"], 2);
+    assert!(plan["remaining"].as_array().unwrap().is_empty());
+    assert_eq!(
+        plan["original_hash"],
+        blake3::hash(original.as_bytes()).to_hex().as_str()
+    );
+    let planned = apply_edits(original, plan["edits"].as_array().unwrap());
+    assert_eq!(plan["fixed_hash"], blake3::hash(planned.as_bytes()).to_hex().as_str());
+
+    // Applying the plan gives exactly what `--fix` writes
+    run(temp.path(), &["--fix", "a.md"]);
+    assert_eq!(fs::read_to_string(temp.path().join("a.md")).unwrap(), planned);
+
+    let broken = &plans[1];
+    assert!(broken["edits"].as_array().unwrap().is_empty());
+    assert_eq!(broken["fixed_hash"], broken["original_hash"]);
+    assert_eq!(broken["remaining"][0]["rule"], "MD057");
+}
+
+#[test]
+fn dry_run_fix_requires_json_output() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "# Title\n").unwrap();
+    let output = run(temp.path(), &["--fix", "--dry-run", "a.md"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--diff and --fix cannot be used together"));
+}
@@ -574,6 +574,7 @@ fn test_per_file_flavor_fix_coordination() {
 [global]
 enable = ["MD013"]
 line-length = 80
+unsafe-fixes = true
 
 [per-file-flavor]
 "docs/**/*.md" = "mkdocs"
@@ -0,0 +1,58 @@
+//! `--group-by` arranges text output by rule or severity across all files.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check .` in `dir` with `extra` arguments, returning stdout
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+/// Trailing spaces (MD009) in two files, and a second `#` heading missing its
+/// space (MD018, and MD025 at error severity) in one
+fn write_project(dir: &Path) {
+    fs::write(dir.join("a.md"), "# A\n\nOne   \nTwo \n\n#Bad\n").unwrap();
+    fs::write(dir.join("b.md"), "# B\n\nThree \n").unwrap();
+}
+
+#[test]
+fn group_by_rule_lists_positions_per_file() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path());
+
+    let stdout = check(temp.path(), &["--group-by", "rule"]);
+    assert!(
+        stdout.contains("MD009: 3 issues in 2 files (3 fixable)\n  a.md: 3:4, 4:4\n  b.md: 3:6\n"),
+        "got:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("MD018: 1 issue in 1 file (1 fixable)\n  a.md: 6:2\n"),
+        "got:\n{stdout}"
+    );
+    assert!(stdout.find("MD009").unwrap() < stdout.find("MD018").unwrap());
+    assert!(stdout.contains("Found 5 issues in 2 files"), "got:\n{stdout}");
+}
+
+#[test]
+fn group_by_severity_keeps_warning_lines() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path());
+
+    let stdout = check(temp.path(), &["--group-by", "severity"]);
+    assert!(
+        stdout.starts_with("error: 1 issue in 1 file (1 fixable)\n  a.md:6:2: [MD025]"),
+        "got:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("\n\nwarning: 4 issues in 2 files (4 fixable)\n"),
+        "got:\n{stdout}"
+    );
+    assert!(stdout.contains("\n  b.md:3:6: [MD009]"), "got:\n{stdout}");
+}
@@ -850,6 +850,35 @@ fn test_markdown_extension_handling() -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+#[test]
+fn test_configured_extensions_and_include() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempdir()?;
+    let dir_path = temp_dir.path();
+
+    fs::create_dir(dir_path.join("docs"))?;
+    fs::write(dir_path.join("top.md"), "# Top\n")?;
+    fs::write(dir_path.join("docs/guide.md"), "# Guide\n")?;
+    fs::write(dir_path.join("docs/notes.txt"), "# Notes\n")?;
+    fs::write(dir_path.join("docs/old.markdown"), "# Old\n")?;
+    create_config(
+        dir_path,
+        "[global]\nextensions = [\"md\", \"txt\"]\ninclude = [\"docs/**\"]\n",
+    );
+
+    // Only files with a configured extension under docs/ are discovered
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.args(["check", ".", "--verbose", "--no-cache"])
+        .current_dir(dir_path);
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Processing file: docs/guide.md"))
+        .stdout(predicates::str::contains("Processing file: docs/notes.txt"))
+        .stdout(predicates::str::contains("old.markdown").not())
+        .stdout(predicates::str::contains("Processing file: top.md").not());
+
+    Ok(())
+}
+
 #[test]
 fn test_type_filter_precedence() -> Result<(), Box<dyn std::error::Error>> {
     let temp_dir = tempdir()?;
@@ -994,6 +1023,38 @@ fn test_rule_command_shows_specific_rule() {
     );
 }
 
+#[test]
+fn test_rule_command_search_ranks_matches() {
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    let output = Command::new(rumdl_exe)
+        .args(["rule", "--search", "fenced code language"])
+        .output()
+        .expect("Failed to execute 'rumdl rule --search'");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    assert!(output.status.success(), "got:\n{stdout}");
+    assert!(
+        stdout.starts_with("Rules matching \"fenced code language\":"),
+        "got:\n{stdout}"
+    );
+    let first = stdout.lines().nth(1).unwrap_or_default();
+    assert!(first.contains("MD040"), "got:\n{stdout}");
+
+    let output = Command::new(rumdl_exe)
+        .args(["rule", "--search", "tabs", "--output-format", "json"])
+        .output()
+        .expect("Failed to execute 'rumdl rule --search'");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let matches = json.as_array().unwrap();
+    assert_eq!(matches[0]["code"], "MD010");
+    assert!(matches[0]["score"].as_u64().unwrap() >= matches[1]["score"].as_u64().unwrap());
+
+    let output = Command::new(rumdl_exe)
+        .args(["rule", "--search", "xyzzyplugh"])
+        .output()
+        .expect("Failed to execute 'rumdl rule --search'");
+    assert_eq!(output.status.code(), Some(2));
+}
+
 #[test]
 fn test_rule_command_accepts_rule_alias() {
     let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
@@ -1252,10 +1313,10 @@ fn test_rule_command_json_output_single_rule() {
     assert!(rule.is_object(), "Expected JSON object for single rule");
 
     assert_eq!(rule.get("code").and_then(|c| c.as_str()), Some("MD041"));
-    assert_eq!(rule.get("name").and_then(|n| n.as_str()), Some("first-line-h1"));
-    // MD041 has "first-line-heading" as an alias
+    assert_eq!(rule.get("name").and_then(|n| n.as_str()), Some("first-line-heading"));
+    // MD041 has "first-line-h1" as a secondary alias
     let aliases = rule.get("aliases").and_then(|a| a.as_array()).unwrap();
-    assert!(aliases.iter().any(|a| a.as_str() == Some("first-line-heading")));
+    assert!(aliases.iter().any(|a| a.as_str() == Some("first-line-h1")));
     assert_eq!(
         rule.get("url").and_then(|u| u.as_str()),
         Some("https://rumdl.dev/md041/")
@@ -1444,6 +1505,43 @@ fn test_rule_command_explain_flag() {
     );
 }
 
+#[test]
+fn test_rule_command_markdown_format() {
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    let output = Command::new(rumdl_exe)
+        .args(["rule", "MD009", "--output-format", "markdown"])
+        .output()
+        .expect("Failed to execute 'rumdl rule MD009 --output-format markdown'");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(stdout.starts_with("# MD009 - "), "got:\n{stdout}");
+    assert!(stdout.contains("Aliases: `no-trailing-spaces`"));
+    assert!(stdout.contains("- Enabled by default: yes"));
+    // Config options and their defaults come from the rule itself
+    assert!(stdout.contains("| `br-spaces` | integer | `2` |"));
+    assert!(stdout.contains("[MD009]\nbr-spaces = 2\n"));
+    // The incorrect example is checked and fixed by the rule
+    assert!(stdout.contains("### Incorrect"));
+    assert!(stdout.contains("3:27: 3 trailing spaces found"));
+    assert!(stdout.contains("### Fixed"));
+    assert!(stdout.contains("## Fix behavior"));
+}
+
+#[test]
+fn test_rule_command_markdown_format_opt_in_rule() {
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    let output = Command::new(rumdl_exe)
+        .args(["rule", "MD091", "--output-format", "md"])
+        .output()
+        .expect("Failed to execute 'rumdl rule MD091 --output-format md'");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(stdout.contains("- Enabled by default: no (enable with `extend-enable = [\"MD091\"]`)"));
+    assert!(stdout.contains("Violations of this rule must be fixed manually."));
+    assert!(!stdout.contains("### Fixed"));
+}
+
 #[test]
 fn test_rule_command_text_output_with_filters() {
     let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
@@ -2186,6 +2284,59 @@ fn test_stdin_filename_in_fmt_mode() {
     assert!(output.status.success());
 }
 
+#[test]
+fn test_stdin_filename_resolves_config_as_on_disk() {
+    let temp_dir = tempdir().unwrap();
+    let base = temp_dir.path();
+    fs::create_dir_all(base.join(".git")).unwrap();
+    fs::create_dir_all(base.join("sub")).unwrap();
+    fs::create_dir_all(base.join("notes")).unwrap();
+    create_config(
+        base,
+        "[global]\nline-length = 20\n\n[per-file-ignores]\n\"notes/*.md\" = [\"MD013\"]\n",
+    );
+    create_config(&base.join("sub"), "[global]\ndisable = [\"MD013\"]\n");
+    fs::write(base.join("sub/target.md"), "# Target\n\n## Real\n").unwrap();
+
+    let run = |filename: &str, input: &str| {
+        let mut cmd = Command::new(env!("CARGO_BIN_EXE_rumdl"));
+        cmd.current_dir(base)
+            .args(["check", "--stdin", "--stdin-filename", filename])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+        let mut child = cmd.spawn().expect("Failed to spawn command");
+        use std::io::Write;
+        child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+        let output = child.wait_with_output().expect("Failed to wait for command");
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+
+    let long_line = "# Title\n\nThis line is longer than twenty characters.\n";
+    assert!(run("doc.md", long_line).contains("MD013"), "root config applies");
+    // The nearest config is discovered from the stdin filename's directory
+    assert!(
+        !run("sub/doc.md", long_line).contains("MD013"),
+        "sub/.rumdl.toml applies"
+    );
+    // per-file-ignores match the stdin filename
+    assert!(
+        !run("notes/todo.md", long_line).contains("MD013"),
+        "per-file-ignores apply"
+    );
+
+    // Cross-file fragments resolve against the linked file on disk
+    let stderr = run(
+        "sub/doc.md",
+        "# Title\n\n[ok](target.md#real) [bad](target.md#missing)\n",
+    );
+    assert!(
+        stderr.contains("Link fragment 'missing' not found in 'target.md'"),
+        "stderr: {stderr}"
+    );
+    assert!(!stderr.contains("'real'"), "stderr: {stderr}");
+}
+
 #[test]
 fn test_fmt_dash_syntax() {
     let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
@@ -2246,6 +2397,39 @@ fn test_fmt_command() {
     assert!(output.status.success());
 }
 
+#[test]
+fn test_fmt_stdin_range() {
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    // Only lines 3-4 are formatted; the trailing spaces and blank lines
+    // outside the range are passed through as they are
+    let input = "# Test   \n\n*  a   \n*  b   \n\n\n\nTail   \n";
+    let mut cmd = Command::new(rumdl_exe);
+    cmd.args(["fmt", "--stdin", "--quiet", "--range", "3:4"]);
+    cmd.stdin(std::process::Stdio::piped());
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn().expect("Failed to spawn command");
+    use std::io::Write;
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    stdin.write_all(input.as_bytes()).expect("Failed to write to stdin");
+    drop(stdin);
+
+    let output = child.wait_with_output().expect("Failed to wait for command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, "# Test   \n\n* a\n* b\n\n\n\nTail   \n");
+    assert!(output.status.success());
+
+    // A range is only meaningful for stdin
+    let output = Command::new(rumdl_exe)
+        .args(["fmt", "--range", "3:4", "README.md"])
+        .output()
+        .expect("Failed to run command");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("requires '--stdin'"));
+}
+
 #[test]
 fn test_fmt_vs_check_fix_exit_codes() {
     let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
@@ -3560,6 +3744,122 @@ fn test_config_include_with_rs_and_directory_pattern() {
     );
 }
 
+#[test]
+fn test_config_extract_from_discovers_and_lints_python_docstrings() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    fs::create_dir_all(base_path.join("pkg")).unwrap();
+    fs::write(
+        base_path.join("pkg/mod.py"),
+        "def f():\n    \"\"\"Summary.\n\n    #Heading\n    \"\"\"\n    return 1\n",
+    )
+    .unwrap();
+    fs::write(base_path.join("pkg/other.js"), "/** #Heading */\n").unwrap();
+    fs::write(base_path.join("test.md"), "# Test\n\nSome text.\n").unwrap();
+    fs::write(
+        base_path.join(".rumdl.toml"),
+        "[global]\nextract-from = [\"**/*.py\"]\n",
+    )
+    .unwrap();
+
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "."])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(
+        stdout.contains("pkg/mod.py:4:6: [MD018]"),
+        "Docstring warning should map to the source line, stdout: {stdout}"
+    );
+    assert!(
+        !stdout.contains("other.js"),
+        "Files not matching extract-from should not be discovered, stdout: {stdout}"
+    );
+    assert!(stdout.contains("2 file"), "Should process 2 files, stdout: {stdout}");
+}
+
+#[test]
+fn test_config_extract_from_fix_leaves_python_source_untouched() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    let source = "x = 1   \n\n\n\ndef f():\n    \"\"\"#Heading\"\"\"\n";
+    fs::write(base_path.join("mod.py"), source).unwrap();
+    fs::write(base_path.join(".rumdl.toml"), "[global]\nextract-from = [\"*.py\"]\n").unwrap();
+
+    Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--fix", "."])
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(fs::read_to_string(base_path.join("mod.py")).unwrap(), source);
+}
+
+#[test]
+fn test_config_extract_from_lints_markdown_regions_in_html() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    let html = "<html>\n<body>\n<zero-md>\n  <script type=\"text/markdown\">\n    #Title\n  </script>\n</zero-md>\n<div class=\"md\">\n##Notes\n</div>\n</body>\n</html>\n";
+    fs::write(base_path.join("index.html"), html).unwrap();
+    fs::write(
+        base_path.join(".rumdl.toml"),
+        "[global]\nextract-from = [\"**/*.html\"]\n",
+    )
+    .unwrap();
+
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--fix", "."])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(
+        stdout.contains("index.html:5:6: [MD018]"),
+        "Warning should map to the HTML line and column, stdout: {stdout}"
+    );
+    assert!(
+        !stdout.contains("index.html:9"),
+        "Content outside extraction patterns should not be linted, stdout: {stdout}"
+    );
+    assert_eq!(
+        fs::read_to_string(base_path.join("index.html")).unwrap(),
+        html,
+        "HTML files are check-only"
+    );
+
+    // Custom patterns replace the built-in ones
+    fs::write(
+        base_path.join(".rumdl.toml"),
+        "[global]\nextract-from = [\"**/*.html\"]\nhtml-markdown-patterns = ['(?s)<div class=\"md\">(?P<markdown>.*?)</div>']\n",
+    )
+    .unwrap();
+
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "."])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(
+        stdout.contains("index.html:9:3: [MD018]"),
+        "Custom pattern should extract the div content, stdout: {stdout}"
+    );
+    assert!(
+        !stdout.contains("index.html:5:"),
+        "Built-in patterns should not apply when custom ones are set, stdout: {stdout}"
+    );
+}
+
 #[test]
 fn test_default_discovery_does_not_include_rs_files() {
     let temp_dir = tempdir().unwrap();
@@ -3593,3 +3893,62 @@ fn test_default_discovery_does_not_include_rs_files() {
         "Should process only 1 file, stdout: {stdout}"
     );
 }
+
+#[test]
+fn test_rule_names_alias_in_diagnostics() {
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("test.md");
+    fs::write(&test_file, "# Title\n\nText\twith tab\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .args([
+            "check",
+            test_file.to_str().unwrap(),
+            "--no-cache",
+            "--rule-names",
+            "alias",
+            "--output-format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute rumdl");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let warnings: serde_json::Value = serde_json::from_str(&stdout).expect("Failed to parse JSON output");
+    let rules: Vec<&str> = warnings
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|w| w.get("rule").and_then(|r| r.as_str()))
+        .collect();
+    assert_eq!(rules, vec!["no-hard-tabs"], "Expected alias in output. Got:\n{stdout}");
+}
+
+#[test]
+fn test_profile_json_output_is_a_json_document() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("test.md"), "# Heading\n\nText\n").unwrap();
+
+    let output = cargo_bin_cmd!("rumdl")
+        .args(["check", "--no-config", "--no-cache", "--silent", "--profile=json"])
+        .arg(temp_dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Without the `profiling` feature the document reports it is disabled
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("--profile=json should print only JSON with --silent");
+    assert!(report["enabled"].is_boolean());
+    assert!(report["rules"].is_array());
+    assert!(report["files"].is_array());
+}
+
+#[test]
+fn test_profile_rejects_unknown_format() {
+    cargo_bin_cmd!("rumdl")
+        .args(["check", "--profile=xml", "README.md"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("folded"));
+}
@@ -0,0 +1,73 @@
+//! `rumdl check --fix --interactive` applies only the fixes accepted at its
+//! prompts.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+/// Run `rumdl check --fix --interactive --no-cache` in `dir` on `files`,
+/// answering the prompts with `answers`
+fn fix_interactively(dir: &Path, files: &[&str], answers: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--fix", "--interactive", "--no-cache"])
+        .args(files)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to execute rumdl");
+    child.stdin.take().unwrap().write_all(answers.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn only_accepted_fixes_are_written() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "# Title\n\none \n\ntwo \n").unwrap();
+
+    let output = fix_interactively(temp.path(), &["a.md"], "y\nn\n");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("a.md:3 [MD009]\n-one \n+one\n"), "got:\n{stdout}");
+    assert!(stdout.contains("a.md:5 [MD009]\n-two \n+two\n"), "got:\n{stdout}");
+    assert!(stdout.contains("Fixed 1/2 issues"), "got:\n{stdout}");
+    assert_eq!(
+        fs::read_to_string(temp.path().join("a.md")).unwrap(),
+        "# Title\n\none\n\ntwo \n"
+    );
+}
+
+#[test]
+fn accepting_a_rule_applies_its_fixes_in_later_files() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "# A\n\none \n").unwrap();
+    fs::write(temp.path().join("b.md"), "# B\n\ntwo \n").unwrap();
+
+    let output = fix_interactively(temp.path(), &["a.md", "b.md"], "a\n");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("Apply this fix").count(), 1, "got:\n{stdout}");
+    assert_eq!(fs::read_to_string(temp.path().join("a.md")).unwrap(), "# A\n\none\n");
+    assert_eq!(fs::read_to_string(temp.path().join("b.md")).unwrap(), "# B\n\ntwo\n");
+}
+
+#[test]
+fn interactive_needs_fix_and_file_input() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "# A\n\none \n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(temp.path())
+        .args(["check", "--interactive", "a.md"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = fix_interactively(temp.path(), &["-"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("--interactive cannot be used with stdin input"),
+        "got:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,58 @@
+//! Files over `max-file-size` are checked in chunks or skipped.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(args)
+        .args(["--no-cache", "big.md"])
+        .output()
+        .expect("failed to execute rumdl")
+}
+
+/// A file over the limit with trailing spaces on line 3, a fenced block with
+/// no language, a second top-level heading (MD025) and no final newline (MD047)
+fn write_project(dir: &Path, mode: &str) {
+    fs::write(
+        dir.join(".rumdl.toml"),
+        format!("[global]\nmax-file-size = 10\nlarge-file-mode = \"{mode}\"\n"),
+    )
+    .unwrap();
+    fs::write(dir.join("big.md"), "# Big\n\nTrailing   \n\n```\ncode\n```\n\n# Again").unwrap();
+}
+
+#[test]
+fn stream_mode_runs_block_local_rules_without_fixing() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path(), "stream");
+
+    let output = run(temp.path(), &["check"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("big.md:3:9: [MD009]"), "got:\n{stdout}");
+    assert!(stdout.contains("big.md:5:1: [MD040]"), "got:\n{stdout}");
+    assert!(!stdout.contains("MD025") && !stdout.contains("MD047"), "got:\n{stdout}");
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = run(temp.path(), &["check", "--fix"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Not fixing big.md"), "got:\n{stderr}");
+    assert_eq!(
+        fs::read_to_string(temp.path().join("big.md")).unwrap(),
+        "# Big\n\nTrailing   \n\n```\ncode\n```\n\n# Again"
+    );
+}
+
+#[test]
+fn skip_mode_leaves_file_unchecked() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path(), "skip");
+
+    let output = run(temp.path(), &["check"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Skipping big.md"), "got:\n{stderr}");
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("[MD"));
+    assert_eq!(output.status.code(), Some(0));
+}
@@ -0,0 +1,78 @@
+//! `[code-linters]` pipes fenced code blocks to external linters, which run
+//! when `--lint-code-blocks` is passed or `[code-block-tools]` is enabled.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check doc.md` in `dir` with `extra` arguments, returning stdout
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "doc.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+/// A document with a `sh` block whose second line is flagged by an inline
+/// `awk` linter that reports TODO lines in `-:line:col: message` form
+fn write_project(dir: &Path) {
+    fs::write(
+        dir.join(".rumdl.toml"),
+        "[code-linters]\nshell = [[\"awk\", \"/TODO/ { print \\\"-:\\\" NR \\\":3: found TODO\\\" }\"]]\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("doc.md"),
+        "# Doc\n\nIntro.\n\n```sh\necho hi\n# TODO: more\n```\n",
+    )
+    .unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn code_linters_run_with_lint_code_blocks() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path());
+
+    let stdout = check(temp.path(), &["--lint-code-blocks"]);
+    assert!(stdout.contains("doc.md:7:3: [shell:awk] found TODO"), "got:\n{stdout}");
+}
+
+#[cfg(unix)]
+#[test]
+fn code_linters_need_the_flag_or_enabled() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path());
+
+    let stdout = check(temp.path(), &[]);
+    assert!(!stdout.contains("found TODO"), "got:\n{stdout}");
+
+    let config = fs::read_to_string(temp.path().join(".rumdl.toml")).unwrap();
+    fs::write(
+        temp.path().join(".rumdl.toml"),
+        format!("[code-block-tools]\nenabled = true\n\n{config}"),
+    )
+    .unwrap();
+    let stdout = check(temp.path(), &[]);
+    assert!(stdout.contains("doc.md:7:3:"), "got:\n{stdout}");
+}
+
+#[cfg(unix)]
+#[test]
+fn code_linters_in_pyproject() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path());
+    let config = fs::read_to_string(temp.path().join(".rumdl.toml")).unwrap();
+    fs::remove_file(temp.path().join(".rumdl.toml")).unwrap();
+    fs::write(
+        temp.path().join("pyproject.toml"),
+        config.replace("[code-linters]", "[tool.rumdl.code-linters]"),
+    )
+    .unwrap();
+
+    let stdout = check(temp.path(), &["--lint-code-blocks"]);
+    assert!(stdout.contains("doc.md:7:3: [shell:awk] found TODO"), "got:\n{stdout}");
+}
@@ -296,6 +296,31 @@ fn get_test_content_for_rule(rule_name: &str) -> Option<&'static str> {
         "MD066" => Some("Text[^1]\n\n[^1]:"),
         "MD067" => Some("Text[^2][^1]\n\n[^1]: First\n[^2]: Second"),
         "MD068" => Some("[^1]:\n\n[^1]: Empty footnote"),
+        "MD083" => Some("<Alert type=\"info\">Missing import</Alert>"),
+        "MD084" => Some("```bash\nmake\n```\n\n```bash\n$ make install\n```"),
+        "MD085" => Some("> [!tip] read this first\n> Body text."),
+        "MD086" => Some("![Logo](images/logo.png)"),
+        "MD087" => Some("# 🚀 Launch"),
+        "MD088" => Some("## Contributors\n\n- Grace\n- Ada"),
+        "MD090" => Some("Run `rumdl check —fix` to apply fixes."),
+        "MD091" => Some("[a]: https://a.example\n[a]: https://b.example"),
+        "MD092" => Some("---\ntitle: Getting Started\n---\n\n# Setup"),
+        "MD093" => Some("![Screenshot](settings.png)"),
+        "MD094" => Some("!!! note\n    Remember to save."),
+        "MD095" => Some("See [b] and [a].\n\n[b]: https://b.example\n[a]: https://a.example"),
+        "MD096" => Some("# Installation"),
+        "MD097" => Some("```mermaid\ngrpah TD\n```\n"),
+        "MD098" => Some("She said \"wait...\" -- twice."),
+        "MD099" => Some("- Apples\n- Pears.\n- Plums"),
+        "MD100" => Some("- [X] Done\n- [x] Also done\n- []  Open"),
+        "MD101" => Some("## Getting set up"),
+        "MD102" => Some("[Run](javascript:alert(1))"),
+        "MD103" => Some("[Dashboard](http://localhost:3000)"),
+        "MD104" => Some("## Summary"),
+        "MD105" => Some("## [1.0.0] - 2024/01/02"),
+        "MD106" => Some("# Project"),
+        "MD107" => Some("\u{feff}# Title\r\n\r\nOne\nTwo\r\n"),
+        "MD089" => Some("## Old\n<!-- rumdl-deprecated-anchor: #new -->\n\n## New\n\nSee [old](#old)."),
         _ => None,
     }
 }
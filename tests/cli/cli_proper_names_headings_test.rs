@@ -0,0 +1,54 @@
+//! MD044 (proper names) and MD063 (heading capitalization) fix headings in a
+//! single `--fix` run without rewriting each other's changes.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check --no-cache README.md` in `dir` with `extra` arguments, returning stdout
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "README.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn fix_with_style(style: &str, content: &str) -> (String, String) {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join(".rumdl.toml"),
+        format!(
+            "[MD044]\nnames = [\"GitHub\", \"Good Application\"]\n\n[MD063]\nenabled = true\nstyle = \"{style}\"\n"
+        ),
+    )
+    .unwrap();
+    let path = temp.path().join("README.md");
+    fs::write(&path, content).unwrap();
+
+    check(temp.path(), &["--fix", "--unsafe-fixes"]);
+    let fixed = fs::read_to_string(&path).unwrap();
+    (fixed, check(temp.path(), &[]))
+}
+
+#[test]
+fn all_caps_headings_converge_with_proper_names() {
+    let (fixed, recheck) = fix_with_style(
+        "all-caps",
+        "# USING GITHUB ACTIONS\n\n## setting up github for good application\n\nPush to GITHUB today.\n",
+    );
+    assert_eq!(
+        fixed,
+        "# USING GITHUB ACTIONS\n\n## SETTING UP GitHub FOR Good Application\n\nPush to GitHub today.\n"
+    );
+    assert!(recheck.contains("No issues found"), "got:\n{recheck}");
+}
+
+#[test]
+fn sentence_case_headings_converge_with_proper_names() {
+    let (fixed, recheck) = fix_with_style("sentence-case", "# Deploying With GITHUB And good application\n");
+    assert_eq!(fixed, "# Deploying with GitHub and Good Application\n");
+    assert!(recheck.contains("No issues found"), "got:\n{recheck}");
+}
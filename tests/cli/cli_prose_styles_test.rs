@@ -0,0 +1,86 @@
+//! `[prose-styles]` loads Vale-compatible YAML rules from a styles folder and
+//! runs them as `<Style>.<Rule>` rules.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check doc.md` in `dir` with `extra` arguments, returning stdout
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "doc.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+/// A project using an `Acme` style with one substitution and one existence rule
+fn write_project(dir: &Path, doc: &str) {
+    let style = dir.join("styles/Acme");
+    fs::create_dir_all(&style).unwrap();
+    fs::write(
+        style.join("Terms.yml"),
+        "extends: substitution\nmessage: \"Use '%s' instead of '%s'.\"\nlevel: error\nignorecase: true\nswap:\n  e-mail: email\n",
+    )
+    .unwrap();
+    fs::write(
+        style.join("Weasel.yml"),
+        "extends: existence\nmessage: \"Avoid '%s'.\"\nlevel: warning\ntokens:\n  - very\n",
+    )
+    .unwrap();
+    fs::write(dir.join(".rumdl.toml"), "[prose-styles]\nstyles = [\"Acme\"]\n").unwrap();
+    fs::write(dir.join("doc.md"), doc).unwrap();
+}
+
+#[test]
+fn prose_style_rules_report_warnings() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path(), "# Doc\n\nSend an E-mail, it is very quick.\n");
+
+    let stdout = check(temp.path(), &[]);
+    assert!(
+        stdout.contains("doc.md:3:9: [Acme.Terms] Use 'email' instead of 'E-mail'. [*]"),
+        "got:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("doc.md:3:23: [Acme.Weasel] Avoid 'very'."),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn prose_style_rules_fix_and_disable() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(
+        temp.path(),
+        "# Doc\n\nSend an E-mail.\n\n<!-- rumdl-disable-next-line Acme.Terms -->\nOr an e-mail.\n",
+    );
+
+    check(temp.path(), &["--fix"]);
+    let fixed = fs::read_to_string(temp.path().join("doc.md")).unwrap();
+    assert_eq!(
+        fixed,
+        "# Doc\n\nSend an Email.\n\n<!-- rumdl-disable-next-line Acme.Terms -->\nOr an e-mail.\n"
+    );
+
+    let stdout = check(temp.path(), &["--disable", "Acme.Weasel,Acme.Terms"]);
+    assert!(!stdout.contains("Acme."), "got:\n{stdout}");
+}
+
+#[test]
+fn invalid_prose_styles_section_is_reported() {
+    let temp = tempfile::tempdir().unwrap();
+    write_project(temp.path(), "# Doc\n");
+    fs::write(temp.path().join(".rumdl.toml"), "[prose-styles]\nstyle = [\"Acme\"]\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(temp.path())
+        .args(["check", "--no-cache", "doc.md"])
+        .output()
+        .expect("failed to execute rumdl");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid [prose-styles] section"), "got:\n{stderr}");
+    assert!(stderr.contains("style"), "got:\n{stderr}");
+}
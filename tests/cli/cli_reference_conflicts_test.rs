@@ -0,0 +1,60 @@
+//! MD091 with `include-snippets` reports reference definitions that conflict
+//! across MkDocs snippet includes, in both the page and the snippet.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check --no-cache .` in `dir`, returning stdout
+fn check(dir: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "--disable", "MD053", "."])
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn write_docs(dir: &Path, include_snippets: bool) {
+    fs::write(
+        dir.join(".rumdl.toml"),
+        format!(
+            "[global]\nflavor = \"mkdocs\"\nextend-enable = [\"MD091\"]\n\n[MD091]\ninclude-snippets = {include_snippets}\n"
+        ),
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("docs/_snippets")).unwrap();
+    fs::write(
+        dir.join("docs/page.md"),
+        "# Page\n\nSee [api].\n\n--8<-- \"_snippets/links.md\"\n\n[api]: https://old.example\n",
+    )
+    .unwrap();
+    fs::write(dir.join("docs/_snippets/links.md"), "[API]: https://new.example\n").unwrap();
+}
+
+#[test]
+fn conflicts_across_snippet_includes_are_reported_in_both_files() {
+    let temp = tempfile::tempdir().unwrap();
+    write_docs(temp.path(), true);
+
+    let stdout = check(temp.path());
+    assert!(
+        stdout.contains(
+            "docs/page.md:7:1: [MD091] Conflicting reference definition [api]: 'https://old.example' here, 'https://new.example' in _snippets/links.md:1"
+        ),
+        "got:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("docs/_snippets/links.md:1:1: [MD091]") && stdout.contains("in ../page.md:7"),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn snippets_are_not_compared_without_include_snippets() {
+    let temp = tempfile::tempdir().unwrap();
+    write_docs(temp.path(), false);
+
+    let stdout = check(temp.path());
+    assert!(!stdout.contains("MD091"), "got:\n{stdout}");
+}
@@ -294,3 +294,48 @@ fn test_config_file_respect_gitignore_false() {
         "included.md should be linted, got:\n{combined}"
     );
 }
+
+#[test]
+fn test_dot_ignore_file_is_respected() {
+    // `.ignore` files exclude files like `.gitignore`, and are dropped with it
+    let temp_dir = setup_test_directory();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    fs::write(base_path.join(".ignore"), "also-ignored.md\n").unwrap();
+    fs::write(base_path.join("also-ignored.md"), "No heading here either.\n").unwrap();
+
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "."])
+        .output()
+        .expect("Failed to execute command");
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        !combined.contains("also-ignored.md"),
+        "also-ignored.md is listed in .ignore and should be skipped, got:\n{combined}"
+    );
+    assert!(
+        combined.contains("included.md"),
+        "included.md should be linted, got:\n{combined}"
+    );
+
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--respect-gitignore=false", "."])
+        .output()
+        .expect("Failed to execute command");
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("also-ignored.md"),
+        "also-ignored.md should be linted when --respect-gitignore=false, got:\n{combined}"
+    );
+}
@@ -0,0 +1,85 @@
+//! `--rule-profile NAME` applies a `[profiles.NAME]` table from the config on
+//! top of the file settings, below explicit `--config` overrides.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check` in `dir` with `extra` arguments, returning the exit code and output
+fn check(dir: &Path, extra: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    (output.status.code().unwrap_or(-1), combined)
+}
+
+/// A project with a 50-character line and two profiles
+fn project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join(".rumdl.toml"),
+        r#"[global]
+enable = ["MD013"]
+
+[profiles.strict]
+MD013 = { line-length = 40 }
+
+[profiles.relaxed]
+disable = ["MD013"]
+"#,
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("a.md"),
+        "# A\n\nThis line is exactly fifty characters long, yes.\n",
+    )
+    .unwrap();
+    temp
+}
+
+#[test]
+fn profile_is_inactive_unless_selected() {
+    let temp = project();
+    let (code, output) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{output}");
+}
+
+#[test]
+fn selected_profile_overrides_file_settings() {
+    let temp = project();
+    let (code, output) = check(temp.path(), &["--rule-profile", "strict"]);
+    assert_eq!(code, 1, "got:\n{output}");
+    assert!(output.contains("[MD013]"), "got:\n{output}");
+
+    let (code, output) = check(
+        temp.path(),
+        &["--rule-profile", "relaxed", "--config", "MD013.line-length=40"],
+    );
+    assert_eq!(code, 0, "got:\n{output}");
+}
+
+#[test]
+fn inline_config_wins_over_profile() {
+    let temp = project();
+    let (code, output) = check(
+        temp.path(),
+        &["--rule-profile", "strict", "--config", "MD013.line-length=60"],
+    );
+    assert_eq!(code, 0, "got:\n{output}");
+}
+
+#[test]
+fn unknown_profile_is_a_tool_error() {
+    let temp = project();
+    let (code, output) = check(temp.path(), &["--rule-profile", "nope"]);
+    assert_eq!(code, 2, "got:\n{output}");
+    assert!(
+        output.contains("Unknown rule profile 'nope' (available: relaxed, strict)"),
+        "got:\n{output}"
+    );
+}
@@ -0,0 +1,119 @@
+//! `--shard K/N` splits the discovered files across CI jobs: every file is
+//! linted by exactly one shard, cross-file links still resolve against files
+//! of other shards, and workspace-level checks report only in the first shard.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const SHARDS: usize = 3;
+
+/// Run `rumdl check` in `dir` with `extra` arguments, returning stdout and stderr
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "."])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    combined
+}
+
+/// Diagnostic lines (`path:line:col: [RULE] message`) of a run
+fn diagnostics(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter(|line| line.contains(": ["))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Six files in a ring, each with one valid and one broken link to the next
+fn write_ring(dir: &Path) {
+    fs::write(dir.join(".rumdl.toml"), "[global]\nextend-enable = [\"MD086\"]\n").unwrap();
+    fs::create_dir_all(dir.join("img")).unwrap();
+    fs::write(dir.join("img/unused.png"), b"png").unwrap();
+    for i in 1..=6 {
+        let next = i % 6 + 1;
+        fs::write(
+            dir.join(format!("f{i}.md")),
+            format!("# File {i}\n\n## Section\n\nSee [next](f{next}.md#section) and [bad](f{next}.md#missing).\n"),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn shards_partition_the_files_and_resolve_links_across_shards() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    write_ring(dir);
+
+    let full: BTreeSet<String> = diagnostics(&check(dir, &[])).into_iter().collect();
+    // One broken link per file plus the unreferenced image
+    assert_eq!(full.len(), 7, "got:\n{full:#?}");
+
+    let mut union = BTreeSet::new();
+    for k in 1..=SHARDS {
+        let shard = format!("{k}/{SHARDS}");
+        let output = check(dir, &["--shard", &shard]);
+        let lines = diagnostics(&output);
+        let image_reported = lines.iter().any(|line| line.contains("img/unused.png"));
+        assert_eq!(image_reported, k == 1, "shard {shard} got:\n{output}");
+        for line in lines {
+            assert!(union.insert(line.clone()), "reported by two shards: {line}");
+        }
+    }
+    assert_eq!(union, full);
+}
+
+#[test]
+fn shard_assignment_is_deterministic() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    write_ring(dir);
+
+    let first = check(dir, &["--shard", "2/3"]);
+    let second = check(dir, &["--shard", "2/3"]);
+    assert_eq!(diagnostics(&first), diagnostics(&second));
+}
+
+#[test]
+fn cached_index_picks_up_changes_in_other_shards() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    write_ring(dir);
+    let cached = |dir: &Path| {
+        let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+            .current_dir(dir)
+            .args(["check", ".", "--shard", "1/3"])
+            .output()
+            .expect("failed to execute rumdl");
+        diagnostics(&String::from_utf8_lossy(&output.stdout))
+    };
+
+    let first = cached(dir);
+    assert_eq!(cached(dir), first);
+
+    // Renaming every target heading breaks the links of this shard's files
+    for i in 1..=6 {
+        let path = dir.join(format!("f{i}.md"));
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, content.replace("## Section", "## Renamed")).unwrap();
+    }
+    let after = cached(dir);
+    assert_eq!(after, diagnostics(&check(dir, &["--shard", "1/3"])));
+    assert!(after.len() > first.len(), "got:\n{after:#?}");
+}
+
+#[test]
+fn invalid_shard_specs_are_rejected() {
+    let temp = tempfile::tempdir().unwrap();
+    for spec in ["0/3", "4/3", "2", "a/b"] {
+        let output = check(temp.path(), &["--shard", spec]);
+        assert!(output.contains("expected K/N"), "{spec} got:\n{output}");
+    }
+}
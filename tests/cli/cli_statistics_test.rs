@@ -103,3 +103,65 @@ fn test_statistics_with_quiet_mode() {
         // Statistics should not be shown in silent mode
         .stdout(predicate::str::is_empty());
 }
+
+#[test]
+fn test_statistics_json_output() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("a.md"),
+        "# Heading\nText\n## Sub\n* item 1\n+ item 2\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("b.md"), "# Heading\n\n* item 1\n+ item 2\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check")
+        .arg("--no-config")
+        .arg("--statistics")
+        .arg("--output-format")
+        .arg("json")
+        .arg(temp_dir.path());
+
+    let output = cmd.output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stats: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("statistics should be the only stdout output");
+
+    // Sorted by count, ties by rule ID
+    let rules: Vec<(&str, u64, u64)> = stats["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| {
+            (
+                r["rule"].as_str().unwrap(),
+                r["count"].as_u64().unwrap(),
+                r["fixable"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(rules, vec![("MD004", 2, 2), ("MD022", 2, 2), ("MD032", 1, 1)]);
+    assert_eq!(stats["rules"][0]["percentage"], 40.0);
+    assert_eq!(stats["total"], 5);
+    assert_eq!(stats["fixable"], 5);
+}
+
+#[test]
+fn test_statistics_json_output_with_no_issues() {
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("clean.md");
+    fs::write(&test_file, "# Heading\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check")
+        .arg("--no-config")
+        .arg("--statistics")
+        .arg("--output-format")
+        .arg("json")
+        .arg(&test_file);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stats, serde_json::json!({ "rules": [], "total": 0, "fixable": 0 }));
+}
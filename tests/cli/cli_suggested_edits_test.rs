@@ -0,0 +1,55 @@
+//! Suggested edits (MD024, MD041, MD045) appear in JSON output but are never
+//! counted as fixable or applied by `--fix`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `rumdl check --no-cache README.md` in `dir` with `extra` arguments, returning stdout
+fn check(dir: &Path, extra: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "README.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+const CONTENT: &str =
+    "Intro paragraph that is not a title, just some text.\n\n## Usage\n\n## Usage\n\n![](diagram.png)\n";
+
+#[test]
+fn json_output_reports_suggested_edits_as_not_fixable() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("README.md"), CONTENT).unwrap();
+
+    let stdout = check(temp.path(), &["--output-format", "json"]);
+    let warnings: Vec<serde_json::Value> = serde_json::from_str(&stdout).expect("valid JSON");
+
+    for rule in ["MD024", "MD041", "MD045"] {
+        let warning = warnings
+            .iter()
+            .find(|w| w["rule"] == rule)
+            .unwrap_or_else(|| panic!("missing {rule} in:\n{stdout}"));
+        assert_eq!(warning["fixable"], false, "{rule}: {warning}");
+        assert_eq!(warning["fix"]["applicability"], "suggested", "{rule}: {warning}");
+    }
+}
+
+#[test]
+fn fix_does_not_apply_suggested_edits() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("README.md");
+    fs::write(&path, CONTENT).unwrap();
+
+    let stdout = check(temp.path(), &[]);
+    assert!(
+        !stdout.contains("[*]"),
+        "suggestions must not be marked fixable:\n{stdout}"
+    );
+    assert!(!stdout.contains("Run `rumdl fmt`"), "got:\n{stdout}");
+
+    check(temp.path(), &["--fix"]);
+    assert_eq!(fs::read_to_string(&path).unwrap(), CONTENT);
+}
@@ -0,0 +1,69 @@
+//! Fixes that may change what a document says run only with `--unsafe-fixes`
+//! or `unsafe-fixes = true`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CONTENT: &str = "# hello world\n\nTrailing spaces  \nnext line \n";
+
+/// Run `rumdl check --fix --no-cache README.md` in `dir` with `extra` arguments
+/// and return the fixed file and stdout
+fn fix(dir: &Path, extra: &[&str]) -> (String, String) {
+    let path = dir.join("README.md");
+    fs::write(&path, CONTENT).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--fix", "--no-cache", "README.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    (
+        fs::read_to_string(&path).unwrap(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn unsafe_fixes_are_skipped_by_default() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join(".rumdl.toml"), "[MD063]\nenabled = true\n").unwrap();
+
+    let (fixed, stdout) = fix(temp.path(), &[]);
+    assert_eq!(fixed, "# hello world\n\nTrailing spaces  \nnext line\n");
+    assert!(stdout.contains("[MD009]"), "got:\n{stdout}");
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.contains("[MD063]") && !line.contains("[fixed]")),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn unsafe_fixes_flag_applies_them() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join(".rumdl.toml"), "[MD063]\nenabled = true\n").unwrap();
+
+    let (fixed, stdout) = fix(temp.path(), &["--unsafe-fixes"]);
+    assert_eq!(fixed, "# Hello World\n\nTrailing spaces  \nnext line\n");
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.contains("[MD063]") && line.contains("[fixed]")),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn unsafe_fixes_config_applies_them() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join(".rumdl.toml"),
+        "[global]\nunsafe-fixes = true\n\n[MD063]\nenabled = true\n",
+    )
+    .unwrap();
+
+    let (fixed, _) = fix(temp.path(), &[]);
+    assert_eq!(fixed, "# Hello World\n\nTrailing spaces  \nnext line\n");
+}
@@ -0,0 +1,82 @@
+//! `--report-unused-disables` flags inline disable comments that suppress no
+//! warnings, and `--fix` removes them while keeping the ones still in use.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const DOC: &str = "# Title
+
+<!-- rumdl-disable-next-line MD013 -- short line -->
+Short line.
+
+<!-- rumdl-disable-next-line MD013 MD009 -- generated -->
+This line is definitely far too long for the default limit of eighty characters, yes.
+
+<!-- rumdl-disable -->
+Nothing to suppress here.
+<!-- rumdl-enable -->
+
+Closing text.
+";
+
+/// Run `rumdl check` on `doc.md` in `dir` with `extra` arguments, returning
+/// the exit code and stdout
+fn check(dir: &Path, extra: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(dir)
+        .args(["check", "--no-cache", "--no-config", "doc.md"])
+        .args(extra)
+        .output()
+        .expect("failed to execute rumdl");
+    (
+        output.status.code().unwrap_or(-1),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn unused_disables_are_only_reported_on_request() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("doc.md"), DOC).unwrap();
+
+    let (code, stdout) = check(temp.path(), &[]);
+    assert_eq!(code, 0, "got:\n{stdout}");
+
+    let (code, stdout) = check(temp.path(), &["--report-unused-disables"]);
+    assert_eq!(code, 1);
+    let reported: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.contains("[unused-disable]"))
+        .collect();
+    assert_eq!(reported.len(), 3, "got:\n{stdout}");
+    assert!(reported[0].starts_with("doc.md:3:1:") && reported[0].contains("no MD013 warnings"));
+    assert!(reported[1].starts_with("doc.md:6:1:") && reported[1].contains("no MD009 warnings"));
+    assert!(reported[2].starts_with("doc.md:9:1:"));
+}
+
+#[test]
+fn fix_removes_unused_disables() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("doc.md");
+    fs::write(&path, DOC).unwrap();
+
+    check(temp.path(), &["--report-unused-disables", "--fix"]);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# Title
+
+Short line.
+
+<!-- rumdl-disable-next-line MD013 -- generated -->
+This line is definitely far too long for the default limit of eighty characters, yes.
+
+Nothing to suppress here.
+
+Closing text.
+"
+    );
+
+    let (code, stdout) = check(temp.path(), &["--report-unused-disables"]);
+    assert_eq!(code, 0, "got:\n{stdout}");
+}
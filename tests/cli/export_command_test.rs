@@ -0,0 +1,48 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_export_markdownlint_json_and_yaml() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join(".rumdl.toml"),
+        "[global]\ndisable = [\"MD033\"]\n\n[MD013]\nline-length = 120\n\n[MD061]\nterms = [\"TODO\"]\n",
+    )
+    .unwrap();
+
+    let output = cargo_bin_cmd!("rumdl")
+        .current_dir(temp_dir.path())
+        .arg("export")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["default"], serde_json::json!(true));
+    assert_eq!(json["MD013"], serde_json::json!({"line_length": 120}));
+    assert_eq!(json["MD033"], serde_json::json!(false));
+    assert!(json.get("MD061").is_none());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("MD061"));
+
+    cargo_bin_cmd!("rumdl")
+        .current_dir(temp_dir.path())
+        .args([
+            "export",
+            "--format",
+            "markdownlint-yaml",
+            "--output",
+            ".markdownlint.yaml",
+        ])
+        .assert()
+        .success();
+    let yaml = fs::read_to_string(temp_dir.path().join(".markdownlint.yaml")).unwrap();
+    assert!(yaml.starts_with("default: true\n"), "{yaml}");
+    assert!(yaml.contains("MD013:\n  line_length: 120\n"), "{yaml}");
+
+    // An existing output file is never overwritten
+    cargo_bin_cmd!("rumdl")
+        .current_dir(temp_dir.path())
+        .args(["export", "--output", ".markdownlint.yaml"])
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,49 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::fs;
+use tempfile::tempdir;
+
+const OLD_CONFIG: &str = "# Ported from markdownlint\nMD033 = false\n\n[global]\nforce-exclude = true\n\n[rules.MD013]\nline_length = 100\nenable_reflow = true\n";
+
+const MIGRATED_CONFIG: &str =
+    "# Ported from markdownlint\n\n[global]\ndisable = [\"MD033\"]\n\n[MD013]\nline-length = 100\nreflow = true\n";
+
+#[test]
+fn test_migrate_prints_diff_without_writing() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::write(root.join(".rumdl.toml"), OLD_CONFIG).unwrap();
+
+    let output = cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .arg("migrate")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+reflow = true"), "stdout: {stdout}");
+    assert!(
+        stdout.contains("Renamed enable_reflow to reflow in [MD013]"),
+        "stdout: {stdout}"
+    );
+    assert!(stdout.contains("Would update 1 config file(s)"), "stdout: {stdout}");
+    assert_eq!(fs::read_to_string(root.join(".rumdl.toml")).unwrap(), OLD_CONFIG);
+}
+
+#[test]
+fn test_migrate_write_updates_given_file() {
+    let temp_dir = tempdir().unwrap();
+    let config = temp_dir.path().join("old.toml");
+    fs::write(&config, OLD_CONFIG).unwrap();
+
+    cargo_bin_cmd!("rumdl")
+        .args(["migrate", "--write"])
+        .arg(&config)
+        .assert()
+        .success();
+    assert_eq!(fs::read_to_string(&config).unwrap(), MIGRATED_CONFIG);
+
+    let output = cargo_bin_cmd!("rumdl").arg("migrate").arg(&config).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("No config changes needed"));
+}
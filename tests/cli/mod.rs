@@ -1,24 +1,46 @@
+mod anchors_command_test;
 mod check_runner_tests;
 mod cli_alias_test;
 mod cli_cache_cross_file_test;
+mod cli_cache_invalidation_test;
+mod cli_ci_config_test;
 mod cli_config_override_test;
 mod cli_config_test;
+mod cli_daemon_test;
+mod cli_diff_base_test;
+mod cli_draft_test;
 mod cli_duplication_test;
 mod cli_explain_test;
 mod cli_fail_on_test;
+mod cli_fix_plan_test;
 mod cli_flag_precedence_test;
 mod cli_flavor_test;
+mod cli_group_by_test;
 mod cli_integration_tests;
+mod cli_interactive_fix_test;
+mod cli_large_file_test;
+mod cli_lint_code_blocks_test;
 mod cli_list_rules_removed_test;
 mod cli_lsp_fix_consistency;
+mod cli_proper_names_headings_test;
+mod cli_prose_styles_test;
+mod cli_reference_conflicts_test;
 mod cli_respect_gitignore_test;
+mod cli_rule_profile_test;
 mod cli_rules_wrapper_test;
+mod cli_shard_test;
 mod cli_show_full_path_test;
 mod cli_statistics_test;
+mod cli_suggested_edits_test;
+mod cli_unsafe_fixes_test;
+mod cli_unused_disables_test;
 mod config_shadow_warning_test;
 mod exclude_with_explicit_paths_test;
+mod export_command_test;
 pub(crate) mod fixtures;
 mod import_command_test;
 mod init_command_test;
 mod init_tests;
 mod markdownlintignore_test;
+mod migrate_command_test;
+mod mv_command_test;
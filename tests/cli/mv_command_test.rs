@@ -0,0 +1,76 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn setup_workspace(root: &Path) {
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(
+        root.join("README.md"),
+        "# Home\n\nRead [the guide](docs/guide.md#install).\n\n[guide]: ./docs/guide.md\n",
+    )
+    .unwrap();
+    fs::write(root.join("docs/guide.md"), "# Guide\n\nBack to [home](../README.md).\n").unwrap();
+}
+
+#[test]
+fn test_mv_dry_run_prints_diff_without_writing() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    setup_workspace(root);
+
+    let output = cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["mv", "docs/guide.md", "guide.md", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("-Back to [home](../README.md)."), "{stdout}");
+    assert!(stdout.contains("+Back to [home](README.md)."), "{stdout}");
+    assert!(stdout.contains("+Read [the guide](guide.md#install)."), "{stdout}");
+    assert!(stdout.contains("+[guide]: ./guide.md"), "{stdout}");
+    assert!(stdout.contains("update links in 1 file(s)"), "{stdout}");
+
+    assert!(root.join("docs/guide.md").exists());
+    assert!(!root.join("guide.md").exists());
+    assert!(
+        fs::read_to_string(root.join("README.md"))
+            .unwrap()
+            .contains("docs/guide.md")
+    );
+}
+
+#[test]
+fn test_mv_moves_file_and_rewrites_links() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    setup_workspace(root);
+    fs::create_dir_all(root.join("manual")).unwrap();
+
+    cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["mv", "docs/guide.md", "manual"])
+        .assert()
+        .success();
+
+    assert!(!root.join("docs/guide.md").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("manual/guide.md")).unwrap(),
+        "# Guide\n\nBack to [home](../README.md).\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("README.md")).unwrap(),
+        "# Home\n\nRead [the guide](manual/guide.md#install).\n\n[guide]: ./manual/guide.md\n"
+    );
+
+    // The destination must not already exist
+    fs::write(root.join("other.md"), "# Other\n").unwrap();
+    cargo_bin_cmd!("rumdl")
+        .current_dir(root)
+        .args(["mv", "other.md", "README.md"])
+        .assert()
+        .code(2);
+}
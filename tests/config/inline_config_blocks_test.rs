@@ -816,3 +816,48 @@ This is a very long line that exceeds 80 characters and should trigger MD013 wit
     // Should have MD013 warning with default 80 char limit
     assert_eq!(md013_warnings.len(), 1, "Expected MD013 warning with empty configure");
 }
+
+fn rule_lines(content: &str, rule: &str) -> Vec<usize> {
+    let rules = all_rules(&Config::default());
+    lint(
+        content,
+        &rules,
+        false,
+        rumdl_lib::config::MarkdownFlavor::Standard,
+        None,
+        Some(&Config::default()),
+    )
+    .unwrap()
+    .iter()
+    .filter(|w| w.rule_name.as_deref() == Some(rule))
+    .map(|w| w.line)
+    .collect()
+}
+
+#[test]
+fn test_front_matter_disable_and_configure() {
+    let long_line = "word ".repeat(22);
+    let content = format!(
+        "---\ntitle: Post\nrumdl:\n  disable: [single-title]\n  configure:\n    MD013:\n      line-length: 120\n---\n\n# One\n\n# Two\n\n{long_line}\n"
+    );
+
+    assert!(rule_lines(&content, "MD025").is_empty());
+    // 110 characters is within the configured 120
+    assert!(rule_lines(&content, "MD013").is_empty());
+}
+
+#[test]
+fn test_front_matter_draft_skips_line_length() {
+    let long_line = "word ".repeat(30);
+    let draft = format!("---\ndraft: true\n---\n\n# Draft\n\n{long_line}\n");
+    assert!(rule_lines(&draft, "MD013").is_empty());
+
+    let published = format!("---\ndraft: false\n---\n\n# Published\n\n{long_line}\n");
+    assert_eq!(rule_lines(&published, "MD013"), vec![7]);
+}
+
+#[test]
+fn test_comments_override_front_matter() {
+    let content = "---\nrumdl:\n  disable: [MD025]\n---\n\n# One\n\n<!-- rumdl-enable-file MD025 -->\n\n# Two\n";
+    assert_eq!(rule_lines(content, "MD025"), vec![10]);
+}
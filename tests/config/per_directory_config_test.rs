@@ -459,3 +459,91 @@ fn test_dot_config_dir_treated_as_root_config() {
         "Expected no MD013 for docs/guide.md with docs config (line-length=120), got:\n{combined}"
     );
 }
+
+#[test]
+fn test_overrides_apply_per_path_in_cli() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".git")).unwrap();
+    create_file(
+        root,
+        ".rumdl.toml",
+        r#"
+[MD013]
+line-length = 40
+
+[overrides]
+"docs/api/**" = { MD013 = { line-length = 120 }, disable = ["MD033"] }
+"#,
+    );
+
+    let content =
+        "# Title\n\nThis is a line with real words that exceeds the configured limit easily.\n\n<b>bold</b>\n";
+    create_file(root, "README.md", content);
+    create_file(root, "docs/api/endpoint.md", content);
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .args(["check", "--no-cache", "."])
+        .current_dir(root)
+        .output()
+        .expect("Failed to run rumdl");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let rules_for = |file: &str| -> Vec<&str> {
+        stdout
+            .lines()
+            .filter(|line| line.starts_with(file))
+            .filter_map(|line| line.split('[').nth(1)?.split(']').next())
+            .collect()
+    };
+    assert_eq!(rules_for("README.md"), vec!["MD013", "MD033"], "got:\n{stdout}");
+    assert!(rules_for("docs/api/endpoint.md").is_empty(), "got:\n{stdout}");
+}
+
+#[test]
+fn test_nested_configs_merge_layers_from_root_down() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+
+    fs::create_dir_all(root.join(".git")).unwrap();
+    create_file(
+        root,
+        ".rumdl.toml",
+        r#"
+[global]
+nested-configs = "merge"
+disable = ["MD033"]
+
+[MD013]
+line-length = 40
+"#,
+    );
+    create_file(root, "docs/.rumdl.toml", "[MD013]\nline-length = 120\n");
+    create_file(root, "docs/guide/.rumdl.toml", "[MD013]\ncode-blocks = false\n");
+
+    let content =
+        "# Title\n\nThis is a line with real words that exceeds the configured limit easily.\n\n<b>bold</b>\n";
+    create_file(root, "README.md", content);
+    create_file(root, "docs/intro.md", content);
+    create_file(root, "docs/guide/setup.md", content);
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .args(["check", "--no-cache", "."])
+        .current_dir(root)
+        .output()
+        .expect("Failed to run rumdl");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let rules_for = |file: &str| -> Vec<&str> {
+        stdout
+            .lines()
+            .filter(|line| line.starts_with(file))
+            .filter_map(|line| line.split('[').nth(1)?.split(']').next())
+            .collect()
+    };
+    // The root's disable list and the docs/ line length reach the deepest file
+    assert_eq!(rules_for("README.md"), vec!["MD013"], "got:\n{stdout}");
+    assert!(rules_for("docs/intro.md").is_empty(), "got:\n{stdout}");
+    assert!(rules_for("docs/guide/setup.md").is_empty(), "got:\n{stdout}");
+}
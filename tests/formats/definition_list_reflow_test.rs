@@ -38,6 +38,7 @@ reflow_mode = "sentence-per-line"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -94,6 +95,7 @@ reflow = true
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
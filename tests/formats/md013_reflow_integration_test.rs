@@ -40,6 +40,7 @@ reflow = true
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -108,6 +109,7 @@ fn test_md013_reflow_disabled_by_default() {
         .arg("check")
         .arg("--no-config")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .output()
         .expect("Failed to execute rumdl");
@@ -181,6 +183,7 @@ tables = true
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -254,6 +257,7 @@ enable-reflow = true
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -358,6 +362,7 @@ reflow = true
     // Run rumdl fmt to apply fix
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg("-e")
         .arg("MD013")
@@ -415,6 +420,7 @@ reflow-mode = "semantic-line-breaks"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -462,6 +468,7 @@ reflow-mode = "semantic-line-breaks"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -474,6 +481,7 @@ reflow-mode = "semantic-line-breaks"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -545,6 +553,7 @@ reflow = true
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -567,6 +576,7 @@ reflow = true
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -597,6 +607,7 @@ fn test_md013_issue_677_code_spans_exempt_after_reflow() {
     // Format: the paragraph reflows.
     let fmt = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -652,6 +663,7 @@ reflow-mode = "normalize"
 
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -684,6 +696,7 @@ reflow-mode = "normalize"
     // Idempotence: a second pass changes nothing.
     let _ = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -719,6 +732,7 @@ reflow-mode = "normalize"
 
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -743,6 +757,7 @@ reflow-mode = "normalize"
 
     let second_pass = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -825,6 +840,7 @@ fn test_md013_reflow_preserves_mkdocs_attr_list() {
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -868,6 +884,7 @@ fn test_md013_reflow_preserves_multiple_attr_lists() {
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -913,6 +930,7 @@ fn test_md013_reflow_attr_list_wrappable_in_standard_flavor() {
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -943,6 +961,7 @@ fn test_md013_reflow_attr_list_wrappable_in_standard_flavor() {
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -984,6 +1003,7 @@ This line has {some random text in braces} that is not an attr list and should b
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -1072,6 +1092,7 @@ Content inside the block.
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -1111,6 +1132,7 @@ reflow-mode = "normalize"
 
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("fmt")
+        .arg("--unsafe-fixes")
         .arg("--no-cache")
         .arg(&file_path)
         .arg("--config")
@@ -1234,6 +1256,7 @@ reflow-mode = "normalize"
         std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
             .arg("check")
             .arg("--fix")
+            .arg("--unsafe-fixes")
             .arg("--no-cache")
             .arg(&file_path)
             .arg("--config")
@@ -1292,6 +1315,7 @@ reflow-mode = "semantic-line-breaks"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -1333,6 +1357,7 @@ reflow-mode = "semantic-line-breaks"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -1357,6 +1382,7 @@ fn run_md013_fix(content: &str, config: &str) -> String {
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -1377,6 +1403,7 @@ fn run_md013_fix(content: &str, config: &str) -> String {
     let _ = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -59,6 +59,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -186,6 +187,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -250,6 +252,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -314,6 +317,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -382,6 +386,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -450,6 +455,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -505,6 +511,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -563,6 +570,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -628,6 +636,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -664,6 +673,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -720,6 +730,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -767,6 +778,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -814,6 +826,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -865,6 +878,7 @@ reflow-mode = "normalize"
     let _output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -930,6 +944,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -1100,6 +1115,7 @@ reflow-mode = "normalize"
     std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -20,6 +20,10 @@ fn create_sentence_per_line_rule() -> MD013LineLength {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec![],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -238,6 +242,10 @@ fn test_single_sentence_with_no_line_length_constraint() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec![],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -305,6 +313,10 @@ fn test_custom_abbreviations_recognized() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec!["Assn".to_string()],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -340,6 +352,10 @@ fn test_custom_abbreviations_merged_with_builtin() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec!["Assn".to_string()],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -375,6 +391,10 @@ fn test_custom_abbreviation_with_period_in_config() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec!["Univ".to_string()],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -395,6 +415,10 @@ fn test_custom_abbreviation_with_period_in_config() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec!["Univ.".to_string()],
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -437,6 +461,10 @@ fn test_issue_335_abbreviations_config_empty_vec_uses_defaults() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec![], // Empty = use built-in defaults
         require_sentence_capital: true,
         ignore_link_urls: true,
@@ -493,6 +521,10 @@ fn test_issue_335_custom_abbreviations_extend_defaults() {
         reflow: true,
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
+        ambiguous_width: rumdl_lib::rules::md013_line_length::md013_config::AmbiguousWidth::default(),
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: rumdl_lib::rules::md013_line_length::md013_config::SemanticBreakRules::default(),
+        table_reflow: rumdl_lib::rules::md013_line_length::md013_config::TableReflow::default(),
         abbreviations: vec!["Corp".to_string(), "Inc".to_string()],
         require_sentence_capital: true,
         ignore_link_urls: true,
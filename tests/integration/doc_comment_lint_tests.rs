@@ -1,9 +1,11 @@
-/// Integration tests for Rust doc comment linting.
+/// Integration tests for doc comment linting.
 ///
 /// Tests extraction, checking, and fix restoration of markdown
-/// embedded in `///` and `//!` doc comments.
+/// embedded in `///` and `//!` doc comments, Python docstrings, and JSDoc blocks.
 use rumdl_lib::config::Config;
-use rumdl_lib::doc_comment_lint::{DocCommentKind, check_doc_comment_blocks, extract_doc_comment_blocks};
+use rumdl_lib::doc_comment_lint::{
+    DocCommentKind, SourceLanguage, check_doc_comment_blocks, check_source_doc_blocks, extract_doc_comment_blocks,
+};
 use rumdl_lib::rule::Rule;
 use rumdl_lib::rules;
 
@@ -705,3 +707,68 @@ fn test_md013_still_flags_long_prose_in_doc_comments() {
         "MD013 should still flag long prose lines in doc comments"
     );
 }
+
+// ─── Python docstring and JSDoc tests ───────────────────────────
+
+#[test]
+fn test_python_docstring_warnings_map_to_file_lines() {
+    let content =
+        "import os\n\n\ndef f():\n    \"\"\"Summary.\n\n    *  bad list marker spacing\n    \"\"\"\n    return 1\n";
+
+    let rules = default_rules();
+    let config = Config::default();
+    let warnings = check_source_doc_blocks(content, SourceLanguage::Python, &rules, &config);
+
+    let md030: Vec<_> = warnings
+        .iter()
+        .filter(|w| w.rule_name.as_deref() == Some("MD030"))
+        .collect();
+    assert_eq!(md030.len(), 1, "Expected one MD030 warning, got: {warnings:?}");
+    assert_eq!(md030[0].line, 7);
+    // Column within the dedented markdown, offset by the 4-space indent
+    assert_eq!(md030[0].column, 2 + 4);
+    assert!(md030[0].fix.is_none(), "Docstring warnings must not carry fixes");
+}
+
+#[test]
+fn test_python_code_is_not_linted_as_markdown() {
+    let content = "#  Not a heading, just a comment\nx = 1   \n\n\n\ndef f():\n    \"\"\"Clean docstring.\"\"\"\n";
+
+    let rules = default_rules();
+    let config = Config::default();
+    let warnings = check_source_doc_blocks(content, SourceLanguage::Python, &rules, &config);
+    assert!(
+        warnings.is_empty(),
+        "Only docstrings should be linted, got: {warnings:?}"
+    );
+}
+
+#[test]
+fn test_jsdoc_warnings_map_to_file_lines() {
+    let content = "const a = 1;\n/**\n * Adds numbers.\n *\n * #Usage\n * @param {number} x\n */\nfunction add(x) {}\n";
+
+    let rules = default_rules();
+    let config = Config::default();
+    let warnings = check_source_doc_blocks(content, SourceLanguage::JavaScript, &rules, &config);
+
+    let md018: Vec<_> = warnings
+        .iter()
+        .filter(|w| w.rule_name.as_deref() == Some("MD018"))
+        .collect();
+    assert_eq!(md018.len(), 1, "Expected one MD018 warning, got: {warnings:?}");
+    assert_eq!(md018[0].line, 5);
+    // Column within the markdown, offset by the ` * ` prefix
+    assert_eq!(md018[0].column, 2 + 3);
+}
+
+#[test]
+fn test_check_source_doc_blocks_rust_matches_check_doc_comment_blocks() {
+    let content = "/// #Heading\n///\n/// Text.\nfn foo() {}\n";
+
+    let rules = default_rules();
+    let config = Config::default();
+    let via_language = check_source_doc_blocks(content, SourceLanguage::Rust, &rules, &config);
+    let direct = check_doc_comment_blocks(content, &rules, &config);
+    assert_eq!(via_language.len(), direct.len());
+    assert!(!direct.is_empty());
+}
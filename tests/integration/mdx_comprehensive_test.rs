@@ -229,6 +229,26 @@ import Something from 'somewhere'
     assert!(!ctx.lines[2].in_esm_block, "Code block content should not be ESM block");
 }
 
+#[test]
+fn test_mdx_jsx_tags_in_fenced_code_stay_code() {
+    let content = r#"```jsx
+<Card title="Example" />
+<Tabs>
+```
+
+Text
+"#;
+    let ctx = LintContext::new(content, MarkdownFlavor::MDX, None);
+
+    assert!(ctx.lines[1].in_code_block, "Self-closing tag in fence is still code");
+    assert!(!ctx.lines[1].in_jsx_block, "Sample code should not be a JSX block");
+    assert!(ctx.lines[2].in_code_block, "Unclosed tag in fence is still code");
+    assert!(
+        !ctx.lines[5].in_jsx_block,
+        "Unclosed sample tag must not open a JSX block"
+    );
+}
+
 // ====================================================================
 // Mixed Content Tests
 // ====================================================================
@@ -7,8 +7,8 @@ fn test_all_rules_returns_all_rules() {
     let config = Config::default();
     let rules = all_rules(&config);
 
-    // Should return all 76 rules as defined in the RULES array (MD001-MD082)
-    assert_eq!(rules.len(), 76);
+    // Should return all 101 rules as defined in the RULES array (MD001-MD107)
+    assert_eq!(rules.len(), 101);
 
     // Verify some specific rules are present
     let rule_names: HashSet<String> = rules.iter().map(|r| r.name().to_string()).collect();
@@ -28,16 +28,20 @@ fn test_all_rules_returns_all_rules() {
 /// `docs/rules.md` and `docs/stability.md`): which rules run by default must not
 /// change silently. Flipping a rule's `opt_in` flag, adding a new opt-in rule, or
 /// removing one all change the default set and trip this guard. The sibling test
-/// `test_all_rules_returns_all_rules` pins the total at 76, so together they pin
+/// `test_all_rules_returns_all_rules` pins the total at 101, so together they pin
 /// the default-enabled set as well.
 ///
 /// If this fails because of an intentional change, update both this set and the
 /// opt-in table in `docs/rules.md`.
 #[test]
 fn test_opt_in_rule_set_is_frozen() {
-    let expected: HashSet<&'static str> = ["MD060", "MD063", "MD070", "MD072", "MD073", "MD074", "MD080", "MD082"]
-        .into_iter()
-        .collect();
+    let expected: HashSet<&'static str> = [
+        "MD060", "MD063", "MD070", "MD072", "MD073", "MD074", "MD080", "MD082", "MD083", "MD084", "MD085", "MD086",
+        "MD087", "MD088", "MD089", "MD090", "MD091", "MD092", "MD093", "MD094", "MD095", "MD096", "MD097", "MD098",
+        "MD099", "MD100", "MD101", "MD102", "MD103", "MD104", "MD105", "MD106", "MD107",
+    ]
+    .into_iter()
+    .collect();
 
     assert_eq!(
         opt_in_rules(),
@@ -393,8 +397,8 @@ fn test_all_configurable_rules_expose_config_schema() {
     // Update this number when adding new configurable rules.
     assert_eq!(
         rules_with_config.len(),
-        52,
-        "Expected 52 rules with config sections. If you added config to a rule, \
+        77,
+        "Expected 77 rules with config sections. If you added config to a rule, \
          implement default_config_section(). Rules with config: {rules_with_config:?}"
     );
 }
@@ -811,18 +815,35 @@ fn test_wasm_config_parity_all_global_fields_wired() {
         extend_enable,
         extend_disable,
         line_length,
+        length_mode,
+        ambiguous_width,
         flavor,
         fixable,
         unfixable,
+        unsafe_fixes,
         enable_is_explicit,
         // Filesystem-only fields (not relevant for WASM single-string linting)
         exclude: _,
         include: _,
+        extensions: _,
+        extract_from: _,
+        html_markdown_patterns: _,
         respect_gitignore: _,
         output_format: _,
         force_exclude: _,
         cache_dir: _,
+        docs_base_url: _,
         cache: _,
+        nested_configs: _,
+        fix_max_iterations: _,
+        rule_timeout_ms: _,
+        max_file_size: _,
+        large_file_mode: _,
+        draft_key: _,
+        draft_value: _,
+        draft_disable: _,
+        // Not yet exposed to WASM: anchor rules fall back to the flavor's style
+        slugify: _,
     } = gc;
 
     // Verify the WASM-relevant fields have known defaults
@@ -831,9 +852,12 @@ fn test_wasm_config_parity_all_global_fields_wired() {
     assert!(extend_enable.is_empty());
     assert!(extend_disable.is_empty());
     assert_eq!(line_length.get(), 80);
+    assert_eq!(length_mode, rumdl_lib::types::LengthMode::Visual);
+    assert_eq!(ambiguous_width, rumdl_lib::types::AmbiguousWidth::Narrow);
     assert_eq!(flavor, rumdl_lib::config::MarkdownFlavor::Standard);
     assert!(fixable.is_empty());
     assert!(unfixable.is_empty());
+    assert!(!unsafe_fixes);
     assert!(!enable_is_explicit);
 
     // Now construct a Config with every WASM-relevant field set to non-default values
@@ -844,9 +868,12 @@ fn test_wasm_config_parity_all_global_fields_wired() {
     config.global.extend_enable = vec!["MD060".to_string()];
     config.global.extend_disable = vec!["MD013".to_string()];
     config.global.line_length = rumdl_lib::types::LineLength::new(120);
+    config.global.length_mode = rumdl_lib::types::LengthMode::Chars;
+    config.global.ambiguous_width = rumdl_lib::types::AmbiguousWidth::Wide;
     config.global.flavor = rumdl_lib::config::MarkdownFlavor::MkDocs;
     config.global.fixable = vec!["MD009".to_string()];
     config.global.unfixable = vec!["MD033".to_string()];
+    config.global.unsafe_fixes = true;
 
     // Verify every field is set to what we expect (non-default)
     assert_eq!(config.global.disable, vec!["MD041".to_string()], "disable");
@@ -863,6 +890,16 @@ fn test_wasm_config_parity_all_global_fields_wired() {
         "extend_disable"
     );
     assert_eq!(config.global.line_length.get(), 120, "line_length");
+    assert_eq!(
+        config.global.length_mode,
+        rumdl_lib::types::LengthMode::Chars,
+        "length_mode"
+    );
+    assert_eq!(
+        config.global.ambiguous_width,
+        rumdl_lib::types::AmbiguousWidth::Wide,
+        "ambiguous_width"
+    );
     assert_eq!(
         config.global.flavor,
         rumdl_lib::config::MarkdownFlavor::MkDocs,
@@ -870,6 +907,7 @@ fn test_wasm_config_parity_all_global_fields_wired() {
     );
     assert_eq!(config.global.fixable, vec!["MD009".to_string()], "fixable");
     assert_eq!(config.global.unfixable, vec!["MD033".to_string()], "unfixable");
+    assert!(config.global.unsafe_fixes, "unsafe_fixes");
 
     // filter_rules should respect enable_is_explicit + extend_enable
     let all = all_rules(&config);
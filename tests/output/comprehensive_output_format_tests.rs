@@ -207,6 +207,22 @@ fn test_azure_output_format() {
     assert_no_summary_text(&stdout, "azure");
 }
 
+#[test]
+fn test_teamcity_output_format() {
+    let (_temp_dir, test_file) = create_test_file();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check").arg("--output-format").arg("teamcity").arg(&test_file);
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let stdout = String::from_utf8_lossy(&output);
+
+    assert!(stdout.contains("##teamcity[inspectionType id='MD022' name='MD022'"));
+    assert!(stdout.contains("##teamcity[inspection typeId='MD009' message="));
+    assert!(stdout.contains("line='2' SEVERITY='WARNING']"));
+    assert_no_summary_text(&stdout, "teamcity");
+}
+
 #[test]
 fn test_sarif_output_format() {
     let (_temp_dir, test_file) = create_test_file();
@@ -242,6 +258,47 @@ fn test_junit_output_format() {
     assert!(stdout.contains(r#"<failure"#));
 }
 
+#[test]
+fn test_html_output_format() {
+    let (_temp_dir, test_file) = create_test_file();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check").arg("--output-format").arg("html").arg(&test_file);
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let stdout = String::from_utf8_lossy(&output);
+
+    // A standalone page with a section per file and the source around each warning
+    assert!(stdout.starts_with("<!DOCTYPE html>"));
+    assert!(stdout.contains(r#"<section class="file" id="file-0">"#));
+    assert!(stdout.contains(r#"class="rule-filter""#));
+    assert!(stdout.contains(r#"<pre class="context">"#));
+    assert!(stdout.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn test_show_source_text_output() {
+    let (_temp_dir, test_file) = create_test_file();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check").arg("--show-source").arg("--no-config").arg(&test_file);
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let stdout = String::from_utf8_lossy(&output);
+
+    // Each warning line is followed by the source with the span underlined
+    assert!(stdout.contains(":2:28: [MD009]"), "stdout: {stdout}");
+    assert!(
+        stdout.contains(" 2 | Content with trailing space   \n"),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains("   |                            ^^^\n"),
+        "stdout: {stdout}"
+    );
+    assert!(stdout.contains(" 1 | # Test Heading"), "stdout: {stdout}");
+}
+
 #[test]
 fn test_invalid_output_format() {
     let (_temp_dir, test_file) = create_test_file();
@@ -328,7 +385,7 @@ fn test_machine_readable_formats_no_summary_on_clean_file() {
     let test_file = temp_dir.path().join("clean.md");
     fs::write(&test_file, "# Clean heading\n\nSome content.\n").unwrap();
 
-    for format in &["json-lines", "github", "pylint", "azure"] {
+    for format in &["json-lines", "github", "pylint", "azure", "teamcity"] {
         let mut cmd = cargo_bin_cmd!("rumdl");
         cmd.arg("check").arg("--output-format").arg(format).arg(&test_file);
 
@@ -345,7 +402,7 @@ fn test_machine_readable_formats_no_summary_on_clean_file() {
 /// Verify that ALL machine-readable formats produce zero summary text in fix mode.
 #[test]
 fn test_machine_readable_formats_no_summary_in_fix_mode() {
-    for format in &["json-lines", "github", "pylint", "azure"] {
+    for format in &["json-lines", "github", "pylint", "azure", "teamcity"] {
         let temp_dir = tempdir().unwrap();
         let test_file = temp_dir.path().join("fixable.md");
         fs::write(&test_file, "# Test\nContent with trailing space   \n").unwrap();
@@ -400,13 +457,13 @@ fn test_json_lines_strict_validity_multiple_warnings() {
     }
 }
 
-/// Verify batch formats (json, gitlab, sarif, junit) also produce no summary text.
+/// Verify batch formats (json, gitlab, sarif, junit, html) also produce no summary text.
 /// These were already suppressed via needs_collection, but verify explicitly.
 #[test]
 fn test_batch_formats_no_summary_text() {
     let (_temp_dir, test_file) = create_test_file();
 
-    for format in &["json", "gitlab", "sarif", "junit"] {
+    for format in &["json", "gitlab", "sarif", "junit", "html"] {
         let mut cmd = cargo_bin_cmd!("rumdl");
         cmd.arg("check").arg("--output-format").arg(format).arg(&test_file);
 
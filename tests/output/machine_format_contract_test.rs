@@ -62,6 +62,10 @@ fn json_contract() {
             assert!(fix["range"]["start"].is_u64(), "fix.range.start: {w}");
             assert!(fix["range"]["end"].is_u64(), "fix.range.end: {w}");
             assert!(fix["replacement"].is_string(), "fix.replacement: {w}");
+            assert!(
+                matches!(fix["safety"].as_str(), Some("safe" | "unsafe")),
+                "fix.safety: {w}"
+            );
             saw_fix = true;
         }
     }
@@ -72,21 +76,29 @@ fn json_contract() {
 }
 
 #[test]
-fn json_lines_contract_omits_fix() {
+fn json_lines_contract_carries_fix() {
     let (_dir, path) = write_fixture();
     let stdout = run_format(&path, "json-lines");
 
     let mut count = 0;
+    let mut saw_fix = false;
     for line in stdout.lines().filter(|l| !l.trim().is_empty()) {
         let w: Value = serde_json::from_str(line).expect("each line is a valid JSON object");
         for field in ["file", "line", "column", "rule", "message", "severity", "fixable"] {
             assert!(w.get(field).is_some(), "json-lines missing {field:?}: {line}");
         }
-        // json-lines intentionally omits the fix object (use json for fix detail).
-        assert!(w.get("fix").is_none(), "json-lines must omit the fix object: {line}");
+        // json-lines carries the same fix object as json.
+        if w["fixable"] == true {
+            let fix = &w["fix"];
+            assert!(fix["range"]["start"].is_u64(), "fix.range.start: {line}");
+            assert!(fix["replacement"].is_string(), "fix.replacement: {line}");
+            assert!(fix["safety"].is_string(), "fix.safety: {line}");
+            saw_fix = true;
+        }
         count += 1;
     }
     assert!(count >= 1, "expected at least one json-lines record");
+    assert!(saw_fix, "expected at least one json-lines record with a fix");
 }
 
 #[test]
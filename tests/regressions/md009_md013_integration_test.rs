@@ -36,6 +36,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
@@ -108,6 +109,7 @@ reflow-mode = "normalize"
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_rumdl"))
         .arg("check")
         .arg("--fix")
+        .arg("--unsafe-fixes")
         .arg(&file_path)
         .arg("--config")
         .arg(&config_path)
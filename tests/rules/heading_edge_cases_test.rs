@@ -1038,7 +1038,7 @@ fn test_table_row_not_setext_heading_cjk_content() {
     let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
     let headings: Vec<_> = ctx.valid_headings().collect();
     assert_eq!(headings.len(), 1, "Only the ATX heading should be detected");
-    assert_eq!(headings[0].heading.text, "📚 文档");
+    assert_eq!(headings[0].heading.text(ctx.content), "📚 文档");
 
     let rule = MD003HeadingStyle::new(HeadingStyle::Atx);
     let result = rule.check(&ctx).unwrap();
@@ -217,7 +217,7 @@ fn test_tab_indented_list_detection_regression() {
         .lines
         .iter()
         .enumerate()
-        .filter_map(|(i, l)| l.list_item.as_ref().map(|item| (i + 1, &item.marker)))
+        .filter_map(|(i, l)| l.list_item.as_ref().map(|item| (i + 1, item.marker(ctx.content))))
         .collect();
 
     assert_eq!(
@@ -225,8 +225,8 @@ fn test_tab_indented_list_detection_regression() {
         2,
         "Regression: Both list items must be detected. Got: {detected:?}"
     );
-    assert_eq!(detected[0], (1, &"*".to_string()), "Line 1 should have '*' marker");
-    assert_eq!(detected[1], (2, &"-".to_string()), "Line 2 should have '-' marker");
+    assert_eq!(detected[0], (1, "*"), "Line 1 should have '*' marker");
+    assert_eq!(detected[1], (2, "-"), "Line 2 should have '-' marker");
 }
 
 /// Verify that rules actually WORK with tab-indented lists, not just detection.
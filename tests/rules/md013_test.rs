@@ -580,12 +580,13 @@ More regular text exceeding 40 characters"#;
 
 #[test]
 fn test_unicode_characters_counted_correctly() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
 
     // Test with explicit chars mode (for backward compatibility testing)
     let config = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1142,12 +1143,13 @@ fn test_reflow_unicode_handling() {
 
 #[test]
 fn test_length_mode_chars_with_cjk() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
 
     // Default mode (chars) counts CJK characters as 1 each
     let config = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
 
@@ -1165,6 +1167,7 @@ fn test_length_mode_chars_with_cjk() {
     let config_strict = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: true,
         ..Default::default()
     };
@@ -1178,13 +1181,14 @@ fn test_length_mode_chars_with_cjk() {
 
 #[test]
 fn test_length_mode_visual_with_cjk() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
     use unicode_width::UnicodeWidthStr;
 
     // Visual mode counts CJK characters as 2 columns each
     let config = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
 
@@ -1219,6 +1223,7 @@ fn test_length_mode_visual_with_cjk() {
     let config_strict = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: true,
         ..Default::default()
     };
@@ -1232,12 +1237,13 @@ fn test_length_mode_visual_with_cjk() {
 
 #[test]
 fn test_length_mode_chars_with_emoji() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
 
     // Chars mode counts emoji as 1 character each
     let config = MD013Config {
         line_length: LineLength::from_const(15),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
 
@@ -1253,13 +1259,14 @@ fn test_length_mode_chars_with_emoji() {
 
 #[test]
 fn test_length_mode_visual_with_emoji() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
     use unicode_width::UnicodeWidthStr;
 
     // Visual mode: Most emoji are 2 columns wide
     let config = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
 
@@ -1295,6 +1302,7 @@ fn test_length_mode_visual_with_emoji() {
     let config_strict = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: true,
         ..Default::default()
     };
@@ -1309,12 +1317,13 @@ fn test_length_mode_visual_with_emoji() {
 
 #[test]
 fn test_length_mode_bytes() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
 
     // Bytes mode counts raw UTF-8 bytes
     let config = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Bytes,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
 
@@ -1338,6 +1347,7 @@ fn test_length_mode_bytes() {
     let config_strict = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Bytes,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: true,
         ..Default::default()
     };
@@ -1351,7 +1361,7 @@ fn test_length_mode_bytes() {
 
 #[test]
 fn test_length_mode_mixed_content() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
     use unicode_width::UnicodeWidthStr;
 
     // Test with mixed CJK, emoji, and ASCII content
@@ -1361,6 +1371,7 @@ fn test_length_mode_mixed_content() {
     let config_chars = MD013Config {
         line_length: LineLength::from_const(15),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
     let rule_chars = MD013LineLength::from_config_struct(config_chars);
@@ -1372,6 +1383,7 @@ fn test_length_mode_mixed_content() {
     let config_visual = MD013Config {
         line_length: LineLength::from_const(15),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
     let rule_visual = MD013LineLength::from_config_struct(config_visual);
@@ -1388,7 +1400,7 @@ fn test_length_mode_mixed_content() {
 
 #[test]
 fn test_length_mode_with_urls() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
 
     // Test that URL exceptions work correctly with different length modes
     // Note: Non-strict mode replaces long URLs with placeholders
@@ -1398,6 +1410,7 @@ fn test_length_mode_with_urls() {
     let config = MD013Config {
         line_length: LineLength::from_const(100), // Set high limit to test behavior
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: false,
         ..Default::default()
     };
@@ -1411,7 +1424,7 @@ fn test_length_mode_with_urls() {
 
 #[test]
 fn test_length_mode_japanese_text() {
-    use rumdl_lib::rules::md013_line_length::md013_config::{LengthMode, MD013Config};
+    use rumdl_lib::rules::md013_line_length::md013_config::{AmbiguousWidth, LengthMode, MD013Config};
     use unicode_width::UnicodeWidthStr;
 
     // Real-world Japanese example
@@ -1421,6 +1434,7 @@ fn test_length_mode_japanese_text() {
     let config_chars = MD013Config {
         line_length: LineLength::from_const(15),
         length_mode: LengthMode::Chars,
+        ambiguous_width: AmbiguousWidth::default(),
         ..Default::default()
     };
     let rule_chars = MD013LineLength::from_config_struct(config_chars);
@@ -1433,6 +1447,7 @@ fn test_length_mode_japanese_text() {
     let config_visual = MD013Config {
         line_length: LineLength::from_const(20),
         length_mode: LengthMode::Visual,
+        ambiguous_width: AmbiguousWidth::default(),
         strict: true,
         ..Default::default()
     };
@@ -1634,6 +1649,42 @@ fn test_pandoc_div_with_indented_yaml_like_content_not_suppressed() {
     );
 }
 
+#[test]
+fn test_pandoc_attribute_blocks_do_not_count_toward_length() {
+    let content = "\
+::: {.callout-note #note-1 title=\"A long title attribute that pushes this div opener past eighty\"}
+Body.
+:::
+
+A [span]{.underline #u1 key=\"value with spaces and a long attribute past the limit\"} in prose.
+
+![Caption](image.png){#fig-1 width=50% fig-alt=\"A long alt text attribute for this figure\"} Figure one.
+";
+    let rule = MD013LineLength::new(80, true, true, true, false);
+
+    let ctx = LintContext::new(content, MarkdownFlavor::Pandoc, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+    let ctx = LintContext::new(content, MarkdownFlavor::Quarto, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+
+    // Standard flavor has no attribute syntax, so the lines are flagged
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    assert_eq!(rule.check(&ctx).unwrap().len(), 3);
+
+    // Strict mode disables the exemption
+    let strict = MD013LineLength::new(80, true, true, true, true);
+    let ctx = LintContext::new(content, MarkdownFlavor::Pandoc, None);
+    assert_eq!(strict.check(&ctx).unwrap().len(), 3);
+}
+
+#[test]
+fn test_pandoc_prose_beyond_attribute_block_still_flagged() {
+    let content = "This sentence is already long enough to exceed the configured limit by itself [x]{.y}\n";
+    let rule = MD013LineLength::new(40, true, true, true, false);
+    let ctx = LintContext::new(content, MarkdownFlavor::Pandoc, None);
+    assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+}
+
 #[test]
 fn test_autodoc_with_handler_colon_syntax() {
     use rumdl_lib::rules::md013_line_length::md013_config::MD013Config;
@@ -17,7 +17,7 @@ fn test_missing_alt_text() {
     let ctx = rumdl_lib::lint_context::LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
     let result = rule.check(&ctx).unwrap();
     assert_eq!(result.len(), 1);
-    assert!(result[0].fix.is_none(), "MD045 should not offer auto-fix");
+    assert!(result[0].automatic_fix().is_none(), "MD045 should not offer auto-fix");
 }
 
 #[test]
@@ -27,7 +27,7 @@ fn test_empty_alt_text() {
     let ctx = rumdl_lib::lint_context::LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
     let result = rule.check(&ctx).unwrap();
     assert_eq!(result.len(), 1);
-    assert!(result[0].fix.is_none());
+    assert!(result[0].automatic_fix().is_none());
 }
 
 #[test]
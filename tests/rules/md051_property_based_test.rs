@@ -307,7 +307,7 @@ fn extract_generated_headings(_rule: &MD051LinkFragments, ctx: &LintContext) ->
         if let Some(heading) = &line_info.heading {
             // We can't directly call the private method, so we'll use a heuristic
             // This is not perfect but good enough for property testing
-            let text = &heading.text;
+            let text = heading.text(ctx.content);
             let fragment = text
                 .to_lowercase()
                 .chars()
@@ -452,7 +452,11 @@ fn test_inline_code_spans() {
             line_info.byte_offset
         );
         if let Some(heading) = &line_info.heading {
-            println!("  Has heading: level={}, text='{}'", heading.level, heading.text);
+            println!(
+                "  Has heading: level={}, text='{}'",
+                heading.level,
+                heading.text(ctx.content)
+            );
         }
     }
 
@@ -1230,7 +1234,7 @@ fn test_performance_with_many_links() {
     println!(
         "MD051 performance test: {}ms for {} links",
         duration.as_millis(),
-        ctx.links.len()
+        ctx.links().len()
     );
 }
 
@@ -3453,3 +3457,54 @@ mod same_doc_html_anchor_consistency_tests {
         );
     }
 }
+
+/// The global `slugify` option changes the anchors MD051 expects, unless the
+/// rule pins its own `anchor-style`.
+mod global_slugify_tests {
+    use rumdl_lib::config::{Config, MarkdownFlavor, RuleConfig};
+    use rumdl_lib::lint_context::LintContext;
+    use rumdl_lib::rule::Rule;
+    use rumdl_lib::rules::MD051LinkFragments;
+    use rumdl_lib::utils::anchor_styles::AnchorStyle;
+    use rumdl_lib::utils::slug::{SlugSubstitution, Slugify};
+
+    fn check(config: &Config, content: &str) -> usize {
+        let rule = MD051LinkFragments::from_config(config);
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap().len()
+    }
+
+    #[test]
+    fn test_custom_substitutions_define_expected_anchors() {
+        let mut config = Config::default();
+        config.global.slugify = Some(Slugify::Custom(vec![
+            SlugSubstitution {
+                pattern: "[^a-z0-9 ]".to_string(),
+                replacement: String::new(),
+            },
+            SlugSubstitution {
+                pattern: " ".to_string(),
+                replacement: "_".to_string(),
+            },
+        ]));
+
+        let content = "# Getting Started\n\n[ok](#getting_started) [bad](#getting-started)\n";
+        assert_eq!(check(&config, content), 1);
+    }
+
+    #[test]
+    fn test_rule_anchor_style_overrides_global_slugify() {
+        let mut config = Config::default();
+        config.global.slugify = Some(Slugify::Style(AnchorStyle::GitLab));
+        let content = "# API  Guide\n\n[link](#api--guide)\n";
+        // GitLab collapses repeated hyphens, GitHub keeps them
+        assert_eq!(check(&config, content), 1);
+
+        let mut rule_config = RuleConfig::default();
+        rule_config
+            .values
+            .insert("anchor-style".to_string(), toml::Value::String("github".to_string()));
+        config.rules.insert("MD051".to_string(), rule_config);
+        assert_eq!(check(&config, content), 0);
+    }
+}
@@ -346,7 +346,7 @@ From the Python documentation on [`dataclasses.InitVar`]:
     let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
 
     println!("\n=== PARSED LINKS ===");
-    for (i, link) in ctx.links.iter().enumerate() {
+    for (i, link) in ctx.links().iter().enumerate() {
         println!(
             "Link {}: line {}, text='{}', is_reference={}, reference_id={:?}",
             i, link.line, link.text, link.is_reference, link.reference_id
@@ -19,6 +19,8 @@ fn test_list_item_trailing_whitespace_removal() {
         myst_roles: false,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
     };
 
     let result = reflow_markdown(input, &options);
@@ -229,6 +231,8 @@ fn test_sentence_per_line_reflow() {
         myst_roles: false,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
     };
 
     let input = "First sentence. Second sentence. Third sentence.";
@@ -561,6 +565,8 @@ fn test_ie_abbreviation_split_debug() {
         myst_roles: false,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
     };
 
     let result = reflow_line(input, &options);
@@ -586,6 +592,8 @@ fn test_ie_abbreviation_paragraph() {
         myst_roles: false,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
     };
 
     let result = reflow_markdown(input, &options);
@@ -666,6 +674,8 @@ fn test_definition_list_with_paragraphs() {
         myst_roles: false,
         require_sentence_capital: true,
         max_list_continuation_indent: None,
+        no_break_patterns: Vec::new(),
+        semantic_break_rules: SemanticBreakRules::default(),
     };
 
     let content = "Regular paragraph. With multiple sentences.\n\nTerm\n: Definition.\n\nAnother paragraph.";
@@ -6339,3 +6349,183 @@ fn test_reflow_preserves_space_after_code_span_before_punctuation() {
         "a space must not be introduced before attached punctuation, got:\n{result3}"
     );
 }
+
+fn jinja_options(line_length: usize) -> ReflowOptions {
+    ReflowOptions {
+        line_length,
+        no_break_patterns: vec![
+            regex::Regex::new(r"\{\{.*?\}\}").unwrap(),
+            regex::Regex::new(r"\{%.*?%\}").unwrap(),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_no_break_patterns_keep_template_spans_on_one_line() {
+    let input = "Welcome back {{ user.first_name or 'friend' }}, your order {% if order %}is ready{% endif %} today.";
+
+    // Without patterns the expression is ordinary text and gets wrapped mid-span
+    let plain = reflow_markdown(
+        input,
+        &ReflowOptions {
+            line_length: 30,
+            ..Default::default()
+        },
+    );
+    assert!(
+        !plain.contains("{{ user.first_name or 'friend' }}"),
+        "expected the unprotected span to be split, got:\n{plain}"
+    );
+
+    let result = reflow_markdown(input, &jinja_options(30));
+    assert!(
+        result.contains("{{ user.first_name or 'friend' }},"),
+        "expression and its trailing comma must stay on one line, got:\n{result}"
+    );
+    assert!(
+        result.contains("{% if order %}is"),
+        "statement must stay intact and attached, got:\n{result}"
+    );
+    assert!(result.contains("ready{% endif %}"), "got:\n{result}");
+    assert_eq!(
+        reflow_markdown(&result, &jinja_options(30)),
+        result,
+        "reflow must be idempotent"
+    );
+}
+
+#[test]
+fn test_no_break_patterns_hide_sentence_and_clause_breaks() {
+    let input = "Run it first. Then {{ 'Done. Next step, please.' }} appears. Continue with the rest.";
+
+    let options = ReflowOptions {
+        sentence_per_line: true,
+        ..jinja_options(80)
+    };
+    let result = reflow_markdown(input, &options);
+    assert_eq!(
+        result,
+        "Run it first.\nThen {{ 'Done. Next step, please.' }} appears.\nContinue with the rest."
+    );
+
+    let options = ReflowOptions {
+        semantic_line_breaks: true,
+        ..jinja_options(40)
+    };
+    let result = reflow_markdown(input, &options);
+    assert!(
+        result.lines().any(|l| l.contains("{{ 'Done. Next step, please.' }}")),
+        "no sentence or clause break inside the expression, got:\n{result}"
+    );
+}
+
+fn semantic_options(line_length: usize, rules: SemanticBreakRules) -> ReflowOptions {
+    ReflowOptions {
+        line_length,
+        semantic_line_breaks: true,
+        semantic_break_rules: rules,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_semantic_break_rules_comma_min_length() {
+    let input = "Folding is fast, unfolding was slow, the measurements were taken at many temperatures.";
+
+    let result = reflow_line(input, &semantic_options(60, SemanticBreakRules::default()));
+    assert_eq!(
+        result,
+        vec![
+            "Folding is fast, unfolding was slow,",
+            "the measurements were taken at many temperatures."
+        ]
+    );
+
+    // Both commas fall before 45 chars, so neither is a break point
+    let rules = SemanticBreakRules {
+        comma_min_length: 45,
+        ..Default::default()
+    };
+    let result = reflow_line(input, &semantic_options(60, rules));
+    assert_eq!(
+        result,
+        vec![
+            "Folding is fast, unfolding was slow, the measurements were",
+            "taken at many temperatures."
+        ]
+    );
+}
+
+#[test]
+fn test_semantic_break_rules_custom_break_words() {
+    let input =
+        "The mutation destabilised the hydrophobic core thereby accelerating unfolding in every condition tested.";
+
+    let result = reflow_line(input, &semantic_options(60, SemanticBreakRules::default()));
+    assert_eq!(result[0], "The mutation destabilised the hydrophobic core thereby");
+
+    // Custom words are matched case-insensitively
+    let rules = SemanticBreakRules {
+        break_words: Some(vec!["Thereby".to_string()]),
+        ..Default::default()
+    };
+    let result = reflow_line(input, &semantic_options(60, rules));
+    assert_eq!(
+        result,
+        vec![
+            "The mutation destabilised the hydrophobic core",
+            "thereby accelerating unfolding in every condition tested."
+        ]
+    );
+}
+
+#[test]
+fn test_semantic_break_rules_empty_break_words_disables_word_splits() {
+    let input = "Binding affinity increased under reducing conditions whereas the control samples remained unchanged.";
+
+    let result = reflow_line(input, &semantic_options(60, SemanticBreakRules::default()));
+    assert_eq!(result[1], "whereas the control samples remained unchanged.");
+
+    let rules = SemanticBreakRules {
+        break_words: Some(Vec::new()),
+        ..Default::default()
+    };
+    let result = reflow_line(input, &semantic_options(60, rules));
+    assert_eq!(
+        result,
+        vec![
+            "Binding affinity increased under reducing conditions whereas",
+            "the control samples remained unchanged."
+        ]
+    );
+}
+
+#[test]
+fn test_semantic_break_rules_keep_citations() {
+    let input = "Folding rates were measured previously with stopped flow [14] using the wild type protein in buffer.";
+
+    // The citation carries the word it cites onto the next line
+    let result = reflow_line(input, &semantic_options(60, SemanticBreakRules::default()));
+    assert_eq!(
+        result,
+        vec![
+            "Folding rates were measured previously with stopped",
+            "flow [14] using the wild type protein in buffer."
+        ]
+    );
+
+    let rules = SemanticBreakRules {
+        keep_citations: false,
+        ..Default::default()
+    };
+    let result = reflow_line(input, &semantic_options(60, rules));
+    assert_eq!(result[1], "[14] using the wild type protein in buffer.");
+}
+
+#[test]
+fn test_semantic_break_rules_citation_after_sentence_end() {
+    let input = "Unfolding is slow.[3] Refolding is fast.";
+    let result = reflow_line(input, &semantic_options(80, SemanticBreakRules::default()));
+    assert_eq!(result, vec!["Unfolding is slow.[3]", "Refolding is fast."]);
+}